@@ -1,10 +1,42 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
 use oxygen_error::{ early_error, Result };
+use oxygen_fmt::format;
+use oxygen_interpreter::{ evaluate_globals, interpret, HostFunctions };
 use oxygen_options::Options;
 use oxygen_parser::parse;
+use oxygen_repl::Repl;
+use oxygen_resolve::{ load_crate, resolve };
+use oxygen_span::add_file;
+use oxygen_typeck::check;
 
 fn usage() {
     println!("Usage: oxygen [OPTIONS] <input>");
+    println!("       oxygen fmt [--check] <files>...");
+    println!("       oxygen repl");
+    println!();
+    println!("<input> is the crate root, other files are included with `mod name;`");
+    println!();
     println!("\t-h, --help            Print this message and exit.");
+    println!("\t    --print-ast       Print the AST of each module instead of running the program.");
+    println!("\t-C <option>=<value>   Set a code generation option, one of:");
+    println!("\t                          overflow-checks=yes|no  Panic when integer arithmetic overflows (default yes)");
+    println!();
+    println!("`oxygen fmt` formats each file in place, or with `--check` lists the files");
+    println!("that aren't formatted, exiting with 1 if there are any.");
+    println!();
+    println!("`oxygen repl` runs code as it's typed in, type :help inside it for more.");
+}
+
+// Parses the value of an on/off `-C` option
+fn parse_switch(option: &str, value: &str) -> Result<bool> {
+    match value {
+        "y" | "yes" | "on" | "true" => Ok(true),
+        "n" | "no" | "off" | "false" => Ok(false),
+        _ => Err(early_error(format!("Expected `yes` or `no` for `-C {option}`, but got `{value}`")))
+    }
 }
 
 fn handle_arguments(arguments: &[String]) -> Result<Option<Options>> {
@@ -28,6 +60,22 @@ fn handle_arguments(arguments: &[String]) -> Result<Option<Options>> {
                     usage();
                     return Ok(None);
                 },
+                "--print-ast" => {
+                    options.print_ast = true;
+                },
+                "-C" => {
+                    let Some(option) = arguments.get(idx) else {
+                        Err(early_error("Expected a code generation option after `-C`".to_string()))?
+                    };
+                    idx += 1;
+
+                    // A switch by itself is turned on e.g. `-C overflow-checks`
+                    let (name, value) = option.split_once('=').unwrap_or((option, "yes"));
+                    match name {
+                        "overflow-checks" => options.overflow_checks = parse_switch(name, value)?,
+                        _ => Err(early_error(format!("No such code generation option: {name}")))?
+                    }
+                },
                 _ => {
                     Err(early_error(format!("No such argument: {argument}")))?;
                 }
@@ -35,7 +83,8 @@ fn handle_arguments(arguments: &[String]) -> Result<Option<Options>> {
         } else {
             if !options.input_path.is_empty() {
                 Err(early_error(format!(
-                    "More than one input file was provided (Found {} and {})",
+                    "More than one input file was provided (Found {} and {}), \
+                    other files should be declared as modules of the crate root with `mod`",
                     options.input_path,
                     argument
                 )))?;
@@ -48,12 +97,87 @@ fn handle_arguments(arguments: &[String]) -> Result<Option<Options>> {
     Ok(Some(options))
 }
 
-pub fn run_compiler(arguments: Vec<String>) -> Result<()> {
-    let Some(options) = handle_arguments(&arguments)? else { return Ok(()) };
+// `oxygen fmt [--check] <files>...`
+fn run_formatter(arguments: &[String]) -> Result<i32> {
+    let check = arguments.iter().any(|argument| argument == "--check");
+    let mut paths = Vec::new();
 
-    let src = std::fs::read_to_string(options.input_path).unwrap();
+    for argument in arguments {
+        match argument.as_str() {
+            "--check" => {},
+            "-h" | "--help" => {
+                usage();
+                return Ok(0);
+            },
+            _ if argument.starts_with("-") => Err(early_error(format!("No such argument: {argument}")))?,
+            _ => paths.push(argument)
+        }
+    }
+
+    if paths.is_empty() {
+        Err(early_error("Expected at least one file to format".to_string()))?;
+    }
+
+    let mut unformatted = false;
+    for path in paths {
+        let src = fs::read_to_string(path).map_err(|err| {
+            early_error(format!("Couldn't read {path}: {err}"))
+        })?;
+
+        // Code with syntax errors isn't formatted, since
+        // it might not be clear what it's meant to be
+        parse(add_file(Path::new(path), &src))?;
+
+        let formatted = format(&src);
+        if formatted == src { continue };
+
+        if check {
+            println!("{path} isn't formatted");
+            unformatted = true;
+        } else {
+            fs::write(path, formatted).map_err(|err| {
+                early_error(format!("Couldn't write {path}: {err}"))
+            })?;
+        }
+    }
+
+    Ok(unformatted as i32)
+}
+
+// Returns the exit status of the program that was run
+pub fn run_compiler(arguments: Vec<String>) -> Result<i32> {
+    if arguments.get(1).is_some_and(|command| command == "fmt") {
+        return run_formatter(&arguments[2..]);
+    }
+
+    if arguments.get(1).is_some_and(|command| command == "repl") {
+        if let Some(argument) = arguments.get(2) {
+            Err(early_error(format!("`oxygen repl` doesn't take any arguments, but got {argument}")))?;
+        }
+
+        Repl::new().run(io::stdin().lock(), io::stdout()).map_err(|err| {
+            early_error(format!("Couldn't read the input or write the output of the REPL: {err}"))
+        })?;
+        return Ok(0);
+    }
+
+    let Some(options) = handle_arguments(&arguments)? else { return Ok(0) };
+
+    let mut krate = load_crate(Path::new(&options.input_path))?;
+
+    resolve(&mut krate)?;
+
+    if options.print_ast {
+        for module in &krate.modules {
+            println!("{:#?}", module.items);
+        }
+
+        return Ok(0);
+    }
 
-    parse(&src)?;
+    let types = check(&krate)?;
+    oxygen_abi::check(&krate)?;
+    let globals = evaluate_globals(&krate, &types)?;
 
-    Ok(())
+    interpret(&krate, &types, globals, &HostFunctions::standard(), options.overflow_checks)
 }
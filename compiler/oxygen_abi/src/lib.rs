@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use oxygen_ast::{ FloatTy, Function, IntTy };
+use oxygen_error::{ span_error, Result };
+use oxygen_resolve::Crate;
+use oxygen_typeck::{ FunctionTy, Ty };
+
+// The C type that an Oxide type is passed to or returned
+// from a foreign function as
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CType {
+    // The fixed width integers of `stdint.h`, e.g. `i8` is `int8_t`
+    // and `u64` is `uint64_t`, along with `char`, which is a
+    // Unicode scalar value passed as a `uint32_t`
+    Int(IntTy),
+    // f32
+    Float,
+    // f64
+    Double,
+    // bool, which is a single byte that is either 0 or 1
+    Bool,
+    // () and never, which can only be returned
+    Void
+}
+
+impl CType {
+    // The C type with the same representation as `ty`, if there is one.
+    // Strings, arrays, slices, tuples and functions have no fixed
+    // representation yet, so can't cross into another language.
+    pub fn of(ty: &Ty) -> Option<CType> {
+        match ty {
+            Ty::Int(int) => Some(CType::Int(*int)),
+            Ty::Float(FloatTy::F32) => Some(CType::Float),
+            Ty::Float(FloatTy::F64) => Some(CType::Double),
+            Ty::Char => Some(CType::Int(IntTy::U32)),
+            Ty::Bool => Some(CType::Bool),
+            // A function that never returns is `noreturn void` in C
+            Ty::Unit | Ty::Never => Some(CType::Void),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for CType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CType::Int(int) => {
+                let bits = &int.name()[1..];
+                match int.name().starts_with('u') {
+                    true => write!(f, "uint{bits}_t"),
+                    false => write!(f, "int{bits}_t")
+                }
+            },
+            CType::Float => write!(f, "float"),
+            CType::Double => write!(f, "double"),
+            CType::Bool => write!(f, "bool"),
+            CType::Void => write!(f, "void")
+        }
+    }
+}
+
+// A value passed to or returned from a foreign function
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CValue {
+    // Any of the integer types, which all fit in an i128
+    Int(i128),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    Void
+}
+
+// A foreign function as it is seen from C
+#[derive(Clone, PartialEq, Debug)]
+pub struct ForeignSignature {
+    // Foreign functions aren't mangled, so a backend
+    // links against their name as it is written
+    pub symbol: String,
+    pub parameters: Vec<CType>,
+    pub return_type: CType
+}
+
+impl ForeignSignature {
+    pub fn new(symbol: &str, parameters: &[CType], return_type: CType) -> Self {
+        ForeignSignature {
+            symbol: symbol.to_string(),
+            parameters: parameters.to_vec(),
+            return_type
+        }
+    }
+
+    // The signature of a foreign function, or `None` if its
+    // types can't be passed through the C ABI (which `check` reports)
+    pub fn of(function: &Function) -> Option<ForeignSignature> {
+        let signature = FunctionTy::of(function);
+        if signature.fallible { return None };
+
+        let parameters = signature.parameters.iter()
+            .map(|parameter| CType::of(parameter).filter(|ty| *ty != CType::Void))
+            .collect::<Option<_>>()?;
+
+        Some(ForeignSignature {
+            symbol: function.name.clone(),
+            parameters,
+            return_type: CType::of(&signature.return_ty)?
+        })
+    }
+}
+
+// Written as a C declaration e.g. `int32_t abs(int32_t)`
+impl fmt::Display for ForeignSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}(", self.return_type, self.symbol)?;
+
+        if self.parameters.is_empty() {
+            write!(f, "void")?;
+        }
+        for (idx, parameter) in self.parameters.iter().enumerate() {
+            if idx != 0 { write!(f, ", ")? };
+            write!(f, "{parameter}")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+// Checks that every foreign function in the crate can be called
+// through the C ABI, and that functions declared more than once
+// (which link to the same symbol) agree on their signature
+pub fn check(krate: &Crate) -> Result<()> {
+    let mut symbols: HashMap<String, ForeignSignature> = HashMap::new();
+
+    for (_, function) in krate.functions().filter(|(_, function)| function.block.is_none()) {
+        let signature = FunctionTy::of(function);
+
+        if signature.fallible {
+            Err(span_error(function.span, format!(
+                "The foreign function `{}` can't return an error (`!`), since C has no way to represent it",
+                function.name
+            )))?;
+        }
+
+        for parameter in &signature.parameters {
+            if CType::of(parameter).is_none_or(|ty| ty == CType::Void) {
+                Err(span_error(function.span, format!(
+                    "The foreign function `{}` can't take a `{parameter}`, which has no C representation",
+                    function.name
+                )))?;
+            }
+        }
+
+        if CType::of(&signature.return_ty).is_none() {
+            Err(span_error(function.span, format!(
+                "The foreign function `{}` can't return a `{}`, which has no C representation",
+                function.name, signature.return_ty
+            )))?;
+        }
+
+        let foreign = ForeignSignature::of(function).unwrap();
+        match symbols.get(&foreign.symbol) {
+            Some(previous) if *previous != foreign => Err(span_error(function.span, format!(
+                "`{}` is declared as `{foreign}` here, but as `{previous}` elsewhere", function.name
+            )))?,
+            Some(_) => {},
+            None => { symbols.insert(foreign.symbol.clone(), foreign); }
+        }
+    }
+
+    Ok(())
+}
@@ -1,66 +1,517 @@
-use std::collections::HashMap;
+use std::fmt;
 
-pub type Ast = Vec<TopLevelItem>;
+use oxygen_span::Span;
 
-pub type FunctionParameters = HashMap<String, Type>;
+// A file of code, which is a module
+#[derive(Debug)]
+pub struct Ast {
+    // The `#!` comments at the start of the file, without the `#!`s
+    pub docs: Option<String>,
+    pub items: Vec<TopLevelItem>
+}
+
+// In the order they were written, so that calls can
+// match their arguments up with them
+pub type FunctionParameters = Vec<Param>;
 
+// A parameter of a function or closure e.g. `i32 count = 1`
 #[derive(Debug)]
+pub struct Param {
+    pub pattern: Pattern,
+    pub r#type: Type,
+    // Used when a call leaves this parameter out
+    pub default: Option<Expression>,
+    pub span: Span
+}
+
+impl Param {
+    // The name that a named argument gives this parameter by,
+    // which only parameters bound to a single variable have
+    pub fn name(&self) -> Option<&str> {
+        match &self.pattern {
+            Pattern::Ident(name) => Some(name),
+            _ => None
+        }
+    }
+}
+
+// The integer types, which all wrap around on overflow
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IntTy {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64
+}
+
+impl IntTy {
+    pub const ALL: [IntTy; 8] = [
+        IntTy::I8, IntTy::I16, IntTy::I32, IntTy::I64,
+        IntTy::U8, IntTy::U16, IntTy::U32, IntTy::U64
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IntTy::I8 => "i8",
+            IntTy::I16 => "i16",
+            IntTy::I32 => "i32",
+            IntTy::I64 => "i64",
+            IntTy::U8 => "u8",
+            IntTy::U16 => "u16",
+            IntTy::U32 => "u32",
+            IntTy::U64 => "u64"
+        }
+    }
+
+    // The type with the name `name` e.g. the `u8` suffix of `255u8`
+    pub fn from_name(name: &str) -> Option<IntTy> {
+        IntTy::ALL.into_iter().find(|ty| ty.name() == name)
+    }
+
+    pub fn bits(self) -> u32 {
+        match self {
+            IntTy::I8 | IntTy::U8 => 8,
+            IntTy::I16 | IntTy::U16 => 16,
+            IntTy::I32 | IntTy::U32 => 32,
+            IntTy::I64 | IntTy::U64 => 64
+        }
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(self, IntTy::I8 | IntTy::I16 | IntTy::I32 | IntTy::I64)
+    }
+
+    pub fn min(self) -> i128 {
+        if self.is_signed() { -(1 << (self.bits() - 1)) } else { 0 }
+    }
+
+    pub fn max(self) -> i128 {
+        if self.is_signed() { (1 << (self.bits() - 1)) - 1 } else { (1 << self.bits()) - 1 }
+    }
+
+    pub fn contains(self, value: i128) -> bool {
+        (self.min()..=self.max()).contains(&value)
+    }
+
+    // Whether every value of `other` is also a value of this type
+    pub fn contains_all(self, other: IntTy) -> bool {
+        self.min() <= other.min() && other.max() <= self.max()
+    }
+
+    // Wraps `value` around into the range of this type, keeping
+    // only its lowest bits like a two's complement machine would
+    pub fn wrap(self, value: i128) -> i128 {
+        let unused = 128 - self.bits();
+        if self.is_signed() {
+            (value << unused) >> unused
+        } else {
+            ((value as u128) << unused >> unused) as i128
+        }
+    }
+
+    // Works out `left operator right` for two values of this type,
+    // failing if the result doesn't fit in it or doesn't exist
+    pub fn checked(self, operator: BinaryOperator, left: i128, right: i128) -> Result<i128, IntError> {
+        use BinaryOperator::*;
+
+        // Values of this type always fit in 65 bits, so only
+        // multiplying them can overflow an i128
+        let (result, overflowed) = match operator {
+            Plus => (left + right, false),
+            Minus => (left - right, false),
+            Star => left.overflowing_mul(right),
+            Divide | Remainder if right == 0 => return Err(IntError::DivideByZero(operator)),
+            Divide => (left / right, false),
+            // Truncated like division, so the result has the sign of `left` e.g. -7 % 3 == -1
+            Remainder => (left % right, false),
+            Power if right < 0 => return Err(IntError::NegativePower(right)),
+            Power => overflowing_pow(left, right),
+            ShiftLeft | ShiftRight if !(0..self.bits() as i128).contains(&right) => {
+                return Err(IntError::ShiftTooFar { amount: right, bits: self.bits() })
+            },
+            // Bits shifted out of the top are lost, which isn't an overflow
+            ShiftLeft => return Ok(self.wrap(left << right)),
+            // Arithmetic, so negative numbers stay negative e.g. -8 >> 1 == -4
+            ShiftRight => (left >> right, false),
+            And => (left & right, false),
+            Or => (left | right, false),
+            Xor => (left ^ right, false),
+            _ => unreachable!("{operator:?} doesn't produce an integer")
+        };
+
+        if overflowed || !self.contains(result) {
+            let action = match operator {
+                Plus => "add",
+                Minus => "subtract",
+                Star | Power => "multiply",
+                Divide => "divide",
+                _ => "calculate the remainder"
+            };
+            return Err(IntError::Overflow { action, wrapped: self.wrap(result) });
+        }
+
+        Ok(result)
+    }
+
+    pub fn checked_neg(self, value: i128) -> Result<i128, IntError> {
+        match self.contains(-value) {
+            true => Ok(-value),
+            false => Err(IntError::Overflow { action: "negate", wrapped: self.wrap(-value) })
+        }
+    }
+}
+
+// `base` to the power of `exponent` and whether it
+// overflowed an i128, like the `overflowing_*` methods
+fn overflowing_pow(mut base: i128, mut exponent: i128) -> (i128, bool) {
+    let mut result: i128 = 1;
+    let mut overflowed = false;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            let (product, overflow) = result.overflowing_mul(base);
+            result = product;
+            overflowed |= overflow;
+        }
+
+        exponent >>= 1;
+        if exponent > 0 {
+            let (square, overflow) = base.overflowing_mul(base);
+            base = square;
+            overflowed |= overflow;
+        }
+    }
+
+    (result, overflowed)
+}
+
+// Why an operation on integers doesn't have a result,
+// displayed as the message the program panics with
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IntError {
+    // The result doesn't fit in the type, which is only an
+    // error when overflow checks are on, and otherwise
+    // wraps around to `wrapped`
+    Overflow { action: &'static str, wrapped: i128 },
+    // Dividing or taking the remainder by zero
+    DivideByZero(BinaryOperator),
+    NegativePower(i128),
+    ShiftTooFar { amount: i128, bits: u32 }
+}
+
+impl fmt::Display for IntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntError::Overflow { action, .. } => write!(f, "attempt to {action} with overflow"),
+            IntError::DivideByZero(BinaryOperator::Remainder) => {
+                write!(f, "attempt to calculate the remainder with a divisor of zero")
+            },
+            IntError::DivideByZero(_) => write!(f, "attempt to divide by zero"),
+            IntError::NegativePower(power) => {
+                write!(f, "attempt to raise an integer to a negative power ({power})")
+            },
+            IntError::ShiftTooFar { amount, bits } => {
+                write!(f, "attempt to shift by {amount}, which isn't between 0 and {}", bits - 1)
+            }
+        }
+    }
+}
+
+// The floating point types, from IEEE 754
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FloatTy {
+    F32,
+    F64
+}
+
+impl FloatTy {
+    pub fn name(self) -> &'static str {
+        match self {
+            FloatTy::F32 => "f32",
+            FloatTy::F64 => "f64"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<FloatTy> {
+        match name {
+            "f32" => Some(FloatTy::F32),
+            "f64" => Some(FloatTy::F64),
+            _ => None
+        }
+    }
+
+    // How many bits of precision the type has, including
+    // the implicit leading bit, so integers with more
+    // bits than this can't all be represented exactly
+    pub fn mantissa_bits(self) -> u32 {
+        match self {
+            FloatTy::F32 => f32::MANTISSA_DIGITS,
+            FloatTy::F64 => f64::MANTISSA_DIGITS
+        }
+    }
+
+    // Rounds `value` to the nearest value of this type. Floats
+    // of either type are kept as an f64, which every f32 fits in
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            FloatTy::F32 => value as f32 as f64,
+            FloatTy::F64 => value
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub enum Type {
-    I32
+    // i8, u32, ...
+    Int(IntTy),
+    // f32, f64
+    Float(FloatTy),
+    Bool,
+    Char,
+    // (), the type of functions without a return type
+    Unit,
+    // The type of expressions that never finish, such as `panic(...)`
+    Never,
+    // [i32; 4]
+    Array(Box<Type>, usize),
+    // [i32]
+    Slice(Box<Type>),
+    // (i32, [i32; 2])
+    Tuple(Vec<Type>),
+    // func(i32) ~ i32, impure func(i32) ~ i32!
+    Function {
+        impure: bool,
+        parameters: Vec<Type>,
+        return_type: Option<Box<ReturnType>>
+    }
+}
+
+// What a value is bound to, in `let` statements and function parameters
+#[derive(Clone, PartialEq, Debug)]
+pub enum Pattern {
+    // x
+    Ident(String),
+    // _
+    Wildcard,
+    // (quotient, remainder)
+    Tuple(Vec<Pattern>)
+}
+
+impl Pattern {
+    // The names of every variable bound by this pattern
+    pub fn bindings(&self) -> Vec<&str> {
+        match self {
+            Pattern::Ident(name) => vec![name],
+            Pattern::Wildcard => Vec::new(),
+            Pattern::Tuple(patterns) => patterns.iter().flat_map(Pattern::bindings).collect()
+        }
+    }
 }
 
 // bool = can_error (`!`)
 pub type ReturnType = (Type, bool);
 
+// A (possibly) multi-segment path e.g. `maths::divide_two`
+#[derive(Clone, Debug)]
+pub struct Path {
+    pub segments: Vec<String>,
+    pub span: Span
+}
+
 #[derive(Debug)]
 pub enum TopLevelItem {
-    Function(Function)
+    Function(Function),
+    Global(Global),
+    Extern(ExternBlock),
+    Module(ModuleDeclaration),
+    Use(UseDeclaration),
+    StaticAssert(StaticAssert)
 }
 
+// A function without a block is a foreign function, defined in
+// another language and called through the C ABI. These are impure
+// unless marked `pure`, since the compiler can't see what they do.
 #[derive(Debug)]
 pub struct Function {
+    // The `##` comments before it, without the `##`s
+    pub docs: Option<String>,
+    pub public: bool,
     pub impure: bool,
     pub name: String,
     pub parameters: Option<FunctionParameters>,
     pub return_type: Option<ReturnType>,
-    pub block: Option<Block>
+    pub block: Option<Block>,
+    pub span: Span
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Abi {
+    // extern "C"
+    C
 }
 
-pub type Block = Vec<Statement>;
+// extern "C" { func abs(i32 x) ~ i32; }
+#[derive(Debug)]
+pub struct ExternBlock {
+    pub abi: Abi,
+    pub functions: Vec<Function>,
+    pub span: Span
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GlobalKind {
+    // Worked out when the program is compiled, and can never change
+    Const,
+    // Starts with a value worked out when the program is compiled,
+    // but can be changed by `impure` functions
+    Static
+}
+
+// const LIMIT: i32 = square(8);
+// static COUNTER: i32 = 0;
+#[derive(Debug)]
+pub struct Global {
+    pub kind: GlobalKind,
+    pub public: bool,
+    pub name: String,
+    pub r#type: Type,
+    pub value: Expression,
+    pub span: Span
+}
+
+// `mod name;`, whose items live in another file
+#[derive(Debug)]
+pub struct ModuleDeclaration {
+    pub public: bool,
+    pub name: String,
+    pub span: Span
+}
+
+// `use path::to::item;`
+#[derive(Debug)]
+pub struct UseDeclaration {
+    pub public: bool,
+    pub path: Path,
+    pub span: Span
+}
+
+// `static_assert(LIMIT < 100);`, which fails
+// compilation if the condition is false
+#[derive(Debug)]
+pub struct StaticAssert {
+    pub condition: Expression,
+    pub span: Span
+}
+
+#[derive(Debug)]
+pub struct Block {
+    pub statements: Vec<Statement>,
+    // The final expression without a `;`, which is
+    // the value of the block
+    pub tail: Option<Box<Expression>>
+}
 
 #[derive(Debug)]
 pub enum Statement {
-    Expression(Expression)
+    Expression(Expression),
+    // let x: i32 = 5;
+    // let (q, r) = divide(7, 2);
+    Let {
+        pattern: Pattern,
+        r#type: Option<Type>,
+        value: Expression,
+        span: Span
+    },
+    // defer print("done");
+    // defer { close(file); }
+    Defer {
+        body: Deferred,
+        span: Span
+    }
+}
+
+// The code a `defer` runs when its scope is left
+#[derive(Debug)]
+pub enum Deferred {
+    Expression(Expression),
+    Block(Block)
 }
 
 #[derive(Debug)]
 pub enum LiteralType {
-    Integer(isize),
-    Float(f64),
+    // 4, 0xff, 255u8
+    Integer {
+        value: u128,
+        suffix: Option<IntTy>
+    },
+    // 2.5, 1e3, 0.5f32
+    Float {
+        value: f64,
+        suffix: Option<FloatTy>
+    },
+    // 'a', '\n'
+    Char(char),
     String(String),
     True,
     False
 }
 
-pub type CallParameters = Vec<Expression>;
+// An argument given to a call, which can be
+// named e.g. `b` in `f(1, b: 2)`
+#[derive(Debug)]
+pub struct Argument {
+    pub name: Option<String>,
+    pub value: Expression,
+    pub span: Span,
+    // Whether this is the value on the left of a `|>`
+    // e.g. `x` in `x |> f(1)`, which is passed first
+    pub piped: bool
+}
+
+pub type CallParameters = Vec<Argument>;
 
 #[derive(Debug)]
-pub enum Expression {
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub span: Span
+}
+
+#[derive(Debug)]
+pub enum ExpressionKind {
     // Literal e.g. "Hello, world!", 38, 38u8
     Literal(LiteralType),
     // Variable by itself
     Ident(String),
-    // Function call e.g. hello_world();
+    // Path to an item e.g. maths::add, LIMIT
+    Path(Path),
+    // Function call e.g. hello_world(); maths::add(4, 5); callback(3); f(b: 1, a: 2);
     FunctionCall {
-        name: String,
+        callee: Box<Expression>,
         parameters: Option<CallParameters>
     },
+    // Anonymous function e.g. func(i32 x) ~ i32 { x + offset }
+    Closure {
+        impure: bool,
+        parameters: Option<FunctionParameters>,
+        // Worked out from the body when not given
+        return_type: Option<ReturnType>,
+        block: Block
+    },
     // Method call e.g. object.method();
     MethodCall {
         path: Box<Expression>,
         name: String,
         parameters: Option<CallParameters>
     },
+    // Cast e.g. count as f64
+    Cast {
+        value: Box<Expression>,
+        r#type: Type
+    },
     // Binary operation e.g. 4 + 5
     Binary {
         left: Box<Expression>,
@@ -75,41 +526,940 @@ pub enum Expression {
     // If statement 
     IfExpression {
         condition: Box<Expression>,
-        block: Block
-        // TODO: Else
+        block: Block,
+        else_branch: Option<ElseBranch>
+    },
+    // loop { ... }
+    Loop(Block),
+    Break,
+    // return; return x + 1;
+    Return(Option<Box<Expression>>),
+    // Array literal e.g. [1, 2, 3]
+    Array(Vec<Expression>),
+    // Repeated array literal e.g. [0; 4]
+    ArrayRepeat {
+        value: Box<Expression>,
+        count: usize
+    },
+    // Indexing e.g. numbers[2]
+    Index {
+        target: Box<Expression>,
+        index: Box<Expression>
+    },
+    // Tuple literal e.g. (1, "one")
+    Tuple(Vec<Expression>),
+    // Tuple element access e.g. pair.0
+    TupleIndex {
+        target: Box<Expression>,
+        index: usize
+    },
+    // Assignment e.g. x = 4, numbers[2] += 1
+    Assign {
+        target: Box<Expression>,
+        // The operator of a compound assignment
+        operator: Option<BinaryOperator>,
+        value: Box<Expression>
+    }
+}
+
+impl ExpressionKind {
+    // Whether this expression ends in a block, meaning it
+    // doesn't need a `;` to be used as a statement
+    pub fn ends_with_block(&self) -> bool {
+        matches!(self, ExpressionKind::IfExpression { .. } | ExpressionKind::Loop(_))
     }
 }
 
 #[derive(Debug)]
+pub enum ElseBranch {
+    // else { ... }
+    Block(Block),
+    // else if ... { ... }
+    If(Box<Expression>)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum BinaryOperator {
     Plus,
     Minus,
     Star,
     Divide,
+    // %, whose result has the sign of the left hand side
+    Remainder,
+    // **
+    Power,
     And,
-    Or
+    Or,
+    // ^
+    Xor,
+    // <<
+    ShiftLeft,
+    // >>, which keeps the sign of the left hand side
+    ShiftRight,
+    EqEq,
+    NotEq,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    // &&
+    LogicalAnd,
+    // ||
+    LogicalOr
 }
 
-#[derive(Debug)]
+impl BinaryOperator {
+    // How the operator is written e.g. `+`
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOperator::Plus => "+",
+            BinaryOperator::Minus => "-",
+            BinaryOperator::Star => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Remainder => "%",
+            BinaryOperator::Power => "**",
+            BinaryOperator::And => "&",
+            BinaryOperator::Or => "|",
+            BinaryOperator::Xor => "^",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+            BinaryOperator::EqEq => "==",
+            BinaryOperator::NotEq => "!=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterEq => ">=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessEq => "<=",
+            BinaryOperator::LogicalAnd => "&&",
+            BinaryOperator::LogicalOr => "||"
+        }
+    }
+
+    // How tightly the operator binds its operands
+    pub fn precedence(self) -> Precedence {
+        match self {
+            BinaryOperator::Plus | BinaryOperator::Minus => Precedence::Sum,
+            BinaryOperator::Star | BinaryOperator::Divide | BinaryOperator::Remainder => Precedence::Product,
+            BinaryOperator::Power => Precedence::Power,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => Precedence::Shift,
+            BinaryOperator::And => Precedence::BitAnd,
+            BinaryOperator::Xor => Precedence::BitXor,
+            BinaryOperator::Or => Precedence::BitOr,
+            BinaryOperator::EqEq | BinaryOperator::NotEq => Precedence::Equality,
+            BinaryOperator::Greater | BinaryOperator::GreaterEq | BinaryOperator::Less | BinaryOperator::LessEq =>
+                Precedence::Comparison,
+            BinaryOperator::LogicalAnd => Precedence::And,
+            BinaryOperator::LogicalOr => Precedence::Or
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum UnaryOperator {
     // -
     Negate,
     // !
-    Not
+    Not,
+    // ~
+    BitNot
 }
 
-#[derive(Debug)]
+impl UnaryOperator {
+    // How the operator is written e.g. `!`
+    pub fn symbol(self) -> &'static str {
+        match self {
+            UnaryOperator::Negate => "-",
+            UnaryOperator::Not => "!",
+            UnaryOperator::BitNot => "~"
+        }
+    }
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
 pub enum Precedence {
     None,
     Assign,
     Or,
     And,
     BitOr,
+    BitXor,
     BitAnd,
     Equality,
     Comparison,
+    // |>
+    Pipeline,
+    Shift,
     Sum,
     Product,
+    // as
+    Cast,
     Unary,
+    // Tighter than unary operators, so `-2 ** 2` is `-(2 ** 2)`
+    Power,
     Call
 }
+
+impl Precedence {
+    // The precedence one level tighter than this one, which
+    // is what the right hand side of a left associative
+    // operator is parsed with
+    pub fn next(&self) -> Precedence {
+        match self {
+            Precedence::None => Precedence::Assign,
+            Precedence::Assign => Precedence::Or,
+            Precedence::Or => Precedence::And,
+            Precedence::And => Precedence::BitOr,
+            Precedence::BitOr => Precedence::BitXor,
+            Precedence::BitXor => Precedence::BitAnd,
+            Precedence::BitAnd => Precedence::Equality,
+            Precedence::Equality => Precedence::Comparison,
+            Precedence::Comparison => Precedence::Pipeline,
+            Precedence::Pipeline => Precedence::Shift,
+            Precedence::Shift => Precedence::Sum,
+            Precedence::Sum => Precedence::Product,
+            Precedence::Product => Precedence::Cast,
+            Precedence::Cast => Precedence::Unary,
+            Precedence::Unary => Precedence::Power,
+            Precedence::Power | Precedence::Call => Precedence::Call
+        }
+    }
+}
+
+// Goes through an AST without changing it. Every method carries on into
+// the children of its node by default, through the `walk_*` function of
+// the same name, so a pass only overrides the nodes it cares about and
+// calls `walk_*` itself if it still wants to go further in.
+pub mod visit {
+    use super::*;
+
+    pub trait Visitor<'ast> {
+        fn visit_ast(&mut self, ast: &'ast Ast) {
+            walk_ast(self, ast);
+        }
+
+        fn visit_item(&mut self, item: &'ast TopLevelItem) {
+            walk_item(self, item);
+        }
+
+        fn visit_function(&mut self, function: &'ast Function) {
+            walk_function(self, function);
+        }
+
+        fn visit_global(&mut self, global: &'ast Global) {
+            walk_global(self, global);
+        }
+
+        fn visit_extern_block(&mut self, block: &'ast ExternBlock) {
+            walk_extern_block(self, block);
+        }
+
+        fn visit_param(&mut self, param: &'ast Param) {
+            walk_param(self, param);
+        }
+
+        fn visit_block(&mut self, block: &'ast Block) {
+            walk_block(self, block);
+        }
+
+        fn visit_statement(&mut self, statement: &'ast Statement) {
+            walk_statement(self, statement);
+        }
+
+        fn visit_expression(&mut self, expression: &'ast Expression) {
+            walk_expression(self, expression);
+        }
+
+        fn visit_argument(&mut self, argument: &'ast Argument) {
+            walk_argument(self, argument);
+        }
+
+        fn visit_pattern(&mut self, pattern: &'ast Pattern) {
+            walk_pattern(self, pattern);
+        }
+
+        fn visit_type(&mut self, r#type: &'ast Type) {
+            walk_type(self, r#type);
+        }
+
+        fn visit_path(&mut self, _path: &'ast Path) {}
+
+        fn visit_literal(&mut self, _literal: &'ast LiteralType) {}
+    }
+
+    pub fn walk_ast<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, ast: &'ast Ast) {
+        for item in &ast.items {
+            visitor.visit_item(item);
+        }
+    }
+
+    pub fn walk_item<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, item: &'ast TopLevelItem) {
+        match item {
+            TopLevelItem::Function(function) => visitor.visit_function(function),
+            TopLevelItem::Global(global) => visitor.visit_global(global),
+            TopLevelItem::Extern(block) => visitor.visit_extern_block(block),
+            TopLevelItem::Module(_) => {},
+            TopLevelItem::Use(declaration) => visitor.visit_path(&declaration.path),
+            TopLevelItem::StaticAssert(assert) => visitor.visit_expression(&assert.condition)
+        }
+    }
+
+    pub fn walk_function<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, function: &'ast Function) {
+        for param in function.parameters.iter().flatten() {
+            visitor.visit_param(param);
+        }
+
+        if let Some((r#type, _)) = &function.return_type {
+            visitor.visit_type(r#type);
+        }
+
+        if let Some(block) = &function.block {
+            visitor.visit_block(block);
+        }
+    }
+
+    pub fn walk_global<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, global: &'ast Global) {
+        visitor.visit_type(&global.r#type);
+        visitor.visit_expression(&global.value);
+    }
+
+    pub fn walk_extern_block<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, block: &'ast ExternBlock) {
+        for function in &block.functions {
+            visitor.visit_function(function);
+        }
+    }
+
+    pub fn walk_param<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, param: &'ast Param) {
+        visitor.visit_type(&param.r#type);
+        visitor.visit_pattern(&param.pattern);
+
+        if let Some(default) = &param.default {
+            visitor.visit_expression(default);
+        }
+    }
+
+    pub fn walk_block<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, block: &'ast Block) {
+        for statement in &block.statements {
+            visitor.visit_statement(statement);
+        }
+
+        if let Some(tail) = &block.tail {
+            visitor.visit_expression(tail);
+        }
+    }
+
+    pub fn walk_statement<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, statement: &'ast Statement) {
+        match statement {
+            Statement::Expression(expression) => visitor.visit_expression(expression),
+            Statement::Let { pattern, r#type, value, .. } => {
+                visitor.visit_pattern(pattern);
+                if let Some(r#type) = r#type {
+                    visitor.visit_type(r#type);
+                }
+                visitor.visit_expression(value);
+            },
+            Statement::Defer { body: Deferred::Expression(expression), .. } => visitor.visit_expression(expression),
+            Statement::Defer { body: Deferred::Block(block), .. } => visitor.visit_block(block)
+        }
+    }
+
+    pub fn walk_expression<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, expression: &'ast Expression) {
+        match &expression.kind {
+            ExpressionKind::Literal(literal) => visitor.visit_literal(literal),
+            ExpressionKind::Ident(_) | ExpressionKind::Break | ExpressionKind::Return(None) => {},
+            ExpressionKind::Path(path) => visitor.visit_path(path),
+            ExpressionKind::FunctionCall { callee, parameters } => {
+                visitor.visit_expression(callee);
+                for argument in parameters.iter().flatten() {
+                    visitor.visit_argument(argument);
+                }
+            },
+            ExpressionKind::Closure { parameters, return_type, block, .. } => {
+                for param in parameters.iter().flatten() {
+                    visitor.visit_param(param);
+                }
+                if let Some((r#type, _)) = return_type {
+                    visitor.visit_type(r#type);
+                }
+                visitor.visit_block(block);
+            },
+            ExpressionKind::MethodCall { path, parameters, .. } => {
+                visitor.visit_expression(path);
+                for argument in parameters.iter().flatten() {
+                    visitor.visit_argument(argument);
+                }
+            },
+            ExpressionKind::Cast { value, r#type } => {
+                visitor.visit_expression(value);
+                visitor.visit_type(r#type);
+            },
+            ExpressionKind::Binary { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            },
+            ExpressionKind::Unary { right, .. } => visitor.visit_expression(right),
+            ExpressionKind::IfExpression { condition, block, else_branch } => {
+                visitor.visit_expression(condition);
+                visitor.visit_block(block);
+
+                match else_branch {
+                    Some(ElseBranch::Block(block)) => visitor.visit_block(block),
+                    Some(ElseBranch::If(expression)) => visitor.visit_expression(expression),
+                    None => {}
+                }
+            },
+            ExpressionKind::Loop(block) => visitor.visit_block(block),
+            ExpressionKind::Return(Some(value)) => visitor.visit_expression(value),
+            ExpressionKind::Array(elements) | ExpressionKind::Tuple(elements) => {
+                for element in elements {
+                    visitor.visit_expression(element);
+                }
+            },
+            ExpressionKind::ArrayRepeat { value, .. } => visitor.visit_expression(value),
+            ExpressionKind::Index { target, index } => {
+                visitor.visit_expression(target);
+                visitor.visit_expression(index);
+            },
+            ExpressionKind::TupleIndex { target, .. } => visitor.visit_expression(target),
+            ExpressionKind::Assign { target, value, .. } => {
+                visitor.visit_expression(target);
+                visitor.visit_expression(value);
+            }
+        }
+    }
+
+    pub fn walk_argument<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, argument: &'ast Argument) {
+        visitor.visit_expression(&argument.value);
+    }
+
+    pub fn walk_pattern<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, pattern: &'ast Pattern) {
+        if let Pattern::Tuple(patterns) = pattern {
+            for pattern in patterns {
+                visitor.visit_pattern(pattern);
+            }
+        }
+    }
+
+    pub fn walk_type<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, r#type: &'ast Type) {
+        match r#type {
+            Type::Array(element, _) | Type::Slice(element) => visitor.visit_type(element),
+            Type::Tuple(types) => {
+                for r#type in types {
+                    visitor.visit_type(r#type);
+                }
+            },
+            Type::Function { parameters, return_type, .. } => {
+                for r#type in parameters {
+                    visitor.visit_type(r#type);
+                }
+                if let Some(return_type) = return_type {
+                    visitor.visit_type(&return_type.0);
+                }
+            },
+            Type::Int(_) | Type::Float(_) | Type::Bool | Type::Char | Type::Unit | Type::Never => {}
+        }
+    }
+}
+
+// Like `visit`, but able to change the AST in place
+pub mod visit_mut {
+    use super::*;
+
+    pub trait VisitorMut {
+        fn visit_ast(&mut self, ast: &mut Ast) {
+            walk_ast(self, ast);
+        }
+
+        fn visit_item(&mut self, item: &mut TopLevelItem) {
+            walk_item(self, item);
+        }
+
+        fn visit_function(&mut self, function: &mut Function) {
+            walk_function(self, function);
+        }
+
+        fn visit_global(&mut self, global: &mut Global) {
+            walk_global(self, global);
+        }
+
+        fn visit_extern_block(&mut self, block: &mut ExternBlock) {
+            walk_extern_block(self, block);
+        }
+
+        fn visit_param(&mut self, param: &mut Param) {
+            walk_param(self, param);
+        }
+
+        fn visit_block(&mut self, block: &mut Block) {
+            walk_block(self, block);
+        }
+
+        fn visit_statement(&mut self, statement: &mut Statement) {
+            walk_statement(self, statement);
+        }
+
+        fn visit_expression(&mut self, expression: &mut Expression) {
+            walk_expression(self, expression);
+        }
+
+        fn visit_argument(&mut self, argument: &mut Argument) {
+            walk_argument(self, argument);
+        }
+
+        fn visit_pattern(&mut self, pattern: &mut Pattern) {
+            walk_pattern(self, pattern);
+        }
+
+        fn visit_type(&mut self, r#type: &mut Type) {
+            walk_type(self, r#type);
+        }
+
+        fn visit_path(&mut self, _path: &mut Path) {}
+
+        fn visit_literal(&mut self, _literal: &mut LiteralType) {}
+    }
+
+    pub fn walk_ast<V: VisitorMut + ?Sized>(visitor: &mut V, ast: &mut Ast) {
+        for item in &mut ast.items {
+            visitor.visit_item(item);
+        }
+    }
+
+    pub fn walk_item<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut TopLevelItem) {
+        match item {
+            TopLevelItem::Function(function) => visitor.visit_function(function),
+            TopLevelItem::Global(global) => visitor.visit_global(global),
+            TopLevelItem::Extern(block) => visitor.visit_extern_block(block),
+            TopLevelItem::Module(_) => {},
+            TopLevelItem::Use(declaration) => visitor.visit_path(&mut declaration.path),
+            TopLevelItem::StaticAssert(assert) => visitor.visit_expression(&mut assert.condition)
+        }
+    }
+
+    pub fn walk_function<V: VisitorMut + ?Sized>(visitor: &mut V, function: &mut Function) {
+        for param in function.parameters.iter_mut().flatten() {
+            visitor.visit_param(param);
+        }
+
+        if let Some((r#type, _)) = &mut function.return_type {
+            visitor.visit_type(r#type);
+        }
+
+        if let Some(block) = &mut function.block {
+            visitor.visit_block(block);
+        }
+    }
+
+    pub fn walk_global<V: VisitorMut + ?Sized>(visitor: &mut V, global: &mut Global) {
+        visitor.visit_type(&mut global.r#type);
+        visitor.visit_expression(&mut global.value);
+    }
+
+    pub fn walk_extern_block<V: VisitorMut + ?Sized>(visitor: &mut V, block: &mut ExternBlock) {
+        for function in &mut block.functions {
+            visitor.visit_function(function);
+        }
+    }
+
+    pub fn walk_param<V: VisitorMut + ?Sized>(visitor: &mut V, param: &mut Param) {
+        visitor.visit_type(&mut param.r#type);
+        visitor.visit_pattern(&mut param.pattern);
+
+        if let Some(default) = &mut param.default {
+            visitor.visit_expression(default);
+        }
+    }
+
+    pub fn walk_block<V: VisitorMut + ?Sized>(visitor: &mut V, block: &mut Block) {
+        for statement in &mut block.statements {
+            visitor.visit_statement(statement);
+        }
+
+        if let Some(tail) = &mut block.tail {
+            visitor.visit_expression(tail);
+        }
+    }
+
+    pub fn walk_statement<V: VisitorMut + ?Sized>(visitor: &mut V, statement: &mut Statement) {
+        match statement {
+            Statement::Expression(expression) => visitor.visit_expression(expression),
+            Statement::Let { pattern, r#type, value, .. } => {
+                visitor.visit_pattern(pattern);
+                if let Some(r#type) = r#type {
+                    visitor.visit_type(r#type);
+                }
+                visitor.visit_expression(value);
+            },
+            Statement::Defer { body: Deferred::Expression(expression), .. } => visitor.visit_expression(expression),
+            Statement::Defer { body: Deferred::Block(block), .. } => visitor.visit_block(block)
+        }
+    }
+
+    pub fn walk_expression<V: VisitorMut + ?Sized>(visitor: &mut V, expression: &mut Expression) {
+        match &mut expression.kind {
+            ExpressionKind::Literal(literal) => visitor.visit_literal(literal),
+            ExpressionKind::Ident(_) | ExpressionKind::Break | ExpressionKind::Return(None) => {},
+            ExpressionKind::Path(path) => visitor.visit_path(path),
+            ExpressionKind::FunctionCall { callee, parameters } => {
+                visitor.visit_expression(callee);
+                for argument in parameters.iter_mut().flatten() {
+                    visitor.visit_argument(argument);
+                }
+            },
+            ExpressionKind::Closure { parameters, return_type, block, .. } => {
+                for param in parameters.iter_mut().flatten() {
+                    visitor.visit_param(param);
+                }
+                if let Some((r#type, _)) = return_type {
+                    visitor.visit_type(r#type);
+                }
+                visitor.visit_block(block);
+            },
+            ExpressionKind::MethodCall { path, parameters, .. } => {
+                visitor.visit_expression(path);
+                for argument in parameters.iter_mut().flatten() {
+                    visitor.visit_argument(argument);
+                }
+            },
+            ExpressionKind::Cast { value, r#type } => {
+                visitor.visit_expression(value);
+                visitor.visit_type(r#type);
+            },
+            ExpressionKind::Binary { left, right, .. } => {
+                visitor.visit_expression(left);
+                visitor.visit_expression(right);
+            },
+            ExpressionKind::Unary { right, .. } => visitor.visit_expression(right),
+            ExpressionKind::IfExpression { condition, block, else_branch } => {
+                visitor.visit_expression(condition);
+                visitor.visit_block(block);
+
+                match else_branch {
+                    Some(ElseBranch::Block(block)) => visitor.visit_block(block),
+                    Some(ElseBranch::If(expression)) => visitor.visit_expression(expression),
+                    None => {}
+                }
+            },
+            ExpressionKind::Loop(block) => visitor.visit_block(block),
+            ExpressionKind::Return(Some(value)) => visitor.visit_expression(value),
+            ExpressionKind::Array(elements) | ExpressionKind::Tuple(elements) => {
+                for element in elements {
+                    visitor.visit_expression(element);
+                }
+            },
+            ExpressionKind::ArrayRepeat { value, .. } => visitor.visit_expression(value),
+            ExpressionKind::Index { target, index } => {
+                visitor.visit_expression(target);
+                visitor.visit_expression(index);
+            },
+            ExpressionKind::TupleIndex { target, .. } => visitor.visit_expression(target),
+            ExpressionKind::Assign { target, value, .. } => {
+                visitor.visit_expression(target);
+                visitor.visit_expression(value);
+            }
+        }
+    }
+
+    pub fn walk_argument<V: VisitorMut + ?Sized>(visitor: &mut V, argument: &mut Argument) {
+        visitor.visit_expression(&mut argument.value);
+    }
+
+    pub fn walk_pattern<V: VisitorMut + ?Sized>(visitor: &mut V, pattern: &mut Pattern) {
+        if let Pattern::Tuple(patterns) = pattern {
+            for pattern in patterns {
+                visitor.visit_pattern(pattern);
+            }
+        }
+    }
+
+    pub fn walk_type<V: VisitorMut + ?Sized>(visitor: &mut V, r#type: &mut Type) {
+        match r#type {
+            Type::Array(element, _) | Type::Slice(element) => visitor.visit_type(element),
+            Type::Tuple(types) => {
+                for r#type in types {
+                    visitor.visit_type(r#type);
+                }
+            },
+            Type::Function { parameters, return_type, .. } => {
+                for r#type in parameters {
+                    visitor.visit_type(r#type);
+                }
+                if let Some(return_type) = return_type {
+                    visitor.visit_type(&mut return_type.0);
+                }
+            },
+            Type::Int(_) | Type::Float(_) | Type::Bool | Type::Char | Type::Unit | Type::Never => {}
+        }
+    }
+}
+
+// Turns an AST into a new one, taking ownership of each node and giving
+// back what replaces it, e.g. for desugaring one kind of expression into
+// others. The `walk_*` functions rebuild the node from its folded children.
+pub mod fold {
+    use super::*;
+
+    pub trait Folder {
+        fn fold_ast(&mut self, ast: Ast) -> Ast {
+            walk_ast(self, ast)
+        }
+
+        fn fold_item(&mut self, item: TopLevelItem) -> TopLevelItem {
+            walk_item(self, item)
+        }
+
+        fn fold_function(&mut self, function: Function) -> Function {
+            walk_function(self, function)
+        }
+
+        fn fold_global(&mut self, global: Global) -> Global {
+            walk_global(self, global)
+        }
+
+        fn fold_extern_block(&mut self, block: ExternBlock) -> ExternBlock {
+            walk_extern_block(self, block)
+        }
+
+        fn fold_param(&mut self, param: Param) -> Param {
+            walk_param(self, param)
+        }
+
+        fn fold_block(&mut self, block: Block) -> Block {
+            walk_block(self, block)
+        }
+
+        fn fold_statement(&mut self, statement: Statement) -> Statement {
+            walk_statement(self, statement)
+        }
+
+        fn fold_expression(&mut self, expression: Expression) -> Expression {
+            walk_expression(self, expression)
+        }
+
+        fn fold_argument(&mut self, argument: Argument) -> Argument {
+            walk_argument(self, argument)
+        }
+
+        fn fold_pattern(&mut self, pattern: Pattern) -> Pattern {
+            walk_pattern(self, pattern)
+        }
+
+        fn fold_type(&mut self, r#type: Type) -> Type {
+            walk_type(self, r#type)
+        }
+
+        fn fold_path(&mut self, path: Path) -> Path {
+            path
+        }
+
+        fn fold_literal(&mut self, literal: LiteralType) -> LiteralType {
+            literal
+        }
+    }
+
+    fn fold_boxed<F: Folder + ?Sized>(folder: &mut F, expression: Expression) -> Box<Expression> {
+        Box::new(folder.fold_expression(expression))
+    }
+
+    fn fold_parameters<F: Folder + ?Sized>(folder: &mut F, parameters: Option<FunctionParameters>) -> Option<FunctionParameters> {
+        parameters.map(|parameters| parameters.into_iter().map(|param| folder.fold_param(param)).collect())
+    }
+
+    fn fold_arguments<F: Folder + ?Sized>(folder: &mut F, arguments: Option<CallParameters>) -> Option<CallParameters> {
+        arguments.map(|arguments| arguments.into_iter().map(|argument| folder.fold_argument(argument)).collect())
+    }
+
+    fn fold_return_type<F: Folder + ?Sized>(folder: &mut F, (r#type, fallible): ReturnType) -> ReturnType {
+        (folder.fold_type(r#type), fallible)
+    }
+
+    pub fn walk_ast<F: Folder + ?Sized>(folder: &mut F, ast: Ast) -> Ast {
+        Ast {
+            docs: ast.docs,
+            items: ast.items.into_iter().map(|item| folder.fold_item(item)).collect()
+        }
+    }
+
+    pub fn walk_item<F: Folder + ?Sized>(folder: &mut F, item: TopLevelItem) -> TopLevelItem {
+        match item {
+            TopLevelItem::Function(function) => TopLevelItem::Function(folder.fold_function(function)),
+            TopLevelItem::Global(global) => TopLevelItem::Global(folder.fold_global(global)),
+            TopLevelItem::Extern(block) => TopLevelItem::Extern(folder.fold_extern_block(block)),
+            TopLevelItem::Module(declaration) => TopLevelItem::Module(declaration),
+            TopLevelItem::Use(UseDeclaration { public, path, span }) => {
+                TopLevelItem::Use(UseDeclaration { public, path: folder.fold_path(path), span })
+            },
+            TopLevelItem::StaticAssert(StaticAssert { condition, span }) => {
+                TopLevelItem::StaticAssert(StaticAssert { condition: folder.fold_expression(condition), span })
+            }
+        }
+    }
+
+    pub fn walk_function<F: Folder + ?Sized>(folder: &mut F, function: Function) -> Function {
+        Function {
+            parameters: fold_parameters(folder, function.parameters),
+            return_type: function.return_type.map(|return_type| fold_return_type(folder, return_type)),
+            block: function.block.map(|block| folder.fold_block(block)),
+            ..function
+        }
+    }
+
+    pub fn walk_global<F: Folder + ?Sized>(folder: &mut F, global: Global) -> Global {
+        Global {
+            r#type: folder.fold_type(global.r#type),
+            value: folder.fold_expression(global.value),
+            ..global
+        }
+    }
+
+    pub fn walk_extern_block<F: Folder + ?Sized>(folder: &mut F, block: ExternBlock) -> ExternBlock {
+        ExternBlock {
+            functions: block.functions.into_iter().map(|function| folder.fold_function(function)).collect(),
+            ..block
+        }
+    }
+
+    pub fn walk_param<F: Folder + ?Sized>(folder: &mut F, param: Param) -> Param {
+        Param {
+            r#type: folder.fold_type(param.r#type),
+            pattern: folder.fold_pattern(param.pattern),
+            default: param.default.map(|default| folder.fold_expression(default)),
+            span: param.span
+        }
+    }
+
+    pub fn walk_block<F: Folder + ?Sized>(folder: &mut F, block: Block) -> Block {
+        Block {
+            statements: block.statements.into_iter().map(|statement| folder.fold_statement(statement)).collect(),
+            tail: block.tail.map(|tail| fold_boxed(folder, *tail))
+        }
+    }
+
+    pub fn walk_statement<F: Folder + ?Sized>(folder: &mut F, statement: Statement) -> Statement {
+        match statement {
+            Statement::Expression(expression) => Statement::Expression(folder.fold_expression(expression)),
+            Statement::Let { pattern, r#type, value, span } => Statement::Let {
+                pattern: folder.fold_pattern(pattern),
+                r#type: r#type.map(|r#type| folder.fold_type(r#type)),
+                value: folder.fold_expression(value),
+                span
+            },
+            Statement::Defer { body, span } => Statement::Defer {
+                body: match body {
+                    Deferred::Expression(expression) => Deferred::Expression(folder.fold_expression(expression)),
+                    Deferred::Block(block) => Deferred::Block(folder.fold_block(block))
+                },
+                span
+            }
+        }
+    }
+
+    pub fn walk_expression<F: Folder + ?Sized>(folder: &mut F, expression: Expression) -> Expression {
+        let kind = match expression.kind {
+            ExpressionKind::Literal(literal) => ExpressionKind::Literal(folder.fold_literal(literal)),
+            kind @ (ExpressionKind::Ident(_) | ExpressionKind::Break | ExpressionKind::Return(None)) => kind,
+            ExpressionKind::Path(path) => ExpressionKind::Path(folder.fold_path(path)),
+            ExpressionKind::FunctionCall { callee, parameters } => ExpressionKind::FunctionCall {
+                callee: fold_boxed(folder, *callee),
+                parameters: fold_arguments(folder, parameters)
+            },
+            ExpressionKind::Closure { impure, parameters, return_type, block } => ExpressionKind::Closure {
+                impure,
+                parameters: fold_parameters(folder, parameters),
+                return_type: return_type.map(|return_type| fold_return_type(folder, return_type)),
+                block: folder.fold_block(block)
+            },
+            ExpressionKind::MethodCall { path, name, parameters } => ExpressionKind::MethodCall {
+                path: fold_boxed(folder, *path),
+                name,
+                parameters: fold_arguments(folder, parameters)
+            },
+            ExpressionKind::Cast { value, r#type } => ExpressionKind::Cast {
+                value: fold_boxed(folder, *value),
+                r#type: folder.fold_type(r#type)
+            },
+            ExpressionKind::Binary { left, operator, right } => ExpressionKind::Binary {
+                left: fold_boxed(folder, *left),
+                operator,
+                right: fold_boxed(folder, *right)
+            },
+            ExpressionKind::Unary { operator, right } => ExpressionKind::Unary {
+                operator,
+                right: fold_boxed(folder, *right)
+            },
+            ExpressionKind::IfExpression { condition, block, else_branch } => ExpressionKind::IfExpression {
+                condition: fold_boxed(folder, *condition),
+                block: folder.fold_block(block),
+                else_branch: else_branch.map(|else_branch| match else_branch {
+                    ElseBranch::Block(block) => ElseBranch::Block(folder.fold_block(block)),
+                    ElseBranch::If(expression) => ElseBranch::If(fold_boxed(folder, *expression))
+                })
+            },
+            ExpressionKind::Loop(block) => ExpressionKind::Loop(folder.fold_block(block)),
+            ExpressionKind::Return(Some(value)) => ExpressionKind::Return(Some(fold_boxed(folder, *value))),
+            ExpressionKind::Array(elements) => {
+                ExpressionKind::Array(elements.into_iter().map(|element| folder.fold_expression(element)).collect())
+            },
+            ExpressionKind::ArrayRepeat { value, count } => ExpressionKind::ArrayRepeat {
+                value: fold_boxed(folder, *value),
+                count
+            },
+            ExpressionKind::Index { target, index } => ExpressionKind::Index {
+                target: fold_boxed(folder, *target),
+                index: fold_boxed(folder, *index)
+            },
+            ExpressionKind::Tuple(elements) => {
+                ExpressionKind::Tuple(elements.into_iter().map(|element| folder.fold_expression(element)).collect())
+            },
+            ExpressionKind::TupleIndex { target, index } => ExpressionKind::TupleIndex {
+                target: fold_boxed(folder, *target),
+                index
+            },
+            ExpressionKind::Assign { target, operator, value } => ExpressionKind::Assign {
+                target: fold_boxed(folder, *target),
+                operator,
+                value: fold_boxed(folder, *value)
+            }
+        };
+
+        Expression { kind, span: expression.span }
+    }
+
+    pub fn walk_argument<F: Folder + ?Sized>(folder: &mut F, argument: Argument) -> Argument {
+        Argument {
+            value: folder.fold_expression(argument.value),
+            ..argument
+        }
+    }
+
+    pub fn walk_pattern<F: Folder + ?Sized>(folder: &mut F, pattern: Pattern) -> Pattern {
+        match pattern {
+            Pattern::Tuple(patterns) => Pattern::Tuple(patterns.into_iter().map(|pattern| folder.fold_pattern(pattern)).collect()),
+            pattern => pattern
+        }
+    }
+
+    pub fn walk_type<F: Folder + ?Sized>(folder: &mut F, r#type: Type) -> Type {
+        match r#type {
+            Type::Array(element, length) => Type::Array(Box::new(folder.fold_type(*element)), length),
+            Type::Slice(element) => Type::Slice(Box::new(folder.fold_type(*element))),
+            Type::Tuple(types) => Type::Tuple(types.into_iter().map(|r#type| folder.fold_type(r#type)).collect()),
+            Type::Function { impure, parameters, return_type } => Type::Function {
+                impure,
+                parameters: parameters.into_iter().map(|r#type| folder.fold_type(r#type)).collect(),
+                return_type: return_type.map(|return_type| Box::new(fold_return_type(folder, *return_type)))
+            },
+            r#type => r#type
+        }
+    }
+}
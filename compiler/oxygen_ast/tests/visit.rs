@@ -0,0 +1,281 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use std::path::Path as FsPath;
+
+use oxygen_ast::fold::{ self, Folder };
+use oxygen_ast::visit::{ self, Visitor };
+use oxygen_ast::visit_mut::{ self, VisitorMut };
+use oxygen_ast::*;
+use oxygen_parser::parse;
+use oxygen_span::add_file;
+use oxygen_unparse::unparse;
+
+fn parse_src(src: &str) -> Ast {
+    parse(add_file(FsPath::new("<visit>"), src)).unwrap_or_else(|_| panic!("couldn't parse:\n{src}"))
+}
+
+// Uses every kind of item, statement, expression, pattern and type
+const EVERYTHING: &str = "
+mod maths;
+use maths::square;
+const LIMIT: i32 = 8;
+static_assert(LIMIT == 8);
+extern \"C\" { func abs(i32 x) ~ i32; }
+
+func stop() ~ never { loop {} }
+
+impure func f(i32 x, (i32, [u8; 2]) (a, _) = (1, [0; 2]), func(i32) ~ bool g) ~ i32! {
+  let y: [i32] = [x, 2];
+  let (c, s, b, fl, u): (char, f64, bool, f64, ()) = ('c', 1.5, true, 2.5f64, ());
+  defer print(x);
+  defer { print(false); }
+  let h = func(i32 n) ~ i32 { n };
+  if x > 0 { x += 1; } else if x < 0 { return -x; } else { !b; }
+  loop { break; }
+  print(y[0], (a, c).1, x as u8, x.wrapping_add(1), maths::square(2), named: \"s\");
+  x |> h();
+  ~x;
+  u8::try_from(x);
+  x
+}
+";
+
+// The name of each kind of expression, so that the test below has to
+// be told about any new kind, and the walk checked for it
+fn expression_kind(kind: &ExpressionKind) -> &'static str {
+    match kind {
+        ExpressionKind::Literal(_) => "Literal",
+        ExpressionKind::Ident(_) => "Ident",
+        ExpressionKind::Path(_) => "Path",
+        ExpressionKind::FunctionCall { .. } => "FunctionCall",
+        ExpressionKind::Closure { .. } => "Closure",
+        ExpressionKind::MethodCall { .. } => "MethodCall",
+        ExpressionKind::Cast { .. } => "Cast",
+        ExpressionKind::Binary { .. } => "Binary",
+        ExpressionKind::Unary { .. } => "Unary",
+        ExpressionKind::IfExpression { .. } => "IfExpression",
+        ExpressionKind::Loop(_) => "Loop",
+        ExpressionKind::Break => "Break",
+        ExpressionKind::Return(_) => "Return",
+        ExpressionKind::Array(_) => "Array",
+        ExpressionKind::ArrayRepeat { .. } => "ArrayRepeat",
+        ExpressionKind::Index { .. } => "Index",
+        ExpressionKind::Tuple(_) => "Tuple",
+        ExpressionKind::TupleIndex { .. } => "TupleIndex",
+        ExpressionKind::Assign { .. } => "Assign"
+    }
+}
+
+fn type_kind(r#type: &Type) -> &'static str {
+    match r#type {
+        Type::Int(_) => "Int",
+        Type::Float(_) => "Float",
+        Type::Bool => "Bool",
+        Type::Char => "Char",
+        Type::Unit => "Unit",
+        Type::Never => "Never",
+        Type::Array(..) => "Array",
+        Type::Slice(_) => "Slice",
+        Type::Tuple(_) => "Tuple",
+        Type::Function { .. } => "Function"
+    }
+}
+
+#[derive(Default)]
+struct Counter {
+    nodes: BTreeMap<&'static str, usize>,
+    expressions: BTreeSet<&'static str>,
+    types: BTreeSet<&'static str>
+}
+
+impl Counter {
+    fn count(&mut self, node: &'static str) {
+        *self.nodes.entry(node).or_default() += 1;
+    }
+}
+
+impl<'ast> Visitor<'ast> for Counter {
+    fn visit_item(&mut self, item: &'ast TopLevelItem) {
+        self.count("item");
+        visit::walk_item(self, item);
+    }
+
+    fn visit_function(&mut self, function: &'ast Function) {
+        self.count("function");
+        visit::walk_function(self, function);
+    }
+
+    fn visit_global(&mut self, global: &'ast Global) {
+        self.count("global");
+        visit::walk_global(self, global);
+    }
+
+    fn visit_extern_block(&mut self, block: &'ast ExternBlock) {
+        self.count("extern block");
+        visit::walk_extern_block(self, block);
+    }
+
+    fn visit_param(&mut self, param: &'ast Param) {
+        self.count("param");
+        visit::walk_param(self, param);
+    }
+
+    fn visit_block(&mut self, block: &'ast Block) {
+        self.count("block");
+        visit::walk_block(self, block);
+    }
+
+    fn visit_statement(&mut self, statement: &'ast Statement) {
+        self.count("statement");
+        visit::walk_statement(self, statement);
+    }
+
+    fn visit_expression(&mut self, expression: &'ast Expression) {
+        self.count("expression");
+        self.expressions.insert(expression_kind(&expression.kind));
+        visit::walk_expression(self, expression);
+    }
+
+    fn visit_argument(&mut self, argument: &'ast Argument) {
+        self.count("argument");
+        visit::walk_argument(self, argument);
+    }
+
+    fn visit_pattern(&mut self, pattern: &'ast Pattern) {
+        self.count("pattern");
+        visit::walk_pattern(self, pattern);
+    }
+
+    fn visit_type(&mut self, r#type: &'ast Type) {
+        self.count("type");
+        self.types.insert(type_kind(r#type));
+        visit::walk_type(self, r#type);
+    }
+
+    fn visit_path(&mut self, _path: &'ast Path) {
+        self.count("path");
+    }
+
+    fn visit_literal(&mut self, _literal: &'ast LiteralType) {
+        self.count("literal");
+    }
+}
+
+#[test]
+fn visitor_counts_each_node_once() {
+    let ast = parse_src("func f(i32 x) ~ i32 { let y = x + 1; print(y); y }");
+    let mut counter = Counter::default();
+    counter.visit_ast(&ast);
+
+    let counts: Vec<_> = counter.nodes.into_iter().collect();
+    assert_eq!(counts, [
+        ("argument", 1),
+        ("block", 1),
+        ("expression", 7),
+        ("function", 1),
+        ("item", 1),
+        ("literal", 1),
+        ("param", 1),
+        ("pattern", 2),
+        ("statement", 2),
+        ("type", 2)
+    ]);
+}
+
+#[test]
+fn visitor_reaches_every_kind() {
+    let ast = parse_src(EVERYTHING);
+    let mut counter = Counter::default();
+    counter.visit_ast(&ast);
+
+    let every_expression = [
+        "Array", "ArrayRepeat", "Assign", "Binary", "Break", "Cast", "Closure", "FunctionCall",
+        "Ident", "IfExpression", "Index", "Literal", "Loop", "MethodCall", "Path", "Return",
+        "Tuple", "TupleIndex", "Unary"
+    ];
+    assert_eq!(counter.expressions.into_iter().collect::<Vec<_>>(), every_expression);
+
+    let every_type = ["Array", "Bool", "Char", "Float", "Function", "Int", "Never", "Slice", "Tuple", "Unit"];
+    assert_eq!(counter.types.into_iter().collect::<Vec<_>>(), every_type);
+}
+
+// Renames the variable `x` to `renamed`, wherever it's bound or used
+struct Rename;
+
+fn rename(name: &mut String) {
+    if name == "x" {
+        *name = "renamed".to_string();
+    }
+}
+
+impl VisitorMut for Rename {
+    fn visit_pattern(&mut self, pattern: &mut Pattern) {
+        if let Pattern::Ident(name) = pattern {
+            rename(name);
+        }
+        visit_mut::walk_pattern(self, pattern);
+    }
+
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        if let ExpressionKind::Ident(name) = &mut expression.kind {
+            rename(name);
+        }
+        visit_mut::walk_expression(self, expression);
+    }
+}
+
+impl Folder for Rename {
+    fn fold_pattern(&mut self, pattern: Pattern) -> Pattern {
+        match pattern {
+            Pattern::Ident(mut name) => {
+                rename(&mut name);
+                Pattern::Ident(name)
+            },
+            pattern => fold::walk_pattern(self, pattern)
+        }
+    }
+
+    fn fold_expression(&mut self, expression: Expression) -> Expression {
+        match expression.kind {
+            ExpressionKind::Ident(mut name) => {
+                rename(&mut name);
+                Expression { kind: ExpressionKind::Ident(name), ..expression }
+            },
+            _ => fold::walk_expression(self, expression)
+        }
+    }
+}
+
+const BEFORE: &str = "
+func f(i32 x, i32 y = LIMIT) ~ i32 {
+  let double = func(i32 x) ~ i32 { x * 2 };
+  let (a, _) = (x + 1, [x; 2]);
+  defer { print(x); }
+  if x > 0 { x = double(a) * x; }
+  x.wrapping_add(y)
+}
+";
+
+const AFTER: &str = "
+func f(i32 renamed, i32 y = LIMIT) ~ i32 {
+  let double = func(i32 renamed) ~ i32 { renamed * 2 };
+  let (a, _) = (renamed + 1, [renamed; 2]);
+  defer { print(renamed); }
+  if renamed > 0 { renamed = double(a) * renamed; }
+  renamed.wrapping_add(y)
+}
+";
+
+#[test]
+fn visitor_mut_renames_in_place() {
+    let mut ast = parse_src(BEFORE);
+    Rename.visit_ast(&mut ast);
+
+    assert_eq!(unparse(&ast), unparse(&parse_src(AFTER)));
+}
+
+#[test]
+fn folder_renames_into_a_new_ast() {
+    let ast = Rename.fold_ast(parse_src(BEFORE));
+
+    assert_eq!(unparse(&ast), unparse(&parse_src(AFTER)));
+}
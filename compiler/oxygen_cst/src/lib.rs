@@ -0,0 +1,760 @@
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+
+use oxygen_error::{ capture_diagnostics, Diagnostic, Severity };
+use oxygen_lexer::{ tokenize_losslessly, Token, TokenKind };
+use oxygen_parser::{ parse_events, Event, Node };
+use oxygen_span::{ add_file, file_src, FileId };
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SyntaxKind {
+    // Tokens
+    Whitespace,
+    Comment,
+    Identifier,
+    Keyword,
+    Literal,
+    Punctuation,
+    Unknown,
+
+    // Nodes
+    SourceFile,
+    Function,
+    ParamList,
+    Param,
+    ReturnType,
+    Block,
+    LetStatement,
+    DeferStatement,
+    ExpressionStatement,
+    Expression,
+    // Anything in `(...)` or `[...]`
+    Group,
+    Global,
+    ModuleDeclaration,
+    UseDeclaration,
+    ExternBlock,
+    StaticAssert,
+    // The rest of the source after a syntax error
+    Error
+}
+
+impl SyntaxKind {
+    pub fn is_trivia(self) -> bool {
+        matches!(self, SyntaxKind::Whitespace | SyntaxKind::Comment)
+    }
+}
+
+impl From<&TokenKind> for SyntaxKind {
+    fn from(kind: &TokenKind) -> Self {
+        match kind {
+            TokenKind::Whitespace => SyntaxKind::Whitespace,
+            kind if kind.is_comment() => SyntaxKind::Comment,
+            TokenKind::Identifier => SyntaxKind::Identifier,
+            TokenKind::Keyword(_) => SyntaxKind::Keyword,
+            TokenKind::Literal { .. } => SyntaxKind::Literal,
+            TokenKind::Unknown => SyntaxKind::Unknown,
+            _ => SyntaxKind::Punctuation
+        }
+    }
+}
+
+// The green tree is immutable and knows nothing about where it is, only
+// its kind, its text and its children. Every node is built afresh, so
+// identical subtrees aren't shared, even though nothing stops them being
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct GreenToken {
+    kind: SyntaxKind,
+    text: Box<str>
+}
+
+impl GreenToken {
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct GreenNode {
+    kind: SyntaxKind,
+    // The length of the node's text, in bytes
+    width: usize,
+    children: Vec<GreenElement>
+}
+
+impl GreenNode {
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn children(&self) -> &[GreenElement] {
+        &self.children
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GreenElement {
+    Node(Rc<GreenNode>),
+    Token(Rc<GreenToken>)
+}
+
+impl GreenElement {
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            GreenElement::Node(node) => node.kind,
+            GreenElement::Token(token) => token.kind
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            GreenElement::Node(node) => node.width,
+            GreenElement::Token(token) => token.text.len()
+        }
+    }
+}
+
+impl fmt::Display for GreenNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for child in &self.children {
+            match child {
+                GreenElement::Node(node) => write!(f, "{node}")?,
+                GreenElement::Token(token) => f.write_str(&token.text)?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct GreenNodeBuilder {
+    // The kind of each node that's been started but not
+    // finished, and where its children start in `children`
+    parents: Vec<(SyntaxKind, usize)>,
+    children: Vec<GreenElement>
+}
+
+impl GreenNodeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start_node(&mut self, kind: SyntaxKind) {
+        self.parents.push((kind, self.children.len()));
+    }
+
+    pub fn token(&mut self, kind: SyntaxKind, text: &str) {
+        self.children.push(GreenElement::Token(Rc::new(GreenToken { kind, text: text.into() })));
+    }
+
+    pub fn finish_node(&mut self) {
+        let (kind, first_child) = self.parents.pop().expect("finish_node called without a started node");
+        let children: Vec<_> = self.children.drain(first_child..).collect();
+        let width = children.iter().map(GreenElement::width).sum();
+
+        self.children.push(GreenElement::Node(Rc::new(GreenNode { kind, width, children })));
+    }
+
+    pub fn finish(mut self) -> Rc<GreenNode> {
+        assert!(self.parents.is_empty(), "finish called with nodes still started");
+        assert_eq!(self.children.len(), 1, "finish called without exactly one root node");
+
+        match self.children.pop() {
+            Some(GreenElement::Node(node)) => node,
+            _ => panic!("the root of a tree must be a node")
+        }
+    }
+}
+
+// The red tree is made on demand over the green tree, and each node in
+// it knows its parent and where in the source its text starts
+
+struct NodeData {
+    green: Rc<GreenNode>,
+    parent: Option<SyntaxNode>,
+    // Where the node's text starts in the source, in bytes
+    offset: usize
+}
+
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<NodeData>);
+
+#[derive(Clone)]
+pub struct SyntaxToken {
+    green: Rc<GreenToken>,
+    parent: SyntaxNode,
+    offset: usize
+}
+
+#[derive(Clone)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken)
+}
+
+impl SyntaxNode {
+    pub fn new_root(green: Rc<GreenNode>) -> Self {
+        SyntaxNode(Rc::new(NodeData { green, parent: None, offset: 0 }))
+    }
+
+    pub fn green(&self) -> &Rc<GreenNode> {
+        &self.0.green
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.green.kind
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.0.parent.clone()
+    }
+
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        std::iter::successors(Some(self.clone()), SyntaxNode::parent)
+    }
+
+    // The byte range of the node's text in the source
+    pub fn text_range(&self) -> std::ops::Range<usize> {
+        self.0.offset..self.0.offset + self.0.green.width
+    }
+
+    pub fn children_with_tokens(&self) -> impl Iterator<Item = SyntaxElement> + '_ {
+        let mut offset = self.0.offset;
+
+        self.0.green.children.iter().map(move |child| {
+            let element = match child {
+                GreenElement::Node(green) => SyntaxElement::Node(SyntaxNode(Rc::new(NodeData {
+                    green: green.clone(),
+                    parent: Some(self.clone()),
+                    offset
+                }))),
+                GreenElement::Token(green) => SyntaxElement::Token(SyntaxToken {
+                    green: green.clone(),
+                    parent: self.clone(),
+                    offset
+                })
+            };
+
+            offset += child.width();
+            element
+        })
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        self.children_with_tokens().filter_map(|element| match element {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None
+        })
+    }
+
+    // The tokens directly in this node, not in its children
+    pub fn tokens(&self) -> impl Iterator<Item = SyntaxToken> + '_ {
+        self.children_with_tokens().filter_map(|element| match element {
+            SyntaxElement::Token(token) => Some(token),
+            SyntaxElement::Node(_) => None
+        })
+    }
+
+    // Every node within this one, including itself, in the order they start
+    pub fn descendants(&self) -> impl Iterator<Item = SyntaxNode> {
+        let mut stack = vec![self.clone()];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let mut children: Vec<_> = node.children().collect();
+            children.reverse();
+            stack.extend(children);
+            Some(node)
+        })
+    }
+
+    // Every token within this one, in the order they're written
+    pub fn descendant_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = Vec::new();
+
+        for element in self.children_with_tokens() {
+            match element {
+                SyntaxElement::Node(node) => tokens.extend(node.descendant_tokens()),
+                SyntaxElement::Token(token) => tokens.push(token)
+            }
+        }
+
+        tokens
+    }
+
+    // The smallest node whose text contains `offset`
+    pub fn covering_node(&self, offset: usize) -> SyntaxNode {
+        match self.children().find(|child| child.text_range().contains(&offset)) {
+            Some(child) => child.covering_node(offset),
+            None => self.clone()
+        }
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0.green, &other.0.green) && self.0.offset == other.0.offset
+    }
+}
+
+impl fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.green)
+    }
+}
+
+impl fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{:?}", self.kind(), self.text_range())
+    }
+}
+
+impl SyntaxToken {
+    pub fn kind(&self) -> SyntaxKind {
+        self.green.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.green.text
+    }
+
+    pub fn parent(&self) -> SyntaxNode {
+        self.parent.clone()
+    }
+
+    pub fn text_range(&self) -> std::ops::Range<usize> {
+        self.offset..self.offset + self.green.text.len()
+    }
+}
+
+impl fmt::Display for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text())
+    }
+}
+
+impl fmt::Debug for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{:?} {:?}", self.kind(), self.text_range(), self.text())
+    }
+}
+
+// Builds the tree from the events of the real parser, putting back the
+// whitespace and comments it skips in between the tokens it takes. The
+// parser only marks the outline of the code (items, statements and the
+// expressions in them), so anything in `(...)` or `[...]` is grouped here.
+struct Builder<'src> {
+    tokens: Vec<Token<'src>>,
+    idx: usize,
+    builder: GreenNodeBuilder,
+    // The kind of every node that's been started but not finished
+    open: Vec<SyntaxKind>,
+    // Whether a `ParamList` was just started, since its
+    // brackets are part of it rather than a group inside of it
+    param_list_started: bool
+}
+
+impl<'src> Builder<'src> {
+    fn new(src: &'src str) -> Self {
+        Builder {
+            tokens: tokenize_losslessly(src).collect(),
+            idx: 0,
+            builder: GreenNodeBuilder::new(),
+            open: Vec::new(),
+            param_list_started: false
+        }
+    }
+
+    // Adds any whitespace and comments that are next to the current node
+    fn trivia(&mut self) {
+        while let Some(token) = self.tokens.get(self.idx) {
+            let kind = SyntaxKind::from(&token.kind);
+            if !kind.is_trivia() { break };
+
+            self.builder.token(kind, token.string);
+            self.idx += 1;
+        }
+    }
+
+    // Adds the next token of code, and the trivia before it, to the
+    // current node, grouping it with the tokens up to its closing bracket
+    fn token(&mut self) {
+        self.trivia();
+        let Some(token) = self.tokens.get(self.idx) else { return };
+        let (kind, text) = (SyntaxKind::from(&token.kind), token.string);
+        let opens_group = matches!(token.kind, TokenKind::OpenParen | TokenKind::OpenBracket);
+        let closes_group = matches!(token.kind, TokenKind::CloseParen | TokenKind::CloseBracket);
+
+        if opens_group && !self.param_list_started {
+            self.start_node(SyntaxKind::Group);
+        }
+        self.param_list_started = false;
+
+        self.builder.token(kind, text);
+        self.idx += 1;
+
+        if closes_group && self.open.last() == Some(&SyntaxKind::Group) {
+            self.finish_node();
+        }
+    }
+
+    // Nodes start at their first token, so the trivia
+    // before them is left in the node around them
+    fn start_node(&mut self, kind: SyntaxKind) {
+        self.trivia();
+        self.builder.start_node(kind);
+        self.open.push(kind);
+        self.param_list_started = kind == SyntaxKind::ParamList;
+    }
+
+    fn finish_node(&mut self) {
+        self.builder.finish_node();
+        self.open.pop();
+    }
+
+    fn source_file(mut self, events: &[Event], parsed: bool) -> Rc<GreenNode> {
+        self.builder.start_node(SyntaxKind::SourceFile);
+
+        for event in events {
+            match event {
+                Event::Start(node) => self.start_node(SyntaxKind::from(*node)),
+                Event::Token => self.token(),
+                Event::Finish => {
+                    // Only a group with a missing bracket can still be open
+                    while self.open.last() == Some(&SyntaxKind::Group) {
+                        self.finish_node();
+                    }
+                    self.finish_node();
+                }
+            }
+        }
+
+        // The parser stops at the first error, leaving the nodes
+        // around it open and the tokens after it where they are
+        if !parsed {
+            while !self.open.is_empty() {
+                self.finish_node();
+            }
+
+            self.trivia();
+            let end = self.tokens.iter()
+                .rposition(|token| !SyntaxKind::from(&token.kind).is_trivia())
+                .map_or(self.idx, |last| last + 1);
+            if self.idx < end {
+                self.builder.start_node(SyntaxKind::Error);
+                while self.idx < end {
+                    let token = &self.tokens[self.idx];
+                    self.builder.token(SyntaxKind::from(&token.kind), token.string);
+                    self.idx += 1;
+                }
+                self.builder.finish_node();
+            }
+        }
+
+        self.trivia();
+        self.builder.finish_node();
+
+        self.builder.finish()
+    }
+}
+
+impl From<Node> for SyntaxKind {
+    fn from(node: Node) -> Self {
+        match node {
+            Node::Function => SyntaxKind::Function,
+            Node::ParamList => SyntaxKind::ParamList,
+            Node::Param => SyntaxKind::Param,
+            Node::ReturnType => SyntaxKind::ReturnType,
+            Node::Block => SyntaxKind::Block,
+            Node::LetStatement => SyntaxKind::LetStatement,
+            Node::DeferStatement => SyntaxKind::DeferStatement,
+            Node::ExpressionStatement => SyntaxKind::ExpressionStatement,
+            Node::Expression => SyntaxKind::Expression,
+            Node::Global => SyntaxKind::Global,
+            Node::ModuleDeclaration => SyntaxKind::ModuleDeclaration,
+            Node::UseDeclaration => SyntaxKind::UseDeclaration,
+            Node::ExternBlock => SyntaxKind::ExternBlock,
+            Node::StaticAssert => SyntaxKind::StaticAssert
+        }
+    }
+}
+
+// Parses the file into a tree which keeps every character of it, including
+// whitespace and comments, so `tree.to_string()` is always its source, even
+// if it has syntax errors. These are returned too, and everything from the
+// first of them on is in an `Error` node at the end of the tree.
+pub fn parse_file(file: FileId) -> (SyntaxNode, Vec<Diagnostic>) {
+    let ((events, result), diagnostics) = capture_diagnostics(|| parse_events(file));
+    let errors = diagnostics.into_iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .collect();
+
+    let src = file_src(file);
+    let green = Builder::new(&src).source_file(&events, result.is_ok());
+    (SyntaxNode::new_root(green), errors)
+}
+
+// Like `parse_file`, for source that isn't in a file, e.g. in tests
+pub fn parse(src: &str) -> SyntaxNode {
+    parse_file(add_file(Path::new("<cst>"), src)).0
+}
+
+// Typed views of the nodes of the tree, for finding
+// the parts of e.g. a function without knowing its layout
+pub mod ast {
+    use oxygen_span::FileId;
+
+    use super::{ SyntaxKind, SyntaxNode, SyntaxToken };
+
+    pub trait AstNode: Sized {
+        fn cast(node: SyntaxNode) -> Option<Self>;
+        fn syntax(&self) -> &SyntaxNode;
+    }
+
+    macro_rules! ast_node {
+        ($name:ident) => {
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct $name(SyntaxNode);
+
+            impl AstNode for $name {
+                fn cast(node: SyntaxNode) -> Option<Self> {
+                    (node.kind() == SyntaxKind::$name).then_some($name(node))
+                }
+
+                fn syntax(&self) -> &SyntaxNode {
+                    &self.0
+                }
+            }
+        };
+    }
+
+    ast_node!(SourceFile);
+    ast_node!(Function);
+    ast_node!(ParamList);
+    ast_node!(Param);
+    ast_node!(ReturnType);
+    ast_node!(Block);
+    ast_node!(LetStatement);
+    ast_node!(DeferStatement);
+    ast_node!(ExpressionStatement);
+    ast_node!(Expression);
+    ast_node!(Global);
+    ast_node!(ModuleDeclaration);
+    ast_node!(UseDeclaration);
+    ast_node!(ExternBlock);
+    ast_node!(StaticAssert);
+
+    fn child<N: AstNode>(node: &SyntaxNode) -> Option<N> {
+        node.children().find_map(N::cast)
+    }
+
+    fn children<'a, N: AstNode + 'a>(node: &'a SyntaxNode) -> impl Iterator<Item = N> + 'a {
+        node.children().filter_map(N::cast)
+    }
+
+    // The tokens directly in the node which aren't whitespace or comments
+    fn code_tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> + '_ {
+        node.tokens().filter(|token| !token.kind().is_trivia())
+    }
+
+    fn has_keyword(node: &SyntaxNode, keyword: &str) -> bool {
+        code_tokens(node).any(|token| token.text() == keyword)
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Item {
+        Function(Function),
+        Global(Global),
+        ModuleDeclaration(ModuleDeclaration),
+        UseDeclaration(UseDeclaration),
+        ExternBlock(ExternBlock),
+        StaticAssert(StaticAssert)
+    }
+
+    impl AstNode for Item {
+        fn cast(node: SyntaxNode) -> Option<Self> {
+            Some(match node.kind() {
+                SyntaxKind::Function => Item::Function(Function(node)),
+                SyntaxKind::Global => Item::Global(Global(node)),
+                SyntaxKind::ModuleDeclaration => Item::ModuleDeclaration(ModuleDeclaration(node)),
+                SyntaxKind::UseDeclaration => Item::UseDeclaration(UseDeclaration(node)),
+                SyntaxKind::ExternBlock => Item::ExternBlock(ExternBlock(node)),
+                SyntaxKind::StaticAssert => Item::StaticAssert(StaticAssert(node)),
+                _ => return None
+            })
+        }
+
+        fn syntax(&self) -> &SyntaxNode {
+            match self {
+                Item::Function(item) => item.syntax(),
+                Item::Global(item) => item.syntax(),
+                Item::ModuleDeclaration(item) => item.syntax(),
+                Item::UseDeclaration(item) => item.syntax(),
+                Item::ExternBlock(item) => item.syntax(),
+                Item::StaticAssert(item) => item.syntax()
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Statement {
+        Let(LetStatement),
+        Defer(DeferStatement),
+        Expression(ExpressionStatement)
+    }
+
+    impl AstNode for Statement {
+        fn cast(node: SyntaxNode) -> Option<Self> {
+            Some(match node.kind() {
+                SyntaxKind::LetStatement => Statement::Let(LetStatement(node)),
+                SyntaxKind::DeferStatement => Statement::Defer(DeferStatement(node)),
+                SyntaxKind::ExpressionStatement => Statement::Expression(ExpressionStatement(node)),
+                _ => return None
+            })
+        }
+
+        fn syntax(&self) -> &SyntaxNode {
+            match self {
+                Statement::Let(statement) => statement.syntax(),
+                Statement::Defer(statement) => statement.syntax(),
+                Statement::Expression(statement) => statement.syntax()
+            }
+        }
+    }
+
+    impl SourceFile {
+        pub fn parse(src: &str) -> SourceFile {
+            SourceFile(super::parse(src))
+        }
+
+        pub fn parse_file(file: FileId) -> SourceFile {
+            SourceFile(super::parse_file(file).0)
+        }
+
+        pub fn items(&self) -> impl Iterator<Item = Item> + '_ {
+            children(&self.0)
+        }
+
+        pub fn functions(&self) -> impl Iterator<Item = Function> + '_ {
+            children(&self.0)
+        }
+    }
+
+    impl Function {
+        pub fn name(&self) -> Option<SyntaxToken> {
+            code_tokens(&self.0).find(|token| token.kind() == SyntaxKind::Identifier && token.text() != "pure")
+        }
+
+        pub fn is_public(&self) -> bool {
+            has_keyword(&self.0, "pub")
+        }
+
+        // Foreign functions are impure unless marked `pure`,
+        // since the compiler can't see what they do
+        pub fn is_impure(&self) -> bool {
+            match self.0.parent().is_some_and(|parent| parent.kind() == SyntaxKind::ExternBlock) {
+                true => !code_tokens(&self.0)
+                    .take_while(|token| token.text() != "func")
+                    .any(|token| token.text() == "pure"),
+                false => has_keyword(&self.0, "impure")
+            }
+        }
+
+        pub fn param_list(&self) -> Option<ParamList> {
+            child(&self.0)
+        }
+
+        pub fn return_type(&self) -> Option<ReturnType> {
+            child(&self.0)
+        }
+
+        pub fn body(&self) -> Option<Block> {
+            child(&self.0)
+        }
+    }
+
+    impl ParamList {
+        pub fn params(&self) -> impl Iterator<Item = Param> + '_ {
+            children(&self.0)
+        }
+    }
+
+    impl Param {
+        // The name the parameter is bound to, if it's a
+        // name rather than a pattern e.g. `(i32, i32) (a, b)`
+        pub fn name(&self) -> Option<SyntaxToken> {
+            self.0.descendant_tokens().into_iter()
+                .filter(|token| !token.kind().is_trivia())
+                .last()
+                .filter(|token| token.kind() == SyntaxKind::Identifier && token.parent() == self.0)
+        }
+    }
+
+    impl ReturnType {
+        // Whether it's e.g. `~ i32!`
+        pub fn is_fallible(&self) -> bool {
+            code_tokens(&self.0).last().is_some_and(|token| token.text() == "!")
+        }
+    }
+
+    impl Block {
+        pub fn statements(&self) -> impl Iterator<Item = Statement> + '_ {
+            children(&self.0)
+        }
+    }
+
+    impl LetStatement {
+        pub fn value(&self) -> Option<Expression> {
+            child(&self.0)
+        }
+    }
+
+    impl DeferStatement {
+        pub fn block(&self) -> Option<Block> {
+            child(&self.0)
+        }
+
+        pub fn expression(&self) -> Option<Expression> {
+            child(&self.0)
+        }
+    }
+
+    impl ExpressionStatement {
+        pub fn expression(&self) -> Option<Expression> {
+            child(&self.0)
+        }
+
+        // Whether the statement ends in a `;`, rather than being
+        // the value of its block or an `if` or `loop`
+        pub fn has_semicolon(&self) -> bool {
+            code_tokens(&self.0).any(|token| token.text() == ";")
+        }
+    }
+
+    impl Global {
+        pub fn name(&self) -> Option<SyntaxToken> {
+            code_tokens(&self.0).find(|token| token.kind() == SyntaxKind::Identifier)
+        }
+
+        pub fn value(&self) -> Option<Expression> {
+            child(&self.0)
+        }
+    }
+
+    impl ExternBlock {
+        pub fn functions(&self) -> impl Iterator<Item = Function> + '_ {
+            children(&self.0)
+        }
+    }
+}
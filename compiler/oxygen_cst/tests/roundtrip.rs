@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use oxygen_cst::ast::{ AstNode, Item, SourceFile, Statement };
+use oxygen_cst::{ parse, parse_file, SyntaxKind };
+use oxygen_error::capture_diagnostics;
+use oxygen_span::add_file;
+
+fn repo_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+fn sources_in(directory: &str) -> Vec<(PathBuf, String)> {
+    let mut sources: Vec<_> = fs::read_dir(repo_path(directory)).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ox"))
+        .map(|path| {
+            let src = fs::read_to_string(&path).unwrap();
+            (path, src)
+        })
+        .collect();
+
+    sources.sort();
+    sources
+}
+
+#[test]
+fn examples_round_trip() {
+    let examples = sources_in("../../examples");
+    assert!(!examples.is_empty());
+
+    for (path, src) in examples {
+        assert_eq!(parse(&src).to_string(), src, "{} didn't round trip", path.display());
+    }
+}
+
+#[test]
+fn formatter_fixtures_round_trip() {
+    for (path, src) in sources_in("../oxygen_fmt/tests/fixtures") {
+        assert_eq!(parse(&src).to_string(), src, "{} didn't round trip", path.display());
+    }
+}
+
+#[test]
+fn broken_code_round_trips() {
+    for src in [
+        "",
+        "   \n\t",
+        "# only a comment",
+        "func",
+        "func main( {",
+        "func main() { let x = (1 + ; }",
+        "}}} )) ]] $ @",
+        "func main() { print(\"unterminated); }",
+        "extern \"C\" { func f(i32 x); ",
+        "let x = 1; func 🦀() {}\r\n"
+    ] {
+        assert_eq!(parse(src).to_string(), src);
+    }
+}
+
+// The tree is built from the parser's own events, so it has an
+// error exactly when the parser would reject the file, and holds
+// the same items when it doesn't
+#[test]
+fn agrees_with_the_parser() {
+    let mut corpus: Vec<String> = sources_in("../../examples").into_iter()
+        .chain(sources_in("../oxygen_fmt/tests/fixtures"))
+        .map(|(_, src)| src)
+        .collect();
+    corpus.extend([
+        "",
+        "#!/usr/bin/env oxygen\n#! docs\nfunc main() {}",
+        "func main( {",
+        "func main() { let x = (1 + ; }",
+        "func main() { print(1) print(2); }",
+        "func main() { if true { 1 } else { 2 } }",
+        "const X: i32 = 1",
+        "static_assert(1 == 1);",
+        "mod a; use a::b; pub use a::c;",
+        "pub extern \"C\" { func f(); }",
+        "extern \"C\" { func f(i32 x); ",
+        "func f(i32 a, i32 a) {}",
+        "func f() { let (x, y) = (1, 2); defer { print(x); } defer print(y); }",
+        "func f() { #= never closed",
+        "## docs for a const\nconst X: i32 = 1;",
+        "func main() {}\n#! late module docs",
+        "}}} )) ]] $ @",
+        "let x = 1;"
+    ].map(str::to_string));
+
+    for src in corpus {
+        let file = add_file(Path::new("<corpus>"), &src);
+        let (ast, _) = capture_diagnostics(|| oxygen_parser::parse(file));
+        let (tree, errors) = parse_file(file);
+
+        assert_eq!(tree.to_string(), src);
+        let has_error_node = tree.children().any(|node| node.kind() == SyntaxKind::Error);
+        match ast {
+            Ok(ast) => {
+                assert!(errors.is_empty() && !has_error_node, "only the parser accepted:\n{src}");
+                assert_eq!(SourceFile::parse(&src).items().count(), ast.items.len(), "different items in:\n{src}");
+            },
+            Err(_) => assert!(!errors.is_empty(), "only the parser rejected:\n{src}")
+        }
+    }
+}
+
+#[test]
+fn the_rest_of_broken_code_is_an_error_node() {
+    let (tree, errors) = parse_file(add_file(Path::new("<broken>"), "func f() {}\nfunc main() { let x = (1 + ; }\nfunc g() {}\n"));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Can't start expression with Semicolon (`;`)");
+
+    let children: Vec<_> = tree.children().map(|node| (node.kind(), node.to_string())).collect();
+    assert_eq!(children, [
+        (SyntaxKind::Function, "func f() {}".to_string()),
+        // The nodes around the error are closed where it is
+        (SyntaxKind::Function, "func main() { let x = (1 +".to_string()),
+        (SyntaxKind::Error, "; }\nfunc g() {}".to_string())
+    ]);
+}
+
+#[test]
+fn keeps_whitespace_and_comments() {
+    let src = "# leading\nfunc main() {  # after brace\n  print(1);\n}\n";
+    let tokens = parse(src).descendant_tokens();
+
+    let comments: Vec<_> = tokens.iter()
+        .filter(|token| token.kind() == SyntaxKind::Comment)
+        .map(|token| token.text().trim_end())
+        .collect();
+    assert_eq!(comments, ["# leading", "# after brace"]);
+
+    // Every byte of the source is in exactly one token, in order
+    let mut offset = 0;
+    for token in &tokens {
+        assert_eq!(token.text_range().start, offset);
+        assert_eq!(&src[token.text_range()], token.text());
+        offset = token.text_range().end;
+    }
+    assert_eq!(offset, src.len());
+}
+
+#[test]
+fn typed_view() {
+    let src = concat!(
+        "const LIMIT: i32 = 8;\n",
+        "pub func add(i32 a, (i32, i32) (b, c)) ~ i32! { a + b + c }\n",
+        "impure func main() {\n",
+        "  let x = 1;\n",
+        "  defer { print(x); }\n",
+        "  if x > 0 { print(x); }\n",
+        "  print(add(x, (2, 3)));\n",
+        "}\n"
+    );
+    let file = SourceFile::parse(src);
+
+    let items: Vec<_> = file.items().collect();
+    assert_eq!(items.len(), 3);
+    let Item::Global(global) = &items[0] else { panic!("expected a global, found {items:?}") };
+    assert_eq!(global.name().unwrap().text(), "LIMIT");
+    assert_eq!(global.value().unwrap().syntax().to_string(), "8");
+
+    let functions: Vec<_> = file.functions().collect();
+    let names: Vec<_> = functions.iter().map(|function| function.name().unwrap().text().to_string()).collect();
+    assert_eq!(names, ["add", "main"]);
+
+    let add = &functions[0];
+    assert!(add.is_public() && !add.is_impure());
+    assert!(add.return_type().unwrap().is_fallible());
+    let params: Vec<_> = add.param_list().unwrap().params()
+        .map(|param| (param.syntax().to_string(), param.name().map(|name| name.text().to_string())))
+        .collect();
+    assert_eq!(params, [
+        ("i32 a".to_string(), Some("a".to_string())),
+        ("(i32, i32) (b, c)".to_string(), None)
+    ]);
+    assert_eq!(add.body().unwrap().syntax().to_string(), "{ a + b + c }");
+
+    let main = &functions[1];
+    assert!(main.is_impure() && main.return_type().is_none());
+    let statements: Vec<_> = main.body().unwrap().statements().collect();
+    assert_eq!(statements.len(), 4);
+    assert!(matches!(&statements[0], Statement::Let(statement) if statement.value().unwrap().syntax().to_string() == "1"));
+    assert!(matches!(&statements[1], Statement::Defer(statement) if statement.block().is_some()));
+    // An `if` used as a statement ends at its block, with no `;`
+    let Statement::Expression(if_statement) = &statements[2] else { panic!("expected an `if`") };
+    assert_eq!(if_statement.syntax().to_string(), "if x > 0 { print(x); }");
+    assert!(!if_statement.has_semicolon());
+    assert!(matches!(&statements[3], Statement::Expression(statement) if statement.has_semicolon()));
+
+    // The text ranges of nodes point back into the source
+    let range = main.syntax().text_range();
+    assert_eq!(&src[range], main.syntax().to_string());
+    assert_eq!(main.syntax().parent().unwrap().kind(), SyntaxKind::SourceFile);
+}
+
+#[test]
+fn foreign_functions_are_impure_unless_marked_pure() {
+    let file = SourceFile::parse("extern \"C\" {\n  func write(i32 fd);\n  pub pure func abs(i32 x) ~ i32;\n}\nfunc pure() {}\n");
+
+    let Some(Item::ExternBlock(block)) = file.items().next() else { panic!("expected an extern block") };
+    let impure: Vec<_> = block.functions().map(|function| function.is_impure()).collect();
+    assert_eq!(impure, [true, false]);
+
+    // Outside of an `extern` block, functions are pure unless marked `impure`
+    let pure = file.functions().next().unwrap();
+    assert!(!pure.is_impure());
+}
@@ -1,6 +1,11 @@
+use std::cell::RefCell;
 use std::result;
 
+use oxygen_span::{ file_path, Span };
+
 const RED: &str = "\u{001b}[31m";
+const YELLOW: &str = "\u{001b}[33m";
+const BLUE: &str = "\u{001b}[34m";
 const BOLD: &str = "\u{001b}[1m";
 const WHITE: &str = "\u{001b}[37m";
 const RESET: &str = "\u{001b}[0;0m";
@@ -13,8 +18,95 @@ pub struct ErrorEmitted(());
 
 pub type Result<T> = result::Result<T, ErrorEmitted>;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Error,
+    Warning
+}
+
+// An error or warning that was captured rather than outputted
+#[derive(Clone, PartialEq, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    // Errors about e.g. the arguments have no location
+    pub span: Option<Span>
+}
+
+thread_local! {
+    // While this is `Some`, errors and warnings are collected into it
+    // instead of being outputted, e.g. for an editor to show them
+    static CAPTURED: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+}
+
+// Runs `f`, returning what it returned along with the
+// errors and warnings it emitted, which aren't outputted
+pub fn capture_diagnostics<T>(f: impl FnOnce() -> T) -> (T, Vec<Diagnostic>) {
+    let outer = CAPTURED.with(|captured| captured.borrow_mut().replace(Vec::new()));
+    let result = f();
+    let diagnostics = CAPTURED.with(|captured| captured.replace(outer)).unwrap_or_default();
+
+    (result, diagnostics)
+}
+
+// Returns true if the diagnostic was captured, and so shouldn't be outputted
+fn capture(severity: Severity, message: &str, span: Option<Span>) -> bool {
+    CAPTURED.with(|captured| match &mut *captured.borrow_mut() {
+        Some(diagnostics) => {
+            diagnostics.push(Diagnostic { severity, message: message.to_string(), span });
+            true
+        },
+        None => false
+    })
+}
+
 pub fn early_error(message: String) -> ErrorEmitted {
-    eprintln!("{RED}{BOLD}error: {WHITE}{message}{RESET}");    
+    if !capture(Severity::Error, &message, None) {
+        eprintln!("{RED}{BOLD}error: {WHITE}{message}{RESET}");
+    }
+
+    ErrorEmitted(())
+}
+
+fn emit_location(span: Span) {
+    let path = file_path(span.file);
+    eprintln!(" {BLUE}{BOLD}--> {RESET}{}:{}:{}", path.display(), span.line + 1, span.display_column() + 1);
+}
+
+// An error that points at a location in a source file
+pub fn span_error(span: Span, message: String) -> ErrorEmitted {
+    if capture(Severity::Error, &message, Some(span)) {
+        return ErrorEmitted(());
+    }
+
+    eprintln!("{RED}{BOLD}error: {WHITE}{message}{RESET}");
+    emit_location(span);
+
+    ErrorEmitted(())
+}
+
+// Warnings don't stop compilation, so there is nothing to propogate
+pub fn span_warning(span: Span, message: String) {
+    if capture(Severity::Warning, &message, Some(span)) {
+        return;
+    }
+
+    eprintln!("{YELLOW}{BOLD}warning: {WHITE}{message}{RESET}");
+    emit_location(span);
+}
+
+// The program panicked while it was being run, which
+// stops it in the same way as a compile error
+pub fn runtime_panic(span: Span, message: String) -> ErrorEmitted {
+    if capture(Severity::Error, &message, Some(span)) {
+        return ErrorEmitted(());
+    }
+
+    let path = file_path(span.file);
+    eprintln!(
+        "{RED}{BOLD}panicked at {}:{}:{}:{RESET}\n{message}",
+        path.display(), span.line + 1, span.display_column() + 1
+    );
 
     ErrorEmitted(())
 }
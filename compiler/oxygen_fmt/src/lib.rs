@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+
+use oxygen_lexer::{ tokenize, BinaryOperation, Keyword, Token, TokenKind };
+
+// What each level of nesting is indented by
+const INDENT: &str = "  ";
+// Lines longer than this have the parameters of
+// the function on them split up, one per line
+const MAX_WIDTH: usize = 100;
+
+// Line breaks the formatter adds that the source didn't have,
+// to split up parameters that don't fit on one line
+#[derive(Default)]
+struct Breaks {
+    // Tokens which are followed by a line break
+    after: HashSet<usize>,
+    // Closing parentheses which get a trailing comma before them
+    trailing_comma: HashSet<usize>
+}
+
+// The line the token ends on, which is further down than
+// it starts for multi-line strings and block comments
+fn end_line(token: &Token<'_>) -> usize {
+    token.line + token.string.matches('\n').count()
+}
+
+// Comments that run to the end of the line, so nothing can follow them on it
+fn is_line_comment(kind: &TokenKind) -> bool {
+    matches!(kind, TokenKind::Comment | TokenKind::DocComment(_))
+}
+
+fn is_opener(kind: &TokenKind) -> bool {
+    matches!(kind, TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenCurly)
+}
+
+fn is_closer(kind: &TokenKind) -> bool {
+    matches!(kind, TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::CloseCurly)
+}
+
+// Whether a token can be the end of a value, after which
+// e.g. `-` is subtraction rather than negation
+fn is_operand_end(kind: &TokenKind) -> bool {
+    use Keyword::*;
+
+    matches!(
+        kind,
+        TokenKind::Identifier | TokenKind::Literal { .. } | TokenKind::CloseParen | TokenKind::CloseBracket
+            | TokenKind::Keyword(
+                True | False | I8 | I16 | I32 | I64 | U8 | U16 | U32 | U64 | F32 | F64 | Bool | Char | Never
+            )
+    )
+}
+
+// The `(` and `)` around the parameters of every function, function type
+// and closure which has any, as indices into `tokens`
+fn parameter_lists(tokens: &[Token<'_>]) -> Vec<(usize, usize)> {
+    let mut lists = Vec::new();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.kind != TokenKind::Keyword(Keyword::Func) { continue };
+
+        let mut open = idx + 1;
+        if tokens.get(open).is_some_and(|token| token.kind == TokenKind::Identifier) {
+            open += 1;
+        }
+        if tokens.get(open).is_none_or(|token| token.kind != TokenKind::OpenParen) { continue };
+
+        let mut depth = 0;
+        let close = (open..tokens.len()).find(|&idx| {
+            if is_opener(&tokens[idx].kind) { depth += 1 };
+            if is_closer(&tokens[idx].kind) { depth -= 1 };
+            depth == 0
+        });
+
+        if let Some(close) = close.filter(|close| *close > open + 1) {
+            lists.push((open, close));
+        }
+    }
+
+    lists
+}
+
+struct Formatter<'t, 'src> {
+    tokens: &'t [Token<'src>],
+    breaks: &'t Breaks,
+    lines: Vec<String>,
+    // The line being written, including its indentation
+    line: String,
+    indent: usize,
+    // The brackets that are still open, along with whether they're a
+    // `{`, and the indentation and index of the line they were opened on
+    brackets: Vec<(bool, usize, usize)>,
+    // The index in `lines` that each token was written to
+    token_lines: Vec<usize>
+}
+
+impl<'t, 'src> Formatter<'t, 'src> {
+    fn new(tokens: &'t [Token<'src>], breaks: &'t Breaks) -> Self {
+        Formatter {
+            tokens,
+            breaks,
+            lines: Vec::new(),
+            line: String::new(),
+            indent: 0,
+            brackets: Vec::new(),
+            token_lines: Vec::new()
+        }
+    }
+
+    fn format(mut self) -> (Vec<String>, Vec<usize>) {
+        for (idx, token) in self.tokens.iter().enumerate() {
+            if idx == 0 {
+                self.start_line(idx);
+            } else {
+                let previous = &self.tokens[idx - 1];
+                let newlines = token.line - end_line(previous);
+
+                // Braces go on the same line as what they belong to, empty
+                // blocks are `{}`, and `else` goes on the same line as the `}`
+                // before it
+                let joined = !is_line_comment(&previous.kind) && (
+                    token.kind == TokenKind::OpenCurly
+                        || (token.kind == TokenKind::CloseCurly && previous.kind == TokenKind::OpenCurly)
+                        || (token.kind == TokenKind::Keyword(Keyword::Else) && previous.kind == TokenKind::CloseCurly)
+                );
+                let split = self.breaks.after.contains(&(idx - 1));
+
+                if split || self.starts_line(idx) || (newlines > 0 && !joined) {
+                    if self.breaks.trailing_comma.contains(&idx) {
+                        self.line.push(',');
+                    }
+                    self.finish_line();
+
+                    // Blank lines are kept, but only one at a time,
+                    // and not at the start or end of brackets
+                    if newlines > 1 && !split && !is_opener(&previous.kind) && !is_closer(&token.kind) {
+                        self.lines.push(String::new());
+                    }
+
+                    self.start_line(idx);
+                } else if self.space_between(idx) {
+                    self.line.push(' ');
+                }
+            }
+
+            match token.kind {
+                _ if is_line_comment(&token.kind) => self.line.push_str(token.string.trim_end()),
+                _ => self.line.push_str(token.string)
+            }
+            self.token_lines.push(self.lines.len());
+
+            if is_opener(&token.kind) {
+                self.brackets.push((token.kind == TokenKind::OpenCurly, self.indent, self.lines.len()));
+            } else if is_closer(&token.kind) {
+                self.brackets.pop();
+            }
+        }
+
+        if !self.tokens.is_empty() {
+            self.finish_line();
+        }
+
+        (self.lines, self.token_lines)
+    }
+
+    fn finish_line(&mut self) {
+        let line = std::mem::take(&mut self.line);
+        self.lines.push(line.trim_end().to_string());
+    }
+
+    // Indents a new line which starts with the token at `idx`
+    fn start_line(&mut self, idx: usize) {
+        let token = &self.tokens[idx];
+
+        self.indent = match self.brackets.last() {
+            // A closing bracket lines up with the line that opened it
+            Some((_, indent, _)) if is_closer(&token.kind) => *indent,
+            Some((true, indent, _)) => indent + 1 + self.continues(idx) as usize,
+            Some((false, indent, _)) => indent + 1,
+            None => self.continues(idx) as usize
+        };
+
+        self.line = INDENT.repeat(self.indent);
+    }
+
+    // Whether the token at `idx` goes on a new line however the source
+    // was laid out, so that every statement and item in a block is on its
+    // own line, as is the `}` at the end of any block that isn't empty.
+    // Comments stay on the end of the line they were written on.
+    fn starts_line(&self, idx: usize) -> bool {
+        use TokenKind::*;
+
+        let token = &self.tokens[idx];
+        if token.kind.is_comment() && token.line == end_line(&self.tokens[idx - 1]) {
+            return false;
+        }
+
+        let Some(previous_idx) = (0..idx).rev().find(|&idx| !self.tokens[idx].kind.is_comment()) else {
+            return false;
+        };
+        // Comments after it have already started a new line
+        if self.token_lines[previous_idx] != self.lines.len() {
+            return false;
+        }
+        let previous = &self.tokens[previous_idx];
+
+        if token.kind == CloseCurly {
+            return previous.kind != OpenCurly;
+        }
+
+        // Statements are separated by `;`, except for ones that end with a
+        // block, which are followed by the keyword or name of the next one
+        let in_block = matches!(self.brackets.last(), Some((true, _, _)) | None);
+        match previous.kind {
+            OpenCurly => true,
+            Semicolon => in_block,
+            CloseCurly => in_block && match token.kind {
+                Keyword(oxygen_lexer::Keyword::Else | oxygen_lexer::Keyword::As) => false,
+                Identifier | Literal { .. } | Keyword(_) => true,
+                _ => false
+            },
+            _ => false
+        }
+    }
+
+    // Whether the line starting with the token at `idx` continues
+    // an expression or item from the line before, e.g. the second
+    // line of `let x = a\n |> f();`, which is indented further
+    fn continues(&self, idx: usize) -> bool {
+        let previous = self.tokens[..idx].iter().rev().find(|token| !token.kind.is_comment());
+
+        previous.is_some_and(|previous| !matches!(
+            previous.kind,
+            TokenKind::Semicolon | TokenKind::Comma | TokenKind::OpenCurly | TokenKind::CloseCurly
+        ))
+    }
+
+    // Whether the `-`, `!` or `~` at `idx` is a prefix operator
+    fn is_prefix(&self, idx: usize) -> bool {
+        idx == 0 || !is_operand_end(&self.tokens[idx - 1].kind)
+    }
+
+    // Whether there is a space between the token at `idx`
+    // and the one before it, when they're on the same line
+    fn space_between(&self, idx: usize) -> bool {
+        use TokenKind::*;
+
+        let previous = &self.tokens[idx - 1];
+        let token = &self.tokens[idx];
+
+        match (&previous.kind, &token.kind) {
+            (_, Comment | BlockComment { .. } | DocComment(_)) => true,
+            (OpenParen | OpenBracket, _) | (_, CloseParen | CloseBracket) => false,
+            (_, Comma | Semicolon) => false,
+            (BlockComment { .. }, _) => true,
+            (Comma | Semicolon, _) => true,
+            (ColonColon | Dot, _) | (_, ColonColon | Dot) => false,
+            (_, Colon) => false,
+            (Colon, _) => true,
+            (OpenCurly, CloseCurly) => false,
+            (OpenCurly, _) | (_, OpenCurly | CloseCurly) => true,
+            // A call of a call looks like a tuple type followed by a tuple
+            // pattern, e.g. `(i32, i32) (a, b)`, so is left how it was
+            (CloseParen, OpenParen) => token.column != previous.column + 1 || token.line != previous.line,
+            // A call, or a function type or closure e.g. `func(i32)`
+            (_, OpenParen) => !is_operand_end(&previous.kind) && previous.kind != Keyword(oxygen_lexer::Keyword::Func),
+            // Indexing, rather than an array
+            (_, OpenBracket) => !is_operand_end(&previous.kind),
+            (BinOp(BinaryOperation::Minus) | Bang | Tilde, _) if self.is_prefix(idx - 1) => false,
+            // The `!` of a fallible return type e.g. `~ i32!`
+            (_, Bang) => !is_operand_end(&previous.kind),
+            _ => true
+        }
+    }
+}
+
+// Formats Oxide source code, keeping its comments, blank lines (though
+// no more than one in a row) and where its lines break, but changing
+// its indentation and the spacing within each line. Parameters that
+// make a line too long are split up, one per line. The source must
+// have no syntax errors, or it may be formatted strangely.
+pub fn format(src: &str) -> String {
+    let tokens: Vec<Token<'_>> = tokenize(src).collect();
+    let parameter_lists = parameter_lists(&tokens);
+    let mut breaks = Breaks::default();
+
+    loop {
+        let (lines, token_lines) = Formatter::new(&tokens, &breaks).format();
+
+        // The first parameter list which is all on a line that's too long
+        let too_long = parameter_lists.iter().find(|(open, close)| {
+            let line = token_lines[*open];
+            line == token_lines[*close] && lines[line].chars().count() > MAX_WIDTH
+        });
+
+        let Some(&(open, close)) = too_long else {
+            let mut formatted = lines.join("\n");
+            if !formatted.is_empty() {
+                formatted.push('\n');
+            }
+
+            return formatted;
+        };
+
+        breaks.after.insert(open);
+        let mut depth = 0;
+        for (idx, token) in tokens.iter().enumerate().take(close).skip(open) {
+            match &token.kind {
+                kind if is_opener(kind) => depth += 1,
+                kind if is_closer(kind) => depth -= 1,
+                TokenKind::Comma if depth == 1 => { breaks.after.insert(idx); },
+                _ => {}
+            }
+        }
+        breaks.after.insert(close - 1);
+        if tokens[close - 1].kind != TokenKind::Comma {
+            breaks.trailing_comma.insert(close);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use oxygen_fmt::format;
+
+fn repo_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+fn fixture(name: &str) -> String {
+    fs::read_to_string(repo_path("tests/fixtures").join(name)).unwrap()
+}
+
+fn examples() -> Vec<(PathBuf, String)> {
+    let mut examples: Vec<_> = fs::read_dir(repo_path("../../examples")).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ox"))
+        .map(|path| {
+            let src = fs::read_to_string(&path).unwrap();
+            (path, src)
+        })
+        .collect();
+
+    examples.sort();
+    examples
+}
+
+fn assert_idempotent(src: &str) {
+    let once = format(src);
+    let twice = format(&once);
+    assert_eq!(once, twice, "formatting again changed the output of:\n{src}");
+}
+
+fn comments(src: &str) -> Vec<&str> {
+    src.lines().filter_map(|line| line.find('#').map(|idx| line[idx..].trim_end())).collect()
+}
+
+#[test]
+fn examples_are_formatted() {
+    for (path, src) in examples() {
+        assert_eq!(format(&src), src, "{} isn't formatted", path.display());
+    }
+}
+
+#[test]
+fn formats_messy_code() {
+    assert_eq!(format(&fixture("messy.ox")), fixture("messy.formatted.ox"));
+}
+
+#[test]
+fn formatting_is_idempotent() {
+    assert_idempotent(&fixture("messy.ox"));
+    assert_idempotent(&fixture("messy.formatted.ox"));
+
+    for (_, src) in examples() {
+        assert_idempotent(&src);
+    }
+
+    for src in [
+        "",
+        "func main() {}",
+        "func main() {\n}\n",
+        "# only a comment",
+        "func f()~i32!{\n\n\n  1\n\n}",
+        "func f(func(i32, i32) ~ i32 combine, [i32] numbers, (i32, i32) (low, high)) ~ i32 { combine(low, high) }",
+        "impure func main() {\n  let x = 1 +\n  2 +\n  3;\n  let y = x\n  |> f()\n  |> g();\n}",
+        "func f() ~ i32 {\n  if a {\n    1\n  }\n  else if b { 2 }\n  else {\n    -3\n  }\n}",
+        "func curried() ~ func(i32) ~ i32 { func(i32 x) ~ i32 { x } }\nimpure func main() { print(curried()(1)); }"
+    ] {
+        assert_idempotent(src);
+    }
+}
+
+#[test]
+fn keeps_every_comment() {
+    let src = fixture("messy.ox");
+    assert_eq!(comments(&format(&src)), comments(&src));
+}
+
+#[test]
+fn keeps_block_and_doc_comments() {
+    let src = concat!(
+        "#!/usr/bin/env oxygen\n",
+        "#! A module\n",
+        "\n",
+        "## Adds one\n",
+        "func  f( i32 x #= the #= nested =# input =# )~i32{\n",
+        "  #= before =#x+1\n",
+        "}\n"
+    );
+
+    assert_eq!(format(src), concat!(
+        "#!/usr/bin/env oxygen\n",
+        "#! A module\n",
+        "\n",
+        "## Adds one\n",
+        "func f(i32 x #= the #= nested =# input =#) ~ i32 {\n",
+        "  #= before =# x + 1\n",
+        "}\n"
+    ));
+    assert_idempotent(src);
+}
+
+#[test]
+fn splits_long_parameter_lists() {
+    let src = "func add_three_numbers(i32 first_number, i32 second_number, i32 third_number, i32 fourth_number) ~ i32 {\n  first_number\n}\n";
+
+    assert_eq!(format(src), concat!(
+        "func add_three_numbers(\n",
+        "  i32 first_number,\n",
+        "  i32 second_number,\n",
+        "  i32 third_number,\n",
+        "  i32 fourth_number,\n",
+        ") ~ i32 {\n",
+        "  first_number\n",
+        "}\n"
+    ));
+
+    // Short ones are left on one line
+    let src = "func add(i32 a, i32 b) ~ i32 {\n  a + b\n}\n";
+    assert_eq!(format(src), src);
+}
+
+#[test]
+fn spaces_operators() {
+    assert_eq!(
+        format("func f(i32 a,i32 b)~i32!{let c=-a*(b+1)**2%3;c<<1|~b}"),
+        "func f(i32 a, i32 b) ~ i32! {\n  let c = -a * (b + 1) ** 2 % 3;\n  c << 1 | ~b\n}\n"
+    );
+}
+
+#[test]
+fn statements_go_on_their_own_lines() {
+    let canonical = concat!(
+        "func clamp(i32 x) ~ i32 {\n",
+        "  if x < 0 {\n",
+        "    return 0;\n",
+        "  }\n",
+        "  let y = x * 2;\n",
+        "  defer {\n",
+        "    print(y);\n",
+        "  }\n",
+        "  y\n",
+        "}\n",
+        "impure func main() {}\n"
+    );
+
+    // The same program laid out in different ways
+    for src in [
+        "func clamp(i32 x) ~ i32 { if x < 0 { return 0; } let y = x * 2; defer { print(y); } y }\nimpure func main() {}",
+        "func clamp(i32 x) ~ i32 {\n  if x < 0 { return 0; }\n  let y = x * 2; defer { print(y); }\n  y }\nimpure func main() {\n}\n",
+        "func clamp(i32 x) ~ i32\n{\nif x < 0\n{\nreturn 0;\n}\nlet y = x * 2;\ndefer\n{\nprint(y);\n}\ny\n}\nimpure func main() {}",
+        canonical
+    ] {
+        assert_eq!(format(src), canonical, "formatting gave a different layout for:\n{src}");
+        assert_idempotent(src);
+    }
+}
@@ -0,0 +1,1020 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{ Read, Write };
+use std::rc::Rc;
+
+use oxygen_abi::{ CType, CValue, ForeignSignature };
+use oxygen_ast::*;
+use oxygen_error::{ early_error, runtime_panic, span_error, ErrorEmitted, Result };
+use oxygen_parser::expression_text;
+use oxygen_resolve::Crate;
+use oxygen_span::Span;
+use oxygen_typeck::{ integer_method, OverflowMode, TypeckResults, Ty };
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value<'a> {
+    // Every integer fits in an i128, which is
+    // always kept in the range of its type
+    Int(i128, IntTy),
+    // Both f32s and f64s, which are rounded to their type
+    Float(f64, FloatTy),
+    Bool(bool),
+    Char(char),
+    Str(Rc<str>),
+    Unit,
+    // Arrays and slices, which are both copied
+    // when assigned, like every other value
+    Array(Vec<Value<'a>>),
+    Tuple(Vec<Value<'a>>),
+    Function(Callable<'a>)
+}
+
+#[derive(Clone, Debug)]
+pub enum Callable<'a> {
+    // A function defined with `func name`
+    Item(&'a Function),
+    Closure(Rc<Closure<'a>>)
+}
+
+impl<'a> PartialEq for Callable<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Callable::Item(function), Callable::Item(other)) => std::ptr::eq(*function, *other),
+            (Callable::Closure(closure), Callable::Closure(other)) => Rc::ptr_eq(closure, other),
+            _ => false
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Closure<'a> {
+    parameters: &'a Option<FunctionParameters>,
+    block: &'a Block,
+    // The variables that were in scope when the closure was
+    // created, which it keeps its own copy of
+    captured: HashMap<String, Value<'a>>
+}
+
+impl<'a> fmt::Display for Value<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(value, _) => write!(f, "{value}"),
+            // With Debug, floats always have a decimal point e.g. 1.0
+            Value::Float(value, FloatTy::F32) => write!(f, "{:?}", *value as f32),
+            Value::Float(value, FloatTy::F64) => write!(f, "{value:?}"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Char(value) => write!(f, "{value}"),
+            Value::Str(value) => write!(f, "{value}"),
+            Value::Unit => write!(f, "()"),
+            Value::Array(elements) => {
+                write!(f, "[")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 { write!(f, ", ")? };
+                    write!(f, "{element}")?;
+                }
+                write!(f, "]")
+            },
+            Value::Tuple(elements) => {
+                write!(f, "(")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 { write!(f, ", ")? };
+                    write!(f, "{element}")?;
+                }
+                if elements.len() == 1 { write!(f, ",")? };
+                write!(f, ")")
+            },
+            Value::Function(Callable::Item(function)) => write!(f, "<func {}>", function.name),
+            Value::Function(Callable::Closure(_)) => write!(f, "<closure>")
+        }
+    }
+}
+
+// One step from a variable to the part of it being assigned to
+enum Projection {
+    // [index], which needs to be bounds checked
+    Index(i128, Span),
+    // .0
+    Element(usize)
+}
+
+// Checks `index` against the length of `elements`,
+// panicking at `span` if it is out of bounds
+fn bounds_check(elements: &[Value<'_>], index: i128, span: Span) -> Result<usize> {
+    match usize::try_from(index) {
+        Ok(idx) if idx < elements.len() => Ok(idx),
+        _ => Err(runtime_panic(span, format!(
+            "index out of bounds: the length is {} but the index is {index}", elements.len()
+        )))
+    }
+}
+
+// Works out `left operator right` for integers of type `ty`,
+// doing what `mode` says if the result doesn't fit in it
+fn integer_operation(mode: OverflowMode, ty: IntTy, operator: BinaryOperator, left: i128, right: i128, span: Span) -> Result<i128> {
+    match (ty.checked(operator, left, right), mode) {
+        (Ok(value), _) => Ok(value),
+        (Err(IntError::Overflow { wrapped, .. }), OverflowMode::Wrapping) => Ok(wrapped),
+        (Err(IntError::Overflow { .. }), OverflowMode::Saturating) => {
+            // Whether the result would have been negative
+            let negative = match operator {
+                BinaryOperator::Plus => left + right < 0,
+                BinaryOperator::Minus => left - right < 0,
+                BinaryOperator::Power => left < 0 && right % 2 == 1,
+                // Only the smallest value divided by -1 overflows,
+                // which is positive, as is the remainder
+                BinaryOperator::Divide | BinaryOperator::Remainder => false,
+                _ => (left < 0) != (right < 0)
+            };
+
+            Ok(if negative { ty.min() } else { ty.max() })
+        },
+        (Err(error), _) => Err(runtime_panic(span, error.to_string()))
+    }
+}
+
+// Runs a binary operator. Without overflow checks, integer
+// arithmetic that doesn't fit in its type wraps around.
+fn binary_operation<'a>(operator: BinaryOperator, left: Value<'a>, right: Value<'a>, overflow_checks: bool, span: Span) -> Result<Value<'a>> {
+    use BinaryOperator::*;
+
+    let mode = if overflow_checks { OverflowMode::Checked } else { OverflowMode::Wrapping };
+
+    let value = match (operator, left, right) {
+        (Plus | Minus | Star | Divide | Remainder | Power | ShiftLeft | ShiftRight | And | Or | Xor, Value::Int(l, ty), Value::Int(r, _)) => {
+            Value::Int(integer_operation(mode, ty, operator, l, r, span)?, ty)
+        },
+        (Plus, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l + r), ty),
+        (Minus, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l - r), ty),
+        (Star, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l * r), ty),
+        (Divide, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l / r), ty),
+        (Remainder, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l % r), ty),
+        (Power, Value::Float(l, ty), Value::Float(r, _)) => Value::Float(ty.round(l.powf(r)), ty),
+        (And | LogicalAnd, Value::Bool(l), Value::Bool(r)) => Value::Bool(l && r),
+        (Or | LogicalOr, Value::Bool(l), Value::Bool(r)) => Value::Bool(l || r),
+        (Xor, Value::Bool(l), Value::Bool(r)) => Value::Bool(l != r),
+        (EqEq, l, r) => Value::Bool(l == r),
+        (NotEq, l, r) => Value::Bool(l != r),
+        (Greater | GreaterEq | Less | LessEq, l, r) => {
+            let ordering = match (&l, &r) {
+                (Value::Int(l, _), Value::Int(r, _)) => l.partial_cmp(r),
+                (Value::Float(l, _), Value::Float(r, _)) => l.partial_cmp(r),
+                (Value::Char(l), Value::Char(r)) => l.partial_cmp(r),
+                _ => unreachable!("{operator:?} on {l:?} and {r:?} should have been rejected by typeck")
+            };
+
+            // Comparisons with NaN are always false
+            Value::Bool(ordering.is_some_and(|ordering| match operator {
+                Greater => ordering.is_gt(),
+                GreaterEq => ordering.is_ge(),
+                Less => ordering.is_lt(),
+                _ => ordering.is_le()
+            }))
+        },
+        (operator, l, r) => unreachable!("{operator:?} on {l:?} and {r:?} should have been rejected by typeck")
+    };
+
+    Ok(value)
+}
+
+// Converts `value` to the type `to`, which typeck has checked it can be
+fn cast<'a>(value: Value<'a>, to: &Type) -> Value<'a> {
+    match (value, to) {
+        (Value::Int(value, _), Type::Int(ty)) => Value::Int(ty.wrap(value), *ty),
+        (Value::Int(value, _), Type::Float(ty)) => match ty {
+            // Straight from the integer, since going through
+            // an f64 first could round it twice
+            FloatTy::F32 => Value::Float(value as f32 as f64, *ty),
+            FloatTy::F64 => Value::Float(value as f64, *ty)
+        },
+        // Rust's float to integer casts saturate,
+        // with NaN becoming 0, and so does this
+        (Value::Float(value, _), Type::Int(ty)) => Value::Int((value as i128).clamp(ty.min(), ty.max()), *ty),
+        (Value::Float(value, _), Type::Float(ty)) => Value::Float(ty.round(value), *ty),
+        (Value::Bool(value), Type::Int(ty)) => Value::Int(value as i128, *ty),
+        (Value::Char(value), Type::Int(ty)) => Value::Int(ty.wrap(value as i128), *ty),
+        (Value::Int(value, _), Type::Char) => Value::Char(char::from(value as u8)),
+        (value, _) => value
+    }
+}
+
+pub type HostFunction = fn(&[CValue]) -> CValue;
+
+// Foreign functions that the interpreter can call, implemented in Rust
+// in place of the C functions that a native backend would link to
+#[derive(Default)]
+pub struct HostFunctions {
+    functions: HashMap<String, (ForeignSignature, HostFunction)>
+}
+
+impl HostFunctions {
+    pub fn new() -> Self {
+        HostFunctions::default()
+    }
+
+    // The functions from the C standard library that Oxide
+    // programs can declare and use when being interpreted
+    pub fn standard() -> Self {
+        use CType::*;
+
+        let mut host = HostFunctions::new();
+        let int = Int(IntTy::I32);
+
+        host.register(ForeignSignature::new("abs", &[int], int), |arguments| {
+            let [CValue::Int(value)] = arguments else { unreachable!() };
+            CValue::Int((*value as i32).wrapping_abs() as i128)
+        });
+        host.register(ForeignSignature::new("llabs", &[Int(IntTy::I64)], Int(IntTy::I64)), |arguments| {
+            let [CValue::Int(value)] = arguments else { unreachable!() };
+            CValue::Int((*value as i64).wrapping_abs() as i128)
+        });
+        host.register(ForeignSignature::new("sqrt", &[Double], Double), |arguments| {
+            let [CValue::Double(value)] = arguments else { unreachable!() };
+            CValue::Double(value.sqrt())
+        });
+        host.register(ForeignSignature::new("sqrtf", &[Float], Float), |arguments| {
+            let [CValue::Float(value)] = arguments else { unreachable!() };
+            CValue::Float(value.sqrt())
+        });
+        host.register(ForeignSignature::new("putchar", &[int], int), |arguments| {
+            let [CValue::Int(value)] = arguments else { unreachable!() };
+            print!("{}", *value as u8 as char);
+            CValue::Int(*value)
+        });
+        host.register(ForeignSignature::new("exit", &[int], Void), |arguments| {
+            let [CValue::Int(code)] = arguments else { unreachable!() };
+            let _ = std::io::stdout().flush();
+            std::process::exit(*code as i32)
+        });
+        host.register(ForeignSignature::new("getchar", &[], int), |_| {
+            let mut byte = [0];
+            match std::io::stdin().read(&mut byte) {
+                Ok(1) => CValue::Int(byte[0] as i128),
+                // EOF
+                _ => CValue::Int(-1)
+            }
+        });
+
+        host
+    }
+
+    pub fn register(&mut self, signature: ForeignSignature, function: HostFunction) {
+        self.functions.insert(signature.symbol.clone(), (signature, function));
+    }
+}
+
+// How far code run while compiling can go before it is stopped, since
+// it might otherwise never finish, or overflow the compiler's stack
+const MAX_COMPILE_TIME_DEPTH: usize = 256;
+const MAX_COMPILE_TIME_STEPS: usize = 1_000_000;
+
+// How deep calls can go when the program is run, which is well short
+// of where they would overflow the interpreter's stack
+const MAX_DEPTH: usize = 1000;
+
+// Why evaluation stopped before the end of an expression
+enum Unwind<'a> {
+    // The program panicked, which has already been reported
+    Panic(ErrorEmitted),
+    Return(Value<'a>),
+    Break
+}
+
+impl From<ErrorEmitted> for Unwind<'_> {
+    fn from(error: ErrorEmitted) -> Self {
+        Unwind::Panic(error)
+    }
+}
+
+type Flow<'a, T> = std::result::Result<T, Unwind<'a>>;
+
+// Where an assignment stores its value
+enum Place<'a> {
+    Variable(&'a str),
+    Static(&'a [String])
+}
+
+// The value of every const and the starting value of every static,
+// worked out by `evaluate_globals` before the program is run
+pub struct Globals<'a> {
+    values: HashMap<Vec<String>, Value<'a>>
+}
+
+impl<'a> Globals<'a> {
+    // Puts back the current values of the statics in `previous` in place
+    // of their starting values, e.g. in the REPL when other items are
+    // defined, except for those in `replaced`, which were defined again
+    pub fn keep_statics(&mut self, krate: &Crate, previous: &Globals<'a>, replaced: &[Vec<String>]) {
+        for (path, value) in &previous.values {
+            let is_static = krate.globals()
+                .any(|(other, global)| other == *path && global.kind == GlobalKind::Static);
+
+            if is_static && !replaced.contains(path) {
+                self.values.insert(path.clone(), value.clone());
+            }
+        }
+    }
+}
+
+// Values can point into the crate being run, so live as long as it
+// does, but only need the types and host functions while it's running
+struct Interpreter<'a, 'r> {
+    types: &'r TypeckResults,
+    functions: HashMap<Vec<String>, &'a Function>,
+    globals: HashMap<Vec<String>, &'a Global>,
+    // The current value of every const and static
+    // that has been evaluated so far
+    global_values: HashMap<Vec<String>, Value<'a>>,
+    // Consts and statics whose values are being worked
+    // out, so that ones which depend on themselves are caught
+    evaluating: Vec<Vec<String>>,
+    // There are no foreign functions to call while compiling
+    host: Option<&'r HostFunctions>,
+    // Whether integer arithmetic panics when the result doesn't fit
+    overflow_checks: bool,
+    // Variables of the function being run, innermost scope last
+    scopes: Vec<HashMap<String, Value<'a>>>,
+    // The const, static or static assertion being worked out while
+    // compiling, which is where running into a limit is reported
+    compiling: Option<Span>,
+    // Calls that haven't returned yet
+    depth: usize,
+    // Expressions evaluated and loop iterations run so far
+    // for the item that is being worked out while compiling
+    steps: usize
+}
+
+impl<'a, 'r> Interpreter<'a, 'r> {
+    fn new(
+        krate: &'a Crate,
+        types: &'r TypeckResults,
+        global_values: HashMap<Vec<String>, Value<'a>>,
+        host: Option<&'r HostFunctions>,
+        overflow_checks: bool
+    ) -> Self {
+        Interpreter {
+            types,
+            functions: krate.functions().collect(),
+            globals: krate.globals().collect(),
+            global_values,
+            evaluating: Vec::new(),
+            host,
+            overflow_checks,
+            scopes: Vec::new(),
+            compiling: None,
+            depth: 0,
+            steps: 0
+        }
+    }
+
+    // Stops code being run while compiling once it has gone too deep or
+    // taken too long, which is an error at the item being worked out
+    fn check_limits(&mut self) -> Flow<'a, ()> {
+        let Some(span) = self.compiling else { return Ok(()) };
+
+        if self.depth == MAX_COMPILE_TIME_DEPTH {
+            Err(span_error(span, format!(
+                "This makes calls more than {MAX_COMPILE_TIME_DEPTH} deep while being worked out, \
+                so it might never finish"
+            )))?;
+        }
+
+        self.steps += 1;
+        if self.steps > MAX_COMPILE_TIME_STEPS {
+            Err(span_error(span, format!(
+                "This takes more than {MAX_COMPILE_TIME_STEPS} steps to work out, so it might never finish"
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    // Counts a call that is about to start, panicking if the program
+    // has gone too deep, which usually means it recurses forever
+    fn enter_call(&mut self, span: Span) -> Flow<'a, ()> {
+        self.check_limits()?;
+
+        if self.depth == MAX_DEPTH {
+            Err(runtime_panic(span, format!(
+                "This call goes more than {MAX_DEPTH} calls deep, so the program might never finish"
+            )))?;
+        }
+
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn global_value(&mut self, path: &[String], span: Span) -> Flow<'a, Value<'a>> {
+        if let Some(value) = self.global_values.get(path) {
+            return Ok(value.clone());
+        }
+
+        if self.evaluating.iter().any(|other| other == path) {
+            Err(span_error(span, format!("The value of `{}` depends on itself", path.join("::"))))?;
+        }
+
+        let global = self.globals[path];
+        self.evaluating.push(path.to_vec());
+        let outer_scopes = std::mem::replace(&mut self.scopes, vec![HashMap::new()]);
+        let outer_item = self.compiling.replace(global.span);
+
+        let value = self.evaluate(&global.value);
+
+        self.compiling = outer_item;
+        self.scopes = outer_scopes;
+        self.evaluating.pop();
+
+        let value = value?;
+        self.global_values.insert(path.to_vec(), value.clone());
+
+        Ok(value)
+    }
+
+    fn lookup(&mut self, name: &str) -> &mut Value<'a> {
+        self.scopes.iter_mut().rev()
+            .find_map(|scope| scope.get_mut(name))
+            .expect("variables should have been checked by typeck")
+    }
+
+    fn call(&mut self, callable: &Callable<'a>, arguments: Vec<Value<'a>>, span: Span) -> Flow<'a, Value<'a>> {
+        let (parameters, block, captured) = match callable {
+            Callable::Item(function) => {
+                let Some(block) = &function.block else {
+                    return self.call_foreign(function, arguments, span);
+                };
+
+                (&function.parameters, block, HashMap::new())
+            },
+            Callable::Closure(closure) => (closure.parameters, closure.block, closure.captured.clone())
+        };
+
+        self.enter_call(span)?;
+
+        let caller_scopes = std::mem::replace(&mut self.scopes, vec![captured, HashMap::new()]);
+        for (param, argument) in parameters.iter().flatten().zip(arguments) {
+            self.bind_pattern(&param.pattern, argument);
+        }
+
+        let value = match self.evaluate_block(block) {
+            Err(Unwind::Return(value)) => Ok(value),
+            value => value
+        };
+        self.scopes = caller_scopes;
+        self.depth -= 1;
+
+        value
+    }
+
+    fn call_foreign(&mut self, function: &Function, arguments: Vec<Value<'a>>, span: Span) -> Flow<'a, Value<'a>> {
+        let Some(host) = self.host else {
+            Err(span_error(span, format!(
+                "Can't call the foreign function `{}` while compiling", function.name
+            )))?
+        };
+
+        let Some((signature, host_function)) = host.functions.get(&function.name) else {
+            Err(runtime_panic(span, format!(
+                "The foreign function `{}` isn't available when running in the interpreter", function.name
+            )))?
+        };
+
+        let arguments: Vec<CValue> = arguments.into_iter().map(|argument| match argument {
+            Value::Int(value, _) => CValue::Int(value),
+            Value::Float(value, FloatTy::F32) => CValue::Float(value as f32),
+            Value::Float(value, FloatTy::F64) => CValue::Double(value),
+            Value::Char(value) => CValue::Int(value as i128),
+            Value::Bool(value) => CValue::Bool(value),
+            value => unreachable!("{value:?} can't be passed to a foreign function, which oxygen_abi should have caught")
+        }).collect();
+
+        let returned = host_function(&arguments);
+
+        if function.return_type.as_ref().is_some_and(|(r#type, _)| *r#type == Type::Never) {
+            Err(runtime_panic(span, format!(
+                "The foreign function `{}` returned, but is declared to never return", function.name
+            )))?;
+        }
+
+        let returns_char = function.return_type.as_ref().is_some_and(|(r#type, _)| *r#type == Type::Char);
+        let value = match (signature.return_type, returned) {
+            (CType::Int(_), CValue::Int(value)) if returns_char => match u32::try_from(value).ok().and_then(char::from_u32) {
+                Some(value) => Value::Char(value),
+                None => Err(runtime_panic(span, format!(
+                    "The foreign function `{}` returned {value}, which isn't a `char`", function.name
+                )))?
+            },
+            (CType::Int(int), CValue::Int(value)) => Value::Int(value, int),
+            (CType::Float, CValue::Float(value)) => Value::Float(value as f64, FloatTy::F32),
+            (CType::Double, CValue::Double(value)) => Value::Float(value, FloatTy::F64),
+            (CType::Bool, CValue::Bool(value)) => Value::Bool(value),
+            (CType::Void, CValue::Void) => Value::Unit,
+            (ty, value) => unreachable!("the host function `{}` returned {value:?} instead of a `{ty}`", function.name)
+        };
+
+        Ok(value)
+    }
+
+    // `written` are the arguments as they were written, so that
+    // failed assertions can show the code that failed
+    fn call_builtin(&mut self, name: &str, arguments: Vec<Value<'a>>, written: &[Argument], span: Span) -> Flow<'a, Value<'a>> {
+        match name {
+            "print" => {
+                print!("{}", arguments[0]);
+                Ok(Value::Unit)
+            },
+            "panic" => Err(runtime_panic(span, arguments[0].to_string()).into()),
+            "assert" => {
+                if arguments[0] == Value::Bool(true) {
+                    return Ok(Value::Unit);
+                }
+
+                let mut message = format!("assertion `{}` failed", expression_text(written[0].span));
+                if let Some(extra) = arguments.get(1) {
+                    message += &format!(": {extra}");
+                }
+
+                Err(runtime_panic(span, message).into())
+            },
+            "assert_eq" => {
+                let (left, right) = (&arguments[0], &arguments[1]);
+                if binary_operation(BinaryOperator::EqEq, left.clone(), right.clone(), true, span)? == Value::Bool(true) {
+                    return Ok(Value::Unit);
+                }
+
+                let mut message = format!(
+                    "assertion `{} == {}` failed", expression_text(written[0].span), expression_text(written[1].span)
+                );
+                if let Some(extra) = arguments.get(2) {
+                    message += &format!(": {extra}");
+                }
+                message += &format!("\n  left: {left}\n right: {right}");
+
+                Err(runtime_panic(span, message).into())
+            },
+            // The error of a fallible builtin is a panic, like
+            // that of a call to `panic` in a fallible function
+            name if name.ends_with("::try_from") => {
+                let value = match arguments[0] {
+                    Value::Int(value, _) => value,
+                    Value::Char(value) => value as i128,
+                    _ => unreachable!()
+                };
+
+                match name.split_once("::").and_then(|(target, _)| IntTy::from_name(target)) {
+                    Some(ty) if ty.contains(value) => Ok(Value::Int(value, ty)),
+                    Some(ty) => Err(runtime_panic(span, format!(
+                        "`{}` doesn't fit in `{}`, which goes from {} to {}", arguments[0], ty.name(), ty.min(), ty.max()
+                    )).into()),
+                    None => match u32::try_from(value).ok().and_then(char::from_u32) {
+                        Some(c) => Ok(Value::Char(c)),
+                        None => Err(runtime_panic(span, format!("`{value}` isn't a valid `char`")).into())
+                    }
+                }
+            },
+            _ => unreachable!("unknown builtin `{name}` should have been rejected by typeck")
+        }
+    }
+
+    fn evaluate_block(&mut self, block: &'a Block) -> Flow<'a, Value<'a>> {
+        let depth = self.scopes.len();
+        self.scopes.push(HashMap::new());
+
+        let mut deferred = Vec::new();
+        let value = self.evaluate_statements(block, &mut deferred);
+        let value = self.run_deferred(deferred, value);
+
+        // The block's scopes are left even when unwinding out of it
+        self.scopes.truncate(depth);
+
+        value
+    }
+
+    // Each deferred piece of code is kept with the number of scopes there
+    // were when it was deferred, so that it only sees the variables it did then
+    fn evaluate_statements(&mut self, block: &'a Block, deferred: &mut Vec<(&'a Deferred, usize)>) -> Flow<'a, Value<'a>> {
+        for statement in &block.statements {
+            self.evaluate_statement(statement, deferred)?;
+        }
+
+        match &block.tail {
+            Some(tail) => self.evaluate(tail),
+            None => Ok(Value::Unit)
+        }
+    }
+
+    fn evaluate_statement(&mut self, statement: &'a Statement, deferred: &mut Vec<(&'a Deferred, usize)>) -> Flow<'a, ()> {
+        match statement {
+            Statement::Expression(expression) => {
+                self.evaluate(expression)?;
+            },
+            Statement::Let { pattern, value, .. } => {
+                let value = self.evaluate(value)?;
+                self.bind_pattern(pattern, value);
+            },
+            Statement::Defer { body, .. } => {
+                deferred.push((body, self.scopes.len()));
+                // Later variables go in a new scope, so
+                // they can't shadow the ones it uses
+                self.scopes.push(HashMap::new());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Runs the code deferred in a block in reverse order, however the block
+    // was left. A panic in deferred code replaces a `return` or `break`
+    // that was leaving the block, but not an earlier panic.
+    fn run_deferred(&mut self, deferred: Vec<(&'a Deferred, usize)>, mut value: Flow<'a, Value<'a>>) -> Flow<'a, Value<'a>> {
+        for (body, depth) in deferred.into_iter().rev() {
+            self.scopes.truncate(depth);
+
+            let result = match body {
+                Deferred::Expression(expression) => self.evaluate(expression),
+                Deferred::Block(block) => self.evaluate_block(block)
+            };
+
+            if let Err(unwind) = result {
+                if !matches!(value, Err(Unwind::Panic(_))) {
+                    value = Err(unwind);
+                }
+            }
+        }
+
+        value
+    }
+
+    fn bind_pattern(&mut self, pattern: &Pattern, value: Value<'a>) {
+        match (pattern, value) {
+            (Pattern::Ident(name), value) => {
+                self.scopes.last_mut().unwrap().insert(name.clone(), value);
+            },
+            (Pattern::Wildcard, _) => {},
+            (Pattern::Tuple(patterns), Value::Tuple(elements)) => {
+                for (pattern, element) in patterns.iter().zip(elements) {
+                    self.bind_pattern(pattern, element);
+                }
+            },
+            (pattern, value) => unreachable!("{pattern:?} can't match {value:?}, which typeck should have caught")
+        }
+    }
+
+    fn evaluate_arguments(&mut self, parameters: &'a Option<CallParameters>) -> Flow<'a, Vec<Value<'a>>> {
+        parameters.iter().flatten().map(|argument| self.evaluate(&argument.value)).collect()
+    }
+
+    // Evaluates the arguments of a call to a function by its name in
+    // the order they were written, then puts them in the order of the
+    // parameters, working out the default value of any left out
+    fn evaluate_item_arguments(
+        &mut self,
+        function: &'a Function,
+        parameters: &'a Option<CallParameters>,
+        span: Span
+    ) -> Flow<'a, Vec<Value<'a>>> {
+        let params = function.parameters.as_deref().unwrap_or(&[]);
+        let mut values: Vec<Option<Value<'a>>> = vec![None; params.len()];
+
+        for (idx, argument) in parameters.iter().flatten().enumerate() {
+            let position = match &argument.name {
+                Some(name) => params.iter().position(|param| param.name() == Some(name)).unwrap(),
+                None => idx
+            };
+
+            values[position] = Some(self.evaluate(&argument.value)?);
+        }
+
+        params.iter().zip(values).map(|(param, value)| match value {
+            Some(value) => Ok(value),
+            None => {
+                // Defaults can't see the caller's variables, and count as
+                // part of the call, since they can call the function again
+                self.enter_call(span)?;
+                let caller_scopes = std::mem::replace(&mut self.scopes, vec![HashMap::new()]);
+                let value = self.evaluate(param.default.as_ref().unwrap());
+                self.scopes = caller_scopes;
+                self.depth -= 1;
+                value
+            }
+        }).collect()
+    }
+
+    // Finds the variable or static being assigned to, along with the
+    // way into it e.g. `a[1].0` is (Variable("a"), [Index(1), Element(0)])
+    fn evaluate_place(&mut self, target: &'a Expression) -> Flow<'a, (Place<'a>, Vec<Projection>)> {
+        match &target.kind {
+            ExpressionKind::Ident(name) => Ok((Place::Variable(name), Vec::new())),
+            ExpressionKind::Path(path) => Ok((Place::Static(&path.segments), Vec::new())),
+            ExpressionKind::Index { target: inner, index } => {
+                let (name, mut projections) = self.evaluate_place(inner)?;
+                let Value::Int(index, _) = self.evaluate(index)? else { unreachable!() };
+                projections.push(Projection::Index(index, target.span));
+                Ok((name, projections))
+            },
+            ExpressionKind::TupleIndex { target: inner, index } => {
+                let (name, mut projections) = self.evaluate_place(inner)?;
+                projections.push(Projection::Element(*index));
+                Ok((name, projections))
+            },
+            _ => unreachable!("assignment targets are checked by the parser")
+        }
+    }
+
+    fn assign(&mut self, target: &'a Expression, operator: Option<BinaryOperator>, value: Value<'a>, span: Span) -> Flow<'a, ()> {
+        let (place, projections) = self.evaluate_place(target)?;
+        let overflow_checks = self.overflow_checks;
+
+        let mut slot = match place {
+            Place::Variable(name) => self.lookup(name),
+            Place::Static(path) => self.global_values.get_mut(path).unwrap()
+        };
+        for projection in projections {
+            slot = match (projection, slot) {
+                (Projection::Index(index, index_span), Value::Array(elements)) => {
+                    let idx = bounds_check(elements, index, index_span)?;
+                    &mut elements[idx]
+                },
+                (Projection::Element(idx), Value::Tuple(elements)) => &mut elements[idx],
+                _ => unreachable!()
+            };
+        }
+
+        *slot = match operator {
+            Some(operator) => binary_operation(operator, slot.clone(), value, overflow_checks, span)?,
+            None => value
+        };
+
+        Ok(())
+    }
+
+    fn evaluate(&mut self, expression: &'a Expression) -> Flow<'a, Value<'a>> {
+        let span = expression.span;
+        self.check_limits()?;
+
+        let value = match &expression.kind {
+            ExpressionKind::Literal(literal) => match literal {
+                LiteralType::Integer { value, .. } => match self.types.literal_type(span) {
+                    Ty::Int(ty) => Value::Int(*value as i128, *ty),
+                    ty => unreachable!("an integer literal can't be a `{ty}`")
+                },
+                LiteralType::Float { value, .. } => match self.types.literal_type(span) {
+                    Ty::Float(ty) => Value::Float(ty.round(*value), *ty),
+                    ty => unreachable!("a float literal can't be a `{ty}`")
+                },
+                LiteralType::Char(value) => Value::Char(*value),
+                LiteralType::String(value) => Value::Str(Rc::from(value.as_str())),
+                LiteralType::True => Value::Bool(true),
+                LiteralType::False => Value::Bool(false)
+            },
+            ExpressionKind::Ident(name) => self.lookup(name).clone(),
+            ExpressionKind::Path(path) => match self.functions.get(&path.segments) {
+                Some(function) => Value::Function(Callable::Item(function)),
+                None => self.global_value(&path.segments, span)?
+            },
+            ExpressionKind::FunctionCall { callee, parameters } => {
+                if let ExpressionKind::Path(path) = &callee.kind {
+                    // Functions called by their name can have named arguments and defaults
+                    if let Some(function) = self.functions.get(&path.segments).copied() {
+                        let arguments = self.evaluate_item_arguments(function, parameters, span)?;
+                        return self.call(&Callable::Item(function), arguments, span);
+                    }
+
+                    // Builtins aren't values, so are called directly
+                    if !self.globals.contains_key(&path.segments) {
+                        let arguments = self.evaluate_arguments(parameters)?;
+                        let written = parameters.as_deref().unwrap_or(&[]);
+                        return self.call_builtin(&path.segments.join("::"), arguments, written, span);
+                    }
+                }
+
+                let Value::Function(callable) = self.evaluate(callee)? else { unreachable!() };
+                let arguments = self.evaluate_arguments(parameters)?;
+                self.call(&callable, arguments, span)?
+            },
+            ExpressionKind::Closure { parameters, block, .. } => {
+                // Inner scopes come last, so shadow the outer ones
+                let captured = self.scopes.iter().flatten()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect();
+
+                Value::Function(Callable::Closure(Rc::new(Closure { parameters, block, captured })))
+            },
+            ExpressionKind::MethodCall { path, name, parameters } => {
+                let receiver = self.evaluate(path)?;
+                let arguments = parameters.iter().flatten()
+                    .map(|argument| self.evaluate(&argument.value))
+                    .collect::<Flow<'a, Vec<_>>>()?;
+
+                match (name.as_str(), receiver, arguments.as_slice()) {
+                    ("len", Value::Array(elements), []) => Value::Int(elements.len() as i128, IntTy::I32),
+                    (name, Value::Int(left, ty), [Value::Int(right, _)]) => {
+                        let (mode, operator) = integer_method(name).unwrap();
+                        Value::Int(integer_operation(mode, ty, operator, left, *right, span)?, ty)
+                    },
+                    _ => unreachable!("unknown method `{name}` should have been rejected by typeck")
+                }
+            },
+            ExpressionKind::Binary { left, operator, right } => {
+                let left = self.evaluate(left)?;
+
+                // && and || don't evaluate their right hand side
+                // if the left is enough to know the result
+                match (operator, &left) {
+                    (BinaryOperator::LogicalAnd, Value::Bool(false)) => return Ok(Value::Bool(false)),
+                    (BinaryOperator::LogicalOr, Value::Bool(true)) => return Ok(Value::Bool(true)),
+                    _ => {}
+                }
+
+                let right = self.evaluate(right)?;
+                binary_operation(*operator, left, right, self.overflow_checks, span)?
+            },
+            ExpressionKind::Cast { value, r#type } => cast(self.evaluate(value)?, r#type),
+            ExpressionKind::Unary { operator, right } => {
+                match (operator, self.evaluate(right)?) {
+                    (UnaryOperator::Negate, Value::Int(value, ty)) => match ty.checked_neg(value) {
+                        Ok(negated) => Value::Int(negated, ty),
+                        Err(IntError::Overflow { wrapped, .. }) if !self.overflow_checks => Value::Int(wrapped, ty),
+                        Err(error) => Err(runtime_panic(span, error.to_string()))?
+                    },
+                    (UnaryOperator::Negate, Value::Float(value, ty)) => Value::Float(-value, ty),
+                    (UnaryOperator::Not, Value::Bool(value)) => Value::Bool(!value),
+                    (UnaryOperator::BitNot, Value::Int(value, ty)) => Value::Int(ty.wrap(!value), ty),
+                    (operator, value) => unreachable!("{operator:?} on {value:?} should have been rejected by typeck")
+                }
+            },
+            ExpressionKind::IfExpression { condition, block, else_branch } => {
+                if self.evaluate(condition)? == Value::Bool(true) {
+                    self.evaluate_block(block)?
+                } else {
+                    match else_branch {
+                        Some(ElseBranch::Block(block)) => self.evaluate_block(block)?,
+                        Some(ElseBranch::If(expression)) => self.evaluate(expression)?,
+                        None => Value::Unit
+                    }
+                }
+            },
+            ExpressionKind::Loop(block) => loop {
+                // An empty loop doesn't evaluate anything else
+                self.check_limits()?;
+
+                match self.evaluate_block(block) {
+                    Ok(_) => {},
+                    Err(Unwind::Break) => break Value::Unit,
+                    Err(unwind) => return Err(unwind)
+                }
+            },
+            ExpressionKind::Break => return Err(Unwind::Break),
+            ExpressionKind::Return(value) => {
+                let value = match value {
+                    Some(value) => self.evaluate(value)?,
+                    None => Value::Unit
+                };
+
+                return Err(Unwind::Return(value));
+            },
+            ExpressionKind::Array(elements) => {
+                Value::Array(elements.iter().map(|element| self.evaluate(element)).collect::<Flow<'a, _>>()?)
+            },
+            ExpressionKind::ArrayRepeat { value, count } => {
+                Value::Array(vec![self.evaluate(value)?; *count])
+            },
+            ExpressionKind::Tuple(elements) if elements.is_empty() => Value::Unit,
+            ExpressionKind::Tuple(elements) => {
+                Value::Tuple(elements.iter().map(|element| self.evaluate(element)).collect::<Flow<'a, _>>()?)
+            },
+            ExpressionKind::TupleIndex { target, index } => {
+                let Value::Tuple(mut elements) = self.evaluate(target)? else { unreachable!() };
+                elements.swap_remove(*index)
+            },
+            ExpressionKind::Index { target, index } => {
+                let Value::Array(elements) = self.evaluate(target)? else { unreachable!() };
+                let Value::Int(index, _) = self.evaluate(index)? else { unreachable!() };
+
+                let idx = bounds_check(&elements, index, span)?;
+                elements[idx].clone()
+            },
+            ExpressionKind::Assign { target, operator, value } => {
+                let value = self.evaluate(value)?;
+                self.assign(target, *operator, value, span)?;
+                Value::Unit
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+// Works out the values of every const and static in the crate, which
+// must have been resolved and type checked already. Their values can
+// only use pure code, so this is done while compiling, and anything
+// that goes wrong is a compile error rather than a panic at runtime,
+// including overflow, which is always checked for.
+pub fn evaluate_globals<'a>(krate: &'a Crate, types: &TypeckResults) -> Result<Globals<'a>> {
+    let mut interpreter = Interpreter::new(krate, types, HashMap::new(), None, true);
+
+    for (path, global) in krate.globals() {
+        interpreter.steps = 0;
+
+        if interpreter.global_value(&path, global.span).is_err() {
+            Err(span_error(global.span, format!(
+                "Couldn't work out the value of `{}` while compiling", global.name
+            )))?;
+        }
+    }
+
+    for assert in krate.static_asserts() {
+        interpreter.scopes = vec![HashMap::new()];
+        interpreter.compiling = Some(assert.span);
+        interpreter.steps = 0;
+
+        match interpreter.evaluate(&assert.condition) {
+            Ok(Value::Bool(true)) => {},
+            Ok(_) => Err(span_error(assert.span, format!(
+                "Static assertion `{}` failed", expression_text(assert.condition.span)
+            )))?,
+            Err(_) => Err(span_error(assert.span, "Couldn't work out this static assertion while compiling".to_string()))?
+        }
+    }
+
+    Ok(Globals { values: interpreter.global_values })
+}
+
+// Runs the `main` function in the crate root, which must have been
+// resolved and type checked already, returning the exit status that
+// `main` gives (or 0). Foreign functions are called through `host`,
+// which must agree with how they are declared. With `overflow_checks`,
+// integer arithmetic panics instead of wrapping around on overflow.
+pub fn interpret<'a>(
+    krate: &'a Crate,
+    types: &'a TypeckResults,
+    globals: Globals<'a>,
+    host: &'a HostFunctions,
+    overflow_checks: bool
+) -> Result<i32> {
+    for (_, function) in krate.functions().filter(|(_, function)| function.block.is_none()) {
+        let declared = ForeignSignature::of(function).unwrap();
+
+        if let Some((provided, _)) = host.functions.get(&declared.symbol) {
+            if *provided != declared {
+                Err(span_error(function.span, format!(
+                    "`{}` is declared as `{declared}`, but the interpreter provides `{provided}`", function.name
+                )))?;
+            }
+        }
+    }
+
+    let mut interpreter = Interpreter::new(krate, types, globals.values, Some(host), overflow_checks);
+
+    let main_path = vec!["crate".to_string(), "main".to_string()];
+    let Some(main) = interpreter.functions.get(&main_path).copied() else {
+        Err(early_error("There is no `main` function in the crate root".to_string()))?
+    };
+
+    match interpreter.call(&Callable::Item(main), Vec::new(), main.span) {
+        Ok(Value::Int(status, _)) => Ok(status as i32),
+        Ok(_) => Ok(0),
+        Err(Unwind::Panic(error)) => Err(error),
+        Err(_) => unreachable!("`break` outside of a loop should have been rejected by typeck")
+    }
+}
+
+// Runs `block` as if it were the body of an impure function in the crate
+// root, which must have been checked with `check_snippet`, returning its
+// value, e.g. for code in the REPL. It starts with `variables` in scope,
+// and if it finishes without panicking, they and `globals` are updated
+// to what it left them as, so that the next snippet can carry on from it.
+pub fn evaluate_snippet<'a>(
+    krate: &'a Crate,
+    types: &TypeckResults,
+    globals: &mut Globals<'a>,
+    variables: &mut HashMap<String, Value<'a>>,
+    host: &HostFunctions,
+    overflow_checks: bool,
+    block: &'a Block
+) -> Result<Value<'a>> {
+    let mut interpreter = Interpreter::new(krate, types, globals.values.clone(), Some(host), overflow_checks);
+    interpreter.scopes = vec![variables.clone()];
+
+    let mut deferred = Vec::new();
+    let mut value = Ok(Value::Unit);
+    for statement in &block.statements {
+        if let Err(unwind) = interpreter.evaluate_statement(statement, &mut deferred) {
+            value = Err(unwind);
+            break;
+        }
+    }
+
+    if let (Ok(_), Some(tail)) = (&value, &block.tail) {
+        value = interpreter.evaluate(tail);
+    }
+
+    let value = match interpreter.run_deferred(deferred, value) {
+        Ok(value) => value,
+        Err(Unwind::Panic(error)) => return Err(error),
+        Err(_) => unreachable!("`return` and `break` outside of a loop should have been rejected by typeck")
+    };
+
+    *variables = interpreter.scopes.pop().unwrap();
+    globals.values = interpreter.global_values;
+
+    Ok(value)
+}
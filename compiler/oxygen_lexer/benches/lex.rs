@@ -0,0 +1,109 @@
+use std::hint::black_box;
+use std::time::{ Duration, Instant };
+
+use oxygen_lexer::tokenize;
+
+// The lexer from before it worked over bytes, to compare against
+#[path = "../tests/reference/mod.rs"]
+mod reference;
+
+// Makes the `i`th piece of a generated source
+type Piece = fn(usize) -> String;
+
+// Code like most code, which is all ASCII
+fn code(i: usize) -> String {
+    format!("\
+func helper_{i}(i32 value, i32 count = 1) ~ i32 {{
+  let (first, second) = (value + count, value * {i});
+  let numbers: [i32; 4] = [first, second, 0x{i:x}, 1.5e3 as i32];
+  if numbers[0] >= LIMIT && !(second == 0) {{
+    return numbers[1] |> helper(count: 2) << 1;
+  }}
+  first % 3
+}}
+
+")
+}
+
+// Mostly comments and strings, which are skipped over quickly
+fn comments_and_strings(i: usize) -> String {
+    format!("\
+# Prints the {i}th message, which is a long string with \"escapes\"
+# and a comment above it going over more than one line
+func message_{i}() {{
+  print(\"message number {i} is quite a long one, with a \\\"quote\\\" and a newline\\n\");
+}}
+
+")
+}
+
+// Code with comments, strings and whitespace that aren't ASCII
+fn unicode(i: usize) -> String {
+    format!("\
+# Größe des {i}. Puffers — 🦀
+func größe_{i}(i32 wert) ~ i32 {{
+  print(\"Größe: {i} → 🦀🦀\");\u{a0}# not ASCII whitespace
+  wert * {i}
+}}
+
+")
+}
+
+// Repeats `piece` until the source is at least `bytes` long
+fn generate(bytes: usize, piece: Piece) -> String {
+    let mut src = String::new();
+    let mut i = 0;
+    while src.len() < bytes {
+        src.push_str(&piece(i));
+        i += 1;
+    }
+
+    src
+}
+
+// The fastest run of `f` in a third of a second of running it over and
+// over, which is steadier than the average when the machine is busy
+fn measure(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    let mut fastest = Duration::MAX;
+    while start.elapsed() < Duration::from_millis(300) {
+        let run = Instant::now();
+        f();
+        fastest = fastest.min(run.elapsed());
+    }
+
+    fastest
+}
+
+fn report(name: &str, bytes: usize, time: Duration) {
+    let throughput = bytes as f64 / time.as_secs_f64() / (1024.0 * 1024.0);
+    println!("  {name:<12} {time:>10.2?} {throughput:>8.1} MiB/s");
+}
+
+fn main() {
+    let sources: [(&str, Piece); 3] = [
+        ("code", code),
+        ("comments and strings", comments_and_strings),
+        ("unicode", unicode)
+    ];
+
+    for (name, piece) in sources {
+        let src = generate(4 * 1024 * 1024, piece);
+        println!("{name} ({} MiB)", src.len() / (1024 * 1024));
+
+        // Lines and columns are worked out when they're used, so use them.
+        // The two lexers take turns, so both see the machine as busy.
+        let (mut new, mut old) = (Duration::MAX, Duration::MAX);
+        for _ in 0..5 {
+            new = new.min(measure(|| {
+                black_box(tokenize(&src).map(|token| token.line + token.column).sum::<usize>());
+            }));
+            old = old.min(measure(|| {
+                black_box(reference::tokenize(&src, false).map(|token| token.line + token.column).sum::<usize>());
+            }));
+        }
+
+        report("bytes", src.len(), new);
+        report("chars (old)", src.len(), old);
+    }
+}
@@ -1,7 +1,5 @@
 #![deny(rust_2018_idioms)]
 
-use std::str::Chars;
-
 // use oxygen_error::Result;
 use BinaryOperation::*;
 use TokenKind::*;
@@ -32,8 +30,18 @@ pub enum BinaryOperation {
     Plus,
     Slash,
     Star,
+    // %
+    Percent,
+    // **
+    StarStar,
     And,
-    Or
+    Or,
+    // ^
+    Caret,
+    // <<
+    Shl,
+    // >>
+    Shr
 }
 
 #[derive(PartialEq, Debug)]
@@ -53,6 +61,8 @@ pub enum LiteralKind {
     Int { base: Base, empty_int: bool },
     Float { base: Base, empty_exponent: bool },
 
+    // bool = terminated
+    Char(bool),
     // bool = terminated
     Str(bool)
 }
@@ -60,17 +70,60 @@ pub enum LiteralKind {
 #[derive(PartialEq, Debug)]
 pub enum Keyword {
     If,
+    Else,
+    Loop,
+    Break,
+    Return,
+    Defer,
     Impure,
     Func,
+    Mod,
+    Use,
+    Pub,
+    Let,
+    Const,
+    Static,
+    Extern,
+    As,
+    True,
+    False,
+
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+    Bool,
+    Char,
+    Never
+}
 
-    I32
+// Which item a doc comment documents
+#[derive(PartialEq, Debug)]
+pub enum DocStyle {
+    // `## ...`, for the item after it
+    Outer,
+    // `#! ...`, for the module it's in
+    Inner
 }
 
 #[derive(PartialEq, Debug)]
 pub enum TokenKind {
     Eof,
 
+    // Only produced by `tokenize_losslessly`
+    Whitespace,
+    // `# ...`, to the end of the line, which is also what
+    // a `#!/usr/bin/env oxygen` line at the start of a file is
     Comment,
+    // `#= ... =#`, which can have others inside it
+    BlockComment { terminated: bool },
+    DocComment(DocStyle),
 
     Identifier,
     Keyword(Keyword),
@@ -85,13 +138,18 @@ pub enum TokenKind {
     EqEq,
     AndAnd,
     OrOr,
+    // |>
+    Pipe,
 
-    // +, /, &
+    // +, /, &, <<
     BinOp(BinaryOperation),
-    // +=, /=, &=
+    // +=, /=, &=, <<=
     BinOpEq(BinaryOperation),
 
     Semicolon,
+    Colon,
+    ColonColon,
+    Dot,
     Bang,
     Tilde,
     Comma,
@@ -107,10 +165,15 @@ pub enum TokenKind {
 }
 
 impl TokenKind {
+    pub fn is_comment(&self) -> bool {
+        matches!(self, Comment | BlockComment { .. } | DocComment(_))
+    }
+
     // Returns the two tokens that make up this one, if it is made of
     // two, else None
     //
     // e.g. "&=".split_two() -> ("&", "=")
+    //      "<<=".split_two() -> ("<<", "=")
     #[allow(dead_code)]
     fn split_two(&self) -> Option<(TokenKind, TokenKind)> {
         match self {
@@ -119,6 +182,8 @@ impl TokenKind {
             LessEq => Some((Less, Eq)),
             AndAnd => Some((BinOp(And), BinOp(And))),
             OrOr => Some((BinOp(Or), BinOp(Or))),
+            Pipe => Some((BinOp(Or), Greater)),
+            ColonColon => Some((Colon, Colon)),
             
             BinOpEq(Plus) => Some((BinOp(Plus), Eq)),
             BinOpEq(Minus) => Some((BinOp(Minus), Eq)),
@@ -126,142 +191,206 @@ impl TokenKind {
             BinOpEq(Star) => Some((BinOp(Star), Eq)),
             BinOpEq(And) => Some((BinOp(And), Eq)),
             BinOpEq(Or) => Some((BinOp(Or), Eq)),
+            BinOpEq(Percent) => Some((BinOp(Percent), Eq)),
+            BinOpEq(StarStar) => Some((BinOp(StarStar), Eq)),
+            BinOpEq(Caret) => Some((BinOp(Caret), Eq)),
+            BinOpEq(Shl) => Some((BinOp(Shl), Eq)),
+            BinOpEq(Shr) => Some((BinOp(Shr), Eq)),
+            BinOp(StarStar) => Some((BinOp(Star), BinOp(Star))),
+            BinOp(Shl) => Some((Less, Less)),
+            BinOp(Shr) => Some((Greater, Greater)),
 
             _ => None
         }
     }
 }
 
-#[allow(dead_code)]
+// Goes through the source a byte at a time, since almost all of it is
+// ASCII, and only decodes a whole UTF-8 character when it finds a byte
+// that isn't. Every byte the lexer looks for is ASCII, and the bytes of
+// a multi-byte character never are, so they can't be mistaken for one.
 pub struct TokenStream<'src> {
     src: &'src str,
-    chars: Chars<'src>,
+    bytes: &'src [u8],
+    idx: usize,
+    // The byte offset each line starts at, so that the line and column
+    // of a token are only worked out when it's made, rather than kept
+    // up to date after every character
+    line_starts: Vec<usize>,
+    // When the whole source is ASCII, which it usually is, a
+    // column is just how many bytes into the line a token is
+    ascii: bool,
+    // The position of the last token, which the next one is worked out
+    // from, since tokens come in order
     line: usize,
     column: usize,
-    idx: usize
+    position_idx: usize,
+    // Whether the last token was a `.`, in which case a number
+    // is a tuple index e.g. `pair.0.1`, and so can't be a float
+    after_dot: bool,
+    // Whether whitespace is made into tokens, rather than skipped
+    keep_whitespace: bool
 }
 
 impl<'src> TokenStream<'src> {
-    fn new(input: &'src str) -> Self {
+    fn new(input: &'src str, keep_whitespace: bool) -> Self {
+        let bytes = input.as_bytes();
+        let line_starts = std::iter::once(0)
+            .chain(bytes.iter().enumerate().filter(|&(_, &byte)| byte == b'\n').map(|(idx, _)| idx + 1))
+            .collect();
+
         TokenStream {
             src: input,
-            chars: input.chars(),
+            bytes,
+            idx: 0,
+            line_starts,
+            ascii: input.is_ascii(),
             line: 0,
             column: 0,
-            idx: 0
+            position_idx: 0,
+            after_dot: false,
+            keep_whitespace
         }
     }
-    
-    fn peek(&self) -> char {
-        self.chars.clone().next().unwrap_or('\0') 
+
+    // The line and column of `idx`, which can't be before the last token
+    fn position(&mut self, idx: usize) -> (usize, usize) {
+        while self.line_starts.get(self.line + 1).is_some_and(|&start| start <= idx) {
+            self.line += 1;
+            self.column = 0;
+            self.position_idx = self.line_starts[self.line];
+        }
+
+        let between = &self.bytes[self.position_idx..idx];
+        self.column += match self.ascii {
+            true => between.len(),
+            // Each character has exactly one byte that isn't a UTF-8 continuation byte
+            false => between.iter().filter(|&&byte| !is_continuation(byte)).count()
+        };
+        self.position_idx = idx;
+
+        (self.line, self.column)
     }
 
-    #[allow(dead_code)]
-    fn peek_second(&self) -> char {
-        let mut iter = self.chars.clone();
-        iter.next();
-        iter.next().unwrap_or('\0')
+    fn peek(&self) -> u8 {
+        self.bytes.get(self.idx).copied().unwrap_or(b'\0')
+    }
+
+    // The byte after the next one, which is only compared against ASCII,
+    // so may be part of a character rather than all of one
+    fn peek_second(&self) -> u8 {
+        self.bytes.get(self.idx + 1).copied().unwrap_or(b'\0')
+    }
+
+    // The next character, which is only decoded if it isn't ASCII
+    fn peek_char(&self) -> char {
+        match self.peek() {
+            byte if byte.is_ascii() => byte as char,
+            _ => self.src[self.idx..].chars().next().unwrap()
+        }
     }
 
     #[inline(always)]
     fn at_end(&self) -> bool {
-        self.chars.as_str().is_empty()
+        self.idx >= self.bytes.len()
     }
 
-    fn advance(&mut self) -> Option<char> {
-        let char = self.chars.next()?;
+    // Moves past the next character, which must be ASCII
+    fn advance(&mut self) {
         self.idx += 1;
+    }
 
-        match char {
-            '\n' => {
-                self.line += 1;
-                self.column = 0;
-            },
-            _ => {
-                self.column += 1;
-            }
-        }
-
-        Some(char)
+    fn advance_char(&mut self) {
+        self.idx += self.peek_char().len_utf8();
     }
 
-    fn advance_while(&mut self, mut predicate: impl FnMut(char) -> bool) {
-        while predicate(self.peek()) && !self.at_end() {
+    fn advance_while(&mut self, mut predicate: impl FnMut(u8) -> bool) {
+        while !self.at_end() && predicate(self.peek()) {
             self.advance();
         }
     }
-    
-    fn skip_whitespace(&mut self) {
-        self.advance_while(|c| c.is_whitespace());
-    }
 
-    fn consume_decimal_digits(&mut self) -> bool {
-        let mut has_digits = false;
+    fn skip_whitespace(&mut self) {
         loop {
             match self.peek() {
-                '0'..='9' => {
-                    has_digits = true;
-                    self.advance();
-                },
-                _ => break
+                // What `char::is_whitespace` counts as whitespace in ASCII
+                b' ' | b'\t' | b'\n' | b'\r' | b'\x0b' | b'\x0c' => self.advance(),
+                byte if !byte.is_ascii() && self.peek_char().is_whitespace() => self.advance_char(),
+                _ => return
             }
         }
-        has_digits
     }
 
-    fn consume_hexadecimal_digits(&mut self) -> bool {
-        let mut has_digits = false;
+    fn consume_ident_continue(&mut self) {
         loop {
             match self.peek() {
-                '0'..='9' | 'a'..='f' | 'A'..='F' => {
-                    has_digits = true;
-                    self.advance();
-                },
-                _ => break
+                byte if is_ident_continue(byte) => self.advance(),
+                byte if !byte.is_ascii() && unicode::is_xid_continue(self.peek_char()) => self.advance_char(),
+                _ => return
             }
         }
-        has_digits
+    }
+
+    fn consume_decimal_digits(&mut self) -> bool {
+        let start_idx = self.idx;
+        self.advance_while(|byte| byte.is_ascii_digit());
+        self.idx != start_idx
+    }
+
+    fn consume_hexadecimal_digits(&mut self) -> bool {
+        let start_idx = self.idx;
+        self.advance_while(|byte| byte.is_ascii_hexdigit());
+        self.idx != start_idx
+    }
+
+    // Whether an identifier could start at `idx`, which must be the start of a character
+    fn ident_starts_at(&self, idx: usize) -> bool {
+        match self.bytes.get(idx) {
+            Some(&byte) if byte.is_ascii() => is_ident_start(byte),
+            Some(_) => unicode::is_xid_start(self.src[idx..].chars().next().unwrap()),
+            None => false
+        }
     }
 
     fn consume_literal_suffix(&mut self) {
-        if !is_ident_start(self.peek()) { return };
+        if !self.ident_starts_at(self.idx) { return };
 
-        self.advance();
-        self.advance_while(is_ident_continue);
+        self.advance_char();
+        self.consume_ident_continue();
     }
 
     fn consume_float_exponent(&mut self) -> bool {
         match self.peek() {
-            '+' | '-' => { self.advance(); },
+            b'+' | b'-' => { self.advance(); },
             _ => {}
         };
         self.consume_decimal_digits()
     }
 
-    fn number(&mut self, first_digit: char) -> LiteralKind {
+    fn number(&mut self, first_digit: u8) -> LiteralKind {
         let mut base = Base::Decimal;
-        if first_digit == '0' {
+        if first_digit == b'0' {
             let has_digits = match self.peek() {
-                'b' => {
+                b'b' => {
                     base = Base::Binary;
                     self.advance();
                     self.consume_decimal_digits()
                 },
-                'o' => {
+                b'o' => {
                     base = Base::Octal;
                     self.advance();
                     self.consume_decimal_digits()
                 },
-                'd' => {
+                b'd' => {
                     self.advance();
                     self.consume_decimal_digits()
                 },
-                'x' => {
+                b'x' => {
                     base = Base::Hexadecimal;
                     self.advance();
                     self.consume_hexadecimal_digits()
                 },
-                '0'..='9' | '.' | 'e' | 'E' => {
+                b'0'..=b'9' | b'.' | b'e' | b'E' => {
                     self.consume_decimal_digits();
                     true
                 },
@@ -283,13 +412,13 @@ impl<'src> TokenStream<'src> {
             // on an integer literal
             //
             // e.g. 13.as_string();
-            '.' if !is_ident_start(self.peek_second()) => {
+            b'.' if !self.after_dot && !self.ident_starts_at(self.idx + 1) => {
                 self.advance();
                 let mut empty_exponent = false;
-                if self.peek().is_digit(10) {
+                if self.peek().is_ascii_digit() {
                     self.consume_decimal_digits();
                     match self.peek() {
-                        'e' | 'E' => {
+                        b'e' | b'E' => {
                             self.advance();
                             empty_exponent = !self.consume_float_exponent();
                         }
@@ -298,7 +427,7 @@ impl<'src> TokenStream<'src> {
                 }
                 LiteralKind::Float { base, empty_exponent }
             }
-            'e' | 'E' => {
+            b'e' | b'E' if !self.after_dot => {
                 self.advance();
                 let empty_exponent = !self.consume_float_exponent();
                 LiteralKind::Float { base, empty_exponent }
@@ -307,70 +436,161 @@ impl<'src> TokenStream<'src> {
         }
     }
 
+    // Moves to the next byte that `predicate` is true for, or the end
+    fn skip_to(&mut self, predicate: impl FnMut(&u8) -> bool) {
+        self.idx = match self.bytes[self.idx..].iter().position(predicate) {
+            Some(offset) => self.idx + offset,
+            None => self.bytes.len()
+        };
+    }
+
+    // After the `#` that starts a comment
+    fn comment(&mut self, start_idx: usize) -> TokenKind {
+        let kind = match (self.peek(), self.peek_second()) {
+            (b'=', _) => {
+                self.advance();
+                return BlockComment { terminated: self.block_comment() };
+            },
+            // `###` is an ordinary comment, e.g. a line of them to divide up a file
+            (b'#', second) if second != b'#' => DocComment(DocStyle::Outer),
+            (b'!', _) if start_idx == 0 && is_shebang(&self.src[2..]) => Comment,
+            (b'!', _) => DocComment(DocStyle::Inner),
+            _ => Comment
+        };
+
+        self.skip_to(|&byte| byte == b'\n');
+        kind
+    }
+
+    // Whether the block comment that has been opened is closed, counting
+    // the ones inside it, which have to be closed first
+    fn block_comment(&mut self) -> bool {
+        let mut depth = 1;
+        while !self.at_end() {
+            match (self.peek(), self.peek_second()) {
+                (b'#', b'=') => depth += 1,
+                (b'=', b'#') => depth -= 1,
+                _ => {
+                    self.advance();
+                    continue;
+                }
+            }
+
+            self.idx += 2;
+            if depth == 0 {
+                return true;
+            }
+        }
+
+        false
+    }
+
     // Returns true if string is terminated
     fn double_quoted_string(&mut self) -> bool {
-        while let Some(c) = self.advance() {
-            match c {
-                '"' => return true,
-                '\\' if self.peek() == '\\' || self.peek() == '"' => {
+        loop {
+            self.skip_to(|&byte| byte == b'"' || byte == b'\\');
+            if self.at_end() {
+                // EOF reached
+                return false;
+            }
+
+            let byte = self.peek();
+            self.advance();
+
+            match byte {
+                b'"' => return true,
+                _ if self.peek() == b'\\' || self.peek() == b'"' => {
                     // Handle escaped \ and "
                     self.advance();
                 },
                 _ => {}
             };
         }
+    }
+
+    // Returns true if the character is terminated
+    fn single_quoted_char(&mut self) -> bool {
+        while !self.at_end() {
+            let byte = self.peek();
+            self.advance();
+
+            match byte {
+                b'\'' => return true,
+                b'\\' if self.peek() == b'\\' || self.peek() == b'\'' => {
+                    // Handle escaped \ and '
+                    self.advance();
+                },
+                // A character literal can't go over more than one line
+                b'\n' => return false,
+                _ => {}
+            };
+        }
 
         // EOF reached
         false
     }
 
+    // The operator, or its compound assignment form
+    // if it's followed by a `=` e.g. `<<` or `<<=`
+    fn binary_operation(&mut self, operation: BinaryOperation) -> TokenKind {
+        match self.peek() {
+            b'=' => {
+                self.advance();
+                BinOpEq(operation)
+            },
+            _ => BinOp(operation)
+        }
+    }
+
     fn next_token(&mut self) -> Token<'src> {
+        if self.keep_whitespace && !self.at_end() && self.peek_char().is_whitespace() {
+            let start_idx = self.idx;
+            let (line, column) = self.position(start_idx);
+            self.skip_whitespace();
+            return Token::new(&self.src[start_idx..self.idx], Whitespace, line, column);
+        }
+
         self.skip_whitespace();
 
         let start_idx = self.idx;
-        let line = self.line;
-        let column = self.column;
+        let (line, column) = self.position(start_idx);
 
-        let c = match self.advance() {
-            Some(c) => c,
-            None => return Token::new("", TokenKind::Eof, line, column)
-        };
-
-        let kind = match c {
-            '#' => {
-                self.advance_while(|c| c != '\n');
-                Comment
-            },
+        if self.at_end() {
+            return Token::new("", TokenKind::Eof, line, column);
+        }
 
-            c if is_ident_start(c) => {
-                self.advance_while(is_ident_continue);
-                match &self.src.clone()[start_idx..self.idx] {
-                    // Keywords
-                    "if" => Keyword(Keyword::If),
-                    "func" => Keyword(Keyword::Func),
-                    "impure" => Keyword(Keyword::Impure),
+        let c = self.peek();
+        if c.is_ascii() {
+            self.advance();
+        } else {
+            self.advance_char();
+        }
 
-                    // Types
-                    "i32" => Keyword(Keyword::I32),
+        let kind = match c {
+            b'#' => self.comment(start_idx),
 
-                    _ => Identifier
+            c if is_ident_start(c) || (!c.is_ascii() && self.ident_starts_at(start_idx)) => {
+                self.consume_ident_continue();
+                match keyword(&self.src[start_idx..self.idx]) {
+                    Some(keyword) => Keyword(keyword),
+                    None => Identifier
                 }
             },
 
-            c @ '0'..='9' => {
+            c @ b'0'..=b'9' => {
                 let literal_kind = self.number(c);
                 let suffix_start = self.idx - start_idx;
                 self.consume_literal_suffix();
                 TokenKind::Literal { kind: literal_kind, suffix_start }
             }, 
 
-            '&' => {
+            b'&' => {
                 match self.peek() {
-                    '&' => {
+                    b'&' => {
                         self.advance();
                         AndAnd
                     },
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         BinOpEq(And)
                     },
@@ -378,13 +598,17 @@ impl<'src> TokenStream<'src> {
                 }
             },
 
-            '|' => {
+            b'|' => {
                 match self.peek() {
-                    '|' => {
+                    b'|' => {
                         self.advance();
                         OrOr
                     },
-                    '=' => {
+                    b'>' => {
+                        self.advance();
+                        Pipe
+                    },
+                    b'=' => {
                         self.advance();
                         BinOpEq(Or)
                     },
@@ -392,36 +616,42 @@ impl<'src> TokenStream<'src> {
                 }
             },
 
-            '+' => {
+            b'+' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         BinOpEq(Plus)
                     },
                     _ => BinOp(Plus)
                 }
             },
-            '-' => {
+            b'-' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         BinOpEq(Minus)
                     },
-                    _ => BinOp(Plus)
+                    _ => BinOp(Minus)
                 }
             },
-            '*' => {
+            b'*' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         BinOpEq(Star)
                     },
+                    b'*' => {
+                        self.advance();
+                        self.binary_operation(StarStar)
+                    },
                     _ => BinOp(Star)
                 }
             },
-            '/' => {
+            b'%' => self.binary_operation(Percent),
+            b'^' => self.binary_operation(Caret),
+            b'/' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         BinOpEq(Slash)
                     },
@@ -429,37 +659,45 @@ impl<'src> TokenStream<'src> {
                 }
             },
 
-            '>' => {
+            b'>' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         GreaterEq
                     },
+                    b'>' => {
+                        self.advance();
+                        self.binary_operation(Shr)
+                    },
                     _ => Greater
                 }
             },
-            '=' => {
+            b'=' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         EqEq
                     },
                     _ => Eq
                 }
             },
-            '<' => {
+            b'<' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         LessEq
                     },
+                    b'<' => {
+                        self.advance();
+                        self.binary_operation(Shl)
+                    },
                     _ => Less
                 }
             },
 
-            '!' => {
+            b'!' => {
                 match self.peek() {
-                    '=' => {
+                    b'=' => {
                         self.advance();
                         NotEq
                     },
@@ -467,40 +705,129 @@ impl<'src> TokenStream<'src> {
                 }
             },
 
-            '"' => {
+            b'"' => {
                 let terminated = self.double_quoted_string();
                 let suffix_start = self.idx - start_idx;
                 Literal { kind: LiteralKind::Str(terminated), suffix_start }
             },
 
-            ';' => Semicolon,
-            ',' => Comma,
-            '~' => Tilde,
-            '(' => OpenParen,
-            ')' => CloseParen,
-            '[' => OpenBracket,
-            ']' => CloseBracket,
-            '{' => OpenCurly,
-            '}' => CloseCurly,
+            b'\'' => {
+                let terminated = self.single_quoted_char();
+                let suffix_start = self.idx - start_idx;
+                Literal { kind: LiteralKind::Char(terminated), suffix_start }
+            },
+
+            b':' => {
+                match self.peek() {
+                    b':' => {
+                        self.advance();
+                        ColonColon
+                    },
+                    _ => Colon
+                }
+            },
+
+            b';' => Semicolon,
+            b'.' => Dot,
+            b',' => Comma,
+            b'~' => Tilde,
+            b'(' => OpenParen,
+            b')' => CloseParen,
+            b'[' => OpenBracket,
+            b']' => CloseBracket,
+            b'{' => OpenCurly,
+            b'}' => CloseCurly,
 
             _ => Unknown
         };
 
 
         let end_idx = self.idx;
+        self.after_dot = matches!(kind, Dot);
 
         Token::new(&self.src[start_idx..end_idx], kind, line, column)
     }
 }
 
+// The keyword `ident` is, if it is one. Going by the length first means
+// an identifier is only compared with the few keywords that could match it.
+fn keyword(ident: &str) -> Option<Keyword> {
+    let keyword = match ident.len() {
+        2 => match ident {
+            "if" => Keyword::If,
+            "as" => Keyword::As,
+            "i8" => Keyword::I8,
+            "u8" => Keyword::U8,
+            _ => return None
+        },
+        3 => match ident {
+            "mod" => Keyword::Mod,
+            "use" => Keyword::Use,
+            "pub" => Keyword::Pub,
+            "let" => Keyword::Let,
+            "i16" => Keyword::I16,
+            "i32" => Keyword::I32,
+            "i64" => Keyword::I64,
+            "u16" => Keyword::U16,
+            "u32" => Keyword::U32,
+            "u64" => Keyword::U64,
+            "f32" => Keyword::F32,
+            "f64" => Keyword::F64,
+            _ => return None
+        },
+        4 => match ident {
+            "else" => Keyword::Else,
+            "loop" => Keyword::Loop,
+            "func" => Keyword::Func,
+            "true" => Keyword::True,
+            "bool" => Keyword::Bool,
+            "char" => Keyword::Char,
+            _ => return None
+        },
+        5 => match ident {
+            "break" => Keyword::Break,
+            "defer" => Keyword::Defer,
+            "const" => Keyword::Const,
+            "false" => Keyword::False,
+            "never" => Keyword::Never,
+            _ => return None
+        },
+        6 => match ident {
+            "return" => Keyword::Return,
+            "impure" => Keyword::Impure,
+            "static" => Keyword::Static,
+            "extern" => Keyword::Extern,
+            _ => return None
+        },
+        _ => return None
+    };
+
+    Some(keyword)
+}
+
+// Whether the rest of a first line starting with `#!` is the program to
+// run a script with, e.g. `#!/usr/bin/env oxygen`, rather than the doc
+// comment of the module
+fn is_shebang(rest: &str) -> bool {
+    rest.trim_start_matches([' ', '\t']).starts_with('/')
+}
+
+// The bytes after the first of a multi-byte UTF-8 character, 0b10xxxxxx
 #[inline(always)]
-fn is_ident_start(c: char) -> bool {
-    c == '_' || c.is_ascii_alphabetic()
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
 }
 
+// These two are only for ASCII, which is most identifiers,
+// and the rest of Unicode is left to the `unicode` module
 #[inline(always)]
-fn is_ident_continue(c: char) -> bool {
-    is_ident_start(c) || c.is_numeric()
+fn is_ident_start(c: u8) -> bool {
+    c == b'_' || c.is_ascii_alphabetic()
+}
+
+#[inline(always)]
+fn is_ident_continue(c: u8) -> bool {
+    is_ident_start(c) || c.is_ascii_digit()
 }
 
 
@@ -510,8 +837,8 @@ pub struct Tokenizer<'src> {
 }
 
 impl<'src> Tokenizer<'src> {
-    fn new(input: &'src str) -> Self {
-        let stream = TokenStream::new(input);
+    fn new(input: &'src str, keep_whitespace: bool) -> Self {
+        let stream = TokenStream::new(input, keep_whitespace);
 
         Tokenizer {
             stream
@@ -529,5 +856,209 @@ impl<'src> Iterator for Tokenizer<'src> {
 }
 
 pub fn tokenize(input: &str) -> Tokenizer<'_> {
-    Tokenizer::new(input)
+    Tokenizer::new(input, false)
+}
+
+// Like `tokenize`, but whitespace is made into `Whitespace` tokens, so
+// that the tokens together are exactly `input`, e.g. for a syntax tree
+// that can reproduce the source it came from
+pub fn tokenize_losslessly(input: &str) -> Tokenizer<'_> {
+    Tokenizer::new(input, true)
+}
+
+// Identifiers follow UAX #31, so they can be written in any script, and
+// are compared once normalised to NFC. The parser also uses this to warn
+// about identifiers that could be mistaken for one another, following
+// UTS #39.
+pub mod unicode {
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
+
+    mod tables;
+    use tables::*;
+
+    pub fn is_xid_start(c: char) -> bool {
+        match c {
+            'a'..='z' | 'A'..='Z' => true,
+            _ if c.is_ascii() => false,
+            _ => within(XID_START, c)
+        }
+    }
+
+    pub fn is_xid_continue(c: char) -> bool {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => true,
+            _ if c.is_ascii() => false,
+            _ => within(XID_CONTINUE, c)
+        }
+    }
+
+    fn within(table: &[(char, char)], c: char) -> bool {
+        table.binary_search_by(|&(start, end)| compare_range(start, end, c)).is_ok()
+    }
+
+    fn compare_range(start: char, end: char, c: char) -> Ordering {
+        if end < c { Ordering::Less }
+        else if start > c { Ordering::Greater }
+        else { Ordering::Equal }
+    }
+
+    // `s` in Normalization Form C, which is only allocated
+    // if it isn't already, e.g. because it's ASCII
+    pub fn nfc(s: &str) -> Cow<'_, str> {
+        if s.is_ascii() {
+            return Cow::Borrowed(s);
+        }
+
+        let composed = compose(&decompose(s));
+        if composed == s { Cow::Borrowed(s) } else { Cow::Owned(composed) }
+    }
+
+    // What an identifier looks like, as the skeleton from UTS #39, so two
+    // identifiers with the same skeleton could be mistaken for each other
+    pub fn skeleton(ident: &str) -> String {
+        let mut prototype = String::with_capacity(ident.len());
+        for c in decompose(ident) {
+            match CONFUSABLES.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(idx) => prototype.push_str(CONFUSABLES[idx].1),
+                Err(_) => prototype.push(c)
+            }
+        }
+        decompose(&prototype).into_iter().collect()
+    }
+
+    // The script a character belongs to, or `None` if it's
+    // Common or Inherited, e.g. digits and combining accents
+    pub fn script(c: char) -> Option<&'static str> {
+        SCRIPTS.binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+            .ok()
+            .map(|idx| SCRIPT_NAMES[SCRIPTS[idx].2 as usize])
+    }
+
+    // Scripts that are written together, so an identifier
+    // can mix them without being suspicious
+    const WRITTEN_TOGETHER: &[&[&str]] = &[
+        &["Han", "Hiragana", "Katakana"],
+        &["Han", "Bopomofo"],
+        &["Han", "Hangul"]
+    ];
+
+    // The scripts in an identifier, if there are ones
+    // that aren't usually written together
+    pub fn mixed_scripts(ident: &str) -> Option<Vec<&'static str>> {
+        let mut scripts = Vec::new();
+        for script in ident.chars().filter_map(script) {
+            if !scripts.contains(&script) {
+                scripts.push(script);
+            }
+        }
+
+        let mixed = scripts.len() > 1 && !WRITTEN_TOGETHER.iter()
+            .any(|together| scripts.iter().all(|script| together.contains(script)));
+        if mixed { Some(scripts) } else { None }
+    }
+
+    const S_BASE: u32 = 0xac00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11a7;
+    const L_COUNT: u32 = 19;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = V_COUNT * T_COUNT;
+    const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+    fn combining_class(c: char) -> u8 {
+        if c.is_ascii() {
+            return 0;
+        }
+
+        COMBINING_CLASS.binary_search_by(|&(start, end, _)| compare_range(start, end, c))
+            .map_or(0, |idx| COMBINING_CLASS[idx].2)
+    }
+
+    // The canonical decomposition of `s`, with the
+    // combining marks after each starter put in order
+    fn decompose(s: &str) -> Vec<char> {
+        let mut chars = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            let syllable = (c as u32).wrapping_sub(S_BASE);
+            if syllable < S_COUNT {
+                // Hangul syllables are made of two or three jamo
+                chars.push(char::from_u32(L_BASE + syllable / N_COUNT).unwrap());
+                chars.push(char::from_u32(V_BASE + syllable % N_COUNT / T_COUNT).unwrap());
+                if !syllable.is_multiple_of(T_COUNT) {
+                    chars.push(char::from_u32(T_BASE + syllable % T_COUNT).unwrap());
+                }
+                continue;
+            }
+
+            match DECOMPOSITION.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(idx) => chars.extend(DECOMPOSITION[idx].1.chars()),
+                _ => chars.push(c)
+            }
+        }
+
+        let mut idx = 0;
+        while idx < chars.len() {
+            let start = idx;
+            while idx < chars.len() && combining_class(chars[idx]) != 0 {
+                idx += 1;
+            }
+            // A stable sort, so marks of the same class keep their order
+            chars[start..idx].sort_by_key(|&c| combining_class(c));
+            idx += 1;
+        }
+
+        chars
+    }
+
+    fn compose(chars: &[char]) -> String {
+        let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+        let mut starter: Option<usize> = None;
+        let mut last_class = 0;
+
+        for &c in chars {
+            let class = combining_class(c);
+            if let Some(starter) = starter {
+                // Something between this and the starter stops them composing,
+                // unless it's a mark of a lower class
+                let blocked = starter != composed.len() - 1 && (last_class == 0 || last_class >= class);
+                if !blocked {
+                    if let Some(pair) = compose_pair(composed[starter], c) {
+                        composed[starter] = pair;
+                        continue;
+                    }
+                }
+            }
+
+            if class == 0 {
+                starter = Some(composed.len());
+            }
+            last_class = class;
+            composed.push(c);
+        }
+
+        composed.into_iter().collect()
+    }
+
+    fn compose_pair(first: char, second: char) -> Option<char> {
+        let (l, v, t) = (
+            (first as u32).wrapping_sub(L_BASE),
+            (second as u32).wrapping_sub(V_BASE),
+            (second as u32).wrapping_sub(T_BASE)
+        );
+        let syllable = (first as u32).wrapping_sub(S_BASE);
+
+        if l < L_COUNT && v < V_COUNT {
+            return char::from_u32(S_BASE + l * N_COUNT + v * T_COUNT);
+        }
+        if syllable < S_COUNT && syllable.is_multiple_of(T_COUNT) && t > 0 && t < T_COUNT {
+            return char::from_u32(first as u32 + t);
+        }
+
+        COMPOSITION.binary_search_by_key(&(first, second), |&(first, second, _)| (first, second))
+            .ok()
+            .map(|idx| COMPOSITION[idx].2)
+    }
 }
@@ -0,0 +1,3213 @@
+// Generated from version 16.0.0 of the Unicode Character Database and of
+// the confusables data of UTS #39. Don't edit this by hand, but remake it
+// with tools/unicode_tables.
+
+// Characters with the XID_Start property
+pub const XID_START: &[(char, char)] = &[
+    ('A', 'Z'), ('a', 'z'), ('\u{aa}', '\u{aa}'), ('\u{b5}', '\u{b5}'),
+    ('\u{ba}', '\u{ba}'), ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'), ('\u{2e0}', '\u{2e4}'), ('\u{2ec}', '\u{2ec}'), ('\u{2ee}', '\u{2ee}'),
+    ('\u{370}', '\u{374}'), ('\u{376}', '\u{377}'), ('\u{37b}', '\u{37d}'), ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}', '\u{38c}'), ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3f5}'), ('\u{3f7}', '\u{481}'), ('\u{48a}', '\u{52f}'), ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'), ('\u{560}', '\u{588}'), ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f2}'),
+    ('\u{620}', '\u{64a}'), ('\u{66e}', '\u{66f}'), ('\u{671}', '\u{6d3}'), ('\u{6d5}', '\u{6d5}'),
+    ('\u{6e5}', '\u{6e6}'), ('\u{6ee}', '\u{6ef}'), ('\u{6fa}', '\u{6fc}'), ('\u{6ff}', '\u{6ff}'),
+    ('\u{710}', '\u{710}'), ('\u{712}', '\u{72f}'), ('\u{74d}', '\u{7a5}'), ('\u{7b1}', '\u{7b1}'),
+    ('\u{7ca}', '\u{7ea}'), ('\u{7f4}', '\u{7f5}'), ('\u{7fa}', '\u{7fa}'), ('\u{800}', '\u{815}'),
+    ('\u{81a}', '\u{81a}'), ('\u{824}', '\u{824}'), ('\u{828}', '\u{828}'), ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86a}'), ('\u{870}', '\u{887}'), ('\u{889}', '\u{88e}'), ('\u{8a0}', '\u{8c9}'),
+    ('\u{904}', '\u{939}'), ('\u{93d}', '\u{93d}'), ('\u{950}', '\u{950}'), ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'), ('\u{985}', '\u{98c}'), ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'), ('\u{9b6}', '\u{9b9}'), ('\u{9bd}', '\u{9bd}'),
+    ('\u{9ce}', '\u{9ce}'), ('\u{9dc}', '\u{9dd}'), ('\u{9df}', '\u{9e1}'), ('\u{9f0}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'), ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'), ('\u{a35}', '\u{a36}'), ('\u{a38}', '\u{a39}'),
+    ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'), ('\u{a72}', '\u{a74}'), ('\u{a85}', '\u{a8d}'),
+    ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'), ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'),
+    ('\u{ab5}', '\u{ab9}'), ('\u{abd}', '\u{abd}'), ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae1}'),
+    ('\u{af9}', '\u{af9}'), ('\u{b05}', '\u{b0c}'), ('\u{b0f}', '\u{b10}'), ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'), ('\u{b35}', '\u{b39}'), ('\u{b3d}', '\u{b3d}'),
+    ('\u{b5c}', '\u{b5d}'), ('\u{b5f}', '\u{b61}'), ('\u{b71}', '\u{b71}'), ('\u{b83}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'), ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'), ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'), ('\u{bd0}', '\u{bd0}'), ('\u{c05}', '\u{c0c}'), ('\u{c0e}', '\u{c10}'),
+    ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c39}'), ('\u{c3d}', '\u{c3d}'), ('\u{c58}', '\u{c5a}'),
+    ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c61}'), ('\u{c80}', '\u{c80}'), ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'), ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb3}'), ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbd}', '\u{cbd}'), ('\u{cdd}', '\u{cde}'), ('\u{ce0}', '\u{ce1}'), ('\u{cf1}', '\u{cf2}'),
+    ('\u{d04}', '\u{d0c}'), ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d3a}'), ('\u{d3d}', '\u{d3d}'),
+    ('\u{d4e}', '\u{d4e}'), ('\u{d54}', '\u{d56}'), ('\u{d5f}', '\u{d61}'), ('\u{d7a}', '\u{d7f}'),
+    ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'), ('\u{db3}', '\u{dbb}'), ('\u{dbd}', '\u{dbd}'),
+    ('\u{dc0}', '\u{dc6}'), ('\u{e01}', '\u{e30}'), ('\u{e32}', '\u{e32}'), ('\u{e40}', '\u{e46}'),
+    ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'), ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'), ('\u{ea7}', '\u{eb0}'), ('\u{eb2}', '\u{eb2}'), ('\u{ebd}', '\u{ebd}'),
+    ('\u{ec0}', '\u{ec4}'), ('\u{ec6}', '\u{ec6}'), ('\u{edc}', '\u{edf}'), ('\u{f00}', '\u{f00}'),
+    ('\u{f40}', '\u{f47}'), ('\u{f49}', '\u{f6c}'), ('\u{f88}', '\u{f8c}'), ('\u{1000}', '\u{102a}'),
+    ('\u{103f}', '\u{103f}'), ('\u{1050}', '\u{1055}'), ('\u{105a}', '\u{105d}'), ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'), ('\u{106e}', '\u{1070}'), ('\u{1075}', '\u{1081}'), ('\u{108e}', '\u{108e}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'), ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'), ('\u{13a0}', '\u{13f5}'), ('\u{13f8}', '\u{13fd}'), ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'), ('\u{1681}', '\u{169a}'), ('\u{16a0}', '\u{16ea}'), ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1711}'), ('\u{171f}', '\u{1731}'), ('\u{1740}', '\u{1751}'), ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'), ('\u{1780}', '\u{17b3}'), ('\u{17d7}', '\u{17d7}'), ('\u{17dc}', '\u{17dc}'),
+    ('\u{1820}', '\u{1878}'), ('\u{1880}', '\u{18a8}'), ('\u{18aa}', '\u{18aa}'), ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'), ('\u{1950}', '\u{196d}'), ('\u{1970}', '\u{1974}'), ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'), ('\u{1a00}', '\u{1a16}'), ('\u{1a20}', '\u{1a54}'), ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1b05}', '\u{1b33}'), ('\u{1b45}', '\u{1b4c}'), ('\u{1b83}', '\u{1ba0}'), ('\u{1bae}', '\u{1baf}'),
+    ('\u{1bba}', '\u{1be5}'), ('\u{1c00}', '\u{1c23}'), ('\u{1c4d}', '\u{1c4f}'), ('\u{1c5a}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c8a}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'), ('\u{1cf5}', '\u{1cf6}'), ('\u{1cfa}', '\u{1cfa}'), ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1e00}', '\u{1f15}'), ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'), ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'), ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'), ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'), ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fbc}'), ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fcc}'), ('\u{1fd0}', '\u{1fd3}'), ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffc}'), ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{2102}', '\u{2102}'), ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'), ('\u{2115}', '\u{2115}'), ('\u{2118}', '\u{211d}'), ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'), ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{2139}'), ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'), ('\u{214e}', '\u{214e}'), ('\u{2160}', '\u{2188}'), ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'), ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'), ('\u{2d30}', '\u{2d67}'), ('\u{2d6f}', '\u{2d6f}'), ('\u{2d80}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'), ('\u{2da8}', '\u{2dae}'), ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'), ('\u{2dc8}', '\u{2dce}'), ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'),
+    ('\u{3005}', '\u{3007}'), ('\u{3021}', '\u{3029}'), ('\u{3031}', '\u{3035}'), ('\u{3038}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'), ('\u{309d}', '\u{309f}'), ('\u{30a1}', '\u{30fa}'), ('\u{30fc}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'), ('\u{3131}', '\u{318e}'), ('\u{31a0}', '\u{31bf}'), ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'), ('\u{4e00}', '\u{a48c}'), ('\u{a4d0}', '\u{a4fd}'), ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a61f}'), ('\u{a62a}', '\u{a62b}'), ('\u{a640}', '\u{a66e}'), ('\u{a67f}', '\u{a69d}'),
+    ('\u{a6a0}', '\u{a6ef}'), ('\u{a717}', '\u{a71f}'), ('\u{a722}', '\u{a788}'), ('\u{a78b}', '\u{a7cd}'),
+    ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'), ('\u{a7d5}', '\u{a7dc}'), ('\u{a7f2}', '\u{a801}'),
+    ('\u{a803}', '\u{a805}'), ('\u{a807}', '\u{a80a}'), ('\u{a80c}', '\u{a822}'), ('\u{a840}', '\u{a873}'),
+    ('\u{a882}', '\u{a8b3}'), ('\u{a8f2}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'), ('\u{a8fd}', '\u{a8fe}'),
+    ('\u{a90a}', '\u{a925}'), ('\u{a930}', '\u{a946}'), ('\u{a960}', '\u{a97c}'), ('\u{a984}', '\u{a9b2}'),
+    ('\u{a9cf}', '\u{a9cf}'), ('\u{a9e0}', '\u{a9e4}'), ('\u{a9e6}', '\u{a9ef}'), ('\u{a9fa}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa28}'), ('\u{aa40}', '\u{aa42}'), ('\u{aa44}', '\u{aa4b}'), ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aa7a}'), ('\u{aa7e}', '\u{aaaf}'), ('\u{aab1}', '\u{aab1}'), ('\u{aab5}', '\u{aab6}'),
+    ('\u{aab9}', '\u{aabd}'), ('\u{aac0}', '\u{aac0}'), ('\u{aac2}', '\u{aac2}'), ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaea}'), ('\u{aaf2}', '\u{aaf4}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'), ('\u{ab70}', '\u{abe2}'), ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'), ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}', '\u{fb1d}'), ('\u{fb1f}', '\u{fb28}'), ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'), ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fc5d}'), ('\u{fc64}', '\u{fd3d}'), ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdf9}'), ('\u{fe71}', '\u{fe71}'), ('\u{fe73}', '\u{fe73}'),
+    ('\u{fe77}', '\u{fe77}'), ('\u{fe79}', '\u{fe79}'), ('\u{fe7b}', '\u{fe7b}'), ('\u{fe7d}', '\u{fe7d}'),
+    ('\u{fe7f}', '\u{fefc}'), ('\u{ff21}', '\u{ff3a}'), ('\u{ff41}', '\u{ff5a}'), ('\u{ff66}', '\u{ff9d}'),
+    ('\u{ffa0}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'), ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'), ('\u{10000}', '\u{1000b}'), ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'), ('\u{1003f}', '\u{1004d}'), ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'),
+    ('\u{10140}', '\u{10174}'), ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'), ('\u{10350}', '\u{10375}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'), ('\u{103d1}', '\u{103d5}'), ('\u{10400}', '\u{1049d}'), ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'), ('\u{10500}', '\u{10527}'), ('\u{10530}', '\u{10563}'), ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'), ('\u{10594}', '\u{10595}'), ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'), ('\u{105c0}', '\u{105f3}'),
+    ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'), ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'), ('\u{10800}', '\u{10805}'), ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'), ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'), ('\u{108e0}', '\u{108f2}'), ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'), ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a00}'), ('\u{10a10}', '\u{10a13}'), ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae4}'),
+    ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'), ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'), ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{10d00}', '\u{10d23}'),
+    ('\u{10d4a}', '\u{10d65}'), ('\u{10d6f}', '\u{10d85}'), ('\u{10e80}', '\u{10ea9}'), ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10ec2}', '\u{10ec4}'), ('\u{10f00}', '\u{10f1c}'), ('\u{10f27}', '\u{10f27}'), ('\u{10f30}', '\u{10f45}'),
+    ('\u{10f70}', '\u{10f81}'), ('\u{10fb0}', '\u{10fc4}'), ('\u{10fe0}', '\u{10ff6}'), ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'), ('\u{11075}', '\u{11075}'), ('\u{11083}', '\u{110af}'), ('\u{110d0}', '\u{110e8}'),
+    ('\u{11103}', '\u{11126}'), ('\u{11144}', '\u{11144}'), ('\u{11147}', '\u{11147}'), ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'), ('\u{11183}', '\u{111b2}'), ('\u{111c1}', '\u{111c4}'), ('\u{111da}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'), ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{1122b}'), ('\u{1123f}', '\u{11240}'),
+    ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'), ('\u{1128a}', '\u{1128d}'), ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'), ('\u{112b0}', '\u{112de}'), ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'), ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'),
+    ('\u{1133d}', '\u{1133d}'), ('\u{11350}', '\u{11350}'), ('\u{1135d}', '\u{11361}'), ('\u{11380}', '\u{11389}'),
+    ('\u{1138b}', '\u{1138b}'), ('\u{1138e}', '\u{1138e}'), ('\u{11390}', '\u{113b5}'), ('\u{113b7}', '\u{113b7}'),
+    ('\u{113d1}', '\u{113d1}'), ('\u{113d3}', '\u{113d3}'), ('\u{11400}', '\u{11434}'), ('\u{11447}', '\u{1144a}'),
+    ('\u{1145f}', '\u{11461}'), ('\u{11480}', '\u{114af}'), ('\u{114c4}', '\u{114c5}'), ('\u{114c7}', '\u{114c7}'),
+    ('\u{11580}', '\u{115ae}'), ('\u{115d8}', '\u{115db}'), ('\u{11600}', '\u{1162f}'), ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116aa}'), ('\u{116b8}', '\u{116b8}'), ('\u{11700}', '\u{1171a}'), ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182b}'), ('\u{118a0}', '\u{118df}'), ('\u{118ff}', '\u{11906}'), ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'), ('\u{11915}', '\u{11916}'), ('\u{11918}', '\u{1192f}'), ('\u{1193f}', '\u{1193f}'),
+    ('\u{11941}', '\u{11941}'), ('\u{119a0}', '\u{119a7}'), ('\u{119aa}', '\u{119d0}'), ('\u{119e1}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e3}'), ('\u{11a00}', '\u{11a00}'), ('\u{11a0b}', '\u{11a32}'), ('\u{11a3a}', '\u{11a3a}'),
+    ('\u{11a50}', '\u{11a50}'), ('\u{11a5c}', '\u{11a89}'), ('\u{11a9d}', '\u{11a9d}'), ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11bc0}', '\u{11be0}'), ('\u{11c00}', '\u{11c08}'), ('\u{11c0a}', '\u{11c2e}'), ('\u{11c40}', '\u{11c40}'),
+    ('\u{11c72}', '\u{11c8f}'), ('\u{11d00}', '\u{11d06}'), ('\u{11d08}', '\u{11d09}'), ('\u{11d0b}', '\u{11d30}'),
+    ('\u{11d46}', '\u{11d46}'), ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'), ('\u{11d6a}', '\u{11d89}'),
+    ('\u{11d98}', '\u{11d98}'), ('\u{11ee0}', '\u{11ef2}'), ('\u{11f02}', '\u{11f02}'), ('\u{11f04}', '\u{11f10}'),
+    ('\u{11f12}', '\u{11f33}'), ('\u{11fb0}', '\u{11fb0}'), ('\u{12000}', '\u{12399}'), ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'), ('\u{12f90}', '\u{12ff0}'), ('\u{13000}', '\u{1342f}'), ('\u{13441}', '\u{13446}'),
+    ('\u{13460}', '\u{143fa}'), ('\u{14400}', '\u{14646}'), ('\u{16100}', '\u{1611d}'), ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'), ('\u{16a70}', '\u{16abe}'), ('\u{16ad0}', '\u{16aed}'), ('\u{16b00}', '\u{16b2f}'),
+    ('\u{16b40}', '\u{16b43}'), ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'), ('\u{16d40}', '\u{16d6c}'),
+    ('\u{16e40}', '\u{16e7f}'), ('\u{16f00}', '\u{16f4a}'), ('\u{16f50}', '\u{16f50}'), ('\u{16f93}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'), ('\u{16fe3}', '\u{16fe3}'), ('\u{17000}', '\u{187f7}'), ('\u{18800}', '\u{18cd5}'),
+    ('\u{18cff}', '\u{18d08}'), ('\u{1aff0}', '\u{1aff3}'), ('\u{1aff5}', '\u{1affb}'), ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'), ('\u{1b132}', '\u{1b132}'), ('\u{1b150}', '\u{1b152}'), ('\u{1b155}', '\u{1b155}'),
+    ('\u{1b164}', '\u{1b167}'), ('\u{1b170}', '\u{1b2fb}'), ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'), ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1df00}', '\u{1df1e}'), ('\u{1df25}', '\u{1df2a}'), ('\u{1e030}', '\u{1e06d}'), ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e137}', '\u{1e13d}'), ('\u{1e14e}', '\u{1e14e}'), ('\u{1e290}', '\u{1e2ad}'), ('\u{1e2c0}', '\u{1e2eb}'),
+    ('\u{1e4d0}', '\u{1e4eb}'), ('\u{1e5d0}', '\u{1e5ed}'), ('\u{1e5f0}', '\u{1e5f0}'), ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'), ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e900}', '\u{1e943}'), ('\u{1e94b}', '\u{1e94b}'), ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'), ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'), ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'), ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'), ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'), ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'), ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'), ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'), ('\u{1eeab}', '\u{1eebb}'), ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b739}'), ('\u{2b740}', '\u{2b81d}'), ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2ebf0}', '\u{2ee5d}'), ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'), ('\u{31350}', '\u{323af}')
+];
+
+// Characters with the XID_Continue property
+pub const XID_CONTINUE: &[(char, char)] = &[
+    ('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z'),
+    ('\u{aa}', '\u{aa}'), ('\u{b5}', '\u{b5}'), ('\u{b7}', '\u{b7}'), ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'), ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{2c1}'), ('\u{2c6}', '\u{2d1}'),
+    ('\u{2e0}', '\u{2e4}'), ('\u{2ec}', '\u{2ec}'), ('\u{2ee}', '\u{2ee}'), ('\u{300}', '\u{374}'),
+    ('\u{376}', '\u{377}'), ('\u{37b}', '\u{37d}'), ('\u{37f}', '\u{37f}'), ('\u{386}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'), ('\u{38e}', '\u{3a1}'), ('\u{3a3}', '\u{3f5}'), ('\u{3f7}', '\u{481}'),
+    ('\u{483}', '\u{487}'), ('\u{48a}', '\u{52f}'), ('\u{531}', '\u{556}'), ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'), ('\u{591}', '\u{5bd}'), ('\u{5bf}', '\u{5bf}'), ('\u{5c1}', '\u{5c2}'),
+    ('\u{5c4}', '\u{5c5}'), ('\u{5c7}', '\u{5c7}'), ('\u{5d0}', '\u{5ea}'), ('\u{5ef}', '\u{5f2}'),
+    ('\u{610}', '\u{61a}'), ('\u{620}', '\u{669}'), ('\u{66e}', '\u{6d3}'), ('\u{6d5}', '\u{6dc}'),
+    ('\u{6df}', '\u{6e8}'), ('\u{6ea}', '\u{6fc}'), ('\u{6ff}', '\u{6ff}'), ('\u{710}', '\u{74a}'),
+    ('\u{74d}', '\u{7b1}'), ('\u{7c0}', '\u{7f5}'), ('\u{7fa}', '\u{7fa}'), ('\u{7fd}', '\u{7fd}'),
+    ('\u{800}', '\u{82d}'), ('\u{840}', '\u{85b}'), ('\u{860}', '\u{86a}'), ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88e}'), ('\u{897}', '\u{8e1}'), ('\u{8e3}', '\u{963}'), ('\u{966}', '\u{96f}'),
+    ('\u{971}', '\u{983}'), ('\u{985}', '\u{98c}'), ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'), ('\u{9b2}', '\u{9b2}'), ('\u{9b6}', '\u{9b9}'), ('\u{9bc}', '\u{9c4}'),
+    ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9ce}'), ('\u{9d7}', '\u{9d7}'), ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e3}'), ('\u{9e6}', '\u{9f1}'), ('\u{9fc}', '\u{9fc}'), ('\u{9fe}', '\u{9fe}'),
+    ('\u{a01}', '\u{a03}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'), ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a33}'), ('\u{a35}', '\u{a36}'), ('\u{a38}', '\u{a39}'),
+    ('\u{a3c}', '\u{a3c}'), ('\u{a3e}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a59}', '\u{a5c}'), ('\u{a5e}', '\u{a5e}'), ('\u{a66}', '\u{a75}'),
+    ('\u{a81}', '\u{a83}'), ('\u{a85}', '\u{a8d}'), ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'), ('\u{ab2}', '\u{ab3}'), ('\u{ab5}', '\u{ab9}'), ('\u{abc}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acd}'), ('\u{ad0}', '\u{ad0}'), ('\u{ae0}', '\u{ae3}'),
+    ('\u{ae6}', '\u{aef}'), ('\u{af9}', '\u{aff}'), ('\u{b01}', '\u{b03}'), ('\u{b05}', '\u{b0c}'),
+    ('\u{b0f}', '\u{b10}'), ('\u{b13}', '\u{b28}'), ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'),
+    ('\u{b35}', '\u{b39}'), ('\u{b3c}', '\u{b44}'), ('\u{b47}', '\u{b48}'), ('\u{b4b}', '\u{b4d}'),
+    ('\u{b55}', '\u{b57}'), ('\u{b5c}', '\u{b5d}'), ('\u{b5f}', '\u{b63}'), ('\u{b66}', '\u{b6f}'),
+    ('\u{b71}', '\u{b71}'), ('\u{b82}', '\u{b83}'), ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'), ('\u{b9c}', '\u{b9c}'), ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'), ('\u{bae}', '\u{bb9}'), ('\u{bbe}', '\u{bc2}'),
+    ('\u{bc6}', '\u{bc8}'), ('\u{bca}', '\u{bcd}'), ('\u{bd0}', '\u{bd0}'), ('\u{bd7}', '\u{bd7}'),
+    ('\u{be6}', '\u{bef}'), ('\u{c00}', '\u{c0c}'), ('\u{c0e}', '\u{c10}'), ('\u{c12}', '\u{c28}'),
+    ('\u{c2a}', '\u{c39}'), ('\u{c3c}', '\u{c44}'), ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4d}'),
+    ('\u{c55}', '\u{c56}'), ('\u{c58}', '\u{c5a}'), ('\u{c5d}', '\u{c5d}'), ('\u{c60}', '\u{c63}'),
+    ('\u{c66}', '\u{c6f}'), ('\u{c80}', '\u{c83}'), ('\u{c85}', '\u{c8c}'), ('\u{c8e}', '\u{c90}'),
+    ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb3}'), ('\u{cb5}', '\u{cb9}'), ('\u{cbc}', '\u{cc4}'),
+    ('\u{cc6}', '\u{cc8}'), ('\u{cca}', '\u{ccd}'), ('\u{cd5}', '\u{cd6}'), ('\u{cdd}', '\u{cde}'),
+    ('\u{ce0}', '\u{ce3}'), ('\u{ce6}', '\u{cef}'), ('\u{cf1}', '\u{cf3}'), ('\u{d00}', '\u{d0c}'),
+    ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d44}'), ('\u{d46}', '\u{d48}'), ('\u{d4a}', '\u{d4e}'),
+    ('\u{d54}', '\u{d57}'), ('\u{d5f}', '\u{d63}'), ('\u{d66}', '\u{d6f}'), ('\u{d7a}', '\u{d7f}'),
+    ('\u{d81}', '\u{d83}'), ('\u{d85}', '\u{d96}'), ('\u{d9a}', '\u{db1}'), ('\u{db3}', '\u{dbb}'),
+    ('\u{dbd}', '\u{dbd}'), ('\u{dc0}', '\u{dc6}'), ('\u{dca}', '\u{dca}'), ('\u{dcf}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'), ('\u{dd8}', '\u{ddf}'), ('\u{de6}', '\u{def}'), ('\u{df2}', '\u{df3}'),
+    ('\u{e01}', '\u{e3a}'), ('\u{e40}', '\u{e4e}'), ('\u{e50}', '\u{e59}'), ('\u{e81}', '\u{e82}'),
+    ('\u{e84}', '\u{e84}'), ('\u{e86}', '\u{e8a}'), ('\u{e8c}', '\u{ea3}'), ('\u{ea5}', '\u{ea5}'),
+    ('\u{ea7}', '\u{ebd}'), ('\u{ec0}', '\u{ec4}'), ('\u{ec6}', '\u{ec6}'), ('\u{ec8}', '\u{ece}'),
+    ('\u{ed0}', '\u{ed9}'), ('\u{edc}', '\u{edf}'), ('\u{f00}', '\u{f00}'), ('\u{f18}', '\u{f19}'),
+    ('\u{f20}', '\u{f29}'), ('\u{f35}', '\u{f35}'), ('\u{f37}', '\u{f37}'), ('\u{f39}', '\u{f39}'),
+    ('\u{f3e}', '\u{f47}'), ('\u{f49}', '\u{f6c}'), ('\u{f71}', '\u{f84}'), ('\u{f86}', '\u{f97}'),
+    ('\u{f99}', '\u{fbc}'), ('\u{fc6}', '\u{fc6}'), ('\u{1000}', '\u{1049}'), ('\u{1050}', '\u{109d}'),
+    ('\u{10a0}', '\u{10c5}'), ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'), ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'), ('\u{1260}', '\u{1288}'), ('\u{128a}', '\u{128d}'), ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'), ('\u{12b8}', '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'), ('\u{12d8}', '\u{1310}'), ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{135a}'),
+    ('\u{135d}', '\u{135f}'), ('\u{1369}', '\u{1371}'), ('\u{1380}', '\u{138f}'), ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'), ('\u{1401}', '\u{166c}'), ('\u{166f}', '\u{167f}'), ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'), ('\u{16ee}', '\u{16f8}'), ('\u{1700}', '\u{1715}'), ('\u{171f}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'), ('\u{1760}', '\u{176c}'), ('\u{176e}', '\u{1770}'), ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17d3}'), ('\u{17d7}', '\u{17d7}'), ('\u{17dc}', '\u{17dd}'), ('\u{17e0}', '\u{17e9}'),
+    ('\u{180b}', '\u{180d}'), ('\u{180f}', '\u{1819}'), ('\u{1820}', '\u{1878}'), ('\u{1880}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'), ('\u{1900}', '\u{191e}'), ('\u{1920}', '\u{192b}'), ('\u{1930}', '\u{193b}'),
+    ('\u{1946}', '\u{196d}'), ('\u{1970}', '\u{1974}'), ('\u{1980}', '\u{19ab}'), ('\u{19b0}', '\u{19c9}'),
+    ('\u{19d0}', '\u{19da}'), ('\u{1a00}', '\u{1a1b}'), ('\u{1a20}', '\u{1a5e}'), ('\u{1a60}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a89}'), ('\u{1a90}', '\u{1a99}'), ('\u{1aa7}', '\u{1aa7}'), ('\u{1ab0}', '\u{1abd}'),
+    ('\u{1abf}', '\u{1ace}'), ('\u{1b00}', '\u{1b4c}'), ('\u{1b50}', '\u{1b59}'), ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1bf3}'), ('\u{1c00}', '\u{1c37}'), ('\u{1c40}', '\u{1c49}'), ('\u{1c4d}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c8a}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'), ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1cfa}'), ('\u{1d00}', '\u{1f15}'), ('\u{1f18}', '\u{1f1d}'), ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'), ('\u{1f50}', '\u{1f57}'), ('\u{1f59}', '\u{1f59}'), ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'), ('\u{1f5f}', '\u{1f7d}'), ('\u{1f80}', '\u{1fb4}'), ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'), ('\u{1fc2}', '\u{1fc4}'), ('\u{1fc6}', '\u{1fcc}'), ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'), ('\u{1fe0}', '\u{1fec}'), ('\u{1ff2}', '\u{1ff4}'), ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{200c}', '\u{200d}'), ('\u{203f}', '\u{2040}'), ('\u{2054}', '\u{2054}'), ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'), ('\u{2090}', '\u{209c}'), ('\u{20d0}', '\u{20dc}'), ('\u{20e1}', '\u{20e1}'),
+    ('\u{20e5}', '\u{20f0}'), ('\u{2102}', '\u{2102}'), ('\u{2107}', '\u{2107}'), ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'), ('\u{2118}', '\u{211d}'), ('\u{2124}', '\u{2124}'), ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'), ('\u{212a}', '\u{2139}'), ('\u{213c}', '\u{213f}'), ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'), ('\u{2160}', '\u{2188}'), ('\u{2c00}', '\u{2ce4}'), ('\u{2ceb}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'), ('\u{2d27}', '\u{2d27}'), ('\u{2d2d}', '\u{2d2d}'), ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'), ('\u{2d7f}', '\u{2d96}'), ('\u{2da0}', '\u{2da6}'), ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'), ('\u{2db8}', '\u{2dbe}'), ('\u{2dc0}', '\u{2dc6}'), ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'), ('\u{2dd8}', '\u{2dde}'), ('\u{2de0}', '\u{2dff}'), ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302f}'), ('\u{3031}', '\u{3035}'), ('\u{3038}', '\u{303c}'), ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309a}'), ('\u{309d}', '\u{309f}'), ('\u{30a1}', '\u{30ff}'), ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'), ('\u{31a0}', '\u{31bf}'), ('\u{31f0}', '\u{31ff}'), ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'), ('\u{a4d0}', '\u{a4fd}'), ('\u{a500}', '\u{a60c}'), ('\u{a610}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66f}'), ('\u{a674}', '\u{a67d}'), ('\u{a67f}', '\u{a6f1}'), ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'), ('\u{a78b}', '\u{a7cd}'), ('\u{a7d0}', '\u{a7d1}'), ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7dc}'), ('\u{a7f2}', '\u{a827}'), ('\u{a82c}', '\u{a82c}'), ('\u{a840}', '\u{a873}'),
+    ('\u{a880}', '\u{a8c5}'), ('\u{a8d0}', '\u{a8d9}'), ('\u{a8e0}', '\u{a8f7}'), ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a92d}'), ('\u{a930}', '\u{a953}'), ('\u{a960}', '\u{a97c}'), ('\u{a980}', '\u{a9c0}'),
+    ('\u{a9cf}', '\u{a9d9}'), ('\u{a9e0}', '\u{a9fe}'), ('\u{aa00}', '\u{aa36}'), ('\u{aa40}', '\u{aa4d}'),
+    ('\u{aa50}', '\u{aa59}'), ('\u{aa60}', '\u{aa76}'), ('\u{aa7a}', '\u{aac2}'), ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaef}'), ('\u{aaf2}', '\u{aaf6}'), ('\u{ab01}', '\u{ab06}'), ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'), ('\u{ab20}', '\u{ab26}'), ('\u{ab28}', '\u{ab2e}'), ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'), ('\u{ab70}', '\u{abea}'), ('\u{abec}', '\u{abed}'), ('\u{abf0}', '\u{abf9}'),
+    ('\u{ac00}', '\u{d7a3}'), ('\u{d7b0}', '\u{d7c6}'), ('\u{d7cb}', '\u{d7fb}'), ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'), ('\u{fb00}', '\u{fb06}'), ('\u{fb13}', '\u{fb17}'), ('\u{fb1d}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'), ('\u{fb38}', '\u{fb3c}'), ('\u{fb3e}', '\u{fb3e}'), ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'), ('\u{fb46}', '\u{fbb1}'), ('\u{fbd3}', '\u{fc5d}'), ('\u{fc64}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'), ('\u{fd92}', '\u{fdc7}'), ('\u{fdf0}', '\u{fdf9}'), ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'), ('\u{fe33}', '\u{fe34}'), ('\u{fe4d}', '\u{fe4f}'), ('\u{fe71}', '\u{fe71}'),
+    ('\u{fe73}', '\u{fe73}'), ('\u{fe77}', '\u{fe77}'), ('\u{fe79}', '\u{fe79}'), ('\u{fe7b}', '\u{fe7b}'),
+    ('\u{fe7d}', '\u{fe7d}'), ('\u{fe7f}', '\u{fefc}'), ('\u{ff10}', '\u{ff19}'), ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff3f}', '\u{ff3f}'), ('\u{ff41}', '\u{ff5a}'), ('\u{ff65}', '\u{ffbe}'), ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'), ('\u{ffd2}', '\u{ffd7}'), ('\u{ffda}', '\u{ffdc}'), ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'), ('\u{10028}', '\u{1003a}'), ('\u{1003c}', '\u{1003d}'), ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'), ('\u{10080}', '\u{100fa}'), ('\u{10140}', '\u{10174}'), ('\u{101fd}', '\u{101fd}'),
+    ('\u{10280}', '\u{1029c}'), ('\u{102a0}', '\u{102d0}'), ('\u{102e0}', '\u{102e0}'), ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'), ('\u{10350}', '\u{1037a}'), ('\u{10380}', '\u{1039d}'), ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'), ('\u{103d1}', '\u{103d5}'), ('\u{10400}', '\u{1049d}'), ('\u{104a0}', '\u{104a9}'),
+    ('\u{104b0}', '\u{104d3}'), ('\u{104d8}', '\u{104fb}'), ('\u{10500}', '\u{10527}'), ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'), ('\u{1057c}', '\u{1058a}'), ('\u{1058c}', '\u{10592}'), ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'), ('\u{105a3}', '\u{105b1}'), ('\u{105b3}', '\u{105b9}'), ('\u{105bb}', '\u{105bc}'),
+    ('\u{105c0}', '\u{105f3}'), ('\u{10600}', '\u{10736}'), ('\u{10740}', '\u{10755}'), ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'), ('\u{10787}', '\u{107b0}'), ('\u{107b2}', '\u{107ba}'), ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'), ('\u{1080a}', '\u{10835}'), ('\u{10837}', '\u{10838}'), ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'), ('\u{10860}', '\u{10876}'), ('\u{10880}', '\u{1089e}'), ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'), ('\u{10900}', '\u{10915}'), ('\u{10920}', '\u{10939}'), ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'), ('\u{10a00}', '\u{10a03}'), ('\u{10a05}', '\u{10a06}'), ('\u{10a0c}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'), ('\u{10a19}', '\u{10a35}'), ('\u{10a38}', '\u{10a3a}'), ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10a60}', '\u{10a7c}'), ('\u{10a80}', '\u{10a9c}'), ('\u{10ac0}', '\u{10ac7}'), ('\u{10ac9}', '\u{10ae6}'),
+    ('\u{10b00}', '\u{10b35}'), ('\u{10b40}', '\u{10b55}'), ('\u{10b60}', '\u{10b72}'), ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'), ('\u{10c80}', '\u{10cb2}'), ('\u{10cc0}', '\u{10cf2}'), ('\u{10d00}', '\u{10d27}'),
+    ('\u{10d30}', '\u{10d39}'), ('\u{10d40}', '\u{10d65}'), ('\u{10d69}', '\u{10d6d}'), ('\u{10d6f}', '\u{10d85}'),
+    ('\u{10e80}', '\u{10ea9}'), ('\u{10eab}', '\u{10eac}'), ('\u{10eb0}', '\u{10eb1}'), ('\u{10ec2}', '\u{10ec4}'),
+    ('\u{10efc}', '\u{10f1c}'), ('\u{10f27}', '\u{10f27}'), ('\u{10f30}', '\u{10f50}'), ('\u{10f70}', '\u{10f85}'),
+    ('\u{10fb0}', '\u{10fc4}'), ('\u{10fe0}', '\u{10ff6}'), ('\u{11000}', '\u{11046}'), ('\u{11066}', '\u{11075}'),
+    ('\u{1107f}', '\u{110ba}'), ('\u{110c2}', '\u{110c2}'), ('\u{110d0}', '\u{110e8}'), ('\u{110f0}', '\u{110f9}'),
+    ('\u{11100}', '\u{11134}'), ('\u{11136}', '\u{1113f}'), ('\u{11144}', '\u{11147}'), ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'), ('\u{11180}', '\u{111c4}'), ('\u{111c9}', '\u{111cc}'), ('\u{111ce}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'), ('\u{11200}', '\u{11211}'), ('\u{11213}', '\u{11237}'), ('\u{1123e}', '\u{11241}'),
+    ('\u{11280}', '\u{11286}'), ('\u{11288}', '\u{11288}'), ('\u{1128a}', '\u{1128d}'), ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'), ('\u{112b0}', '\u{112ea}'), ('\u{112f0}', '\u{112f9}'), ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130c}'), ('\u{1130f}', '\u{11310}'), ('\u{11313}', '\u{11328}'), ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'), ('\u{11335}', '\u{11339}'), ('\u{1133b}', '\u{11344}'), ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'), ('\u{11350}', '\u{11350}'), ('\u{11357}', '\u{11357}'), ('\u{1135d}', '\u{11363}'),
+    ('\u{11366}', '\u{1136c}'), ('\u{11370}', '\u{11374}'), ('\u{11380}', '\u{11389}'), ('\u{1138b}', '\u{1138b}'),
+    ('\u{1138e}', '\u{1138e}'), ('\u{11390}', '\u{113b5}'), ('\u{113b7}', '\u{113c0}'), ('\u{113c2}', '\u{113c2}'),
+    ('\u{113c5}', '\u{113c5}'), ('\u{113c7}', '\u{113ca}'), ('\u{113cc}', '\u{113d3}'), ('\u{113e1}', '\u{113e2}'),
+    ('\u{11400}', '\u{1144a}'), ('\u{11450}', '\u{11459}'), ('\u{1145e}', '\u{11461}'), ('\u{11480}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'), ('\u{114d0}', '\u{114d9}'), ('\u{11580}', '\u{115b5}'), ('\u{115b8}', '\u{115c0}'),
+    ('\u{115d8}', '\u{115dd}'), ('\u{11600}', '\u{11640}'), ('\u{11644}', '\u{11644}'), ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116b8}'), ('\u{116c0}', '\u{116c9}'), ('\u{116d0}', '\u{116e3}'), ('\u{11700}', '\u{1171a}'),
+    ('\u{1171d}', '\u{1172b}'), ('\u{11730}', '\u{11739}'), ('\u{11740}', '\u{11746}'), ('\u{11800}', '\u{1183a}'),
+    ('\u{118a0}', '\u{118e9}'), ('\u{118ff}', '\u{11906}'), ('\u{11909}', '\u{11909}'), ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'), ('\u{11918}', '\u{11935}'), ('\u{11937}', '\u{11938}'), ('\u{1193b}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'), ('\u{119a0}', '\u{119a7}'), ('\u{119aa}', '\u{119d7}'), ('\u{119da}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e4}'), ('\u{11a00}', '\u{11a3e}'), ('\u{11a47}', '\u{11a47}'), ('\u{11a50}', '\u{11a99}'),
+    ('\u{11a9d}', '\u{11a9d}'), ('\u{11ab0}', '\u{11af8}'), ('\u{11bc0}', '\u{11be0}'), ('\u{11bf0}', '\u{11bf9}'),
+    ('\u{11c00}', '\u{11c08}'), ('\u{11c0a}', '\u{11c36}'), ('\u{11c38}', '\u{11c40}'), ('\u{11c50}', '\u{11c59}'),
+    ('\u{11c72}', '\u{11c8f}'), ('\u{11c92}', '\u{11ca7}'), ('\u{11ca9}', '\u{11cb6}'), ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'), ('\u{11d0b}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'), ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d47}'), ('\u{11d50}', '\u{11d59}'), ('\u{11d60}', '\u{11d65}'), ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d8e}'), ('\u{11d90}', '\u{11d91}'), ('\u{11d93}', '\u{11d98}'), ('\u{11da0}', '\u{11da9}'),
+    ('\u{11ee0}', '\u{11ef6}'), ('\u{11f00}', '\u{11f10}'), ('\u{11f12}', '\u{11f3a}'), ('\u{11f3e}', '\u{11f42}'),
+    ('\u{11f50}', '\u{11f5a}'), ('\u{11fb0}', '\u{11fb0}'), ('\u{12000}', '\u{12399}'), ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'), ('\u{12f90}', '\u{12ff0}'), ('\u{13000}', '\u{1342f}'), ('\u{13440}', '\u{13455}'),
+    ('\u{13460}', '\u{143fa}'), ('\u{14400}', '\u{14646}'), ('\u{16100}', '\u{16139}'), ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'), ('\u{16a60}', '\u{16a69}'), ('\u{16a70}', '\u{16abe}'), ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16ad0}', '\u{16aed}'), ('\u{16af0}', '\u{16af4}'), ('\u{16b00}', '\u{16b36}'), ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b50}', '\u{16b59}'), ('\u{16b63}', '\u{16b77}'), ('\u{16b7d}', '\u{16b8f}'), ('\u{16d40}', '\u{16d6c}'),
+    ('\u{16d70}', '\u{16d79}'), ('\u{16e40}', '\u{16e7f}'), ('\u{16f00}', '\u{16f4a}'), ('\u{16f4f}', '\u{16f87}'),
+    ('\u{16f8f}', '\u{16f9f}'), ('\u{16fe0}', '\u{16fe1}'), ('\u{16fe3}', '\u{16fe4}'), ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{17000}', '\u{187f7}'), ('\u{18800}', '\u{18cd5}'), ('\u{18cff}', '\u{18d08}'), ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'), ('\u{1affd}', '\u{1affe}'), ('\u{1b000}', '\u{1b122}'), ('\u{1b132}', '\u{1b132}'),
+    ('\u{1b150}', '\u{1b152}'), ('\u{1b155}', '\u{1b155}'), ('\u{1b164}', '\u{1b167}'), ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'), ('\u{1bc70}', '\u{1bc7c}'), ('\u{1bc80}', '\u{1bc88}'), ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1bc9d}', '\u{1bc9e}'), ('\u{1ccf0}', '\u{1ccf9}'), ('\u{1cf00}', '\u{1cf2d}'), ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'), ('\u{1d16d}', '\u{1d172}'), ('\u{1d17b}', '\u{1d182}'), ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'), ('\u{1d242}', '\u{1d244}'), ('\u{1d400}', '\u{1d454}'), ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'), ('\u{1d4a2}', '\u{1d4a2}'), ('\u{1d4a5}', '\u{1d4a6}'), ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'), ('\u{1d4bb}', '\u{1d4bb}'), ('\u{1d4bd}', '\u{1d4c3}'), ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'), ('\u{1d50d}', '\u{1d514}'), ('\u{1d516}', '\u{1d51c}'), ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'), ('\u{1d540}', '\u{1d544}'), ('\u{1d546}', '\u{1d546}'), ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'), ('\u{1d6a8}', '\u{1d6c0}'), ('\u{1d6c2}', '\u{1d6da}'), ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'), ('\u{1d716}', '\u{1d734}'), ('\u{1d736}', '\u{1d74e}'), ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'), ('\u{1d78a}', '\u{1d7a8}'), ('\u{1d7aa}', '\u{1d7c2}'), ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1d7ce}', '\u{1d7ff}'), ('\u{1da00}', '\u{1da36}'), ('\u{1da3b}', '\u{1da6c}'), ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'), ('\u{1da9b}', '\u{1da9f}'), ('\u{1daa1}', '\u{1daaf}'), ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1df25}', '\u{1df2a}'), ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'), ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'), ('\u{1e026}', '\u{1e02a}'), ('\u{1e030}', '\u{1e06d}'), ('\u{1e08f}', '\u{1e08f}'),
+    ('\u{1e100}', '\u{1e12c}'), ('\u{1e130}', '\u{1e13d}'), ('\u{1e140}', '\u{1e149}'), ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ae}'), ('\u{1e2c0}', '\u{1e2f9}'), ('\u{1e4d0}', '\u{1e4f9}'), ('\u{1e5d0}', '\u{1e5fa}'),
+    ('\u{1e7e0}', '\u{1e7e6}'), ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}', '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'), ('\u{1e8d0}', '\u{1e8d6}'), ('\u{1e900}', '\u{1e94b}'), ('\u{1e950}', '\u{1e959}'),
+    ('\u{1ee00}', '\u{1ee03}'), ('\u{1ee05}', '\u{1ee1f}'), ('\u{1ee21}', '\u{1ee22}'), ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'), ('\u{1ee29}', '\u{1ee32}'), ('\u{1ee34}', '\u{1ee37}'), ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'), ('\u{1ee42}', '\u{1ee42}'), ('\u{1ee47}', '\u{1ee47}'), ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'), ('\u{1ee4d}', '\u{1ee4f}'), ('\u{1ee51}', '\u{1ee52}'), ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'), ('\u{1ee59}', '\u{1ee59}'), ('\u{1ee5b}', '\u{1ee5b}'), ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'), ('\u{1ee61}', '\u{1ee62}'), ('\u{1ee64}', '\u{1ee64}'), ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'), ('\u{1ee74}', '\u{1ee77}'), ('\u{1ee79}', '\u{1ee7c}'), ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'), ('\u{1ee8b}', '\u{1ee9b}'), ('\u{1eea1}', '\u{1eea3}'), ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'), ('\u{1fbf0}', '\u{1fbf9}'), ('\u{20000}', '\u{2a6df}'), ('\u{2a700}', '\u{2b739}'),
+    ('\u{2b740}', '\u{2b81d}'), ('\u{2b820}', '\u{2cea1}'), ('\u{2ceb0}', '\u{2ebe0}'), ('\u{2ebf0}', '\u{2ee5d}'),
+    ('\u{2f800}', '\u{2fa1d}'), ('\u{30000}', '\u{3134a}'), ('\u{31350}', '\u{323af}'), ('\u{e0100}', '\u{e01ef}')
+];
+
+// The Canonical_Combining_Class of every character where it isn't 0
+pub const COMBINING_CLASS: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230), ('\u{315}', '\u{315}', 232), ('\u{316}', '\u{319}', 220),
+    ('\u{31a}', '\u{31a}', 232), ('\u{31b}', '\u{31b}', 216), ('\u{31c}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202), ('\u{323}', '\u{326}', 220), ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220), ('\u{334}', '\u{338}', 1), ('\u{339}', '\u{33c}', 220),
+    ('\u{33d}', '\u{344}', 230), ('\u{345}', '\u{345}', 240), ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220), ('\u{34a}', '\u{34c}', 230), ('\u{34d}', '\u{34e}', 220),
+    ('\u{350}', '\u{352}', 230), ('\u{353}', '\u{356}', 220), ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232), ('\u{359}', '\u{35a}', 220), ('\u{35b}', '\u{35b}', 230),
+    ('\u{35c}', '\u{35c}', 233), ('\u{35d}', '\u{35e}', 234), ('\u{35f}', '\u{35f}', 233),
+    ('\u{360}', '\u{361}', 234), ('\u{362}', '\u{362}', 233), ('\u{363}', '\u{36f}', 230),
+    ('\u{483}', '\u{487}', 230), ('\u{591}', '\u{591}', 220), ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220), ('\u{597}', '\u{599}', 230), ('\u{59a}', '\u{59a}', 222),
+    ('\u{59b}', '\u{59b}', 220), ('\u{59c}', '\u{5a1}', 230), ('\u{5a2}', '\u{5a7}', 220),
+    ('\u{5a8}', '\u{5a9}', 230), ('\u{5aa}', '\u{5aa}', 220), ('\u{5ab}', '\u{5ac}', 230),
+    ('\u{5ad}', '\u{5ad}', 222), ('\u{5ae}', '\u{5ae}', 228), ('\u{5af}', '\u{5af}', 230),
+    ('\u{5b0}', '\u{5b0}', 10), ('\u{5b1}', '\u{5b1}', 11), ('\u{5b2}', '\u{5b2}', 12),
+    ('\u{5b3}', '\u{5b3}', 13), ('\u{5b4}', '\u{5b4}', 14), ('\u{5b5}', '\u{5b5}', 15),
+    ('\u{5b6}', '\u{5b6}', 16), ('\u{5b7}', '\u{5b7}', 17), ('\u{5b8}', '\u{5b8}', 18),
+    ('\u{5b9}', '\u{5ba}', 19), ('\u{5bb}', '\u{5bb}', 20), ('\u{5bc}', '\u{5bc}', 21),
+    ('\u{5bd}', '\u{5bd}', 22), ('\u{5bf}', '\u{5bf}', 23), ('\u{5c1}', '\u{5c1}', 24),
+    ('\u{5c2}', '\u{5c2}', 25), ('\u{5c4}', '\u{5c4}', 230), ('\u{5c5}', '\u{5c5}', 220),
+    ('\u{5c7}', '\u{5c7}', 18), ('\u{610}', '\u{617}', 230), ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31), ('\u{61a}', '\u{61a}', 32), ('\u{64b}', '\u{64b}', 27),
+    ('\u{64c}', '\u{64c}', 28), ('\u{64d}', '\u{64d}', 29), ('\u{64e}', '\u{64e}', 30),
+    ('\u{64f}', '\u{64f}', 31), ('\u{650}', '\u{650}', 32), ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34), ('\u{653}', '\u{654}', 230), ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65b}', 230), ('\u{65c}', '\u{65c}', 220), ('\u{65d}', '\u{65e}', 230),
+    ('\u{65f}', '\u{65f}', 220), ('\u{670}', '\u{670}', 35), ('\u{6d6}', '\u{6dc}', 230),
+    ('\u{6df}', '\u{6e2}', 230), ('\u{6e3}', '\u{6e3}', 220), ('\u{6e4}', '\u{6e4}', 230),
+    ('\u{6e7}', '\u{6e8}', 230), ('\u{6ea}', '\u{6ea}', 220), ('\u{6eb}', '\u{6ec}', 230),
+    ('\u{6ed}', '\u{6ed}', 220), ('\u{711}', '\u{711}', 36), ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220), ('\u{732}', '\u{733}', 230), ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230), ('\u{737}', '\u{739}', 220), ('\u{73a}', '\u{73a}', 230),
+    ('\u{73b}', '\u{73c}', 220), ('\u{73d}', '\u{73d}', 230), ('\u{73e}', '\u{73e}', 220),
+    ('\u{73f}', '\u{741}', 230), ('\u{742}', '\u{742}', 220), ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220), ('\u{745}', '\u{745}', 230), ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230), ('\u{748}', '\u{748}', 220), ('\u{749}', '\u{74a}', 230),
+    ('\u{7eb}', '\u{7f1}', 230), ('\u{7f2}', '\u{7f2}', 220), ('\u{7f3}', '\u{7f3}', 230),
+    ('\u{7fd}', '\u{7fd}', 220), ('\u{816}', '\u{819}', 230), ('\u{81b}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230), ('\u{829}', '\u{82d}', 230), ('\u{859}', '\u{85b}', 220),
+    ('\u{897}', '\u{898}', 230), ('\u{899}', '\u{89b}', 220), ('\u{89c}', '\u{89f}', 230),
+    ('\u{8ca}', '\u{8ce}', 230), ('\u{8cf}', '\u{8d3}', 220), ('\u{8d4}', '\u{8e1}', 230),
+    ('\u{8e3}', '\u{8e3}', 220), ('\u{8e4}', '\u{8e5}', 230), ('\u{8e6}', '\u{8e6}', 220),
+    ('\u{8e7}', '\u{8e8}', 230), ('\u{8e9}', '\u{8e9}', 220), ('\u{8ea}', '\u{8ec}', 230),
+    ('\u{8ed}', '\u{8ef}', 220), ('\u{8f0}', '\u{8f0}', 27), ('\u{8f1}', '\u{8f1}', 28),
+    ('\u{8f2}', '\u{8f2}', 29), ('\u{8f3}', '\u{8f5}', 230), ('\u{8f6}', '\u{8f6}', 220),
+    ('\u{8f7}', '\u{8f8}', 230), ('\u{8f9}', '\u{8fa}', 220), ('\u{8fb}', '\u{8ff}', 230),
+    ('\u{93c}', '\u{93c}', 7), ('\u{94d}', '\u{94d}', 9), ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220), ('\u{953}', '\u{954}', 230), ('\u{9bc}', '\u{9bc}', 7),
+    ('\u{9cd}', '\u{9cd}', 9), ('\u{9fe}', '\u{9fe}', 230), ('\u{a3c}', '\u{a3c}', 7),
+    ('\u{a4d}', '\u{a4d}', 9), ('\u{abc}', '\u{abc}', 7), ('\u{acd}', '\u{acd}', 9),
+    ('\u{b3c}', '\u{b3c}', 7), ('\u{b4d}', '\u{b4d}', 9), ('\u{bcd}', '\u{bcd}', 9),
+    ('\u{c3c}', '\u{c3c}', 7), ('\u{c4d}', '\u{c4d}', 9), ('\u{c55}', '\u{c55}', 84),
+    ('\u{c56}', '\u{c56}', 91), ('\u{cbc}', '\u{cbc}', 7), ('\u{ccd}', '\u{ccd}', 9),
+    ('\u{d3b}', '\u{d3c}', 9), ('\u{d4d}', '\u{d4d}', 9), ('\u{dca}', '\u{dca}', 9),
+    ('\u{e38}', '\u{e39}', 103), ('\u{e3a}', '\u{e3a}', 9), ('\u{e48}', '\u{e4b}', 107),
+    ('\u{eb8}', '\u{eb9}', 118), ('\u{eba}', '\u{eba}', 9), ('\u{ec8}', '\u{ecb}', 122),
+    ('\u{f18}', '\u{f19}', 220), ('\u{f35}', '\u{f35}', 220), ('\u{f37}', '\u{f37}', 220),
+    ('\u{f39}', '\u{f39}', 216), ('\u{f71}', '\u{f71}', 129), ('\u{f72}', '\u{f72}', 130),
+    ('\u{f74}', '\u{f74}', 132), ('\u{f7a}', '\u{f7d}', 130), ('\u{f80}', '\u{f80}', 130),
+    ('\u{f82}', '\u{f83}', 230), ('\u{f84}', '\u{f84}', 9), ('\u{f86}', '\u{f87}', 230),
+    ('\u{fc6}', '\u{fc6}', 220), ('\u{1037}', '\u{1037}', 7), ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220), ('\u{135d}', '\u{135f}', 230), ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9), ('\u{17d2}', '\u{17d2}', 9), ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228), ('\u{1939}', '\u{1939}', 222), ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220), ('\u{1a17}', '\u{1a17}', 230), ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9), ('\u{1a75}', '\u{1a7c}', 230), ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230), ('\u{1ab5}', '\u{1aba}', 220), ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220), ('\u{1abf}', '\u{1ac0}', 220), ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220), ('\u{1ac5}', '\u{1ac9}', 230), ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230), ('\u{1b34}', '\u{1b34}', 7), ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230), ('\u{1b6c}', '\u{1b6c}', 220), ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9), ('\u{1be6}', '\u{1be6}', 7), ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7), ('\u{1cd0}', '\u{1cd2}', 230), ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220), ('\u{1cda}', '\u{1cdb}', 230), ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230), ('\u{1ce2}', '\u{1ce8}', 1), ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230), ('\u{1cf8}', '\u{1cf9}', 230), ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220), ('\u{1dc3}', '\u{1dc9}', 230), ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230), ('\u{1dcd}', '\u{1dcd}', 234), ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220), ('\u{1dd0}', '\u{1dd0}', 202), ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232), ('\u{1df7}', '\u{1df8}', 228), ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218), ('\u{1dfb}', '\u{1dfb}', 230), ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220), ('\u{1dfe}', '\u{1dfe}', 230), ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230), ('\u{20d2}', '\u{20d3}', 1), ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1), ('\u{20db}', '\u{20dc}', 230), ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1), ('\u{20e7}', '\u{20e7}', 230), ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230), ('\u{20ea}', '\u{20eb}', 1), ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230), ('\u{2cef}', '\u{2cf1}', 230), ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230), ('\u{302a}', '\u{302a}', 218), ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232), ('\u{302d}', '\u{302d}', 222), ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8), ('\u{a66f}', '\u{a66f}', 230), ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230), ('\u{a6f0}', '\u{a6f1}', 230), ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9), ('\u{a8c4}', '\u{a8c4}', 9), ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220), ('\u{a953}', '\u{a953}', 9), ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9), ('\u{aab0}', '\u{aab0}', 230), ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220), ('\u{aab7}', '\u{aab8}', 230), ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230), ('\u{aaf6}', '\u{aaf6}', 9), ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26), ('\u{fe20}', '\u{fe26}', 230), ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230), ('\u{101fd}', '\u{101fd}', 220), ('\u{102e0}', '\u{102e0}', 220),
+    ('\u{10376}', '\u{1037a}', 230), ('\u{10a0d}', '\u{10a0d}', 220), ('\u{10a0f}', '\u{10a0f}', 230),
+    ('\u{10a38}', '\u{10a38}', 230), ('\u{10a39}', '\u{10a39}', 1), ('\u{10a3a}', '\u{10a3a}', 220),
+    ('\u{10a3f}', '\u{10a3f}', 9), ('\u{10ae5}', '\u{10ae5}', 230), ('\u{10ae6}', '\u{10ae6}', 220),
+    ('\u{10d24}', '\u{10d27}', 230), ('\u{10d69}', '\u{10d6d}', 230), ('\u{10eab}', '\u{10eac}', 230),
+    ('\u{10efd}', '\u{10eff}', 220), ('\u{10f46}', '\u{10f47}', 220), ('\u{10f48}', '\u{10f4a}', 230),
+    ('\u{10f4b}', '\u{10f4b}', 220), ('\u{10f4c}', '\u{10f4c}', 230), ('\u{10f4d}', '\u{10f50}', 220),
+    ('\u{10f82}', '\u{10f82}', 230), ('\u{10f83}', '\u{10f83}', 220), ('\u{10f84}', '\u{10f84}', 230),
+    ('\u{10f85}', '\u{10f85}', 220), ('\u{11046}', '\u{11046}', 9), ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107f}', '\u{1107f}', 9), ('\u{110b9}', '\u{110b9}', 9), ('\u{110ba}', '\u{110ba}', 7),
+    ('\u{11100}', '\u{11102}', 230), ('\u{11133}', '\u{11134}', 9), ('\u{11173}', '\u{11173}', 7),
+    ('\u{111c0}', '\u{111c0}', 9), ('\u{111ca}', '\u{111ca}', 7), ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7), ('\u{112e9}', '\u{112e9}', 7), ('\u{112ea}', '\u{112ea}', 9),
+    ('\u{1133b}', '\u{1133c}', 7), ('\u{1134d}', '\u{1134d}', 9), ('\u{11366}', '\u{1136c}', 230),
+    ('\u{11370}', '\u{11374}', 230), ('\u{113ce}', '\u{113d0}', 9), ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7), ('\u{1145e}', '\u{1145e}', 230), ('\u{114c2}', '\u{114c2}', 9),
+    ('\u{114c3}', '\u{114c3}', 7), ('\u{115bf}', '\u{115bf}', 9), ('\u{115c0}', '\u{115c0}', 7),
+    ('\u{1163f}', '\u{1163f}', 9), ('\u{116b6}', '\u{116b6}', 9), ('\u{116b7}', '\u{116b7}', 7),
+    ('\u{1172b}', '\u{1172b}', 9), ('\u{11839}', '\u{11839}', 9), ('\u{1183a}', '\u{1183a}', 7),
+    ('\u{1193d}', '\u{1193e}', 9), ('\u{11943}', '\u{11943}', 7), ('\u{119e0}', '\u{119e0}', 9),
+    ('\u{11a34}', '\u{11a34}', 9), ('\u{11a47}', '\u{11a47}', 9), ('\u{11a99}', '\u{11a99}', 9),
+    ('\u{11c3f}', '\u{11c3f}', 9), ('\u{11d42}', '\u{11d42}', 7), ('\u{11d44}', '\u{11d45}', 9),
+    ('\u{11d97}', '\u{11d97}', 9), ('\u{11f41}', '\u{11f42}', 9), ('\u{1612f}', '\u{1612f}', 9),
+    ('\u{16af0}', '\u{16af4}', 1), ('\u{16b30}', '\u{16b36}', 230), ('\u{16ff0}', '\u{16ff1}', 6),
+    ('\u{1bc9e}', '\u{1bc9e}', 1), ('\u{1d165}', '\u{1d166}', 216), ('\u{1d167}', '\u{1d169}', 1),
+    ('\u{1d16d}', '\u{1d16d}', 226), ('\u{1d16e}', '\u{1d172}', 216), ('\u{1d17b}', '\u{1d182}', 220),
+    ('\u{1d185}', '\u{1d189}', 230), ('\u{1d18a}', '\u{1d18b}', 220), ('\u{1d1aa}', '\u{1d1ad}', 230),
+    ('\u{1d242}', '\u{1d244}', 230), ('\u{1e000}', '\u{1e006}', 230), ('\u{1e008}', '\u{1e018}', 230),
+    ('\u{1e01b}', '\u{1e021}', 230), ('\u{1e023}', '\u{1e024}', 230), ('\u{1e026}', '\u{1e02a}', 230),
+    ('\u{1e08f}', '\u{1e08f}', 230), ('\u{1e130}', '\u{1e136}', 230), ('\u{1e2ae}', '\u{1e2ae}', 230),
+    ('\u{1e2ec}', '\u{1e2ef}', 230), ('\u{1e4ec}', '\u{1e4ed}', 232), ('\u{1e4ee}', '\u{1e4ee}', 220),
+    ('\u{1e4ef}', '\u{1e4ef}', 230), ('\u{1e5ee}', '\u{1e5ee}', 230), ('\u{1e5ef}', '\u{1e5ef}', 220),
+    ('\u{1e8d0}', '\u{1e8d6}', 220), ('\u{1e944}', '\u{1e949}', 230), ('\u{1e94a}', '\u{1e94a}', 7)
+];
+
+// The full canonical decomposition of every character that has one,
+// apart from Hangul syllables, which are decomposed by an algorithm
+pub const DECOMPOSITION: &[(char, &str)] = &[
+    ('\u{c0}', "A\u{300}"), ('\u{c1}', "A\u{301}"), ('\u{c2}', "A\u{302}"),
+    ('\u{c3}', "A\u{303}"), ('\u{c4}', "A\u{308}"), ('\u{c5}', "A\u{30a}"),
+    ('\u{c7}', "C\u{327}"), ('\u{c8}', "E\u{300}"), ('\u{c9}', "E\u{301}"),
+    ('\u{ca}', "E\u{302}"), ('\u{cb}', "E\u{308}"), ('\u{cc}', "I\u{300}"),
+    ('\u{cd}', "I\u{301}"), ('\u{ce}', "I\u{302}"), ('\u{cf}', "I\u{308}"),
+    ('\u{d1}', "N\u{303}"), ('\u{d2}', "O\u{300}"), ('\u{d3}', "O\u{301}"),
+    ('\u{d4}', "O\u{302}"), ('\u{d5}', "O\u{303}"), ('\u{d6}', "O\u{308}"),
+    ('\u{d9}', "U\u{300}"), ('\u{da}', "U\u{301}"), ('\u{db}', "U\u{302}"),
+    ('\u{dc}', "U\u{308}"), ('\u{dd}', "Y\u{301}"), ('\u{e0}', "a\u{300}"),
+    ('\u{e1}', "a\u{301}"), ('\u{e2}', "a\u{302}"), ('\u{e3}', "a\u{303}"),
+    ('\u{e4}', "a\u{308}"), ('\u{e5}', "a\u{30a}"), ('\u{e7}', "c\u{327}"),
+    ('\u{e8}', "e\u{300}"), ('\u{e9}', "e\u{301}"), ('\u{ea}', "e\u{302}"),
+    ('\u{eb}', "e\u{308}"), ('\u{ec}', "i\u{300}"), ('\u{ed}', "i\u{301}"),
+    ('\u{ee}', "i\u{302}"), ('\u{ef}', "i\u{308}"), ('\u{f1}', "n\u{303}"),
+    ('\u{f2}', "o\u{300}"), ('\u{f3}', "o\u{301}"), ('\u{f4}', "o\u{302}"),
+    ('\u{f5}', "o\u{303}"), ('\u{f6}', "o\u{308}"), ('\u{f9}', "u\u{300}"),
+    ('\u{fa}', "u\u{301}"), ('\u{fb}', "u\u{302}"), ('\u{fc}', "u\u{308}"),
+    ('\u{fd}', "y\u{301}"), ('\u{ff}', "y\u{308}"), ('\u{100}', "A\u{304}"),
+    ('\u{101}', "a\u{304}"), ('\u{102}', "A\u{306}"), ('\u{103}', "a\u{306}"),
+    ('\u{104}', "A\u{328}"), ('\u{105}', "a\u{328}"), ('\u{106}', "C\u{301}"),
+    ('\u{107}', "c\u{301}"), ('\u{108}', "C\u{302}"), ('\u{109}', "c\u{302}"),
+    ('\u{10a}', "C\u{307}"), ('\u{10b}', "c\u{307}"), ('\u{10c}', "C\u{30c}"),
+    ('\u{10d}', "c\u{30c}"), ('\u{10e}', "D\u{30c}"), ('\u{10f}', "d\u{30c}"),
+    ('\u{112}', "E\u{304}"), ('\u{113}', "e\u{304}"), ('\u{114}', "E\u{306}"),
+    ('\u{115}', "e\u{306}"), ('\u{116}', "E\u{307}"), ('\u{117}', "e\u{307}"),
+    ('\u{118}', "E\u{328}"), ('\u{119}', "e\u{328}"), ('\u{11a}', "E\u{30c}"),
+    ('\u{11b}', "e\u{30c}"), ('\u{11c}', "G\u{302}"), ('\u{11d}', "g\u{302}"),
+    ('\u{11e}', "G\u{306}"), ('\u{11f}', "g\u{306}"), ('\u{120}', "G\u{307}"),
+    ('\u{121}', "g\u{307}"), ('\u{122}', "G\u{327}"), ('\u{123}', "g\u{327}"),
+    ('\u{124}', "H\u{302}"), ('\u{125}', "h\u{302}"), ('\u{128}', "I\u{303}"),
+    ('\u{129}', "i\u{303}"), ('\u{12a}', "I\u{304}"), ('\u{12b}', "i\u{304}"),
+    ('\u{12c}', "I\u{306}"), ('\u{12d}', "i\u{306}"), ('\u{12e}', "I\u{328}"),
+    ('\u{12f}', "i\u{328}"), ('\u{130}', "I\u{307}"), ('\u{134}', "J\u{302}"),
+    ('\u{135}', "j\u{302}"), ('\u{136}', "K\u{327}"), ('\u{137}', "k\u{327}"),
+    ('\u{139}', "L\u{301}"), ('\u{13a}', "l\u{301}"), ('\u{13b}', "L\u{327}"),
+    ('\u{13c}', "l\u{327}"), ('\u{13d}', "L\u{30c}"), ('\u{13e}', "l\u{30c}"),
+    ('\u{143}', "N\u{301}"), ('\u{144}', "n\u{301}"), ('\u{145}', "N\u{327}"),
+    ('\u{146}', "n\u{327}"), ('\u{147}', "N\u{30c}"), ('\u{148}', "n\u{30c}"),
+    ('\u{14c}', "O\u{304}"), ('\u{14d}', "o\u{304}"), ('\u{14e}', "O\u{306}"),
+    ('\u{14f}', "o\u{306}"), ('\u{150}', "O\u{30b}"), ('\u{151}', "o\u{30b}"),
+    ('\u{154}', "R\u{301}"), ('\u{155}', "r\u{301}"), ('\u{156}', "R\u{327}"),
+    ('\u{157}', "r\u{327}"), ('\u{158}', "R\u{30c}"), ('\u{159}', "r\u{30c}"),
+    ('\u{15a}', "S\u{301}"), ('\u{15b}', "s\u{301}"), ('\u{15c}', "S\u{302}"),
+    ('\u{15d}', "s\u{302}"), ('\u{15e}', "S\u{327}"), ('\u{15f}', "s\u{327}"),
+    ('\u{160}', "S\u{30c}"), ('\u{161}', "s\u{30c}"), ('\u{162}', "T\u{327}"),
+    ('\u{163}', "t\u{327}"), ('\u{164}', "T\u{30c}"), ('\u{165}', "t\u{30c}"),
+    ('\u{168}', "U\u{303}"), ('\u{169}', "u\u{303}"), ('\u{16a}', "U\u{304}"),
+    ('\u{16b}', "u\u{304}"), ('\u{16c}', "U\u{306}"), ('\u{16d}', "u\u{306}"),
+    ('\u{16e}', "U\u{30a}"), ('\u{16f}', "u\u{30a}"), ('\u{170}', "U\u{30b}"),
+    ('\u{171}', "u\u{30b}"), ('\u{172}', "U\u{328}"), ('\u{173}', "u\u{328}"),
+    ('\u{174}', "W\u{302}"), ('\u{175}', "w\u{302}"), ('\u{176}', "Y\u{302}"),
+    ('\u{177}', "y\u{302}"), ('\u{178}', "Y\u{308}"), ('\u{179}', "Z\u{301}"),
+    ('\u{17a}', "z\u{301}"), ('\u{17b}', "Z\u{307}"), ('\u{17c}', "z\u{307}"),
+    ('\u{17d}', "Z\u{30c}"), ('\u{17e}', "z\u{30c}"), ('\u{1a0}', "O\u{31b}"),
+    ('\u{1a1}', "o\u{31b}"), ('\u{1af}', "U\u{31b}"), ('\u{1b0}', "u\u{31b}"),
+    ('\u{1cd}', "A\u{30c}"), ('\u{1ce}', "a\u{30c}"), ('\u{1cf}', "I\u{30c}"),
+    ('\u{1d0}', "i\u{30c}"), ('\u{1d1}', "O\u{30c}"), ('\u{1d2}', "o\u{30c}"),
+    ('\u{1d3}', "U\u{30c}"), ('\u{1d4}', "u\u{30c}"), ('\u{1d5}', "U\u{308}\u{304}"),
+    ('\u{1d6}', "u\u{308}\u{304}"), ('\u{1d7}', "U\u{308}\u{301}"), ('\u{1d8}', "u\u{308}\u{301}"),
+    ('\u{1d9}', "U\u{308}\u{30c}"), ('\u{1da}', "u\u{308}\u{30c}"), ('\u{1db}', "U\u{308}\u{300}"),
+    ('\u{1dc}', "u\u{308}\u{300}"), ('\u{1de}', "A\u{308}\u{304}"), ('\u{1df}', "a\u{308}\u{304}"),
+    ('\u{1e0}', "A\u{307}\u{304}"), ('\u{1e1}', "a\u{307}\u{304}"), ('\u{1e2}', "\u{c6}\u{304}"),
+    ('\u{1e3}', "\u{e6}\u{304}"), ('\u{1e6}', "G\u{30c}"), ('\u{1e7}', "g\u{30c}"),
+    ('\u{1e8}', "K\u{30c}"), ('\u{1e9}', "k\u{30c}"), ('\u{1ea}', "O\u{328}"),
+    ('\u{1eb}', "o\u{328}"), ('\u{1ec}', "O\u{328}\u{304}"), ('\u{1ed}', "o\u{328}\u{304}"),
+    ('\u{1ee}', "\u{1b7}\u{30c}"), ('\u{1ef}', "\u{292}\u{30c}"), ('\u{1f0}', "j\u{30c}"),
+    ('\u{1f4}', "G\u{301}"), ('\u{1f5}', "g\u{301}"), ('\u{1f8}', "N\u{300}"),
+    ('\u{1f9}', "n\u{300}"), ('\u{1fa}', "A\u{30a}\u{301}"), ('\u{1fb}', "a\u{30a}\u{301}"),
+    ('\u{1fc}', "\u{c6}\u{301}"), ('\u{1fd}', "\u{e6}\u{301}"), ('\u{1fe}', "\u{d8}\u{301}"),
+    ('\u{1ff}', "\u{f8}\u{301}"), ('\u{200}', "A\u{30f}"), ('\u{201}', "a\u{30f}"),
+    ('\u{202}', "A\u{311}"), ('\u{203}', "a\u{311}"), ('\u{204}', "E\u{30f}"),
+    ('\u{205}', "e\u{30f}"), ('\u{206}', "E\u{311}"), ('\u{207}', "e\u{311}"),
+    ('\u{208}', "I\u{30f}"), ('\u{209}', "i\u{30f}"), ('\u{20a}', "I\u{311}"),
+    ('\u{20b}', "i\u{311}"), ('\u{20c}', "O\u{30f}"), ('\u{20d}', "o\u{30f}"),
+    ('\u{20e}', "O\u{311}"), ('\u{20f}', "o\u{311}"), ('\u{210}', "R\u{30f}"),
+    ('\u{211}', "r\u{30f}"), ('\u{212}', "R\u{311}"), ('\u{213}', "r\u{311}"),
+    ('\u{214}', "U\u{30f}"), ('\u{215}', "u\u{30f}"), ('\u{216}', "U\u{311}"),
+    ('\u{217}', "u\u{311}"), ('\u{218}', "S\u{326}"), ('\u{219}', "s\u{326}"),
+    ('\u{21a}', "T\u{326}"), ('\u{21b}', "t\u{326}"), ('\u{21e}', "H\u{30c}"),
+    ('\u{21f}', "h\u{30c}"), ('\u{226}', "A\u{307}"), ('\u{227}', "a\u{307}"),
+    ('\u{228}', "E\u{327}"), ('\u{229}', "e\u{327}"), ('\u{22a}', "O\u{308}\u{304}"),
+    ('\u{22b}', "o\u{308}\u{304}"), ('\u{22c}', "O\u{303}\u{304}"), ('\u{22d}', "o\u{303}\u{304}"),
+    ('\u{22e}', "O\u{307}"), ('\u{22f}', "o\u{307}"), ('\u{230}', "O\u{307}\u{304}"),
+    ('\u{231}', "o\u{307}\u{304}"), ('\u{232}', "Y\u{304}"), ('\u{233}', "y\u{304}"),
+    ('\u{340}', "\u{300}"), ('\u{341}', "\u{301}"), ('\u{343}', "\u{313}"),
+    ('\u{344}', "\u{308}\u{301}"), ('\u{374}', "\u{2b9}"), ('\u{37e}', "\u{3b}"),
+    ('\u{385}', "\u{a8}\u{301}"), ('\u{386}', "\u{391}\u{301}"), ('\u{387}', "\u{b7}"),
+    ('\u{388}', "\u{395}\u{301}"), ('\u{389}', "\u{397}\u{301}"), ('\u{38a}', "\u{399}\u{301}"),
+    ('\u{38c}', "\u{39f}\u{301}"), ('\u{38e}', "\u{3a5}\u{301}"), ('\u{38f}', "\u{3a9}\u{301}"),
+    ('\u{390}', "\u{3b9}\u{308}\u{301}"), ('\u{3aa}', "\u{399}\u{308}"), ('\u{3ab}', "\u{3a5}\u{308}"),
+    ('\u{3ac}', "\u{3b1}\u{301}"), ('\u{3ad}', "\u{3b5}\u{301}"), ('\u{3ae}', "\u{3b7}\u{301}"),
+    ('\u{3af}', "\u{3b9}\u{301}"), ('\u{3b0}', "\u{3c5}\u{308}\u{301}"), ('\u{3ca}', "\u{3b9}\u{308}"),
+    ('\u{3cb}', "\u{3c5}\u{308}"), ('\u{3cc}', "\u{3bf}\u{301}"), ('\u{3cd}', "\u{3c5}\u{301}"),
+    ('\u{3ce}', "\u{3c9}\u{301}"), ('\u{3d3}', "\u{3d2}\u{301}"), ('\u{3d4}', "\u{3d2}\u{308}"),
+    ('\u{400}', "\u{415}\u{300}"), ('\u{401}', "\u{415}\u{308}"), ('\u{403}', "\u{413}\u{301}"),
+    ('\u{407}', "\u{406}\u{308}"), ('\u{40c}', "\u{41a}\u{301}"), ('\u{40d}', "\u{418}\u{300}"),
+    ('\u{40e}', "\u{423}\u{306}"), ('\u{419}', "\u{418}\u{306}"), ('\u{439}', "\u{438}\u{306}"),
+    ('\u{450}', "\u{435}\u{300}"), ('\u{451}', "\u{435}\u{308}"), ('\u{453}', "\u{433}\u{301}"),
+    ('\u{457}', "\u{456}\u{308}"), ('\u{45c}', "\u{43a}\u{301}"), ('\u{45d}', "\u{438}\u{300}"),
+    ('\u{45e}', "\u{443}\u{306}"), ('\u{476}', "\u{474}\u{30f}"), ('\u{477}', "\u{475}\u{30f}"),
+    ('\u{4c1}', "\u{416}\u{306}"), ('\u{4c2}', "\u{436}\u{306}"), ('\u{4d0}', "\u{410}\u{306}"),
+    ('\u{4d1}', "\u{430}\u{306}"), ('\u{4d2}', "\u{410}\u{308}"), ('\u{4d3}', "\u{430}\u{308}"),
+    ('\u{4d6}', "\u{415}\u{306}"), ('\u{4d7}', "\u{435}\u{306}"), ('\u{4da}', "\u{4d8}\u{308}"),
+    ('\u{4db}', "\u{4d9}\u{308}"), ('\u{4dc}', "\u{416}\u{308}"), ('\u{4dd}', "\u{436}\u{308}"),
+    ('\u{4de}', "\u{417}\u{308}"), ('\u{4df}', "\u{437}\u{308}"), ('\u{4e2}', "\u{418}\u{304}"),
+    ('\u{4e3}', "\u{438}\u{304}"), ('\u{4e4}', "\u{418}\u{308}"), ('\u{4e5}', "\u{438}\u{308}"),
+    ('\u{4e6}', "\u{41e}\u{308}"), ('\u{4e7}', "\u{43e}\u{308}"), ('\u{4ea}', "\u{4e8}\u{308}"),
+    ('\u{4eb}', "\u{4e9}\u{308}"), ('\u{4ec}', "\u{42d}\u{308}"), ('\u{4ed}', "\u{44d}\u{308}"),
+    ('\u{4ee}', "\u{423}\u{304}"), ('\u{4ef}', "\u{443}\u{304}"), ('\u{4f0}', "\u{423}\u{308}"),
+    ('\u{4f1}', "\u{443}\u{308}"), ('\u{4f2}', "\u{423}\u{30b}"), ('\u{4f3}', "\u{443}\u{30b}"),
+    ('\u{4f4}', "\u{427}\u{308}"), ('\u{4f5}', "\u{447}\u{308}"), ('\u{4f8}', "\u{42b}\u{308}"),
+    ('\u{4f9}', "\u{44b}\u{308}"), ('\u{622}', "\u{627}\u{653}"), ('\u{623}', "\u{627}\u{654}"),
+    ('\u{624}', "\u{648}\u{654}"), ('\u{625}', "\u{627}\u{655}"), ('\u{626}', "\u{64a}\u{654}"),
+    ('\u{6c0}', "\u{6d5}\u{654}"), ('\u{6c2}', "\u{6c1}\u{654}"), ('\u{6d3}', "\u{6d2}\u{654}"),
+    ('\u{929}', "\u{928}\u{93c}"), ('\u{931}', "\u{930}\u{93c}"), ('\u{934}', "\u{933}\u{93c}"),
+    ('\u{958}', "\u{915}\u{93c}"), ('\u{959}', "\u{916}\u{93c}"), ('\u{95a}', "\u{917}\u{93c}"),
+    ('\u{95b}', "\u{91c}\u{93c}"), ('\u{95c}', "\u{921}\u{93c}"), ('\u{95d}', "\u{922}\u{93c}"),
+    ('\u{95e}', "\u{92b}\u{93c}"), ('\u{95f}', "\u{92f}\u{93c}"), ('\u{9cb}', "\u{9c7}\u{9be}"),
+    ('\u{9cc}', "\u{9c7}\u{9d7}"), ('\u{9dc}', "\u{9a1}\u{9bc}"), ('\u{9dd}', "\u{9a2}\u{9bc}"),
+    ('\u{9df}', "\u{9af}\u{9bc}"), ('\u{a33}', "\u{a32}\u{a3c}"), ('\u{a36}', "\u{a38}\u{a3c}"),
+    ('\u{a59}', "\u{a16}\u{a3c}"), ('\u{a5a}', "\u{a17}\u{a3c}"), ('\u{a5b}', "\u{a1c}\u{a3c}"),
+    ('\u{a5e}', "\u{a2b}\u{a3c}"), ('\u{b48}', "\u{b47}\u{b56}"), ('\u{b4b}', "\u{b47}\u{b3e}"),
+    ('\u{b4c}', "\u{b47}\u{b57}"), ('\u{b5c}', "\u{b21}\u{b3c}"), ('\u{b5d}', "\u{b22}\u{b3c}"),
+    ('\u{b94}', "\u{b92}\u{bd7}"), ('\u{bca}', "\u{bc6}\u{bbe}"), ('\u{bcb}', "\u{bc7}\u{bbe}"),
+    ('\u{bcc}', "\u{bc6}\u{bd7}"), ('\u{c48}', "\u{c46}\u{c56}"), ('\u{cc0}', "\u{cbf}\u{cd5}"),
+    ('\u{cc7}', "\u{cc6}\u{cd5}"), ('\u{cc8}', "\u{cc6}\u{cd6}"), ('\u{cca}', "\u{cc6}\u{cc2}"),
+    ('\u{ccb}', "\u{cc6}\u{cc2}\u{cd5}"), ('\u{d4a}', "\u{d46}\u{d3e}"), ('\u{d4b}', "\u{d47}\u{d3e}"),
+    ('\u{d4c}', "\u{d46}\u{d57}"), ('\u{dda}', "\u{dd9}\u{dca}"), ('\u{ddc}', "\u{dd9}\u{dcf}"),
+    ('\u{ddd}', "\u{dd9}\u{dcf}\u{dca}"), ('\u{dde}', "\u{dd9}\u{ddf}"), ('\u{f43}', "\u{f42}\u{fb7}"),
+    ('\u{f4d}', "\u{f4c}\u{fb7}"), ('\u{f52}', "\u{f51}\u{fb7}"), ('\u{f57}', "\u{f56}\u{fb7}"),
+    ('\u{f5c}', "\u{f5b}\u{fb7}"), ('\u{f69}', "\u{f40}\u{fb5}"), ('\u{f73}', "\u{f71}\u{f72}"),
+    ('\u{f75}', "\u{f71}\u{f74}"), ('\u{f76}', "\u{fb2}\u{f80}"), ('\u{f78}', "\u{fb3}\u{f80}"),
+    ('\u{f81}', "\u{f71}\u{f80}"), ('\u{f93}', "\u{f92}\u{fb7}"), ('\u{f9d}', "\u{f9c}\u{fb7}"),
+    ('\u{fa2}', "\u{fa1}\u{fb7}"), ('\u{fa7}', "\u{fa6}\u{fb7}"), ('\u{fac}', "\u{fab}\u{fb7}"),
+    ('\u{fb9}', "\u{f90}\u{fb5}"), ('\u{1026}', "\u{1025}\u{102e}"), ('\u{1b06}', "\u{1b05}\u{1b35}"),
+    ('\u{1b08}', "\u{1b07}\u{1b35}"), ('\u{1b0a}', "\u{1b09}\u{1b35}"), ('\u{1b0c}', "\u{1b0b}\u{1b35}"),
+    ('\u{1b0e}', "\u{1b0d}\u{1b35}"), ('\u{1b12}', "\u{1b11}\u{1b35}"), ('\u{1b3b}', "\u{1b3a}\u{1b35}"),
+    ('\u{1b3d}', "\u{1b3c}\u{1b35}"), ('\u{1b40}', "\u{1b3e}\u{1b35}"), ('\u{1b41}', "\u{1b3f}\u{1b35}"),
+    ('\u{1b43}', "\u{1b42}\u{1b35}"), ('\u{1e00}', "A\u{325}"), ('\u{1e01}', "a\u{325}"),
+    ('\u{1e02}', "B\u{307}"), ('\u{1e03}', "b\u{307}"), ('\u{1e04}', "B\u{323}"),
+    ('\u{1e05}', "b\u{323}"), ('\u{1e06}', "B\u{331}"), ('\u{1e07}', "b\u{331}"),
+    ('\u{1e08}', "C\u{327}\u{301}"), ('\u{1e09}', "c\u{327}\u{301}"), ('\u{1e0a}', "D\u{307}"),
+    ('\u{1e0b}', "d\u{307}"), ('\u{1e0c}', "D\u{323}"), ('\u{1e0d}', "d\u{323}"),
+    ('\u{1e0e}', "D\u{331}"), ('\u{1e0f}', "d\u{331}"), ('\u{1e10}', "D\u{327}"),
+    ('\u{1e11}', "d\u{327}"), ('\u{1e12}', "D\u{32d}"), ('\u{1e13}', "d\u{32d}"),
+    ('\u{1e14}', "E\u{304}\u{300}"), ('\u{1e15}', "e\u{304}\u{300}"), ('\u{1e16}', "E\u{304}\u{301}"),
+    ('\u{1e17}', "e\u{304}\u{301}"), ('\u{1e18}', "E\u{32d}"), ('\u{1e19}', "e\u{32d}"),
+    ('\u{1e1a}', "E\u{330}"), ('\u{1e1b}', "e\u{330}"), ('\u{1e1c}', "E\u{327}\u{306}"),
+    ('\u{1e1d}', "e\u{327}\u{306}"), ('\u{1e1e}', "F\u{307}"), ('\u{1e1f}', "f\u{307}"),
+    ('\u{1e20}', "G\u{304}"), ('\u{1e21}', "g\u{304}"), ('\u{1e22}', "H\u{307}"),
+    ('\u{1e23}', "h\u{307}"), ('\u{1e24}', "H\u{323}"), ('\u{1e25}', "h\u{323}"),
+    ('\u{1e26}', "H\u{308}"), ('\u{1e27}', "h\u{308}"), ('\u{1e28}', "H\u{327}"),
+    ('\u{1e29}', "h\u{327}"), ('\u{1e2a}', "H\u{32e}"), ('\u{1e2b}', "h\u{32e}"),
+    ('\u{1e2c}', "I\u{330}"), ('\u{1e2d}', "i\u{330}"), ('\u{1e2e}', "I\u{308}\u{301}"),
+    ('\u{1e2f}', "i\u{308}\u{301}"), ('\u{1e30}', "K\u{301}"), ('\u{1e31}', "k\u{301}"),
+    ('\u{1e32}', "K\u{323}"), ('\u{1e33}', "k\u{323}"), ('\u{1e34}', "K\u{331}"),
+    ('\u{1e35}', "k\u{331}"), ('\u{1e36}', "L\u{323}"), ('\u{1e37}', "l\u{323}"),
+    ('\u{1e38}', "L\u{323}\u{304}"), ('\u{1e39}', "l\u{323}\u{304}"), ('\u{1e3a}', "L\u{331}"),
+    ('\u{1e3b}', "l\u{331}"), ('\u{1e3c}', "L\u{32d}"), ('\u{1e3d}', "l\u{32d}"),
+    ('\u{1e3e}', "M\u{301}"), ('\u{1e3f}', "m\u{301}"), ('\u{1e40}', "M\u{307}"),
+    ('\u{1e41}', "m\u{307}"), ('\u{1e42}', "M\u{323}"), ('\u{1e43}', "m\u{323}"),
+    ('\u{1e44}', "N\u{307}"), ('\u{1e45}', "n\u{307}"), ('\u{1e46}', "N\u{323}"),
+    ('\u{1e47}', "n\u{323}"), ('\u{1e48}', "N\u{331}"), ('\u{1e49}', "n\u{331}"),
+    ('\u{1e4a}', "N\u{32d}"), ('\u{1e4b}', "n\u{32d}"), ('\u{1e4c}', "O\u{303}\u{301}"),
+    ('\u{1e4d}', "o\u{303}\u{301}"), ('\u{1e4e}', "O\u{303}\u{308}"), ('\u{1e4f}', "o\u{303}\u{308}"),
+    ('\u{1e50}', "O\u{304}\u{300}"), ('\u{1e51}', "o\u{304}\u{300}"), ('\u{1e52}', "O\u{304}\u{301}"),
+    ('\u{1e53}', "o\u{304}\u{301}"), ('\u{1e54}', "P\u{301}"), ('\u{1e55}', "p\u{301}"),
+    ('\u{1e56}', "P\u{307}"), ('\u{1e57}', "p\u{307}"), ('\u{1e58}', "R\u{307}"),
+    ('\u{1e59}', "r\u{307}"), ('\u{1e5a}', "R\u{323}"), ('\u{1e5b}', "r\u{323}"),
+    ('\u{1e5c}', "R\u{323}\u{304}"), ('\u{1e5d}', "r\u{323}\u{304}"), ('\u{1e5e}', "R\u{331}"),
+    ('\u{1e5f}', "r\u{331}"), ('\u{1e60}', "S\u{307}"), ('\u{1e61}', "s\u{307}"),
+    ('\u{1e62}', "S\u{323}"), ('\u{1e63}', "s\u{323}"), ('\u{1e64}', "S\u{301}\u{307}"),
+    ('\u{1e65}', "s\u{301}\u{307}"), ('\u{1e66}', "S\u{30c}\u{307}"), ('\u{1e67}', "s\u{30c}\u{307}"),
+    ('\u{1e68}', "S\u{323}\u{307}"), ('\u{1e69}', "s\u{323}\u{307}"), ('\u{1e6a}', "T\u{307}"),
+    ('\u{1e6b}', "t\u{307}"), ('\u{1e6c}', "T\u{323}"), ('\u{1e6d}', "t\u{323}"),
+    ('\u{1e6e}', "T\u{331}"), ('\u{1e6f}', "t\u{331}"), ('\u{1e70}', "T\u{32d}"),
+    ('\u{1e71}', "t\u{32d}"), ('\u{1e72}', "U\u{324}"), ('\u{1e73}', "u\u{324}"),
+    ('\u{1e74}', "U\u{330}"), ('\u{1e75}', "u\u{330}"), ('\u{1e76}', "U\u{32d}"),
+    ('\u{1e77}', "u\u{32d}"), ('\u{1e78}', "U\u{303}\u{301}"), ('\u{1e79}', "u\u{303}\u{301}"),
+    ('\u{1e7a}', "U\u{304}\u{308}"), ('\u{1e7b}', "u\u{304}\u{308}"), ('\u{1e7c}', "V\u{303}"),
+    ('\u{1e7d}', "v\u{303}"), ('\u{1e7e}', "V\u{323}"), ('\u{1e7f}', "v\u{323}"),
+    ('\u{1e80}', "W\u{300}"), ('\u{1e81}', "w\u{300}"), ('\u{1e82}', "W\u{301}"),
+    ('\u{1e83}', "w\u{301}"), ('\u{1e84}', "W\u{308}"), ('\u{1e85}', "w\u{308}"),
+    ('\u{1e86}', "W\u{307}"), ('\u{1e87}', "w\u{307}"), ('\u{1e88}', "W\u{323}"),
+    ('\u{1e89}', "w\u{323}"), ('\u{1e8a}', "X\u{307}"), ('\u{1e8b}', "x\u{307}"),
+    ('\u{1e8c}', "X\u{308}"), ('\u{1e8d}', "x\u{308}"), ('\u{1e8e}', "Y\u{307}"),
+    ('\u{1e8f}', "y\u{307}"), ('\u{1e90}', "Z\u{302}"), ('\u{1e91}', "z\u{302}"),
+    ('\u{1e92}', "Z\u{323}"), ('\u{1e93}', "z\u{323}"), ('\u{1e94}', "Z\u{331}"),
+    ('\u{1e95}', "z\u{331}"), ('\u{1e96}', "h\u{331}"), ('\u{1e97}', "t\u{308}"),
+    ('\u{1e98}', "w\u{30a}"), ('\u{1e99}', "y\u{30a}"), ('\u{1e9b}', "\u{17f}\u{307}"),
+    ('\u{1ea0}', "A\u{323}"), ('\u{1ea1}', "a\u{323}"), ('\u{1ea2}', "A\u{309}"),
+    ('\u{1ea3}', "a\u{309}"), ('\u{1ea4}', "A\u{302}\u{301}"), ('\u{1ea5}', "a\u{302}\u{301}"),
+    ('\u{1ea6}', "A\u{302}\u{300}"), ('\u{1ea7}', "a\u{302}\u{300}"), ('\u{1ea8}', "A\u{302}\u{309}"),
+    ('\u{1ea9}', "a\u{302}\u{309}"), ('\u{1eaa}', "A\u{302}\u{303}"), ('\u{1eab}', "a\u{302}\u{303}"),
+    ('\u{1eac}', "A\u{323}\u{302}"), ('\u{1ead}', "a\u{323}\u{302}"), ('\u{1eae}', "A\u{306}\u{301}"),
+    ('\u{1eaf}', "a\u{306}\u{301}"), ('\u{1eb0}', "A\u{306}\u{300}"), ('\u{1eb1}', "a\u{306}\u{300}"),
+    ('\u{1eb2}', "A\u{306}\u{309}"), ('\u{1eb3}', "a\u{306}\u{309}"), ('\u{1eb4}', "A\u{306}\u{303}"),
+    ('\u{1eb5}', "a\u{306}\u{303}"), ('\u{1eb6}', "A\u{323}\u{306}"), ('\u{1eb7}', "a\u{323}\u{306}"),
+    ('\u{1eb8}', "E\u{323}"), ('\u{1eb9}', "e\u{323}"), ('\u{1eba}', "E\u{309}"),
+    ('\u{1ebb}', "e\u{309}"), ('\u{1ebc}', "E\u{303}"), ('\u{1ebd}', "e\u{303}"),
+    ('\u{1ebe}', "E\u{302}\u{301}"), ('\u{1ebf}', "e\u{302}\u{301}"), ('\u{1ec0}', "E\u{302}\u{300}"),
+    ('\u{1ec1}', "e\u{302}\u{300}"), ('\u{1ec2}', "E\u{302}\u{309}"), ('\u{1ec3}', "e\u{302}\u{309}"),
+    ('\u{1ec4}', "E\u{302}\u{303}"), ('\u{1ec5}', "e\u{302}\u{303}"), ('\u{1ec6}', "E\u{323}\u{302}"),
+    ('\u{1ec7}', "e\u{323}\u{302}"), ('\u{1ec8}', "I\u{309}"), ('\u{1ec9}', "i\u{309}"),
+    ('\u{1eca}', "I\u{323}"), ('\u{1ecb}', "i\u{323}"), ('\u{1ecc}', "O\u{323}"),
+    ('\u{1ecd}', "o\u{323}"), ('\u{1ece}', "O\u{309}"), ('\u{1ecf}', "o\u{309}"),
+    ('\u{1ed0}', "O\u{302}\u{301}"), ('\u{1ed1}', "o\u{302}\u{301}"), ('\u{1ed2}', "O\u{302}\u{300}"),
+    ('\u{1ed3}', "o\u{302}\u{300}"), ('\u{1ed4}', "O\u{302}\u{309}"), ('\u{1ed5}', "o\u{302}\u{309}"),
+    ('\u{1ed6}', "O\u{302}\u{303}"), ('\u{1ed7}', "o\u{302}\u{303}"), ('\u{1ed8}', "O\u{323}\u{302}"),
+    ('\u{1ed9}', "o\u{323}\u{302}"), ('\u{1eda}', "O\u{31b}\u{301}"), ('\u{1edb}', "o\u{31b}\u{301}"),
+    ('\u{1edc}', "O\u{31b}\u{300}"), ('\u{1edd}', "o\u{31b}\u{300}"), ('\u{1ede}', "O\u{31b}\u{309}"),
+    ('\u{1edf}', "o\u{31b}\u{309}"), ('\u{1ee0}', "O\u{31b}\u{303}"), ('\u{1ee1}', "o\u{31b}\u{303}"),
+    ('\u{1ee2}', "O\u{31b}\u{323}"), ('\u{1ee3}', "o\u{31b}\u{323}"), ('\u{1ee4}', "U\u{323}"),
+    ('\u{1ee5}', "u\u{323}"), ('\u{1ee6}', "U\u{309}"), ('\u{1ee7}', "u\u{309}"),
+    ('\u{1ee8}', "U\u{31b}\u{301}"), ('\u{1ee9}', "u\u{31b}\u{301}"), ('\u{1eea}', "U\u{31b}\u{300}"),
+    ('\u{1eeb}', "u\u{31b}\u{300}"), ('\u{1eec}', "U\u{31b}\u{309}"), ('\u{1eed}', "u\u{31b}\u{309}"),
+    ('\u{1eee}', "U\u{31b}\u{303}"), ('\u{1eef}', "u\u{31b}\u{303}"), ('\u{1ef0}', "U\u{31b}\u{323}"),
+    ('\u{1ef1}', "u\u{31b}\u{323}"), ('\u{1ef2}', "Y\u{300}"), ('\u{1ef3}', "y\u{300}"),
+    ('\u{1ef4}', "Y\u{323}"), ('\u{1ef5}', "y\u{323}"), ('\u{1ef6}', "Y\u{309}"),
+    ('\u{1ef7}', "y\u{309}"), ('\u{1ef8}', "Y\u{303}"), ('\u{1ef9}', "y\u{303}"),
+    ('\u{1f00}', "\u{3b1}\u{313}"), ('\u{1f01}', "\u{3b1}\u{314}"), ('\u{1f02}', "\u{3b1}\u{313}\u{300}"),
+    ('\u{1f03}', "\u{3b1}\u{314}\u{300}"), ('\u{1f04}', "\u{3b1}\u{313}\u{301}"), ('\u{1f05}', "\u{3b1}\u{314}\u{301}"),
+    ('\u{1f06}', "\u{3b1}\u{313}\u{342}"), ('\u{1f07}', "\u{3b1}\u{314}\u{342}"), ('\u{1f08}', "\u{391}\u{313}"),
+    ('\u{1f09}', "\u{391}\u{314}"), ('\u{1f0a}', "\u{391}\u{313}\u{300}"), ('\u{1f0b}', "\u{391}\u{314}\u{300}"),
+    ('\u{1f0c}', "\u{391}\u{313}\u{301}"), ('\u{1f0d}', "\u{391}\u{314}\u{301}"), ('\u{1f0e}', "\u{391}\u{313}\u{342}"),
+    ('\u{1f0f}', "\u{391}\u{314}\u{342}"), ('\u{1f10}', "\u{3b5}\u{313}"), ('\u{1f11}', "\u{3b5}\u{314}"),
+    ('\u{1f12}', "\u{3b5}\u{313}\u{300}"), ('\u{1f13}', "\u{3b5}\u{314}\u{300}"), ('\u{1f14}', "\u{3b5}\u{313}\u{301}"),
+    ('\u{1f15}', "\u{3b5}\u{314}\u{301}"), ('\u{1f18}', "\u{395}\u{313}"), ('\u{1f19}', "\u{395}\u{314}"),
+    ('\u{1f1a}', "\u{395}\u{313}\u{300}"), ('\u{1f1b}', "\u{395}\u{314}\u{300}"), ('\u{1f1c}', "\u{395}\u{313}\u{301}"),
+    ('\u{1f1d}', "\u{395}\u{314}\u{301}"), ('\u{1f20}', "\u{3b7}\u{313}"), ('\u{1f21}', "\u{3b7}\u{314}"),
+    ('\u{1f22}', "\u{3b7}\u{313}\u{300}"), ('\u{1f23}', "\u{3b7}\u{314}\u{300}"), ('\u{1f24}', "\u{3b7}\u{313}\u{301}"),
+    ('\u{1f25}', "\u{3b7}\u{314}\u{301}"), ('\u{1f26}', "\u{3b7}\u{313}\u{342}"), ('\u{1f27}', "\u{3b7}\u{314}\u{342}"),
+    ('\u{1f28}', "\u{397}\u{313}"), ('\u{1f29}', "\u{397}\u{314}"), ('\u{1f2a}', "\u{397}\u{313}\u{300}"),
+    ('\u{1f2b}', "\u{397}\u{314}\u{300}"), ('\u{1f2c}', "\u{397}\u{313}\u{301}"), ('\u{1f2d}', "\u{397}\u{314}\u{301}"),
+    ('\u{1f2e}', "\u{397}\u{313}\u{342}"), ('\u{1f2f}', "\u{397}\u{314}\u{342}"), ('\u{1f30}', "\u{3b9}\u{313}"),
+    ('\u{1f31}', "\u{3b9}\u{314}"), ('\u{1f32}', "\u{3b9}\u{313}\u{300}"), ('\u{1f33}', "\u{3b9}\u{314}\u{300}"),
+    ('\u{1f34}', "\u{3b9}\u{313}\u{301}"), ('\u{1f35}', "\u{3b9}\u{314}\u{301}"), ('\u{1f36}', "\u{3b9}\u{313}\u{342}"),
+    ('\u{1f37}', "\u{3b9}\u{314}\u{342}"), ('\u{1f38}', "\u{399}\u{313}"), ('\u{1f39}', "\u{399}\u{314}"),
+    ('\u{1f3a}', "\u{399}\u{313}\u{300}"), ('\u{1f3b}', "\u{399}\u{314}\u{300}"), ('\u{1f3c}', "\u{399}\u{313}\u{301}"),
+    ('\u{1f3d}', "\u{399}\u{314}\u{301}"), ('\u{1f3e}', "\u{399}\u{313}\u{342}"), ('\u{1f3f}', "\u{399}\u{314}\u{342}"),
+    ('\u{1f40}', "\u{3bf}\u{313}"), ('\u{1f41}', "\u{3bf}\u{314}"), ('\u{1f42}', "\u{3bf}\u{313}\u{300}"),
+    ('\u{1f43}', "\u{3bf}\u{314}\u{300}"), ('\u{1f44}', "\u{3bf}\u{313}\u{301}"), ('\u{1f45}', "\u{3bf}\u{314}\u{301}"),
+    ('\u{1f48}', "\u{39f}\u{313}"), ('\u{1f49}', "\u{39f}\u{314}"), ('\u{1f4a}', "\u{39f}\u{313}\u{300}"),
+    ('\u{1f4b}', "\u{39f}\u{314}\u{300}"), ('\u{1f4c}', "\u{39f}\u{313}\u{301}"), ('\u{1f4d}', "\u{39f}\u{314}\u{301}"),
+    ('\u{1f50}', "\u{3c5}\u{313}"), ('\u{1f51}', "\u{3c5}\u{314}"), ('\u{1f52}', "\u{3c5}\u{313}\u{300}"),
+    ('\u{1f53}', "\u{3c5}\u{314}\u{300}"), ('\u{1f54}', "\u{3c5}\u{313}\u{301}"), ('\u{1f55}', "\u{3c5}\u{314}\u{301}"),
+    ('\u{1f56}', "\u{3c5}\u{313}\u{342}"), ('\u{1f57}', "\u{3c5}\u{314}\u{342}"), ('\u{1f59}', "\u{3a5}\u{314}"),
+    ('\u{1f5b}', "\u{3a5}\u{314}\u{300}"), ('\u{1f5d}', "\u{3a5}\u{314}\u{301}"), ('\u{1f5f}', "\u{3a5}\u{314}\u{342}"),
+    ('\u{1f60}', "\u{3c9}\u{313}"), ('\u{1f61}', "\u{3c9}\u{314}"), ('\u{1f62}', "\u{3c9}\u{313}\u{300}"),
+    ('\u{1f63}', "\u{3c9}\u{314}\u{300}"), ('\u{1f64}', "\u{3c9}\u{313}\u{301}"), ('\u{1f65}', "\u{3c9}\u{314}\u{301}"),
+    ('\u{1f66}', "\u{3c9}\u{313}\u{342}"), ('\u{1f67}', "\u{3c9}\u{314}\u{342}"), ('\u{1f68}', "\u{3a9}\u{313}"),
+    ('\u{1f69}', "\u{3a9}\u{314}"), ('\u{1f6a}', "\u{3a9}\u{313}\u{300}"), ('\u{1f6b}', "\u{3a9}\u{314}\u{300}"),
+    ('\u{1f6c}', "\u{3a9}\u{313}\u{301}"), ('\u{1f6d}', "\u{3a9}\u{314}\u{301}"), ('\u{1f6e}', "\u{3a9}\u{313}\u{342}"),
+    ('\u{1f6f}', "\u{3a9}\u{314}\u{342}"), ('\u{1f70}', "\u{3b1}\u{300}"), ('\u{1f71}', "\u{3b1}\u{301}"),
+    ('\u{1f72}', "\u{3b5}\u{300}"), ('\u{1f73}', "\u{3b5}\u{301}"), ('\u{1f74}', "\u{3b7}\u{300}"),
+    ('\u{1f75}', "\u{3b7}\u{301}"), ('\u{1f76}', "\u{3b9}\u{300}"), ('\u{1f77}', "\u{3b9}\u{301}"),
+    ('\u{1f78}', "\u{3bf}\u{300}"), ('\u{1f79}', "\u{3bf}\u{301}"), ('\u{1f7a}', "\u{3c5}\u{300}"),
+    ('\u{1f7b}', "\u{3c5}\u{301}"), ('\u{1f7c}', "\u{3c9}\u{300}"), ('\u{1f7d}', "\u{3c9}\u{301}"),
+    ('\u{1f80}', "\u{3b1}\u{313}\u{345}"), ('\u{1f81}', "\u{3b1}\u{314}\u{345}"), ('\u{1f82}', "\u{3b1}\u{313}\u{300}\u{345}"),
+    ('\u{1f83}', "\u{3b1}\u{314}\u{300}\u{345}"), ('\u{1f84}', "\u{3b1}\u{313}\u{301}\u{345}"), ('\u{1f85}', "\u{3b1}\u{314}\u{301}\u{345}"),
+    ('\u{1f86}', "\u{3b1}\u{313}\u{342}\u{345}"), ('\u{1f87}', "\u{3b1}\u{314}\u{342}\u{345}"), ('\u{1f88}', "\u{391}\u{313}\u{345}"),
+    ('\u{1f89}', "\u{391}\u{314}\u{345}"), ('\u{1f8a}', "\u{391}\u{313}\u{300}\u{345}"), ('\u{1f8b}', "\u{391}\u{314}\u{300}\u{345}"),
+    ('\u{1f8c}', "\u{391}\u{313}\u{301}\u{345}"), ('\u{1f8d}', "\u{391}\u{314}\u{301}\u{345}"), ('\u{1f8e}', "\u{391}\u{313}\u{342}\u{345}"),
+    ('\u{1f8f}', "\u{391}\u{314}\u{342}\u{345}"), ('\u{1f90}', "\u{3b7}\u{313}\u{345}"), ('\u{1f91}', "\u{3b7}\u{314}\u{345}"),
+    ('\u{1f92}', "\u{3b7}\u{313}\u{300}\u{345}"), ('\u{1f93}', "\u{3b7}\u{314}\u{300}\u{345}"), ('\u{1f94}', "\u{3b7}\u{313}\u{301}\u{345}"),
+    ('\u{1f95}', "\u{3b7}\u{314}\u{301}\u{345}"), ('\u{1f96}', "\u{3b7}\u{313}\u{342}\u{345}"), ('\u{1f97}', "\u{3b7}\u{314}\u{342}\u{345}"),
+    ('\u{1f98}', "\u{397}\u{313}\u{345}"), ('\u{1f99}', "\u{397}\u{314}\u{345}"), ('\u{1f9a}', "\u{397}\u{313}\u{300}\u{345}"),
+    ('\u{1f9b}', "\u{397}\u{314}\u{300}\u{345}"), ('\u{1f9c}', "\u{397}\u{313}\u{301}\u{345}"), ('\u{1f9d}', "\u{397}\u{314}\u{301}\u{345}"),
+    ('\u{1f9e}', "\u{397}\u{313}\u{342}\u{345}"), ('\u{1f9f}', "\u{397}\u{314}\u{342}\u{345}"), ('\u{1fa0}', "\u{3c9}\u{313}\u{345}"),
+    ('\u{1fa1}', "\u{3c9}\u{314}\u{345}"), ('\u{1fa2}', "\u{3c9}\u{313}\u{300}\u{345}"), ('\u{1fa3}', "\u{3c9}\u{314}\u{300}\u{345}"),
+    ('\u{1fa4}', "\u{3c9}\u{313}\u{301}\u{345}"), ('\u{1fa5}', "\u{3c9}\u{314}\u{301}\u{345}"), ('\u{1fa6}', "\u{3c9}\u{313}\u{342}\u{345}"),
+    ('\u{1fa7}', "\u{3c9}\u{314}\u{342}\u{345}"), ('\u{1fa8}', "\u{3a9}\u{313}\u{345}"), ('\u{1fa9}', "\u{3a9}\u{314}\u{345}"),
+    ('\u{1faa}', "\u{3a9}\u{313}\u{300}\u{345}"), ('\u{1fab}', "\u{3a9}\u{314}\u{300}\u{345}"), ('\u{1fac}', "\u{3a9}\u{313}\u{301}\u{345}"),
+    ('\u{1fad}', "\u{3a9}\u{314}\u{301}\u{345}"), ('\u{1fae}', "\u{3a9}\u{313}\u{342}\u{345}"), ('\u{1faf}', "\u{3a9}\u{314}\u{342}\u{345}"),
+    ('\u{1fb0}', "\u{3b1}\u{306}"), ('\u{1fb1}', "\u{3b1}\u{304}"), ('\u{1fb2}', "\u{3b1}\u{300}\u{345}"),
+    ('\u{1fb3}', "\u{3b1}\u{345}"), ('\u{1fb4}', "\u{3b1}\u{301}\u{345}"), ('\u{1fb6}', "\u{3b1}\u{342}"),
+    ('\u{1fb7}', "\u{3b1}\u{342}\u{345}"), ('\u{1fb8}', "\u{391}\u{306}"), ('\u{1fb9}', "\u{391}\u{304}"),
+    ('\u{1fba}', "\u{391}\u{300}"), ('\u{1fbb}', "\u{391}\u{301}"), ('\u{1fbc}', "\u{391}\u{345}"),
+    ('\u{1fbe}', "\u{3b9}"), ('\u{1fc1}', "\u{a8}\u{342}"), ('\u{1fc2}', "\u{3b7}\u{300}\u{345}"),
+    ('\u{1fc3}', "\u{3b7}\u{345}"), ('\u{1fc4}', "\u{3b7}\u{301}\u{345}"), ('\u{1fc6}', "\u{3b7}\u{342}"),
+    ('\u{1fc7}', "\u{3b7}\u{342}\u{345}"), ('\u{1fc8}', "\u{395}\u{300}"), ('\u{1fc9}', "\u{395}\u{301}"),
+    ('\u{1fca}', "\u{397}\u{300}"), ('\u{1fcb}', "\u{397}\u{301}"), ('\u{1fcc}', "\u{397}\u{345}"),
+    ('\u{1fcd}', "\u{1fbf}\u{300}"), ('\u{1fce}', "\u{1fbf}\u{301}"), ('\u{1fcf}', "\u{1fbf}\u{342}"),
+    ('\u{1fd0}', "\u{3b9}\u{306}"), ('\u{1fd1}', "\u{3b9}\u{304}"), ('\u{1fd2}', "\u{3b9}\u{308}\u{300}"),
+    ('\u{1fd3}', "\u{3b9}\u{308}\u{301}"), ('\u{1fd6}', "\u{3b9}\u{342}"), ('\u{1fd7}', "\u{3b9}\u{308}\u{342}"),
+    ('\u{1fd8}', "\u{399}\u{306}"), ('\u{1fd9}', "\u{399}\u{304}"), ('\u{1fda}', "\u{399}\u{300}"),
+    ('\u{1fdb}', "\u{399}\u{301}"), ('\u{1fdd}', "\u{1ffe}\u{300}"), ('\u{1fde}', "\u{1ffe}\u{301}"),
+    ('\u{1fdf}', "\u{1ffe}\u{342}"), ('\u{1fe0}', "\u{3c5}\u{306}"), ('\u{1fe1}', "\u{3c5}\u{304}"),
+    ('\u{1fe2}', "\u{3c5}\u{308}\u{300}"), ('\u{1fe3}', "\u{3c5}\u{308}\u{301}"), ('\u{1fe4}', "\u{3c1}\u{313}"),
+    ('\u{1fe5}', "\u{3c1}\u{314}"), ('\u{1fe6}', "\u{3c5}\u{342}"), ('\u{1fe7}', "\u{3c5}\u{308}\u{342}"),
+    ('\u{1fe8}', "\u{3a5}\u{306}"), ('\u{1fe9}', "\u{3a5}\u{304}"), ('\u{1fea}', "\u{3a5}\u{300}"),
+    ('\u{1feb}', "\u{3a5}\u{301}"), ('\u{1fec}', "\u{3a1}\u{314}"), ('\u{1fed}', "\u{a8}\u{300}"),
+    ('\u{1fee}', "\u{a8}\u{301}"), ('\u{1fef}', "\u{60}"), ('\u{1ff2}', "\u{3c9}\u{300}\u{345}"),
+    ('\u{1ff3}', "\u{3c9}\u{345}"), ('\u{1ff4}', "\u{3c9}\u{301}\u{345}"), ('\u{1ff6}', "\u{3c9}\u{342}"),
+    ('\u{1ff7}', "\u{3c9}\u{342}\u{345}"), ('\u{1ff8}', "\u{39f}\u{300}"), ('\u{1ff9}', "\u{39f}\u{301}"),
+    ('\u{1ffa}', "\u{3a9}\u{300}"), ('\u{1ffb}', "\u{3a9}\u{301}"), ('\u{1ffc}', "\u{3a9}\u{345}"),
+    ('\u{1ffd}', "\u{b4}"), ('\u{2000}', "\u{2002}"), ('\u{2001}', "\u{2003}"),
+    ('\u{2126}', "\u{3a9}"), ('\u{212a}', "K"), ('\u{212b}', "A\u{30a}"),
+    ('\u{219a}', "\u{2190}\u{338}"), ('\u{219b}', "\u{2192}\u{338}"), ('\u{21ae}', "\u{2194}\u{338}"),
+    ('\u{21cd}', "\u{21d0}\u{338}"), ('\u{21ce}', "\u{21d4}\u{338}"), ('\u{21cf}', "\u{21d2}\u{338}"),
+    ('\u{2204}', "\u{2203}\u{338}"), ('\u{2209}', "\u{2208}\u{338}"), ('\u{220c}', "\u{220b}\u{338}"),
+    ('\u{2224}', "\u{2223}\u{338}"), ('\u{2226}', "\u{2225}\u{338}"), ('\u{2241}', "\u{223c}\u{338}"),
+    ('\u{2244}', "\u{2243}\u{338}"), ('\u{2247}', "\u{2245}\u{338}"), ('\u{2249}', "\u{2248}\u{338}"),
+    ('\u{2260}', "\u{3d}\u{338}"), ('\u{2262}', "\u{2261}\u{338}"), ('\u{226d}', "\u{224d}\u{338}"),
+    ('\u{226e}', "\u{3c}\u{338}"), ('\u{226f}', "\u{3e}\u{338}"), ('\u{2270}', "\u{2264}\u{338}"),
+    ('\u{2271}', "\u{2265}\u{338}"), ('\u{2274}', "\u{2272}\u{338}"), ('\u{2275}', "\u{2273}\u{338}"),
+    ('\u{2278}', "\u{2276}\u{338}"), ('\u{2279}', "\u{2277}\u{338}"), ('\u{2280}', "\u{227a}\u{338}"),
+    ('\u{2281}', "\u{227b}\u{338}"), ('\u{2284}', "\u{2282}\u{338}"), ('\u{2285}', "\u{2283}\u{338}"),
+    ('\u{2288}', "\u{2286}\u{338}"), ('\u{2289}', "\u{2287}\u{338}"), ('\u{22ac}', "\u{22a2}\u{338}"),
+    ('\u{22ad}', "\u{22a8}\u{338}"), ('\u{22ae}', "\u{22a9}\u{338}"), ('\u{22af}', "\u{22ab}\u{338}"),
+    ('\u{22e0}', "\u{227c}\u{338}"), ('\u{22e1}', "\u{227d}\u{338}"), ('\u{22e2}', "\u{2291}\u{338}"),
+    ('\u{22e3}', "\u{2292}\u{338}"), ('\u{22ea}', "\u{22b2}\u{338}"), ('\u{22eb}', "\u{22b3}\u{338}"),
+    ('\u{22ec}', "\u{22b4}\u{338}"), ('\u{22ed}', "\u{22b5}\u{338}"), ('\u{2329}', "\u{3008}"),
+    ('\u{232a}', "\u{3009}"), ('\u{2adc}', "\u{2add}\u{338}"), ('\u{304c}', "\u{304b}\u{3099}"),
+    ('\u{304e}', "\u{304d}\u{3099}"), ('\u{3050}', "\u{304f}\u{3099}"), ('\u{3052}', "\u{3051}\u{3099}"),
+    ('\u{3054}', "\u{3053}\u{3099}"), ('\u{3056}', "\u{3055}\u{3099}"), ('\u{3058}', "\u{3057}\u{3099}"),
+    ('\u{305a}', "\u{3059}\u{3099}"), ('\u{305c}', "\u{305b}\u{3099}"), ('\u{305e}', "\u{305d}\u{3099}"),
+    ('\u{3060}', "\u{305f}\u{3099}"), ('\u{3062}', "\u{3061}\u{3099}"), ('\u{3065}', "\u{3064}\u{3099}"),
+    ('\u{3067}', "\u{3066}\u{3099}"), ('\u{3069}', "\u{3068}\u{3099}"), ('\u{3070}', "\u{306f}\u{3099}"),
+    ('\u{3071}', "\u{306f}\u{309a}"), ('\u{3073}', "\u{3072}\u{3099}"), ('\u{3074}', "\u{3072}\u{309a}"),
+    ('\u{3076}', "\u{3075}\u{3099}"), ('\u{3077}', "\u{3075}\u{309a}"), ('\u{3079}', "\u{3078}\u{3099}"),
+    ('\u{307a}', "\u{3078}\u{309a}"), ('\u{307c}', "\u{307b}\u{3099}"), ('\u{307d}', "\u{307b}\u{309a}"),
+    ('\u{3094}', "\u{3046}\u{3099}"), ('\u{309e}', "\u{309d}\u{3099}"), ('\u{30ac}', "\u{30ab}\u{3099}"),
+    ('\u{30ae}', "\u{30ad}\u{3099}"), ('\u{30b0}', "\u{30af}\u{3099}"), ('\u{30b2}', "\u{30b1}\u{3099}"),
+    ('\u{30b4}', "\u{30b3}\u{3099}"), ('\u{30b6}', "\u{30b5}\u{3099}"), ('\u{30b8}', "\u{30b7}\u{3099}"),
+    ('\u{30ba}', "\u{30b9}\u{3099}"), ('\u{30bc}', "\u{30bb}\u{3099}"), ('\u{30be}', "\u{30bd}\u{3099}"),
+    ('\u{30c0}', "\u{30bf}\u{3099}"), ('\u{30c2}', "\u{30c1}\u{3099}"), ('\u{30c5}', "\u{30c4}\u{3099}"),
+    ('\u{30c7}', "\u{30c6}\u{3099}"), ('\u{30c9}', "\u{30c8}\u{3099}"), ('\u{30d0}', "\u{30cf}\u{3099}"),
+    ('\u{30d1}', "\u{30cf}\u{309a}"), ('\u{30d3}', "\u{30d2}\u{3099}"), ('\u{30d4}', "\u{30d2}\u{309a}"),
+    ('\u{30d6}', "\u{30d5}\u{3099}"), ('\u{30d7}', "\u{30d5}\u{309a}"), ('\u{30d9}', "\u{30d8}\u{3099}"),
+    ('\u{30da}', "\u{30d8}\u{309a}"), ('\u{30dc}', "\u{30db}\u{3099}"), ('\u{30dd}', "\u{30db}\u{309a}"),
+    ('\u{30f4}', "\u{30a6}\u{3099}"), ('\u{30f7}', "\u{30ef}\u{3099}"), ('\u{30f8}', "\u{30f0}\u{3099}"),
+    ('\u{30f9}', "\u{30f1}\u{3099}"), ('\u{30fa}', "\u{30f2}\u{3099}"), ('\u{30fe}', "\u{30fd}\u{3099}"),
+    ('\u{f900}', "\u{8c48}"), ('\u{f901}', "\u{66f4}"), ('\u{f902}', "\u{8eca}"),
+    ('\u{f903}', "\u{8cc8}"), ('\u{f904}', "\u{6ed1}"), ('\u{f905}', "\u{4e32}"),
+    ('\u{f906}', "\u{53e5}"), ('\u{f907}', "\u{9f9c}"), ('\u{f908}', "\u{9f9c}"),
+    ('\u{f909}', "\u{5951}"), ('\u{f90a}', "\u{91d1}"), ('\u{f90b}', "\u{5587}"),
+    ('\u{f90c}', "\u{5948}"), ('\u{f90d}', "\u{61f6}"), ('\u{f90e}', "\u{7669}"),
+    ('\u{f90f}', "\u{7f85}"), ('\u{f910}', "\u{863f}"), ('\u{f911}', "\u{87ba}"),
+    ('\u{f912}', "\u{88f8}"), ('\u{f913}', "\u{908f}"), ('\u{f914}', "\u{6a02}"),
+    ('\u{f915}', "\u{6d1b}"), ('\u{f916}', "\u{70d9}"), ('\u{f917}', "\u{73de}"),
+    ('\u{f918}', "\u{843d}"), ('\u{f919}', "\u{916a}"), ('\u{f91a}', "\u{99f1}"),
+    ('\u{f91b}', "\u{4e82}"), ('\u{f91c}', "\u{5375}"), ('\u{f91d}', "\u{6b04}"),
+    ('\u{f91e}', "\u{721b}"), ('\u{f91f}', "\u{862d}"), ('\u{f920}', "\u{9e1e}"),
+    ('\u{f921}', "\u{5d50}"), ('\u{f922}', "\u{6feb}"), ('\u{f923}', "\u{85cd}"),
+    ('\u{f924}', "\u{8964}"), ('\u{f925}', "\u{62c9}"), ('\u{f926}', "\u{81d8}"),
+    ('\u{f927}', "\u{881f}"), ('\u{f928}', "\u{5eca}"), ('\u{f929}', "\u{6717}"),
+    ('\u{f92a}', "\u{6d6a}"), ('\u{f92b}', "\u{72fc}"), ('\u{f92c}', "\u{90ce}"),
+    ('\u{f92d}', "\u{4f86}"), ('\u{f92e}', "\u{51b7}"), ('\u{f92f}', "\u{52de}"),
+    ('\u{f930}', "\u{64c4}"), ('\u{f931}', "\u{6ad3}"), ('\u{f932}', "\u{7210}"),
+    ('\u{f933}', "\u{76e7}"), ('\u{f934}', "\u{8001}"), ('\u{f935}', "\u{8606}"),
+    ('\u{f936}', "\u{865c}"), ('\u{f937}', "\u{8def}"), ('\u{f938}', "\u{9732}"),
+    ('\u{f939}', "\u{9b6f}"), ('\u{f93a}', "\u{9dfa}"), ('\u{f93b}', "\u{788c}"),
+    ('\u{f93c}', "\u{797f}"), ('\u{f93d}', "\u{7da0}"), ('\u{f93e}', "\u{83c9}"),
+    ('\u{f93f}', "\u{9304}"), ('\u{f940}', "\u{9e7f}"), ('\u{f941}', "\u{8ad6}"),
+    ('\u{f942}', "\u{58df}"), ('\u{f943}', "\u{5f04}"), ('\u{f944}', "\u{7c60}"),
+    ('\u{f945}', "\u{807e}"), ('\u{f946}', "\u{7262}"), ('\u{f947}', "\u{78ca}"),
+    ('\u{f948}', "\u{8cc2}"), ('\u{f949}', "\u{96f7}"), ('\u{f94a}', "\u{58d8}"),
+    ('\u{f94b}', "\u{5c62}"), ('\u{f94c}', "\u{6a13}"), ('\u{f94d}', "\u{6dda}"),
+    ('\u{f94e}', "\u{6f0f}"), ('\u{f94f}', "\u{7d2f}"), ('\u{f950}', "\u{7e37}"),
+    ('\u{f951}', "\u{964b}"), ('\u{f952}', "\u{52d2}"), ('\u{f953}', "\u{808b}"),
+    ('\u{f954}', "\u{51dc}"), ('\u{f955}', "\u{51cc}"), ('\u{f956}', "\u{7a1c}"),
+    ('\u{f957}', "\u{7dbe}"), ('\u{f958}', "\u{83f1}"), ('\u{f959}', "\u{9675}"),
+    ('\u{f95a}', "\u{8b80}"), ('\u{f95b}', "\u{62cf}"), ('\u{f95c}', "\u{6a02}"),
+    ('\u{f95d}', "\u{8afe}"), ('\u{f95e}', "\u{4e39}"), ('\u{f95f}', "\u{5be7}"),
+    ('\u{f960}', "\u{6012}"), ('\u{f961}', "\u{7387}"), ('\u{f962}', "\u{7570}"),
+    ('\u{f963}', "\u{5317}"), ('\u{f964}', "\u{78fb}"), ('\u{f965}', "\u{4fbf}"),
+    ('\u{f966}', "\u{5fa9}"), ('\u{f967}', "\u{4e0d}"), ('\u{f968}', "\u{6ccc}"),
+    ('\u{f969}', "\u{6578}"), ('\u{f96a}', "\u{7d22}"), ('\u{f96b}', "\u{53c3}"),
+    ('\u{f96c}', "\u{585e}"), ('\u{f96d}', "\u{7701}"), ('\u{f96e}', "\u{8449}"),
+    ('\u{f96f}', "\u{8aaa}"), ('\u{f970}', "\u{6bba}"), ('\u{f971}', "\u{8fb0}"),
+    ('\u{f972}', "\u{6c88}"), ('\u{f973}', "\u{62fe}"), ('\u{f974}', "\u{82e5}"),
+    ('\u{f975}', "\u{63a0}"), ('\u{f976}', "\u{7565}"), ('\u{f977}', "\u{4eae}"),
+    ('\u{f978}', "\u{5169}"), ('\u{f979}', "\u{51c9}"), ('\u{f97a}', "\u{6881}"),
+    ('\u{f97b}', "\u{7ce7}"), ('\u{f97c}', "\u{826f}"), ('\u{f97d}', "\u{8ad2}"),
+    ('\u{f97e}', "\u{91cf}"), ('\u{f97f}', "\u{52f5}"), ('\u{f980}', "\u{5442}"),
+    ('\u{f981}', "\u{5973}"), ('\u{f982}', "\u{5eec}"), ('\u{f983}', "\u{65c5}"),
+    ('\u{f984}', "\u{6ffe}"), ('\u{f985}', "\u{792a}"), ('\u{f986}', "\u{95ad}"),
+    ('\u{f987}', "\u{9a6a}"), ('\u{f988}', "\u{9e97}"), ('\u{f989}', "\u{9ece}"),
+    ('\u{f98a}', "\u{529b}"), ('\u{f98b}', "\u{66c6}"), ('\u{f98c}', "\u{6b77}"),
+    ('\u{f98d}', "\u{8f62}"), ('\u{f98e}', "\u{5e74}"), ('\u{f98f}', "\u{6190}"),
+    ('\u{f990}', "\u{6200}"), ('\u{f991}', "\u{649a}"), ('\u{f992}', "\u{6f23}"),
+    ('\u{f993}', "\u{7149}"), ('\u{f994}', "\u{7489}"), ('\u{f995}', "\u{79ca}"),
+    ('\u{f996}', "\u{7df4}"), ('\u{f997}', "\u{806f}"), ('\u{f998}', "\u{8f26}"),
+    ('\u{f999}', "\u{84ee}"), ('\u{f99a}', "\u{9023}"), ('\u{f99b}', "\u{934a}"),
+    ('\u{f99c}', "\u{5217}"), ('\u{f99d}', "\u{52a3}"), ('\u{f99e}', "\u{54bd}"),
+    ('\u{f99f}', "\u{70c8}"), ('\u{f9a0}', "\u{88c2}"), ('\u{f9a1}', "\u{8aaa}"),
+    ('\u{f9a2}', "\u{5ec9}"), ('\u{f9a3}', "\u{5ff5}"), ('\u{f9a4}', "\u{637b}"),
+    ('\u{f9a5}', "\u{6bae}"), ('\u{f9a6}', "\u{7c3e}"), ('\u{f9a7}', "\u{7375}"),
+    ('\u{f9a8}', "\u{4ee4}"), ('\u{f9a9}', "\u{56f9}"), ('\u{f9aa}', "\u{5be7}"),
+    ('\u{f9ab}', "\u{5dba}"), ('\u{f9ac}', "\u{601c}"), ('\u{f9ad}', "\u{73b2}"),
+    ('\u{f9ae}', "\u{7469}"), ('\u{f9af}', "\u{7f9a}"), ('\u{f9b0}', "\u{8046}"),
+    ('\u{f9b1}', "\u{9234}"), ('\u{f9b2}', "\u{96f6}"), ('\u{f9b3}', "\u{9748}"),
+    ('\u{f9b4}', "\u{9818}"), ('\u{f9b5}', "\u{4f8b}"), ('\u{f9b6}', "\u{79ae}"),
+    ('\u{f9b7}', "\u{91b4}"), ('\u{f9b8}', "\u{96b8}"), ('\u{f9b9}', "\u{60e1}"),
+    ('\u{f9ba}', "\u{4e86}"), ('\u{f9bb}', "\u{50da}"), ('\u{f9bc}', "\u{5bee}"),
+    ('\u{f9bd}', "\u{5c3f}"), ('\u{f9be}', "\u{6599}"), ('\u{f9bf}', "\u{6a02}"),
+    ('\u{f9c0}', "\u{71ce}"), ('\u{f9c1}', "\u{7642}"), ('\u{f9c2}', "\u{84fc}"),
+    ('\u{f9c3}', "\u{907c}"), ('\u{f9c4}', "\u{9f8d}"), ('\u{f9c5}', "\u{6688}"),
+    ('\u{f9c6}', "\u{962e}"), ('\u{f9c7}', "\u{5289}"), ('\u{f9c8}', "\u{677b}"),
+    ('\u{f9c9}', "\u{67f3}"), ('\u{f9ca}', "\u{6d41}"), ('\u{f9cb}', "\u{6e9c}"),
+    ('\u{f9cc}', "\u{7409}"), ('\u{f9cd}', "\u{7559}"), ('\u{f9ce}', "\u{786b}"),
+    ('\u{f9cf}', "\u{7d10}"), ('\u{f9d0}', "\u{985e}"), ('\u{f9d1}', "\u{516d}"),
+    ('\u{f9d2}', "\u{622e}"), ('\u{f9d3}', "\u{9678}"), ('\u{f9d4}', "\u{502b}"),
+    ('\u{f9d5}', "\u{5d19}"), ('\u{f9d6}', "\u{6dea}"), ('\u{f9d7}', "\u{8f2a}"),
+    ('\u{f9d8}', "\u{5f8b}"), ('\u{f9d9}', "\u{6144}"), ('\u{f9da}', "\u{6817}"),
+    ('\u{f9db}', "\u{7387}"), ('\u{f9dc}', "\u{9686}"), ('\u{f9dd}', "\u{5229}"),
+    ('\u{f9de}', "\u{540f}"), ('\u{f9df}', "\u{5c65}"), ('\u{f9e0}', "\u{6613}"),
+    ('\u{f9e1}', "\u{674e}"), ('\u{f9e2}', "\u{68a8}"), ('\u{f9e3}', "\u{6ce5}"),
+    ('\u{f9e4}', "\u{7406}"), ('\u{f9e5}', "\u{75e2}"), ('\u{f9e6}', "\u{7f79}"),
+    ('\u{f9e7}', "\u{88cf}"), ('\u{f9e8}', "\u{88e1}"), ('\u{f9e9}', "\u{91cc}"),
+    ('\u{f9ea}', "\u{96e2}"), ('\u{f9eb}', "\u{533f}"), ('\u{f9ec}', "\u{6eba}"),
+    ('\u{f9ed}', "\u{541d}"), ('\u{f9ee}', "\u{71d0}"), ('\u{f9ef}', "\u{7498}"),
+    ('\u{f9f0}', "\u{85fa}"), ('\u{f9f1}', "\u{96a3}"), ('\u{f9f2}', "\u{9c57}"),
+    ('\u{f9f3}', "\u{9e9f}"), ('\u{f9f4}', "\u{6797}"), ('\u{f9f5}', "\u{6dcb}"),
+    ('\u{f9f6}', "\u{81e8}"), ('\u{f9f7}', "\u{7acb}"), ('\u{f9f8}', "\u{7b20}"),
+    ('\u{f9f9}', "\u{7c92}"), ('\u{f9fa}', "\u{72c0}"), ('\u{f9fb}', "\u{7099}"),
+    ('\u{f9fc}', "\u{8b58}"), ('\u{f9fd}', "\u{4ec0}"), ('\u{f9fe}', "\u{8336}"),
+    ('\u{f9ff}', "\u{523a}"), ('\u{fa00}', "\u{5207}"), ('\u{fa01}', "\u{5ea6}"),
+    ('\u{fa02}', "\u{62d3}"), ('\u{fa03}', "\u{7cd6}"), ('\u{fa04}', "\u{5b85}"),
+    ('\u{fa05}', "\u{6d1e}"), ('\u{fa06}', "\u{66b4}"), ('\u{fa07}', "\u{8f3b}"),
+    ('\u{fa08}', "\u{884c}"), ('\u{fa09}', "\u{964d}"), ('\u{fa0a}', "\u{898b}"),
+    ('\u{fa0b}', "\u{5ed3}"), ('\u{fa0c}', "\u{5140}"), ('\u{fa0d}', "\u{55c0}"),
+    ('\u{fa10}', "\u{585a}"), ('\u{fa12}', "\u{6674}"), ('\u{fa15}', "\u{51de}"),
+    ('\u{fa16}', "\u{732a}"), ('\u{fa17}', "\u{76ca}"), ('\u{fa18}', "\u{793c}"),
+    ('\u{fa19}', "\u{795e}"), ('\u{fa1a}', "\u{7965}"), ('\u{fa1b}', "\u{798f}"),
+    ('\u{fa1c}', "\u{9756}"), ('\u{fa1d}', "\u{7cbe}"), ('\u{fa1e}', "\u{7fbd}"),
+    ('\u{fa20}', "\u{8612}"), ('\u{fa22}', "\u{8af8}"), ('\u{fa25}', "\u{9038}"),
+    ('\u{fa26}', "\u{90fd}"), ('\u{fa2a}', "\u{98ef}"), ('\u{fa2b}', "\u{98fc}"),
+    ('\u{fa2c}', "\u{9928}"), ('\u{fa2d}', "\u{9db4}"), ('\u{fa2e}', "\u{90de}"),
+    ('\u{fa2f}', "\u{96b7}"), ('\u{fa30}', "\u{4fae}"), ('\u{fa31}', "\u{50e7}"),
+    ('\u{fa32}', "\u{514d}"), ('\u{fa33}', "\u{52c9}"), ('\u{fa34}', "\u{52e4}"),
+    ('\u{fa35}', "\u{5351}"), ('\u{fa36}', "\u{559d}"), ('\u{fa37}', "\u{5606}"),
+    ('\u{fa38}', "\u{5668}"), ('\u{fa39}', "\u{5840}"), ('\u{fa3a}', "\u{58a8}"),
+    ('\u{fa3b}', "\u{5c64}"), ('\u{fa3c}', "\u{5c6e}"), ('\u{fa3d}', "\u{6094}"),
+    ('\u{fa3e}', "\u{6168}"), ('\u{fa3f}', "\u{618e}"), ('\u{fa40}', "\u{61f2}"),
+    ('\u{fa41}', "\u{654f}"), ('\u{fa42}', "\u{65e2}"), ('\u{fa43}', "\u{6691}"),
+    ('\u{fa44}', "\u{6885}"), ('\u{fa45}', "\u{6d77}"), ('\u{fa46}', "\u{6e1a}"),
+    ('\u{fa47}', "\u{6f22}"), ('\u{fa48}', "\u{716e}"), ('\u{fa49}', "\u{722b}"),
+    ('\u{fa4a}', "\u{7422}"), ('\u{fa4b}', "\u{7891}"), ('\u{fa4c}', "\u{793e}"),
+    ('\u{fa4d}', "\u{7949}"), ('\u{fa4e}', "\u{7948}"), ('\u{fa4f}', "\u{7950}"),
+    ('\u{fa50}', "\u{7956}"), ('\u{fa51}', "\u{795d}"), ('\u{fa52}', "\u{798d}"),
+    ('\u{fa53}', "\u{798e}"), ('\u{fa54}', "\u{7a40}"), ('\u{fa55}', "\u{7a81}"),
+    ('\u{fa56}', "\u{7bc0}"), ('\u{fa57}', "\u{7df4}"), ('\u{fa58}', "\u{7e09}"),
+    ('\u{fa59}', "\u{7e41}"), ('\u{fa5a}', "\u{7f72}"), ('\u{fa5b}', "\u{8005}"),
+    ('\u{fa5c}', "\u{81ed}"), ('\u{fa5d}', "\u{8279}"), ('\u{fa5e}', "\u{8279}"),
+    ('\u{fa5f}', "\u{8457}"), ('\u{fa60}', "\u{8910}"), ('\u{fa61}', "\u{8996}"),
+    ('\u{fa62}', "\u{8b01}"), ('\u{fa63}', "\u{8b39}"), ('\u{fa64}', "\u{8cd3}"),
+    ('\u{fa65}', "\u{8d08}"), ('\u{fa66}', "\u{8fb6}"), ('\u{fa67}', "\u{9038}"),
+    ('\u{fa68}', "\u{96e3}"), ('\u{fa69}', "\u{97ff}"), ('\u{fa6a}', "\u{983b}"),
+    ('\u{fa6b}', "\u{6075}"), ('\u{fa6c}', "\u{242ee}"), ('\u{fa6d}', "\u{8218}"),
+    ('\u{fa70}', "\u{4e26}"), ('\u{fa71}', "\u{51b5}"), ('\u{fa72}', "\u{5168}"),
+    ('\u{fa73}', "\u{4f80}"), ('\u{fa74}', "\u{5145}"), ('\u{fa75}', "\u{5180}"),
+    ('\u{fa76}', "\u{52c7}"), ('\u{fa77}', "\u{52fa}"), ('\u{fa78}', "\u{559d}"),
+    ('\u{fa79}', "\u{5555}"), ('\u{fa7a}', "\u{5599}"), ('\u{fa7b}', "\u{55e2}"),
+    ('\u{fa7c}', "\u{585a}"), ('\u{fa7d}', "\u{58b3}"), ('\u{fa7e}', "\u{5944}"),
+    ('\u{fa7f}', "\u{5954}"), ('\u{fa80}', "\u{5a62}"), ('\u{fa81}', "\u{5b28}"),
+    ('\u{fa82}', "\u{5ed2}"), ('\u{fa83}', "\u{5ed9}"), ('\u{fa84}', "\u{5f69}"),
+    ('\u{fa85}', "\u{5fad}"), ('\u{fa86}', "\u{60d8}"), ('\u{fa87}', "\u{614e}"),
+    ('\u{fa88}', "\u{6108}"), ('\u{fa89}', "\u{618e}"), ('\u{fa8a}', "\u{6160}"),
+    ('\u{fa8b}', "\u{61f2}"), ('\u{fa8c}', "\u{6234}"), ('\u{fa8d}', "\u{63c4}"),
+    ('\u{fa8e}', "\u{641c}"), ('\u{fa8f}', "\u{6452}"), ('\u{fa90}', "\u{6556}"),
+    ('\u{fa91}', "\u{6674}"), ('\u{fa92}', "\u{6717}"), ('\u{fa93}', "\u{671b}"),
+    ('\u{fa94}', "\u{6756}"), ('\u{fa95}', "\u{6b79}"), ('\u{fa96}', "\u{6bba}"),
+    ('\u{fa97}', "\u{6d41}"), ('\u{fa98}', "\u{6edb}"), ('\u{fa99}', "\u{6ecb}"),
+    ('\u{fa9a}', "\u{6f22}"), ('\u{fa9b}', "\u{701e}"), ('\u{fa9c}', "\u{716e}"),
+    ('\u{fa9d}', "\u{77a7}"), ('\u{fa9e}', "\u{7235}"), ('\u{fa9f}', "\u{72af}"),
+    ('\u{faa0}', "\u{732a}"), ('\u{faa1}', "\u{7471}"), ('\u{faa2}', "\u{7506}"),
+    ('\u{faa3}', "\u{753b}"), ('\u{faa4}', "\u{761d}"), ('\u{faa5}', "\u{761f}"),
+    ('\u{faa6}', "\u{76ca}"), ('\u{faa7}', "\u{76db}"), ('\u{faa8}', "\u{76f4}"),
+    ('\u{faa9}', "\u{774a}"), ('\u{faaa}', "\u{7740}"), ('\u{faab}', "\u{78cc}"),
+    ('\u{faac}', "\u{7ab1}"), ('\u{faad}', "\u{7bc0}"), ('\u{faae}', "\u{7c7b}"),
+    ('\u{faaf}', "\u{7d5b}"), ('\u{fab0}', "\u{7df4}"), ('\u{fab1}', "\u{7f3e}"),
+    ('\u{fab2}', "\u{8005}"), ('\u{fab3}', "\u{8352}"), ('\u{fab4}', "\u{83ef}"),
+    ('\u{fab5}', "\u{8779}"), ('\u{fab6}', "\u{8941}"), ('\u{fab7}', "\u{8986}"),
+    ('\u{fab8}', "\u{8996}"), ('\u{fab9}', "\u{8abf}"), ('\u{faba}', "\u{8af8}"),
+    ('\u{fabb}', "\u{8acb}"), ('\u{fabc}', "\u{8b01}"), ('\u{fabd}', "\u{8afe}"),
+    ('\u{fabe}', "\u{8aed}"), ('\u{fabf}', "\u{8b39}"), ('\u{fac0}', "\u{8b8a}"),
+    ('\u{fac1}', "\u{8d08}"), ('\u{fac2}', "\u{8f38}"), ('\u{fac3}', "\u{9072}"),
+    ('\u{fac4}', "\u{9199}"), ('\u{fac5}', "\u{9276}"), ('\u{fac6}', "\u{967c}"),
+    ('\u{fac7}', "\u{96e3}"), ('\u{fac8}', "\u{9756}"), ('\u{fac9}', "\u{97db}"),
+    ('\u{faca}', "\u{97ff}"), ('\u{facb}', "\u{980b}"), ('\u{facc}', "\u{983b}"),
+    ('\u{facd}', "\u{9b12}"), ('\u{face}', "\u{9f9c}"), ('\u{facf}', "\u{2284a}"),
+    ('\u{fad0}', "\u{22844}"), ('\u{fad1}', "\u{233d5}"), ('\u{fad2}', "\u{3b9d}"),
+    ('\u{fad3}', "\u{4018}"), ('\u{fad4}', "\u{4039}"), ('\u{fad5}', "\u{25249}"),
+    ('\u{fad6}', "\u{25cd0}"), ('\u{fad7}', "\u{27ed3}"), ('\u{fad8}', "\u{9f43}"),
+    ('\u{fad9}', "\u{9f8e}"), ('\u{fb1d}', "\u{5d9}\u{5b4}"), ('\u{fb1f}', "\u{5f2}\u{5b7}"),
+    ('\u{fb2a}', "\u{5e9}\u{5c1}"), ('\u{fb2b}', "\u{5e9}\u{5c2}"), ('\u{fb2c}', "\u{5e9}\u{5bc}\u{5c1}"),
+    ('\u{fb2d}', "\u{5e9}\u{5bc}\u{5c2}"), ('\u{fb2e}', "\u{5d0}\u{5b7}"), ('\u{fb2f}', "\u{5d0}\u{5b8}"),
+    ('\u{fb30}', "\u{5d0}\u{5bc}"), ('\u{fb31}', "\u{5d1}\u{5bc}"), ('\u{fb32}', "\u{5d2}\u{5bc}"),
+    ('\u{fb33}', "\u{5d3}\u{5bc}"), ('\u{fb34}', "\u{5d4}\u{5bc}"), ('\u{fb35}', "\u{5d5}\u{5bc}"),
+    ('\u{fb36}', "\u{5d6}\u{5bc}"), ('\u{fb38}', "\u{5d8}\u{5bc}"), ('\u{fb39}', "\u{5d9}\u{5bc}"),
+    ('\u{fb3a}', "\u{5da}\u{5bc}"), ('\u{fb3b}', "\u{5db}\u{5bc}"), ('\u{fb3c}', "\u{5dc}\u{5bc}"),
+    ('\u{fb3e}', "\u{5de}\u{5bc}"), ('\u{fb40}', "\u{5e0}\u{5bc}"), ('\u{fb41}', "\u{5e1}\u{5bc}"),
+    ('\u{fb43}', "\u{5e3}\u{5bc}"), ('\u{fb44}', "\u{5e4}\u{5bc}"), ('\u{fb46}', "\u{5e6}\u{5bc}"),
+    ('\u{fb47}', "\u{5e7}\u{5bc}"), ('\u{fb48}', "\u{5e8}\u{5bc}"), ('\u{fb49}', "\u{5e9}\u{5bc}"),
+    ('\u{fb4a}', "\u{5ea}\u{5bc}"), ('\u{fb4b}', "\u{5d5}\u{5b9}"), ('\u{fb4c}', "\u{5d1}\u{5bf}"),
+    ('\u{fb4d}', "\u{5db}\u{5bf}"), ('\u{fb4e}', "\u{5e4}\u{5bf}"), ('\u{105c9}', "\u{105d2}\u{307}"),
+    ('\u{105e4}', "\u{105da}\u{307}"), ('\u{1109a}', "\u{11099}\u{110ba}"), ('\u{1109c}', "\u{1109b}\u{110ba}"),
+    ('\u{110ab}', "\u{110a5}\u{110ba}"), ('\u{1112e}', "\u{11131}\u{11127}"), ('\u{1112f}', "\u{11132}\u{11127}"),
+    ('\u{1134b}', "\u{11347}\u{1133e}"), ('\u{1134c}', "\u{11347}\u{11357}"), ('\u{11383}', "\u{11382}\u{113c9}"),
+    ('\u{11385}', "\u{11384}\u{113bb}"), ('\u{1138e}', "\u{1138b}\u{113c2}"), ('\u{11391}', "\u{11390}\u{113c9}"),
+    ('\u{113c5}', "\u{113c2}\u{113c2}"), ('\u{113c7}', "\u{113c2}\u{113b8}"), ('\u{113c8}', "\u{113c2}\u{113c9}"),
+    ('\u{114bb}', "\u{114b9}\u{114ba}"), ('\u{114bc}', "\u{114b9}\u{114b0}"), ('\u{114be}', "\u{114b9}\u{114bd}"),
+    ('\u{115ba}', "\u{115b8}\u{115af}"), ('\u{115bb}', "\u{115b9}\u{115af}"), ('\u{11938}', "\u{11935}\u{11930}"),
+    ('\u{16121}', "\u{1611e}\u{1611e}"), ('\u{16122}', "\u{1611e}\u{16129}"), ('\u{16123}', "\u{1611e}\u{1611f}"),
+    ('\u{16124}', "\u{16129}\u{1611f}"), ('\u{16125}', "\u{1611e}\u{16120}"), ('\u{16126}', "\u{1611e}\u{1611e}\u{1611f}"),
+    ('\u{16127}', "\u{1611e}\u{16129}\u{1611f}"), ('\u{16128}', "\u{1611e}\u{1611e}\u{16120}"), ('\u{16d68}', "\u{16d67}\u{16d67}"),
+    ('\u{16d69}', "\u{16d63}\u{16d67}"), ('\u{16d6a}', "\u{16d63}\u{16d67}\u{16d67}"), ('\u{1d15e}', "\u{1d157}\u{1d165}"),
+    ('\u{1d15f}', "\u{1d158}\u{1d165}"), ('\u{1d160}', "\u{1d158}\u{1d165}\u{1d16e}"), ('\u{1d161}', "\u{1d158}\u{1d165}\u{1d16f}"),
+    ('\u{1d162}', "\u{1d158}\u{1d165}\u{1d170}"), ('\u{1d163}', "\u{1d158}\u{1d165}\u{1d171}"), ('\u{1d164}', "\u{1d158}\u{1d165}\u{1d172}"),
+    ('\u{1d1bb}', "\u{1d1b9}\u{1d165}"), ('\u{1d1bc}', "\u{1d1ba}\u{1d165}"), ('\u{1d1bd}', "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    ('\u{1d1be}', "\u{1d1ba}\u{1d165}\u{1d16e}"), ('\u{1d1bf}', "\u{1d1b9}\u{1d165}\u{1d16f}"), ('\u{1d1c0}', "\u{1d1ba}\u{1d165}\u{1d16f}"),
+    ('\u{2f800}', "\u{4e3d}"), ('\u{2f801}', "\u{4e38}"), ('\u{2f802}', "\u{4e41}"),
+    ('\u{2f803}', "\u{20122}"), ('\u{2f804}', "\u{4f60}"), ('\u{2f805}', "\u{4fae}"),
+    ('\u{2f806}', "\u{4fbb}"), ('\u{2f807}', "\u{5002}"), ('\u{2f808}', "\u{507a}"),
+    ('\u{2f809}', "\u{5099}"), ('\u{2f80a}', "\u{50e7}"), ('\u{2f80b}', "\u{50cf}"),
+    ('\u{2f80c}', "\u{349e}"), ('\u{2f80d}', "\u{2063a}"), ('\u{2f80e}', "\u{514d}"),
+    ('\u{2f80f}', "\u{5154}"), ('\u{2f810}', "\u{5164}"), ('\u{2f811}', "\u{5177}"),
+    ('\u{2f812}', "\u{2051c}"), ('\u{2f813}', "\u{34b9}"), ('\u{2f814}', "\u{5167}"),
+    ('\u{2f815}', "\u{518d}"), ('\u{2f816}', "\u{2054b}"), ('\u{2f817}', "\u{5197}"),
+    ('\u{2f818}', "\u{51a4}"), ('\u{2f819}', "\u{4ecc}"), ('\u{2f81a}', "\u{51ac}"),
+    ('\u{2f81b}', "\u{51b5}"), ('\u{2f81c}', "\u{291df}"), ('\u{2f81d}', "\u{51f5}"),
+    ('\u{2f81e}', "\u{5203}"), ('\u{2f81f}', "\u{34df}"), ('\u{2f820}', "\u{523b}"),
+    ('\u{2f821}', "\u{5246}"), ('\u{2f822}', "\u{5272}"), ('\u{2f823}', "\u{5277}"),
+    ('\u{2f824}', "\u{3515}"), ('\u{2f825}', "\u{52c7}"), ('\u{2f826}', "\u{52c9}"),
+    ('\u{2f827}', "\u{52e4}"), ('\u{2f828}', "\u{52fa}"), ('\u{2f829}', "\u{5305}"),
+    ('\u{2f82a}', "\u{5306}"), ('\u{2f82b}', "\u{5317}"), ('\u{2f82c}', "\u{5349}"),
+    ('\u{2f82d}', "\u{5351}"), ('\u{2f82e}', "\u{535a}"), ('\u{2f82f}', "\u{5373}"),
+    ('\u{2f830}', "\u{537d}"), ('\u{2f831}', "\u{537f}"), ('\u{2f832}', "\u{537f}"),
+    ('\u{2f833}', "\u{537f}"), ('\u{2f834}', "\u{20a2c}"), ('\u{2f835}', "\u{7070}"),
+    ('\u{2f836}', "\u{53ca}"), ('\u{2f837}', "\u{53df}"), ('\u{2f838}', "\u{20b63}"),
+    ('\u{2f839}', "\u{53eb}"), ('\u{2f83a}', "\u{53f1}"), ('\u{2f83b}', "\u{5406}"),
+    ('\u{2f83c}', "\u{549e}"), ('\u{2f83d}', "\u{5438}"), ('\u{2f83e}', "\u{5448}"),
+    ('\u{2f83f}', "\u{5468}"), ('\u{2f840}', "\u{54a2}"), ('\u{2f841}', "\u{54f6}"),
+    ('\u{2f842}', "\u{5510}"), ('\u{2f843}', "\u{5553}"), ('\u{2f844}', "\u{5563}"),
+    ('\u{2f845}', "\u{5584}"), ('\u{2f846}', "\u{5584}"), ('\u{2f847}', "\u{5599}"),
+    ('\u{2f848}', "\u{55ab}"), ('\u{2f849}', "\u{55b3}"), ('\u{2f84a}', "\u{55c2}"),
+    ('\u{2f84b}', "\u{5716}"), ('\u{2f84c}', "\u{5606}"), ('\u{2f84d}', "\u{5717}"),
+    ('\u{2f84e}', "\u{5651}"), ('\u{2f84f}', "\u{5674}"), ('\u{2f850}', "\u{5207}"),
+    ('\u{2f851}', "\u{58ee}"), ('\u{2f852}', "\u{57ce}"), ('\u{2f853}', "\u{57f4}"),
+    ('\u{2f854}', "\u{580d}"), ('\u{2f855}', "\u{578b}"), ('\u{2f856}', "\u{5832}"),
+    ('\u{2f857}', "\u{5831}"), ('\u{2f858}', "\u{58ac}"), ('\u{2f859}', "\u{214e4}"),
+    ('\u{2f85a}', "\u{58f2}"), ('\u{2f85b}', "\u{58f7}"), ('\u{2f85c}', "\u{5906}"),
+    ('\u{2f85d}', "\u{591a}"), ('\u{2f85e}', "\u{5922}"), ('\u{2f85f}', "\u{5962}"),
+    ('\u{2f860}', "\u{216a8}"), ('\u{2f861}', "\u{216ea}"), ('\u{2f862}', "\u{59ec}"),
+    ('\u{2f863}', "\u{5a1b}"), ('\u{2f864}', "\u{5a27}"), ('\u{2f865}', "\u{59d8}"),
+    ('\u{2f866}', "\u{5a66}"), ('\u{2f867}', "\u{36ee}"), ('\u{2f868}', "\u{36fc}"),
+    ('\u{2f869}', "\u{5b08}"), ('\u{2f86a}', "\u{5b3e}"), ('\u{2f86b}', "\u{5b3e}"),
+    ('\u{2f86c}', "\u{219c8}"), ('\u{2f86d}', "\u{5bc3}"), ('\u{2f86e}', "\u{5bd8}"),
+    ('\u{2f86f}', "\u{5be7}"), ('\u{2f870}', "\u{5bf3}"), ('\u{2f871}', "\u{21b18}"),
+    ('\u{2f872}', "\u{5bff}"), ('\u{2f873}', "\u{5c06}"), ('\u{2f874}', "\u{5f53}"),
+    ('\u{2f875}', "\u{5c22}"), ('\u{2f876}', "\u{3781}"), ('\u{2f877}', "\u{5c60}"),
+    ('\u{2f878}', "\u{5c6e}"), ('\u{2f879}', "\u{5cc0}"), ('\u{2f87a}', "\u{5c8d}"),
+    ('\u{2f87b}', "\u{21de4}"), ('\u{2f87c}', "\u{5d43}"), ('\u{2f87d}', "\u{21de6}"),
+    ('\u{2f87e}', "\u{5d6e}"), ('\u{2f87f}', "\u{5d6b}"), ('\u{2f880}', "\u{5d7c}"),
+    ('\u{2f881}', "\u{5de1}"), ('\u{2f882}', "\u{5de2}"), ('\u{2f883}', "\u{382f}"),
+    ('\u{2f884}', "\u{5dfd}"), ('\u{2f885}', "\u{5e28}"), ('\u{2f886}', "\u{5e3d}"),
+    ('\u{2f887}', "\u{5e69}"), ('\u{2f888}', "\u{3862}"), ('\u{2f889}', "\u{22183}"),
+    ('\u{2f88a}', "\u{387c}"), ('\u{2f88b}', "\u{5eb0}"), ('\u{2f88c}', "\u{5eb3}"),
+    ('\u{2f88d}', "\u{5eb6}"), ('\u{2f88e}', "\u{5eca}"), ('\u{2f88f}', "\u{2a392}"),
+    ('\u{2f890}', "\u{5efe}"), ('\u{2f891}', "\u{22331}"), ('\u{2f892}', "\u{22331}"),
+    ('\u{2f893}', "\u{8201}"), ('\u{2f894}', "\u{5f22}"), ('\u{2f895}', "\u{5f22}"),
+    ('\u{2f896}', "\u{38c7}"), ('\u{2f897}', "\u{232b8}"), ('\u{2f898}', "\u{261da}"),
+    ('\u{2f899}', "\u{5f62}"), ('\u{2f89a}', "\u{5f6b}"), ('\u{2f89b}', "\u{38e3}"),
+    ('\u{2f89c}', "\u{5f9a}"), ('\u{2f89d}', "\u{5fcd}"), ('\u{2f89e}', "\u{5fd7}"),
+    ('\u{2f89f}', "\u{5ff9}"), ('\u{2f8a0}', "\u{6081}"), ('\u{2f8a1}', "\u{393a}"),
+    ('\u{2f8a2}', "\u{391c}"), ('\u{2f8a3}', "\u{6094}"), ('\u{2f8a4}', "\u{226d4}"),
+    ('\u{2f8a5}', "\u{60c7}"), ('\u{2f8a6}', "\u{6148}"), ('\u{2f8a7}', "\u{614c}"),
+    ('\u{2f8a8}', "\u{614e}"), ('\u{2f8a9}', "\u{614c}"), ('\u{2f8aa}', "\u{617a}"),
+    ('\u{2f8ab}', "\u{618e}"), ('\u{2f8ac}', "\u{61b2}"), ('\u{2f8ad}', "\u{61a4}"),
+    ('\u{2f8ae}', "\u{61af}"), ('\u{2f8af}', "\u{61de}"), ('\u{2f8b0}', "\u{61f2}"),
+    ('\u{2f8b1}', "\u{61f6}"), ('\u{2f8b2}', "\u{6210}"), ('\u{2f8b3}', "\u{621b}"),
+    ('\u{2f8b4}', "\u{625d}"), ('\u{2f8b5}', "\u{62b1}"), ('\u{2f8b6}', "\u{62d4}"),
+    ('\u{2f8b7}', "\u{6350}"), ('\u{2f8b8}', "\u{22b0c}"), ('\u{2f8b9}', "\u{633d}"),
+    ('\u{2f8ba}', "\u{62fc}"), ('\u{2f8bb}', "\u{6368}"), ('\u{2f8bc}', "\u{6383}"),
+    ('\u{2f8bd}', "\u{63e4}"), ('\u{2f8be}', "\u{22bf1}"), ('\u{2f8bf}', "\u{6422}"),
+    ('\u{2f8c0}', "\u{63c5}"), ('\u{2f8c1}', "\u{63a9}"), ('\u{2f8c2}', "\u{3a2e}"),
+    ('\u{2f8c3}', "\u{6469}"), ('\u{2f8c4}', "\u{647e}"), ('\u{2f8c5}', "\u{649d}"),
+    ('\u{2f8c6}', "\u{6477}"), ('\u{2f8c7}', "\u{3a6c}"), ('\u{2f8c8}', "\u{654f}"),
+    ('\u{2f8c9}', "\u{656c}"), ('\u{2f8ca}', "\u{2300a}"), ('\u{2f8cb}', "\u{65e3}"),
+    ('\u{2f8cc}', "\u{66f8}"), ('\u{2f8cd}', "\u{6649}"), ('\u{2f8ce}', "\u{3b19}"),
+    ('\u{2f8cf}', "\u{6691}"), ('\u{2f8d0}', "\u{3b08}"), ('\u{2f8d1}', "\u{3ae4}"),
+    ('\u{2f8d2}', "\u{5192}"), ('\u{2f8d3}', "\u{5195}"), ('\u{2f8d4}', "\u{6700}"),
+    ('\u{2f8d5}', "\u{669c}"), ('\u{2f8d6}', "\u{80ad}"), ('\u{2f8d7}', "\u{43d9}"),
+    ('\u{2f8d8}', "\u{6717}"), ('\u{2f8d9}', "\u{671b}"), ('\u{2f8da}', "\u{6721}"),
+    ('\u{2f8db}', "\u{675e}"), ('\u{2f8dc}', "\u{6753}"), ('\u{2f8dd}', "\u{233c3}"),
+    ('\u{2f8de}', "\u{3b49}"), ('\u{2f8df}', "\u{67fa}"), ('\u{2f8e0}', "\u{6785}"),
+    ('\u{2f8e1}', "\u{6852}"), ('\u{2f8e2}', "\u{6885}"), ('\u{2f8e3}', "\u{2346d}"),
+    ('\u{2f8e4}', "\u{688e}"), ('\u{2f8e5}', "\u{681f}"), ('\u{2f8e6}', "\u{6914}"),
+    ('\u{2f8e7}', "\u{3b9d}"), ('\u{2f8e8}', "\u{6942}"), ('\u{2f8e9}', "\u{69a3}"),
+    ('\u{2f8ea}', "\u{69ea}"), ('\u{2f8eb}', "\u{6aa8}"), ('\u{2f8ec}', "\u{236a3}"),
+    ('\u{2f8ed}', "\u{6adb}"), ('\u{2f8ee}', "\u{3c18}"), ('\u{2f8ef}', "\u{6b21}"),
+    ('\u{2f8f0}', "\u{238a7}"), ('\u{2f8f1}', "\u{6b54}"), ('\u{2f8f2}', "\u{3c4e}"),
+    ('\u{2f8f3}', "\u{6b72}"), ('\u{2f8f4}', "\u{6b9f}"), ('\u{2f8f5}', "\u{6bba}"),
+    ('\u{2f8f6}', "\u{6bbb}"), ('\u{2f8f7}', "\u{23a8d}"), ('\u{2f8f8}', "\u{21d0b}"),
+    ('\u{2f8f9}', "\u{23afa}"), ('\u{2f8fa}', "\u{6c4e}"), ('\u{2f8fb}', "\u{23cbc}"),
+    ('\u{2f8fc}', "\u{6cbf}"), ('\u{2f8fd}', "\u{6ccd}"), ('\u{2f8fe}', "\u{6c67}"),
+    ('\u{2f8ff}', "\u{6d16}"), ('\u{2f900}', "\u{6d3e}"), ('\u{2f901}', "\u{6d77}"),
+    ('\u{2f902}', "\u{6d41}"), ('\u{2f903}', "\u{6d69}"), ('\u{2f904}', "\u{6d78}"),
+    ('\u{2f905}', "\u{6d85}"), ('\u{2f906}', "\u{23d1e}"), ('\u{2f907}', "\u{6d34}"),
+    ('\u{2f908}', "\u{6e2f}"), ('\u{2f909}', "\u{6e6e}"), ('\u{2f90a}', "\u{3d33}"),
+    ('\u{2f90b}', "\u{6ecb}"), ('\u{2f90c}', "\u{6ec7}"), ('\u{2f90d}', "\u{23ed1}"),
+    ('\u{2f90e}', "\u{6df9}"), ('\u{2f90f}', "\u{6f6e}"), ('\u{2f910}', "\u{23f5e}"),
+    ('\u{2f911}', "\u{23f8e}"), ('\u{2f912}', "\u{6fc6}"), ('\u{2f913}', "\u{7039}"),
+    ('\u{2f914}', "\u{701e}"), ('\u{2f915}', "\u{701b}"), ('\u{2f916}', "\u{3d96}"),
+    ('\u{2f917}', "\u{704a}"), ('\u{2f918}', "\u{707d}"), ('\u{2f919}', "\u{7077}"),
+    ('\u{2f91a}', "\u{70ad}"), ('\u{2f91b}', "\u{20525}"), ('\u{2f91c}', "\u{7145}"),
+    ('\u{2f91d}', "\u{24263}"), ('\u{2f91e}', "\u{719c}"), ('\u{2f91f}', "\u{243ab}"),
+    ('\u{2f920}', "\u{7228}"), ('\u{2f921}', "\u{7235}"), ('\u{2f922}', "\u{7250}"),
+    ('\u{2f923}', "\u{24608}"), ('\u{2f924}', "\u{7280}"), ('\u{2f925}', "\u{7295}"),
+    ('\u{2f926}', "\u{24735}"), ('\u{2f927}', "\u{24814}"), ('\u{2f928}', "\u{737a}"),
+    ('\u{2f929}', "\u{738b}"), ('\u{2f92a}', "\u{3eac}"), ('\u{2f92b}', "\u{73a5}"),
+    ('\u{2f92c}', "\u{3eb8}"), ('\u{2f92d}', "\u{3eb8}"), ('\u{2f92e}', "\u{7447}"),
+    ('\u{2f92f}', "\u{745c}"), ('\u{2f930}', "\u{7471}"), ('\u{2f931}', "\u{7485}"),
+    ('\u{2f932}', "\u{74ca}"), ('\u{2f933}', "\u{3f1b}"), ('\u{2f934}', "\u{7524}"),
+    ('\u{2f935}', "\u{24c36}"), ('\u{2f936}', "\u{753e}"), ('\u{2f937}', "\u{24c92}"),
+    ('\u{2f938}', "\u{7570}"), ('\u{2f939}', "\u{2219f}"), ('\u{2f93a}', "\u{7610}"),
+    ('\u{2f93b}', "\u{24fa1}"), ('\u{2f93c}', "\u{24fb8}"), ('\u{2f93d}', "\u{25044}"),
+    ('\u{2f93e}', "\u{3ffc}"), ('\u{2f93f}', "\u{4008}"), ('\u{2f940}', "\u{76f4}"),
+    ('\u{2f941}', "\u{250f3}"), ('\u{2f942}', "\u{250f2}"), ('\u{2f943}', "\u{25119}"),
+    ('\u{2f944}', "\u{25133}"), ('\u{2f945}', "\u{771e}"), ('\u{2f946}', "\u{771f}"),
+    ('\u{2f947}', "\u{771f}"), ('\u{2f948}', "\u{774a}"), ('\u{2f949}', "\u{4039}"),
+    ('\u{2f94a}', "\u{778b}"), ('\u{2f94b}', "\u{4046}"), ('\u{2f94c}', "\u{4096}"),
+    ('\u{2f94d}', "\u{2541d}"), ('\u{2f94e}', "\u{784e}"), ('\u{2f94f}', "\u{788c}"),
+    ('\u{2f950}', "\u{78cc}"), ('\u{2f951}', "\u{40e3}"), ('\u{2f952}', "\u{25626}"),
+    ('\u{2f953}', "\u{7956}"), ('\u{2f954}', "\u{2569a}"), ('\u{2f955}', "\u{256c5}"),
+    ('\u{2f956}', "\u{798f}"), ('\u{2f957}', "\u{79eb}"), ('\u{2f958}', "\u{412f}"),
+    ('\u{2f959}', "\u{7a40}"), ('\u{2f95a}', "\u{7a4a}"), ('\u{2f95b}', "\u{7a4f}"),
+    ('\u{2f95c}', "\u{2597c}"), ('\u{2f95d}', "\u{25aa7}"), ('\u{2f95e}', "\u{25aa7}"),
+    ('\u{2f95f}', "\u{7aee}"), ('\u{2f960}', "\u{4202}"), ('\u{2f961}', "\u{25bab}"),
+    ('\u{2f962}', "\u{7bc6}"), ('\u{2f963}', "\u{7bc9}"), ('\u{2f964}', "\u{4227}"),
+    ('\u{2f965}', "\u{25c80}"), ('\u{2f966}', "\u{7cd2}"), ('\u{2f967}', "\u{42a0}"),
+    ('\u{2f968}', "\u{7ce8}"), ('\u{2f969}', "\u{7ce3}"), ('\u{2f96a}', "\u{7d00}"),
+    ('\u{2f96b}', "\u{25f86}"), ('\u{2f96c}', "\u{7d63}"), ('\u{2f96d}', "\u{4301}"),
+    ('\u{2f96e}', "\u{7dc7}"), ('\u{2f96f}', "\u{7e02}"), ('\u{2f970}', "\u{7e45}"),
+    ('\u{2f971}', "\u{4334}"), ('\u{2f972}', "\u{26228}"), ('\u{2f973}', "\u{26247}"),
+    ('\u{2f974}', "\u{4359}"), ('\u{2f975}', "\u{262d9}"), ('\u{2f976}', "\u{7f7a}"),
+    ('\u{2f977}', "\u{2633e}"), ('\u{2f978}', "\u{7f95}"), ('\u{2f979}', "\u{7ffa}"),
+    ('\u{2f97a}', "\u{8005}"), ('\u{2f97b}', "\u{264da}"), ('\u{2f97c}', "\u{26523}"),
+    ('\u{2f97d}', "\u{8060}"), ('\u{2f97e}', "\u{265a8}"), ('\u{2f97f}', "\u{8070}"),
+    ('\u{2f980}', "\u{2335f}"), ('\u{2f981}', "\u{43d5}"), ('\u{2f982}', "\u{80b2}"),
+    ('\u{2f983}', "\u{8103}"), ('\u{2f984}', "\u{440b}"), ('\u{2f985}', "\u{813e}"),
+    ('\u{2f986}', "\u{5ab5}"), ('\u{2f987}', "\u{267a7}"), ('\u{2f988}', "\u{267b5}"),
+    ('\u{2f989}', "\u{23393}"), ('\u{2f98a}', "\u{2339c}"), ('\u{2f98b}', "\u{8201}"),
+    ('\u{2f98c}', "\u{8204}"), ('\u{2f98d}', "\u{8f9e}"), ('\u{2f98e}', "\u{446b}"),
+    ('\u{2f98f}', "\u{8291}"), ('\u{2f990}', "\u{828b}"), ('\u{2f991}', "\u{829d}"),
+    ('\u{2f992}', "\u{52b3}"), ('\u{2f993}', "\u{82b1}"), ('\u{2f994}', "\u{82b3}"),
+    ('\u{2f995}', "\u{82bd}"), ('\u{2f996}', "\u{82e6}"), ('\u{2f997}', "\u{26b3c}"),
+    ('\u{2f998}', "\u{82e5}"), ('\u{2f999}', "\u{831d}"), ('\u{2f99a}', "\u{8363}"),
+    ('\u{2f99b}', "\u{83ad}"), ('\u{2f99c}', "\u{8323}"), ('\u{2f99d}', "\u{83bd}"),
+    ('\u{2f99e}', "\u{83e7}"), ('\u{2f99f}', "\u{8457}"), ('\u{2f9a0}', "\u{8353}"),
+    ('\u{2f9a1}', "\u{83ca}"), ('\u{2f9a2}', "\u{83cc}"), ('\u{2f9a3}', "\u{83dc}"),
+    ('\u{2f9a4}', "\u{26c36}"), ('\u{2f9a5}', "\u{26d6b}"), ('\u{2f9a6}', "\u{26cd5}"),
+    ('\u{2f9a7}', "\u{452b}"), ('\u{2f9a8}', "\u{84f1}"), ('\u{2f9a9}', "\u{84f3}"),
+    ('\u{2f9aa}', "\u{8516}"), ('\u{2f9ab}', "\u{273ca}"), ('\u{2f9ac}', "\u{8564}"),
+    ('\u{2f9ad}', "\u{26f2c}"), ('\u{2f9ae}', "\u{455d}"), ('\u{2f9af}', "\u{4561}"),
+    ('\u{2f9b0}', "\u{26fb1}"), ('\u{2f9b1}', "\u{270d2}"), ('\u{2f9b2}', "\u{456b}"),
+    ('\u{2f9b3}', "\u{8650}"), ('\u{2f9b4}', "\u{865c}"), ('\u{2f9b5}', "\u{8667}"),
+    ('\u{2f9b6}', "\u{8669}"), ('\u{2f9b7}', "\u{86a9}"), ('\u{2f9b8}', "\u{8688}"),
+    ('\u{2f9b9}', "\u{870e}"), ('\u{2f9ba}', "\u{86e2}"), ('\u{2f9bb}', "\u{8779}"),
+    ('\u{2f9bc}', "\u{8728}"), ('\u{2f9bd}', "\u{876b}"), ('\u{2f9be}', "\u{8786}"),
+    ('\u{2f9bf}', "\u{45d7}"), ('\u{2f9c0}', "\u{87e1}"), ('\u{2f9c1}', "\u{8801}"),
+    ('\u{2f9c2}', "\u{45f9}"), ('\u{2f9c3}', "\u{8860}"), ('\u{2f9c4}', "\u{8863}"),
+    ('\u{2f9c5}', "\u{27667}"), ('\u{2f9c6}', "\u{88d7}"), ('\u{2f9c7}', "\u{88de}"),
+    ('\u{2f9c8}', "\u{4635}"), ('\u{2f9c9}', "\u{88fa}"), ('\u{2f9ca}', "\u{34bb}"),
+    ('\u{2f9cb}', "\u{278ae}"), ('\u{2f9cc}', "\u{27966}"), ('\u{2f9cd}', "\u{46be}"),
+    ('\u{2f9ce}', "\u{46c7}"), ('\u{2f9cf}', "\u{8aa0}"), ('\u{2f9d0}', "\u{8aed}"),
+    ('\u{2f9d1}', "\u{8b8a}"), ('\u{2f9d2}', "\u{8c55}"), ('\u{2f9d3}', "\u{27ca8}"),
+    ('\u{2f9d4}', "\u{8cab}"), ('\u{2f9d5}', "\u{8cc1}"), ('\u{2f9d6}', "\u{8d1b}"),
+    ('\u{2f9d7}', "\u{8d77}"), ('\u{2f9d8}', "\u{27f2f}"), ('\u{2f9d9}', "\u{20804}"),
+    ('\u{2f9da}', "\u{8dcb}"), ('\u{2f9db}', "\u{8dbc}"), ('\u{2f9dc}', "\u{8df0}"),
+    ('\u{2f9dd}', "\u{208de}"), ('\u{2f9de}', "\u{8ed4}"), ('\u{2f9df}', "\u{8f38}"),
+    ('\u{2f9e0}', "\u{285d2}"), ('\u{2f9e1}', "\u{285ed}"), ('\u{2f9e2}', "\u{9094}"),
+    ('\u{2f9e3}', "\u{90f1}"), ('\u{2f9e4}', "\u{9111}"), ('\u{2f9e5}', "\u{2872e}"),
+    ('\u{2f9e6}', "\u{911b}"), ('\u{2f9e7}', "\u{9238}"), ('\u{2f9e8}', "\u{92d7}"),
+    ('\u{2f9e9}', "\u{92d8}"), ('\u{2f9ea}', "\u{927c}"), ('\u{2f9eb}', "\u{93f9}"),
+    ('\u{2f9ec}', "\u{9415}"), ('\u{2f9ed}', "\u{28bfa}"), ('\u{2f9ee}', "\u{958b}"),
+    ('\u{2f9ef}', "\u{4995}"), ('\u{2f9f0}', "\u{95b7}"), ('\u{2f9f1}', "\u{28d77}"),
+    ('\u{2f9f2}', "\u{49e6}"), ('\u{2f9f3}', "\u{96c3}"), ('\u{2f9f4}', "\u{5db2}"),
+    ('\u{2f9f5}', "\u{9723}"), ('\u{2f9f6}', "\u{29145}"), ('\u{2f9f7}', "\u{2921a}"),
+    ('\u{2f9f8}', "\u{4a6e}"), ('\u{2f9f9}', "\u{4a76}"), ('\u{2f9fa}', "\u{97e0}"),
+    ('\u{2f9fb}', "\u{2940a}"), ('\u{2f9fc}', "\u{4ab2}"), ('\u{2f9fd}', "\u{29496}"),
+    ('\u{2f9fe}', "\u{980b}"), ('\u{2f9ff}', "\u{980b}"), ('\u{2fa00}', "\u{9829}"),
+    ('\u{2fa01}', "\u{295b6}"), ('\u{2fa02}', "\u{98e2}"), ('\u{2fa03}', "\u{4b33}"),
+    ('\u{2fa04}', "\u{9929}"), ('\u{2fa05}', "\u{99a7}"), ('\u{2fa06}', "\u{99c2}"),
+    ('\u{2fa07}', "\u{99fe}"), ('\u{2fa08}', "\u{4bce}"), ('\u{2fa09}', "\u{29b30}"),
+    ('\u{2fa0a}', "\u{9b12}"), ('\u{2fa0b}', "\u{9c40}"), ('\u{2fa0c}', "\u{9cfd}"),
+    ('\u{2fa0d}', "\u{4cce}"), ('\u{2fa0e}', "\u{4ced}"), ('\u{2fa0f}', "\u{9d67}"),
+    ('\u{2fa10}', "\u{2a0ce}"), ('\u{2fa11}', "\u{4cf8}"), ('\u{2fa12}', "\u{2a105}"),
+    ('\u{2fa13}', "\u{2a20e}"), ('\u{2fa14}', "\u{2a291}"), ('\u{2fa15}', "\u{9ebb}"),
+    ('\u{2fa16}', "\u{4d56}"), ('\u{2fa17}', "\u{9ef9}"), ('\u{2fa18}', "\u{9efe}"),
+    ('\u{2fa19}', "\u{9f05}"), ('\u{2fa1a}', "\u{9f0f}"), ('\u{2fa1b}', "\u{9f16}"),
+    ('\u{2fa1c}', "\u{9f3b}"), ('\u{2fa1d}', "\u{2a600}")
+];
+
+// The pairs of characters that compose into another, sorted
+// by the pair, apart from Hangul, which is composed by an algorithm
+pub const COMPOSITION: &[(char, char, char)] = &[
+    ('\u{3c}', '\u{338}', '\u{226e}'), ('\u{3d}', '\u{338}', '\u{2260}'), ('\u{3e}', '\u{338}', '\u{226f}'),
+    ('A', '\u{300}', '\u{c0}'), ('A', '\u{301}', '\u{c1}'), ('A', '\u{302}', '\u{c2}'),
+    ('A', '\u{303}', '\u{c3}'), ('A', '\u{304}', '\u{100}'), ('A', '\u{306}', '\u{102}'),
+    ('A', '\u{307}', '\u{226}'), ('A', '\u{308}', '\u{c4}'), ('A', '\u{309}', '\u{1ea2}'),
+    ('A', '\u{30a}', '\u{c5}'), ('A', '\u{30c}', '\u{1cd}'), ('A', '\u{30f}', '\u{200}'),
+    ('A', '\u{311}', '\u{202}'), ('A', '\u{323}', '\u{1ea0}'), ('A', '\u{325}', '\u{1e00}'),
+    ('A', '\u{328}', '\u{104}'), ('B', '\u{307}', '\u{1e02}'), ('B', '\u{323}', '\u{1e04}'),
+    ('B', '\u{331}', '\u{1e06}'), ('C', '\u{301}', '\u{106}'), ('C', '\u{302}', '\u{108}'),
+    ('C', '\u{307}', '\u{10a}'), ('C', '\u{30c}', '\u{10c}'), ('C', '\u{327}', '\u{c7}'),
+    ('D', '\u{307}', '\u{1e0a}'), ('D', '\u{30c}', '\u{10e}'), ('D', '\u{323}', '\u{1e0c}'),
+    ('D', '\u{327}', '\u{1e10}'), ('D', '\u{32d}', '\u{1e12}'), ('D', '\u{331}', '\u{1e0e}'),
+    ('E', '\u{300}', '\u{c8}'), ('E', '\u{301}', '\u{c9}'), ('E', '\u{302}', '\u{ca}'),
+    ('E', '\u{303}', '\u{1ebc}'), ('E', '\u{304}', '\u{112}'), ('E', '\u{306}', '\u{114}'),
+    ('E', '\u{307}', '\u{116}'), ('E', '\u{308}', '\u{cb}'), ('E', '\u{309}', '\u{1eba}'),
+    ('E', '\u{30c}', '\u{11a}'), ('E', '\u{30f}', '\u{204}'), ('E', '\u{311}', '\u{206}'),
+    ('E', '\u{323}', '\u{1eb8}'), ('E', '\u{327}', '\u{228}'), ('E', '\u{328}', '\u{118}'),
+    ('E', '\u{32d}', '\u{1e18}'), ('E', '\u{330}', '\u{1e1a}'), ('F', '\u{307}', '\u{1e1e}'),
+    ('G', '\u{301}', '\u{1f4}'), ('G', '\u{302}', '\u{11c}'), ('G', '\u{304}', '\u{1e20}'),
+    ('G', '\u{306}', '\u{11e}'), ('G', '\u{307}', '\u{120}'), ('G', '\u{30c}', '\u{1e6}'),
+    ('G', '\u{327}', '\u{122}'), ('H', '\u{302}', '\u{124}'), ('H', '\u{307}', '\u{1e22}'),
+    ('H', '\u{308}', '\u{1e26}'), ('H', '\u{30c}', '\u{21e}'), ('H', '\u{323}', '\u{1e24}'),
+    ('H', '\u{327}', '\u{1e28}'), ('H', '\u{32e}', '\u{1e2a}'), ('I', '\u{300}', '\u{cc}'),
+    ('I', '\u{301}', '\u{cd}'), ('I', '\u{302}', '\u{ce}'), ('I', '\u{303}', '\u{128}'),
+    ('I', '\u{304}', '\u{12a}'), ('I', '\u{306}', '\u{12c}'), ('I', '\u{307}', '\u{130}'),
+    ('I', '\u{308}', '\u{cf}'), ('I', '\u{309}', '\u{1ec8}'), ('I', '\u{30c}', '\u{1cf}'),
+    ('I', '\u{30f}', '\u{208}'), ('I', '\u{311}', '\u{20a}'), ('I', '\u{323}', '\u{1eca}'),
+    ('I', '\u{328}', '\u{12e}'), ('I', '\u{330}', '\u{1e2c}'), ('J', '\u{302}', '\u{134}'),
+    ('K', '\u{301}', '\u{1e30}'), ('K', '\u{30c}', '\u{1e8}'), ('K', '\u{323}', '\u{1e32}'),
+    ('K', '\u{327}', '\u{136}'), ('K', '\u{331}', '\u{1e34}'), ('L', '\u{301}', '\u{139}'),
+    ('L', '\u{30c}', '\u{13d}'), ('L', '\u{323}', '\u{1e36}'), ('L', '\u{327}', '\u{13b}'),
+    ('L', '\u{32d}', '\u{1e3c}'), ('L', '\u{331}', '\u{1e3a}'), ('M', '\u{301}', '\u{1e3e}'),
+    ('M', '\u{307}', '\u{1e40}'), ('M', '\u{323}', '\u{1e42}'), ('N', '\u{300}', '\u{1f8}'),
+    ('N', '\u{301}', '\u{143}'), ('N', '\u{303}', '\u{d1}'), ('N', '\u{307}', '\u{1e44}'),
+    ('N', '\u{30c}', '\u{147}'), ('N', '\u{323}', '\u{1e46}'), ('N', '\u{327}', '\u{145}'),
+    ('N', '\u{32d}', '\u{1e4a}'), ('N', '\u{331}', '\u{1e48}'), ('O', '\u{300}', '\u{d2}'),
+    ('O', '\u{301}', '\u{d3}'), ('O', '\u{302}', '\u{d4}'), ('O', '\u{303}', '\u{d5}'),
+    ('O', '\u{304}', '\u{14c}'), ('O', '\u{306}', '\u{14e}'), ('O', '\u{307}', '\u{22e}'),
+    ('O', '\u{308}', '\u{d6}'), ('O', '\u{309}', '\u{1ece}'), ('O', '\u{30b}', '\u{150}'),
+    ('O', '\u{30c}', '\u{1d1}'), ('O', '\u{30f}', '\u{20c}'), ('O', '\u{311}', '\u{20e}'),
+    ('O', '\u{31b}', '\u{1a0}'), ('O', '\u{323}', '\u{1ecc}'), ('O', '\u{328}', '\u{1ea}'),
+    ('P', '\u{301}', '\u{1e54}'), ('P', '\u{307}', '\u{1e56}'), ('R', '\u{301}', '\u{154}'),
+    ('R', '\u{307}', '\u{1e58}'), ('R', '\u{30c}', '\u{158}'), ('R', '\u{30f}', '\u{210}'),
+    ('R', '\u{311}', '\u{212}'), ('R', '\u{323}', '\u{1e5a}'), ('R', '\u{327}', '\u{156}'),
+    ('R', '\u{331}', '\u{1e5e}'), ('S', '\u{301}', '\u{15a}'), ('S', '\u{302}', '\u{15c}'),
+    ('S', '\u{307}', '\u{1e60}'), ('S', '\u{30c}', '\u{160}'), ('S', '\u{323}', '\u{1e62}'),
+    ('S', '\u{326}', '\u{218}'), ('S', '\u{327}', '\u{15e}'), ('T', '\u{307}', '\u{1e6a}'),
+    ('T', '\u{30c}', '\u{164}'), ('T', '\u{323}', '\u{1e6c}'), ('T', '\u{326}', '\u{21a}'),
+    ('T', '\u{327}', '\u{162}'), ('T', '\u{32d}', '\u{1e70}'), ('T', '\u{331}', '\u{1e6e}'),
+    ('U', '\u{300}', '\u{d9}'), ('U', '\u{301}', '\u{da}'), ('U', '\u{302}', '\u{db}'),
+    ('U', '\u{303}', '\u{168}'), ('U', '\u{304}', '\u{16a}'), ('U', '\u{306}', '\u{16c}'),
+    ('U', '\u{308}', '\u{dc}'), ('U', '\u{309}', '\u{1ee6}'), ('U', '\u{30a}', '\u{16e}'),
+    ('U', '\u{30b}', '\u{170}'), ('U', '\u{30c}', '\u{1d3}'), ('U', '\u{30f}', '\u{214}'),
+    ('U', '\u{311}', '\u{216}'), ('U', '\u{31b}', '\u{1af}'), ('U', '\u{323}', '\u{1ee4}'),
+    ('U', '\u{324}', '\u{1e72}'), ('U', '\u{328}', '\u{172}'), ('U', '\u{32d}', '\u{1e76}'),
+    ('U', '\u{330}', '\u{1e74}'), ('V', '\u{303}', '\u{1e7c}'), ('V', '\u{323}', '\u{1e7e}'),
+    ('W', '\u{300}', '\u{1e80}'), ('W', '\u{301}', '\u{1e82}'), ('W', '\u{302}', '\u{174}'),
+    ('W', '\u{307}', '\u{1e86}'), ('W', '\u{308}', '\u{1e84}'), ('W', '\u{323}', '\u{1e88}'),
+    ('X', '\u{307}', '\u{1e8a}'), ('X', '\u{308}', '\u{1e8c}'), ('Y', '\u{300}', '\u{1ef2}'),
+    ('Y', '\u{301}', '\u{dd}'), ('Y', '\u{302}', '\u{176}'), ('Y', '\u{303}', '\u{1ef8}'),
+    ('Y', '\u{304}', '\u{232}'), ('Y', '\u{307}', '\u{1e8e}'), ('Y', '\u{308}', '\u{178}'),
+    ('Y', '\u{309}', '\u{1ef6}'), ('Y', '\u{323}', '\u{1ef4}'), ('Z', '\u{301}', '\u{179}'),
+    ('Z', '\u{302}', '\u{1e90}'), ('Z', '\u{307}', '\u{17b}'), ('Z', '\u{30c}', '\u{17d}'),
+    ('Z', '\u{323}', '\u{1e92}'), ('Z', '\u{331}', '\u{1e94}'), ('a', '\u{300}', '\u{e0}'),
+    ('a', '\u{301}', '\u{e1}'), ('a', '\u{302}', '\u{e2}'), ('a', '\u{303}', '\u{e3}'),
+    ('a', '\u{304}', '\u{101}'), ('a', '\u{306}', '\u{103}'), ('a', '\u{307}', '\u{227}'),
+    ('a', '\u{308}', '\u{e4}'), ('a', '\u{309}', '\u{1ea3}'), ('a', '\u{30a}', '\u{e5}'),
+    ('a', '\u{30c}', '\u{1ce}'), ('a', '\u{30f}', '\u{201}'), ('a', '\u{311}', '\u{203}'),
+    ('a', '\u{323}', '\u{1ea1}'), ('a', '\u{325}', '\u{1e01}'), ('a', '\u{328}', '\u{105}'),
+    ('b', '\u{307}', '\u{1e03}'), ('b', '\u{323}', '\u{1e05}'), ('b', '\u{331}', '\u{1e07}'),
+    ('c', '\u{301}', '\u{107}'), ('c', '\u{302}', '\u{109}'), ('c', '\u{307}', '\u{10b}'),
+    ('c', '\u{30c}', '\u{10d}'), ('c', '\u{327}', '\u{e7}'), ('d', '\u{307}', '\u{1e0b}'),
+    ('d', '\u{30c}', '\u{10f}'), ('d', '\u{323}', '\u{1e0d}'), ('d', '\u{327}', '\u{1e11}'),
+    ('d', '\u{32d}', '\u{1e13}'), ('d', '\u{331}', '\u{1e0f}'), ('e', '\u{300}', '\u{e8}'),
+    ('e', '\u{301}', '\u{e9}'), ('e', '\u{302}', '\u{ea}'), ('e', '\u{303}', '\u{1ebd}'),
+    ('e', '\u{304}', '\u{113}'), ('e', '\u{306}', '\u{115}'), ('e', '\u{307}', '\u{117}'),
+    ('e', '\u{308}', '\u{eb}'), ('e', '\u{309}', '\u{1ebb}'), ('e', '\u{30c}', '\u{11b}'),
+    ('e', '\u{30f}', '\u{205}'), ('e', '\u{311}', '\u{207}'), ('e', '\u{323}', '\u{1eb9}'),
+    ('e', '\u{327}', '\u{229}'), ('e', '\u{328}', '\u{119}'), ('e', '\u{32d}', '\u{1e19}'),
+    ('e', '\u{330}', '\u{1e1b}'), ('f', '\u{307}', '\u{1e1f}'), ('g', '\u{301}', '\u{1f5}'),
+    ('g', '\u{302}', '\u{11d}'), ('g', '\u{304}', '\u{1e21}'), ('g', '\u{306}', '\u{11f}'),
+    ('g', '\u{307}', '\u{121}'), ('g', '\u{30c}', '\u{1e7}'), ('g', '\u{327}', '\u{123}'),
+    ('h', '\u{302}', '\u{125}'), ('h', '\u{307}', '\u{1e23}'), ('h', '\u{308}', '\u{1e27}'),
+    ('h', '\u{30c}', '\u{21f}'), ('h', '\u{323}', '\u{1e25}'), ('h', '\u{327}', '\u{1e29}'),
+    ('h', '\u{32e}', '\u{1e2b}'), ('h', '\u{331}', '\u{1e96}'), ('i', '\u{300}', '\u{ec}'),
+    ('i', '\u{301}', '\u{ed}'), ('i', '\u{302}', '\u{ee}'), ('i', '\u{303}', '\u{129}'),
+    ('i', '\u{304}', '\u{12b}'), ('i', '\u{306}', '\u{12d}'), ('i', '\u{308}', '\u{ef}'),
+    ('i', '\u{309}', '\u{1ec9}'), ('i', '\u{30c}', '\u{1d0}'), ('i', '\u{30f}', '\u{209}'),
+    ('i', '\u{311}', '\u{20b}'), ('i', '\u{323}', '\u{1ecb}'), ('i', '\u{328}', '\u{12f}'),
+    ('i', '\u{330}', '\u{1e2d}'), ('j', '\u{302}', '\u{135}'), ('j', '\u{30c}', '\u{1f0}'),
+    ('k', '\u{301}', '\u{1e31}'), ('k', '\u{30c}', '\u{1e9}'), ('k', '\u{323}', '\u{1e33}'),
+    ('k', '\u{327}', '\u{137}'), ('k', '\u{331}', '\u{1e35}'), ('l', '\u{301}', '\u{13a}'),
+    ('l', '\u{30c}', '\u{13e}'), ('l', '\u{323}', '\u{1e37}'), ('l', '\u{327}', '\u{13c}'),
+    ('l', '\u{32d}', '\u{1e3d}'), ('l', '\u{331}', '\u{1e3b}'), ('m', '\u{301}', '\u{1e3f}'),
+    ('m', '\u{307}', '\u{1e41}'), ('m', '\u{323}', '\u{1e43}'), ('n', '\u{300}', '\u{1f9}'),
+    ('n', '\u{301}', '\u{144}'), ('n', '\u{303}', '\u{f1}'), ('n', '\u{307}', '\u{1e45}'),
+    ('n', '\u{30c}', '\u{148}'), ('n', '\u{323}', '\u{1e47}'), ('n', '\u{327}', '\u{146}'),
+    ('n', '\u{32d}', '\u{1e4b}'), ('n', '\u{331}', '\u{1e49}'), ('o', '\u{300}', '\u{f2}'),
+    ('o', '\u{301}', '\u{f3}'), ('o', '\u{302}', '\u{f4}'), ('o', '\u{303}', '\u{f5}'),
+    ('o', '\u{304}', '\u{14d}'), ('o', '\u{306}', '\u{14f}'), ('o', '\u{307}', '\u{22f}'),
+    ('o', '\u{308}', '\u{f6}'), ('o', '\u{309}', '\u{1ecf}'), ('o', '\u{30b}', '\u{151}'),
+    ('o', '\u{30c}', '\u{1d2}'), ('o', '\u{30f}', '\u{20d}'), ('o', '\u{311}', '\u{20f}'),
+    ('o', '\u{31b}', '\u{1a1}'), ('o', '\u{323}', '\u{1ecd}'), ('o', '\u{328}', '\u{1eb}'),
+    ('p', '\u{301}', '\u{1e55}'), ('p', '\u{307}', '\u{1e57}'), ('r', '\u{301}', '\u{155}'),
+    ('r', '\u{307}', '\u{1e59}'), ('r', '\u{30c}', '\u{159}'), ('r', '\u{30f}', '\u{211}'),
+    ('r', '\u{311}', '\u{213}'), ('r', '\u{323}', '\u{1e5b}'), ('r', '\u{327}', '\u{157}'),
+    ('r', '\u{331}', '\u{1e5f}'), ('s', '\u{301}', '\u{15b}'), ('s', '\u{302}', '\u{15d}'),
+    ('s', '\u{307}', '\u{1e61}'), ('s', '\u{30c}', '\u{161}'), ('s', '\u{323}', '\u{1e63}'),
+    ('s', '\u{326}', '\u{219}'), ('s', '\u{327}', '\u{15f}'), ('t', '\u{307}', '\u{1e6b}'),
+    ('t', '\u{308}', '\u{1e97}'), ('t', '\u{30c}', '\u{165}'), ('t', '\u{323}', '\u{1e6d}'),
+    ('t', '\u{326}', '\u{21b}'), ('t', '\u{327}', '\u{163}'), ('t', '\u{32d}', '\u{1e71}'),
+    ('t', '\u{331}', '\u{1e6f}'), ('u', '\u{300}', '\u{f9}'), ('u', '\u{301}', '\u{fa}'),
+    ('u', '\u{302}', '\u{fb}'), ('u', '\u{303}', '\u{169}'), ('u', '\u{304}', '\u{16b}'),
+    ('u', '\u{306}', '\u{16d}'), ('u', '\u{308}', '\u{fc}'), ('u', '\u{309}', '\u{1ee7}'),
+    ('u', '\u{30a}', '\u{16f}'), ('u', '\u{30b}', '\u{171}'), ('u', '\u{30c}', '\u{1d4}'),
+    ('u', '\u{30f}', '\u{215}'), ('u', '\u{311}', '\u{217}'), ('u', '\u{31b}', '\u{1b0}'),
+    ('u', '\u{323}', '\u{1ee5}'), ('u', '\u{324}', '\u{1e73}'), ('u', '\u{328}', '\u{173}'),
+    ('u', '\u{32d}', '\u{1e77}'), ('u', '\u{330}', '\u{1e75}'), ('v', '\u{303}', '\u{1e7d}'),
+    ('v', '\u{323}', '\u{1e7f}'), ('w', '\u{300}', '\u{1e81}'), ('w', '\u{301}', '\u{1e83}'),
+    ('w', '\u{302}', '\u{175}'), ('w', '\u{307}', '\u{1e87}'), ('w', '\u{308}', '\u{1e85}'),
+    ('w', '\u{30a}', '\u{1e98}'), ('w', '\u{323}', '\u{1e89}'), ('x', '\u{307}', '\u{1e8b}'),
+    ('x', '\u{308}', '\u{1e8d}'), ('y', '\u{300}', '\u{1ef3}'), ('y', '\u{301}', '\u{fd}'),
+    ('y', '\u{302}', '\u{177}'), ('y', '\u{303}', '\u{1ef9}'), ('y', '\u{304}', '\u{233}'),
+    ('y', '\u{307}', '\u{1e8f}'), ('y', '\u{308}', '\u{ff}'), ('y', '\u{309}', '\u{1ef7}'),
+    ('y', '\u{30a}', '\u{1e99}'), ('y', '\u{323}', '\u{1ef5}'), ('z', '\u{301}', '\u{17a}'),
+    ('z', '\u{302}', '\u{1e91}'), ('z', '\u{307}', '\u{17c}'), ('z', '\u{30c}', '\u{17e}'),
+    ('z', '\u{323}', '\u{1e93}'), ('z', '\u{331}', '\u{1e95}'), ('\u{a8}', '\u{300}', '\u{1fed}'),
+    ('\u{a8}', '\u{301}', '\u{385}'), ('\u{a8}', '\u{342}', '\u{1fc1}'), ('\u{c2}', '\u{300}', '\u{1ea6}'),
+    ('\u{c2}', '\u{301}', '\u{1ea4}'), ('\u{c2}', '\u{303}', '\u{1eaa}'), ('\u{c2}', '\u{309}', '\u{1ea8}'),
+    ('\u{c4}', '\u{304}', '\u{1de}'), ('\u{c5}', '\u{301}', '\u{1fa}'), ('\u{c6}', '\u{301}', '\u{1fc}'),
+    ('\u{c6}', '\u{304}', '\u{1e2}'), ('\u{c7}', '\u{301}', '\u{1e08}'), ('\u{ca}', '\u{300}', '\u{1ec0}'),
+    ('\u{ca}', '\u{301}', '\u{1ebe}'), ('\u{ca}', '\u{303}', '\u{1ec4}'), ('\u{ca}', '\u{309}', '\u{1ec2}'),
+    ('\u{cf}', '\u{301}', '\u{1e2e}'), ('\u{d4}', '\u{300}', '\u{1ed2}'), ('\u{d4}', '\u{301}', '\u{1ed0}'),
+    ('\u{d4}', '\u{303}', '\u{1ed6}'), ('\u{d4}', '\u{309}', '\u{1ed4}'), ('\u{d5}', '\u{301}', '\u{1e4c}'),
+    ('\u{d5}', '\u{304}', '\u{22c}'), ('\u{d5}', '\u{308}', '\u{1e4e}'), ('\u{d6}', '\u{304}', '\u{22a}'),
+    ('\u{d8}', '\u{301}', '\u{1fe}'), ('\u{dc}', '\u{300}', '\u{1db}'), ('\u{dc}', '\u{301}', '\u{1d7}'),
+    ('\u{dc}', '\u{304}', '\u{1d5}'), ('\u{dc}', '\u{30c}', '\u{1d9}'), ('\u{e2}', '\u{300}', '\u{1ea7}'),
+    ('\u{e2}', '\u{301}', '\u{1ea5}'), ('\u{e2}', '\u{303}', '\u{1eab}'), ('\u{e2}', '\u{309}', '\u{1ea9}'),
+    ('\u{e4}', '\u{304}', '\u{1df}'), ('\u{e5}', '\u{301}', '\u{1fb}'), ('\u{e6}', '\u{301}', '\u{1fd}'),
+    ('\u{e6}', '\u{304}', '\u{1e3}'), ('\u{e7}', '\u{301}', '\u{1e09}'), ('\u{ea}', '\u{300}', '\u{1ec1}'),
+    ('\u{ea}', '\u{301}', '\u{1ebf}'), ('\u{ea}', '\u{303}', '\u{1ec5}'), ('\u{ea}', '\u{309}', '\u{1ec3}'),
+    ('\u{ef}', '\u{301}', '\u{1e2f}'), ('\u{f4}', '\u{300}', '\u{1ed3}'), ('\u{f4}', '\u{301}', '\u{1ed1}'),
+    ('\u{f4}', '\u{303}', '\u{1ed7}'), ('\u{f4}', '\u{309}', '\u{1ed5}'), ('\u{f5}', '\u{301}', '\u{1e4d}'),
+    ('\u{f5}', '\u{304}', '\u{22d}'), ('\u{f5}', '\u{308}', '\u{1e4f}'), ('\u{f6}', '\u{304}', '\u{22b}'),
+    ('\u{f8}', '\u{301}', '\u{1ff}'), ('\u{fc}', '\u{300}', '\u{1dc}'), ('\u{fc}', '\u{301}', '\u{1d8}'),
+    ('\u{fc}', '\u{304}', '\u{1d6}'), ('\u{fc}', '\u{30c}', '\u{1da}'), ('\u{102}', '\u{300}', '\u{1eb0}'),
+    ('\u{102}', '\u{301}', '\u{1eae}'), ('\u{102}', '\u{303}', '\u{1eb4}'), ('\u{102}', '\u{309}', '\u{1eb2}'),
+    ('\u{103}', '\u{300}', '\u{1eb1}'), ('\u{103}', '\u{301}', '\u{1eaf}'), ('\u{103}', '\u{303}', '\u{1eb5}'),
+    ('\u{103}', '\u{309}', '\u{1eb3}'), ('\u{112}', '\u{300}', '\u{1e14}'), ('\u{112}', '\u{301}', '\u{1e16}'),
+    ('\u{113}', '\u{300}', '\u{1e15}'), ('\u{113}', '\u{301}', '\u{1e17}'), ('\u{14c}', '\u{300}', '\u{1e50}'),
+    ('\u{14c}', '\u{301}', '\u{1e52}'), ('\u{14d}', '\u{300}', '\u{1e51}'), ('\u{14d}', '\u{301}', '\u{1e53}'),
+    ('\u{15a}', '\u{307}', '\u{1e64}'), ('\u{15b}', '\u{307}', '\u{1e65}'), ('\u{160}', '\u{307}', '\u{1e66}'),
+    ('\u{161}', '\u{307}', '\u{1e67}'), ('\u{168}', '\u{301}', '\u{1e78}'), ('\u{169}', '\u{301}', '\u{1e79}'),
+    ('\u{16a}', '\u{308}', '\u{1e7a}'), ('\u{16b}', '\u{308}', '\u{1e7b}'), ('\u{17f}', '\u{307}', '\u{1e9b}'),
+    ('\u{1a0}', '\u{300}', '\u{1edc}'), ('\u{1a0}', '\u{301}', '\u{1eda}'), ('\u{1a0}', '\u{303}', '\u{1ee0}'),
+    ('\u{1a0}', '\u{309}', '\u{1ede}'), ('\u{1a0}', '\u{323}', '\u{1ee2}'), ('\u{1a1}', '\u{300}', '\u{1edd}'),
+    ('\u{1a1}', '\u{301}', '\u{1edb}'), ('\u{1a1}', '\u{303}', '\u{1ee1}'), ('\u{1a1}', '\u{309}', '\u{1edf}'),
+    ('\u{1a1}', '\u{323}', '\u{1ee3}'), ('\u{1af}', '\u{300}', '\u{1eea}'), ('\u{1af}', '\u{301}', '\u{1ee8}'),
+    ('\u{1af}', '\u{303}', '\u{1eee}'), ('\u{1af}', '\u{309}', '\u{1eec}'), ('\u{1af}', '\u{323}', '\u{1ef0}'),
+    ('\u{1b0}', '\u{300}', '\u{1eeb}'), ('\u{1b0}', '\u{301}', '\u{1ee9}'), ('\u{1b0}', '\u{303}', '\u{1eef}'),
+    ('\u{1b0}', '\u{309}', '\u{1eed}'), ('\u{1b0}', '\u{323}', '\u{1ef1}'), ('\u{1b7}', '\u{30c}', '\u{1ee}'),
+    ('\u{1ea}', '\u{304}', '\u{1ec}'), ('\u{1eb}', '\u{304}', '\u{1ed}'), ('\u{226}', '\u{304}', '\u{1e0}'),
+    ('\u{227}', '\u{304}', '\u{1e1}'), ('\u{228}', '\u{306}', '\u{1e1c}'), ('\u{229}', '\u{306}', '\u{1e1d}'),
+    ('\u{22e}', '\u{304}', '\u{230}'), ('\u{22f}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30c}', '\u{1ef}'),
+    ('\u{391}', '\u{300}', '\u{1fba}'), ('\u{391}', '\u{301}', '\u{386}'), ('\u{391}', '\u{304}', '\u{1fb9}'),
+    ('\u{391}', '\u{306}', '\u{1fb8}'), ('\u{391}', '\u{313}', '\u{1f08}'), ('\u{391}', '\u{314}', '\u{1f09}'),
+    ('\u{391}', '\u{345}', '\u{1fbc}'), ('\u{395}', '\u{300}', '\u{1fc8}'), ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1f18}'), ('\u{395}', '\u{314}', '\u{1f19}'), ('\u{397}', '\u{300}', '\u{1fca}'),
+    ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1f28}'), ('\u{397}', '\u{314}', '\u{1f29}'),
+    ('\u{397}', '\u{345}', '\u{1fcc}'), ('\u{399}', '\u{300}', '\u{1fda}'), ('\u{399}', '\u{301}', '\u{38a}'),
+    ('\u{399}', '\u{304}', '\u{1fd9}'), ('\u{399}', '\u{306}', '\u{1fd8}'), ('\u{399}', '\u{308}', '\u{3aa}'),
+    ('\u{399}', '\u{313}', '\u{1f38}'), ('\u{399}', '\u{314}', '\u{1f39}'), ('\u{39f}', '\u{300}', '\u{1ff8}'),
+    ('\u{39f}', '\u{301}', '\u{38c}'), ('\u{39f}', '\u{313}', '\u{1f48}'), ('\u{39f}', '\u{314}', '\u{1f49}'),
+    ('\u{3a1}', '\u{314}', '\u{1fec}'), ('\u{3a5}', '\u{300}', '\u{1fea}'), ('\u{3a5}', '\u{301}', '\u{38e}'),
+    ('\u{3a5}', '\u{304}', '\u{1fe9}'), ('\u{3a5}', '\u{306}', '\u{1fe8}'), ('\u{3a5}', '\u{308}', '\u{3ab}'),
+    ('\u{3a5}', '\u{314}', '\u{1f59}'), ('\u{3a9}', '\u{300}', '\u{1ffa}'), ('\u{3a9}', '\u{301}', '\u{38f}'),
+    ('\u{3a9}', '\u{313}', '\u{1f68}'), ('\u{3a9}', '\u{314}', '\u{1f69}'), ('\u{3a9}', '\u{345}', '\u{1ffc}'),
+    ('\u{3ac}', '\u{345}', '\u{1fb4}'), ('\u{3ae}', '\u{345}', '\u{1fc4}'), ('\u{3b1}', '\u{300}', '\u{1f70}'),
+    ('\u{3b1}', '\u{301}', '\u{3ac}'), ('\u{3b1}', '\u{304}', '\u{1fb1}'), ('\u{3b1}', '\u{306}', '\u{1fb0}'),
+    ('\u{3b1}', '\u{313}', '\u{1f00}'), ('\u{3b1}', '\u{314}', '\u{1f01}'), ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+    ('\u{3b1}', '\u{345}', '\u{1fb3}'), ('\u{3b5}', '\u{300}', '\u{1f72}'), ('\u{3b5}', '\u{301}', '\u{3ad}'),
+    ('\u{3b5}', '\u{313}', '\u{1f10}'), ('\u{3b5}', '\u{314}', '\u{1f11}'), ('\u{3b7}', '\u{300}', '\u{1f74}'),
+    ('\u{3b7}', '\u{301}', '\u{3ae}'), ('\u{3b7}', '\u{313}', '\u{1f20}'), ('\u{3b7}', '\u{314}', '\u{1f21}'),
+    ('\u{3b7}', '\u{342}', '\u{1fc6}'), ('\u{3b7}', '\u{345}', '\u{1fc3}'), ('\u{3b9}', '\u{300}', '\u{1f76}'),
+    ('\u{3b9}', '\u{301}', '\u{3af}'), ('\u{3b9}', '\u{304}', '\u{1fd1}'), ('\u{3b9}', '\u{306}', '\u{1fd0}'),
+    ('\u{3b9}', '\u{308}', '\u{3ca}'), ('\u{3b9}', '\u{313}', '\u{1f30}'), ('\u{3b9}', '\u{314}', '\u{1f31}'),
+    ('\u{3b9}', '\u{342}', '\u{1fd6}'), ('\u{3bf}', '\u{300}', '\u{1f78}'), ('\u{3bf}', '\u{301}', '\u{3cc}'),
+    ('\u{3bf}', '\u{313}', '\u{1f40}'), ('\u{3bf}', '\u{314}', '\u{1f41}'), ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+    ('\u{3c1}', '\u{314}', '\u{1fe5}'), ('\u{3c5}', '\u{300}', '\u{1f7a}'), ('\u{3c5}', '\u{301}', '\u{3cd}'),
+    ('\u{3c5}', '\u{304}', '\u{1fe1}'), ('\u{3c5}', '\u{306}', '\u{1fe0}'), ('\u{3c5}', '\u{308}', '\u{3cb}'),
+    ('\u{3c5}', '\u{313}', '\u{1f50}'), ('\u{3c5}', '\u{314}', '\u{1f51}'), ('\u{3c5}', '\u{342}', '\u{1fe6}'),
+    ('\u{3c9}', '\u{300}', '\u{1f7c}'), ('\u{3c9}', '\u{301}', '\u{3ce}'), ('\u{3c9}', '\u{313}', '\u{1f60}'),
+    ('\u{3c9}', '\u{314}', '\u{1f61}'), ('\u{3c9}', '\u{342}', '\u{1ff6}'), ('\u{3c9}', '\u{345}', '\u{1ff3}'),
+    ('\u{3ca}', '\u{300}', '\u{1fd2}'), ('\u{3ca}', '\u{301}', '\u{390}'), ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+    ('\u{3cb}', '\u{300}', '\u{1fe2}'), ('\u{3cb}', '\u{301}', '\u{3b0}'), ('\u{3cb}', '\u{342}', '\u{1fe7}'),
+    ('\u{3ce}', '\u{345}', '\u{1ff4}'), ('\u{3d2}', '\u{301}', '\u{3d3}'), ('\u{3d2}', '\u{308}', '\u{3d4}'),
+    ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4d0}'), ('\u{410}', '\u{308}', '\u{4d2}'),
+    ('\u{413}', '\u{301}', '\u{403}'), ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4d6}'),
+    ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4c1}'), ('\u{416}', '\u{308}', '\u{4dc}'),
+    ('\u{417}', '\u{308}', '\u{4de}'), ('\u{418}', '\u{300}', '\u{40d}'), ('\u{418}', '\u{304}', '\u{4e2}'),
+    ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4e4}'), ('\u{41a}', '\u{301}', '\u{40c}'),
+    ('\u{41e}', '\u{308}', '\u{4e6}'), ('\u{423}', '\u{304}', '\u{4ee}'), ('\u{423}', '\u{306}', '\u{40e}'),
+    ('\u{423}', '\u{308}', '\u{4f0}'), ('\u{423}', '\u{30b}', '\u{4f2}'), ('\u{427}', '\u{308}', '\u{4f4}'),
+    ('\u{42b}', '\u{308}', '\u{4f8}'), ('\u{42d}', '\u{308}', '\u{4ec}'), ('\u{430}', '\u{306}', '\u{4d1}'),
+    ('\u{430}', '\u{308}', '\u{4d3}'), ('\u{433}', '\u{301}', '\u{453}'), ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4d7}'), ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4c2}'),
+    ('\u{436}', '\u{308}', '\u{4dd}'), ('\u{437}', '\u{308}', '\u{4df}'), ('\u{438}', '\u{300}', '\u{45d}'),
+    ('\u{438}', '\u{304}', '\u{4e3}'), ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4e5}'),
+    ('\u{43a}', '\u{301}', '\u{45c}'), ('\u{43e}', '\u{308}', '\u{4e7}'), ('\u{443}', '\u{304}', '\u{4ef}'),
+    ('\u{443}', '\u{306}', '\u{45e}'), ('\u{443}', '\u{308}', '\u{4f1}'), ('\u{443}', '\u{30b}', '\u{4f3}'),
+    ('\u{447}', '\u{308}', '\u{4f5}'), ('\u{44b}', '\u{308}', '\u{4f9}'), ('\u{44d}', '\u{308}', '\u{4ed}'),
+    ('\u{456}', '\u{308}', '\u{457}'), ('\u{474}', '\u{30f}', '\u{476}'), ('\u{475}', '\u{30f}', '\u{477}'),
+    ('\u{4d8}', '\u{308}', '\u{4da}'), ('\u{4d9}', '\u{308}', '\u{4db}'), ('\u{4e8}', '\u{308}', '\u{4ea}'),
+    ('\u{4e9}', '\u{308}', '\u{4eb}'), ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'), ('\u{64a}', '\u{654}', '\u{626}'),
+    ('\u{6c1}', '\u{654}', '\u{6c2}'), ('\u{6d2}', '\u{654}', '\u{6d3}'), ('\u{6d5}', '\u{654}', '\u{6c0}'),
+    ('\u{928}', '\u{93c}', '\u{929}'), ('\u{930}', '\u{93c}', '\u{931}'), ('\u{933}', '\u{93c}', '\u{934}'),
+    ('\u{9c7}', '\u{9be}', '\u{9cb}'), ('\u{9c7}', '\u{9d7}', '\u{9cc}'), ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+    ('\u{b47}', '\u{b56}', '\u{b48}'), ('\u{b47}', '\u{b57}', '\u{b4c}'), ('\u{b92}', '\u{bd7}', '\u{b94}'),
+    ('\u{bc6}', '\u{bbe}', '\u{bca}'), ('\u{bc6}', '\u{bd7}', '\u{bcc}'), ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+    ('\u{c46}', '\u{c56}', '\u{c48}'), ('\u{cbf}', '\u{cd5}', '\u{cc0}'), ('\u{cc6}', '\u{cc2}', '\u{cca}'),
+    ('\u{cc6}', '\u{cd5}', '\u{cc7}'), ('\u{cc6}', '\u{cd6}', '\u{cc8}'), ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+    ('\u{d46}', '\u{d3e}', '\u{d4a}'), ('\u{d46}', '\u{d57}', '\u{d4c}'), ('\u{d47}', '\u{d3e}', '\u{d4b}'),
+    ('\u{dd9}', '\u{dca}', '\u{dda}'), ('\u{dd9}', '\u{dcf}', '\u{ddc}'), ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+    ('\u{ddc}', '\u{dca}', '\u{ddd}'), ('\u{1025}', '\u{102e}', '\u{1026}'), ('\u{1b05}', '\u{1b35}', '\u{1b06}'),
+    ('\u{1b07}', '\u{1b35}', '\u{1b08}'), ('\u{1b09}', '\u{1b35}', '\u{1b0a}'), ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+    ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'), ('\u{1b11}', '\u{1b35}', '\u{1b12}'), ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'),
+    ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'), ('\u{1b3e}', '\u{1b35}', '\u{1b40}'), ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+    ('\u{1b42}', '\u{1b35}', '\u{1b43}'), ('\u{1e36}', '\u{304}', '\u{1e38}'), ('\u{1e37}', '\u{304}', '\u{1e39}'),
+    ('\u{1e5a}', '\u{304}', '\u{1e5c}'), ('\u{1e5b}', '\u{304}', '\u{1e5d}'), ('\u{1e62}', '\u{307}', '\u{1e68}'),
+    ('\u{1e63}', '\u{307}', '\u{1e69}'), ('\u{1ea0}', '\u{302}', '\u{1eac}'), ('\u{1ea0}', '\u{306}', '\u{1eb6}'),
+    ('\u{1ea1}', '\u{302}', '\u{1ead}'), ('\u{1ea1}', '\u{306}', '\u{1eb7}'), ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+    ('\u{1eb9}', '\u{302}', '\u{1ec7}'), ('\u{1ecc}', '\u{302}', '\u{1ed8}'), ('\u{1ecd}', '\u{302}', '\u{1ed9}'),
+    ('\u{1f00}', '\u{300}', '\u{1f02}'), ('\u{1f00}', '\u{301}', '\u{1f04}'), ('\u{1f00}', '\u{342}', '\u{1f06}'),
+    ('\u{1f00}', '\u{345}', '\u{1f80}'), ('\u{1f01}', '\u{300}', '\u{1f03}'), ('\u{1f01}', '\u{301}', '\u{1f05}'),
+    ('\u{1f01}', '\u{342}', '\u{1f07}'), ('\u{1f01}', '\u{345}', '\u{1f81}'), ('\u{1f02}', '\u{345}', '\u{1f82}'),
+    ('\u{1f03}', '\u{345}', '\u{1f83}'), ('\u{1f04}', '\u{345}', '\u{1f84}'), ('\u{1f05}', '\u{345}', '\u{1f85}'),
+    ('\u{1f06}', '\u{345}', '\u{1f86}'), ('\u{1f07}', '\u{345}', '\u{1f87}'), ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+    ('\u{1f08}', '\u{301}', '\u{1f0c}'), ('\u{1f08}', '\u{342}', '\u{1f0e}'), ('\u{1f08}', '\u{345}', '\u{1f88}'),
+    ('\u{1f09}', '\u{300}', '\u{1f0b}'), ('\u{1f09}', '\u{301}', '\u{1f0d}'), ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+    ('\u{1f09}', '\u{345}', '\u{1f89}'), ('\u{1f0a}', '\u{345}', '\u{1f8a}'), ('\u{1f0b}', '\u{345}', '\u{1f8b}'),
+    ('\u{1f0c}', '\u{345}', '\u{1f8c}'), ('\u{1f0d}', '\u{345}', '\u{1f8d}'), ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+    ('\u{1f0f}', '\u{345}', '\u{1f8f}'), ('\u{1f10}', '\u{300}', '\u{1f12}'), ('\u{1f10}', '\u{301}', '\u{1f14}'),
+    ('\u{1f11}', '\u{300}', '\u{1f13}'), ('\u{1f11}', '\u{301}', '\u{1f15}'), ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+    ('\u{1f18}', '\u{301}', '\u{1f1c}'), ('\u{1f19}', '\u{300}', '\u{1f1b}'), ('\u{1f19}', '\u{301}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{300}', '\u{1f22}'), ('\u{1f20}', '\u{301}', '\u{1f24}'), ('\u{1f20}', '\u{342}', '\u{1f26}'),
+    ('\u{1f20}', '\u{345}', '\u{1f90}'), ('\u{1f21}', '\u{300}', '\u{1f23}'), ('\u{1f21}', '\u{301}', '\u{1f25}'),
+    ('\u{1f21}', '\u{342}', '\u{1f27}'), ('\u{1f21}', '\u{345}', '\u{1f91}'), ('\u{1f22}', '\u{345}', '\u{1f92}'),
+    ('\u{1f23}', '\u{345}', '\u{1f93}'), ('\u{1f24}', '\u{345}', '\u{1f94}'), ('\u{1f25}', '\u{345}', '\u{1f95}'),
+    ('\u{1f26}', '\u{345}', '\u{1f96}'), ('\u{1f27}', '\u{345}', '\u{1f97}'), ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+    ('\u{1f28}', '\u{301}', '\u{1f2c}'), ('\u{1f28}', '\u{342}', '\u{1f2e}'), ('\u{1f28}', '\u{345}', '\u{1f98}'),
+    ('\u{1f29}', '\u{300}', '\u{1f2b}'), ('\u{1f29}', '\u{301}', '\u{1f2d}'), ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+    ('\u{1f29}', '\u{345}', '\u{1f99}'), ('\u{1f2a}', '\u{345}', '\u{1f9a}'), ('\u{1f2b}', '\u{345}', '\u{1f9b}'),
+    ('\u{1f2c}', '\u{345}', '\u{1f9c}'), ('\u{1f2d}', '\u{345}', '\u{1f9d}'), ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+    ('\u{1f2f}', '\u{345}', '\u{1f9f}'), ('\u{1f30}', '\u{300}', '\u{1f32}'), ('\u{1f30}', '\u{301}', '\u{1f34}'),
+    ('\u{1f30}', '\u{342}', '\u{1f36}'), ('\u{1f31}', '\u{300}', '\u{1f33}'), ('\u{1f31}', '\u{301}', '\u{1f35}'),
+    ('\u{1f31}', '\u{342}', '\u{1f37}'), ('\u{1f38}', '\u{300}', '\u{1f3a}'), ('\u{1f38}', '\u{301}', '\u{1f3c}'),
+    ('\u{1f38}', '\u{342}', '\u{1f3e}'), ('\u{1f39}', '\u{300}', '\u{1f3b}'), ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+    ('\u{1f39}', '\u{342}', '\u{1f3f}'), ('\u{1f40}', '\u{300}', '\u{1f42}'), ('\u{1f40}', '\u{301}', '\u{1f44}'),
+    ('\u{1f41}', '\u{300}', '\u{1f43}'), ('\u{1f41}', '\u{301}', '\u{1f45}'), ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+    ('\u{1f48}', '\u{301}', '\u{1f4c}'), ('\u{1f49}', '\u{300}', '\u{1f4b}'), ('\u{1f49}', '\u{301}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{300}', '\u{1f52}'), ('\u{1f50}', '\u{301}', '\u{1f54}'), ('\u{1f50}', '\u{342}', '\u{1f56}'),
+    ('\u{1f51}', '\u{300}', '\u{1f53}'), ('\u{1f51}', '\u{301}', '\u{1f55}'), ('\u{1f51}', '\u{342}', '\u{1f57}'),
+    ('\u{1f59}', '\u{300}', '\u{1f5b}'), ('\u{1f59}', '\u{301}', '\u{1f5d}'), ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+    ('\u{1f60}', '\u{300}', '\u{1f62}'), ('\u{1f60}', '\u{301}', '\u{1f64}'), ('\u{1f60}', '\u{342}', '\u{1f66}'),
+    ('\u{1f60}', '\u{345}', '\u{1fa0}'), ('\u{1f61}', '\u{300}', '\u{1f63}'), ('\u{1f61}', '\u{301}', '\u{1f65}'),
+    ('\u{1f61}', '\u{342}', '\u{1f67}'), ('\u{1f61}', '\u{345}', '\u{1fa1}'), ('\u{1f62}', '\u{345}', '\u{1fa2}'),
+    ('\u{1f63}', '\u{345}', '\u{1fa3}'), ('\u{1f64}', '\u{345}', '\u{1fa4}'), ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+    ('\u{1f66}', '\u{345}', '\u{1fa6}'), ('\u{1f67}', '\u{345}', '\u{1fa7}'), ('\u{1f68}', '\u{300}', '\u{1f6a}'),
+    ('\u{1f68}', '\u{301}', '\u{1f6c}'), ('\u{1f68}', '\u{342}', '\u{1f6e}'), ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+    ('\u{1f69}', '\u{300}', '\u{1f6b}'), ('\u{1f69}', '\u{301}', '\u{1f6d}'), ('\u{1f69}', '\u{342}', '\u{1f6f}'),
+    ('\u{1f69}', '\u{345}', '\u{1fa9}'), ('\u{1f6a}', '\u{345}', '\u{1faa}'), ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+    ('\u{1f6c}', '\u{345}', '\u{1fac}'), ('\u{1f6d}', '\u{345}', '\u{1fad}'), ('\u{1f6e}', '\u{345}', '\u{1fae}'),
+    ('\u{1f6f}', '\u{345}', '\u{1faf}'), ('\u{1f70}', '\u{345}', '\u{1fb2}'), ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+    ('\u{1f7c}', '\u{345}', '\u{1ff2}'), ('\u{1fb6}', '\u{345}', '\u{1fb7}'), ('\u{1fbf}', '\u{300}', '\u{1fcd}'),
+    ('\u{1fbf}', '\u{301}', '\u{1fce}'), ('\u{1fbf}', '\u{342}', '\u{1fcf}'), ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+    ('\u{1ff6}', '\u{345}', '\u{1ff7}'), ('\u{1ffe}', '\u{300}', '\u{1fdd}'), ('\u{1ffe}', '\u{301}', '\u{1fde}'),
+    ('\u{1ffe}', '\u{342}', '\u{1fdf}'), ('\u{2190}', '\u{338}', '\u{219a}'), ('\u{2192}', '\u{338}', '\u{219b}'),
+    ('\u{2194}', '\u{338}', '\u{21ae}'), ('\u{21d0}', '\u{338}', '\u{21cd}'), ('\u{21d2}', '\u{338}', '\u{21cf}'),
+    ('\u{21d4}', '\u{338}', '\u{21ce}'), ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220b}', '\u{338}', '\u{220c}'), ('\u{2223}', '\u{338}', '\u{2224}'), ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223c}', '\u{338}', '\u{2241}'), ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224d}', '\u{338}', '\u{226d}'), ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'), ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'), ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227a}', '\u{338}', '\u{2280}'), ('\u{227b}', '\u{338}', '\u{2281}'), ('\u{227c}', '\u{338}', '\u{22e0}'),
+    ('\u{227d}', '\u{338}', '\u{22e1}'), ('\u{2282}', '\u{338}', '\u{2284}'), ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'), ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22e2}'),
+    ('\u{2292}', '\u{338}', '\u{22e3}'), ('\u{22a2}', '\u{338}', '\u{22ac}'), ('\u{22a8}', '\u{338}', '\u{22ad}'),
+    ('\u{22a9}', '\u{338}', '\u{22ae}'), ('\u{22ab}', '\u{338}', '\u{22af}'), ('\u{22b2}', '\u{338}', '\u{22ea}'),
+    ('\u{22b3}', '\u{338}', '\u{22eb}'), ('\u{22b4}', '\u{338}', '\u{22ec}'), ('\u{22b5}', '\u{338}', '\u{22ed}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'), ('\u{304b}', '\u{3099}', '\u{304c}'), ('\u{304d}', '\u{3099}', '\u{304e}'),
+    ('\u{304f}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'), ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'), ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305a}'),
+    ('\u{305b}', '\u{3099}', '\u{305c}'), ('\u{305d}', '\u{3099}', '\u{305e}'), ('\u{305f}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'), ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306f}', '\u{3099}', '\u{3070}'), ('\u{306f}', '\u{309a}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'), ('\u{3072}', '\u{309a}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309a}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'), ('\u{3078}', '\u{309a}', '\u{307a}'),
+    ('\u{307b}', '\u{3099}', '\u{307c}'), ('\u{307b}', '\u{309a}', '\u{307d}'), ('\u{309d}', '\u{3099}', '\u{309e}'),
+    ('\u{30a6}', '\u{3099}', '\u{30f4}'), ('\u{30ab}', '\u{3099}', '\u{30ac}'), ('\u{30ad}', '\u{3099}', '\u{30ae}'),
+    ('\u{30af}', '\u{3099}', '\u{30b0}'), ('\u{30b1}', '\u{3099}', '\u{30b2}'), ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+    ('\u{30b5}', '\u{3099}', '\u{30b6}'), ('\u{30b7}', '\u{3099}', '\u{30b8}'), ('\u{30b9}', '\u{3099}', '\u{30ba}'),
+    ('\u{30bb}', '\u{3099}', '\u{30bc}'), ('\u{30bd}', '\u{3099}', '\u{30be}'), ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+    ('\u{30c1}', '\u{3099}', '\u{30c2}'), ('\u{30c4}', '\u{3099}', '\u{30c5}'), ('\u{30c6}', '\u{3099}', '\u{30c7}'),
+    ('\u{30c8}', '\u{3099}', '\u{30c9}'), ('\u{30cf}', '\u{3099}', '\u{30d0}'), ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+    ('\u{30d2}', '\u{3099}', '\u{30d3}'), ('\u{30d2}', '\u{309a}', '\u{30d4}'), ('\u{30d5}', '\u{3099}', '\u{30d6}'),
+    ('\u{30d5}', '\u{309a}', '\u{30d7}'), ('\u{30d8}', '\u{3099}', '\u{30d9}'), ('\u{30d8}', '\u{309a}', '\u{30da}'),
+    ('\u{30db}', '\u{3099}', '\u{30dc}'), ('\u{30db}', '\u{309a}', '\u{30dd}'), ('\u{30ef}', '\u{3099}', '\u{30f7}'),
+    ('\u{30f0}', '\u{3099}', '\u{30f8}'), ('\u{30f1}', '\u{3099}', '\u{30f9}'), ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+    ('\u{30fd}', '\u{3099}', '\u{30fe}'), ('\u{105d2}', '\u{307}', '\u{105c9}'), ('\u{105da}', '\u{307}', '\u{105e4}'),
+    ('\u{11099}', '\u{110ba}', '\u{1109a}'), ('\u{1109b}', '\u{110ba}', '\u{1109c}'), ('\u{110a5}', '\u{110ba}', '\u{110ab}'),
+    ('\u{11131}', '\u{11127}', '\u{1112e}'), ('\u{11132}', '\u{11127}', '\u{1112f}'), ('\u{11347}', '\u{1133e}', '\u{1134b}'),
+    ('\u{11347}', '\u{11357}', '\u{1134c}'), ('\u{11382}', '\u{113c9}', '\u{11383}'), ('\u{11384}', '\u{113bb}', '\u{11385}'),
+    ('\u{1138b}', '\u{113c2}', '\u{1138e}'), ('\u{11390}', '\u{113c9}', '\u{11391}'), ('\u{113c2}', '\u{113b8}', '\u{113c7}'),
+    ('\u{113c2}', '\u{113c2}', '\u{113c5}'), ('\u{113c2}', '\u{113c9}', '\u{113c8}'), ('\u{114b9}', '\u{114b0}', '\u{114bc}'),
+    ('\u{114b9}', '\u{114ba}', '\u{114bb}'), ('\u{114b9}', '\u{114bd}', '\u{114be}'), ('\u{115b8}', '\u{115af}', '\u{115ba}'),
+    ('\u{115b9}', '\u{115af}', '\u{115bb}'), ('\u{11935}', '\u{11930}', '\u{11938}'), ('\u{1611e}', '\u{1611e}', '\u{16121}'),
+    ('\u{1611e}', '\u{1611f}', '\u{16123}'), ('\u{1611e}', '\u{16120}', '\u{16125}'), ('\u{1611e}', '\u{16129}', '\u{16122}'),
+    ('\u{16121}', '\u{1611f}', '\u{16126}'), ('\u{16121}', '\u{16120}', '\u{16128}'), ('\u{16122}', '\u{1611f}', '\u{16127}'),
+    ('\u{16129}', '\u{1611f}', '\u{16124}'), ('\u{16d63}', '\u{16d67}', '\u{16d69}'), ('\u{16d67}', '\u{16d67}', '\u{16d68}'),
+    ('\u{16d69}', '\u{16d67}', '\u{16d6a}')
+];
+
+// The names of scripts, which `SCRIPTS` refers to by index
+pub const SCRIPT_NAMES: &[&str] = &[
+    "Adlam", "Ahom", "Anatolian Hieroglyphs", "Arabic", "Armenian", "Avestan",
+    "Balinese", "Bamum", "Bassa Vah", "Batak", "Bengali", "Bhaiksuki",
+    "Bopomofo", "Brahmi", "Braille", "Buginese", "Buhid", "Canadian Aboriginal",
+    "Carian", "Caucasian Albanian", "Chakma", "Cham", "Cherokee", "Chorasmian",
+    "Coptic", "Cuneiform", "Cypriot", "Cypro Minoan", "Cyrillic", "Deseret",
+    "Devanagari", "Dives Akuru", "Dogra", "Duployan", "Egyptian Hieroglyphs", "Elbasan",
+    "Elymaic", "Ethiopic", "Garay", "Georgian", "Glagolitic", "Gothic",
+    "Grantha", "Greek", "Gujarati", "Gunjala Gondi", "Gurmukhi", "Gurung Khema",
+    "Han", "Hangul", "Hanifi Rohingya", "Hanunoo", "Hatran", "Hebrew",
+    "Hiragana", "Imperial Aramaic", "Inscriptional Pahlavi", "Inscriptional Parthian", "Javanese", "Kaithi",
+    "Kannada", "Katakana", "Kawi", "Kayah Li", "Kharoshthi", "Khitan Small Script",
+    "Khmer", "Khojki", "Khudawadi", "Kirat Rai", "Lao", "Latin",
+    "Lepcha", "Limbu", "Linear A", "Linear B", "Lisu", "Lycian",
+    "Lydian", "Mahajani", "Makasar", "Malayalam", "Mandaic", "Manichaean",
+    "Marchen", "Masaram Gondi", "Medefaidrin", "Meetei Mayek", "Mende Kikakui", "Meroitic Cursive",
+    "Meroitic Hieroglyphs", "Miao", "Modi", "Mongolian", "Mro", "Multani",
+    "Myanmar", "Nabataean", "Nag Mundari", "Nandinagari", "New Tai Lue", "Newa",
+    "Nko", "Nushu", "Nyiakeng Puachue Hmong", "Ogham", "Ol Chiki", "Ol Onal",
+    "Old Hungarian", "Old Italic", "Old North Arabian", "Old Permic", "Old Persian", "Old Sogdian",
+    "Old South Arabian", "Old Turkic", "Old Uyghur", "Oriya", "Osage", "Osmanya",
+    "Pahawh Hmong", "Palmyrene", "Pau Cin Hau", "Phags Pa", "Phoenician", "Psalter Pahlavi",
+    "Rejang", "Runic", "Samaritan", "Saurashtra", "Sharada", "Shavian",
+    "Siddham", "SignWriting", "Sinhala", "Sogdian", "Sora Sompeng", "Soyombo",
+    "Sundanese", "Sunuwar", "Syloti Nagri", "Syriac", "Tagalog", "Tagbanwa",
+    "Tai Le", "Tai Tham", "Tai Viet", "Takri", "Tamil", "Tangsa",
+    "Tangut", "Telugu", "Thaana", "Thai", "Tibetan", "Tifinagh",
+    "Tirhuta", "Todhri", "Toto", "Tulu Tigalari", "Ugaritic", "Vai",
+    "Vithkuqi", "Wancho", "Warang Citi", "Yezidi", "Yi", "Zanabazar Square"
+];
+
+// The script of every character that belongs to one, rather than being
+// Common or Inherited, which are used alongside any script
+pub const SCRIPTS: &[(char, char, u8)] = &[
+    ('A', 'Z', 71), ('a', 'z', 71), ('\u{aa}', '\u{aa}', 71),
+    ('\u{ba}', '\u{ba}', 71), ('\u{c0}', '\u{d6}', 71), ('\u{d8}', '\u{f6}', 71),
+    ('\u{f8}', '\u{2b8}', 71), ('\u{2e0}', '\u{2e4}', 71), ('\u{2ea}', '\u{2eb}', 12),
+    ('\u{370}', '\u{373}', 43), ('\u{375}', '\u{377}', 43), ('\u{37a}', '\u{37d}', 43),
+    ('\u{37f}', '\u{37f}', 43), ('\u{384}', '\u{384}', 43), ('\u{386}', '\u{386}', 43),
+    ('\u{388}', '\u{38a}', 43), ('\u{38c}', '\u{38c}', 43), ('\u{38e}', '\u{3a1}', 43),
+    ('\u{3a3}', '\u{3e1}', 43), ('\u{3e2}', '\u{3ef}', 24), ('\u{3f0}', '\u{3ff}', 43),
+    ('\u{400}', '\u{484}', 28), ('\u{487}', '\u{52f}', 28), ('\u{531}', '\u{556}', 4),
+    ('\u{559}', '\u{58a}', 4), ('\u{58d}', '\u{58f}', 4), ('\u{591}', '\u{5c7}', 53),
+    ('\u{5d0}', '\u{5ea}', 53), ('\u{5ef}', '\u{5f4}', 53), ('\u{600}', '\u{604}', 3),
+    ('\u{606}', '\u{60b}', 3), ('\u{60d}', '\u{61a}', 3), ('\u{61c}', '\u{61e}', 3),
+    ('\u{620}', '\u{63f}', 3), ('\u{641}', '\u{64a}', 3), ('\u{656}', '\u{66f}', 3),
+    ('\u{671}', '\u{6dc}', 3), ('\u{6de}', '\u{6ff}', 3), ('\u{700}', '\u{70d}', 141),
+    ('\u{70f}', '\u{74a}', 141), ('\u{74d}', '\u{74f}', 141), ('\u{750}', '\u{77f}', 3),
+    ('\u{780}', '\u{7b1}', 152), ('\u{7c0}', '\u{7fa}', 102), ('\u{7fd}', '\u{7ff}', 102),
+    ('\u{800}', '\u{82d}', 128), ('\u{830}', '\u{83e}', 128), ('\u{840}', '\u{85b}', 82),
+    ('\u{85e}', '\u{85e}', 82), ('\u{860}', '\u{86a}', 141), ('\u{870}', '\u{88e}', 3),
+    ('\u{890}', '\u{891}', 3), ('\u{897}', '\u{8e1}', 3), ('\u{8e3}', '\u{8ff}', 3),
+    ('\u{900}', '\u{950}', 30), ('\u{955}', '\u{963}', 30), ('\u{966}', '\u{97f}', 30),
+    ('\u{980}', '\u{983}', 10), ('\u{985}', '\u{98c}', 10), ('\u{98f}', '\u{990}', 10),
+    ('\u{993}', '\u{9a8}', 10), ('\u{9aa}', '\u{9b0}', 10), ('\u{9b2}', '\u{9b2}', 10),
+    ('\u{9b6}', '\u{9b9}', 10), ('\u{9bc}', '\u{9c4}', 10), ('\u{9c7}', '\u{9c8}', 10),
+    ('\u{9cb}', '\u{9ce}', 10), ('\u{9d7}', '\u{9d7}', 10), ('\u{9dc}', '\u{9dd}', 10),
+    ('\u{9df}', '\u{9e3}', 10), ('\u{9e6}', '\u{9fe}', 10), ('\u{a01}', '\u{a03}', 46),
+    ('\u{a05}', '\u{a0a}', 46), ('\u{a0f}', '\u{a10}', 46), ('\u{a13}', '\u{a28}', 46),
+    ('\u{a2a}', '\u{a30}', 46), ('\u{a32}', '\u{a33}', 46), ('\u{a35}', '\u{a36}', 46),
+    ('\u{a38}', '\u{a39}', 46), ('\u{a3c}', '\u{a3c}', 46), ('\u{a3e}', '\u{a42}', 46),
+    ('\u{a47}', '\u{a48}', 46), ('\u{a4b}', '\u{a4d}', 46), ('\u{a51}', '\u{a51}', 46),
+    ('\u{a59}', '\u{a5c}', 46), ('\u{a5e}', '\u{a5e}', 46), ('\u{a66}', '\u{a76}', 46),
+    ('\u{a81}', '\u{a83}', 44), ('\u{a85}', '\u{a8d}', 44), ('\u{a8f}', '\u{a91}', 44),
+    ('\u{a93}', '\u{aa8}', 44), ('\u{aaa}', '\u{ab0}', 44), ('\u{ab2}', '\u{ab3}', 44),
+    ('\u{ab5}', '\u{ab9}', 44), ('\u{abc}', '\u{ac5}', 44), ('\u{ac7}', '\u{ac9}', 44),
+    ('\u{acb}', '\u{acd}', 44), ('\u{ad0}', '\u{ad0}', 44), ('\u{ae0}', '\u{ae3}', 44),
+    ('\u{ae6}', '\u{af1}', 44), ('\u{af9}', '\u{aff}', 44), ('\u{b01}', '\u{b03}', 117),
+    ('\u{b05}', '\u{b0c}', 117), ('\u{b0f}', '\u{b10}', 117), ('\u{b13}', '\u{b28}', 117),
+    ('\u{b2a}', '\u{b30}', 117), ('\u{b32}', '\u{b33}', 117), ('\u{b35}', '\u{b39}', 117),
+    ('\u{b3c}', '\u{b44}', 117), ('\u{b47}', '\u{b48}', 117), ('\u{b4b}', '\u{b4d}', 117),
+    ('\u{b55}', '\u{b57}', 117), ('\u{b5c}', '\u{b5d}', 117), ('\u{b5f}', '\u{b63}', 117),
+    ('\u{b66}', '\u{b77}', 117), ('\u{b82}', '\u{b83}', 148), ('\u{b85}', '\u{b8a}', 148),
+    ('\u{b8e}', '\u{b90}', 148), ('\u{b92}', '\u{b95}', 148), ('\u{b99}', '\u{b9a}', 148),
+    ('\u{b9c}', '\u{b9c}', 148), ('\u{b9e}', '\u{b9f}', 148), ('\u{ba3}', '\u{ba4}', 148),
+    ('\u{ba8}', '\u{baa}', 148), ('\u{bae}', '\u{bb9}', 148), ('\u{bbe}', '\u{bc2}', 148),
+    ('\u{bc6}', '\u{bc8}', 148), ('\u{bca}', '\u{bcd}', 148), ('\u{bd0}', '\u{bd0}', 148),
+    ('\u{bd7}', '\u{bd7}', 148), ('\u{be6}', '\u{bfa}', 148), ('\u{c00}', '\u{c0c}', 151),
+    ('\u{c0e}', '\u{c10}', 151), ('\u{c12}', '\u{c28}', 151), ('\u{c2a}', '\u{c39}', 151),
+    ('\u{c3c}', '\u{c44}', 151), ('\u{c46}', '\u{c48}', 151), ('\u{c4a}', '\u{c4d}', 151),
+    ('\u{c55}', '\u{c56}', 151), ('\u{c58}', '\u{c5a}', 151), ('\u{c5d}', '\u{c5d}', 151),
+    ('\u{c60}', '\u{c63}', 151), ('\u{c66}', '\u{c6f}', 151), ('\u{c77}', '\u{c7f}', 151),
+    ('\u{c80}', '\u{c8c}', 60), ('\u{c8e}', '\u{c90}', 60), ('\u{c92}', '\u{ca8}', 60),
+    ('\u{caa}', '\u{cb3}', 60), ('\u{cb5}', '\u{cb9}', 60), ('\u{cbc}', '\u{cc4}', 60),
+    ('\u{cc6}', '\u{cc8}', 60), ('\u{cca}', '\u{ccd}', 60), ('\u{cd5}', '\u{cd6}', 60),
+    ('\u{cdd}', '\u{cde}', 60), ('\u{ce0}', '\u{ce3}', 60), ('\u{ce6}', '\u{cef}', 60),
+    ('\u{cf1}', '\u{cf3}', 60), ('\u{d00}', '\u{d0c}', 81), ('\u{d0e}', '\u{d10}', 81),
+    ('\u{d12}', '\u{d44}', 81), ('\u{d46}', '\u{d48}', 81), ('\u{d4a}', '\u{d4f}', 81),
+    ('\u{d54}', '\u{d63}', 81), ('\u{d66}', '\u{d7f}', 81), ('\u{d81}', '\u{d83}', 134),
+    ('\u{d85}', '\u{d96}', 134), ('\u{d9a}', '\u{db1}', 134), ('\u{db3}', '\u{dbb}', 134),
+    ('\u{dbd}', '\u{dbd}', 134), ('\u{dc0}', '\u{dc6}', 134), ('\u{dca}', '\u{dca}', 134),
+    ('\u{dcf}', '\u{dd4}', 134), ('\u{dd6}', '\u{dd6}', 134), ('\u{dd8}', '\u{ddf}', 134),
+    ('\u{de6}', '\u{def}', 134), ('\u{df2}', '\u{df4}', 134), ('\u{e01}', '\u{e3a}', 153),
+    ('\u{e40}', '\u{e5b}', 153), ('\u{e81}', '\u{e82}', 70), ('\u{e84}', '\u{e84}', 70),
+    ('\u{e86}', '\u{e8a}', 70), ('\u{e8c}', '\u{ea3}', 70), ('\u{ea5}', '\u{ea5}', 70),
+    ('\u{ea7}', '\u{ebd}', 70), ('\u{ec0}', '\u{ec4}', 70), ('\u{ec6}', '\u{ec6}', 70),
+    ('\u{ec8}', '\u{ece}', 70), ('\u{ed0}', '\u{ed9}', 70), ('\u{edc}', '\u{edf}', 70),
+    ('\u{f00}', '\u{f47}', 154), ('\u{f49}', '\u{f6c}', 154), ('\u{f71}', '\u{f97}', 154),
+    ('\u{f99}', '\u{fbc}', 154), ('\u{fbe}', '\u{fcc}', 154), ('\u{fce}', '\u{fd4}', 154),
+    ('\u{fd9}', '\u{fda}', 154), ('\u{1000}', '\u{109f}', 96), ('\u{10a0}', '\u{10c5}', 39),
+    ('\u{10c7}', '\u{10c7}', 39), ('\u{10cd}', '\u{10cd}', 39), ('\u{10d0}', '\u{10fa}', 39),
+    ('\u{10fc}', '\u{10ff}', 39), ('\u{1100}', '\u{11ff}', 49), ('\u{1200}', '\u{1248}', 37),
+    ('\u{124a}', '\u{124d}', 37), ('\u{1250}', '\u{1256}', 37), ('\u{1258}', '\u{1258}', 37),
+    ('\u{125a}', '\u{125d}', 37), ('\u{1260}', '\u{1288}', 37), ('\u{128a}', '\u{128d}', 37),
+    ('\u{1290}', '\u{12b0}', 37), ('\u{12b2}', '\u{12b5}', 37), ('\u{12b8}', '\u{12be}', 37),
+    ('\u{12c0}', '\u{12c0}', 37), ('\u{12c2}', '\u{12c5}', 37), ('\u{12c8}', '\u{12d6}', 37),
+    ('\u{12d8}', '\u{1310}', 37), ('\u{1312}', '\u{1315}', 37), ('\u{1318}', '\u{135a}', 37),
+    ('\u{135d}', '\u{137c}', 37), ('\u{1380}', '\u{1399}', 37), ('\u{13a0}', '\u{13f5}', 22),
+    ('\u{13f8}', '\u{13fd}', 22), ('\u{1400}', '\u{167f}', 17), ('\u{1680}', '\u{169c}', 105),
+    ('\u{16a0}', '\u{16ea}', 127), ('\u{16ee}', '\u{16f8}', 127), ('\u{1700}', '\u{1715}', 142),
+    ('\u{171f}', '\u{171f}', 142), ('\u{1720}', '\u{1734}', 51), ('\u{1740}', '\u{1753}', 16),
+    ('\u{1760}', '\u{176c}', 143), ('\u{176e}', '\u{1770}', 143), ('\u{1772}', '\u{1773}', 143),
+    ('\u{1780}', '\u{17dd}', 66), ('\u{17e0}', '\u{17e9}', 66), ('\u{17f0}', '\u{17f9}', 66),
+    ('\u{1800}', '\u{1801}', 93), ('\u{1804}', '\u{1804}', 93), ('\u{1806}', '\u{1819}', 93),
+    ('\u{1820}', '\u{1878}', 93), ('\u{1880}', '\u{18aa}', 93), ('\u{18b0}', '\u{18f5}', 17),
+    ('\u{1900}', '\u{191e}', 73), ('\u{1920}', '\u{192b}', 73), ('\u{1930}', '\u{193b}', 73),
+    ('\u{1940}', '\u{1940}', 73), ('\u{1944}', '\u{194f}', 73), ('\u{1950}', '\u{196d}', 144),
+    ('\u{1970}', '\u{1974}', 144), ('\u{1980}', '\u{19ab}', 100), ('\u{19b0}', '\u{19c9}', 100),
+    ('\u{19d0}', '\u{19da}', 100), ('\u{19de}', '\u{19df}', 100), ('\u{19e0}', '\u{19ff}', 66),
+    ('\u{1a00}', '\u{1a1b}', 15), ('\u{1a1e}', '\u{1a1f}', 15), ('\u{1a20}', '\u{1a5e}', 145),
+    ('\u{1a60}', '\u{1a7c}', 145), ('\u{1a7f}', '\u{1a89}', 145), ('\u{1a90}', '\u{1a99}', 145),
+    ('\u{1aa0}', '\u{1aad}', 145), ('\u{1b00}', '\u{1b4c}', 6), ('\u{1b4e}', '\u{1b7f}', 6),
+    ('\u{1b80}', '\u{1bbf}', 138), ('\u{1bc0}', '\u{1bf3}', 9), ('\u{1bfc}', '\u{1bff}', 9),
+    ('\u{1c00}', '\u{1c37}', 72), ('\u{1c3b}', '\u{1c49}', 72), ('\u{1c4d}', '\u{1c4f}', 72),
+    ('\u{1c50}', '\u{1c7f}', 106), ('\u{1c80}', '\u{1c8a}', 28), ('\u{1c90}', '\u{1cba}', 39),
+    ('\u{1cbd}', '\u{1cbf}', 39), ('\u{1cc0}', '\u{1cc7}', 138), ('\u{1d00}', '\u{1d25}', 71),
+    ('\u{1d26}', '\u{1d2a}', 43), ('\u{1d2b}', '\u{1d2b}', 28), ('\u{1d2c}', '\u{1d5c}', 71),
+    ('\u{1d5d}', '\u{1d61}', 43), ('\u{1d62}', '\u{1d65}', 71), ('\u{1d66}', '\u{1d6a}', 43),
+    ('\u{1d6b}', '\u{1d77}', 71), ('\u{1d78}', '\u{1d78}', 28), ('\u{1d79}', '\u{1dbe}', 71),
+    ('\u{1dbf}', '\u{1dbf}', 43), ('\u{1e00}', '\u{1eff}', 71), ('\u{1f00}', '\u{1f15}', 43),
+    ('\u{1f18}', '\u{1f1d}', 43), ('\u{1f20}', '\u{1f45}', 43), ('\u{1f48}', '\u{1f4d}', 43),
+    ('\u{1f50}', '\u{1f57}', 43), ('\u{1f59}', '\u{1f59}', 43), ('\u{1f5b}', '\u{1f5b}', 43),
+    ('\u{1f5d}', '\u{1f5d}', 43), ('\u{1f5f}', '\u{1f7d}', 43), ('\u{1f80}', '\u{1fb4}', 43),
+    ('\u{1fb6}', '\u{1fc4}', 43), ('\u{1fc6}', '\u{1fd3}', 43), ('\u{1fd6}', '\u{1fdb}', 43),
+    ('\u{1fdd}', '\u{1fef}', 43), ('\u{1ff2}', '\u{1ff4}', 43), ('\u{1ff6}', '\u{1ffe}', 43),
+    ('\u{2071}', '\u{2071}', 71), ('\u{207f}', '\u{207f}', 71), ('\u{2090}', '\u{209c}', 71),
+    ('\u{2126}', '\u{2126}', 43), ('\u{212a}', '\u{212b}', 71), ('\u{2132}', '\u{2132}', 71),
+    ('\u{214e}', '\u{214e}', 71), ('\u{2160}', '\u{2188}', 71), ('\u{2800}', '\u{28ff}', 14),
+    ('\u{2c00}', '\u{2c5f}', 40), ('\u{2c60}', '\u{2c7f}', 71), ('\u{2c80}', '\u{2cf3}', 24),
+    ('\u{2cf9}', '\u{2cff}', 24), ('\u{2d00}', '\u{2d25}', 39), ('\u{2d27}', '\u{2d27}', 39),
+    ('\u{2d2d}', '\u{2d2d}', 39), ('\u{2d30}', '\u{2d67}', 155), ('\u{2d6f}', '\u{2d70}', 155),
+    ('\u{2d7f}', '\u{2d7f}', 155), ('\u{2d80}', '\u{2d96}', 37), ('\u{2da0}', '\u{2da6}', 37),
+    ('\u{2da8}', '\u{2dae}', 37), ('\u{2db0}', '\u{2db6}', 37), ('\u{2db8}', '\u{2dbe}', 37),
+    ('\u{2dc0}', '\u{2dc6}', 37), ('\u{2dc8}', '\u{2dce}', 37), ('\u{2dd0}', '\u{2dd6}', 37),
+    ('\u{2dd8}', '\u{2dde}', 37), ('\u{2de0}', '\u{2dff}', 28), ('\u{2e80}', '\u{2e99}', 48),
+    ('\u{2e9b}', '\u{2ef3}', 48), ('\u{2f00}', '\u{2fd5}', 48), ('\u{3005}', '\u{3005}', 48),
+    ('\u{3007}', '\u{3007}', 48), ('\u{3021}', '\u{3029}', 48), ('\u{302e}', '\u{302f}', 49),
+    ('\u{3038}', '\u{303b}', 48), ('\u{3041}', '\u{3096}', 54), ('\u{309d}', '\u{309f}', 54),
+    ('\u{30a1}', '\u{30fa}', 61), ('\u{30fd}', '\u{30ff}', 61), ('\u{3105}', '\u{312f}', 12),
+    ('\u{3131}', '\u{318e}', 49), ('\u{31a0}', '\u{31bf}', 12), ('\u{31f0}', '\u{31ff}', 61),
+    ('\u{3200}', '\u{321e}', 49), ('\u{3260}', '\u{327e}', 49), ('\u{32d0}', '\u{32fe}', 61),
+    ('\u{3300}', '\u{3357}', 61), ('\u{3400}', '\u{4dbf}', 48), ('\u{4e00}', '\u{9fff}', 48),
+    ('\u{a000}', '\u{a48c}', 166), ('\u{a490}', '\u{a4c6}', 166), ('\u{a4d0}', '\u{a4ff}', 76),
+    ('\u{a500}', '\u{a62b}', 161), ('\u{a640}', '\u{a69f}', 28), ('\u{a6a0}', '\u{a6f7}', 7),
+    ('\u{a722}', '\u{a787}', 71), ('\u{a78b}', '\u{a7cd}', 71), ('\u{a7d0}', '\u{a7d1}', 71),
+    ('\u{a7d3}', '\u{a7d3}', 71), ('\u{a7d5}', '\u{a7dc}', 71), ('\u{a7f2}', '\u{a7ff}', 71),
+    ('\u{a800}', '\u{a82c}', 140), ('\u{a840}', '\u{a877}', 123), ('\u{a880}', '\u{a8c5}', 129),
+    ('\u{a8ce}', '\u{a8d9}', 129), ('\u{a8e0}', '\u{a8ff}', 30), ('\u{a900}', '\u{a92d}', 63),
+    ('\u{a92f}', '\u{a92f}', 63), ('\u{a930}', '\u{a953}', 126), ('\u{a95f}', '\u{a95f}', 126),
+    ('\u{a960}', '\u{a97c}', 49), ('\u{a980}', '\u{a9cd}', 58), ('\u{a9d0}', '\u{a9d9}', 58),
+    ('\u{a9de}', '\u{a9df}', 58), ('\u{a9e0}', '\u{a9fe}', 96), ('\u{aa00}', '\u{aa36}', 21),
+    ('\u{aa40}', '\u{aa4d}', 21), ('\u{aa50}', '\u{aa59}', 21), ('\u{aa5c}', '\u{aa5f}', 21),
+    ('\u{aa60}', '\u{aa7f}', 96), ('\u{aa80}', '\u{aac2}', 146), ('\u{aadb}', '\u{aadf}', 146),
+    ('\u{aae0}', '\u{aaf6}', 87), ('\u{ab01}', '\u{ab06}', 37), ('\u{ab09}', '\u{ab0e}', 37),
+    ('\u{ab11}', '\u{ab16}', 37), ('\u{ab20}', '\u{ab26}', 37), ('\u{ab28}', '\u{ab2e}', 37),
+    ('\u{ab30}', '\u{ab5a}', 71), ('\u{ab5c}', '\u{ab64}', 71), ('\u{ab65}', '\u{ab65}', 43),
+    ('\u{ab66}', '\u{ab69}', 71), ('\u{ab70}', '\u{abbf}', 22), ('\u{abc0}', '\u{abed}', 87),
+    ('\u{abf0}', '\u{abf9}', 87), ('\u{ac00}', '\u{d7a3}', 49), ('\u{d7b0}', '\u{d7c6}', 49),
+    ('\u{d7cb}', '\u{d7fb}', 49), ('\u{f900}', '\u{fa6d}', 48), ('\u{fa70}', '\u{fad9}', 48),
+    ('\u{fb00}', '\u{fb06}', 71), ('\u{fb13}', '\u{fb17}', 4), ('\u{fb1d}', '\u{fb36}', 53),
+    ('\u{fb38}', '\u{fb3c}', 53), ('\u{fb3e}', '\u{fb3e}', 53), ('\u{fb40}', '\u{fb41}', 53),
+    ('\u{fb43}', '\u{fb44}', 53), ('\u{fb46}', '\u{fb4f}', 53), ('\u{fb50}', '\u{fbc2}', 3),
+    ('\u{fbd3}', '\u{fd3d}', 3), ('\u{fd40}', '\u{fd8f}', 3), ('\u{fd92}', '\u{fdc7}', 3),
+    ('\u{fdcf}', '\u{fdcf}', 3), ('\u{fdf0}', '\u{fdff}', 3), ('\u{fe2e}', '\u{fe2f}', 28),
+    ('\u{fe70}', '\u{fe74}', 3), ('\u{fe76}', '\u{fefc}', 3), ('\u{ff21}', '\u{ff3a}', 71),
+    ('\u{ff41}', '\u{ff5a}', 71), ('\u{ff66}', '\u{ff6f}', 61), ('\u{ff71}', '\u{ff9d}', 61),
+    ('\u{ffa0}', '\u{ffbe}', 49), ('\u{ffc2}', '\u{ffc7}', 49), ('\u{ffca}', '\u{ffcf}', 49),
+    ('\u{ffd2}', '\u{ffd7}', 49), ('\u{ffda}', '\u{ffdc}', 49), ('\u{10000}', '\u{1000b}', 75),
+    ('\u{1000d}', '\u{10026}', 75), ('\u{10028}', '\u{1003a}', 75), ('\u{1003c}', '\u{1003d}', 75),
+    ('\u{1003f}', '\u{1004d}', 75), ('\u{10050}', '\u{1005d}', 75), ('\u{10080}', '\u{100fa}', 75),
+    ('\u{10140}', '\u{1018e}', 43), ('\u{101a0}', '\u{101a0}', 43), ('\u{10280}', '\u{1029c}', 77),
+    ('\u{102a0}', '\u{102d0}', 18), ('\u{10300}', '\u{10323}', 109), ('\u{1032d}', '\u{1032f}', 109),
+    ('\u{10330}', '\u{1034a}', 41), ('\u{10350}', '\u{1037a}', 111), ('\u{10380}', '\u{1039d}', 160),
+    ('\u{1039f}', '\u{1039f}', 160), ('\u{103a0}', '\u{103c3}', 112), ('\u{103c8}', '\u{103d5}', 112),
+    ('\u{10400}', '\u{1044f}', 29), ('\u{10450}', '\u{1047f}', 131), ('\u{10480}', '\u{1049d}', 119),
+    ('\u{104a0}', '\u{104a9}', 119), ('\u{104b0}', '\u{104d3}', 118), ('\u{104d8}', '\u{104fb}', 118),
+    ('\u{10500}', '\u{10527}', 35), ('\u{10530}', '\u{10563}', 19), ('\u{1056f}', '\u{1056f}', 19),
+    ('\u{10570}', '\u{1057a}', 162), ('\u{1057c}', '\u{1058a}', 162), ('\u{1058c}', '\u{10592}', 162),
+    ('\u{10594}', '\u{10595}', 162), ('\u{10597}', '\u{105a1}', 162), ('\u{105a3}', '\u{105b1}', 162),
+    ('\u{105b3}', '\u{105b9}', 162), ('\u{105bb}', '\u{105bc}', 162), ('\u{105c0}', '\u{105f3}', 157),
+    ('\u{10600}', '\u{10736}', 74), ('\u{10740}', '\u{10755}', 74), ('\u{10760}', '\u{10767}', 74),
+    ('\u{10780}', '\u{10785}', 71), ('\u{10787}', '\u{107b0}', 71), ('\u{107b2}', '\u{107ba}', 71),
+    ('\u{10800}', '\u{10805}', 26), ('\u{10808}', '\u{10808}', 26), ('\u{1080a}', '\u{10835}', 26),
+    ('\u{10837}', '\u{10838}', 26), ('\u{1083c}', '\u{1083c}', 26), ('\u{1083f}', '\u{1083f}', 26),
+    ('\u{10840}', '\u{10855}', 55), ('\u{10857}', '\u{1085f}', 55), ('\u{10860}', '\u{1087f}', 121),
+    ('\u{10880}', '\u{1089e}', 97), ('\u{108a7}', '\u{108af}', 97), ('\u{108e0}', '\u{108f2}', 52),
+    ('\u{108f4}', '\u{108f5}', 52), ('\u{108fb}', '\u{108ff}', 52), ('\u{10900}', '\u{1091b}', 124),
+    ('\u{1091f}', '\u{1091f}', 124), ('\u{10920}', '\u{10939}', 78), ('\u{1093f}', '\u{1093f}', 78),
+    ('\u{10980}', '\u{1099f}', 90), ('\u{109a0}', '\u{109b7}', 89), ('\u{109bc}', '\u{109cf}', 89),
+    ('\u{109d2}', '\u{109ff}', 89), ('\u{10a00}', '\u{10a03}', 64), ('\u{10a05}', '\u{10a06}', 64),
+    ('\u{10a0c}', '\u{10a13}', 64), ('\u{10a15}', '\u{10a17}', 64), ('\u{10a19}', '\u{10a35}', 64),
+    ('\u{10a38}', '\u{10a3a}', 64), ('\u{10a3f}', '\u{10a48}', 64), ('\u{10a50}', '\u{10a58}', 64),
+    ('\u{10a60}', '\u{10a7f}', 114), ('\u{10a80}', '\u{10a9f}', 110), ('\u{10ac0}', '\u{10ae6}', 83),
+    ('\u{10aeb}', '\u{10af6}', 83), ('\u{10b00}', '\u{10b35}', 5), ('\u{10b39}', '\u{10b3f}', 5),
+    ('\u{10b40}', '\u{10b55}', 57), ('\u{10b58}', '\u{10b5f}', 57), ('\u{10b60}', '\u{10b72}', 56),
+    ('\u{10b78}', '\u{10b7f}', 56), ('\u{10b80}', '\u{10b91}', 125), ('\u{10b99}', '\u{10b9c}', 125),
+    ('\u{10ba9}', '\u{10baf}', 125), ('\u{10c00}', '\u{10c48}', 115), ('\u{10c80}', '\u{10cb2}', 108),
+    ('\u{10cc0}', '\u{10cf2}', 108), ('\u{10cfa}', '\u{10cff}', 108), ('\u{10d00}', '\u{10d27}', 50),
+    ('\u{10d30}', '\u{10d39}', 50), ('\u{10d40}', '\u{10d65}', 38), ('\u{10d69}', '\u{10d85}', 38),
+    ('\u{10d8e}', '\u{10d8f}', 38), ('\u{10e60}', '\u{10e7e}', 3), ('\u{10e80}', '\u{10ea9}', 165),
+    ('\u{10eab}', '\u{10ead}', 165), ('\u{10eb0}', '\u{10eb1}', 165), ('\u{10ec2}', '\u{10ec4}', 3),
+    ('\u{10efc}', '\u{10eff}', 3), ('\u{10f00}', '\u{10f27}', 113), ('\u{10f30}', '\u{10f59}', 135),
+    ('\u{10f70}', '\u{10f89}', 116), ('\u{10fb0}', '\u{10fcb}', 23), ('\u{10fe0}', '\u{10ff6}', 36),
+    ('\u{11000}', '\u{1104d}', 13), ('\u{11052}', '\u{11075}', 13), ('\u{1107f}', '\u{1107f}', 13),
+    ('\u{11080}', '\u{110c2}', 59), ('\u{110cd}', '\u{110cd}', 59), ('\u{110d0}', '\u{110e8}', 136),
+    ('\u{110f0}', '\u{110f9}', 136), ('\u{11100}', '\u{11134}', 20), ('\u{11136}', '\u{11147}', 20),
+    ('\u{11150}', '\u{11176}', 79), ('\u{11180}', '\u{111df}', 130), ('\u{111e1}', '\u{111f4}', 134),
+    ('\u{11200}', '\u{11211}', 67), ('\u{11213}', '\u{11241}', 67), ('\u{11280}', '\u{11286}', 95),
+    ('\u{11288}', '\u{11288}', 95), ('\u{1128a}', '\u{1128d}', 95), ('\u{1128f}', '\u{1129d}', 95),
+    ('\u{1129f}', '\u{112a9}', 95), ('\u{112b0}', '\u{112ea}', 68), ('\u{112f0}', '\u{112f9}', 68),
+    ('\u{11300}', '\u{11303}', 42), ('\u{11305}', '\u{1130c}', 42), ('\u{1130f}', '\u{11310}', 42),
+    ('\u{11313}', '\u{11328}', 42), ('\u{1132a}', '\u{11330}', 42), ('\u{11332}', '\u{11333}', 42),
+    ('\u{11335}', '\u{11339}', 42), ('\u{1133c}', '\u{11344}', 42), ('\u{11347}', '\u{11348}', 42),
+    ('\u{1134b}', '\u{1134d}', 42), ('\u{11350}', '\u{11350}', 42), ('\u{11357}', '\u{11357}', 42),
+    ('\u{1135d}', '\u{11363}', 42), ('\u{11366}', '\u{1136c}', 42), ('\u{11370}', '\u{11374}', 42),
+    ('\u{11380}', '\u{11389}', 159), ('\u{1138b}', '\u{1138b}', 159), ('\u{1138e}', '\u{1138e}', 159),
+    ('\u{11390}', '\u{113b5}', 159), ('\u{113b7}', '\u{113c0}', 159), ('\u{113c2}', '\u{113c2}', 159),
+    ('\u{113c5}', '\u{113c5}', 159), ('\u{113c7}', '\u{113ca}', 159), ('\u{113cc}', '\u{113d5}', 159),
+    ('\u{113d7}', '\u{113d8}', 159), ('\u{113e1}', '\u{113e2}', 159), ('\u{11400}', '\u{1145b}', 101),
+    ('\u{1145d}', '\u{11461}', 101), ('\u{11480}', '\u{114c7}', 156), ('\u{114d0}', '\u{114d9}', 156),
+    ('\u{11580}', '\u{115b5}', 132), ('\u{115b8}', '\u{115dd}', 132), ('\u{11600}', '\u{11644}', 92),
+    ('\u{11650}', '\u{11659}', 92), ('\u{11660}', '\u{1166c}', 93), ('\u{11680}', '\u{116b9}', 147),
+    ('\u{116c0}', '\u{116c9}', 147), ('\u{116d0}', '\u{116e3}', 96), ('\u{11700}', '\u{1171a}', 1),
+    ('\u{1171d}', '\u{1172b}', 1), ('\u{11730}', '\u{11746}', 1), ('\u{11800}', '\u{1183b}', 32),
+    ('\u{118a0}', '\u{118f2}', 164), ('\u{118ff}', '\u{118ff}', 164), ('\u{11900}', '\u{11906}', 31),
+    ('\u{11909}', '\u{11909}', 31), ('\u{1190c}', '\u{11913}', 31), ('\u{11915}', '\u{11916}', 31),
+    ('\u{11918}', '\u{11935}', 31), ('\u{11937}', '\u{11938}', 31), ('\u{1193b}', '\u{11946}', 31),
+    ('\u{11950}', '\u{11959}', 31), ('\u{119a0}', '\u{119a7}', 99), ('\u{119aa}', '\u{119d7}', 99),
+    ('\u{119da}', '\u{119e4}', 99), ('\u{11a00}', '\u{11a47}', 167), ('\u{11a50}', '\u{11aa2}', 137),
+    ('\u{11ab0}', '\u{11abf}', 17), ('\u{11ac0}', '\u{11af8}', 122), ('\u{11b00}', '\u{11b09}', 30),
+    ('\u{11bc0}', '\u{11be1}', 139), ('\u{11bf0}', '\u{11bf9}', 139), ('\u{11c00}', '\u{11c08}', 11),
+    ('\u{11c0a}', '\u{11c36}', 11), ('\u{11c38}', '\u{11c45}', 11), ('\u{11c50}', '\u{11c6c}', 11),
+    ('\u{11c70}', '\u{11c8f}', 84), ('\u{11c92}', '\u{11ca7}', 84), ('\u{11ca9}', '\u{11cb6}', 84),
+    ('\u{11d00}', '\u{11d06}', 85), ('\u{11d08}', '\u{11d09}', 85), ('\u{11d0b}', '\u{11d36}', 85),
+    ('\u{11d3a}', '\u{11d3a}', 85), ('\u{11d3c}', '\u{11d3d}', 85), ('\u{11d3f}', '\u{11d47}', 85),
+    ('\u{11d50}', '\u{11d59}', 85), ('\u{11d60}', '\u{11d65}', 45), ('\u{11d67}', '\u{11d68}', 45),
+    ('\u{11d6a}', '\u{11d8e}', 45), ('\u{11d90}', '\u{11d91}', 45), ('\u{11d93}', '\u{11d98}', 45),
+    ('\u{11da0}', '\u{11da9}', 45), ('\u{11ee0}', '\u{11ef8}', 80), ('\u{11f00}', '\u{11f10}', 62),
+    ('\u{11f12}', '\u{11f3a}', 62), ('\u{11f3e}', '\u{11f5a}', 62), ('\u{11fb0}', '\u{11fb0}', 76),
+    ('\u{11fc0}', '\u{11ff1}', 148), ('\u{11fff}', '\u{11fff}', 148), ('\u{12000}', '\u{12399}', 25),
+    ('\u{12400}', '\u{1246e}', 25), ('\u{12470}', '\u{12474}', 25), ('\u{12480}', '\u{12543}', 25),
+    ('\u{12f90}', '\u{12ff2}', 27), ('\u{13000}', '\u{13455}', 34), ('\u{13460}', '\u{143fa}', 34),
+    ('\u{14400}', '\u{14646}', 2), ('\u{16100}', '\u{16139}', 47), ('\u{16800}', '\u{16a38}', 7),
+    ('\u{16a40}', '\u{16a5e}', 94), ('\u{16a60}', '\u{16a69}', 94), ('\u{16a6e}', '\u{16a6f}', 94),
+    ('\u{16a70}', '\u{16abe}', 149), ('\u{16ac0}', '\u{16ac9}', 149), ('\u{16ad0}', '\u{16aed}', 8),
+    ('\u{16af0}', '\u{16af5}', 8), ('\u{16b00}', '\u{16b45}', 120), ('\u{16b50}', '\u{16b59}', 120),
+    ('\u{16b5b}', '\u{16b61}', 120), ('\u{16b63}', '\u{16b77}', 120), ('\u{16b7d}', '\u{16b8f}', 120),
+    ('\u{16d40}', '\u{16d79}', 69), ('\u{16e40}', '\u{16e9a}', 86), ('\u{16f00}', '\u{16f4a}', 91),
+    ('\u{16f4f}', '\u{16f87}', 91), ('\u{16f8f}', '\u{16f9f}', 91), ('\u{16fe0}', '\u{16fe0}', 150),
+    ('\u{16fe1}', '\u{16fe1}', 103), ('\u{16fe2}', '\u{16fe3}', 48), ('\u{16fe4}', '\u{16fe4}', 65),
+    ('\u{16ff0}', '\u{16ff1}', 48), ('\u{17000}', '\u{187f7}', 150), ('\u{18800}', '\u{18aff}', 150),
+    ('\u{18b00}', '\u{18cd5}', 65), ('\u{18cff}', '\u{18cff}', 65), ('\u{18d00}', '\u{18d08}', 150),
+    ('\u{1aff0}', '\u{1aff3}', 61), ('\u{1aff5}', '\u{1affb}', 61), ('\u{1affd}', '\u{1affe}', 61),
+    ('\u{1b000}', '\u{1b000}', 61), ('\u{1b001}', '\u{1b11f}', 54), ('\u{1b120}', '\u{1b122}', 61),
+    ('\u{1b132}', '\u{1b132}', 54), ('\u{1b150}', '\u{1b152}', 54), ('\u{1b155}', '\u{1b155}', 61),
+    ('\u{1b164}', '\u{1b167}', 61), ('\u{1b170}', '\u{1b2fb}', 103), ('\u{1bc00}', '\u{1bc6a}', 33),
+    ('\u{1bc70}', '\u{1bc7c}', 33), ('\u{1bc80}', '\u{1bc88}', 33), ('\u{1bc90}', '\u{1bc99}', 33),
+    ('\u{1bc9c}', '\u{1bc9f}', 33), ('\u{1d200}', '\u{1d245}', 43), ('\u{1d800}', '\u{1da8b}', 133),
+    ('\u{1da9b}', '\u{1da9f}', 133), ('\u{1daa1}', '\u{1daaf}', 133), ('\u{1df00}', '\u{1df1e}', 71),
+    ('\u{1df25}', '\u{1df2a}', 71), ('\u{1e000}', '\u{1e006}', 40), ('\u{1e008}', '\u{1e018}', 40),
+    ('\u{1e01b}', '\u{1e021}', 40), ('\u{1e023}', '\u{1e024}', 40), ('\u{1e026}', '\u{1e02a}', 40),
+    ('\u{1e030}', '\u{1e06d}', 28), ('\u{1e08f}', '\u{1e08f}', 28), ('\u{1e100}', '\u{1e12c}', 104),
+    ('\u{1e130}', '\u{1e13d}', 104), ('\u{1e140}', '\u{1e149}', 104), ('\u{1e14e}', '\u{1e14f}', 104),
+    ('\u{1e290}', '\u{1e2ae}', 158), ('\u{1e2c0}', '\u{1e2f9}', 163), ('\u{1e2ff}', '\u{1e2ff}', 163),
+    ('\u{1e4d0}', '\u{1e4f9}', 98), ('\u{1e5d0}', '\u{1e5fa}', 107), ('\u{1e5ff}', '\u{1e5ff}', 107),
+    ('\u{1e7e0}', '\u{1e7e6}', 37), ('\u{1e7e8}', '\u{1e7eb}', 37), ('\u{1e7ed}', '\u{1e7ee}', 37),
+    ('\u{1e7f0}', '\u{1e7fe}', 37), ('\u{1e800}', '\u{1e8c4}', 88), ('\u{1e8c7}', '\u{1e8d6}', 88),
+    ('\u{1e900}', '\u{1e94b}', 0), ('\u{1e950}', '\u{1e959}', 0), ('\u{1e95e}', '\u{1e95f}', 0),
+    ('\u{1ee00}', '\u{1ee03}', 3), ('\u{1ee05}', '\u{1ee1f}', 3), ('\u{1ee21}', '\u{1ee22}', 3),
+    ('\u{1ee24}', '\u{1ee24}', 3), ('\u{1ee27}', '\u{1ee27}', 3), ('\u{1ee29}', '\u{1ee32}', 3),
+    ('\u{1ee34}', '\u{1ee37}', 3), ('\u{1ee39}', '\u{1ee39}', 3), ('\u{1ee3b}', '\u{1ee3b}', 3),
+    ('\u{1ee42}', '\u{1ee42}', 3), ('\u{1ee47}', '\u{1ee47}', 3), ('\u{1ee49}', '\u{1ee49}', 3),
+    ('\u{1ee4b}', '\u{1ee4b}', 3), ('\u{1ee4d}', '\u{1ee4f}', 3), ('\u{1ee51}', '\u{1ee52}', 3),
+    ('\u{1ee54}', '\u{1ee54}', 3), ('\u{1ee57}', '\u{1ee57}', 3), ('\u{1ee59}', '\u{1ee59}', 3),
+    ('\u{1ee5b}', '\u{1ee5b}', 3), ('\u{1ee5d}', '\u{1ee5d}', 3), ('\u{1ee5f}', '\u{1ee5f}', 3),
+    ('\u{1ee61}', '\u{1ee62}', 3), ('\u{1ee64}', '\u{1ee64}', 3), ('\u{1ee67}', '\u{1ee6a}', 3),
+    ('\u{1ee6c}', '\u{1ee72}', 3), ('\u{1ee74}', '\u{1ee77}', 3), ('\u{1ee79}', '\u{1ee7c}', 3),
+    ('\u{1ee7e}', '\u{1ee7e}', 3), ('\u{1ee80}', '\u{1ee89}', 3), ('\u{1ee8b}', '\u{1ee9b}', 3),
+    ('\u{1eea1}', '\u{1eea3}', 3), ('\u{1eea5}', '\u{1eea9}', 3), ('\u{1eeab}', '\u{1eebb}', 3),
+    ('\u{1eef0}', '\u{1eef1}', 3), ('\u{1f200}', '\u{1f200}', 54), ('\u{20000}', '\u{2a6df}', 48),
+    ('\u{2a700}', '\u{2b739}', 48), ('\u{2b740}', '\u{2b81d}', 48), ('\u{2b820}', '\u{2cea1}', 48),
+    ('\u{2ceb0}', '\u{2ebe0}', 48), ('\u{2ebf0}', '\u{2ee5d}', 48), ('\u{2f800}', '\u{2fa1d}', 48),
+    ('\u{30000}', '\u{3134a}', 48), ('\u{31350}', '\u{323af}', 48)
+];
+
+// What each character that can be in an identifier looks like, where
+// that's something else, from which a skeleton of the identifier is made
+pub const CONFUSABLES: &[(char, &str)] = &[
+    ('0', "O"), ('1', "l"), ('I', "l"),
+    ('m', "rn"), ('\u{b5}', "\u{3bc}"), ('\u{c6}', "AE"),
+    ('\u{d0}', "D\u{335}"), ('\u{d8}', "O\u{338}"), ('\u{e6}', "ae"),
+    ('\u{f0}', "\u{2202}\u{335}"), ('\u{f8}', "o\u{338}"), ('\u{110}', "D\u{335}"),
+    ('\u{111}', "d\u{335}"), ('\u{126}', "H\u{335}"), ('\u{127}', "h\u{335}"),
+    ('\u{131}', "i"), ('\u{132}', "lJ"), ('\u{133}', "ij"),
+    ('\u{13f}', "l\u{b7}"), ('\u{140}', "l\u{b7}"), ('\u{141}', "L\u{338}"),
+    ('\u{142}', "l\u{338}"), ('\u{149}', "\u{27}n"), ('\u{152}', "OE"),
+    ('\u{153}', "oe"), ('\u{166}', "T\u{335}"), ('\u{167}', "t\u{335}"),
+    ('\u{17f}', "f"), ('\u{180}', "b\u{335}"), ('\u{181}', "\u{27}B"),
+    ('\u{182}', "b\u{304}"), ('\u{183}', "b\u{304}"), ('\u{184}', "b"),
+    ('\u{187}', "C\u{27}"), ('\u{189}', "D\u{335}"), ('\u{18a}', "\u{27}D"),
+    ('\u{18c}', "d\u{304}"), ('\u{18d}', "g"), ('\u{191}', "F\u{326}"),
+    ('\u{192}', "f\u{326}"), ('\u{193}', "G\u{27}"), ('\u{196}', "l"),
+    ('\u{197}', "l\u{335}"), ('\u{198}', "K\u{27}"), ('\u{199}', "k\u{314}"),
+    ('\u{19a}', "l\u{335}"), ('\u{19b}', "\u{3bb}\u{338}"), ('\u{19d}', "N\u{326}"),
+    ('\u{19e}', "n\u{329}"), ('\u{19f}', "O\u{335}"), ('\u{1a4}', "\u{27}P"),
+    ('\u{1a5}', "p\u{314}"), ('\u{1a6}', "R"), ('\u{1a7}', "2"),
+    ('\u{1ac}', "\u{27}T"), ('\u{1ad}', "t\u{314}"), ('\u{1ae}', "T\u{328}"),
+    ('\u{1b3}', "\u{27}Y"), ('\u{1b4}', "y\u{314}"), ('\u{1b5}', "Z\u{335}"),
+    ('\u{1b6}', "z\u{335}"), ('\u{1b7}', "3"), ('\u{1bb}', "2\u{335}"),
+    ('\u{1bc}', "5"), ('\u{1bd}', "s"), ('\u{1bf}', "\u{fe}"),
+    ('\u{1c0}', "l"), ('\u{1c1}', "ll"), ('\u{1c3}', "\u{21}"),
+    ('\u{1c4}', "DZ\u{30c}"), ('\u{1c5}', "Dz\u{30c}"), ('\u{1c6}', "dz\u{30c}"),
+    ('\u{1c7}', "LJ"), ('\u{1c8}', "Lj"), ('\u{1c9}', "lj"),
+    ('\u{1ca}', "NJ"), ('\u{1cb}', "Nj"), ('\u{1cc}', "nj"),
+    ('\u{1e4}', "G\u{335}"), ('\u{1e5}', "g\u{335}"), ('\u{1f1}', "DZ"),
+    ('\u{1f2}', "Dz"), ('\u{1f3}', "dz"), ('\u{21c}', "3"),
+    ('\u{222}', "8"), ('\u{223}', "8"), ('\u{224}', "Z\u{326}"),
+    ('\u{225}', "z\u{326}"), ('\u{23c}', "c\u{338}"), ('\u{23e}', "T\u{338}"),
+    ('\u{241}', "\u{3f}"), ('\u{244}', "U\u{335}"), ('\u{246}', "E\u{338}"),
+    ('\u{247}', "e\u{338}"), ('\u{248}', "J\u{335}"), ('\u{249}', "j\u{335}"),
+    ('\u{24d}', "r\u{335}"), ('\u{24e}', "Y\u{335}"), ('\u{24f}', "y\u{335}"),
+    ('\u{251}', "a"), ('\u{253}', "b\u{314}"), ('\u{256}', "d\u{328}"),
+    ('\u{257}', "d\u{314}"), ('\u{259}', "\u{1dd}"), ('\u{25a}', "\u{1dd}\u{2de}"),
+    ('\u{25b}', "\u{a793}"), ('\u{260}', "g\u{314}"), ('\u{261}', "g"),
+    ('\u{263}', "y"), ('\u{266}', "h\u{314}"), ('\u{268}', "i\u{335}"),
+    ('\u{269}', "i"), ('\u{26a}', "i"), ('\u{26b}', "l\u{334}"),
+    ('\u{26d}', "l\u{328}"), ('\u{26e}', "l\u{21d}"), ('\u{26f}', "w"),
+    ('\u{271}', "rn\u{326}"), ('\u{273}', "n\u{328}"), ('\u{275}', "o\u{335}"),
+    ('\u{276}', "o\u{1d07}"), ('\u{27c}', "r\u{329}"), ('\u{27d}', "r\u{328}"),
+    ('\u{282}', "s\u{328}"), ('\u{28b}', "u"), ('\u{28f}', "y"),
+    ('\u{290}', "z\u{328}"), ('\u{292}', "\u{21d}"), ('\u{294}', "\u{3f}"),
+    ('\u{2a0}', "q\u{314}"), ('\u{2a3}', "dz"), ('\u{2a4}', "d\u{21d}"),
+    ('\u{2a5}', "d\u{291}"), ('\u{2a6}', "ts"), ('\u{2a7}', "t\u{283}"),
+    ('\u{2a8}', "t\u{255}"), ('\u{2a9}', "f\u{14b}"), ('\u{2aa}', "ls"),
+    ('\u{2ab}', "lz"), ('\u{2b3}', "\u{18f4}"), ('\u{2b9}', "\u{27}"),
+    ('\u{2ba}', "\u{27}\u{27}"), ('\u{2bb}', "\u{27}"), ('\u{2bc}', "\u{27}"),
+    ('\u{2bd}', "\u{27}"), ('\u{2be}', "\u{27}"), ('\u{2bf}', "\u{559}"),
+    ('\u{2c6}', "\u{5e}"), ('\u{2c8}', "\u{27}"), ('\u{2ca}', "\u{27}"),
+    ('\u{2cb}', "\u{27}"), ('\u{2d0}', "\u{3a}"), ('\u{2e1}', "\u{18f3}"),
+    ('\u{2e2}', "\u{18f5}"), ('\u{2e4}', "\u{2c1}"), ('\u{2ee}', "\u{27}\u{27}"),
+    ('\u{305}', "\u{304}"), ('\u{30c}', "\u{306}"), ('\u{30d}', "\u{670}"),
+    ('\u{310}', "\u{306}\u{307}"), ('\u{311}', "\u{302}"), ('\u{315}', "\u{313}"),
+    ('\u{317}', "\u{650}"), ('\u{320}', "\u{331}"), ('\u{321}', "\u{326}"),
+    ('\u{322}', "\u{328}"), ('\u{327}', "\u{326}"), ('\u{336}', "\u{335}"),
+    ('\u{337}', "\u{338}"), ('\u{339}', "\u{326}"), ('\u{342}', "\u{303}"),
+    ('\u{345}', "\u{328}"), ('\u{347}', "\u{333}"), ('\u{357}', "\u{350}"),
+    ('\u{358}', "\u{307}"), ('\u{366}', "\u{30a}"), ('\u{36e}', "\u{306}"),
+    ('\u{370}', "\u{2c75}"), ('\u{376}', "\u{418}"), ('\u{377}', "\u{1d0e}"),
+    ('\u{37b}', "\u{254}"), ('\u{37d}', "\u{a73f}"), ('\u{37f}', "J"),
+    ('\u{391}', "A"), ('\u{392}', "B"), ('\u{395}', "E"),
+    ('\u{396}', "Z"), ('\u{397}', "H"), ('\u{398}', "O\u{335}"),
+    ('\u{399}', "l"), ('\u{39a}', "K"), ('\u{39b}', "\u{245}"),
+    ('\u{39c}', "M"), ('\u{39d}', "N"), ('\u{39f}', "O"),
+    ('\u{3a1}', "P"), ('\u{3a3}', "\u{1a9}"), ('\u{3a4}', "T"),
+    ('\u{3a5}', "Y"), ('\u{3a7}', "X"), ('\u{3b1}', "a"),
+    ('\u{3b2}', "\u{df}"), ('\u{3b3}', "y"), ('\u{3b4}', "\u{1e9f}"),
+    ('\u{3b5}', "\u{a793}"), ('\u{3b7}', "n\u{329}"), ('\u{3b8}', "O\u{335}"),
+    ('\u{3b9}', "i"), ('\u{3ba}', "\u{138}"), ('\u{3bd}', "v"),
+    ('\u{3bf}', "o"), ('\u{3c1}', "p"), ('\u{3c3}', "o"),
+    ('\u{3c4}', "\u{1d1b}"), ('\u{3c5}', "u"), ('\u{3c6}', "\u{278}"),
+    ('\u{3d0}', "\u{df}"), ('\u{3d1}', "O\u{335}"), ('\u{3d2}', "Y"),
+    ('\u{3d5}', "\u{278}"), ('\u{3d6}', "\u{3c0}"), ('\u{3db}', "\u{3c2}"),
+    ('\u{3dc}', "F"), ('\u{3e8}', "2"), ('\u{3e9}', "\u{1a8}"),
+    ('\u{3f0}', "\u{138}"), ('\u{3f1}', "p"), ('\u{3f2}', "c"),
+    ('\u{3f3}', "j"), ('\u{3f4}', "O\u{335}"), ('\u{3f5}', "\u{a793}"),
+    ('\u{3f7}', "\u{de}"), ('\u{3f8}', "\u{fe}"), ('\u{3f9}', "C"),
+    ('\u{3fa}', "M"), ('\u{3fd}', "\u{186}"), ('\u{3ff}', "\u{a73e}"),
+    ('\u{404}', "\u{a792}"), ('\u{405}', "S"), ('\u{406}', "l"),
+    ('\u{408}', "J"), ('\u{410}', "A"), ('\u{411}', "b\u{304}"),
+    ('\u{412}', "B"), ('\u{413}', "\u{393}"), ('\u{415}', "E"),
+    ('\u{417}', "3"), ('\u{41a}', "K"), ('\u{41b}', "\u{245}"),
+    ('\u{41c}', "M"), ('\u{41d}', "H"), ('\u{41e}', "O"),
+    ('\u{41f}', "\u{3a0}"), ('\u{420}', "P"), ('\u{421}', "C"),
+    ('\u{422}', "T"), ('\u{423}', "Y"), ('\u{424}', "\u{3a6}"),
+    ('\u{425}', "X"), ('\u{42b}', "bl"), ('\u{42c}', "b"),
+    ('\u{42e}', "lO"), ('\u{430}', "a"), ('\u{431}', "6"),
+    ('\u{432}', "\u{299}"), ('\u{433}', "r"), ('\u{435}', "e"),
+    ('\u{437}', "\u{25c}"), ('\u{438}', "\u{1d0e}"), ('\u{43a}', "\u{138}"),
+    ('\u{43c}', "\u{28d}"), ('\u{43d}', "\u{29c}"), ('\u{43e}', "o"),
+    ('\u{43f}', "\u{3c0}"), ('\u{440}', "p"), ('\u{441}', "c"),
+    ('\u{442}', "\u{1d1b}"), ('\u{443}', "y"), ('\u{444}', "\u{278}"),
+    ('\u{445}', "x"), ('\u{44a}', "\u{2c9}b"), ('\u{44b}', "\u{185}i"),
+    ('\u{44c}', "\u{185}"), ('\u{44f}', "\u{1d19}"), ('\u{454}', "\u{a793}"),
+    ('\u{455}', "s"), ('\u{456}', "i"), ('\u{458}', "j"),
+    ('\u{45b}', "h\u{335}"), ('\u{461}', "w"), ('\u{462}', "b\u{335}"),
+    ('\u{463}', "b\u{335}"), ('\u{470}', "\u{3a8}"), ('\u{471}', "\u{3c8}"),
+    ('\u{472}', "O\u{335}"), ('\u{473}', "o\u{335}"), ('\u{474}', "V"),
+    ('\u{475}', "v"), ('\u{47c}', "\u{460}\u{486}\u{487}"), ('\u{47d}', "w\u{486}\u{487}"),
+    ('\u{48a}', "\u{418}\u{326}\u{300}"), ('\u{48b}', "\u{438}\u{326}\u{306}"), ('\u{48c}', "b\u{335}"),
+    ('\u{48d}', "b\u{335}"), ('\u{490}', "\u{393}\u{27}"), ('\u{491}', "r\u{27}"),
+    ('\u{492}', "\u{393}\u{335}"), ('\u{493}', "r\u{335}"), ('\u{496}', "\u{416}\u{329}"),
+    ('\u{497}', "\u{436}\u{329}"), ('\u{498}', "3\u{326}"), ('\u{499}', "\u{25c}\u{326}"),
+    ('\u{49a}', "K\u{329}"), ('\u{49b}', "\u{138}\u{329}"), ('\u{49e}', "K\u{335}"),
+    ('\u{49f}', "\u{138}\u{335}"), ('\u{4a2}', "H\u{329}"), ('\u{4a3}', "\u{29c}\u{329}"),
+    ('\u{4aa}', "C\u{326}"), ('\u{4ab}', "c\u{326}"), ('\u{4ac}', "T\u{329}"),
+    ('\u{4ad}', "\u{1d1b}\u{329}"), ('\u{4ae}', "Y"), ('\u{4af}', "y"),
+    ('\u{4b0}', "Y\u{335}"), ('\u{4b1}', "y\u{335}"), ('\u{4b2}', "X\u{329}"),
+    ('\u{4bb}', "h"), ('\u{4bd}', "e"), ('\u{4be}', "\u{4bc}\u{328}"),
+    ('\u{4bf}', "e\u{328}"), ('\u{4c0}', "l"), ('\u{4c5}', "\u{245}\u{326}"),
+    ('\u{4c6}', "\u{43b}\u{326}"), ('\u{4c7}', "H\u{326}"), ('\u{4c8}', "\u{29c}\u{326}"),
+    ('\u{4c9}', "H\u{326}"), ('\u{4ca}', "\u{29c}\u{326}"), ('\u{4cb}', "\u{4b6}"),
+    ('\u{4cc}', "\u{4b7}"), ('\u{4cd}', "M\u{326}"), ('\u{4ce}', "\u{28d}\u{326}"),
+    ('\u{4cf}', "i"), ('\u{4d4}', "AE"), ('\u{4d5}', "ae"),
+    ('\u{4d8}', "\u{18f}"), ('\u{4d9}', "\u{1dd}"), ('\u{4e0}', "3"),
+    ('\u{4e1}', "\u{21d}"), ('\u{4e8}', "O\u{335}"), ('\u{4e9}', "o\u{335}"),
+    ('\u{501}', "d"), ('\u{50a}', "\u{1f6}"), ('\u{50c}', "G"),
+    ('\u{50d}', "\u{262}"), ('\u{510}', "\u{190}"), ('\u{511}', "\u{a793}"),
+    ('\u{51b}', "q"), ('\u{51c}', "W"), ('\u{51d}', "w"),
+    ('\u{53b}', "\u{12ae}"), ('\u{544}', "\u{1206}"), ('\u{54a}', "\u{1323}"),
+    ('\u{54c}', "\u{1261}"), ('\u{54d}', "U"), ('\u{54f}', "S"),
+    ('\u{553}', "\u{3a6}"), ('\u{555}', "O"), ('\u{561}', "w"),
+    ('\u{563}', "q"), ('\u{566}', "q"), ('\u{56e}', "\u{1e9f}"),
+    ('\u{570}', "h"), ('\u{575}', "\u{237}"), ('\u{578}', "n"),
+    ('\u{57a}', "\u{270}"), ('\u{57c}', "n"), ('\u{57d}', "u"),
+    ('\u{581}', "g"), ('\u{584}', "f"), ('\u{585}', "o"),
+    ('\u{587}', "\u{565}\u{582}"), ('\u{59c}', "\u{301}"), ('\u{59d}', "\u{301}"),
+    ('\u{5a4}', "\u{59a}"), ('\u{5a8}', "\u{599}"), ('\u{5ad}', "\u{596}"),
+    ('\u{5ae}', "\u{598}"), ('\u{5af}', "\u{30a}"), ('\u{5b4}', "\u{323}"),
+    ('\u{5b9}', "\u{307}"), ('\u{5ba}', "\u{307}"), ('\u{5c1}', "\u{307}"),
+    ('\u{5c2}', "\u{307}"), ('\u{5c4}', "\u{307}"), ('\u{5c5}', "\u{323}"),
+    ('\u{5d5}', "l"), ('\u{5d8}', "v"), ('\u{5d9}', "\u{27}"),
+    ('\u{5df}', "l"), ('\u{5e1}', "o"), ('\u{5f0}', "ll"),
+    ('\u{5f1}', "l\u{27}"), ('\u{5f2}', "\u{27}\u{27}"), ('\u{618}', "\u{301}"),
+    ('\u{619}', "\u{313}"), ('\u{61a}', "\u{650}"), ('\u{627}', "l"),
+    ('\u{62b}', "\u{649}\u{6db}"), ('\u{634}', "\u{633}\u{6db}"), ('\u{63d}', "\u{649}\u{302}"),
+    ('\u{63f}', "\u{649}\u{6db}"), ('\u{647}', "o"), ('\u{64a}', "\u{649}"),
+    ('\u{64b}', "\u{30b}"), ('\u{64e}', "\u{301}"), ('\u{64f}', "\u{313}"),
+    ('\u{652}', "\u{30a}"), ('\u{653}', "\u{303}"), ('\u{656}', "\u{329}"),
+    ('\u{657}', "\u{312}"), ('\u{658}', "\u{306}"), ('\u{659}', "\u{304}"),
+    ('\u{65a}', "\u{306}"), ('\u{65b}', "\u{302}"), ('\u{65c}', "\u{323}"),
+    ('\u{65d}', "\u{314}"), ('\u{65f}', "\u{655}"), ('\u{660}', "\u{2e}"),
+    ('\u{661}', "l"), ('\u{665}', "o"), ('\u{667}', "V"),
+    ('\u{668}', "\u{245}"), ('\u{66e}', "\u{649}"), ('\u{66f}', "\u{6a1}"),
+    ('\u{672}', "l\u{674}"), ('\u{673}', "l\u{655}"), ('\u{675}', "l\u{674}"),
+    ('\u{676}', "\u{648}\u{674}"), ('\u{677}', "\u{648}\u{313}\u{674}"), ('\u{678}', "\u{649}\u{674}"),
+    ('\u{679}', "\u{649}\u{615}"), ('\u{67e}', "\u{649}\u{6db}"), ('\u{681}', "\u{62d}\u{654}"),
+    ('\u{685}', "\u{62d}\u{6db}"), ('\u{688}', "\u{62f}\u{615}"), ('\u{68b}', "\u{68a}\u{615}"),
+    ('\u{68e}', "\u{62f}\u{6db}"), ('\u{691}', "\u{631}\u{615}"), ('\u{692}', "\u{631}\u{306}"),
+    ('\u{698}', "\u{631}\u{6db}"), ('\u{69e}', "\u{635}\u{6db}"), ('\u{69f}', "\u{637}\u{6db}"),
+    ('\u{6a4}', "\u{6a1}\u{6db}"), ('\u{6a7}', "\u{641}"), ('\u{6a8}', "\u{6a1}\u{6db}"),
+    ('\u{6a9}', "\u{643}"), ('\u{6aa}', "\u{643}"), ('\u{6ad}', "\u{643}\u{6db}"),
+    ('\u{6b4}', "\u{6af}\u{6db}"), ('\u{6b5}', "\u{644}\u{306}"), ('\u{6b7}', "\u{644}\u{6db}"),
+    ('\u{6ba}', "\u{649}"), ('\u{6bb}', "\u{649}\u{615}"), ('\u{6bd}', "\u{649}\u{6db}"),
+    ('\u{6be}', "o"), ('\u{6c1}', "o"), ('\u{6c3}', "\u{629}"),
+    ('\u{6c6}', "\u{648}\u{306}"), ('\u{6c7}', "\u{648}\u{313}"), ('\u{6c8}', "\u{648}\u{670}"),
+    ('\u{6c9}', "\u{648}\u{302}"), ('\u{6cb}', "\u{648}\u{6db}"), ('\u{6cc}', "\u{649}"),
+    ('\u{6ce}', "\u{649}\u{306}"), ('\u{6d0}', "\u{67b}"), ('\u{6d1}', "\u{649}\u{6db}"),
+    ('\u{6d2}', "\u{649}"), ('\u{6d5}', "o"), ('\u{6df}', "\u{30a}"),
+    ('\u{6e8}', "\u{306}\u{307}"), ('\u{6ec}', "\u{307}"), ('\u{6ee}', "\u{62f}\u{302}"),
+    ('\u{6ef}', "\u{631}\u{302}"), ('\u{6f0}', "\u{2e}"), ('\u{6f1}', "l"),
+    ('\u{6f2}', "\u{662}"), ('\u{6f3}', "\u{663}"), ('\u{6f4}', "\u{664}"),
+    ('\u{6f5}', "o"), ('\u{6f6}', "\u{666}"), ('\u{6f7}', "V"),
+    ('\u{6f8}', "\u{245}"), ('\u{6f9}', "\u{669}"), ('\u{6ff}', "o\u{302}"),
+    ('\u{740}', "\u{307}"), ('\u{741}', "\u{307}"), ('\u{742}', "\u{73c}"),
+    ('\u{747}', "\u{301}"), ('\u{751}', "\u{628}\u{6db}"), ('\u{756}', "\u{649}\u{306}"),
+    ('\u{762}', "\u{6ac}"), ('\u{763}', "\u{643}\u{6db}"), ('\u{767}', "\u{754}"),
+    ('\u{768}', "\u{646}\u{615}"), ('\u{769}', "\u{646}\u{306}"), ('\u{76c}', "\u{631}\u{654}"),
+    ('\u{771}', "\u{697}\u{615}"), ('\u{772}', "\u{62d}\u{654}"), ('\u{77e}', "\u{633}\u{302}"),
+    ('\u{7c0}', "O"), ('\u{7ca}', "l"), ('\u{7eb}', "\u{304}"),
+    ('\u{7ed}', "\u{307}"), ('\u{7ee}', "\u{302}"), ('\u{7f3}', "\u{308}"),
+    ('\u{7f4}', "\u{27}"), ('\u{7f5}', "\u{27}"), ('\u{7fa}', "_"),
+    ('\u{8a1}', "\u{628}\u{654}"), ('\u{8a4}', "\u{6a2}\u{6db}"), ('\u{8a7}', "\u{645}\u{6db}"),
+    ('\u{8a8}', "\u{649}\u{654}"), ('\u{8a9}', "\u{754}"), ('\u{8ae}', "\u{62f}\u{324}\u{323}"),
+    ('\u{8af}', "\u{635}\u{324}\u{323}"), ('\u{8b0}', "\u{6af}"), ('\u{8b1}', "\u{648}"),
+    ('\u{8b2}', "\u{632}\u{302}"), ('\u{8b6}', "\u{628}\u{6e2}"), ('\u{8b7}', "\u{649}\u{6db}\u{6e2}"),
+    ('\u{8b9}', "\u{631}\u{306}\u{307}"), ('\u{8ba}', "\u{649}\u{306}\u{307}"), ('\u{8bb}', "\u{6a1}"),
+    ('\u{8bc}', "\u{6a1}"), ('\u{8bd}', "\u{649}"), ('\u{8e5}', "\u{64c}"),
+    ('\u{8e8}', "\u{64c}"), ('\u{8ea}', "\u{307}"), ('\u{8eb}', "\u{308}"),
+    ('\u{8ed}', "\u{323}"), ('\u{8ee}', "\u{324}"), ('\u{8f0}', "\u{30b}"),
+    ('\u{8f1}', "\u{64c}"), ('\u{8f2}', "\u{64d}"), ('\u{8f3}', "\u{313}"),
+    ('\u{8f8}', "\u{350}"), ('\u{8f9}', "\u{354}"), ('\u{8fa}', "\u{355}"),
+    ('\u{8ff}', "\u{350}"), ('\u{900}', "\u{352}"), ('\u{901}', "\u{306}\u{307}"),
+    ('\u{902}', "\u{307}"), ('\u{903}', "\u{3a}"), ('\u{904}', "\u{905}\u{946}"),
+    ('\u{906}', "\u{905}\u{93e}"), ('\u{908}', "\u{930}\u{94d}\u{907}"), ('\u{90d}', "\u{90f}\u{945}"),
+    ('\u{90e}', "\u{90f}\u{946}"), ('\u{910}', "\u{90f}\u{947}"), ('\u{911}', "\u{905}\u{949}"),
+    ('\u{912}', "\u{905}\u{93e}\u{946}"), ('\u{913}', "\u{905}\u{93e}\u{947}"), ('\u{914}', "\u{905}\u{93e}\u{948}"),
+    ('\u{93c}', "\u{323}"), ('\u{952}', "\u{331}"), ('\u{953}', "\u{300}"),
+    ('\u{954}', "\u{301}"), ('\u{966}', "o"), ('\u{967}', "\u{669}"),
+    ('\u{97d}', "\u{3f}"), ('\u{981}', "\u{306}\u{307}"), ('\u{986}', "\u{985}\u{9be}"),
+    ('\u{9bc}', "\u{323}"), ('\u{9e0}', "\u{98b}\u{9c3}"), ('\u{9e1}', "\u{98b}\u{9c3}"),
+    ('\u{9e6}', "O"), ('\u{9ea}', "8"), ('\u{9ed}', "9"),
+    ('\u{a02}', "\u{307}"), ('\u{a03}', "\u{983}"), ('\u{a06}', "\u{a05}\u{a3e}"),
+    ('\u{a07}', "\u{a72}\u{a3f}"), ('\u{a08}', "\u{a72}\u{a40}"), ('\u{a09}', "\u{a73}\u{a41}"),
+    ('\u{a0a}', "\u{a73}\u{a42}"), ('\u{a0f}', "\u{a72}\u{a47}"), ('\u{a10}', "\u{a05}\u{a48}"),
+    ('\u{a14}', "\u{a05}\u{a4c}"), ('\u{a3c}', "\u{323}"), ('\u{a4b}', "\u{946}"),
+    ('\u{a4d}', "\u{94d}"), ('\u{a66}', "o"), ('\u{a67}', "9"),
+    ('\u{a6a}', "8"), ('\u{a81}', "\u{306}\u{307}"), ('\u{a82}', "\u{307}"),
+    ('\u{a83}', "\u{3a}"), ('\u{a86}', "\u{a85}\u{abe}"), ('\u{a8d}', "\u{a85}\u{ac5}"),
+    ('\u{a8f}', "\u{a85}\u{ac7}"), ('\u{a90}', "\u{a85}\u{ac8}"), ('\u{a91}', "\u{a85}\u{abe}\u{ac5}"),
+    ('\u{a93}', "\u{a85}\u{abe}\u{ac7}"), ('\u{a94}', "\u{a85}\u{abe}\u{ac8}"), ('\u{abc}', "\u{323}"),
+    ('\u{abd}', "\u{93d}"), ('\u{ac1}', "\u{941}"), ('\u{ac2}', "\u{942}"),
+    ('\u{acd}', "\u{94d}"), ('\u{ae6}', "o"), ('\u{ae8}', "\u{968}"),
+    ('\u{ae9}', "\u{969}"), ('\u{aea}', "\u{96a}"), ('\u{aee}', "\u{96e}"),
+    ('\u{b01}', "\u{306}\u{307}"), ('\u{b03}', "8"), ('\u{b06}', "\u{b05}\u{b3e}"),
+    ('\u{b20}', "O"), ('\u{b3c}', "\u{323}"), ('\u{b66}', "O"),
+    ('\u{b68}', "9"), ('\u{b82}', "\u{30a}"), ('\u{b8a}', "\u{b89}\u{bb3}"),
+    ('\u{b9c}', "\u{b90}"), ('\u{bb0}', "\u{b88}"), ('\u{bbe}', "\u{b88}"),
+    ('\u{bc8}', "\u{ba9}"), ('\u{bcd}', "\u{307}"), ('\u{bd7}', "\u{bb3}"),
+    ('\u{be6}', "o"), ('\u{be7}', "\u{b95}"), ('\u{be8}', "\u{b89}"),
+    ('\u{bea}', "\u{b9a}"), ('\u{beb}', "\u{b88}\u{bc1}"), ('\u{bec}', "\u{b9a}\u{bc1}"),
+    ('\u{bed}', "\u{b8e}"), ('\u{bee}', "\u{b85}"), ('\u{c00}', "\u{306}\u{307}"),
+    ('\u{c02}', "o"), ('\u{c03}', "\u{983}"), ('\u{c13}', "\u{c12}\u{c55}"),
+    ('\u{c14}', "\u{c12}\u{c4c}"), ('\u{c20}', "\u{c30}\u{5bc}"), ('\u{c22}', "\u{c21}\u{323}"),
+    ('\u{c25}', "\u{c27}\u{5bc}"), ('\u{c2d}', "\u{c2c}\u{323}"), ('\u{c2e}', "\u{c35}\u{c41}"),
+    ('\u{c37}', "\u{c35}\u{323}"), ('\u{c39}', "\u{c35}\u{c3e}"), ('\u{c42}', "\u{c41}\u{c3e}"),
+    ('\u{c44}', "\u{c43}\u{c3e}"), ('\u{c60}', "\u{c0b}\u{c3e}"), ('\u{c61}', "\u{c0c}\u{c3e}"),
+    ('\u{c66}', "o"), ('\u{c81}', "\u{306}\u{307}"), ('\u{c82}', "o"),
+    ('\u{c83}', "\u{983}"), ('\u{c85}', "\u{c05}"), ('\u{c86}', "\u{c06}"),
+    ('\u{c87}', "\u{c07}"), ('\u{c92}', "\u{c12}"), ('\u{c93}', "\u{c12}\u{c55}"),
+    ('\u{c94}', "\u{c12}\u{c4c}"), ('\u{c9c}', "\u{c1c}"), ('\u{c9e}', "\u{c1e}"),
+    ('\u{ca3}', "\u{c23}"), ('\u{caf}', "\u{c2f}"), ('\u{cb1}', "\u{c31}"),
+    ('\u{cb2}', "\u{c32}"), ('\u{ce1}', "\u{c8c}\u{cbe}"), ('\u{ce6}', "o"),
+    ('\u{ce7}', "\u{c67}"), ('\u{ce8}', "\u{c68}"), ('\u{cef}', "\u{c6f}"),
+    ('\u{d01}', "\u{306}\u{307}"), ('\u{d02}', "o"), ('\u{d03}', "\u{983}"),
+    ('\u{d08}', "\u{d07}\u{d57}"), ('\u{d09}', "\u{b89}"), ('\u{d0a}', "\u{b89}\u{d57}"),
+    ('\u{d0c}', "\u{d28}\u{d41}"), ('\u{d10}', "\u{d0e}\u{d46}"), ('\u{d13}', "\u{d12}\u{d3e}"),
+    ('\u{d14}', "\u{d12}\u{d57}"), ('\u{d19}', "\u{d28}\u{d41}"), ('\u{d1c}', "\u{b90}"),
+    ('\u{d20}', "o"), ('\u{d23}', "\u{ba3}"), ('\u{d31}', "\u{d30}"),
+    ('\u{d34}', "\u{bb4}"), ('\u{d36}', "\u{bb6}"), ('\u{d3a}', "\u{b9f}\u{bbf}"),
+    ('\u{d3f}', "\u{bbf}"), ('\u{d40}', "\u{bbf}"), ('\u{d42}', "\u{d41}"),
+    ('\u{d43}', "\u{d41}"), ('\u{d48}', "\u{d46}\u{d46}"), ('\u{d4e}', "\u{971}"),
+    ('\u{d5f}', "o\u{d30}o"), ('\u{d61}', "\u{d1e}"), ('\u{d66}', "o"),
+    ('\u{d6a}', "\u{d30}\u{d4d}"), ('\u{d6b}', "\u{d26}\u{d4d}\u{d30}"), ('\u{d6c}', "\u{d28}\u{d4d}\u{d28}"),
+    ('\u{d6d}', "9"), ('\u{d6e}', "\u{d35}\u{d4d}\u{d30}"), ('\u{d6f}', "\u{d28}\u{d4d}"),
+    ('\u{d7b}', "\u{d28}\u{d4d}"), ('\u{d7c}', "\u{d30}\u{d4d}"), ('\u{d82}', "o"),
+    ('\u{d83}', "\u{983}"), ('\u{de9}', "\u{de8}\u{dcf}"), ('\u{dea}', "\u{da2}"),
+    ('\u{deb}', "\u{daf}"), ('\u{def}', "\u{de8}\u{dd3}"), ('\u{e03}', "\u{e02}"),
+    ('\u{e0b}', "\u{e0a}"), ('\u{e0f}', "\u{e0e}"), ('\u{e14}', "\u{e04}"),
+    ('\u{e15}', "\u{e04}"), ('\u{e17}', "\u{e11}"), ('\u{e21}', "\u{e06}"),
+    ('\u{e26}', "\u{e20}"), ('\u{e33}', "\u{30a}\u{e32}"), ('\u{e41}', "\u{e40}\u{e40}"),
+    ('\u{e45}', "\u{e32}"), ('\u{e4d}', "\u{30a}"), ('\u{e50}', "o"),
+    ('\u{e88}', "\u{e08}"), ('\u{e8d}', "\u{e22}"), ('\u{e9a}', "\u{e1a}"),
+    ('\u{e9b}', "\u{e1b}"), ('\u{e9d}', "\u{e1d}"), ('\u{e9e}', "\u{e1e}"),
+    ('\u{e9f}', "\u{e1f}"), ('\u{eb3}', "\u{30a}\u{eb2}"), ('\u{eb8}', "\u{e38}"),
+    ('\u{eb9}', "\u{e39}"), ('\u{ec8}', "\u{e48}"), ('\u{ec9}', "\u{e49}"),
+    ('\u{eca}', "\u{e4a}"), ('\u{ecb}', "\u{e4b}"), ('\u{ecd}', "\u{30a}"),
+    ('\u{ed0}', "o"), ('\u{edc}', "\u{eab}\u{e99}"), ('\u{edd}', "\u{eab}\u{ea1}"),
+    ('\u{f00}', "\u{f68}\u{f7c}\u{f7e}"), ('\u{f37}', "\u{325}"), ('\u{f6a}', "\u{f62}"),
+    ('\u{f77}', "\u{fb2}\u{f71}\u{f80}"), ('\u{f79}', "\u{fb3}\u{f71}\u{f80}"), ('\u{1000}', "\u{1002}\u{102c}"),
+    ('\u{1010}', "o\u{102c}"), ('\u{101d}', "o"), ('\u{101f}', "\u{1015}\u{102c}"),
+    ('\u{1029}', "\u{101e}\u{103c}"), ('\u{102a}', "\u{101e}\u{103c}\u{1031}\u{102c}\u{103a}"), ('\u{1036}', "\u{30a}"),
+    ('\u{1038}', "\u{983}"), ('\u{1040}', "o"), ('\u{1065}', "\u{1041}"),
+    ('\u{1066}', "\u{1015}\u{103e}"), ('\u{106f}', "\u{1015}\u{102c}\u{103e}"), ('\u{1070}', "\u{1003}\u{103e}"),
+    ('\u{107e}', "\u{107d}\u{103e}"), ('\u{1081}', "\u{1002}\u{103e}"), ('\u{10a0}', "\u{a786}"),
+    ('\u{10e7}', "y"), ('\u{10f3}', "\u{21d}"), ('\u{10ff}', "o"),
+    ('\u{1101}', "\u{1100}\u{1100}"), ('\u{1104}', "\u{1103}\u{1103}"), ('\u{1108}', "\u{1107}\u{1107}"),
+    ('\u{110a}', "\u{1109}\u{1109}"), ('\u{110d}', "\u{110c}\u{110c}"), ('\u{1113}', "\u{1102}\u{1100}"),
+    ('\u{1114}', "\u{1102}\u{1102}"), ('\u{1115}', "\u{1102}\u{1103}"), ('\u{1116}', "\u{1102}\u{1107}"),
+    ('\u{1117}', "\u{1103}\u{1100}"), ('\u{1118}', "\u{1105}\u{1102}"), ('\u{1119}', "\u{1105}\u{1105}"),
+    ('\u{111a}', "\u{1105}\u{1112}"), ('\u{111b}', "\u{1105}\u{110b}"), ('\u{111c}', "\u{1106}\u{1107}"),
+    ('\u{111d}', "\u{1106}\u{110b}"), ('\u{111e}', "\u{1107}\u{1100}"), ('\u{111f}', "\u{1107}\u{1102}"),
+    ('\u{1120}', "\u{1107}\u{1103}"), ('\u{1121}', "\u{1107}\u{1109}"), ('\u{1122}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{1123}', "\u{1107}\u{1109}\u{1103}"), ('\u{1124}', "\u{1107}\u{1109}\u{1107}"), ('\u{1125}', "\u{1107}\u{1109}\u{1109}"),
+    ('\u{1126}', "\u{1107}\u{1109}\u{110c}"), ('\u{1127}', "\u{1107}\u{110c}"), ('\u{1128}', "\u{1107}\u{110e}"),
+    ('\u{1129}', "\u{1107}\u{1110}"), ('\u{112a}', "\u{1107}\u{1111}"), ('\u{112b}', "\u{1107}\u{110b}"),
+    ('\u{112c}', "\u{1107}\u{1107}\u{110b}"), ('\u{112d}', "\u{1109}\u{1100}"), ('\u{112e}', "\u{1109}\u{1102}"),
+    ('\u{112f}', "\u{1109}\u{1103}"), ('\u{1130}', "\u{1109}\u{1105}"), ('\u{1131}', "\u{1109}\u{1106}"),
+    ('\u{1132}', "\u{1109}\u{1107}"), ('\u{1133}', "\u{1109}\u{1107}\u{1100}"), ('\u{1134}', "\u{1109}\u{1109}\u{1109}"),
+    ('\u{1135}', "\u{1109}\u{110b}"), ('\u{1136}', "\u{1109}\u{110c}"), ('\u{1137}', "\u{1109}\u{110e}"),
+    ('\u{1138}', "\u{1109}\u{110f}"), ('\u{1139}', "\u{1109}\u{1110}"), ('\u{113a}', "\u{1109}\u{1111}"),
+    ('\u{113b}', "\u{1105}\u{1112}"), ('\u{113d}', "\u{113c}\u{113c}"), ('\u{113f}', "\u{113e}\u{113e}"),
+    ('\u{1141}', "\u{110b}\u{1100}"), ('\u{1142}', "\u{110b}\u{1103}"), ('\u{1143}', "\u{110b}\u{1106}"),
+    ('\u{1144}', "\u{110b}\u{1107}"), ('\u{1145}', "\u{110b}\u{1109}"), ('\u{1146}', "\u{110b}\u{1140}"),
+    ('\u{1147}', "\u{110b}\u{110b}"), ('\u{1148}', "\u{110b}\u{110c}"), ('\u{1149}', "\u{110b}\u{110e}"),
+    ('\u{114a}', "\u{110b}\u{1110}"), ('\u{114b}', "\u{110b}\u{1111}"), ('\u{114d}', "\u{110c}\u{110b}"),
+    ('\u{114f}', "\u{114e}\u{114e}"), ('\u{1151}', "\u{1150}\u{1150}"), ('\u{1152}', "\u{110e}\u{110f}"),
+    ('\u{1153}', "\u{110e}\u{1112}"), ('\u{1156}', "\u{1111}\u{1107}"), ('\u{1157}', "\u{1111}\u{110b}"),
+    ('\u{1158}', "\u{1112}\u{1112}"), ('\u{115a}', "\u{1100}\u{1103}"), ('\u{115b}', "\u{1102}\u{1109}"),
+    ('\u{115c}', "\u{1102}\u{110c}"), ('\u{115d}', "\u{1102}\u{1112}"), ('\u{115e}', "\u{1103}\u{1105}"),
+    ('\u{1162}', "\u{1161}\u{4e28}"), ('\u{1164}', "\u{1163}\u{4e28}"), ('\u{1166}', "\u{1165}\u{4e28}"),
+    ('\u{1168}', "\u{1167}\u{4e28}"), ('\u{116a}', "\u{1169}\u{1161}"), ('\u{116b}', "\u{1169}\u{1161}\u{4e28}"),
+    ('\u{116c}', "\u{1169}\u{4e28}"), ('\u{116f}', "\u{116e}\u{1165}"), ('\u{1170}', "\u{116e}\u{1165}\u{4e28}"),
+    ('\u{1171}', "\u{116e}\u{4e28}"), ('\u{1173}', "\u{30fc}"), ('\u{1174}', "\u{30fc}\u{4e28}"),
+    ('\u{1175}', "\u{4e28}"), ('\u{1176}', "\u{1161}\u{1169}"), ('\u{1177}', "\u{1161}\u{116e}"),
+    ('\u{1178}', "\u{1163}\u{1169}"), ('\u{1179}', "\u{1163}\u{116d}"), ('\u{117a}', "\u{1165}\u{1169}"),
+    ('\u{117b}', "\u{1165}\u{116e}"), ('\u{117c}', "\u{1165}\u{30fc}"), ('\u{117d}', "\u{1167}\u{1169}"),
+    ('\u{117e}', "\u{1167}\u{116e}"), ('\u{117f}', "\u{1169}\u{1165}"), ('\u{1180}', "\u{1169}\u{1165}\u{4e28}"),
+    ('\u{1181}', "\u{1169}\u{1167}\u{4e28}"), ('\u{1182}', "\u{1169}\u{1169}"), ('\u{1183}', "\u{1169}\u{116e}"),
+    ('\u{1184}', "\u{116d}\u{1163}"), ('\u{1185}', "\u{116d}\u{1163}\u{4e28}"), ('\u{1186}', "\u{116d}\u{1163}"),
+    ('\u{1187}', "\u{116d}\u{1169}"), ('\u{1188}', "\u{116d}\u{4e28}"), ('\u{1189}', "\u{116e}\u{1161}"),
+    ('\u{118a}', "\u{116e}\u{1161}\u{4e28}"), ('\u{118b}', "\u{116e}\u{1165}\u{30fc}"), ('\u{118c}', "\u{116e}\u{1167}\u{4e28}"),
+    ('\u{118d}', "\u{116e}\u{116e}"), ('\u{118e}', "\u{1172}\u{1161}"), ('\u{118f}', "\u{1172}\u{1165}"),
+    ('\u{1190}', "\u{1172}\u{1165}\u{4e28}"), ('\u{1191}', "\u{1172}\u{1167}"), ('\u{1192}', "\u{1172}\u{1167}\u{4e28}"),
+    ('\u{1193}', "\u{1172}\u{116e}"), ('\u{1194}', "\u{1172}\u{4e28}"), ('\u{1195}', "\u{30fc}\u{116e}"),
+    ('\u{1196}', "\u{30fc}\u{30fc}"), ('\u{1197}', "\u{30fc}\u{4e28}\u{116e}"), ('\u{1198}', "\u{4e28}\u{1161}"),
+    ('\u{1199}', "\u{4e28}\u{1163}"), ('\u{119a}', "\u{4e28}\u{1169}"), ('\u{119b}', "\u{4e28}\u{116e}"),
+    ('\u{119c}', "\u{4e28}\u{30fc}"), ('\u{119d}', "\u{4e28}\u{119e}"), ('\u{119f}', "\u{119e}\u{1165}"),
+    ('\u{11a0}', "\u{119e}\u{116e}"), ('\u{11a1}', "\u{119e}\u{4e28}"), ('\u{11a2}', "\u{119e}\u{119e}"),
+    ('\u{11a3}', "\u{1161}\u{30fc}"), ('\u{11a4}', "\u{1163}\u{116e}"), ('\u{11a5}', "\u{1167}\u{1163}"),
+    ('\u{11a6}', "\u{1169}\u{1163}"), ('\u{11a7}', "\u{1169}\u{1163}\u{4e28}"), ('\u{11a8}', "\u{1100}"),
+    ('\u{11a9}', "\u{1100}\u{1100}"), ('\u{11aa}', "\u{1100}\u{1109}"), ('\u{11ab}', "\u{1102}"),
+    ('\u{11ac}', "\u{1102}\u{110c}"), ('\u{11ad}', "\u{1102}\u{1112}"), ('\u{11ae}', "\u{1103}"),
+    ('\u{11af}', "\u{1105}"), ('\u{11b0}', "\u{1105}\u{1100}"), ('\u{11b1}', "\u{1105}\u{1106}"),
+    ('\u{11b2}', "\u{1105}\u{1107}"), ('\u{11b3}', "\u{1105}\u{1109}"), ('\u{11b4}', "\u{1105}\u{1110}"),
+    ('\u{11b5}', "\u{1105}\u{1111}"), ('\u{11b6}', "\u{1105}\u{1112}"), ('\u{11b7}', "\u{1106}"),
+    ('\u{11b8}', "\u{1107}"), ('\u{11b9}', "\u{1107}\u{1109}"), ('\u{11ba}', "\u{1109}"),
+    ('\u{11bb}', "\u{1109}\u{1109}"), ('\u{11bc}', "\u{110b}"), ('\u{11bd}', "\u{110c}"),
+    ('\u{11be}', "\u{110e}"), ('\u{11bf}', "\u{110f}"), ('\u{11c0}', "\u{1110}"),
+    ('\u{11c1}', "\u{1111}"), ('\u{11c2}', "\u{1112}"), ('\u{11c3}', "\u{1100}\u{1105}"),
+    ('\u{11c4}', "\u{1100}\u{1109}\u{1100}"), ('\u{11c5}', "\u{1102}\u{1100}"), ('\u{11c6}', "\u{1102}\u{1103}"),
+    ('\u{11c7}', "\u{1102}\u{1109}"), ('\u{11c8}', "\u{1102}\u{1140}"), ('\u{11c9}', "\u{1102}\u{1110}"),
+    ('\u{11ca}', "\u{1103}\u{1100}"), ('\u{11cb}', "\u{1103}\u{1105}"), ('\u{11cc}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{11cd}', "\u{1105}\u{1102}"), ('\u{11ce}', "\u{1105}\u{1103}"), ('\u{11cf}', "\u{1105}\u{1103}\u{1112}"),
+    ('\u{11d0}', "\u{1105}\u{1105}"), ('\u{11d1}', "\u{1105}\u{1106}\u{1100}"), ('\u{11d2}', "\u{1105}\u{1106}\u{1109}"),
+    ('\u{11d3}', "\u{1105}\u{1107}\u{1109}"), ('\u{11d4}', "\u{1105}\u{1107}\u{1112}"), ('\u{11d5}', "\u{1105}\u{1107}\u{110b}"),
+    ('\u{11d6}', "\u{1105}\u{1109}\u{1109}"), ('\u{11d7}', "\u{1105}\u{1140}"), ('\u{11d8}', "\u{1105}\u{110f}"),
+    ('\u{11d9}', "\u{1105}\u{1159}"), ('\u{11da}', "\u{1106}\u{1100}"), ('\u{11db}', "\u{1106}\u{1105}"),
+    ('\u{11dc}', "\u{1106}\u{1107}"), ('\u{11dd}', "\u{1106}\u{1109}"), ('\u{11de}', "\u{1106}\u{1109}\u{1109}"),
+    ('\u{11df}', "\u{1106}\u{1140}"), ('\u{11e0}', "\u{1106}\u{110e}"), ('\u{11e1}', "\u{1106}\u{1112}"),
+    ('\u{11e2}', "\u{1106}\u{110b}"), ('\u{11e3}', "\u{1107}\u{1105}"), ('\u{11e4}', "\u{1107}\u{1111}"),
+    ('\u{11e5}', "\u{1107}\u{1112}"), ('\u{11e6}', "\u{1107}\u{110b}"), ('\u{11e7}', "\u{1109}\u{1100}"),
+    ('\u{11e8}', "\u{1109}\u{1103}"), ('\u{11e9}', "\u{1109}\u{1105}"), ('\u{11ea}', "\u{1109}\u{1107}"),
+    ('\u{11eb}', "\u{1140}"), ('\u{11ec}', "\u{110b}\u{1100}"), ('\u{11ed}', "\u{110b}\u{1100}\u{1100}"),
+    ('\u{11ee}', "\u{110b}\u{110b}"), ('\u{11ef}', "\u{110b}\u{110f}"), ('\u{11f0}', "\u{114c}"),
+    ('\u{11f1}', "\u{110b}\u{1109}"), ('\u{11f2}', "\u{110b}\u{1140}"), ('\u{11f3}', "\u{1111}\u{1107}"),
+    ('\u{11f4}', "\u{1111}\u{110b}"), ('\u{11f5}', "\u{1112}\u{1102}"), ('\u{11f6}', "\u{1112}\u{1105}"),
+    ('\u{11f7}', "\u{1112}\u{1106}"), ('\u{11f8}', "\u{1112}\u{1107}"), ('\u{11f9}', "\u{1159}"),
+    ('\u{11fa}', "\u{1100}\u{1102}"), ('\u{11fb}', "\u{1100}\u{1107}"), ('\u{11fc}', "\u{1100}\u{110e}"),
+    ('\u{11fd}', "\u{1100}\u{110f}"), ('\u{11fe}', "\u{1100}\u{1112}"), ('\u{11ff}', "\u{1102}\u{1102}"),
+    ('\u{1200}', "U"), ('\u{1223}', "\u{270}"), ('\u{1240}', "\u{3a6}"),
+    ('\u{1260}', "\u{548}"), ('\u{1294}', "\u{571}"), ('\u{12d0}', "O"),
+    ('\u{13a0}', "D"), ('\u{13a1}', "R"), ('\u{13a2}', "T"),
+    ('\u{13a4}', "O\u{27}"), ('\u{13a5}', "i"), ('\u{13a8}', "\u{2c75}"),
+    ('\u{13a9}', "Y"), ('\u{13aa}', "A"), ('\u{13ab}', "J"),
+    ('\u{13ac}', "E"), ('\u{13ae}', "\u{3f}"), ('\u{13b0}', "\u{2c75}"),
+    ('\u{13b1}', "\u{393}"), ('\u{13b3}', "W"), ('\u{13b7}', "M"),
+    ('\u{13bb}', "H"), ('\u{13bd}', "Y"), ('\u{13be}', "O\u{335}"),
+    ('\u{13bf}', "\u{1ab}"), ('\u{13c0}', "G"), ('\u{13c2}', "h"),
+    ('\u{13c3}', "Z"), ('\u{13c7}', "\u{460}"), ('\u{13cb}', "\u{190}"),
+    ('\u{13cc}', "U\u{335}"), ('\u{13ce}', "4"), ('\u{13cf}', "b"),
+    ('\u{13d2}', "R"), ('\u{13d4}', "W"), ('\u{13d5}', "S"),
+    ('\u{13d9}', "V"), ('\u{13da}', "S"), ('\u{13de}', "L"),
+    ('\u{13df}', "C"), ('\u{13e2}', "P"), ('\u{13e6}', "K"),
+    ('\u{13e7}', "d"), ('\u{13eb}', "O\u{335}"), ('\u{13ee}', "6"),
+    ('\u{13f0}', "\u{df}"), ('\u{13f2}', "h\u{314}"), ('\u{13f3}', "G"),
+    ('\u{13f4}', "B"), ('\u{13fb}', "\u{262}"), ('\u{13fc}', "\u{299}"),
+    ('\u{1403}', "\u{394}"), ('\u{140c}', "\u{b7}\u{1401}"), ('\u{140d}', "\u{1401}\u{b7}"),
+    ('\u{140e}', "\u{b7}\u{394}"), ('\u{140f}', "\u{394}\u{b7}"), ('\u{1410}', "\u{b7}\u{1404}"),
+    ('\u{1411}', "\u{1404}\u{b7}"), ('\u{1412}', "\u{b7}\u{1405}"), ('\u{1413}', "\u{1405}\u{b7}"),
+    ('\u{1414}', "\u{b7}\u{1406}"), ('\u{1415}', "\u{1406}\u{b7}"), ('\u{1417}', "\u{b7}\u{140a}"),
+    ('\u{1418}', "\u{140a}\u{b7}"), ('\u{1419}', "\u{b7}\u{140b}"), ('\u{141a}', "\u{140b}\u{b7}"),
+    ('\u{1427}', "\u{b7}"), ('\u{142b}', "\u{1401}\u{1420}"), ('\u{142c}', "\u{394}\u{1420}"),
+    ('\u{142d}', "\u{1405}\u{1420}"), ('\u{142e}', "\u{140a}\u{1420}"), ('\u{142f}', "V"),
+    ('\u{1431}', "\u{245}"), ('\u{1433}', "\u{3e}"), ('\u{1437}', "\u{b7}\u{3e}"),
+    ('\u{1438}', "\u{3c}"), ('\u{143a}', "\u{b7}V"), ('\u{143b}', "V\u{b7}"),
+    ('\u{143c}', "\u{b7}\u{245}"), ('\u{143d}', "\u{245}\u{b7}"), ('\u{143e}', "\u{b7}\u{1432}"),
+    ('\u{143f}', "\u{1432}\u{b7}"), ('\u{1440}', "\u{b7}\u{3e}"), ('\u{1441}', "\u{3e}\u{b7}"),
+    ('\u{1442}', "\u{b7}\u{1434}"), ('\u{1443}', "\u{1434}\u{b7}"), ('\u{1444}', "\u{b7}\u{3c}"),
+    ('\u{1445}', "\u{3c}\u{b7}"), ('\u{1446}', "\u{b7}\u{1439}"), ('\u{1447}', "\u{1439}\u{b7}"),
+    ('\u{144a}', "\u{27}"), ('\u{144c}', "U"), ('\u{144e}', "\u{548}"),
+    ('\u{1454}', "\u{b7}\u{1450}"), ('\u{1457}', "\u{b7}U"), ('\u{1458}', "U\u{b7}"),
+    ('\u{1459}', "\u{b7}\u{548}"), ('\u{145a}', "\u{548}\u{b7}"), ('\u{145b}', "\u{b7}\u{144f}"),
+    ('\u{145c}', "\u{144f}\u{b7}"), ('\u{145d}', "\u{b7}\u{1450}"), ('\u{145e}', "\u{1450}\u{b7}"),
+    ('\u{145f}', "\u{b7}\u{1451}"), ('\u{1460}', "\u{1451}\u{b7}"), ('\u{1461}', "\u{b7}\u{1455}"),
+    ('\u{1462}', "\u{1455}\u{b7}"), ('\u{1463}', "\u{b7}\u{1456}"), ('\u{1464}', "\u{1456}\u{b7}"),
+    ('\u{1467}', "U\u{27}"), ('\u{1468}', "\u{548}\u{27}"), ('\u{1469}', "\u{1450}\u{27}"),
+    ('\u{146a}', "\u{1455}\u{27}"), ('\u{146d}', "P"), ('\u{146f}', "d"),
+    ('\u{1472}', "b"), ('\u{1473}', "b\u{307}"), ('\u{1474}', "\u{b7}\u{146b}"),
+    ('\u{1475}', "\u{146b}\u{b7}"), ('\u{1476}', "\u{b7}P"), ('\u{1477}', "p\u{b7}"),
+    ('\u{1478}', "\u{b7}\u{146e}"), ('\u{1479}', "\u{146e}\u{b7}"), ('\u{147a}', "\u{b7}d"),
+    ('\u{147b}', "d\u{b7}"), ('\u{147c}', "\u{b7}\u{1470}"), ('\u{147d}', "\u{1470}\u{b7}"),
+    ('\u{147e}', "\u{b7}b"), ('\u{147f}', "b\u{b7}"), ('\u{1480}', "\u{b7}b\u{307}"),
+    ('\u{1481}', "b\u{307}\u{b7}"), ('\u{1485}', "\u{146b}\u{27}"), ('\u{1486}', "P\u{27}"),
+    ('\u{1487}', "d\u{27}"), ('\u{1488}', "b\u{27}"), ('\u{148d}', "J"),
+    ('\u{1492}', "\u{b7}\u{1489}"), ('\u{1493}', "\u{1489}\u{b7}"), ('\u{1494}', "\u{b7}\u{148b}"),
+    ('\u{1495}', "\u{148b}\u{b7}"), ('\u{1496}', "\u{b7}\u{148c}"), ('\u{1497}', "\u{148c}\u{b7}"),
+    ('\u{1498}', "\u{b7}J"), ('\u{1499}', "J\u{b7}"), ('\u{149a}', "\u{b7}\u{148e}"),
+    ('\u{149b}', "\u{148e}\u{b7}"), ('\u{149c}', "\u{b7}\u{1490}"), ('\u{149d}', "\u{1490}\u{b7}"),
+    ('\u{149e}', "\u{b7}\u{1491}"), ('\u{149f}', "\u{1491}\u{b7}"), ('\u{14a5}', "\u{393}"),
+    ('\u{14aa}', "L"), ('\u{14ac}', "\u{b7}\u{14a3}"), ('\u{14ad}', "\u{14a3}\u{b7}"),
+    ('\u{14ae}', "\u{b7}\u{393}"), ('\u{14af}', "\u{393}\u{b7}"), ('\u{14b0}', "\u{b7}\u{14a6}"),
+    ('\u{14b1}', "\u{14a6}\u{b7}"), ('\u{14b2}', "\u{b7}\u{14a7}"), ('\u{14b3}', "\u{14a7}\u{b7}"),
+    ('\u{14b4}', "\u{b7}\u{14a8}"), ('\u{14b5}', "\u{14a8}\u{b7}"), ('\u{14b6}', "\u{b7}L"),
+    ('\u{14b7}', "l\u{b7}"), ('\u{14b8}', "\u{b7}\u{14ab}"), ('\u{14b9}', "\u{14ab}\u{b7}"),
+    ('\u{14bf}', "2"), ('\u{14c9}', "\u{b7}\u{14c0}"), ('\u{14ca}', "\u{14c0}\u{b7}"),
+    ('\u{14cb}', "\u{b7}\u{14c7}"), ('\u{14cc}', "\u{14c7}\u{b7}"), ('\u{14cd}', "\u{b7}\u{14c8}"),
+    ('\u{14ce}', "\u{14c8}\u{b7}"), ('\u{14d1}', "\u{1421}"), ('\u{14dc}', "\u{b7}\u{14d3}"),
+    ('\u{14dd}', "\u{14d3}\u{b7}"), ('\u{14de}', "\u{b7}\u{14d5}"), ('\u{14df}', "\u{14d5}\u{b7}"),
+    ('\u{14e0}', "\u{b7}\u{14d6}"), ('\u{14e1}', "\u{14d6}\u{b7}"), ('\u{14e2}', "\u{b7}\u{14d7}"),
+    ('\u{14e3}', "\u{14d7}\u{b7}"), ('\u{14e4}', "\u{b7}\u{14d8}"), ('\u{14e5}', "\u{14d8}\u{b7}"),
+    ('\u{14e6}', "\u{b7}\u{14da}"), ('\u{14e7}', "\u{14da}\u{b7}"), ('\u{14e8}', "\u{b7}\u{14db}"),
+    ('\u{14e9}', "\u{14db}\u{b7}"), ('\u{14f6}', "\u{b7}\u{14ed}"), ('\u{14f7}', "\u{14ed}\u{b7}"),
+    ('\u{14f8}', "\u{b7}\u{14ef}"), ('\u{14f9}', "\u{14ef}\u{b7}"), ('\u{14fa}', "\u{b7}\u{14f0}"),
+    ('\u{14fb}', "\u{14f0}\u{b7}"), ('\u{14fc}', "\u{b7}\u{14f1}"), ('\u{14fd}', "\u{14f1}\u{b7}"),
+    ('\u{14fe}', "\u{b7}\u{14f2}"), ('\u{14ff}', "\u{14f2}\u{b7}"), ('\u{1500}', "\u{b7}\u{14f4}"),
+    ('\u{1501}', "\u{14f4}\u{b7}"), ('\u{1502}', "\u{b7}\u{14f5}"), ('\u{1503}', "\u{14f5}\u{b7}"),
+    ('\u{150c}', "\u{150b}\u{3c}"), ('\u{150d}', "\u{150b}\u{1455}"), ('\u{150e}', "\u{150b}b"),
+    ('\u{150f}', "\u{150b}\u{1490}"), ('\u{1517}', "\u{b7}\u{1510}"), ('\u{1518}', "\u{1510}\u{b7}"),
+    ('\u{1519}', "\u{b7}\u{1511}"), ('\u{151a}', "\u{1511}\u{b7}"), ('\u{151b}', "\u{b7}\u{1512}"),
+    ('\u{151c}', "\u{1512}\u{b7}"), ('\u{151d}', "\u{b7}\u{1513}"), ('\u{151e}', "\u{1513}\u{b7}"),
+    ('\u{151f}', "\u{b7}\u{1514}"), ('\u{1520}', "\u{1514}\u{b7}"), ('\u{1521}', "\u{b7}\u{1515}"),
+    ('\u{1522}', "\u{1515}\u{b7}"), ('\u{1523}', "\u{b7}\u{1516}"), ('\u{1524}', "\u{1516}\u{b7}"),
+    ('\u{152f}', "\u{b7}4"), ('\u{1530}', "4\u{b7}"), ('\u{1531}', "\u{b7}\u{1528}"),
+    ('\u{1532}', "\u{1528}\u{b7}"), ('\u{1533}', "\u{b7}\u{1529}"), ('\u{1534}', "\u{1529}\u{b7}"),
+    ('\u{1535}', "\u{b7}\u{152a}"), ('\u{1536}', "\u{152a}\u{b7}"), ('\u{1537}', "\u{b7}\u{152b}"),
+    ('\u{1538}', "\u{152b}\u{b7}"), ('\u{1539}', "\u{b7}\u{152d}"), ('\u{153a}', "\u{152d}\u{b7}"),
+    ('\u{153b}', "\u{b7}\u{152e}"), ('\u{153c}', "\u{152e}\u{b7}"), ('\u{1540}', "\u{1429}"),
+    ('\u{1541}', "x"), ('\u{154e}', "\u{b7}\u{154c}"), ('\u{154f}', "\u{154c}\u{b7}"),
+    ('\u{155b}', "\u{b7}\u{155a}"), ('\u{155c}', "\u{155a}\u{b7}"), ('\u{1568}', "\u{b7}\u{1567}"),
+    ('\u{1569}', "\u{1567}\u{b7}"), ('\u{1577}', "\u{1e9f}"), ('\u{157c}', "H"),
+    ('\u{157d}', "x"), ('\u{157e}', "\u{1550}\u{146c}"), ('\u{157f}', "\u{1550}P"),
+    ('\u{1580}', "\u{1550}\u{146e}"), ('\u{1581}', "\u{1550}d"), ('\u{1582}', "\u{1550}\u{1470}"),
+    ('\u{1583}', "\u{1550}b"), ('\u{1584}', "\u{1550}b\u{307}"), ('\u{1585}', "\u{1550}\u{1483}"),
+    ('\u{1587}', "R"), ('\u{158e}', "\u{1595}\u{148a}"), ('\u{158f}', "\u{1595}\u{148b}"),
+    ('\u{1590}', "\u{1595}\u{148c}"), ('\u{1591}', "\u{1595}J"), ('\u{1592}', "\u{1595}\u{148e}"),
+    ('\u{1593}', "\u{1595}\u{1490}"), ('\u{1594}', "\u{1595}\u{1491}"), ('\u{15af}', "b"),
+    ('\u{15b4}', "F"), ('\u{15b5}', "\u{2132}"), ('\u{15b7}', "\u{a7fb}"),
+    ('\u{15c4}', "\u{2c6f}"), ('\u{15c5}', "A"), ('\u{15de}', "D"),
+    ('\u{15ea}', "D"), ('\u{15ef}', "\u{460}"), ('\u{15f0}', "M"),
+    ('\u{15f7}', "B"), ('\u{1602}', "\u{1490}"), ('\u{1603}', "\u{1489}"),
+    ('\u{1604}', "\u{14d3}"), ('\u{1607}', "\u{14da}"), ('\u{1622}', "\u{1543}"),
+    ('\u{1623}', "\u{1546}"), ('\u{1624}', "\u{154a}"), ('\u{162e}', "\u{1b1}"),
+    ('\u{162f}', "\u{3a9}"), ('\u{1634}', "\u{1b1}"), ('\u{1635}', "\u{3a9}"),
+    ('\u{166f}', "\u{1550}\u{146b}"), ('\u{1670}', "\u{1595}\u{1489}"), ('\u{1671}', "\u{1596}\u{148b}"),
+    ('\u{1672}', "\u{1596}\u{148c}"), ('\u{1673}', "\u{1596}J"), ('\u{1674}', "\u{1596}\u{148e}"),
+    ('\u{1675}', "\u{1596}\u{1490}"), ('\u{1676}', "\u{1596}\u{1491}"), ('\u{1677}', "\u{15a7}\u{b7}"),
+    ('\u{1678}', "\u{15a8}\u{b7}"), ('\u{1679}', "\u{15a9}\u{b7}"), ('\u{167a}', "\u{15aa}\u{b7}"),
+    ('\u{167b}', "\u{15ab}\u{b7}"), ('\u{167c}', "\u{15ac}\u{b7}"), ('\u{167d}', "\u{15ad}\u{b7}"),
+    ('\u{16b2}', "\u{3c}"), ('\u{16b7}', "X"), ('\u{16c1}', "l"),
+    ('\u{16c2}', "\u{16bd}"), ('\u{16cc}', "\u{27}"), ('\u{16d5}', "K"),
+    ('\u{16d6}', "M"), ('\u{16d8}', "\u{3a8}"), ('\u{16e1}', "\u{16bc}"),
+    ('\u{16f0}', "\u{3a6}"), ('\u{1734}', "\u{1715}"), ('\u{17a3}', "\u{17a2}"),
+    ('\u{17b7}', "\u{e34}"), ('\u{17b8}', "\u{e35}"), ('\u{17b9}', "\u{e36}"),
+    ('\u{17ba}', "\u{e37}"), ('\u{17c6}', "\u{30a}"), ('\u{17cb}', "\u{e48}"),
+    ('\u{17d3}', "\u{30a}"), ('\u{1855}', "\u{1835}"), ('\u{1896}', "\u{185c}"),
+    ('\u{18b3}', "\u{b7}\u{18b1}"), ('\u{18b6}', "\u{b7}\u{18b4}"), ('\u{18b9}', "\u{b7}\u{18b8}"),
+    ('\u{18c2}', "\u{b7}\u{18c0}"), ('\u{18c6}', "\u{b7}\u{14c2}"), ('\u{18c7}', "\u{14c2}\u{b7}"),
+    ('\u{18c8}', "\u{b7}\u{14c3}"), ('\u{18c9}', "\u{14c3}\u{b7}"), ('\u{18ca}', "\u{b7}\u{14c4}"),
+    ('\u{18cb}', "\u{14c4}\u{b7}"), ('\u{18cc}', "\u{b7}\u{14c5}"), ('\u{18cd}', "\u{14c5}\u{b7}"),
+    ('\u{18ce}', "\u{b7}\u{1543}"), ('\u{18cf}', "\u{b7}\u{1546}"), ('\u{18d0}', "\u{b7}\u{1547}"),
+    ('\u{18d1}', "\u{b7}\u{1548}"), ('\u{18d2}', "\u{b7}\u{1549}"), ('\u{18d3}', "\u{b7}\u{154b}"),
+    ('\u{18db}', "\u{18f5}"), ('\u{18dc}', "\u{18df}\u{141e}"), ('\u{18dd}', "\u{141e}\u{18df}"),
+    ('\u{18e0}', "\u{1543}\u{b7}"), ('\u{18e3}', "\u{155e}\u{b7}"), ('\u{18e4}', "\u{1566}\u{b7}"),
+    ('\u{18e5}', "\u{156b}\u{b7}"), ('\u{18e8}', "\u{1586}\u{b7}"), ('\u{18ea}', "\u{1597}\u{b7}"),
+    ('\u{18ed}', "\u{460}\u{b7}"), ('\u{18f0}', "\u{15f4}\u{b7}"), ('\u{18f2}', "\u{161b}\u{b7}"),
+    ('\u{19d0}', "\u{199e}"), ('\u{19d1}', "\u{19b1}"), ('\u{1a80}', "\u{1a45}"),
+    ('\u{1a90}', "\u{1a45}"), ('\u{1ab4}', "\u{6db}"), ('\u{1ab7}', "\u{328}"),
+    ('\u{1b52}', "\u{1b0d}"), ('\u{1b53}', "\u{1b11}"), ('\u{1b58}', "\u{1b28}"),
+    ('\u{1cd0}', "\u{302}"), ('\u{1cd2}', "\u{304}"), ('\u{1cd5}', "\u{32b}"),
+    ('\u{1cd8}', "\u{32e}"), ('\u{1cd9}', "\u{32d}"), ('\u{1cda}', "\u{30e}"),
+    ('\u{1cdc}', "\u{329}"), ('\u{1cdd}', "\u{323}"), ('\u{1cde}', "\u{324}"),
+    ('\u{1ced}', "\u{316}"), ('\u{1d04}', "c"), ('\u{1d08}', "\u{25c}"),
+    ('\u{1d0b}', "\u{138}"), ('\u{1d0d}', "\u{28d}"), ('\u{1d0f}', "o"),
+    ('\u{1d10}', "\u{254}"), ('\u{1d11}', "o"), ('\u{1d14}', "\u{1dd}o"),
+    ('\u{1d1c}', "u"), ('\u{1d20}', "v"), ('\u{1d21}', "w"),
+    ('\u{1d22}', "z"), ('\u{1d24}', "\u{1a8}"), ('\u{1d26}', "r"),
+    ('\u{1d27}', "\u{28c}"), ('\u{1d28}', "\u{3c0}"), ('\u{1d29}', "\u{1d18}"),
+    ('\u{1d2b}', "\u{43b}"), ('\u{1d3e}', "\u{18d6}"), ('\u{1d52}', "\u{ba}"),
+    ('\u{1d6b}', "ue"), ('\u{1d6e}', "f\u{334}"), ('\u{1d6f}', "rn\u{334}"),
+    ('\u{1d70}', "n\u{334}"), ('\u{1d72}', "r\u{334}"), ('\u{1d73}', "\u{27e}\u{334}"),
+    ('\u{1d74}', "s\u{334}"), ('\u{1d75}', "t\u{334}"), ('\u{1d76}', "z\u{334}"),
+    ('\u{1d78}', "\u{1d34}"), ('\u{1d7b}', "i\u{335}"), ('\u{1d7c}', "i\u{335}"),
+    ('\u{1d7d}', "p\u{335}"), ('\u{1d7e}', "u\u{335}"), ('\u{1d7f}', "\u{28a}\u{335}"),
+    ('\u{1d83}', "g"), ('\u{1d8c}', "y"), ('\u{1d90}', "\u{24b}"),
+    ('\u{1d9f}', "\u{1d4b}"), ('\u{1da2}', "\u{1d4d}"), ('\u{1dba}', "\u{18d4}"),
+    ('\u{1dbb}', "\u{1646}"), ('\u{1dee}', "\u{2dec}"), ('\u{1e9a}', "a\u{309}"),
+    ('\u{1e9d}', "f"), ('\u{1e9e}', "\u{df}"), ('\u{1eff}', "y"),
+    ('\u{20db}', "\u{6db}"), ('\u{2102}', "C"), ('\u{2107}', "\u{190}"),
+    ('\u{210a}', "g"), ('\u{210b}', "H"), ('\u{210c}', "H"),
+    ('\u{210d}', "H"), ('\u{210e}', "h"), ('\u{210f}', "h\u{335}"),
+    ('\u{2110}', "l"), ('\u{2111}', "l"), ('\u{2112}', "L"),
+    ('\u{2113}', "l"), ('\u{2115}', "N"), ('\u{2119}', "P"),
+    ('\u{211a}', "Q"), ('\u{211b}', "R"), ('\u{211c}', "R"),
+    ('\u{211d}', "R"), ('\u{2124}', "Z"), ('\u{2128}', "Z"),
+    ('\u{212c}', "B"), ('\u{212d}', "C"), ('\u{212e}', "e"),
+    ('\u{212f}', "e"), ('\u{2130}', "E"), ('\u{2131}', "F"),
+    ('\u{2133}', "M"), ('\u{2134}', "o"), ('\u{2135}', "\u{5d0}"),
+    ('\u{2136}', "\u{5d1}"), ('\u{2137}', "\u{5d2}"), ('\u{2138}', "\u{5d3}"),
+    ('\u{2139}', "i"), ('\u{213c}', "\u{3c0}"), ('\u{213d}', "y"),
+    ('\u{213e}', "\u{393}"), ('\u{213f}', "\u{3a0}"), ('\u{2145}', "D"),
+    ('\u{2146}', "d"), ('\u{2147}', "e"), ('\u{2148}', "i"),
+    ('\u{2149}', "j"), ('\u{2160}', "l"), ('\u{2161}', "ll"),
+    ('\u{2162}', "lll"), ('\u{2163}', "lV"), ('\u{2164}', "V"),
+    ('\u{2165}', "Vl"), ('\u{2166}', "Vll"), ('\u{2167}', "Vlll"),
+    ('\u{2168}', "lX"), ('\u{2169}', "X"), ('\u{216a}', "Xl"),
+    ('\u{216b}', "Xll"), ('\u{216c}', "L"), ('\u{216d}', "C"),
+    ('\u{216e}', "D"), ('\u{216f}', "M"), ('\u{2170}', "i"),
+    ('\u{2171}', "ii"), ('\u{2172}', "iii"), ('\u{2173}', "iv"),
+    ('\u{2174}', "v"), ('\u{2175}', "vi"), ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"), ('\u{2178}', "ix"), ('\u{2179}', "x"),
+    ('\u{217a}', "xi"), ('\u{217b}', "xii"), ('\u{217c}', "l"),
+    ('\u{217d}', "c"), ('\u{217e}', "d"), ('\u{217f}', "rn"),
+    ('\u{2183}', "\u{186}"), ('\u{2184}', "\u{254}"), ('\u{2c67}', "H\u{329}"),
+    ('\u{2c69}', "K\u{329}"), ('\u{2c84}', "\u{393}"), ('\u{2c85}', "r"),
+    ('\u{2c86}', "\u{394}"), ('\u{2c88}', "\u{a792}"), ('\u{2c89}', "\u{a793}"),
+    ('\u{2c8e}', "H"), ('\u{2c92}', "l"), ('\u{2c94}', "K"),
+    ('\u{2c95}', "\u{138}"), ('\u{2c96}', "\u{3bb}"), ('\u{2c98}', "M"),
+    ('\u{2c9a}', "N"), ('\u{2c9e}', "O"), ('\u{2c9f}', "o"),
+    ('\u{2ca0}', "\u{3a0}"), ('\u{2ca2}', "P"), ('\u{2ca3}', "p"),
+    ('\u{2ca4}', "C"), ('\u{2ca5}', "c"), ('\u{2ca6}', "T"),
+    ('\u{2ca8}', "Y"), ('\u{2caa}', "\u{3a6}"), ('\u{2cab}', "\u{278}"),
+    ('\u{2cac}', "X"), ('\u{2cad}', "\u{3c7}"), ('\u{2cae}', "\u{3a8}"),
+    ('\u{2cb1}', "\u{3c9}"), ('\u{2cb4}', "\u{3c}\u{b7}"), ('\u{2cba}', "\u{2d}"),
+    ('\u{2cbc}', "\u{428}"), ('\u{2cbd}', "\u{448}"), ('\u{2cc6}', "\u{2f}"),
+    ('\u{2cca}', "9"), ('\u{2ccc}', "3"), ('\u{2ccd}', "\u{21d}"),
+    ('\u{2cd0}', "L"), ('\u{2cd1}', "\u{29f}"), ('\u{2cd2}', "6"),
+    ('\u{2cdc}', "\u{3ec}"), ('\u{2ce4}', "\u{3d7}"), ('\u{2d31}', "O\u{335}"),
+    ('\u{2d37}', "\u{245}"), ('\u{2d38}', "V"), ('\u{2d39}', "E"),
+    ('\u{2d3a}', "\u{18e}"), ('\u{2d41}', "O\u{338}"), ('\u{2d48}', "\u{b7}\u{b7}\u{b7}"),
+    ('\u{2d49}', "\u{1a9}"), ('\u{2d4f}', "l"), ('\u{2d51}', "\u{21}"),
+    ('\u{2d54}', "O"), ('\u{2d55}', "Q"), ('\u{2d59}', "\u{298}"),
+    ('\u{2d5d}', "X"), ('\u{2d60}', "\u{394}"), ('\u{2d63}', "\u{16ef}"),
+    ('\u{2de8}', "\u{1ddf}"), ('\u{2dea}', "\u{30a}"), ('\u{2ded}', "\u{368}"),
+    ('\u{2def}', "\u{36f}"), ('\u{2df6}', "\u{363}"), ('\u{2df7}', "\u{364}"),
+    ('\u{3007}', "O"), ('\u{302c}', "\u{309}"), ('\u{302d}', "\u{325}"),
+    ('\u{3033}', "\u{2f}"), ('\u{3038}', "\u{5341}"), ('\u{3039}', "\u{5344}"),
+    ('\u{303a}', "\u{5345}"), ('\u{304f}', "\u{276c}"), ('\u{309a}', "\u{30a}"),
+    ('\u{30a4}', "\u{4ebb}"), ('\u{30a8}', "\u{5de5}"), ('\u{30ab}', "\u{529b}"),
+    ('\u{30bf}', "\u{5915}"), ('\u{30c8}', "\u{535c}"), ('\u{30cb}', "\u{4e8c}"),
+    ('\u{30ce}', "\u{2f}"), ('\u{30cf}', "\u{516b}"), ('\u{30d8}', "\u{3078}"),
+    ('\u{30ed}', "\u{53e3}"), ('\u{30fb}', "\u{b7}"), ('\u{3126}', "\u{513f}"),
+    ('\u{3131}', "\u{1100}"), ('\u{3132}', "\u{1100}\u{1100}"), ('\u{3133}', "\u{1100}\u{1109}"),
+    ('\u{3134}', "\u{1102}"), ('\u{3135}', "\u{1102}\u{110c}"), ('\u{3136}', "\u{1102}\u{1112}"),
+    ('\u{3137}', "\u{1103}"), ('\u{3138}', "\u{1103}\u{1103}"), ('\u{3139}', "\u{1105}"),
+    ('\u{313a}', "\u{1105}\u{1100}"), ('\u{313b}', "\u{1105}\u{1106}"), ('\u{313c}', "\u{1105}\u{1107}"),
+    ('\u{313d}', "\u{1105}\u{1109}"), ('\u{313e}', "\u{1105}\u{1110}"), ('\u{313f}', "\u{1105}\u{1111}"),
+    ('\u{3140}', "\u{1105}\u{1112}"), ('\u{3141}', "\u{1106}"), ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1107}\u{1107}"), ('\u{3144}', "\u{1107}\u{1109}"), ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{1109}\u{1109}"), ('\u{3147}', "\u{110b}"), ('\u{3148}', "\u{110c}"),
+    ('\u{3149}', "\u{110c}\u{110c}"), ('\u{314a}', "\u{110e}"), ('\u{314b}', "\u{110f}"),
+    ('\u{314c}', "\u{1110}"), ('\u{314d}', "\u{1111}"), ('\u{314e}', "\u{1112}"),
+    ('\u{314f}', "\u{1161}"), ('\u{3150}', "\u{1161}\u{4e28}"), ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1163}\u{4e28}"), ('\u{3153}', "\u{1165}"), ('\u{3154}', "\u{1165}\u{4e28}"),
+    ('\u{3155}', "\u{1167}"), ('\u{3156}', "\u{1167}\u{4e28}"), ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{1169}\u{1161}"), ('\u{3159}', "\u{1169}\u{1161}\u{4e28}"), ('\u{315a}', "\u{1169}\u{4e28}"),
+    ('\u{315b}', "\u{116d}"), ('\u{315c}', "\u{116e}"), ('\u{315d}', "\u{116e}\u{1165}"),
+    ('\u{315e}', "\u{116e}\u{1165}\u{4e28}"), ('\u{315f}', "\u{116e}\u{4e28}"), ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{30fc}"), ('\u{3162}', "\u{30fc}\u{4e28}"), ('\u{3163}', "\u{4e28}"),
+    ('\u{3164}', "\u{1160}"), ('\u{3165}', "\u{1102}\u{1102}"), ('\u{3166}', "\u{1102}\u{1103}"),
+    ('\u{3167}', "\u{1102}\u{1109}"), ('\u{3168}', "\u{1102}\u{1140}"), ('\u{3169}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{316a}', "\u{1105}\u{1103}"), ('\u{316b}', "\u{1105}\u{1107}\u{1109}"), ('\u{316c}', "\u{1105}\u{1140}"),
+    ('\u{316d}', "\u{1105}\u{1159}"), ('\u{316e}', "\u{1106}\u{1107}"), ('\u{316f}', "\u{1106}\u{1109}"),
+    ('\u{3170}', "\u{1106}\u{1140}"), ('\u{3171}', "\u{1106}\u{110b}"), ('\u{3172}', "\u{1107}\u{1100}"),
+    ('\u{3173}', "\u{1107}\u{1103}"), ('\u{3174}', "\u{1107}\u{1109}\u{1100}"), ('\u{3175}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{3176}', "\u{1107}\u{110c}"), ('\u{3177}', "\u{1107}\u{1110}"), ('\u{3178}', "\u{1107}\u{110b}"),
+    ('\u{3179}', "\u{1107}\u{1107}\u{110b}"), ('\u{317a}', "\u{1109}\u{1100}"), ('\u{317b}', "\u{1109}\u{1102}"),
+    ('\u{317c}', "\u{1109}\u{1103}"), ('\u{317d}', "\u{1109}\u{1107}"), ('\u{317e}', "\u{1109}\u{110c}"),
+    ('\u{317f}', "\u{1140}"), ('\u{3180}', "\u{110b}\u{110b}"), ('\u{3181}', "\u{114c}"),
+    ('\u{3182}', "\u{110b}\u{1109}"), ('\u{3183}', "\u{110b}\u{1140}"), ('\u{3184}', "\u{1111}\u{110b}"),
+    ('\u{3185}', "\u{1112}\u{1112}"), ('\u{3186}', "\u{1159}"), ('\u{3187}', "\u{116d}\u{1163}"),
+    ('\u{3188}', "\u{116d}\u{1163}\u{4e28}"), ('\u{3189}', "\u{116d}\u{4e28}"), ('\u{318a}', "\u{1172}\u{1167}"),
+    ('\u{318b}', "\u{1172}\u{1167}\u{4e28}"), ('\u{318c}', "\u{1172}\u{4e28}"), ('\u{318d}', "\u{119e}"),
+    ('\u{318e}', "\u{119e}\u{4e28}"), ('\u{39b3}', "\u{363d}"), ('\u{439b}', "\u{3588}"),
+    ('\u{4420}', "\u{3b3b}"), ('\u{4e00}', "\u{30fc}"), ('\u{4e36}', "\u{5c}"),
+    ('\u{4e3f}', "\u{2f}"), ('\u{5002}', "\u{4f75}"), ('\u{503c}', "\u{5024}"),
+    ('\u{555f}', "\u{5553}"), ('\u{56d7}', "\u{53e3}"), ('\u{586b}', "\u{5861}"),
+    ('\u{58eb}', "\u{571f}"), ('\u{58ff}', "\u{58ab}"), ('\u{5b00}', "\u{5aaf}"),
+    ('\u{5e32}', "\u{5e21}"), ('\u{5e50}', "\u{3b3a}"), ('\u{6238}', "\u{6236}"),
+    ('\u{6409}', "\u{3a41}"), ('\u{6663}', "\u{403f}"), ('\u{6669}', "\u{665a}"),
+    ('\u{66f6}', "\u{3ada}"), ('\u{6726}', "\u{4443}"), ('\u{67ff}', "\u{676e}"),
+    ('\u{69e9}', "\u{3ba3}"), ('\u{6a27}', "\u{699d}"), ('\u{6f59}', "\u{6e88}"),
+    ('\u{784f}', "\u{7814}"), ('\u{7d76}', "\u{7d55}"), ('\u{80a6}', "\u{670c}"),
+    ('\u{80ca}', "\u{6710}"), ('\u{80d0}', "\u{670f}"), ('\u{80f6}', "\u{3b35}"),
+    ('\u{8101}', "\u{6713}"), ('\u{8127}', "\u{6718}"), ('\u{8141}', "\u{80fc}"),
+    ('\u{81a7}', "\u{6723}"), ('\u{853f}', "\u{848d}"), ('\u{8641}', "\u{8637}"),
+    ('\u{8a1e}', "\u{46b6}"), ('\u{8a7d}', "\u{8a2e}"), ('\u{8b8f}', "\u{8b86}"),
+    ('\u{8c63}', "\u{8c5c}"), ('\u{8d86}', "\u{8d7f}"), ('\u{8dfa}', "\u{8de5}"),
+    ('\u{8e9b}', "\u{8e97}"), ('\u{8f27}', "\u{8eff}"), ('\u{90de}', "\u{90ce}"),
+    ('\u{93ae}', "\u{93ad}"), ('\u{96b8}', "\u{96b7}"), ('\u{9e43}', "\u{9e42}"),
+    ('\u{9ed2}', "\u{9ed1}"), ('\u{9fc3}', "\u{4039}"), ('\u{a4d0}', "B"),
+    ('\u{a4d1}', "P"), ('\u{a4d2}', "d"), ('\u{a4d3}', "D"),
+    ('\u{a4d4}', "T"), ('\u{a4d6}', "G"), ('\u{a4d7}', "K"),
+    ('\u{a4d9}', "J"), ('\u{a4da}', "C"), ('\u{a4db}', "\u{186}"),
+    ('\u{a4dc}', "Z"), ('\u{a4dd}', "F"), ('\u{a4de}', "\u{2132}"),
+    ('\u{a4df}', "M"), ('\u{a4e0}', "N"), ('\u{a4e1}', "L"),
+    ('\u{a4e2}', "S"), ('\u{a4e3}', "R"), ('\u{a4e5}', "\u{245}"),
+    ('\u{a4e6}', "V"), ('\u{a4e7}', "H"), ('\u{a4ea}', "W"),
+    ('\u{a4eb}', "X"), ('\u{a4ec}', "Y"), ('\u{a4ed}', "\u{1660}"),
+    ('\u{a4ee}', "A"), ('\u{a4ef}', "\u{2c6f}"), ('\u{a4f0}', "E"),
+    ('\u{a4f1}', "\u{18e}"), ('\u{a4f2}', "l"), ('\u{a4f3}', "O"),
+    ('\u{a4f4}', "U"), ('\u{a4f5}', "\u{548}"), ('\u{a4f7}', "\u{15e1}"),
+    ('\u{a4f8}', "\u{2e}"), ('\u{a4f9}', "\u{2c}"), ('\u{a4fa}', "\u{2e}\u{2e}"),
+    ('\u{a4fb}', "\u{2e}\u{2c}"), ('\u{a4fd}', "\u{3a}"), ('\u{a644}', "2"),
+    ('\u{a645}', "\u{1a8}"), ('\u{a647}', "i"), ('\u{a64d}', "\u{3c9}"),
+    ('\u{a650}', "\u{42a}l"), ('\u{a651}', "\u{2c9}bi"), ('\u{a668}', "\u{298}"),
+    ('\u{a66f}', "\u{20e9}"), ('\u{a67c}', "\u{306}"), ('\u{a695}', "h\u{314}"),
+    ('\u{a698}', "OO"), ('\u{a699}', "oo"), ('\u{a69a}', "\u{102a8}"),
+    ('\u{a6a1}', "\u{418}"), ('\u{a6b0}', "\u{16b9}"), ('\u{a6b1}', "\u{2c75}"),
+    ('\u{a6cd}', "\u{2a1}"), ('\u{a6ce}', "\u{245}"), ('\u{a6db}', "\u{3a0}"),
+    ('\u{a6df}', "V"), ('\u{a6eb}', "\u{3f}"), ('\u{a6ef}', "2"),
+    ('\u{a6f0}', "\u{302}"), ('\u{a6f1}', "\u{304}"), ('\u{a728}', "T3"),
+    ('\u{a729}', "t\u{21d}"), ('\u{a731}', "s"), ('\u{a732}', "AA"),
+    ('\u{a733}', "aa"), ('\u{a734}', "AO"), ('\u{a735}', "ao"),
+    ('\u{a736}', "AU"), ('\u{a737}', "au"), ('\u{a738}', "AV"),
+    ('\u{a739}', "av"), ('\u{a73a}', "AV"), ('\u{a73b}', "av"),
+    ('\u{a73c}', "AY"), ('\u{a73d}', "ay"), ('\u{a740}', "K\u{335}"),
+    ('\u{a74a}', "O\u{335}"), ('\u{a74b}', "o\u{335}"), ('\u{a74e}', "OO"),
+    ('\u{a74f}', "oo"), ('\u{a75a}', "2"), ('\u{a761}', "w\u{326}"),
+    ('\u{a76a}', "3"), ('\u{a76b}', "\u{21d}"), ('\u{a76e}', "9"),
+    ('\u{a777}', "tf"), ('\u{a778}', "\u{26}"), ('\u{a77a}', "\u{a779}"),
+    ('\u{a78c}', "\u{27}"), ('\u{a78f}', "\u{b7}"), ('\u{a795}', "\u{a727}"),
+    ('\u{a798}', "F"), ('\u{a799}', "f"), ('\u{a79a}', "\u{10412}"),
+    ('\u{a79b}', "\u{1043a}"), ('\u{a79d}', "\u{29a}"), ('\u{a79e}', "\u{a4e4}"),
+    ('\u{a79f}', "u"), ('\u{a7ab}', "3"), ('\u{a7b1}', "\u{a4d5}"),
+    ('\u{a7b2}', "J"), ('\u{a7b3}', "X"), ('\u{a7b4}', "B"),
+    ('\u{a7b5}', "\u{df}"), ('\u{a7b6}', "\u{a64c}"), ('\u{a7b7}', "\u{3c9}"),
+    ('\u{a7d6}', "\u{df}"), ('\u{a7da}', "\u{245}"), ('\u{a7db}', "\u{3bb}"),
+    ('\u{a7dc}', "\u{245}\u{338}"), ('\u{a7f7}', "\u{30fc}"), ('\u{a960}', "\u{1103}\u{1106}"),
+    ('\u{a961}', "\u{1103}\u{1107}"), ('\u{a962}', "\u{1103}\u{1109}"), ('\u{a963}', "\u{1103}\u{110c}"),
+    ('\u{a964}', "\u{1105}\u{1100}"), ('\u{a965}', "\u{1105}\u{1100}\u{1100}"), ('\u{a966}', "\u{1105}\u{1103}"),
+    ('\u{a967}', "\u{1105}\u{1103}\u{1103}"), ('\u{a968}', "\u{1105}\u{1106}"), ('\u{a969}', "\u{1105}\u{1107}"),
+    ('\u{a96a}', "\u{1105}\u{1107}\u{1107}"), ('\u{a96b}', "\u{1105}\u{1107}\u{110b}"), ('\u{a96c}', "\u{1105}\u{1109}"),
+    ('\u{a96d}', "\u{1105}\u{110c}"), ('\u{a96e}', "\u{1105}\u{110f}"), ('\u{a96f}', "\u{1106}\u{1100}"),
+    ('\u{a970}', "\u{1106}\u{1103}"), ('\u{a971}', "\u{1106}\u{1109}"), ('\u{a972}', "\u{1107}\u{1109}\u{1110}"),
+    ('\u{a973}', "\u{1107}\u{110f}"), ('\u{a974}', "\u{1107}\u{1112}"), ('\u{a975}', "\u{1109}\u{1109}\u{1107}"),
+    ('\u{a976}', "\u{110b}\u{1105}"), ('\u{a977}', "\u{110b}\u{1112}"), ('\u{a978}', "\u{110c}\u{110c}\u{1112}"),
+    ('\u{a979}', "\u{1110}\u{1110}"), ('\u{a97a}', "\u{1111}\u{1112}"), ('\u{a97b}', "\u{1112}\u{1109}"),
+    ('\u{a97c}', "\u{1159}\u{1159}"), ('\u{a992}', "\u{2c3f}"), ('\u{a9a3}', "\u{a99d}"),
+    ('\u{a9cf}', "\u{662}"), ('\u{aa53}', "\u{aa01}"), ('\u{aa56}', "\u{aa23}"),
+    ('\u{ab32}', "e"), ('\u{ab35}', "f"), ('\u{ab3d}', "o"),
+    ('\u{ab3e}', "o\u{338}"), ('\u{ab3f}', "\u{254}\u{338}"), ('\u{ab41}', "\u{1dd}o\u{338}"),
+    ('\u{ab42}', "\u{1dd}o\u{335}"), ('\u{ab47}', "r"), ('\u{ab48}', "r"),
+    ('\u{ab4d}', "\u{283}"), ('\u{ab4e}', "u"), ('\u{ab52}', "u"),
+    ('\u{ab53}', "\u{3c7}"), ('\u{ab55}', "\u{3c7}"), ('\u{ab5a}', "y"),
+    ('\u{ab60}', "\u{459}"), ('\u{ab62}', "\u{254}e"), ('\u{ab63}', "uo"),
+    ('\u{ab70}', "\u{1d05}"), ('\u{ab71}', "\u{280}"), ('\u{ab72}', "\u{1d1b}"),
+    ('\u{ab74}', "o\u{31b}"), ('\u{ab75}', "i"), ('\u{ab7a}', "\u{1d00}"),
+    ('\u{ab7b}', "\u{1d0a}"), ('\u{ab7c}', "\u{1d07}"), ('\u{ab7e}', "\u{242}"),
+    ('\u{ab80}', "\u{2c76}"), ('\u{ab81}', "r"), ('\u{ab83}', "w"),
+    ('\u{ab87}', "\u{28d}"), ('\u{ab8b}', "\u{29c}"), ('\u{ab8e}', "o\u{335}"),
+    ('\u{ab90}', "\u{262}"), ('\u{ab93}', "z"), ('\u{ab9b}', "\u{a793}"),
+    ('\u{ab9c}', "u\u{335}"), ('\u{ab9f}', "\u{185}"), ('\u{aba2}', "\u{280}"),
+    ('\u{aba9}', "v"), ('\u{abaa}', "s"), ('\u{abae}', "\u{29f}"),
+    ('\u{abaf}', "c"), ('\u{abb2}', "\u{1d18}"), ('\u{abb6}', "\u{138}"),
+    ('\u{abbb}', "o\u{335}"), ('\u{d7b0}', "\u{1169}\u{1167}"), ('\u{d7b1}', "\u{1169}\u{1169}\u{4e28}"),
+    ('\u{d7b2}', "\u{116d}\u{1161}"), ('\u{d7b3}', "\u{116d}\u{1161}\u{4e28}"), ('\u{d7b4}', "\u{116d}\u{1165}"),
+    ('\u{d7b5}', "\u{116e}\u{1167}"), ('\u{d7b6}', "\u{116e}\u{4e28}\u{4e28}"), ('\u{d7b7}', "\u{1172}\u{1161}\u{4e28}"),
+    ('\u{d7b8}', "\u{1172}\u{1169}"), ('\u{d7b9}', "\u{30fc}\u{1161}"), ('\u{d7ba}', "\u{30fc}\u{1165}"),
+    ('\u{d7bb}', "\u{30fc}\u{1165}\u{4e28}"), ('\u{d7bc}', "\u{30fc}\u{1169}"), ('\u{d7bd}', "\u{4e28}\u{1163}\u{1169}"),
+    ('\u{d7be}', "\u{4e28}\u{1163}\u{4e28}"), ('\u{d7bf}', "\u{4e28}\u{1167}"), ('\u{d7c0}', "\u{4e28}\u{1167}\u{4e28}"),
+    ('\u{d7c1}', "\u{4e28}\u{1169}\u{4e28}"), ('\u{d7c2}', "\u{4e28}\u{116d}"), ('\u{d7c3}', "\u{4e28}\u{1172}"),
+    ('\u{d7c4}', "\u{4e28}\u{4e28}"), ('\u{d7c5}', "\u{119e}\u{1161}"), ('\u{d7c6}', "\u{119e}\u{1165}\u{4e28}"),
+    ('\u{d7cb}', "\u{1102}\u{1105}"), ('\u{d7cc}', "\u{1102}\u{110e}"), ('\u{d7cd}', "\u{1103}\u{1103}"),
+    ('\u{d7ce}', "\u{1103}\u{1103}\u{1107}"), ('\u{d7cf}', "\u{1103}\u{1107}"), ('\u{d7d0}', "\u{1103}\u{1109}"),
+    ('\u{d7d1}', "\u{1103}\u{1109}\u{1100}"), ('\u{d7d2}', "\u{1103}\u{110c}"), ('\u{d7d3}', "\u{1103}\u{110e}"),
+    ('\u{d7d4}', "\u{1103}\u{1110}"), ('\u{d7d5}', "\u{1105}\u{1100}\u{1100}"), ('\u{d7d6}', "\u{1105}\u{1100}\u{1112}"),
+    ('\u{d7d7}', "\u{1105}\u{1105}\u{110f}"), ('\u{d7d8}', "\u{1105}\u{1106}\u{1112}"), ('\u{d7d9}', "\u{1105}\u{1107}\u{1103}"),
+    ('\u{d7da}', "\u{1105}\u{1107}\u{1111}"), ('\u{d7db}', "\u{1105}\u{114c}"), ('\u{d7dc}', "\u{1105}\u{1159}\u{1112}"),
+    ('\u{d7dd}', "\u{1105}\u{110b}"), ('\u{d7de}', "\u{1106}\u{1102}"), ('\u{d7df}', "\u{1106}\u{1102}\u{1102}"),
+    ('\u{d7e0}', "\u{1106}\u{1106}"), ('\u{d7e1}', "\u{1106}\u{1107}\u{1109}"), ('\u{d7e2}', "\u{1106}\u{110c}"),
+    ('\u{d7e3}', "\u{1107}\u{1103}"), ('\u{d7e4}', "\u{1107}\u{1105}\u{1111}"), ('\u{d7e5}', "\u{1107}\u{1106}"),
+    ('\u{d7e6}', "\u{1107}\u{1107}"), ('\u{d7e7}', "\u{1107}\u{1109}\u{1103}"), ('\u{d7e8}', "\u{1107}\u{110c}"),
+    ('\u{d7e9}', "\u{1107}\u{110e}"), ('\u{d7ea}', "\u{1109}\u{1106}"), ('\u{d7eb}', "\u{1109}\u{1107}\u{110b}"),
+    ('\u{d7ec}', "\u{1109}\u{1109}\u{1100}"), ('\u{d7ed}', "\u{1109}\u{1109}\u{1103}"), ('\u{d7ee}', "\u{1109}\u{1140}"),
+    ('\u{d7ef}', "\u{1109}\u{110c}"), ('\u{d7f0}', "\u{1109}\u{110e}"), ('\u{d7f1}', "\u{1109}\u{1110}"),
+    ('\u{d7f2}', "\u{1105}\u{1112}"), ('\u{d7f3}', "\u{1140}\u{1107}"), ('\u{d7f4}', "\u{1140}\u{1107}\u{110b}"),
+    ('\u{d7f5}', "\u{114c}\u{1106}"), ('\u{d7f6}', "\u{114c}\u{1112}"), ('\u{d7f7}', "\u{110c}\u{1107}"),
+    ('\u{d7f8}', "\u{110c}\u{1107}\u{1107}"), ('\u{d7f9}', "\u{110c}\u{110c}"), ('\u{d7fa}', "\u{1111}\u{1109}"),
+    ('\u{d7fb}', "\u{1111}\u{1110}"), ('\u{fb00}', "ff"), ('\u{fb01}', "fi"),
+    ('\u{fb02}', "fl"), ('\u{fb03}', "ffi"), ('\u{fb04}', "ffl"),
+    ('\u{fb06}', "st"), ('\u{fb13}', "\u{574}\u{576}"), ('\u{fb14}', "\u{574}\u{565}"),
+    ('\u{fb15}', "\u{574}\u{56b}"), ('\u{fb16}', "\u{57e}\u{576}"), ('\u{fb17}', "\u{574}\u{56d}"),
+    ('\u{fb20}', "\u{5e2}"), ('\u{fb21}', "\u{5d0}"), ('\u{fb22}', "\u{5d3}"),
+    ('\u{fb23}', "\u{5d4}"), ('\u{fb24}', "\u{5db}"), ('\u{fb25}', "\u{5dc}"),
+    ('\u{fb26}', "\u{5dd}"), ('\u{fb27}', "\u{5e8}"), ('\u{fb28}', "\u{5ea}"),
+    ('\u{fb4f}', "\u{5d0}\u{5dc}"), ('\u{fb50}', "\u{671}"), ('\u{fb51}', "\u{671}"),
+    ('\u{fb52}', "\u{67b}"), ('\u{fb53}', "\u{67b}"), ('\u{fb54}', "\u{67b}"),
+    ('\u{fb55}', "\u{67b}"), ('\u{fb56}', "\u{649}\u{6db}"), ('\u{fb57}', "\u{649}\u{6db}"),
+    ('\u{fb58}', "\u{649}\u{6db}"), ('\u{fb59}', "\u{649}\u{6db}"), ('\u{fb5a}', "\u{680}"),
+    ('\u{fb5b}', "\u{680}"), ('\u{fb5c}', "\u{680}"), ('\u{fb5d}', "\u{680}"),
+    ('\u{fb5e}', "\u{67a}"), ('\u{fb5f}', "\u{67a}"), ('\u{fb60}', "\u{67a}"),
+    ('\u{fb61}', "\u{67a}"), ('\u{fb62}', "\u{67f}"), ('\u{fb63}', "\u{67f}"),
+    ('\u{fb64}', "\u{67f}"), ('\u{fb65}', "\u{67f}"), ('\u{fb66}', "\u{649}\u{615}"),
+    ('\u{fb67}', "\u{649}\u{615}"), ('\u{fb68}', "\u{649}\u{615}"), ('\u{fb69}', "\u{649}\u{615}"),
+    ('\u{fb6a}', "\u{6a1}\u{6db}"), ('\u{fb6b}', "\u{6a1}\u{6db}"), ('\u{fb6c}', "\u{6a1}\u{6db}"),
+    ('\u{fb6d}', "\u{6a1}\u{6db}"), ('\u{fb6e}', "\u{6a6}"), ('\u{fb6f}', "\u{6a6}"),
+    ('\u{fb70}', "\u{6a6}"), ('\u{fb71}', "\u{6a6}"), ('\u{fb72}', "\u{684}"),
+    ('\u{fb73}', "\u{684}"), ('\u{fb74}', "\u{684}"), ('\u{fb75}', "\u{684}"),
+    ('\u{fb76}', "\u{683}"), ('\u{fb77}', "\u{683}"), ('\u{fb78}', "\u{683}"),
+    ('\u{fb79}', "\u{683}"), ('\u{fb7a}', "\u{686}"), ('\u{fb7b}', "\u{686}"),
+    ('\u{fb7c}', "\u{686}"), ('\u{fb7d}', "\u{686}"), ('\u{fb7e}', "\u{687}"),
+    ('\u{fb7f}', "\u{687}"), ('\u{fb80}', "\u{687}"), ('\u{fb81}', "\u{687}"),
+    ('\u{fb82}', "\u{68d}"), ('\u{fb83}', "\u{68d}"), ('\u{fb84}', "\u{68c}"),
+    ('\u{fb85}', "\u{68c}"), ('\u{fb86}', "\u{62f}\u{6db}"), ('\u{fb87}', "\u{62f}\u{6db}"),
+    ('\u{fb88}', "\u{62f}\u{615}"), ('\u{fb89}', "\u{62f}\u{615}"), ('\u{fb8a}', "\u{631}\u{6db}"),
+    ('\u{fb8b}', "\u{631}\u{6db}"), ('\u{fb8c}', "\u{631}\u{615}"), ('\u{fb8d}', "\u{631}\u{615}"),
+    ('\u{fb8e}', "\u{643}"), ('\u{fb8f}', "\u{643}"), ('\u{fb90}', "\u{643}"),
+    ('\u{fb91}', "\u{643}"), ('\u{fb92}', "\u{6af}"), ('\u{fb93}', "\u{6af}"),
+    ('\u{fb94}', "\u{6af}"), ('\u{fb95}', "\u{6af}"), ('\u{fb96}', "\u{6b3}"),
+    ('\u{fb97}', "\u{6b3}"), ('\u{fb98}', "\u{6b3}"), ('\u{fb99}', "\u{6b3}"),
+    ('\u{fb9a}', "\u{6b1}"), ('\u{fb9b}', "\u{6b1}"), ('\u{fb9c}', "\u{6b1}"),
+    ('\u{fb9d}', "\u{6b1}"), ('\u{fb9e}', "\u{649}"), ('\u{fb9f}', "\u{649}"),
+    ('\u{fba0}', "\u{649}\u{615}"), ('\u{fba1}', "\u{649}\u{615}"), ('\u{fba2}', "\u{649}\u{615}"),
+    ('\u{fba3}', "\u{649}\u{615}"), ('\u{fba4}', "\u{6d5}\u{654}"), ('\u{fba5}', "\u{6d5}\u{654}"),
+    ('\u{fba6}', "o"), ('\u{fba7}', "o"), ('\u{fba8}', "o"),
+    ('\u{fba9}', "o"), ('\u{fbaa}', "o"), ('\u{fbab}', "o"),
+    ('\u{fbac}', "o"), ('\u{fbad}', "o"), ('\u{fbae}', "\u{649}"),
+    ('\u{fbaf}', "\u{649}"), ('\u{fbb0}', "\u{6d2}\u{654}"), ('\u{fbb1}', "\u{6d2}\u{654}"),
+    ('\u{fbd3}', "\u{643}\u{6db}"), ('\u{fbd4}', "\u{643}\u{6db}"), ('\u{fbd5}', "\u{643}\u{6db}"),
+    ('\u{fbd6}', "\u{643}\u{6db}"), ('\u{fbd7}', "\u{648}\u{313}"), ('\u{fbd8}', "\u{648}\u{313}"),
+    ('\u{fbd9}', "\u{648}\u{306}"), ('\u{fbda}', "\u{648}\u{306}"), ('\u{fbdb}', "\u{648}\u{670}"),
+    ('\u{fbdc}', "\u{648}\u{670}"), ('\u{fbdd}', "\u{648}\u{313}\u{674}"), ('\u{fbde}', "\u{648}\u{6db}"),
+    ('\u{fbdf}', "\u{648}\u{6db}"), ('\u{fbe0}', "\u{6c5}"), ('\u{fbe1}', "\u{6c5}"),
+    ('\u{fbe2}', "\u{648}\u{302}"), ('\u{fbe3}', "\u{648}\u{302}"), ('\u{fbe4}', "\u{67b}"),
+    ('\u{fbe5}', "\u{67b}"), ('\u{fbe6}', "\u{67b}"), ('\u{fbe7}', "\u{67b}"),
+    ('\u{fbe8}', "\u{649}"), ('\u{fbe9}', "\u{649}"), ('\u{fbea}', "\u{649}\u{674}l"),
+    ('\u{fbeb}', "\u{649}\u{674}l"), ('\u{fbec}', "\u{649}\u{674}o"), ('\u{fbed}', "\u{649}\u{674}o"),
+    ('\u{fbee}', "\u{649}\u{674}\u{648}"), ('\u{fbef}', "\u{649}\u{674}\u{648}"), ('\u{fbf0}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{fbf1}', "\u{649}\u{674}\u{648}\u{313}"), ('\u{fbf2}', "\u{649}\u{674}\u{648}\u{306}"), ('\u{fbf3}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{fbf4}', "\u{649}\u{674}\u{648}\u{670}"), ('\u{fbf5}', "\u{649}\u{674}\u{648}\u{670}"), ('\u{fbf6}', "\u{649}\u{674}\u{67b}"),
+    ('\u{fbf7}', "\u{649}\u{674}\u{67b}"), ('\u{fbf8}', "\u{649}\u{674}\u{67b}"), ('\u{fbf9}', "\u{649}\u{674}\u{649}"),
+    ('\u{fbfa}', "\u{649}\u{674}\u{649}"), ('\u{fbfb}', "\u{649}\u{674}\u{649}"), ('\u{fbfc}', "\u{649}"),
+    ('\u{fbfd}', "\u{649}"), ('\u{fbfe}', "\u{649}"), ('\u{fbff}', "\u{649}"),
+    ('\u{fc00}', "\u{649}\u{674}\u{62c}"), ('\u{fc01}', "\u{649}\u{674}\u{62d}"), ('\u{fc02}', "\u{649}\u{674}\u{645}"),
+    ('\u{fc03}', "\u{649}\u{674}\u{649}"), ('\u{fc04}', "\u{649}\u{674}\u{649}"), ('\u{fc05}', "\u{628}\u{62c}"),
+    ('\u{fc06}', "\u{628}\u{62d}"), ('\u{fc07}', "\u{628}\u{62e}"), ('\u{fc08}', "\u{628}\u{645}"),
+    ('\u{fc09}', "\u{628}\u{649}"), ('\u{fc0a}', "\u{628}\u{649}"), ('\u{fc0b}', "\u{62a}\u{62c}"),
+    ('\u{fc0c}', "\u{62a}\u{62d}"), ('\u{fc0d}', "\u{62a}\u{62e}"), ('\u{fc0e}', "\u{62a}\u{645}"),
+    ('\u{fc0f}', "\u{62a}\u{649}"), ('\u{fc10}', "\u{62a}\u{649}"), ('\u{fc11}', "\u{649}\u{6db}\u{62c}"),
+    ('\u{fc12}', "\u{649}\u{6db}\u{645}"), ('\u{fc13}', "\u{649}\u{6db}\u{649}"), ('\u{fc14}', "\u{649}\u{6db}\u{649}"),
+    ('\u{fc15}', "\u{62c}\u{62d}"), ('\u{fc16}', "\u{62c}\u{645}"), ('\u{fc17}', "\u{62d}\u{62c}"),
+    ('\u{fc18}', "\u{62d}\u{645}"), ('\u{fc19}', "\u{62e}\u{62c}"), ('\u{fc1a}', "\u{62e}\u{62d}"),
+    ('\u{fc1b}', "\u{62e}\u{645}"), ('\u{fc1c}', "\u{633}\u{62c}"), ('\u{fc1d}', "\u{633}\u{62d}"),
+    ('\u{fc1e}', "\u{633}\u{62e}"), ('\u{fc1f}', "\u{633}\u{645}"), ('\u{fc20}', "\u{635}\u{62d}"),
+    ('\u{fc21}', "\u{635}\u{645}"), ('\u{fc22}', "\u{636}\u{62c}"), ('\u{fc23}', "\u{636}\u{62d}"),
+    ('\u{fc24}', "\u{636}\u{62e}"), ('\u{fc25}', "\u{636}\u{645}"), ('\u{fc26}', "\u{637}\u{62d}"),
+    ('\u{fc27}', "\u{637}\u{645}"), ('\u{fc28}', "\u{638}\u{645}"), ('\u{fc29}', "\u{639}\u{62c}"),
+    ('\u{fc2a}', "\u{639}\u{645}"), ('\u{fc2b}', "\u{63a}\u{62c}"), ('\u{fc2c}', "\u{63a}\u{645}"),
+    ('\u{fc2d}', "\u{641}\u{62c}"), ('\u{fc2e}', "\u{641}\u{62d}"), ('\u{fc2f}', "\u{641}\u{62e}"),
+    ('\u{fc30}', "\u{641}\u{645}"), ('\u{fc31}', "\u{641}\u{649}"), ('\u{fc32}', "\u{641}\u{649}"),
+    ('\u{fc33}', "\u{642}\u{62d}"), ('\u{fc34}', "\u{642}\u{645}"), ('\u{fc35}', "\u{642}\u{649}"),
+    ('\u{fc36}', "\u{642}\u{649}"), ('\u{fc37}', "\u{643}l"), ('\u{fc38}', "\u{643}\u{62c}"),
+    ('\u{fc39}', "\u{643}\u{62d}"), ('\u{fc3a}', "\u{643}\u{62e}"), ('\u{fc3b}', "\u{643}\u{644}"),
+    ('\u{fc3c}', "\u{643}\u{645}"), ('\u{fc3d}', "\u{643}\u{649}"), ('\u{fc3e}', "\u{643}\u{649}"),
+    ('\u{fc3f}', "\u{644}\u{62c}"), ('\u{fc40}', "\u{644}\u{62d}"), ('\u{fc41}', "\u{644}\u{62e}"),
+    ('\u{fc42}', "\u{644}\u{645}"), ('\u{fc43}', "\u{644}\u{649}"), ('\u{fc44}', "\u{644}\u{649}"),
+    ('\u{fc45}', "\u{645}\u{62c}"), ('\u{fc46}', "\u{645}\u{62d}"), ('\u{fc47}', "\u{645}\u{62e}"),
+    ('\u{fc48}', "\u{645}\u{645}"), ('\u{fc49}', "\u{645}\u{649}"), ('\u{fc4a}', "\u{645}\u{649}"),
+    ('\u{fc4b}', "\u{628}\u{62e}"), ('\u{fc4c}', "\u{646}\u{62d}"), ('\u{fc4d}', "\u{646}\u{62e}"),
+    ('\u{fc4e}', "\u{646}\u{645}"), ('\u{fc4f}', "\u{646}\u{649}"), ('\u{fc50}', "\u{646}\u{649}"),
+    ('\u{fc51}', "o\u{62c}"), ('\u{fc52}', "o\u{645}"), ('\u{fc53}', "o\u{649}"),
+    ('\u{fc54}', "o\u{649}"), ('\u{fc55}', "\u{649}\u{62c}"), ('\u{fc56}', "\u{649}\u{62d}"),
+    ('\u{fc57}', "\u{649}\u{62e}"), ('\u{fc58}', "\u{649}\u{645}"), ('\u{fc59}', "\u{649}\u{649}"),
+    ('\u{fc5a}', "\u{649}\u{649}"), ('\u{fc5b}', "\u{630}\u{670}"), ('\u{fc5c}', "\u{631}\u{670}"),
+    ('\u{fc5d}', "\u{649}\u{670}"), ('\u{fc64}', "\u{649}\u{674}\u{631}"), ('\u{fc65}', "\u{649}\u{674}\u{632}"),
+    ('\u{fc66}', "\u{649}\u{674}\u{645}"), ('\u{fc67}', "\u{649}\u{674}\u{646}"), ('\u{fc68}', "\u{649}\u{674}\u{649}"),
+    ('\u{fc69}', "\u{649}\u{674}\u{649}"), ('\u{fc6a}', "\u{628}\u{631}"), ('\u{fc6b}', "\u{628}\u{632}"),
+    ('\u{fc6c}', "\u{628}\u{645}"), ('\u{fc6d}', "\u{628}\u{646}"), ('\u{fc6e}', "\u{628}\u{649}"),
+    ('\u{fc6f}', "\u{628}\u{649}"), ('\u{fc70}', "\u{62a}\u{631}"), ('\u{fc71}', "\u{62a}\u{632}"),
+    ('\u{fc72}', "\u{62a}\u{645}"), ('\u{fc73}', "\u{62a}\u{646}"), ('\u{fc74}', "\u{62a}\u{649}"),
+    ('\u{fc75}', "\u{62a}\u{649}"), ('\u{fc76}', "\u{649}\u{6db}\u{631}"), ('\u{fc77}', "\u{649}\u{6db}\u{632}"),
+    ('\u{fc78}', "\u{649}\u{6db}\u{645}"), ('\u{fc79}', "\u{649}\u{6db}\u{646}"), ('\u{fc7a}', "\u{649}\u{6db}\u{649}"),
+    ('\u{fc7b}', "\u{649}\u{6db}\u{649}"), ('\u{fc7c}', "\u{641}\u{649}"), ('\u{fc7d}', "\u{641}\u{649}"),
+    ('\u{fc7e}', "\u{642}\u{649}"), ('\u{fc7f}', "\u{642}\u{649}"), ('\u{fc80}', "\u{643}l"),
+    ('\u{fc81}', "\u{643}\u{644}"), ('\u{fc82}', "\u{643}\u{645}"), ('\u{fc83}', "\u{643}\u{649}"),
+    ('\u{fc84}', "\u{643}\u{649}"), ('\u{fc85}', "\u{644}\u{645}"), ('\u{fc86}', "\u{644}\u{649}"),
+    ('\u{fc87}', "\u{644}\u{649}"), ('\u{fc88}', "\u{645}l"), ('\u{fc89}', "\u{645}\u{645}"),
+    ('\u{fc8a}', "\u{646}\u{631}"), ('\u{fc8b}', "\u{646}\u{632}"), ('\u{fc8c}', "\u{646}\u{645}"),
+    ('\u{fc8d}', "\u{646}\u{646}"), ('\u{fc8e}', "\u{646}\u{649}"), ('\u{fc8f}', "\u{646}\u{649}"),
+    ('\u{fc90}', "\u{649}\u{670}"), ('\u{fc91}', "\u{649}\u{631}"), ('\u{fc92}', "\u{649}\u{632}"),
+    ('\u{fc93}', "\u{649}\u{645}"), ('\u{fc94}', "\u{649}\u{646}"), ('\u{fc95}', "\u{649}\u{649}"),
+    ('\u{fc96}', "\u{649}\u{649}"), ('\u{fc97}', "\u{649}\u{674}\u{62c}"), ('\u{fc98}', "\u{649}\u{674}\u{62d}"),
+    ('\u{fc99}', "\u{649}\u{674}\u{62e}"), ('\u{fc9a}', "\u{649}\u{674}\u{645}"), ('\u{fc9b}', "\u{649}\u{674}o"),
+    ('\u{fc9c}', "\u{628}\u{62c}"), ('\u{fc9d}', "\u{628}\u{62d}"), ('\u{fc9e}', "\u{628}\u{62e}"),
+    ('\u{fc9f}', "\u{628}\u{645}"), ('\u{fca0}', "\u{628}o"), ('\u{fca1}', "\u{62a}\u{62c}"),
+    ('\u{fca2}', "\u{62a}\u{62d}"), ('\u{fca3}', "\u{62a}\u{62e}"), ('\u{fca4}', "\u{62a}\u{645}"),
+    ('\u{fca5}', "\u{62a}o"), ('\u{fca6}', "\u{649}\u{6db}\u{645}"), ('\u{fca7}', "\u{62c}\u{62d}"),
+    ('\u{fca8}', "\u{62c}\u{645}"), ('\u{fca9}', "\u{62d}\u{62c}"), ('\u{fcaa}', "\u{62d}\u{645}"),
+    ('\u{fcab}', "\u{62e}\u{62c}"), ('\u{fcac}', "\u{62e}\u{645}"), ('\u{fcad}', "\u{633}\u{62c}"),
+    ('\u{fcae}', "\u{633}\u{62d}"), ('\u{fcaf}', "\u{633}\u{62e}"), ('\u{fcb0}', "\u{633}\u{645}"),
+    ('\u{fcb1}', "\u{635}\u{62d}"), ('\u{fcb2}', "\u{635}\u{62e}"), ('\u{fcb3}', "\u{635}\u{645}"),
+    ('\u{fcb4}', "\u{636}\u{62c}"), ('\u{fcb5}', "\u{636}\u{62d}"), ('\u{fcb6}', "\u{636}\u{62e}"),
+    ('\u{fcb7}', "\u{636}\u{645}"), ('\u{fcb8}', "\u{637}\u{62d}"), ('\u{fcb9}', "\u{638}\u{645}"),
+    ('\u{fcba}', "\u{639}\u{62c}"), ('\u{fcbb}', "\u{639}\u{645}"), ('\u{fcbc}', "\u{63a}\u{62c}"),
+    ('\u{fcbd}', "\u{63a}\u{645}"), ('\u{fcbe}', "\u{641}\u{62c}"), ('\u{fcbf}', "\u{641}\u{62d}"),
+    ('\u{fcc0}', "\u{641}\u{62e}"), ('\u{fcc1}', "\u{641}\u{645}"), ('\u{fcc2}', "\u{642}\u{62d}"),
+    ('\u{fcc3}', "\u{642}\u{645}"), ('\u{fcc4}', "\u{643}\u{62c}"), ('\u{fcc5}', "\u{643}\u{62d}"),
+    ('\u{fcc6}', "\u{643}\u{62e}"), ('\u{fcc7}', "\u{643}\u{644}"), ('\u{fcc8}', "\u{643}\u{645}"),
+    ('\u{fcc9}', "\u{644}\u{62c}"), ('\u{fcca}', "\u{644}\u{62d}"), ('\u{fccb}', "\u{644}\u{62e}"),
+    ('\u{fccc}', "\u{644}\u{645}"), ('\u{fccd}', "\u{644}o"), ('\u{fcce}', "\u{645}\u{62c}"),
+    ('\u{fccf}', "\u{645}\u{62d}"), ('\u{fcd0}', "\u{645}\u{62e}"), ('\u{fcd1}', "\u{645}\u{645}"),
+    ('\u{fcd2}', "\u{628}\u{62e}"), ('\u{fcd3}', "\u{646}\u{62d}"), ('\u{fcd4}', "\u{646}\u{62e}"),
+    ('\u{fcd5}', "\u{646}\u{645}"), ('\u{fcd6}', "\u{646}o"), ('\u{fcd7}', "o\u{62c}"),
+    ('\u{fcd8}', "o\u{645}"), ('\u{fcd9}', "o\u{670}"), ('\u{fcda}', "\u{649}\u{62c}"),
+    ('\u{fcdb}', "\u{649}\u{62d}"), ('\u{fcdc}', "\u{649}\u{62e}"), ('\u{fcdd}', "\u{649}\u{645}"),
+    ('\u{fcde}', "\u{649}o"), ('\u{fcdf}', "\u{649}\u{674}\u{645}"), ('\u{fce0}', "\u{649}\u{674}o"),
+    ('\u{fce1}', "\u{628}\u{645}"), ('\u{fce2}', "\u{628}o"), ('\u{fce3}', "\u{62a}\u{645}"),
+    ('\u{fce4}', "\u{62a}o"), ('\u{fce5}', "\u{649}\u{6db}\u{645}"), ('\u{fce6}', "\u{649}\u{6db}o"),
+    ('\u{fce7}', "\u{633}\u{645}"), ('\u{fce8}', "\u{633}o"), ('\u{fce9}', "\u{633}\u{6db}\u{645}"),
+    ('\u{fcea}', "\u{633}\u{6db}o"), ('\u{fceb}', "\u{643}\u{644}"), ('\u{fcec}', "\u{643}\u{645}"),
+    ('\u{fced}', "\u{644}\u{645}"), ('\u{fcee}', "\u{646}\u{645}"), ('\u{fcef}', "\u{646}o"),
+    ('\u{fcf0}', "\u{649}\u{645}"), ('\u{fcf1}', "\u{649}o"), ('\u{fcf2}', "\u{fe77}\u{651}"),
+    ('\u{fcf3}', "\u{fe79}\u{651}"), ('\u{fcf4}', "\u{fe7b}\u{651}"), ('\u{fcf5}', "\u{637}\u{649}"),
+    ('\u{fcf6}', "\u{637}\u{649}"), ('\u{fcf7}', "\u{639}\u{649}"), ('\u{fcf8}', "\u{639}\u{649}"),
+    ('\u{fcf9}', "\u{63a}\u{649}"), ('\u{fcfa}', "\u{63a}\u{649}"), ('\u{fcfb}', "\u{633}\u{649}"),
+    ('\u{fcfc}', "\u{633}\u{649}"), ('\u{fcfd}', "\u{633}\u{6db}\u{649}"), ('\u{fcfe}', "\u{633}\u{6db}\u{649}"),
+    ('\u{fcff}', "\u{62d}\u{649}"), ('\u{fd00}', "\u{62d}\u{649}"), ('\u{fd01}', "\u{62c}\u{649}"),
+    ('\u{fd02}', "\u{62c}\u{649}"), ('\u{fd03}', "\u{62e}\u{649}"), ('\u{fd04}', "\u{62e}\u{649}"),
+    ('\u{fd05}', "\u{635}\u{649}"), ('\u{fd06}', "\u{635}\u{649}"), ('\u{fd07}', "\u{636}\u{649}"),
+    ('\u{fd08}', "\u{636}\u{649}"), ('\u{fd09}', "\u{633}\u{6db}\u{62c}"), ('\u{fd0a}', "\u{633}\u{6db}\u{62d}"),
+    ('\u{fd0b}', "\u{633}\u{6db}\u{62e}"), ('\u{fd0c}', "\u{633}\u{6db}\u{645}"), ('\u{fd0d}', "\u{633}\u{6db}\u{631}"),
+    ('\u{fd0e}', "\u{633}\u{631}"), ('\u{fd0f}', "\u{635}\u{631}"), ('\u{fd10}', "\u{636}\u{631}"),
+    ('\u{fd11}', "\u{637}\u{649}"), ('\u{fd12}', "\u{637}\u{649}"), ('\u{fd13}', "\u{639}\u{649}"),
+    ('\u{fd14}', "\u{639}\u{649}"), ('\u{fd15}', "\u{63a}\u{649}"), ('\u{fd16}', "\u{63a}\u{649}"),
+    ('\u{fd17}', "\u{633}\u{649}"), ('\u{fd18}', "\u{633}\u{649}"), ('\u{fd19}', "\u{633}\u{6db}\u{649}"),
+    ('\u{fd1a}', "\u{633}\u{6db}\u{649}"), ('\u{fd1b}', "\u{62d}\u{649}"), ('\u{fd1c}', "\u{62d}\u{649}"),
+    ('\u{fd1d}', "\u{62c}\u{649}"), ('\u{fd1e}', "\u{62c}\u{649}"), ('\u{fd1f}', "\u{62e}\u{649}"),
+    ('\u{fd20}', "\u{62e}\u{649}"), ('\u{fd21}', "\u{635}\u{649}"), ('\u{fd22}', "\u{635}\u{649}"),
+    ('\u{fd23}', "\u{636}\u{649}"), ('\u{fd24}', "\u{636}\u{649}"), ('\u{fd25}', "\u{633}\u{6db}\u{62c}"),
+    ('\u{fd26}', "\u{633}\u{6db}\u{62d}"), ('\u{fd27}', "\u{633}\u{6db}\u{62e}"), ('\u{fd28}', "\u{633}\u{6db}\u{645}"),
+    ('\u{fd29}', "\u{633}\u{6db}\u{631}"), ('\u{fd2a}', "\u{633}\u{631}"), ('\u{fd2b}', "\u{635}\u{631}"),
+    ('\u{fd2c}', "\u{636}\u{631}"), ('\u{fd2d}', "\u{633}\u{6db}\u{62c}"), ('\u{fd2e}', "\u{633}\u{6db}\u{62d}"),
+    ('\u{fd2f}', "\u{633}\u{6db}\u{62e}"), ('\u{fd30}', "\u{633}\u{6db}\u{645}"), ('\u{fd31}', "\u{633}o"),
+    ('\u{fd32}', "\u{633}\u{6db}o"), ('\u{fd33}', "\u{637}\u{645}"), ('\u{fd34}', "\u{633}\u{62c}"),
+    ('\u{fd35}', "\u{633}\u{62d}"), ('\u{fd36}', "\u{633}\u{62e}"), ('\u{fd37}', "\u{633}\u{6db}\u{62c}"),
+    ('\u{fd38}', "\u{633}\u{6db}\u{62d}"), ('\u{fd39}', "\u{633}\u{6db}\u{62e}"), ('\u{fd3a}', "\u{637}\u{645}"),
+    ('\u{fd3b}', "\u{638}\u{645}"), ('\u{fd3c}', "l\u{30b}"), ('\u{fd3d}', "l\u{30b}"),
+    ('\u{fd50}', "\u{62a}\u{62c}\u{645}"), ('\u{fd51}', "\u{62a}\u{62d}\u{62c}"), ('\u{fd52}', "\u{62a}\u{62d}\u{62c}"),
+    ('\u{fd53}', "\u{62a}\u{62d}\u{645}"), ('\u{fd54}', "\u{62a}\u{62e}\u{645}"), ('\u{fd55}', "\u{62a}\u{645}\u{62c}"),
+    ('\u{fd56}', "\u{62a}\u{645}\u{62d}"), ('\u{fd57}', "\u{62a}\u{645}\u{62e}"), ('\u{fd58}', "\u{62c}\u{645}\u{62d}"),
+    ('\u{fd59}', "\u{62c}\u{645}\u{62d}"), ('\u{fd5a}', "\u{62d}\u{645}\u{649}"), ('\u{fd5b}', "\u{62d}\u{645}\u{649}"),
+    ('\u{fd5c}', "\u{633}\u{62d}\u{62c}"), ('\u{fd5d}', "\u{633}\u{62c}\u{62d}"), ('\u{fd5e}', "\u{633}\u{62c}\u{649}"),
+    ('\u{fd5f}', "\u{633}\u{645}\u{62d}"), ('\u{fd60}', "\u{633}\u{645}\u{62d}"), ('\u{fd61}', "\u{633}\u{645}\u{62c}"),
+    ('\u{fd62}', "\u{633}\u{645}\u{645}"), ('\u{fd63}', "\u{633}\u{645}\u{645}"), ('\u{fd64}', "\u{635}\u{62d}\u{62d}"),
+    ('\u{fd65}', "\u{635}\u{62d}\u{62d}"), ('\u{fd66}', "\u{635}\u{645}\u{645}"), ('\u{fd67}', "\u{633}\u{6db}\u{62d}\u{645}"),
+    ('\u{fd68}', "\u{633}\u{6db}\u{62d}\u{645}"), ('\u{fd69}', "\u{633}\u{6db}\u{62c}\u{649}"), ('\u{fd6a}', "\u{633}\u{6db}\u{645}\u{62e}"),
+    ('\u{fd6b}', "\u{633}\u{6db}\u{645}\u{62e}"), ('\u{fd6c}', "\u{633}\u{6db}\u{645}\u{645}"), ('\u{fd6d}', "\u{633}\u{6db}\u{645}\u{645}"),
+    ('\u{fd6e}', "\u{636}\u{62d}\u{649}"), ('\u{fd6f}', "\u{636}\u{62e}\u{645}"), ('\u{fd70}', "\u{636}\u{62e}\u{645}"),
+    ('\u{fd71}', "\u{637}\u{645}\u{62d}"), ('\u{fd72}', "\u{637}\u{645}\u{62d}"), ('\u{fd73}', "\u{637}\u{645}\u{645}"),
+    ('\u{fd74}', "\u{637}\u{645}\u{649}"), ('\u{fd75}', "\u{639}\u{62c}\u{645}"), ('\u{fd76}', "\u{639}\u{645}\u{645}"),
+    ('\u{fd77}', "\u{639}\u{645}\u{645}"), ('\u{fd78}', "\u{639}\u{645}\u{649}"), ('\u{fd79}', "\u{63a}\u{645}\u{645}"),
+    ('\u{fd7a}', "\u{63a}\u{645}\u{649}"), ('\u{fd7b}', "\u{63a}\u{645}\u{649}"), ('\u{fd7c}', "\u{641}\u{62e}\u{645}"),
+    ('\u{fd7d}', "\u{641}\u{62e}\u{645}"), ('\u{fd7e}', "\u{642}\u{645}\u{62d}"), ('\u{fd7f}', "\u{642}\u{645}\u{645}"),
+    ('\u{fd80}', "\u{644}\u{62d}\u{645}"), ('\u{fd81}', "\u{644}\u{62d}\u{649}"), ('\u{fd82}', "\u{644}\u{62d}\u{649}"),
+    ('\u{fd83}', "\u{644}\u{62c}\u{62c}"), ('\u{fd84}', "\u{644}\u{62c}\u{62c}"), ('\u{fd85}', "\u{644}\u{62e}\u{645}"),
+    ('\u{fd86}', "\u{644}\u{62e}\u{645}"), ('\u{fd87}', "\u{644}\u{645}\u{62d}"), ('\u{fd88}', "\u{644}\u{645}\u{62d}"),
+    ('\u{fd89}', "\u{645}\u{62d}\u{62c}"), ('\u{fd8a}', "\u{645}\u{62d}\u{645}"), ('\u{fd8b}', "\u{645}\u{62d}\u{649}"),
+    ('\u{fd8c}', "\u{645}\u{62c}\u{62d}"), ('\u{fd8d}', "\u{645}\u{62c}\u{645}"), ('\u{fd8e}', "\u{645}\u{62e}\u{62c}"),
+    ('\u{fd8f}', "\u{645}\u{62e}\u{645}"), ('\u{fd92}', "\u{645}\u{62c}\u{62e}"), ('\u{fd93}', "o\u{645}\u{62c}"),
+    ('\u{fd94}', "o\u{645}\u{645}"), ('\u{fd95}', "\u{646}\u{62d}\u{645}"), ('\u{fd96}', "\u{646}\u{62d}\u{649}"),
+    ('\u{fd97}', "\u{646}\u{62c}\u{645}"), ('\u{fd98}', "\u{646}\u{62c}\u{645}"), ('\u{fd99}', "\u{646}\u{62c}\u{649}"),
+    ('\u{fd9a}', "\u{646}\u{645}\u{649}"), ('\u{fd9b}', "\u{646}\u{645}\u{649}"), ('\u{fd9c}', "\u{649}\u{645}\u{645}"),
+    ('\u{fd9d}', "\u{649}\u{645}\u{645}"), ('\u{fd9e}', "\u{628}\u{62e}\u{649}"), ('\u{fd9f}', "\u{62a}\u{62c}\u{649}"),
+    ('\u{fda0}', "\u{62a}\u{62c}\u{649}"), ('\u{fda1}', "\u{62a}\u{62e}\u{649}"), ('\u{fda2}', "\u{62a}\u{62e}\u{649}"),
+    ('\u{fda3}', "\u{62a}\u{645}\u{649}"), ('\u{fda4}', "\u{62a}\u{645}\u{649}"), ('\u{fda5}', "\u{62c}\u{645}\u{649}"),
+    ('\u{fda6}', "\u{62c}\u{62d}\u{649}"), ('\u{fda7}', "\u{62c}\u{645}\u{649}"), ('\u{fda8}', "\u{633}\u{62e}\u{649}"),
+    ('\u{fda9}', "\u{635}\u{62d}\u{649}"), ('\u{fdaa}', "\u{633}\u{6db}\u{62d}\u{649}"), ('\u{fdab}', "\u{636}\u{62d}\u{649}"),
+    ('\u{fdac}', "\u{644}\u{62c}\u{649}"), ('\u{fdad}', "\u{644}\u{645}\u{649}"), ('\u{fdae}', "\u{649}\u{62d}\u{649}"),
+    ('\u{fdaf}', "\u{649}\u{62c}\u{649}"), ('\u{fdb0}', "\u{649}\u{645}\u{649}"), ('\u{fdb1}', "\u{645}\u{645}\u{649}"),
+    ('\u{fdb2}', "\u{642}\u{645}\u{649}"), ('\u{fdb3}', "\u{646}\u{62d}\u{649}"), ('\u{fdb4}', "\u{642}\u{645}\u{62d}"),
+    ('\u{fdb5}', "\u{644}\u{62d}\u{645}"), ('\u{fdb6}', "\u{639}\u{645}\u{649}"), ('\u{fdb7}', "\u{643}\u{645}\u{649}"),
+    ('\u{fdb8}', "\u{646}\u{62c}\u{62d}"), ('\u{fdb9}', "\u{645}\u{62e}\u{649}"), ('\u{fdba}', "\u{644}\u{62c}\u{645}"),
+    ('\u{fdbb}', "\u{643}\u{645}\u{645}"), ('\u{fdbc}', "\u{644}\u{62c}\u{645}"), ('\u{fdbd}', "\u{646}\u{62c}\u{62d}"),
+    ('\u{fdbe}', "\u{62c}\u{62d}\u{649}"), ('\u{fdbf}', "\u{62d}\u{62c}\u{649}"), ('\u{fdc0}', "\u{645}\u{62c}\u{649}"),
+    ('\u{fdc1}', "\u{641}\u{645}\u{649}"), ('\u{fdc2}', "\u{628}\u{62d}\u{649}"), ('\u{fdc3}', "\u{643}\u{645}\u{645}"),
+    ('\u{fdc4}', "\u{639}\u{62c}\u{645}"), ('\u{fdc5}', "\u{635}\u{645}\u{645}"), ('\u{fdc6}', "\u{633}\u{62e}\u{649}"),
+    ('\u{fdc7}', "\u{646}\u{62c}\u{649}"), ('\u{fdf0}', "\u{635}\u{644}\u{649}"), ('\u{fdf1}', "\u{642}\u{644}\u{649}"),
+    ('\u{fdf2}', "l\u{644}\u{644}\u{651}\u{670}o"), ('\u{fdf3}', "l\u{643}\u{628}\u{631}"), ('\u{fdf4}', "\u{645}\u{62d}\u{645}\u{62f}"),
+    ('\u{fdf5}', "\u{635}\u{644}\u{639}\u{645}"), ('\u{fdf6}', "\u{631}\u{633}\u{648}\u{644}"), ('\u{fdf7}', "\u{639}\u{644}\u{649}o"),
+    ('\u{fdf8}', "\u{648}\u{633}\u{644}\u{645}"), ('\u{fdf9}', "\u{635}\u{644}\u{649}"), ('\u{fe34}', "\u{2307}"),
+    ('\u{fe4d}', "_"), ('\u{fe4e}', "_"), ('\u{fe4f}', "_"),
+    ('\u{fe80}', "\u{621}"), ('\u{fe81}', "\u{627}\u{653}"), ('\u{fe82}', "\u{627}\u{653}"),
+    ('\u{fe83}', "l\u{674}"), ('\u{fe84}', "l\u{674}"), ('\u{fe85}', "\u{648}\u{674}"),
+    ('\u{fe86}', "\u{648}\u{674}"), ('\u{fe87}', "l\u{655}"), ('\u{fe88}', "l\u{655}"),
+    ('\u{fe89}', "\u{649}\u{674}"), ('\u{fe8a}', "\u{649}\u{674}"), ('\u{fe8b}', "\u{649}\u{674}"),
+    ('\u{fe8c}', "\u{649}\u{674}"), ('\u{fe8d}', "l"), ('\u{fe8e}', "l"),
+    ('\u{fe8f}', "\u{628}"), ('\u{fe90}', "\u{628}"), ('\u{fe91}', "\u{628}"),
+    ('\u{fe92}', "\u{628}"), ('\u{fe93}', "\u{629}"), ('\u{fe94}', "\u{629}"),
+    ('\u{fe95}', "\u{62a}"), ('\u{fe96}', "\u{62a}"), ('\u{fe97}', "\u{62a}"),
+    ('\u{fe98}', "\u{62a}"), ('\u{fe99}', "\u{649}\u{6db}"), ('\u{fe9a}', "\u{649}\u{6db}"),
+    ('\u{fe9b}', "\u{649}\u{6db}"), ('\u{fe9c}', "\u{649}\u{6db}"), ('\u{fe9d}', "\u{62c}"),
+    ('\u{fe9e}', "\u{62c}"), ('\u{fe9f}', "\u{62c}"), ('\u{fea0}', "\u{62c}"),
+    ('\u{fea1}', "\u{62d}"), ('\u{fea2}', "\u{62d}"), ('\u{fea3}', "\u{62d}"),
+    ('\u{fea4}', "\u{62d}"), ('\u{fea5}', "\u{62e}"), ('\u{fea6}', "\u{62e}"),
+    ('\u{fea7}', "\u{62e}"), ('\u{fea8}', "\u{62e}"), ('\u{fea9}', "\u{62f}"),
+    ('\u{feaa}', "\u{62f}"), ('\u{feab}', "\u{630}"), ('\u{feac}', "\u{630}"),
+    ('\u{fead}', "\u{631}"), ('\u{feae}', "\u{631}"), ('\u{feaf}', "\u{632}"),
+    ('\u{feb0}', "\u{632}"), ('\u{feb1}', "\u{633}"), ('\u{feb2}', "\u{633}"),
+    ('\u{feb3}', "\u{633}"), ('\u{feb4}', "\u{633}"), ('\u{feb5}', "\u{633}\u{6db}"),
+    ('\u{feb6}', "\u{633}\u{6db}"), ('\u{feb7}', "\u{633}\u{6db}"), ('\u{feb8}', "\u{633}\u{6db}"),
+    ('\u{feb9}', "\u{635}"), ('\u{feba}', "\u{635}"), ('\u{febb}', "\u{635}"),
+    ('\u{febc}', "\u{635}"), ('\u{febd}', "\u{636}"), ('\u{febe}', "\u{636}"),
+    ('\u{febf}', "\u{636}"), ('\u{fec0}', "\u{636}"), ('\u{fec1}', "\u{637}"),
+    ('\u{fec2}', "\u{637}"), ('\u{fec3}', "\u{637}"), ('\u{fec4}', "\u{637}"),
+    ('\u{fec5}', "\u{638}"), ('\u{fec6}', "\u{638}"), ('\u{fec7}', "\u{638}"),
+    ('\u{fec8}', "\u{638}"), ('\u{fec9}', "\u{639}"), ('\u{feca}', "\u{639}"),
+    ('\u{fecb}', "\u{639}"), ('\u{fecc}', "\u{639}"), ('\u{fecd}', "\u{63a}"),
+    ('\u{fece}', "\u{63a}"), ('\u{fecf}', "\u{63a}"), ('\u{fed0}', "\u{63a}"),
+    ('\u{fed1}', "\u{641}"), ('\u{fed2}', "\u{641}"), ('\u{fed3}', "\u{641}"),
+    ('\u{fed4}', "\u{641}"), ('\u{fed5}', "\u{642}"), ('\u{fed6}', "\u{642}"),
+    ('\u{fed7}', "\u{642}"), ('\u{fed8}', "\u{642}"), ('\u{fed9}', "\u{643}"),
+    ('\u{feda}', "\u{643}"), ('\u{fedb}', "\u{643}"), ('\u{fedc}', "\u{643}"),
+    ('\u{fedd}', "\u{644}"), ('\u{fede}', "\u{644}"), ('\u{fedf}', "\u{644}"),
+    ('\u{fee0}', "\u{644}"), ('\u{fee1}', "\u{645}"), ('\u{fee2}', "\u{645}"),
+    ('\u{fee3}', "\u{645}"), ('\u{fee4}', "\u{645}"), ('\u{fee5}', "\u{646}"),
+    ('\u{fee6}', "\u{646}"), ('\u{fee7}', "\u{646}"), ('\u{fee8}', "\u{646}"),
+    ('\u{fee9}', "o"), ('\u{feea}', "o"), ('\u{feeb}', "o"),
+    ('\u{feec}', "o"), ('\u{feed}', "\u{648}"), ('\u{feee}', "\u{648}"),
+    ('\u{feef}', "\u{649}"), ('\u{fef0}', "\u{649}"), ('\u{fef1}', "\u{649}"),
+    ('\u{fef2}', "\u{649}"), ('\u{fef3}', "\u{649}"), ('\u{fef4}', "\u{649}"),
+    ('\u{fef5}', "\u{644}\u{627}\u{653}"), ('\u{fef6}', "\u{644}\u{627}\u{653}"), ('\u{fef7}', "\u{644}l\u{674}"),
+    ('\u{fef8}', "\u{644}l\u{674}"), ('\u{fef9}', "\u{644}l\u{655}"), ('\u{fefa}', "\u{644}l\u{655}"),
+    ('\u{fefb}', "\u{644}l"), ('\u{fefc}', "\u{644}l"), ('\u{ff21}', "A"),
+    ('\u{ff22}', "B"), ('\u{ff23}', "C"), ('\u{ff25}', "E"),
+    ('\u{ff28}', "H"), ('\u{ff29}', "l"), ('\u{ff2a}', "J"),
+    ('\u{ff2b}', "K"), ('\u{ff2d}', "M"), ('\u{ff2e}', "N"),
+    ('\u{ff2f}', "O"), ('\u{ff30}', "P"), ('\u{ff33}', "S"),
+    ('\u{ff34}', "T"), ('\u{ff38}', "X"), ('\u{ff39}', "Y"),
+    ('\u{ff3a}', "Z"), ('\u{ff41}', "a"), ('\u{ff43}', "c"),
+    ('\u{ff45}', "e"), ('\u{ff47}', "g"), ('\u{ff48}', "h"),
+    ('\u{ff49}', "i"), ('\u{ff4a}', "j"), ('\u{ff4c}', "l"),
+    ('\u{ff4f}', "o"), ('\u{ff50}', "p"), ('\u{ff53}', "s"),
+    ('\u{ff56}', "v"), ('\u{ff58}', "x"), ('\u{ff59}', "y"),
+    ('\u{ff65}', "\u{b7}"), ('\u{10282}', "B"), ('\u{10285}', "\u{394}"),
+    ('\u{10286}', "E"), ('\u{10287}', "F"), ('\u{1028a}', "l"),
+    ('\u{1028d}', "\u{245}"), ('\u{10290}', "X"), ('\u{10292}', "O"),
+    ('\u{10294}', "\u{16dc}"), ('\u{10295}', "P"), ('\u{10296}', "S"),
+    ('\u{10297}', "T"), ('\u{1029b}', "\u{2b}"), ('\u{102a0}', "A"),
+    ('\u{102a1}', "B"), ('\u{102a2}', "C"), ('\u{102a3}', "\u{394}"),
+    ('\u{102a5}', "F"), ('\u{102ab}', "O"), ('\u{102ad}', "\u{3d8}"),
+    ('\u{102b0}', "M"), ('\u{102b1}', "T"), ('\u{102b2}', "Y"),
+    ('\u{102b3}', "\u{3a6}"), ('\u{102b4}', "X"), ('\u{102b5}', "\u{3a8}"),
+    ('\u{102b6}', "\u{3a9}"), ('\u{102b8}', "\u{2d40}"), ('\u{102cf}', "H"),
+    ('\u{10301}', "B"), ('\u{10302}', "C"), ('\u{10309}', "l"),
+    ('\u{10311}', "M"), ('\u{10312}', "\u{3d8}"), ('\u{10315}', "T"),
+    ('\u{10317}', "X"), ('\u{1031a}', "8"), ('\u{1031f}', "\u{2a}"),
+    ('\u{103d1}', "\u{10382}"), ('\u{103d3}', "\u{10393}"), ('\u{10401}', "\u{190}"),
+    ('\u{10404}', "O"), ('\u{10411}', "\u{a4f6}"), ('\u{10415}', "C"),
+    ('\u{1041b}', "L"), ('\u{1041f}', "\u{2c70}"), ('\u{10420}', "S"),
+    ('\u{10423}', "\u{186}"), ('\u{10425}', "\u{418}"), ('\u{10429}', "\u{a793}"),
+    ('\u{1042a}', "\u{29a}"), ('\u{1042c}', "o"), ('\u{1043d}', "c"),
+    ('\u{1043f}', "\u{277}"), ('\u{10442}', "\u{25e}"), ('\u{10443}', "\u{29f}"),
+    ('\u{10448}', "s"), ('\u{1044b}', "\u{254}"), ('\u{1044d}', "\u{1d0e}"),
+    ('\u{104a0}', "\u{10486}"), ('\u{104b0}', "\u{245}"), ('\u{104b4}', "R"),
+    ('\u{104bc}', "\u{4c3}"), ('\u{104c2}', "O"), ('\u{104c3}', "\u{298}"),
+    ('\u{104c4}', "\u{de}"), ('\u{104cd}', "\u{40b}"), ('\u{104ce}', "U"),
+    ('\u{104d0}', "\u{16e6}"), ('\u{104d1}', "\u{3a8}"), ('\u{104d2}', "7"),
+    ('\u{104d8}', "\u{28c}"), ('\u{104db}', "\u{3bb}"), ('\u{104ea}', "o"),
+    ('\u{104eb}', "\u{a669}"), ('\u{104f6}', "u"), ('\u{104f9}', "\u{3c8}"),
+    ('\u{10513}', "N"), ('\u{10516}', "O"), ('\u{10518}', "K"),
+    ('\u{1051c}', "C"), ('\u{1051d}', "V"), ('\u{10525}', "F"),
+    ('\u{10526}', "L"), ('\u{10527}', "X"), ('\u{10a3a}', "\u{323}"),
+    ('\u{111ca}', "\u{323}"), ('\u{111cb}', "\u{93a}"), ('\u{111dc}', "\u{a8fb}"),
+    ('\u{11300}', "\u{30a}"), ('\u{11413}', "\u{11434}\u{11442}\u{11412}"), ('\u{11419}', "\u{11434}\u{11442}\u{11418}"),
+    ('\u{11424}', "\u{11434}\u{11442}\u{11423}"), ('\u{1142a}', "\u{11434}\u{11442}\u{11429}"), ('\u{1142d}', "\u{11434}\u{11442}\u{1142c}"),
+    ('\u{1142f}', "\u{11434}\u{11442}\u{1142e}"), ('\u{11492}', "\u{998}"), ('\u{11494}', "\u{99a}"),
+    ('\u{11496}', "\u{99c}"), ('\u{11498}', "\u{99e}"), ('\u{11499}', "\u{99f}"),
+    ('\u{1149b}', "\u{9a1}"), ('\u{1149d}', "\u{9b2}"), ('\u{1149e}', "\u{9a4}"),
+    ('\u{1149f}', "\u{9a5}"), ('\u{114a0}', "\u{9a6}"), ('\u{114a1}', "\u{9a7}"),
+    ('\u{114a2}', "\u{9a8}"), ('\u{114a3}', "\u{9aa}"), ('\u{114a7}', "\u{9ae}"),
+    ('\u{114a8}', "\u{9af}"), ('\u{114a9}', "\u{9ac}"), ('\u{114aa}', "\u{9a3}"),
+    ('\u{114ab}', "\u{9b0}"), ('\u{114ad}', "\u{9b7}"), ('\u{114ae}', "\u{9b8}"),
+    ('\u{114b0}', "\u{9be}"), ('\u{114b1}', "\u{9bf}"), ('\u{114b9}', "\u{9c7}"),
+    ('\u{114bd}', "\u{9d7}"), ('\u{114bf}', "\u{306}\u{307}"), ('\u{114c1}', "\u{983}"),
+    ('\u{114c2}', "\u{9cd}"), ('\u{114c3}', "\u{323}"), ('\u{114c4}', "\u{9bd}"),
+    ('\u{114c5}', "w\u{307}"), ('\u{114d0}', "O"), ('\u{114d1}', "\u{9e7}"),
+    ('\u{114d2}', "\u{9e8}"), ('\u{114d6}', "\u{9ec}"), ('\u{115d8}', "\u{11582}"),
+    ('\u{115d9}', "\u{11582}"), ('\u{115da}', "\u{11583}"), ('\u{115db}', "\u{11584}"),
+    ('\u{115dc}', "\u{115b2}"), ('\u{115dd}', "\u{115b3}"), ('\u{11700}', "rn"),
+    ('\u{11706}', "v"), ('\u{1170a}', "w"), ('\u{1170e}', "w"),
+    ('\u{1170f}', "w"), ('\u{118a0}', "V"), ('\u{118a2}', "F"),
+    ('\u{118a3}', "L"), ('\u{118a4}', "Y"), ('\u{118a6}', "E"),
+    ('\u{118a8}', "\u{2207}"), ('\u{118a9}', "Z"), ('\u{118ac}', "9"),
+    ('\u{118ae}', "E"), ('\u{118af}', "4"), ('\u{118b2}', "L"),
+    ('\u{118b5}', "O"), ('\u{118b7}', "\u{16dc}"), ('\u{118b8}', "U"),
+    ('\u{118bb}', "5"), ('\u{118bc}', "T"), ('\u{118c0}', "v"),
+    ('\u{118c1}', "s"), ('\u{118c2}', "F"), ('\u{118c3}', "i"),
+    ('\u{118c4}', "z"), ('\u{118c6}', "7"), ('\u{118c8}', "o"),
+    ('\u{118ca}', "3"), ('\u{118cc}', "9"), ('\u{118ce}', "\u{a793}"),
+    ('\u{118d5}', "6"), ('\u{118d6}', "9"), ('\u{118d7}', "o"),
+    ('\u{118d8}', "u"), ('\u{118dc}', "y"), ('\u{118e0}', "O"),
+    ('\u{118e3}', "rn"), ('\u{118e4}', "\u{669}"), ('\u{118e5}', "Z"),
+    ('\u{118e6}', "W"), ('\u{118e9}', "C"), ('\u{11ae6}', "\u{11ae5}\u{11aef}"),
+    ('\u{11ae7}', "\u{11ae5}\u{11af0}"), ('\u{11ae8}', "\u{11ae5}\u{11ae5}"), ('\u{11ae9}', "\u{11ae5}\u{11ae5}\u{11aef}"),
+    ('\u{11aea}', "\u{11ae5}\u{11ae5}\u{11af0}"), ('\u{11aec}', "\u{11aeb}\u{11aef}"), ('\u{11aed}', "\u{11aeb}\u{11aeb}"),
+    ('\u{11aee}', "\u{11aeb}\u{11aeb}\u{11aef}"), ('\u{11af4}', "\u{11af3}\u{11aef}"), ('\u{11af5}', "\u{11af3}\u{11af0}"),
+    ('\u{11af6}', "\u{11af3}\u{11af3}"), ('\u{11af7}', "\u{11af3}\u{11af3}\u{11aef}"), ('\u{11af8}', "\u{11af3}\u{11af3}\u{11af0}"),
+    ('\u{11cb2}', "\u{11caa}"), ('\u{12038}', "\u{1039a}"), ('\u{132f9}', "\u{1099e}"),
+    ('\u{16f07}', "\u{393}"), ('\u{16f08}', "V"), ('\u{16f0a}', "T"),
+    ('\u{16f16}', "L"), ('\u{16f1a}', "\u{394}"), ('\u{16f1c}', "\u{a658}"),
+    ('\u{16f26}', "\u{a4f6}"), ('\u{16f28}', "l"), ('\u{16f2d}', "\u{190}"),
+    ('\u{16f35}', "R"), ('\u{16f3a}', "S"), ('\u{16f3b}', "3"),
+    ('\u{16f3d}', "\u{245}"), ('\u{16f3f}', "\u{3e}"), ('\u{16f40}', "A"),
+    ('\u{16f42}', "U"), ('\u{16f43}', "Y"), ('\u{16f51}', "\u{27}"),
+    ('\u{16f52}', "\u{27}"), ('\u{1ccf0}', "O"), ('\u{1ccf1}', "l"),
+    ('\u{1ccf2}', "2"), ('\u{1ccf3}', "3"), ('\u{1ccf4}', "4"),
+    ('\u{1ccf5}', "5"), ('\u{1ccf6}', "6"), ('\u{1ccf7}', "7"),
+    ('\u{1ccf8}', "8"), ('\u{1ccf9}', "9"), ('\u{1d16d}', "\u{2e}"),
+    ('\u{1d400}', "A"), ('\u{1d401}', "B"), ('\u{1d402}', "C"),
+    ('\u{1d403}', "D"), ('\u{1d404}', "E"), ('\u{1d405}', "F"),
+    ('\u{1d406}', "G"), ('\u{1d407}', "H"), ('\u{1d408}', "l"),
+    ('\u{1d409}', "J"), ('\u{1d40a}', "K"), ('\u{1d40b}', "L"),
+    ('\u{1d40c}', "M"), ('\u{1d40d}', "N"), ('\u{1d40e}', "O"),
+    ('\u{1d40f}', "P"), ('\u{1d410}', "Q"), ('\u{1d411}', "R"),
+    ('\u{1d412}', "S"), ('\u{1d413}', "T"), ('\u{1d414}', "U"),
+    ('\u{1d415}', "V"), ('\u{1d416}', "W"), ('\u{1d417}', "X"),
+    ('\u{1d418}', "Y"), ('\u{1d419}', "Z"), ('\u{1d41a}', "a"),
+    ('\u{1d41b}', "b"), ('\u{1d41c}', "c"), ('\u{1d41d}', "d"),
+    ('\u{1d41e}', "e"), ('\u{1d41f}', "f"), ('\u{1d420}', "g"),
+    ('\u{1d421}', "h"), ('\u{1d422}', "i"), ('\u{1d423}', "j"),
+    ('\u{1d424}', "k"), ('\u{1d425}', "l"), ('\u{1d426}', "rn"),
+    ('\u{1d427}', "n"), ('\u{1d428}', "o"), ('\u{1d429}', "p"),
+    ('\u{1d42a}', "q"), ('\u{1d42b}', "r"), ('\u{1d42c}', "s"),
+    ('\u{1d42d}', "t"), ('\u{1d42e}', "u"), ('\u{1d42f}', "v"),
+    ('\u{1d430}', "w"), ('\u{1d431}', "x"), ('\u{1d432}', "y"),
+    ('\u{1d433}', "z"), ('\u{1d434}', "A"), ('\u{1d435}', "B"),
+    ('\u{1d436}', "C"), ('\u{1d437}', "D"), ('\u{1d438}', "E"),
+    ('\u{1d439}', "F"), ('\u{1d43a}', "G"), ('\u{1d43b}', "H"),
+    ('\u{1d43c}', "l"), ('\u{1d43d}', "J"), ('\u{1d43e}', "K"),
+    ('\u{1d43f}', "L"), ('\u{1d440}', "M"), ('\u{1d441}', "N"),
+    ('\u{1d442}', "O"), ('\u{1d443}', "P"), ('\u{1d444}', "Q"),
+    ('\u{1d445}', "R"), ('\u{1d446}', "S"), ('\u{1d447}', "T"),
+    ('\u{1d448}', "U"), ('\u{1d449}', "V"), ('\u{1d44a}', "W"),
+    ('\u{1d44b}', "X"), ('\u{1d44c}', "Y"), ('\u{1d44d}', "Z"),
+    ('\u{1d44e}', "a"), ('\u{1d44f}', "b"), ('\u{1d450}', "c"),
+    ('\u{1d451}', "d"), ('\u{1d452}', "e"), ('\u{1d453}', "f"),
+    ('\u{1d454}', "g"), ('\u{1d456}', "i"), ('\u{1d457}', "j"),
+    ('\u{1d458}', "k"), ('\u{1d459}', "l"), ('\u{1d45a}', "rn"),
+    ('\u{1d45b}', "n"), ('\u{1d45c}', "o"), ('\u{1d45d}', "p"),
+    ('\u{1d45e}', "q"), ('\u{1d45f}', "r"), ('\u{1d460}', "s"),
+    ('\u{1d461}', "t"), ('\u{1d462}', "u"), ('\u{1d463}', "v"),
+    ('\u{1d464}', "w"), ('\u{1d465}', "x"), ('\u{1d466}', "y"),
+    ('\u{1d467}', "z"), ('\u{1d468}', "A"), ('\u{1d469}', "B"),
+    ('\u{1d46a}', "C"), ('\u{1d46b}', "D"), ('\u{1d46c}', "E"),
+    ('\u{1d46d}', "F"), ('\u{1d46e}', "G"), ('\u{1d46f}', "H"),
+    ('\u{1d470}', "l"), ('\u{1d471}', "J"), ('\u{1d472}', "K"),
+    ('\u{1d473}', "L"), ('\u{1d474}', "M"), ('\u{1d475}', "N"),
+    ('\u{1d476}', "O"), ('\u{1d477}', "P"), ('\u{1d478}', "Q"),
+    ('\u{1d479}', "R"), ('\u{1d47a}', "S"), ('\u{1d47b}', "T"),
+    ('\u{1d47c}', "U"), ('\u{1d47d}', "V"), ('\u{1d47e}', "W"),
+    ('\u{1d47f}', "X"), ('\u{1d480}', "Y"), ('\u{1d481}', "Z"),
+    ('\u{1d482}', "a"), ('\u{1d483}', "b"), ('\u{1d484}', "c"),
+    ('\u{1d485}', "d"), ('\u{1d486}', "e"), ('\u{1d487}', "f"),
+    ('\u{1d488}', "g"), ('\u{1d489}', "h"), ('\u{1d48a}', "i"),
+    ('\u{1d48b}', "j"), ('\u{1d48c}', "k"), ('\u{1d48d}', "l"),
+    ('\u{1d48e}', "rn"), ('\u{1d48f}', "n"), ('\u{1d490}', "o"),
+    ('\u{1d491}', "p"), ('\u{1d492}', "q"), ('\u{1d493}', "r"),
+    ('\u{1d494}', "s"), ('\u{1d495}', "t"), ('\u{1d496}', "u"),
+    ('\u{1d497}', "v"), ('\u{1d498}', "w"), ('\u{1d499}', "x"),
+    ('\u{1d49a}', "y"), ('\u{1d49b}', "z"), ('\u{1d49c}', "A"),
+    ('\u{1d49e}', "C"), ('\u{1d49f}', "D"), ('\u{1d4a2}', "G"),
+    ('\u{1d4a5}', "J"), ('\u{1d4a6}', "K"), ('\u{1d4a9}', "N"),
+    ('\u{1d4aa}', "O"), ('\u{1d4ab}', "P"), ('\u{1d4ac}', "Q"),
+    ('\u{1d4ae}', "S"), ('\u{1d4af}', "T"), ('\u{1d4b0}', "U"),
+    ('\u{1d4b1}', "V"), ('\u{1d4b2}', "W"), ('\u{1d4b3}', "X"),
+    ('\u{1d4b4}', "Y"), ('\u{1d4b5}', "Z"), ('\u{1d4b6}', "a"),
+    ('\u{1d4b7}', "b"), ('\u{1d4b8}', "c"), ('\u{1d4b9}', "d"),
+    ('\u{1d4bb}', "f"), ('\u{1d4bd}', "h"), ('\u{1d4be}', "i"),
+    ('\u{1d4bf}', "j"), ('\u{1d4c0}', "k"), ('\u{1d4c1}', "l"),
+    ('\u{1d4c2}', "rn"), ('\u{1d4c3}', "n"), ('\u{1d4c5}', "p"),
+    ('\u{1d4c6}', "q"), ('\u{1d4c7}', "r"), ('\u{1d4c8}', "s"),
+    ('\u{1d4c9}', "t"), ('\u{1d4ca}', "u"), ('\u{1d4cb}', "v"),
+    ('\u{1d4cc}', "w"), ('\u{1d4cd}', "x"), ('\u{1d4ce}', "y"),
+    ('\u{1d4cf}', "z"), ('\u{1d4d0}', "A"), ('\u{1d4d1}', "B"),
+    ('\u{1d4d2}', "C"), ('\u{1d4d3}', "D"), ('\u{1d4d4}', "E"),
+    ('\u{1d4d5}', "F"), ('\u{1d4d6}', "G"), ('\u{1d4d7}', "H"),
+    ('\u{1d4d8}', "l"), ('\u{1d4d9}', "J"), ('\u{1d4da}', "K"),
+    ('\u{1d4db}', "L"), ('\u{1d4dc}', "M"), ('\u{1d4dd}', "N"),
+    ('\u{1d4de}', "O"), ('\u{1d4df}', "P"), ('\u{1d4e0}', "Q"),
+    ('\u{1d4e1}', "R"), ('\u{1d4e2}', "S"), ('\u{1d4e3}', "T"),
+    ('\u{1d4e4}', "U"), ('\u{1d4e5}', "V"), ('\u{1d4e6}', "W"),
+    ('\u{1d4e7}', "X"), ('\u{1d4e8}', "Y"), ('\u{1d4e9}', "Z"),
+    ('\u{1d4ea}', "a"), ('\u{1d4eb}', "b"), ('\u{1d4ec}', "c"),
+    ('\u{1d4ed}', "d"), ('\u{1d4ee}', "e"), ('\u{1d4ef}', "f"),
+    ('\u{1d4f0}', "g"), ('\u{1d4f1}', "h"), ('\u{1d4f2}', "i"),
+    ('\u{1d4f3}', "j"), ('\u{1d4f4}', "k"), ('\u{1d4f5}', "l"),
+    ('\u{1d4f6}', "rn"), ('\u{1d4f7}', "n"), ('\u{1d4f8}', "o"),
+    ('\u{1d4f9}', "p"), ('\u{1d4fa}', "q"), ('\u{1d4fb}', "r"),
+    ('\u{1d4fc}', "s"), ('\u{1d4fd}', "t"), ('\u{1d4fe}', "u"),
+    ('\u{1d4ff}', "v"), ('\u{1d500}', "w"), ('\u{1d501}', "x"),
+    ('\u{1d502}', "y"), ('\u{1d503}', "z"), ('\u{1d504}', "A"),
+    ('\u{1d505}', "B"), ('\u{1d507}', "D"), ('\u{1d508}', "E"),
+    ('\u{1d509}', "F"), ('\u{1d50a}', "G"), ('\u{1d50d}', "J"),
+    ('\u{1d50e}', "K"), ('\u{1d50f}', "L"), ('\u{1d510}', "M"),
+    ('\u{1d511}', "N"), ('\u{1d512}', "O"), ('\u{1d513}', "P"),
+    ('\u{1d514}', "Q"), ('\u{1d516}', "S"), ('\u{1d517}', "T"),
+    ('\u{1d518}', "U"), ('\u{1d519}', "V"), ('\u{1d51a}', "W"),
+    ('\u{1d51b}', "X"), ('\u{1d51c}', "Y"), ('\u{1d51e}', "a"),
+    ('\u{1d51f}', "b"), ('\u{1d520}', "c"), ('\u{1d521}', "d"),
+    ('\u{1d522}', "e"), ('\u{1d523}', "f"), ('\u{1d524}', "g"),
+    ('\u{1d525}', "h"), ('\u{1d526}', "i"), ('\u{1d527}', "j"),
+    ('\u{1d528}', "k"), ('\u{1d529}', "l"), ('\u{1d52a}', "rn"),
+    ('\u{1d52b}', "n"), ('\u{1d52c}', "o"), ('\u{1d52d}', "p"),
+    ('\u{1d52e}', "q"), ('\u{1d52f}', "r"), ('\u{1d530}', "s"),
+    ('\u{1d531}', "t"), ('\u{1d532}', "u"), ('\u{1d533}', "v"),
+    ('\u{1d534}', "w"), ('\u{1d535}', "x"), ('\u{1d536}', "y"),
+    ('\u{1d537}', "z"), ('\u{1d538}', "A"), ('\u{1d539}', "B"),
+    ('\u{1d53b}', "D"), ('\u{1d53c}', "E"), ('\u{1d53d}', "F"),
+    ('\u{1d53e}', "G"), ('\u{1d540}', "l"), ('\u{1d541}', "J"),
+    ('\u{1d542}', "K"), ('\u{1d543}', "L"), ('\u{1d544}', "M"),
+    ('\u{1d546}', "O"), ('\u{1d54a}', "S"), ('\u{1d54b}', "T"),
+    ('\u{1d54c}', "U"), ('\u{1d54d}', "V"), ('\u{1d54e}', "W"),
+    ('\u{1d54f}', "X"), ('\u{1d550}', "Y"), ('\u{1d552}', "a"),
+    ('\u{1d553}', "b"), ('\u{1d554}', "c"), ('\u{1d555}', "d"),
+    ('\u{1d556}', "e"), ('\u{1d557}', "f"), ('\u{1d558}', "g"),
+    ('\u{1d559}', "h"), ('\u{1d55a}', "i"), ('\u{1d55b}', "j"),
+    ('\u{1d55c}', "k"), ('\u{1d55d}', "l"), ('\u{1d55e}', "rn"),
+    ('\u{1d55f}', "n"), ('\u{1d560}', "o"), ('\u{1d561}', "p"),
+    ('\u{1d562}', "q"), ('\u{1d563}', "r"), ('\u{1d564}', "s"),
+    ('\u{1d565}', "t"), ('\u{1d566}', "u"), ('\u{1d567}', "v"),
+    ('\u{1d568}', "w"), ('\u{1d569}', "x"), ('\u{1d56a}', "y"),
+    ('\u{1d56b}', "z"), ('\u{1d56c}', "A"), ('\u{1d56d}', "B"),
+    ('\u{1d56e}', "C"), ('\u{1d56f}', "D"), ('\u{1d570}', "E"),
+    ('\u{1d571}', "F"), ('\u{1d572}', "G"), ('\u{1d573}', "H"),
+    ('\u{1d574}', "l"), ('\u{1d575}', "J"), ('\u{1d576}', "K"),
+    ('\u{1d577}', "L"), ('\u{1d578}', "M"), ('\u{1d579}', "N"),
+    ('\u{1d57a}', "O"), ('\u{1d57b}', "P"), ('\u{1d57c}', "Q"),
+    ('\u{1d57d}', "R"), ('\u{1d57e}', "S"), ('\u{1d57f}', "T"),
+    ('\u{1d580}', "U"), ('\u{1d581}', "V"), ('\u{1d582}', "W"),
+    ('\u{1d583}', "X"), ('\u{1d584}', "Y"), ('\u{1d585}', "Z"),
+    ('\u{1d586}', "a"), ('\u{1d587}', "b"), ('\u{1d588}', "c"),
+    ('\u{1d589}', "d"), ('\u{1d58a}', "e"), ('\u{1d58b}', "f"),
+    ('\u{1d58c}', "g"), ('\u{1d58d}', "h"), ('\u{1d58e}', "i"),
+    ('\u{1d58f}', "j"), ('\u{1d590}', "k"), ('\u{1d591}', "l"),
+    ('\u{1d592}', "rn"), ('\u{1d593}', "n"), ('\u{1d594}', "o"),
+    ('\u{1d595}', "p"), ('\u{1d596}', "q"), ('\u{1d597}', "r"),
+    ('\u{1d598}', "s"), ('\u{1d599}', "t"), ('\u{1d59a}', "u"),
+    ('\u{1d59b}', "v"), ('\u{1d59c}', "w"), ('\u{1d59d}', "x"),
+    ('\u{1d59e}', "y"), ('\u{1d59f}', "z"), ('\u{1d5a0}', "A"),
+    ('\u{1d5a1}', "B"), ('\u{1d5a2}', "C"), ('\u{1d5a3}', "D"),
+    ('\u{1d5a4}', "E"), ('\u{1d5a5}', "F"), ('\u{1d5a6}', "G"),
+    ('\u{1d5a7}', "H"), ('\u{1d5a8}', "l"), ('\u{1d5a9}', "J"),
+    ('\u{1d5aa}', "K"), ('\u{1d5ab}', "L"), ('\u{1d5ac}', "M"),
+    ('\u{1d5ad}', "N"), ('\u{1d5ae}', "O"), ('\u{1d5af}', "P"),
+    ('\u{1d5b0}', "Q"), ('\u{1d5b1}', "R"), ('\u{1d5b2}', "S"),
+    ('\u{1d5b3}', "T"), ('\u{1d5b4}', "U"), ('\u{1d5b5}', "V"),
+    ('\u{1d5b6}', "W"), ('\u{1d5b7}', "X"), ('\u{1d5b8}', "Y"),
+    ('\u{1d5b9}', "Z"), ('\u{1d5ba}', "a"), ('\u{1d5bb}', "b"),
+    ('\u{1d5bc}', "c"), ('\u{1d5bd}', "d"), ('\u{1d5be}', "e"),
+    ('\u{1d5bf}', "f"), ('\u{1d5c0}', "g"), ('\u{1d5c1}', "h"),
+    ('\u{1d5c2}', "i"), ('\u{1d5c3}', "j"), ('\u{1d5c4}', "k"),
+    ('\u{1d5c5}', "l"), ('\u{1d5c6}', "rn"), ('\u{1d5c7}', "n"),
+    ('\u{1d5c8}', "o"), ('\u{1d5c9}', "p"), ('\u{1d5ca}', "q"),
+    ('\u{1d5cb}', "r"), ('\u{1d5cc}', "s"), ('\u{1d5cd}', "t"),
+    ('\u{1d5ce}', "u"), ('\u{1d5cf}', "v"), ('\u{1d5d0}', "w"),
+    ('\u{1d5d1}', "x"), ('\u{1d5d2}', "y"), ('\u{1d5d3}', "z"),
+    ('\u{1d5d4}', "A"), ('\u{1d5d5}', "B"), ('\u{1d5d6}', "C"),
+    ('\u{1d5d7}', "D"), ('\u{1d5d8}', "E"), ('\u{1d5d9}', "F"),
+    ('\u{1d5da}', "G"), ('\u{1d5db}', "H"), ('\u{1d5dc}', "l"),
+    ('\u{1d5dd}', "J"), ('\u{1d5de}', "K"), ('\u{1d5df}', "L"),
+    ('\u{1d5e0}', "M"), ('\u{1d5e1}', "N"), ('\u{1d5e2}', "O"),
+    ('\u{1d5e3}', "P"), ('\u{1d5e4}', "Q"), ('\u{1d5e5}', "R"),
+    ('\u{1d5e6}', "S"), ('\u{1d5e7}', "T"), ('\u{1d5e8}', "U"),
+    ('\u{1d5e9}', "V"), ('\u{1d5ea}', "W"), ('\u{1d5eb}', "X"),
+    ('\u{1d5ec}', "Y"), ('\u{1d5ed}', "Z"), ('\u{1d5ee}', "a"),
+    ('\u{1d5ef}', "b"), ('\u{1d5f0}', "c"), ('\u{1d5f1}', "d"),
+    ('\u{1d5f2}', "e"), ('\u{1d5f3}', "f"), ('\u{1d5f4}', "g"),
+    ('\u{1d5f5}', "h"), ('\u{1d5f6}', "i"), ('\u{1d5f7}', "j"),
+    ('\u{1d5f8}', "k"), ('\u{1d5f9}', "l"), ('\u{1d5fa}', "rn"),
+    ('\u{1d5fb}', "n"), ('\u{1d5fc}', "o"), ('\u{1d5fd}', "p"),
+    ('\u{1d5fe}', "q"), ('\u{1d5ff}', "r"), ('\u{1d600}', "s"),
+    ('\u{1d601}', "t"), ('\u{1d602}', "u"), ('\u{1d603}', "v"),
+    ('\u{1d604}', "w"), ('\u{1d605}', "x"), ('\u{1d606}', "y"),
+    ('\u{1d607}', "z"), ('\u{1d608}', "A"), ('\u{1d609}', "B"),
+    ('\u{1d60a}', "C"), ('\u{1d60b}', "D"), ('\u{1d60c}', "E"),
+    ('\u{1d60d}', "F"), ('\u{1d60e}', "G"), ('\u{1d60f}', "H"),
+    ('\u{1d610}', "l"), ('\u{1d611}', "J"), ('\u{1d612}', "K"),
+    ('\u{1d613}', "L"), ('\u{1d614}', "M"), ('\u{1d615}', "N"),
+    ('\u{1d616}', "O"), ('\u{1d617}', "P"), ('\u{1d618}', "Q"),
+    ('\u{1d619}', "R"), ('\u{1d61a}', "S"), ('\u{1d61b}', "T"),
+    ('\u{1d61c}', "U"), ('\u{1d61d}', "V"), ('\u{1d61e}', "W"),
+    ('\u{1d61f}', "X"), ('\u{1d620}', "Y"), ('\u{1d621}', "Z"),
+    ('\u{1d622}', "a"), ('\u{1d623}', "b"), ('\u{1d624}', "c"),
+    ('\u{1d625}', "d"), ('\u{1d626}', "e"), ('\u{1d627}', "f"),
+    ('\u{1d628}', "g"), ('\u{1d629}', "h"), ('\u{1d62a}', "i"),
+    ('\u{1d62b}', "j"), ('\u{1d62c}', "k"), ('\u{1d62d}', "l"),
+    ('\u{1d62e}', "rn"), ('\u{1d62f}', "n"), ('\u{1d630}', "o"),
+    ('\u{1d631}', "p"), ('\u{1d632}', "q"), ('\u{1d633}', "r"),
+    ('\u{1d634}', "s"), ('\u{1d635}', "t"), ('\u{1d636}', "u"),
+    ('\u{1d637}', "v"), ('\u{1d638}', "w"), ('\u{1d639}', "x"),
+    ('\u{1d63a}', "y"), ('\u{1d63b}', "z"), ('\u{1d63c}', "A"),
+    ('\u{1d63d}', "B"), ('\u{1d63e}', "C"), ('\u{1d63f}', "D"),
+    ('\u{1d640}', "E"), ('\u{1d641}', "F"), ('\u{1d642}', "G"),
+    ('\u{1d643}', "H"), ('\u{1d644}', "l"), ('\u{1d645}', "J"),
+    ('\u{1d646}', "K"), ('\u{1d647}', "L"), ('\u{1d648}', "M"),
+    ('\u{1d649}', "N"), ('\u{1d64a}', "O"), ('\u{1d64b}', "P"),
+    ('\u{1d64c}', "Q"), ('\u{1d64d}', "R"), ('\u{1d64e}', "S"),
+    ('\u{1d64f}', "T"), ('\u{1d650}', "U"), ('\u{1d651}', "V"),
+    ('\u{1d652}', "W"), ('\u{1d653}', "X"), ('\u{1d654}', "Y"),
+    ('\u{1d655}', "Z"), ('\u{1d656}', "a"), ('\u{1d657}', "b"),
+    ('\u{1d658}', "c"), ('\u{1d659}', "d"), ('\u{1d65a}', "e"),
+    ('\u{1d65b}', "f"), ('\u{1d65c}', "g"), ('\u{1d65d}', "h"),
+    ('\u{1d65e}', "i"), ('\u{1d65f}', "j"), ('\u{1d660}', "k"),
+    ('\u{1d661}', "l"), ('\u{1d662}', "rn"), ('\u{1d663}', "n"),
+    ('\u{1d664}', "o"), ('\u{1d665}', "p"), ('\u{1d666}', "q"),
+    ('\u{1d667}', "r"), ('\u{1d668}', "s"), ('\u{1d669}', "t"),
+    ('\u{1d66a}', "u"), ('\u{1d66b}', "v"), ('\u{1d66c}', "w"),
+    ('\u{1d66d}', "x"), ('\u{1d66e}', "y"), ('\u{1d66f}', "z"),
+    ('\u{1d670}', "A"), ('\u{1d671}', "B"), ('\u{1d672}', "C"),
+    ('\u{1d673}', "D"), ('\u{1d674}', "E"), ('\u{1d675}', "F"),
+    ('\u{1d676}', "G"), ('\u{1d677}', "H"), ('\u{1d678}', "l"),
+    ('\u{1d679}', "J"), ('\u{1d67a}', "K"), ('\u{1d67b}', "L"),
+    ('\u{1d67c}', "M"), ('\u{1d67d}', "N"), ('\u{1d67e}', "O"),
+    ('\u{1d67f}', "P"), ('\u{1d680}', "Q"), ('\u{1d681}', "R"),
+    ('\u{1d682}', "S"), ('\u{1d683}', "T"), ('\u{1d684}', "U"),
+    ('\u{1d685}', "V"), ('\u{1d686}', "W"), ('\u{1d687}', "X"),
+    ('\u{1d688}', "Y"), ('\u{1d689}', "Z"), ('\u{1d68a}', "a"),
+    ('\u{1d68b}', "b"), ('\u{1d68c}', "c"), ('\u{1d68d}', "d"),
+    ('\u{1d68e}', "e"), ('\u{1d68f}', "f"), ('\u{1d690}', "g"),
+    ('\u{1d691}', "h"), ('\u{1d692}', "i"), ('\u{1d693}', "j"),
+    ('\u{1d694}', "k"), ('\u{1d695}', "l"), ('\u{1d696}', "rn"),
+    ('\u{1d697}', "n"), ('\u{1d698}', "o"), ('\u{1d699}', "p"),
+    ('\u{1d69a}', "q"), ('\u{1d69b}', "r"), ('\u{1d69c}', "s"),
+    ('\u{1d69d}', "t"), ('\u{1d69e}', "u"), ('\u{1d69f}', "v"),
+    ('\u{1d6a0}', "w"), ('\u{1d6a1}', "x"), ('\u{1d6a2}', "y"),
+    ('\u{1d6a3}', "z"), ('\u{1d6a4}', "i"), ('\u{1d6a5}', "\u{237}"),
+    ('\u{1d6a8}', "A"), ('\u{1d6a9}', "B"), ('\u{1d6aa}', "\u{393}"),
+    ('\u{1d6ab}', "\u{394}"), ('\u{1d6ac}', "E"), ('\u{1d6ad}', "Z"),
+    ('\u{1d6ae}', "H"), ('\u{1d6af}', "O\u{335}"), ('\u{1d6b0}', "l"),
+    ('\u{1d6b1}', "K"), ('\u{1d6b2}', "\u{245}"), ('\u{1d6b3}', "M"),
+    ('\u{1d6b4}', "N"), ('\u{1d6b5}', "\u{39e}"), ('\u{1d6b6}', "O"),
+    ('\u{1d6b7}', "\u{3a0}"), ('\u{1d6b8}', "P"), ('\u{1d6b9}', "O\u{335}"),
+    ('\u{1d6ba}', "\u{1a9}"), ('\u{1d6bb}', "T"), ('\u{1d6bc}', "Y"),
+    ('\u{1d6bd}', "\u{3a6}"), ('\u{1d6be}', "X"), ('\u{1d6bf}', "\u{3a8}"),
+    ('\u{1d6c0}', "\u{3a9}"), ('\u{1d6c2}', "a"), ('\u{1d6c3}', "\u{df}"),
+    ('\u{1d6c4}', "y"), ('\u{1d6c5}', "\u{1e9f}"), ('\u{1d6c6}', "\u{a793}"),
+    ('\u{1d6c7}', "\u{3b6}"), ('\u{1d6c8}', "n\u{329}"), ('\u{1d6c9}', "O\u{335}"),
+    ('\u{1d6ca}', "i"), ('\u{1d6cb}', "\u{138}"), ('\u{1d6cc}', "\u{3bb}"),
+    ('\u{1d6cd}', "\u{3bc}"), ('\u{1d6ce}', "v"), ('\u{1d6cf}', "\u{3be}"),
+    ('\u{1d6d0}', "o"), ('\u{1d6d1}', "\u{3c0}"), ('\u{1d6d2}', "p"),
+    ('\u{1d6d3}', "\u{3c2}"), ('\u{1d6d4}', "o"), ('\u{1d6d5}', "\u{1d1b}"),
+    ('\u{1d6d6}', "u"), ('\u{1d6d7}', "\u{278}"), ('\u{1d6d8}', "\u{3c7}"),
+    ('\u{1d6d9}', "\u{3c8}"), ('\u{1d6da}', "\u{3c9}"), ('\u{1d6dc}', "\u{a793}"),
+    ('\u{1d6dd}', "O\u{335}"), ('\u{1d6de}', "\u{138}"), ('\u{1d6df}', "\u{278}"),
+    ('\u{1d6e0}', "p"), ('\u{1d6e1}', "\u{3c0}"), ('\u{1d6e2}', "A"),
+    ('\u{1d6e3}', "B"), ('\u{1d6e4}', "\u{393}"), ('\u{1d6e5}', "\u{394}"),
+    ('\u{1d6e6}', "E"), ('\u{1d6e7}', "Z"), ('\u{1d6e8}', "H"),
+    ('\u{1d6e9}', "O\u{335}"), ('\u{1d6ea}', "l"), ('\u{1d6eb}', "K"),
+    ('\u{1d6ec}', "\u{245}"), ('\u{1d6ed}', "M"), ('\u{1d6ee}', "N"),
+    ('\u{1d6ef}', "\u{39e}"), ('\u{1d6f0}', "O"), ('\u{1d6f1}', "\u{3a0}"),
+    ('\u{1d6f2}', "P"), ('\u{1d6f3}', "O\u{335}"), ('\u{1d6f4}', "\u{1a9}"),
+    ('\u{1d6f5}', "T"), ('\u{1d6f6}', "Y"), ('\u{1d6f7}', "\u{3a6}"),
+    ('\u{1d6f8}', "X"), ('\u{1d6f9}', "\u{3a8}"), ('\u{1d6fa}', "\u{3a9}"),
+    ('\u{1d6fc}', "a"), ('\u{1d6fd}', "\u{df}"), ('\u{1d6fe}', "y"),
+    ('\u{1d6ff}', "\u{1e9f}"), ('\u{1d700}', "\u{a793}"), ('\u{1d701}', "\u{3b6}"),
+    ('\u{1d702}', "n\u{329}"), ('\u{1d703}', "O\u{335}"), ('\u{1d704}', "i"),
+    ('\u{1d705}', "\u{138}"), ('\u{1d706}', "\u{3bb}"), ('\u{1d707}', "\u{3bc}"),
+    ('\u{1d708}', "v"), ('\u{1d709}', "\u{3be}"), ('\u{1d70a}', "o"),
+    ('\u{1d70b}', "\u{3c0}"), ('\u{1d70c}', "p"), ('\u{1d70d}', "\u{3c2}"),
+    ('\u{1d70e}', "o"), ('\u{1d70f}', "\u{1d1b}"), ('\u{1d710}', "u"),
+    ('\u{1d711}', "\u{278}"), ('\u{1d712}', "\u{3c7}"), ('\u{1d713}', "\u{3c8}"),
+    ('\u{1d714}', "\u{3c9}"), ('\u{1d716}', "\u{a793}"), ('\u{1d717}', "O\u{335}"),
+    ('\u{1d718}', "\u{138}"), ('\u{1d719}', "\u{278}"), ('\u{1d71a}', "p"),
+    ('\u{1d71b}', "\u{3c0}"), ('\u{1d71c}', "A"), ('\u{1d71d}', "B"),
+    ('\u{1d71e}', "\u{393}"), ('\u{1d71f}', "\u{394}"), ('\u{1d720}', "E"),
+    ('\u{1d721}', "Z"), ('\u{1d722}', "H"), ('\u{1d723}', "O\u{335}"),
+    ('\u{1d724}', "l"), ('\u{1d725}', "K"), ('\u{1d726}', "\u{245}"),
+    ('\u{1d727}', "M"), ('\u{1d728}', "N"), ('\u{1d729}', "\u{39e}"),
+    ('\u{1d72a}', "O"), ('\u{1d72b}', "\u{3a0}"), ('\u{1d72c}', "P"),
+    ('\u{1d72d}', "O\u{335}"), ('\u{1d72e}', "\u{1a9}"), ('\u{1d72f}', "T"),
+    ('\u{1d730}', "Y"), ('\u{1d731}', "\u{3a6}"), ('\u{1d732}', "X"),
+    ('\u{1d733}', "\u{3a8}"), ('\u{1d734}', "\u{3a9}"), ('\u{1d736}', "a"),
+    ('\u{1d737}', "\u{df}"), ('\u{1d738}', "y"), ('\u{1d739}', "\u{1e9f}"),
+    ('\u{1d73a}', "\u{a793}"), ('\u{1d73b}', "\u{3b6}"), ('\u{1d73c}', "n\u{329}"),
+    ('\u{1d73d}', "O\u{335}"), ('\u{1d73e}', "i"), ('\u{1d73f}', "\u{138}"),
+    ('\u{1d740}', "\u{3bb}"), ('\u{1d741}', "\u{3bc}"), ('\u{1d742}', "v"),
+    ('\u{1d743}', "\u{3be}"), ('\u{1d744}', "o"), ('\u{1d745}', "\u{3c0}"),
+    ('\u{1d746}', "p"), ('\u{1d747}', "\u{3c2}"), ('\u{1d748}', "o"),
+    ('\u{1d749}', "\u{1d1b}"), ('\u{1d74a}', "u"), ('\u{1d74b}', "\u{278}"),
+    ('\u{1d74c}', "\u{3c7}"), ('\u{1d74d}', "\u{3c8}"), ('\u{1d74e}', "\u{3c9}"),
+    ('\u{1d750}', "\u{a793}"), ('\u{1d751}', "O\u{335}"), ('\u{1d752}', "\u{138}"),
+    ('\u{1d753}', "\u{278}"), ('\u{1d754}', "p"), ('\u{1d755}', "\u{3c0}"),
+    ('\u{1d756}', "A"), ('\u{1d757}', "B"), ('\u{1d758}', "\u{393}"),
+    ('\u{1d759}', "\u{394}"), ('\u{1d75a}', "E"), ('\u{1d75b}', "Z"),
+    ('\u{1d75c}', "H"), ('\u{1d75d}', "O\u{335}"), ('\u{1d75e}', "l"),
+    ('\u{1d75f}', "K"), ('\u{1d760}', "\u{245}"), ('\u{1d761}', "M"),
+    ('\u{1d762}', "N"), ('\u{1d763}', "\u{39e}"), ('\u{1d764}', "O"),
+    ('\u{1d765}', "\u{3a0}"), ('\u{1d766}', "P"), ('\u{1d767}', "O\u{335}"),
+    ('\u{1d768}', "\u{1a9}"), ('\u{1d769}', "T"), ('\u{1d76a}', "Y"),
+    ('\u{1d76b}', "\u{3a6}"), ('\u{1d76c}', "X"), ('\u{1d76d}', "\u{3a8}"),
+    ('\u{1d76e}', "\u{3a9}"), ('\u{1d770}', "a"), ('\u{1d771}', "\u{df}"),
+    ('\u{1d772}', "y"), ('\u{1d773}', "\u{1e9f}"), ('\u{1d774}', "\u{a793}"),
+    ('\u{1d775}', "\u{3b6}"), ('\u{1d776}', "n\u{329}"), ('\u{1d777}', "O\u{335}"),
+    ('\u{1d778}', "i"), ('\u{1d779}', "\u{138}"), ('\u{1d77a}', "\u{3bb}"),
+    ('\u{1d77b}', "\u{3bc}"), ('\u{1d77c}', "v"), ('\u{1d77d}', "\u{3be}"),
+    ('\u{1d77e}', "o"), ('\u{1d77f}', "\u{3c0}"), ('\u{1d780}', "p"),
+    ('\u{1d781}', "\u{3c2}"), ('\u{1d782}', "o"), ('\u{1d783}', "\u{1d1b}"),
+    ('\u{1d784}', "u"), ('\u{1d785}', "\u{278}"), ('\u{1d786}', "\u{3c7}"),
+    ('\u{1d787}', "\u{3c8}"), ('\u{1d788}', "\u{3c9}"), ('\u{1d78a}', "\u{a793}"),
+    ('\u{1d78b}', "O\u{335}"), ('\u{1d78c}', "\u{138}"), ('\u{1d78d}', "\u{278}"),
+    ('\u{1d78e}', "p"), ('\u{1d78f}', "\u{3c0}"), ('\u{1d790}', "A"),
+    ('\u{1d791}', "B"), ('\u{1d792}', "\u{393}"), ('\u{1d793}', "\u{394}"),
+    ('\u{1d794}', "E"), ('\u{1d795}', "Z"), ('\u{1d796}', "H"),
+    ('\u{1d797}', "O\u{335}"), ('\u{1d798}', "l"), ('\u{1d799}', "K"),
+    ('\u{1d79a}', "\u{245}"), ('\u{1d79b}', "M"), ('\u{1d79c}', "N"),
+    ('\u{1d79d}', "\u{39e}"), ('\u{1d79e}', "O"), ('\u{1d79f}', "\u{3a0}"),
+    ('\u{1d7a0}', "P"), ('\u{1d7a1}', "O\u{335}"), ('\u{1d7a2}', "\u{1a9}"),
+    ('\u{1d7a3}', "T"), ('\u{1d7a4}', "Y"), ('\u{1d7a5}', "\u{3a6}"),
+    ('\u{1d7a6}', "X"), ('\u{1d7a7}', "\u{3a8}"), ('\u{1d7a8}', "\u{3a9}"),
+    ('\u{1d7aa}', "a"), ('\u{1d7ab}', "\u{df}"), ('\u{1d7ac}', "y"),
+    ('\u{1d7ad}', "\u{1e9f}"), ('\u{1d7ae}', "\u{a793}"), ('\u{1d7af}', "\u{3b6}"),
+    ('\u{1d7b0}', "n\u{329}"), ('\u{1d7b1}', "O\u{335}"), ('\u{1d7b2}', "i"),
+    ('\u{1d7b3}', "\u{138}"), ('\u{1d7b4}', "\u{3bb}"), ('\u{1d7b5}', "\u{3bc}"),
+    ('\u{1d7b6}', "v"), ('\u{1d7b7}', "\u{3be}"), ('\u{1d7b8}', "o"),
+    ('\u{1d7b9}', "\u{3c0}"), ('\u{1d7ba}', "p"), ('\u{1d7bb}', "\u{3c2}"),
+    ('\u{1d7bc}', "o"), ('\u{1d7bd}', "\u{1d1b}"), ('\u{1d7be}', "u"),
+    ('\u{1d7bf}', "\u{278}"), ('\u{1d7c0}', "\u{3c7}"), ('\u{1d7c1}', "\u{3c8}"),
+    ('\u{1d7c2}', "\u{3c9}"), ('\u{1d7c4}', "\u{a793}"), ('\u{1d7c5}', "O\u{335}"),
+    ('\u{1d7c6}', "\u{138}"), ('\u{1d7c7}', "\u{278}"), ('\u{1d7c8}', "p"),
+    ('\u{1d7c9}', "\u{3c0}"), ('\u{1d7ca}', "F"), ('\u{1d7cb}', "\u{3dd}"),
+    ('\u{1d7ce}', "O"), ('\u{1d7cf}', "l"), ('\u{1d7d0}', "2"),
+    ('\u{1d7d1}', "3"), ('\u{1d7d2}', "4"), ('\u{1d7d3}', "5"),
+    ('\u{1d7d4}', "6"), ('\u{1d7d5}', "7"), ('\u{1d7d6}', "8"),
+    ('\u{1d7d7}', "9"), ('\u{1d7d8}', "O"), ('\u{1d7d9}', "l"),
+    ('\u{1d7da}', "2"), ('\u{1d7db}', "3"), ('\u{1d7dc}', "4"),
+    ('\u{1d7dd}', "5"), ('\u{1d7de}', "6"), ('\u{1d7df}', "7"),
+    ('\u{1d7e0}', "8"), ('\u{1d7e1}', "9"), ('\u{1d7e2}', "O"),
+    ('\u{1d7e3}', "l"), ('\u{1d7e4}', "2"), ('\u{1d7e5}', "3"),
+    ('\u{1d7e6}', "4"), ('\u{1d7e7}', "5"), ('\u{1d7e8}', "6"),
+    ('\u{1d7e9}', "7"), ('\u{1d7ea}', "8"), ('\u{1d7eb}', "9"),
+    ('\u{1d7ec}', "O"), ('\u{1d7ed}', "l"), ('\u{1d7ee}', "2"),
+    ('\u{1d7ef}', "3"), ('\u{1d7f0}', "4"), ('\u{1d7f1}', "5"),
+    ('\u{1d7f2}', "6"), ('\u{1d7f3}', "7"), ('\u{1d7f4}', "8"),
+    ('\u{1d7f5}', "9"), ('\u{1d7f6}', "O"), ('\u{1d7f7}', "l"),
+    ('\u{1d7f8}', "2"), ('\u{1d7f9}', "3"), ('\u{1d7fa}', "4"),
+    ('\u{1d7fb}', "5"), ('\u{1d7fc}', "6"), ('\u{1d7fd}', "7"),
+    ('\u{1d7fe}', "8"), ('\u{1d7ff}', "9"), ('\u{1ee00}', "l"),
+    ('\u{1ee01}', "\u{628}"), ('\u{1ee02}', "\u{62c}"), ('\u{1ee03}', "\u{62f}"),
+    ('\u{1ee05}', "\u{648}"), ('\u{1ee06}', "\u{632}"), ('\u{1ee07}', "\u{62d}"),
+    ('\u{1ee08}', "\u{637}"), ('\u{1ee09}', "\u{649}"), ('\u{1ee0a}', "\u{643}"),
+    ('\u{1ee0b}', "\u{644}"), ('\u{1ee0c}', "\u{645}"), ('\u{1ee0d}', "\u{646}"),
+    ('\u{1ee0e}', "\u{633}"), ('\u{1ee0f}', "\u{639}"), ('\u{1ee10}', "\u{641}"),
+    ('\u{1ee11}', "\u{635}"), ('\u{1ee12}', "\u{642}"), ('\u{1ee13}', "\u{631}"),
+    ('\u{1ee14}', "\u{633}\u{6db}"), ('\u{1ee15}', "\u{62a}"), ('\u{1ee16}', "\u{649}\u{6db}"),
+    ('\u{1ee17}', "\u{62e}"), ('\u{1ee18}', "\u{630}"), ('\u{1ee19}', "\u{636}"),
+    ('\u{1ee1a}', "\u{638}"), ('\u{1ee1b}', "\u{63a}"), ('\u{1ee1c}', "\u{649}"),
+    ('\u{1ee1d}', "\u{649}"), ('\u{1ee1e}', "\u{6a1}"), ('\u{1ee1f}', "\u{6a1}"),
+    ('\u{1ee21}', "\u{628}"), ('\u{1ee22}', "\u{62c}"), ('\u{1ee24}', "o"),
+    ('\u{1ee27}', "\u{62d}"), ('\u{1ee29}', "\u{649}"), ('\u{1ee2a}', "\u{643}"),
+    ('\u{1ee2b}', "\u{644}"), ('\u{1ee2c}', "\u{645}"), ('\u{1ee2d}', "\u{646}"),
+    ('\u{1ee2e}', "\u{633}"), ('\u{1ee2f}', "\u{639}"), ('\u{1ee30}', "\u{641}"),
+    ('\u{1ee31}', "\u{635}"), ('\u{1ee32}', "\u{642}"), ('\u{1ee34}', "\u{633}\u{6db}"),
+    ('\u{1ee35}', "\u{62a}"), ('\u{1ee36}', "\u{649}\u{6db}"), ('\u{1ee37}', "\u{62e}"),
+    ('\u{1ee39}', "\u{636}"), ('\u{1ee3b}', "\u{63a}"), ('\u{1ee42}', "\u{62c}"),
+    ('\u{1ee47}', "\u{62d}"), ('\u{1ee49}', "\u{649}"), ('\u{1ee4b}', "\u{644}"),
+    ('\u{1ee4d}', "\u{646}"), ('\u{1ee4e}', "\u{633}"), ('\u{1ee4f}', "\u{639}"),
+    ('\u{1ee51}', "\u{635}"), ('\u{1ee52}', "\u{642}"), ('\u{1ee54}', "\u{633}\u{6db}"),
+    ('\u{1ee57}', "\u{62e}"), ('\u{1ee59}', "\u{636}"), ('\u{1ee5b}', "\u{63a}"),
+    ('\u{1ee5d}', "\u{649}"), ('\u{1ee5f}', "\u{6a1}"), ('\u{1ee61}', "\u{628}"),
+    ('\u{1ee62}', "\u{62c}"), ('\u{1ee64}', "o"), ('\u{1ee67}', "\u{62d}"),
+    ('\u{1ee68}', "\u{637}"), ('\u{1ee69}', "\u{649}"), ('\u{1ee6a}', "\u{643}"),
+    ('\u{1ee6c}', "\u{645}"), ('\u{1ee6d}', "\u{646}"), ('\u{1ee6e}', "\u{633}"),
+    ('\u{1ee6f}', "\u{639}"), ('\u{1ee70}', "\u{641}"), ('\u{1ee71}', "\u{635}"),
+    ('\u{1ee72}', "\u{642}"), ('\u{1ee74}', "\u{633}\u{6db}"), ('\u{1ee75}', "\u{62a}"),
+    ('\u{1ee76}', "\u{649}\u{6db}"), ('\u{1ee77}', "\u{62e}"), ('\u{1ee79}', "\u{636}"),
+    ('\u{1ee7a}', "\u{638}"), ('\u{1ee7b}', "\u{63a}"), ('\u{1ee7c}', "\u{649}"),
+    ('\u{1ee7e}', "\u{6a1}"), ('\u{1ee80}', "l"), ('\u{1ee81}', "\u{628}"),
+    ('\u{1ee82}', "\u{62c}"), ('\u{1ee83}', "\u{62f}"), ('\u{1ee84}', "o"),
+    ('\u{1ee85}', "\u{648}"), ('\u{1ee86}', "\u{632}"), ('\u{1ee87}', "\u{62d}"),
+    ('\u{1ee88}', "\u{637}"), ('\u{1ee89}', "\u{649}"), ('\u{1ee8b}', "\u{644}"),
+    ('\u{1ee8c}', "\u{645}"), ('\u{1ee8d}', "\u{646}"), ('\u{1ee8e}', "\u{633}"),
+    ('\u{1ee8f}', "\u{639}"), ('\u{1ee90}', "\u{641}"), ('\u{1ee91}', "\u{635}"),
+    ('\u{1ee92}', "\u{642}"), ('\u{1ee93}', "\u{631}"), ('\u{1ee94}', "\u{633}\u{6db}"),
+    ('\u{1ee95}', "\u{62a}"), ('\u{1ee96}', "\u{649}\u{6db}"), ('\u{1ee97}', "\u{62e}"),
+    ('\u{1ee98}', "\u{630}"), ('\u{1ee99}', "\u{636}"), ('\u{1ee9a}', "\u{638}"),
+    ('\u{1ee9b}', "\u{63a}"), ('\u{1eea1}', "\u{628}"), ('\u{1eea2}', "\u{62c}"),
+    ('\u{1eea3}', "\u{62f}"), ('\u{1eea5}', "\u{648}"), ('\u{1eea6}', "\u{632}"),
+    ('\u{1eea7}', "\u{62d}"), ('\u{1eea8}', "\u{637}"), ('\u{1eea9}', "\u{649}"),
+    ('\u{1eeab}', "\u{644}"), ('\u{1eeac}', "\u{645}"), ('\u{1eead}', "\u{646}"),
+    ('\u{1eeae}', "\u{633}"), ('\u{1eeaf}', "\u{639}"), ('\u{1eeb0}', "\u{641}"),
+    ('\u{1eeb1}', "\u{635}"), ('\u{1eeb2}', "\u{642}"), ('\u{1eeb3}', "\u{631}"),
+    ('\u{1eeb4}', "\u{633}\u{6db}"), ('\u{1eeb5}', "\u{62a}"), ('\u{1eeb6}', "\u{649}\u{6db}"),
+    ('\u{1eeb7}', "\u{62e}"), ('\u{1eeb8}', "\u{630}"), ('\u{1eeb9}', "\u{636}"),
+    ('\u{1eeba}', "\u{638}"), ('\u{1eebb}', "\u{63a}"), ('\u{1fbf0}', "O"),
+    ('\u{1fbf1}', "l"), ('\u{1fbf2}', "2"), ('\u{1fbf3}', "3"),
+    ('\u{1fbf4}', "4"), ('\u{1fbf5}', "5"), ('\u{1fbf6}', "6"),
+    ('\u{1fbf7}', "7"), ('\u{1fbf8}', "8"), ('\u{1fbf9}', "9"),
+    ('\u{21fe8}', "\u{276c}")
+];
@@ -0,0 +1,263 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use oxygen_lexer::{ tokenize, tokenize_losslessly, unicode, DocStyle, TokenKind };
+
+mod reference;
+
+fn repo_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+fn sources_in(directory: &str) -> Vec<(PathBuf, String)> {
+    let mut sources: Vec<_> = fs::read_dir(repo_path(directory)).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ox"))
+        .map(|path| {
+            let src = fs::read_to_string(&path).unwrap();
+            (path, src)
+        })
+        .collect();
+
+    sources.sort();
+    sources
+}
+
+// The reference is from before identifiers followed UAX #31 and before
+// block and doc comments, so it only says how code that uses neither
+// should lex. The rest is checked by the tests at the bottom instead.
+fn reference_applies(src: &str) -> bool {
+    !["#=", "##", "#!"].iter().any(|comment| src.contains(comment))
+        && src.chars().all(|c| c.is_ascii() || !(unicode::is_xid_continue(c) || c.is_numeric()))
+}
+
+// Both with and without whitespace tokens, since they take different paths
+fn assert_same_tokens(src: &str) {
+    let expected = format!("{:?}", reference::tokenize(src, false).collect::<Vec<_>>());
+    let actual = format!("{:?}", tokenize(src).collect::<Vec<_>>());
+    assert_eq!(actual, expected, "different tokens for {src:?}");
+
+    let expected = format!("{:?}", reference::tokenize(src, true).collect::<Vec<_>>());
+    let actual = format!("{:?}", tokenize_losslessly(src).collect::<Vec<_>>());
+    assert_eq!(actual, expected, "different lossless tokens for {src:?}");
+}
+
+#[test]
+fn examples_lex_the_same() {
+    let examples = sources_in("../../examples");
+    assert!(!examples.is_empty());
+
+    for (_, src) in examples.iter().chain(&sources_in("../oxygen_fmt/tests/fixtures")) {
+        if reference_applies(src) {
+            assert_same_tokens(src);
+        }
+    }
+}
+
+#[test]
+fn edge_cases_lex_the_same() {
+    for src in [
+        "",
+        " ",
+        "\n\n\n",
+        "x",
+        "# comment without a newline",
+        "# comment\r\nx",
+        "1.0 1. 1.e5 1e 1e+ 1e-3 1.5f32 0x 0b 0o 0d 0xfFu8 0d12 00.5 0.x",
+        "t.0.1 t.0e5 t.1.5 13.as_string() 1..2",
+        "\"unterminated",
+        "\"escaped \\\" quote\" \"\\\\\" \"a\\\nb\"",
+        "'a' '\\'' '\\\\' 'ab' '\n' 'x",
+        "&& &= & || |> |= | ** **= *= * << <<= < <= >> >>= > >= == = != !",
+        "a::b : ; , . ~ ( ) [ ] { } @ $ ` ? \\",
+        "x\0y",
+        // Whitespace that isn't ASCII, and characters that take up
+        // more than one byte, before and after tokens on the same line
+        "let\u{a0}x\u{2003}=\u{85}1;",
+        "🦀 func 🦀() { \"🦀\" } 🦀",
+        "\u{feff}func main() {}",
+        "a\r\nb\rc\n\rd",
+        "\t\x0b\x0cx"
+    ] {
+        assert_same_tokens(src);
+    }
+}
+
+// An xorshift64* generator, so the corpus is the same every time
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn pick<'a>(&mut self, options: &[&'a str]) -> &'a str {
+        options[self.next() as usize % options.len()]
+    }
+}
+
+// Random strings made of pieces of tokens, so that they
+// meet each other in every way, and many are broken
+#[test]
+fn generated_sources_lex_the_same() {
+    let pieces = [
+        "x", "_", "größe", "٣", "²", "🦀", "if", "i32", "0", "1", "9", "0x", "0b", "e", "E", "f32", "u8",
+        ".", "+", "-", "*", "/", "%", "^", "&", "|", "<", ">", "=", "!", ":", ";", ",", "~",
+        "(", ")", "[", "]", "{", "}", "\"", "'", "\\", "#", "@",
+        " ", "  ", "\t", "\n", "\r\n", "\u{a0}", "\u{2003}", "\0"
+    ];
+
+    let mut generator = Generator(0x9e37_79b9_7f4a_7c15);
+    let mut checked = 0;
+    while checked < 5000 {
+        let length = generator.next() % 40;
+        let src: String = (0..length).map(|_| generator.pick(&pieces)).collect();
+        if reference_applies(&src) {
+            checked += 1;
+            assert_same_tokens(&src);
+        }
+    }
+}
+
+#[test]
+fn identifiers_follow_uax_31() {
+    let identifiers = |src| tokenize(src)
+        .filter(|token| token.kind == TokenKind::Identifier)
+        .map(|token| token.string)
+        .collect::<Vec<_>>();
+
+    assert_eq!(identifiers("größe 名前 _x١٢ e\u{301}"), ["größe", "名前", "_x١٢", "e\u{301}"]);
+    // Superscripts and combining marks can't start or carry on
+    // an identifier respectively, so aren't part of one
+    assert_eq!(identifiers("x² \u{301}e"), ["x", "e"]);
+}
+
+#[test]
+fn comments() {
+    let kinds = |src| tokenize(src).map(|token| (token.kind, token.string)).collect::<Vec<_>>();
+
+    assert_eq!(kinds("#!/usr/bin/env oxygen\n#! Module\n## Function\n### Not a doc comment\nx"), [
+        (TokenKind::Comment, "#!/usr/bin/env oxygen"),
+        (TokenKind::DocComment(DocStyle::Inner), "#! Module"),
+        (TokenKind::DocComment(DocStyle::Outer), "## Function"),
+        (TokenKind::Comment, "### Not a doc comment"),
+        (TokenKind::Identifier, "x")
+    ]);
+
+    assert_eq!(kinds("a #= b #= c =# d\n =# e #= f #= g =#"), [
+        (TokenKind::Identifier, "a"),
+        (TokenKind::BlockComment { terminated: true }, "#= b #= c =# d\n =#"),
+        (TokenKind::Identifier, "e"),
+        (TokenKind::BlockComment { terminated: false }, "#= f #= g =#")
+    ]);
+
+    // A shebang is only at the very start of a file
+    assert_eq!(kinds("\n#!/usr/bin/env oxygen"), [(TokenKind::DocComment(DocStyle::Inner), "#!/usr/bin/env oxygen")]);
+}
+
+fn positions(src: &str) -> Vec<(TokenKind, &str, usize, usize)> {
+    tokenize(src).map(|token| (token.kind, token.string, token.line, token.column)).collect()
+}
+
+#[test]
+fn identifiers_are_placed_by_character() {
+    use oxygen_lexer::Base::*;
+    use oxygen_lexer::LiteralKind::*;
+    use oxygen_lexer::TokenKind::*;
+
+    assert_eq!(positions("let größe = \"größe\"; # größe\nlet y = 'ö';"), [
+        (Keyword(oxygen_lexer::Keyword::Let), "let", 0, 0),
+        (Identifier, "größe", 0, 4),
+        (Eq, "=", 0, 10),
+        (Literal { kind: Str(true), suffix_start: 9 }, "\"größe\"", 0, 12),
+        (Semicolon, ";", 0, 19),
+        (Comment, "# größe", 0, 21),
+        (Keyword(oxygen_lexer::Keyword::Let), "let", 1, 0),
+        (Identifier, "y", 1, 4),
+        (Eq, "=", 1, 6),
+        (Literal { kind: Char(true), suffix_start: 4 }, "'ö'", 1, 8),
+        (Semicolon, ";", 1, 11)
+    ]);
+
+    let int = |suffix_start| Literal { kind: Int { base: Decimal, empty_int: false }, suffix_start };
+    assert_eq!(positions("x١٢ x² _٣ 1٣"), [
+        (Identifier, "x١٢", 0, 0),
+        (Identifier, "x", 0, 4),
+        (Unknown, "²", 0, 5),
+        (Identifier, "_٣", 0, 7),
+        (int(1), "1", 0, 10),
+        (Unknown, "٣", 0, 11)
+    ]);
+
+    assert_eq!(positions("名前 pаypal e\u{301} \u{301}e 1.é 1é 0xff名"), [
+        (Identifier, "名前", 0, 0),
+        (Identifier, "pаypal", 0, 3),
+        (Identifier, "e\u{301}", 0, 10),
+        (Unknown, "\u{301}", 0, 13),
+        (Identifier, "e", 0, 14),
+        (int(1), "1", 0, 16),
+        (Dot, ".", 0, 17),
+        (Identifier, "é", 0, 18),
+        (int(1), "1é", 0, 20),
+        (Literal { kind: Int { base: Hexadecimal, empty_int: false }, suffix_start: 4 }, "0xff名", 0, 23)
+    ]);
+}
+
+#[test]
+fn comment_edge_cases() {
+    use oxygen_lexer::TokenKind::*;
+
+    assert_eq!(positions("#= a #= b =# c =# x #= =# #==# #=#=#==#"), [
+        (BlockComment { terminated: true }, "#= a #= b =# c =#", 0, 0),
+        (Identifier, "x", 0, 18),
+        (BlockComment { terminated: true }, "#= =#", 0, 20),
+        (BlockComment { terminated: true }, "#==#", 0, 26),
+        (BlockComment { terminated: false }, "#=#=#==#", 0, 31)
+    ]);
+
+    assert_eq!(positions("x #= unterminated #= nested =#\n"), [
+        (Identifier, "x", 0, 0),
+        (BlockComment { terminated: false }, "#= unterminated #= nested =#\n", 0, 2)
+    ]);
+
+    assert_eq!(positions("## doc\n#! module doc\n### not doc\n##\n#!"), [
+        (DocComment(DocStyle::Outer), "## doc", 0, 0),
+        (DocComment(DocStyle::Inner), "#! module doc", 1, 0),
+        (Comment, "### not doc", 2, 0),
+        (DocComment(DocStyle::Outer), "##", 3, 0),
+        (DocComment(DocStyle::Inner), "#!", 4, 0)
+    ]);
+
+    assert_eq!(positions("#!/usr/bin/env oxygen\nfunc main"), [
+        (Comment, "#!/usr/bin/env oxygen", 0, 0),
+        (Keyword(oxygen_lexer::Keyword::Func), "func", 1, 0),
+        (Identifier, "main", 1, 5)
+    ]);
+    assert_eq!(positions("#! /usr/bin/env oxygen"), [(Comment, "#! /usr/bin/env oxygen", 0, 0)]);
+}
+
+// Sources the reference can't say anything about still have to lex into
+// tokens that make up the whole source, with or without the whitespace
+#[test]
+fn generated_sources_lex_losslessly() {
+    let pieces = [
+        "x", "_", "größe", "٣", "²", "名前", "а", "\u{301}", "🦀", "1", "0x", "e", "f32",
+        ".", "=", "!", "/", ";", "(", ")", "\"", "'", "\\", "#", "#=", "=#", "##", "#!",
+        " ", "\t", "\n", "\r\n", "\u{a0}"
+    ];
+
+    let mut generator = Generator(0x2545_f491_4f6c_dd1d);
+    for _ in 0..5000 {
+        let length = generator.next() % 40;
+        let src: String = (0..length).map(|_| generator.pick(&pieces)).collect();
+
+        let lossless: Vec<_> = tokenize_losslessly(&src).collect();
+        assert_eq!(lossless.iter().map(|token| token.string).collect::<String>(), src);
+
+        let code: Vec<_> = lossless.into_iter().filter(|token| token.kind != TokenKind::Whitespace).collect();
+        assert_eq!(format!("{:?}", tokenize(&src).collect::<Vec<_>>()), format!("{code:?}"), "different tokens for {src:?}");
+    }
+}
@@ -0,0 +1,485 @@
+// The lexer as it was before it worked over bytes, which went through
+// the source one `char` at a time and kept track of the line and column
+// as it went. The new lexer must give exactly the same tokens as this.
+
+use std::str::Chars;
+
+use oxygen_lexer::{ Base, Keyword, LiteralKind, Token, TokenKind };
+use oxygen_lexer::BinaryOperation::{ self, * };
+use oxygen_lexer::TokenKind::*;
+
+fn token(string: &str, kind: TokenKind, line: usize, column: usize) -> Token<'_> {
+    Token { string, kind, line, column }
+}
+
+pub struct TokenStream<'src> {
+    src: &'src str,
+    chars: Chars<'src>,
+    line: usize,
+    column: usize,
+    idx: usize,
+    // Whether the last token was a `.`, in which case a number
+    // is a tuple index e.g. `pair.0.1`, and so can't be a float
+    after_dot: bool,
+    // Whether whitespace is made into tokens, rather than skipped
+    keep_whitespace: bool
+}
+
+impl<'src> TokenStream<'src> {
+    pub fn new(input: &'src str, keep_whitespace: bool) -> Self {
+        TokenStream {
+            src: input,
+            chars: input.chars(),
+            line: 0,
+            column: 0,
+            idx: 0,
+            after_dot: false,
+            keep_whitespace
+        }
+    }
+    
+    fn peek(&self) -> char {
+        self.chars.clone().next().unwrap_or('\0') 
+    }
+
+    fn peek_second(&self) -> char {
+        let mut iter = self.chars.clone();
+        iter.next();
+        iter.next().unwrap_or('\0')
+    }
+
+    #[inline(always)]
+    fn at_end(&self) -> bool {
+        self.chars.as_str().is_empty()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let char = self.chars.next()?;
+        // `idx` is a byte offset into `src`, which tokens are sliced from
+        self.idx += char.len_utf8();
+
+        match char {
+            '\n' => {
+                self.line += 1;
+                self.column = 0;
+            },
+            _ => {
+                self.column += 1;
+            }
+        }
+
+        Some(char)
+    }
+
+    fn advance_while(&mut self, mut predicate: impl FnMut(char) -> bool) {
+        while predicate(self.peek()) && !self.at_end() {
+            self.advance();
+        }
+    }
+    
+    fn skip_whitespace(&mut self) {
+        self.advance_while(|c| c.is_whitespace());
+    }
+
+    fn consume_decimal_digits(&mut self) -> bool {
+        let mut has_digits = false;
+        while let '0'..='9' = self.peek() {
+            has_digits = true;
+            self.advance();
+        }
+        has_digits
+    }
+
+    fn consume_hexadecimal_digits(&mut self) -> bool {
+        let mut has_digits = false;
+        while let '0'..='9' | 'a'..='f' | 'A'..='F' = self.peek() {
+            has_digits = true;
+            self.advance();
+        }
+        has_digits
+    }
+
+    fn consume_literal_suffix(&mut self) {
+        if !is_ident_start(self.peek()) { return };
+
+        self.advance();
+        self.advance_while(is_ident_continue);
+    }
+
+    fn consume_float_exponent(&mut self) -> bool {
+        match self.peek() {
+            '+' | '-' => { self.advance(); },
+            _ => {}
+        };
+        self.consume_decimal_digits()
+    }
+
+    fn number(&mut self, first_digit: char) -> LiteralKind {
+        let mut base = Base::Decimal;
+        if first_digit == '0' {
+            let has_digits = match self.peek() {
+                'b' => {
+                    base = Base::Binary;
+                    self.advance();
+                    self.consume_decimal_digits()
+                },
+                'o' => {
+                    base = Base::Octal;
+                    self.advance();
+                    self.consume_decimal_digits()
+                },
+                'd' => {
+                    self.advance();
+                    self.consume_decimal_digits()
+                },
+                'x' => {
+                    base = Base::Hexadecimal;
+                    self.advance();
+                    self.consume_hexadecimal_digits()
+                },
+                '0'..='9' | '.' | 'e' | 'E' => {
+                    self.consume_decimal_digits();
+                    true
+                },
+                // Just a 0
+                _ => return LiteralKind::Int { base, empty_int: false }
+            };
+
+            // Base prefix provided, but no digits
+            // e.g. "0x"
+            if !has_digits {
+                return LiteralKind::Int { base, empty_int: true };
+            }
+        } else {
+            self.consume_decimal_digits();
+        }
+
+        match self.peek() {
+            // Need to check second, in case this is a field access
+            // on an integer literal
+            //
+            // e.g. 13.as_string();
+            '.' if !self.after_dot && !is_ident_start(self.peek_second()) => {
+                self.advance();
+                let mut empty_exponent = false;
+                if self.peek().is_ascii_digit() {
+                    self.consume_decimal_digits();
+                    match self.peek() {
+                        'e' | 'E' => {
+                            self.advance();
+                            empty_exponent = !self.consume_float_exponent();
+                        }
+                        _ => {}
+                    }
+                }
+                LiteralKind::Float { base, empty_exponent }
+            }
+            'e' | 'E' if !self.after_dot => {
+                self.advance();
+                let empty_exponent = !self.consume_float_exponent();
+                LiteralKind::Float { base, empty_exponent }
+            }
+            _ => LiteralKind::Int { base, empty_int: false }
+        }
+    }
+
+    // Returns true if string is terminated
+    fn double_quoted_string(&mut self) -> bool {
+        while let Some(c) = self.advance() {
+            match c {
+                '"' => return true,
+                '\\' if self.peek() == '\\' || self.peek() == '"' => {
+                    // Handle escaped \ and "
+                    self.advance();
+                },
+                _ => {}
+            };
+        }
+
+        // EOF reached
+        false
+    }
+
+    // Returns true if the character is terminated
+    fn single_quoted_char(&mut self) -> bool {
+        while let Some(c) = self.advance() {
+            match c {
+                '\'' => return true,
+                '\\' if self.peek() == '\\' || self.peek() == '\'' => {
+                    // Handle escaped \ and '
+                    self.advance();
+                },
+                // A character literal can't go over more than one line
+                '\n' => return false,
+                _ => {}
+            };
+        }
+
+        // EOF reached
+        false
+    }
+
+    // The operator, or its compound assignment form
+    // if it's followed by a `=` e.g. `<<` or `<<=`
+    fn binary_operation(&mut self, operation: BinaryOperation) -> TokenKind {
+        match self.peek() {
+            '=' => {
+                self.advance();
+                BinOpEq(operation)
+            },
+            _ => BinOp(operation)
+        }
+    }
+
+    pub fn next_token(&mut self) -> Token<'src> {
+        if self.keep_whitespace && self.peek().is_whitespace() {
+            let (start_idx, line, column) = (self.idx, self.line, self.column);
+            self.skip_whitespace();
+            return token(&self.src[start_idx..self.idx], Whitespace, line, column);
+        }
+
+        self.skip_whitespace();
+
+        let start_idx = self.idx;
+        let line = self.line;
+        let column = self.column;
+
+        let c = match self.advance() {
+            Some(c) => c,
+            None => return token("", TokenKind::Eof, line, column)
+        };
+
+        let kind = match c {
+            '#' => {
+                self.advance_while(|c| c != '\n');
+                Comment
+            },
+
+            c if is_ident_start(c) => {
+                self.advance_while(is_ident_continue);
+                match &self.src[start_idx..self.idx] {
+                    // Keywords
+                    "if" => Keyword(Keyword::If),
+                    "else" => Keyword(Keyword::Else),
+                    "loop" => Keyword(Keyword::Loop),
+                    "break" => Keyword(Keyword::Break),
+                    "return" => Keyword(Keyword::Return),
+                    "defer" => Keyword(Keyword::Defer),
+                    "func" => Keyword(Keyword::Func),
+                    "impure" => Keyword(Keyword::Impure),
+                    "mod" => Keyword(Keyword::Mod),
+                    "use" => Keyword(Keyword::Use),
+                    "pub" => Keyword(Keyword::Pub),
+                    "let" => Keyword(Keyword::Let),
+                    "const" => Keyword(Keyword::Const),
+                    "static" => Keyword(Keyword::Static),
+                    "extern" => Keyword(Keyword::Extern),
+                    "as" => Keyword(Keyword::As),
+                    "true" => Keyword(Keyword::True),
+                    "false" => Keyword(Keyword::False),
+
+                    // Types
+                    "i8" => Keyword(Keyword::I8),
+                    "i16" => Keyword(Keyword::I16),
+                    "i32" => Keyword(Keyword::I32),
+                    "i64" => Keyword(Keyword::I64),
+                    "u8" => Keyword(Keyword::U8),
+                    "u16" => Keyword(Keyword::U16),
+                    "u32" => Keyword(Keyword::U32),
+                    "u64" => Keyword(Keyword::U64),
+                    "f32" => Keyword(Keyword::F32),
+                    "f64" => Keyword(Keyword::F64),
+                    "bool" => Keyword(Keyword::Bool),
+                    "char" => Keyword(Keyword::Char),
+                    "never" => Keyword(Keyword::Never),
+
+                    _ => Identifier
+                }
+            },
+
+            c @ '0'..='9' => {
+                let literal_kind = self.number(c);
+                let suffix_start = self.idx - start_idx;
+                self.consume_literal_suffix();
+                TokenKind::Literal { kind: literal_kind, suffix_start }
+            }, 
+
+            '&' => {
+                match self.peek() {
+                    '&' => {
+                        self.advance();
+                        AndAnd
+                    },
+                    '=' => {
+                        self.advance();
+                        BinOpEq(And)
+                    },
+                    _ => BinOp(And)
+                }
+            },
+
+            '|' => {
+                match self.peek() {
+                    '|' => {
+                        self.advance();
+                        OrOr
+                    },
+                    '>' => {
+                        self.advance();
+                        Pipe
+                    },
+                    '=' => {
+                        self.advance();
+                        BinOpEq(Or)
+                    },
+                    _ => BinOp(Or)
+                }
+            },
+
+            '+' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        BinOpEq(Plus)
+                    },
+                    _ => BinOp(Plus)
+                }
+            },
+            '-' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        BinOpEq(Minus)
+                    },
+                    _ => BinOp(Minus)
+                }
+            },
+            '*' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        BinOpEq(Star)
+                    },
+                    '*' => {
+                        self.advance();
+                        self.binary_operation(StarStar)
+                    },
+                    _ => BinOp(Star)
+                }
+            },
+            '%' => self.binary_operation(Percent),
+            '^' => self.binary_operation(Caret),
+            '/' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        BinOpEq(Slash)
+                    },
+                    _ => BinOp(Slash)
+                }
+            },
+
+            '>' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        GreaterEq
+                    },
+                    '>' => {
+                        self.advance();
+                        self.binary_operation(Shr)
+                    },
+                    _ => Greater
+                }
+            },
+            '=' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        EqEq
+                    },
+                    _ => Eq
+                }
+            },
+            '<' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        LessEq
+                    },
+                    '<' => {
+                        self.advance();
+                        self.binary_operation(Shl)
+                    },
+                    _ => Less
+                }
+            },
+
+            '!' => {
+                match self.peek() {
+                    '=' => {
+                        self.advance();
+                        NotEq
+                    },
+                    _ => Bang
+                }
+            },
+
+            '"' => {
+                let terminated = self.double_quoted_string();
+                let suffix_start = self.idx - start_idx;
+                Literal { kind: LiteralKind::Str(terminated), suffix_start }
+            },
+
+            '\'' => {
+                let terminated = self.single_quoted_char();
+                let suffix_start = self.idx - start_idx;
+                Literal { kind: LiteralKind::Char(terminated), suffix_start }
+            },
+
+            ':' => {
+                match self.peek() {
+                    ':' => {
+                        self.advance();
+                        ColonColon
+                    },
+                    _ => Colon
+                }
+            },
+
+            ';' => Semicolon,
+            '.' => Dot,
+            ',' => Comma,
+            '~' => Tilde,
+            '(' => OpenParen,
+            ')' => CloseParen,
+            '[' => OpenBracket,
+            ']' => CloseBracket,
+            '{' => OpenCurly,
+            '}' => CloseCurly,
+
+            _ => Unknown
+        };
+
+
+        let end_idx = self.idx;
+        self.after_dot = kind == Dot;
+
+        token(&self.src[start_idx..end_idx], kind, line, column)
+    }
+}
+
+#[inline(always)]
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic()
+}
+
+#[inline(always)]
+fn is_ident_continue(c: char) -> bool {
+    is_ident_start(c) || c.is_numeric()
+}
+
+pub fn tokenize(input: &str, keep_whitespace: bool) -> impl Iterator<Item = Token<'_>> {
+    let mut stream = TokenStream::new(input, keep_whitespace);
+    std::iter::from_fn(move || Some(stream.next_token()).filter(|token| token.kind != Eof))
+}
@@ -0,0 +1,827 @@
+use std::collections::HashMap;
+use std::io::{ self, BufRead, Write };
+use std::ops::Range;
+use std::path::Path;
+
+use oxygen_ast::{ Ast, TopLevelItem };
+use oxygen_cst::ast::{ AstNode, SourceFile };
+use oxygen_error::{ capture_diagnostics, Diagnostic, Severity };
+use oxygen_lexer::{ tokenize, unicode, LiteralKind, Token, TokenKind };
+use oxygen_parser::parse;
+use oxygen_span::{ add_file, set_file_src, FileId, Span };
+
+pub use json::Json;
+
+// The functions every program can call, which have no definition to go to
+const BUILTINS: &[(&str, &str)] = &[
+    ("print", "impure func print(value)"),
+    ("panic", "func panic(str message) ~ never"),
+    ("assert", "func assert(bool condition, str message = ...)"),
+    ("assert_eq", "func assert_eq(left, right, str message = ...)")
+];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+// LSP `CompletionItemKind` and `SymbolKind` values
+const COMPLETION_FUNCTION: usize = 3;
+const SYMBOL_FUNCTION: usize = 12;
+
+// Just enough JSON for the messages of the protocol
+pub mod json {
+    use std::fmt;
+
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum Json {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(String),
+        Array(Vec<Json>),
+        // In the order the fields were written
+        Object(Vec<(String, Json)>)
+    }
+
+    static NULL: Json = Json::Null;
+
+    impl Json {
+        pub fn object<const N: usize>(fields: [(&str, Json); N]) -> Json {
+            Json::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+        }
+
+        // The field `key` of an object, which is `null` if there isn't one
+        pub fn get(&self, key: &str) -> &Json {
+            match self {
+                Json::Object(fields) => fields.iter()
+                    .find(|(field, _)| field == key)
+                    .map_or(&NULL, |(_, value)| value),
+                _ => &NULL
+            }
+        }
+
+        pub fn as_str(&self) -> Option<&str> {
+            match self {
+                Json::String(string) => Some(string),
+                _ => None
+            }
+        }
+
+        pub fn as_usize(&self) -> Option<usize> {
+            match self {
+                Json::Number(number) if number.fract() == 0.0 && *number >= 0.0 => Some(*number as usize),
+                _ => None
+            }
+        }
+
+        pub fn as_array(&self) -> Option<&[Json]> {
+            match self {
+                Json::Array(elements) => Some(elements),
+                _ => None
+            }
+        }
+
+        pub fn is_null(&self) -> bool {
+            *self == Json::Null
+        }
+
+        pub fn parse(src: &str) -> Option<Json> {
+            let mut parser = Parser { src: src.as_bytes(), idx: 0 };
+            let value = parser.value()?;
+            parser.skip_whitespace();
+
+            (parser.idx == src.len()).then_some(value)
+        }
+    }
+
+    impl From<bool> for Json {
+        fn from(value: bool) -> Self {
+            Json::Bool(value)
+        }
+    }
+
+    impl From<usize> for Json {
+        fn from(value: usize) -> Self {
+            Json::Number(value as f64)
+        }
+    }
+
+    impl From<i64> for Json {
+        fn from(value: i64) -> Self {
+            Json::Number(value as f64)
+        }
+    }
+
+    impl From<&str> for Json {
+        fn from(value: &str) -> Self {
+            Json::String(value.to_string())
+        }
+    }
+
+    impl From<String> for Json {
+        fn from(value: String) -> Self {
+            Json::String(value)
+        }
+    }
+
+    impl From<Vec<Json>> for Json {
+        fn from(value: Vec<Json>) -> Self {
+            Json::Array(value)
+        }
+    }
+
+    fn write_string(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in string.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{c}")?
+            }
+        }
+        f.write_str("\"")
+    }
+
+    impl fmt::Display for Json {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Json::Null => f.write_str("null"),
+                Json::Bool(value) => write!(f, "{value}"),
+                Json::Number(value) => write!(f, "{value}"),
+                Json::String(value) => write_string(f, value),
+                Json::Array(elements) => {
+                    f.write_str("[")?;
+                    for (idx, element) in elements.iter().enumerate() {
+                        if idx > 0 { f.write_str(",")? };
+                        write!(f, "{element}")?;
+                    }
+                    f.write_str("]")
+                },
+                Json::Object(fields) => {
+                    f.write_str("{")?;
+                    for (idx, (key, value)) in fields.iter().enumerate() {
+                        if idx > 0 { f.write_str(",")? };
+                        write_string(f, key)?;
+                        write!(f, ":{value}")?;
+                    }
+                    f.write_str("}")
+                }
+            }
+        }
+    }
+
+    struct Parser<'src> {
+        src: &'src [u8],
+        idx: usize
+    }
+
+    impl Parser<'_> {
+        fn skip_whitespace(&mut self) {
+            while self.src.get(self.idx).is_some_and(u8::is_ascii_whitespace) {
+                self.idx += 1;
+            }
+        }
+
+        // Skips whitespace, then takes `byte` if it's next
+        fn eat(&mut self, byte: u8) -> bool {
+            self.skip_whitespace();
+            let found = self.src.get(self.idx) == Some(&byte);
+            if found {
+                self.idx += 1;
+            }
+
+            found
+        }
+
+        fn literal(&mut self, text: &str, value: Json) -> Option<Json> {
+            let found = self.src[self.idx..].starts_with(text.as_bytes());
+            self.idx += text.len();
+
+            found.then_some(value)
+        }
+
+        fn value(&mut self) -> Option<Json> {
+            self.skip_whitespace();
+
+            match self.src.get(self.idx)? {
+                b'n' => self.literal("null", Json::Null),
+                b't' => self.literal("true", Json::Bool(true)),
+                b'f' => self.literal("false", Json::Bool(false)),
+                b'"' => self.string().map(Json::String),
+                b'[' => {
+                    self.idx += 1;
+                    let mut elements = Vec::new();
+                    if !self.eat(b']') {
+                        loop {
+                            elements.push(self.value()?);
+                            if self.eat(b']') { break };
+                            if !self.eat(b',') { return None };
+                        }
+                    }
+
+                    Some(Json::Array(elements))
+                },
+                b'{' => {
+                    self.idx += 1;
+                    let mut fields = Vec::new();
+                    if !self.eat(b'}') {
+                        loop {
+                            self.skip_whitespace();
+                            let key = self.string()?;
+                            if !self.eat(b':') { return None };
+                            fields.push((key, self.value()?));
+                            if self.eat(b'}') { break };
+                            if !self.eat(b',') { return None };
+                        }
+                    }
+
+                    Some(Json::Object(fields))
+                },
+                _ => self.number()
+            }
+        }
+
+        fn number(&mut self) -> Option<Json> {
+            let start = self.idx;
+            while self.src.get(self.idx).is_some_and(|byte| matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+                self.idx += 1;
+            }
+
+            std::str::from_utf8(&self.src[start..self.idx]).ok()?.parse().ok().map(Json::Number)
+        }
+
+        fn hex_escape(&mut self) -> Option<u32> {
+            let digits = std::str::from_utf8(self.src.get(self.idx..self.idx + 4)?).ok()?;
+            self.idx += 4;
+
+            u32::from_str_radix(digits, 16).ok()
+        }
+
+        fn string(&mut self) -> Option<String> {
+            if self.src.get(self.idx) != Some(&b'"') { return None };
+            self.idx += 1;
+
+            let mut bytes = Vec::new();
+            loop {
+                let byte = *self.src.get(self.idx)?;
+                self.idx += 1;
+
+                match byte {
+                    b'"' => break,
+                    b'\\' => {
+                        let escape = *self.src.get(self.idx)?;
+                        self.idx += 1;
+
+                        let c = match escape {
+                            b'"' => '"',
+                            b'\\' => '\\',
+                            b'/' => '/',
+                            b'b' => '\u{8}',
+                            b'f' => '\u{c}',
+                            b'n' => '\n',
+                            b'r' => '\r',
+                            b't' => '\t',
+                            b'u' => {
+                                let mut code = self.hex_escape()?;
+                                // Characters outside the BMP are written as a surrogate pair
+                                if (0xD800..0xDC00).contains(&code) && self.src[self.idx..].starts_with(b"\\u") {
+                                    self.idx += 2;
+                                    let low = self.hex_escape()?;
+                                    code = 0x10000 + ((code - 0xD800) << 10) + (low.checked_sub(0xDC00)? & 0x3FF);
+                                }
+
+                                char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                            },
+                            _ => return None
+                        };
+
+                        bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                    },
+                    byte => bytes.push(byte)
+                }
+            }
+
+            String::from_utf8(bytes).ok()
+        }
+    }
+}
+
+// Converts between byte offsets into a document and LSP
+// positions, whose characters are counted in UTF-16 code units
+struct LineIndex {
+    // The byte offset each line starts at
+    line_starts: Vec<usize>
+}
+
+impl LineIndex {
+    fn new(src: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(src.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        LineIndex { line_starts }
+    }
+
+    fn line(&self, src: &str, line: usize) -> Range<usize> {
+        let start = self.line_starts.get(line).copied().unwrap_or(src.len());
+        let end = self.line_starts.get(line + 1).copied().unwrap_or(src.len());
+
+        start..end
+    }
+
+    fn position(&self, src: &str, offset: usize) -> Json {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let start = self.line_starts[line];
+        let character: usize = src[start..offset].chars().map(char::len_utf16).sum();
+
+        Json::object([("line", line.into()), ("character", character.into())])
+    }
+
+    fn range(&self, src: &str, range: Range<usize>) -> Json {
+        Json::object([
+            ("start", self.position(src, range.start)),
+            ("end", self.position(src, range.end))
+        ])
+    }
+
+    fn offset(&self, src: &str, position: &Json) -> usize {
+        let line = self.line(src, position.get("line").as_usize().unwrap_or(0));
+        let character = position.get("character").as_usize().unwrap_or(0);
+
+        let mut units = 0;
+        for (idx, c) in src[line.clone()].char_indices() {
+            if units >= character || c == '\n' {
+                return line.start + idx;
+            }
+            units += c.len_utf16();
+        }
+
+        line.end
+    }
+
+    // The byte offset of a span, whose column counts chars
+    fn span_offset(&self, src: &str, span: Span) -> usize {
+        let line = self.line(src, span.line);
+        src[line.clone()].char_indices().nth(span.column).map_or(line.end, |(idx, _)| line.start + idx)
+    }
+}
+
+// Where in its source a token starts, in bytes
+fn token_offset(src: &str, token: &Token<'_>) -> usize {
+    token.string.as_ptr() as usize - src.as_ptr() as usize
+}
+
+// The identifier that the cursor at `offset` is in or just after
+fn identifier_at(src: &str, offset: usize) -> Option<(&str, Range<usize>)> {
+    tokenize(src)
+        .filter(|token| token.kind == TokenKind::Identifier)
+        .map(|token| {
+            let start = token_offset(src, &token);
+            (token.string, start..start + token.string.len())
+        })
+        .find(|(_, range)| range.start <= offset && offset <= range.end)
+}
+
+// Problems that the lexer finds, which are all of them, rather
+// than just the first like the parser's errors
+fn lexer_diagnostics(src: &str) -> Vec<(Range<usize>, String, Severity)> {
+    tokenize(src).filter_map(|token| {
+        let message = match &token.kind {
+            TokenKind::Unknown => format!("Unknown character `{}`", token.string),
+            TokenKind::Literal { kind: LiteralKind::Str(false), .. } => "Unterminated string literal".to_string(),
+            TokenKind::Literal { kind: LiteralKind::Char(false), .. } => "Unterminated character literal".to_string(),
+            TokenKind::BlockComment { terminated: false } => "Unterminated block comment".to_string(),
+            _ => return None
+        };
+
+        let start = token_offset(src, &token);
+        Some((start..start + token.string.len(), message, Severity::Error))
+    }).collect()
+}
+
+// The range a diagnostic is shown over, which
+// is the token at the start of its span
+fn token_range_at(src: &str, offset: usize) -> Range<usize> {
+    let token = tokenize(src).find(|token| token_offset(src, token) >= offset);
+
+    match token {
+        Some(token) if token_offset(src, &token) == offset => offset..offset + token.string.len(),
+        // There's nothing to point at, e.g. for errors at the end of the file
+        _ => offset..offset
+    }
+}
+
+// Everything needed to answer questions about a function
+struct FunctionInfo {
+    name: String,
+    // e.g. `impure func divide(i32 a, i32 b) ~ i32!`
+    signature: String,
+    docs: Option<String>,
+    // Byte ranges into the source that was parsed
+    name_range: Range<usize>,
+    range: Range<usize>
+}
+
+// Collapses the whitespace, and any comments, in part of a signature
+fn signature_part(text: &str) -> String {
+    tokenize(text).map(|token| token.string).collect::<Vec<_>>().join(" ")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+        .replace(" !", "!")
+}
+
+// Finds the functions of the AST in the tree of the source,
+// which knows exactly where each part of them was written
+fn functions(src: &str, ast: &Ast, file: FileId) -> Vec<FunctionInfo> {
+    let index = LineIndex::new(src);
+    let tree = SourceFile::parse_file(file);
+    let nodes: Vec<_> = tree.syntax().descendants()
+        .filter_map(oxygen_cst::ast::Function::cast)
+        .collect();
+
+    let ast_functions = ast.items.iter().flat_map(|item| match item {
+        TopLevelItem::Function(function) => vec![function],
+        TopLevelItem::Extern(block) => block.functions.iter().collect(),
+        _ => Vec::new()
+    });
+
+    ast_functions.filter_map(|function| {
+        let offset = index.span_offset(src, Span { file, ..function.span });
+        let node = nodes.iter().find(|node| node.syntax().text_range().start == offset)?;
+        let name = node.name()?;
+
+        let mut signature = String::new();
+        if function.public { signature.push_str("pub ") };
+        // Foreign functions are impure unless they're marked `pure`
+        if function.impure { signature.push_str("impure ") };
+        signature.push_str("func ");
+        signature.push_str(&function.name);
+        match node.param_list() {
+            Some(params) => signature.push_str(&signature_part(&params.syntax().to_string())),
+            None => signature.push_str("()")
+        }
+        if let Some(return_type) = node.return_type() {
+            signature.push(' ');
+            signature.push_str(&signature_part(&return_type.syntax().to_string()));
+        }
+
+        Some(FunctionInfo {
+            name: function.name.clone(),
+            signature,
+            docs: function.docs.clone(),
+            name_range: name.text_range(),
+            range: node.syntax().text_range()
+        })
+    }).collect()
+}
+
+struct Document {
+    file: FileId,
+    src: String,
+    // From the last version that parsed, so they're still
+    // there while the code is being edited and is broken
+    functions: Vec<FunctionInfo>,
+    parsed_src: String
+}
+
+impl Document {
+    fn function(&self, name: &str) -> Option<&FunctionInfo> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    fn location(&self, uri: &str, range: Range<usize>) -> Json {
+        let index = LineIndex::new(&self.parsed_src);
+        Json::object([("uri", uri.into()), ("range", index.range(&self.parsed_src, range))])
+    }
+}
+
+#[derive(Default)]
+pub struct Server {
+    documents: HashMap<String, Document>,
+    shut_down: bool,
+    exited: bool
+}
+
+fn response(id: &Json, result: Json) -> Json {
+    Json::object([("jsonrpc", "2.0".into()), ("id", id.clone()), ("result", result)])
+}
+
+fn error_response(id: &Json, code: i64, message: &str) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("id", id.clone()),
+        ("error", Json::object([("code", code.into()), ("message", message.into())]))
+    ])
+}
+
+fn notification(method: &str, params: Json) -> Json {
+    Json::object([("jsonrpc", "2.0".into()), ("method", method.into()), ("params", params)])
+}
+
+// The signature, followed by the doc comment if there is one
+fn hover_text(signature: &str, docs: Option<&str>) -> Json {
+    let mut value = format!("```oxide\n{signature}\n```");
+    if let Some(docs) = docs {
+        value.push_str("\n\n");
+        value.push_str(docs);
+    }
+
+    Json::object([
+        ("contents", Json::object([
+            ("kind", "markdown".into()),
+            ("value", value.into())
+        ]))
+    ])
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Whether the client has sent `exit`, after which nothing more is handled
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    // Handles a message from the client, returning the
+    // responses and notifications to send back to it
+    pub fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let id = message.get("id");
+        let params = message.get("params");
+
+        let Some(method) = message.get("method").as_str() else {
+            // A response to a request we never sent
+            if !id.is_null() { return Vec::new() };
+            return vec![error_response(id, INVALID_REQUEST, "Expected a `method`")];
+        };
+
+        if self.shut_down && method != "exit" {
+            return match id {
+                Json::Null => Vec::new(),
+                _ => vec![error_response(id, INVALID_REQUEST, "The server has been shut down")]
+            };
+        }
+
+        let result = match method {
+            "initialize" => Json::object([
+                ("capabilities", Json::object([
+                    // The whole document is sent on every change
+                    ("textDocumentSync", 1usize.into()),
+                    ("hoverProvider", true.into()),
+                    ("definitionProvider", true.into()),
+                    ("documentSymbolProvider", true.into()),
+                    ("completionProvider", Json::object([]))
+                ])),
+                ("serverInfo", Json::object([("name", "oxygen-lsp".into())]))
+            ]),
+            "shutdown" => {
+                self.shut_down = true;
+                Json::Null
+            },
+            "exit" => {
+                self.exited = true;
+                return Vec::new();
+            },
+            "textDocument/didOpen" => {
+                let document = params.get("textDocument");
+                let uri = document.get("uri").as_str().unwrap_or_default();
+                let text = document.get("text").as_str().unwrap_or_default();
+
+                return vec![self.update(uri, text)];
+            },
+            "textDocument/didChange" => {
+                let uri = params.get("textDocument").get("uri").as_str().unwrap_or_default();
+                let changes = params.get("contentChanges").as_array().unwrap_or_default();
+                let Some(text) = changes.last().and_then(|change| change.get("text").as_str()) else {
+                    return Vec::new();
+                };
+
+                return vec![self.update(uri, text)];
+            },
+            "textDocument/didClose" => {
+                let uri = params.get("textDocument").get("uri").as_str().unwrap_or_default();
+                self.documents.remove(uri);
+
+                return vec![notification("textDocument/publishDiagnostics", Json::object([
+                    ("uri", uri.into()),
+                    ("diagnostics", Json::Array(Vec::new()))
+                ]))];
+            },
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/documentSymbol" => self.document_symbols(params),
+            "textDocument/completion" => self.completion(params),
+            // Other notifications, e.g. `initialized`, need nothing doing
+            _ if id.is_null() => return Vec::new(),
+            _ => return vec![error_response(id, METHOD_NOT_FOUND, &format!("No such method: {method}"))]
+        };
+
+        if id.is_null() { Vec::new() } else { vec![response(id, result)] }
+    }
+
+    // Re-parses a document that was opened or changed, returning its diagnostics
+    fn update(&mut self, uri: &str, text: &str) -> Json {
+        let path = uri.strip_prefix("file://").unwrap_or(uri);
+        let document = self.documents.entry(uri.to_string()).or_insert_with(|| Document {
+            file: add_file(Path::new(path), text),
+            src: String::new(),
+            functions: Vec::new(),
+            parsed_src: String::new()
+        });
+
+        document.src = text.to_string();
+        set_file_src(document.file, text);
+
+        let (ast, errors) = capture_diagnostics(|| parse(document.file));
+        if let Ok(ast) = ast {
+            document.functions = functions(text, &ast, document.file);
+            document.parsed_src = text.to_string();
+        }
+
+        let index = LineIndex::new(text);
+        let mut diagnostics = lexer_diagnostics(text);
+        let lexer_errors = diagnostics.len();
+
+        for Diagnostic { severity, message, span } in errors {
+            let offset = span.map_or(0, |span| index.span_offset(text, span));
+            // The parser stops at the first problem, which the lexer may have already found
+            if diagnostics[..lexer_errors].iter().any(|(range, ..)| range.start == offset) { continue };
+
+            diagnostics.push((token_range_at(text, offset), message, severity));
+        }
+
+        let diagnostics = diagnostics.into_iter().map(|(range, message, severity)| {
+            let severity: usize = match severity {
+                Severity::Error => 1,
+                Severity::Warning => 2
+            };
+
+            Json::object([
+                ("range", index.range(text, range)),
+                ("severity", severity.into()),
+                ("source", "oxygen".into()),
+                ("message", message.into())
+            ])
+        }).collect();
+
+        notification("textDocument/publishDiagnostics", Json::object([
+            ("uri", uri.into()),
+            ("diagnostics", Json::Array(diagnostics))
+        ]))
+    }
+
+    // The document and the identifier at the position of a request
+    fn identifier_at(&self, params: &Json) -> Option<(&str, String)> {
+        let uri = params.get("textDocument").get("uri").as_str()?;
+        let (uri, document) = self.documents.get_key_value(uri)?;
+        let offset = LineIndex::new(&document.src).offset(&document.src, params.get("position"));
+        let (name, _) = identifier_at(&document.src, offset)?;
+
+        // Names in the AST are normalised, so this must be too to match them
+        Some((uri, unicode::nfc(name).into_owned()))
+    }
+
+    // Looks for a function in the document it's used in, then in the other
+    // open documents, e.g. for a call of a function in another module
+    fn find_function(&self, uri: &str, name: &str) -> Option<(&str, &FunctionInfo)> {
+        let current = self.documents.get_key_value(uri)?;
+
+        std::iter::once(current).chain(&self.documents).find_map(|(uri, document)| {
+            document.function(name).map(|function| (uri.as_str(), function))
+        })
+    }
+
+    fn hover(&self, params: &Json) -> Json {
+        let Some((uri, name)) = self.identifier_at(params) else { return Json::Null };
+
+        if let Some((_, function)) = self.find_function(uri, &name) {
+            return hover_text(&function.signature, function.docs.as_deref());
+        }
+
+        match BUILTINS.iter().find(|(builtin, _)| *builtin == name) {
+            Some((_, signature)) => hover_text(signature, None),
+            None => Json::Null
+        }
+    }
+
+    fn definition(&self, params: &Json) -> Json {
+        let Some((uri, name)) = self.identifier_at(params) else { return Json::Null };
+
+        match self.find_function(uri, &name) {
+            Some((uri, function)) => self.documents[uri].location(uri, function.name_range.clone()),
+            None => Json::Null
+        }
+    }
+
+    fn document_symbols(&self, params: &Json) -> Json {
+        let uri = params.get("textDocument").get("uri").as_str().unwrap_or_default();
+        let Some(document) = self.documents.get(uri) else { return Json::Array(Vec::new()) };
+        let index = LineIndex::new(&document.parsed_src);
+
+        let symbols = document.functions.iter().map(|function| Json::object([
+            ("name", function.name.as_str().into()),
+            ("detail", function.signature.as_str().into()),
+            ("kind", SYMBOL_FUNCTION.into()),
+            ("range", index.range(&document.parsed_src, function.range.clone())),
+            ("selectionRange", index.range(&document.parsed_src, function.name_range.clone()))
+        ])).collect();
+
+        Json::Array(symbols)
+    }
+
+    fn completion(&self, params: &Json) -> Json {
+        let uri = params.get("textDocument").get("uri").as_str().unwrap_or_default();
+        let Some(document) = self.documents.get(uri) else { return Json::Array(Vec::new()) };
+
+        // Only names starting with what's already been typed are suggested
+        let offset = LineIndex::new(&document.src).offset(&document.src, params.get("position"));
+        let prefix = match identifier_at(&document.src, offset) {
+            Some((name, range)) => &name[..offset - range.start],
+            None => ""
+        };
+
+        let mut seen = Vec::new();
+        let functions = document.functions.iter()
+            .chain(self.documents.values().filter(|other| other.file != document.file).flat_map(|other| &other.functions))
+            .map(|function| (function.name.as_str(), function.signature.as_str()))
+            .chain(BUILTINS.iter().copied());
+
+        let items = functions.filter_map(|(name, signature)| {
+            if !name.starts_with(prefix) || seen.contains(&name) { return None };
+            seen.push(name);
+
+            Some(Json::object([
+                ("label", name.into()),
+                ("kind", COMPLETION_FUNCTION.into()),
+                ("detail", signature.into())
+            ]))
+        }).collect();
+
+        Json::Array(items)
+    }
+}
+
+// Reads a message, which has a `Content-Length` header, from the
+// client, returning `None` once there are no more
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut length = None;
+
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+        if header.is_empty() { break };
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let Some(length) = length else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Expected a `Content-Length` header"));
+    };
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    String::from_utf8(body).map(Some).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Json) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()
+}
+
+// Talks to a client over `input` and `output` until it says
+// to exit, returning whether it shut the server down first
+pub fn serve(mut input: impl BufRead, mut output: impl Write) -> io::Result<bool> {
+    let mut server = Server::new();
+
+    while let Some(body) = read_message(&mut input)? {
+        let replies = match Json::parse(&body) {
+            Some(message) => server.handle(&message),
+            None => vec![error_response(&Json::Null, PARSE_ERROR, "Couldn't parse the message as JSON")]
+        };
+
+        for reply in &replies {
+            write_message(&mut output, reply)?;
+        }
+
+        if server.has_exited() { break };
+    }
+
+    Ok(server.shut_down)
+}
@@ -0,0 +1,18 @@
+use std::io;
+use std::process::exit;
+
+fn main() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    // The protocol says to exit with 1 if the client
+    // exits without shutting the server down first
+    match oxygen_lsp::serve(stdin.lock(), stdout.lock()) {
+        Ok(true) => {},
+        Ok(false) => exit(1),
+        Err(err) => {
+            eprintln!("oxygen-lsp: {err}");
+            exit(1);
+        }
+    }
+}
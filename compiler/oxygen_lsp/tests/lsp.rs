@@ -0,0 +1,298 @@
+use std::io::BufReader;
+
+use oxygen_lsp::{ read_message, serve, write_message, Json };
+
+const URI: &str = "file:///project/main.ox";
+
+const SRC: &str = "\
+## Multiplies `x` by itself
+func square(i32 x) ~ i32 { x * x }
+
+pub func divide(i32 a, i32 b) ~ i32! {
+  a / b
+}
+
+impure func main() {
+  print(\"🦀\"); print(square(3));
+  let answer = divide(7, 2);
+  sq
+}
+";
+
+// A client which sends a script of messages to the server,
+// and then reads everything the server sent back
+#[derive(Default)]
+struct Client {
+    input: Vec<u8>,
+    next_id: usize
+}
+
+impl Client {
+    fn new() -> Self {
+        let mut client = Client::default();
+        client.request("initialize", Json::object([("capabilities", Json::object([]))]));
+        client.notify("initialized", Json::object([]));
+
+        client
+    }
+
+    fn send(&mut self, message: Json) {
+        write_message(&mut self.input, &message).unwrap();
+    }
+
+    fn request(&mut self, method: &str, params: Json) -> usize {
+        self.next_id += 1;
+        self.send(Json::object([
+            ("jsonrpc", "2.0".into()),
+            ("id", self.next_id.into()),
+            ("method", method.into()),
+            ("params", params)
+        ]));
+
+        self.next_id
+    }
+
+    fn notify(&mut self, method: &str, params: Json) {
+        self.send(Json::object([("jsonrpc", "2.0".into()), ("method", method.into()), ("params", params)]));
+    }
+
+    fn open(&mut self, text: &str) {
+        self.notify("textDocument/didOpen", Json::object([
+            ("textDocument", Json::object([
+                ("uri", URI.into()),
+                ("languageId", "oxide".into()),
+                ("version", 1usize.into()),
+                ("text", text.into())
+            ]))
+        ]));
+    }
+
+    fn change(&mut self, text: &str) {
+        self.notify("textDocument/didChange", Json::object([
+            ("textDocument", Json::object([("uri", URI.into()), ("version", 2usize.into())])),
+            ("contentChanges", vec![Json::object([("text", text.into())])].into())
+        ]));
+    }
+
+    fn at(&mut self, method: &str, line: usize, character: usize) -> usize {
+        self.request(method, Json::object([
+            ("textDocument", Json::object([("uri", URI.into())])),
+            ("position", Json::object([("line", line.into()), ("character", character.into())]))
+        ]))
+    }
+
+    // Shuts the server down, and returns what it sent
+    fn finish(mut self) -> Replies {
+        self.request("shutdown", Json::Null);
+        self.notify("exit", Json::Null);
+
+        let mut output = Vec::new();
+        let shut_down = serve(self.input.as_slice(), &mut output).unwrap();
+        assert!(shut_down);
+
+        let mut reader = BufReader::new(output.as_slice());
+        let mut messages = Vec::new();
+        while let Some(body) = read_message(&mut reader).unwrap() {
+            messages.push(Json::parse(&body).expect("the server sent invalid JSON"));
+        }
+
+        Replies(messages)
+    }
+}
+
+struct Replies(Vec<Json>);
+
+impl Replies {
+    fn response(&self, id: usize) -> &Json {
+        self.0.iter()
+            .find(|message| message.get("id").as_usize() == Some(id))
+            .unwrap_or_else(|| panic!("no response to request {id}"))
+    }
+
+    fn result(&self, id: usize) -> &Json {
+        let response = self.response(id);
+        assert!(response.get("error").is_null(), "request {id} failed: {response}");
+        response.get("result")
+    }
+
+    fn diagnostics(&self) -> Vec<&[Json]> {
+        self.0.iter()
+            .filter(|message| message.get("method").as_str() == Some("textDocument/publishDiagnostics"))
+            .map(|message| message.get("params").get("diagnostics").as_array().unwrap())
+            .collect()
+    }
+}
+
+fn position(json: &Json) -> (usize, usize) {
+    (json.get("line").as_usize().unwrap(), json.get("character").as_usize().unwrap())
+}
+
+fn range(json: &Json) -> ((usize, usize), (usize, usize)) {
+    (position(json.get("start")), position(json.get("end")))
+}
+
+fn hover_value(json: &Json) -> &str {
+    json.get("contents").get("value").as_str().unwrap()
+}
+
+#[test]
+fn initializes_and_shuts_down() {
+    let replies = Client::new().finish();
+
+    let capabilities = replies.result(1).get("capabilities");
+    assert_eq!(capabilities.get("textDocumentSync").as_usize(), Some(1));
+    for capability in ["hoverProvider", "definitionProvider", "documentSymbolProvider"] {
+        assert_eq!(capabilities.get(capability), &Json::Bool(true));
+    }
+    assert!(!capabilities.get("completionProvider").is_null());
+
+    assert!(replies.result(2).is_null());
+}
+
+#[test]
+fn reports_diagnostics_on_every_change() {
+    let mut client = Client::new();
+    client.open(SRC);
+    client.change("func main() {\n  let x = ;\n}\n");
+    client.change("func main() { let s = \"oops; let t = 1; }\n");
+    client.change("func main() { $ }\n@");
+    client.change(SRC);
+    let replies = client.finish();
+
+    let diagnostics = replies.diagnostics();
+    assert_eq!(diagnostics.len(), 5);
+    assert!(diagnostics[0].is_empty());
+    assert!(diagnostics[4].is_empty());
+
+    // A parser error, pointing at the token it's about
+    let [error] = diagnostics[1] else { panic!("expected one error, got {:?}", diagnostics[1]) };
+    assert_eq!(range(error.get("range")), ((1, 10), (1, 11)));
+    assert_eq!(error.get("severity").as_usize(), Some(1));
+    assert!(!error.get("message").as_str().unwrap().is_empty());
+
+    // The lexer's error isn't repeated by the parser
+    let [error] = diagnostics[2] else { panic!("expected one error, got {:?}", diagnostics[2]) };
+    assert_eq!(error.get("message").as_str(), Some("Unterminated string literal"));
+    assert_eq!(position(error.get("range").get("start")), (0, 22));
+
+    // Every problem the lexer finds is reported, not just the first
+    let messages: Vec<_> = diagnostics[3].iter().map(|error| error.get("message").as_str().unwrap()).collect();
+    assert_eq!(&messages[..2], ["Unknown character `$`", "Unknown character `@`"]);
+}
+
+#[test]
+fn reports_warnings_as_warnings() {
+    let mut client = Client::new();
+    client.open("func main() {\n  let p\u{430}ypal = 1;\n}\n");
+    let replies = client.finish();
+
+    let diagnostics = replies.diagnostics();
+    let [warning] = diagnostics[0] else { panic!("expected one warning, got {:?}", diagnostics[0]) };
+    assert_eq!(warning.get("severity").as_usize(), Some(2));
+    assert_eq!(position(warning.get("range").get("start")), (1, 6));
+}
+
+#[test]
+fn reports_unterminated_block_comments_once() {
+    let mut client = Client::new();
+    client.open("func main() {}\n#= never #= closed =#\n");
+    let replies = client.finish();
+
+    let diagnostics = replies.diagnostics();
+    let [error] = diagnostics[0] else { panic!("expected one error, got {:?}", diagnostics[0]) };
+    assert_eq!(error.get("message").as_str(), Some("Unterminated block comment"));
+    assert_eq!(position(error.get("range").get("start")), (1, 0));
+}
+
+#[test]
+fn hovers_over_functions() {
+    let mut client = Client::new();
+    client.open(SRC);
+    // The crab is two UTF-16 code units, so `square` starts at
+    // character 21, and 20 is just after the second `print`
+    let square = client.at("textDocument/hover", 8, 21);
+    let after_print = client.at("textDocument/hover", 8, 20);
+    let divide = client.at("textDocument/hover", 9, 16);
+    let main = client.at("textDocument/hover", 7, 13);
+    let print = client.at("textDocument/hover", 8, 3);
+    let nothing = client.at("textDocument/hover", 9, 6);
+    let replies = client.finish();
+
+    assert_eq!(hover_value(replies.result(square)), "```oxide\nfunc square(i32 x) ~ i32\n```\n\nMultiplies `x` by itself");
+    assert_eq!(hover_value(replies.result(divide)), "```oxide\npub func divide(i32 a, i32 b) ~ i32!\n```");
+    assert_eq!(hover_value(replies.result(main)), "```oxide\nimpure func main()\n```");
+    assert!(hover_value(replies.result(print)).contains("impure func print"));
+    assert!(hover_value(replies.result(after_print)).contains("impure func print"));
+    assert!(replies.result(nothing).is_null());
+}
+
+#[test]
+fn goes_to_definitions() {
+    let mut client = Client::new();
+    client.open(SRC);
+    let square = client.at("textDocument/definition", 8, 21);
+    let divide = client.at("textDocument/definition", 9, 15);
+    let print = client.at("textDocument/definition", 8, 2);
+    let replies = client.finish();
+
+    let location = replies.result(square);
+    assert_eq!(location.get("uri").as_str(), Some(URI));
+    assert_eq!(range(location.get("range")), ((1, 5), (1, 11)));
+    assert_eq!(range(replies.result(divide).get("range")), ((3, 9), (3, 15)));
+    // Builtins have no definition
+    assert!(replies.result(print).is_null());
+}
+
+#[test]
+fn lists_document_symbols() {
+    let mut client = Client::new();
+    client.open(SRC);
+    let symbols = client.request("textDocument/documentSymbol", Json::object([
+        ("textDocument", Json::object([("uri", URI.into())]))
+    ]));
+    // They're kept while the code doesn't parse
+    client.change("func main( {");
+    let broken = client.request("textDocument/documentSymbol", Json::object([
+        ("textDocument", Json::object([("uri", URI.into())]))
+    ]));
+    let replies = client.finish();
+
+    let symbols = replies.result(symbols).as_array().unwrap();
+    let names: Vec<_> = symbols.iter().map(|symbol| symbol.get("name").as_str().unwrap()).collect();
+    assert_eq!(names, ["square", "divide", "main"]);
+
+    assert_eq!(symbols[2].get("detail").as_str(), Some("impure func main()"));
+    assert_eq!(range(symbols[1].get("range")), ((3, 0), (5, 1)));
+    assert_eq!(range(symbols[1].get("selectionRange")), ((3, 9), (3, 15)));
+
+    assert_eq!(replies.result(broken).as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn completes_function_names() {
+    let mut client = Client::new();
+    client.open(SRC);
+    let prefixed = client.at("textDocument/completion", 10, 4);
+    let everything = client.at("textDocument/completion", 11, 0);
+    let replies = client.finish();
+
+    let labels = |id| -> Vec<String> {
+        replies.result(id).as_array().unwrap().iter()
+            .map(|item| item.get("label").as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(labels(prefixed), ["square"]);
+    assert_eq!(labels(everything), ["square", "divide", "main", "print", "panic", "assert", "assert_eq"]);
+}
+
+#[test]
+fn rejects_unknown_methods() {
+    let mut client = Client::new();
+    let id = client.request("textDocument/rename", Json::object([]));
+    // Unknown notifications are ignored
+    client.notify("$/cancelRequest", Json::object([("id", 1usize.into())]));
+    let replies = client.finish();
+
+    assert_eq!(replies.response(id).get("error").get("code"), &Json::from(-32601i64));
+}
@@ -1,4 +1,17 @@
-#[derive(Default)]
 pub struct Options {
-    pub input_path: String
+    pub input_path: String,
+    pub print_ast: bool,
+    // Whether integer arithmetic panics when the result
+    // doesn't fit in its type, rather than wrapping around
+    pub overflow_checks: bool
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            input_path: String::new(),
+            print_ast: false,
+            overflow_checks: true
+        }
+    }
 }
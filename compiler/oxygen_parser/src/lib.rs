@@ -1,22 +1,178 @@
-use std::iter::Peekable;
+use std::collections::{ HashMap, HashSet };
 
-use oxygen_error::Result;
-use oxygen_lexer::{tokenize, Tokenizer, Token, TokenKind, Keyword, LiteralKind, BinaryOperation};
+use oxygen_error::{ span_error, span_warning, ErrorEmitted, Result };
+use oxygen_lexer::{tokenize, unicode, DocStyle, Tokenizer, Token, TokenKind, Keyword, LiteralKind, BinaryOperation, Base};
+use oxygen_span::{ file_src, FileId, Span };
 use oxygen_ast::*;
 
+// The nodes of a syntax tree of the source, which the parser marks the
+// start and finish of as it goes, e.g. for oxygen_cst to build its tree
+// from. Only the outline of the code is marked: what's inside of an
+// expression is left for the tree to group by its brackets.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Node {
+    Function,
+    ParamList,
+    Param,
+    ReturnType,
+    Block,
+    LetStatement,
+    DeferStatement,
+    ExpressionStatement,
+    Expression,
+    Global,
+    ModuleDeclaration,
+    UseDeclaration,
+    ExternBlock,
+    StaticAssert
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Event {
+    Start(Node),
+    // The next token of code was taken, so it goes in the
+    // node started most recently which isn't yet finished
+    Token,
+    Finish
+}
+
+// The tokens of code, which skips comments, since they don't mean anything
+// to the parser. Doc comments are kept to one side for the functions after
+// them to pick up, and for the module, and any that don't document either
+// are errors once the file has been parsed.
+struct Tokens<'src> {
+    tokenizer: Tokenizer<'src>,
+    file: FileId,
+    peeked: Option<Option<Token<'src>>>,
+    // `##` comments since the last token of code
+    docs: Vec<Token<'src>>,
+    // The `##` comments before a token of code, where it is,
+    // and where they start
+    documented: Vec<(Span, String, Span)>,
+    // `#!` comments before the first token of code
+    module_docs: Vec<&'src str>,
+    // `#!` comments after it, which can't document the module
+    misplaced_module_docs: Vec<Span>,
+    code_started: bool,
+    // Where a block comment that runs to the end of the file starts
+    unterminated_comment: Option<Span>,
+    // Only kept when something wants the syntax tree
+    events: Option<Vec<Event>>
+}
+
+impl<'src> Tokens<'src> {
+    fn new(input: &'src str, file: FileId) -> Self {
+        Tokens {
+            tokenizer: tokenize(input),
+            file,
+            peeked: None,
+            docs: Vec::new(),
+            documented: Vec::new(),
+            module_docs: Vec::new(),
+            misplaced_module_docs: Vec::new(),
+            code_started: false,
+            unterminated_comment: None,
+            events: None
+        }
+    }
+
+    fn peek(&mut self) -> Option<&Token<'src>> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.next_code());
+        }
+        self.peeked.as_ref().unwrap().as_ref()
+    }
+
+    fn next_code(&mut self) -> Option<Token<'src>> {
+        for token in self.tokenizer.by_ref() {
+            match token.kind {
+                TokenKind::DocComment(DocStyle::Outer) => self.docs.push(token),
+                TokenKind::DocComment(DocStyle::Inner) if self.code_started => {
+                    self.misplaced_module_docs.push(token.span(self.file));
+                },
+                TokenKind::DocComment(DocStyle::Inner) => self.module_docs.push(token.string),
+                TokenKind::BlockComment { terminated: false } => self.unterminated_comment = Some(token.span(self.file)),
+                _ if token.kind.is_comment() => {},
+                _ => {
+                    if let Some(first) = self.docs.first() {
+                        let lines: Vec<_> = self.docs.iter().map(|doc| doc.string).collect();
+                        self.documented.push((token.span(self.file), doc_text(&lines), first.span(self.file)));
+                        self.docs.clear();
+                    }
+                    self.code_started = true;
+                    return Some(token);
+                }
+            }
+        }
+
+        None
+    }
+
+    // The doc comment of the item that starts at `span`
+    fn docs_before(&mut self, span: Span) -> Option<String> {
+        let idx = self.documented.iter().position(|(documented, ..)| *documented == span)?;
+        Some(self.documented.remove(idx).1)
+    }
+}
+
+impl<'src> Iterator for Tokens<'src> {
+    type Item = Token<'src>;
+
+    fn next(&mut self) -> Option<Token<'src>> {
+        let token = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.next_code()
+        };
+
+        if let (Some(events), Some(_)) = (&mut self.events, &token) {
+            events.push(Event::Token);
+        }
+        token
+    }
+}
+
+// The text of a doc comment made of `lines`, without the `##` or `#!`
+// that start them, or the space after it if there is one
+fn doc_text(lines: &[&str]) -> String {
+    lines.iter()
+        .map(|line| {
+            let text = &line[2..];
+            text.strip_prefix(' ').unwrap_or(text).trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Identifiers are compared once normalised to NFC, so a name is the same
+// whether an accent in it was typed as its own character or not
+fn normalise_ident(ident: &str) -> String {
+    unicode::nfc(ident).into_owned()
+}
+
 struct Parser<'src> {
-    tokenizer: Peekable<Tokenizer<'src>>
+    tokenizer: Tokens<'src>,
+    file: FileId,
+    // Where errors about running out of tokens point to
+    eof_span: Span
 }
 
 trait ParseableToken {
-    fn should_be_kind(&self, kind: TokenKind) -> Result<()>;
+    fn span(&self, file: FileId) -> Span;
+    fn should_be_kind(&self, kind: TokenKind, file: FileId) -> Result<()>;
     fn get_precedence(&self) -> Precedence;
 }
 
 impl<'src> ParseableToken for Token<'src> {
-    fn should_be_kind(&self, kind: TokenKind) -> Result<()> {
+    fn span(&self, file: FileId) -> Span {
+        Span::new(file, self.line, self.column)
+    }
+
+    fn should_be_kind(&self, kind: TokenKind, file: FileId) -> Result<()> {
         if self.kind != kind {
-            todo!("Handle error: 'Expected token kind {kind:?} but got {:?}'", self.kind);
+            Err(span_error(
+                self.span(file),
+                format!("Expected token kind {kind:?} but got {:?} (`{}`)", self.kind, self.string)
+            ))?;
         }
 
         Ok(())
@@ -30,15 +186,23 @@ impl<'src> ParseableToken for Token<'src> {
             TokenKind::BinOp(b) => {
                 match b {
                     BinaryOperation::Plus | BinaryOperation::Minus => Precedence::Sum,
-                    BinaryOperation::Star | BinaryOperation::Slash => Precedence::Product,
+                    BinaryOperation::Star | BinaryOperation::Slash | BinaryOperation::Percent =>
+                        Precedence::Product,
+                    BinaryOperation::StarStar => Precedence::Power,
+                    BinaryOperation::Shl | BinaryOperation::Shr => Precedence::Shift,
                     BinaryOperation::And => Precedence::BitAnd,
+                    BinaryOperation::Caret => Precedence::BitXor,
                     BinaryOperation::Or => Precedence::BitOr
                 }
             },
+            TokenKind::Keyword(Keyword::As) => Precedence::Cast,
+            TokenKind::Pipe => Precedence::Pipeline,
+            TokenKind::AndAnd => Precedence::And,
+            TokenKind::OrOr => Precedence::Or,
             TokenKind::EqEq | TokenKind::NotEq => Precedence::Equality,
             TokenKind::Greater | TokenKind::GreaterEq | TokenKind::Less | TokenKind::LessEq =>
                 Precedence::Comparison,
-            TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenCurly =>
+            TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenCurly | TokenKind::Dot =>
                 Precedence::Call,
             _ => Precedence::None
         }
@@ -46,16 +210,113 @@ impl<'src> ParseableToken for Token<'src> {
 }
 
 
+// The type named by a keyword e.g. `i32`
+fn primitive_type(keyword: &Keyword) -> Option<Type> {
+    let r#type = match keyword {
+        Keyword::I8 => Type::Int(IntTy::I8),
+        Keyword::I16 => Type::Int(IntTy::I16),
+        Keyword::I32 => Type::Int(IntTy::I32),
+        Keyword::I64 => Type::Int(IntTy::I64),
+        Keyword::U8 => Type::Int(IntTy::U8),
+        Keyword::U16 => Type::Int(IntTy::U16),
+        Keyword::U32 => Type::Int(IntTy::U32),
+        Keyword::U64 => Type::Int(IntTy::U64),
+        Keyword::F32 => Type::Float(FloatTy::F32),
+        Keyword::F64 => Type::Float(FloatTy::F64),
+        Keyword::Bool => Type::Bool,
+        Keyword::Char => Type::Char,
+        _ => return None
+    };
+
+    Some(r#type)
+}
+
 impl<'src> Parser<'src> {
-    fn new(input: &'src str) -> Self {
-        Parser { 
-            tokenizer: tokenize(input).peekable()
+    fn new(input: &'src str, file: FileId) -> Self {
+        let line = input.matches('\n').count();
+        let column = input.rsplit('\n').next().unwrap_or("").chars().count();
+
+        Parser {
+            tokenizer: Tokens::new(input, file),
+            file,
+            eof_span: Span::new(file, line, column)
+        }
+    }
+
+    // Where the next event goes, so that a node can be started there
+    // once the parser knows what it is, after taking its first tokens
+    fn mark(&self) -> usize {
+        self.tokenizer.events.as_ref().map_or(0, Vec::len)
+    }
+
+    fn start_at(&mut self, mark: usize, node: Node) {
+        if let Some(events) = &mut self.tokenizer.events {
+            events.insert(mark, Event::Start(node));
+        }
+    }
+
+    fn start(&mut self, node: Node) {
+        self.start_at(self.mark(), node);
+    }
+
+    fn finish(&mut self) {
+        if let Some(events) = &mut self.tokenizer.events {
+            events.push(Event::Finish);
         }
     }
 
+    // Parses an expression which is a node of its own in the syntax
+    // tree, e.g. the value of a `let`, rather than part of another one
+    fn parse_expression_node(&mut self) -> Result<Expression> {
+        self.start(Node::Expression);
+        let expression = self.parse_expression()?;
+        self.finish();
+
+        Ok(expression)
+    }
+
+    fn error_at(&self, token: &Token<'src>, message: String) -> ErrorEmitted {
+        span_error(token.span(self.file), message)
+    }
+
+    // A block comment that was never closed is why the file ended
+    // early, rather than whatever was expected after it
+    fn error_at_eof(&self, message: String) -> ErrorEmitted {
+        match self.tokenizer.unterminated_comment {
+            Some(span) => span_error(span, "Unterminated block comment".to_string()),
+            None => span_error(self.eof_span, message)
+        }
+    }
+
+    // Checks that the file didn't end in a block comment, and that every
+    // doc comment documents something, once everything before them has
+    // been parsed, since until then it isn't known which went unused
+    fn check_comments(&mut self) -> Result<()> {
+        if let Some(span) = self.tokenizer.unterminated_comment {
+            Err(span_error(span, "Unterminated block comment".to_string()))?;
+        }
+
+        // The `##` comments that no function picked up, including
+        // those at the end of the file, before nothing at all
+        let tokens = &mut self.tokenizer;
+        let mut misplaced: Vec<(Span, &str)> = tokens.documented.drain(..)
+            .map(|(_, _, docs)| docs)
+            .chain(tokens.docs.first().map(|docs| docs.span(self.file)))
+            .map(|span| (span, "Doc comments can only document functions"))
+            .chain(tokens.misplaced_module_docs.iter().map(|&span| (span, "Module docs can only come at the start of a module")))
+            .collect();
+        misplaced.sort_by_key(|(span, _)| (span.line, span.column));
+
+        let mut result = Ok(());
+        for (span, message) in misplaced {
+            result = Err(span_error(span, message.to_string()));
+        }
+        result
+    }
+
     fn get_next_token_or_error(&mut self) -> Result<Token<'src>> {
         let Some(token) = self.tokenizer.next() else {
-            todo!("Handle error: 'error in get_next_token_or_error: expected token'");
+            Err(self.error_at_eof("Expected another token but reached the end of the file".to_string()))?
         };
 
         Ok(token)
@@ -63,16 +324,30 @@ impl<'src> Parser<'src> {
 
     fn parse_call_params(&mut self) -> Result<CallParameters> {
         let mut params = CallParameters::new();
-        
+
         'parse_params: loop {
             let expr = self.parse_expression()?;
+            let span = expr.span;
+
+            // A name followed by a `:` names the argument
+            let argument = match (expr.kind, self.tokenizer.peek()) {
+                (ExpressionKind::Ident(name), Some(Token { kind: TokenKind::Colon, .. })) => {
+                    self.tokenizer.next();
+                    Argument { name: Some(name), value: self.parse_expression()?, span, piped: false }
+                },
+                (kind, _) => Argument { name: None, value: Expression { kind, span }, span, piped: false }
+            };
 
-            params.push(expr);
+            if argument.name.is_none() && params.iter().any(|other| other.name.is_some()) {
+                Err(span_error(span, "Positional arguments can't come after named arguments".to_string()))?;
+            }
+
+            params.push(argument);
 
             let Some(next) = self.tokenizer.peek() else {
                 // The end of the file is technically
                 // not a comma, so we return params, since
-                // it's not parse_call_params' job to 
+                // it's not parse_call_params' job to
                 // do anything other than parse the parameters.
                 return Ok(params);
             };
@@ -85,87 +360,479 @@ impl<'src> Parser<'src> {
             };
         };
 
-        return Ok(params);
+        Ok(params)
+    }
+
+    // Parses the rest of a path whose first segment has
+    // already been consumed e.g. `::world` in `hello::world`
+    fn parse_path(&mut self, first_segment: Token<'src>) -> Result<Path> {
+        let mut path = Path {
+            segments: vec![normalise_ident(first_segment.string)],
+            span: first_segment.span(self.file)
+        };
+
+        while let Some(Token { kind: TokenKind::ColonColon, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+
+            let segment = self.get_next_token_or_error()?;
+            segment.should_be_kind(TokenKind::Identifier, self.file)?;
+            path.segments.push(normalise_ident(segment.string));
+        }
+
+        Ok(path)
     }
 
     fn parse_expression(&mut self) -> Result<Expression> {
+        self.parse_precedence(Precedence::Assign)
+    }
+
+    // Parses an expression made up of operators which
+    // bind at least as tightly as `precedence`
+    fn parse_precedence(&mut self, precedence: Precedence) -> Result<Expression> {
+        let mut left = self.parse_prefix()?;
+
+        'parse_infix: loop {
+            let Some(next) = self.tokenizer.peek() else { break 'parse_infix };
+
+            // A `{` after an expression starts the block of
+            // an `if`, rather than continuing the expression
+            if next.kind == TokenKind::OpenCurly { break 'parse_infix };
+
+            let next_precedence = next.get_precedence();
+            if next_precedence == Precedence::None || next_precedence < precedence {
+                break 'parse_infix;
+            }
+
+            left = self.parse_infix(left, next_precedence)?;
+        }
+
+        Ok(left)
+    }
+
+    fn parse_infix(&mut self, left: Expression, precedence: Precedence) -> Result<Expression> {
+        let operator_token = self.tokenizer.next().unwrap();
+        let span = left.span;
+
+        let binary_operator = match &operator_token.kind {
+            TokenKind::BinOp(b) | TokenKind::BinOpEq(b) => Some(match b {
+                BinaryOperation::Plus => BinaryOperator::Plus,
+                BinaryOperation::Minus => BinaryOperator::Minus,
+                BinaryOperation::Star => BinaryOperator::Star,
+                BinaryOperation::Slash => BinaryOperator::Divide,
+                BinaryOperation::Percent => BinaryOperator::Remainder,
+                BinaryOperation::StarStar => BinaryOperator::Power,
+                BinaryOperation::And => BinaryOperator::And,
+                BinaryOperation::Or => BinaryOperator::Or,
+                BinaryOperation::Caret => BinaryOperator::Xor,
+                BinaryOperation::Shl => BinaryOperator::ShiftLeft,
+                BinaryOperation::Shr => BinaryOperator::ShiftRight
+            }),
+            TokenKind::EqEq => Some(BinaryOperator::EqEq),
+            TokenKind::NotEq => Some(BinaryOperator::NotEq),
+            TokenKind::Greater => Some(BinaryOperator::Greater),
+            TokenKind::GreaterEq => Some(BinaryOperator::GreaterEq),
+            TokenKind::Less => Some(BinaryOperator::Less),
+            TokenKind::LessEq => Some(BinaryOperator::LessEq),
+            TokenKind::AndAnd => Some(BinaryOperator::LogicalAnd),
+            TokenKind::OrOr => Some(BinaryOperator::LogicalOr),
+            _ => None
+        };
+
+        let kind = match &operator_token.kind {
+            TokenKind::Eq | TokenKind::BinOpEq(_) => {
+                if !matches!(
+                    left.kind,
+                    ExpressionKind::Ident(_) | ExpressionKind::Path(_)
+                        | ExpressionKind::Index { .. } | ExpressionKind::TupleIndex { .. }
+                ) {
+                    Err(span_error(span, "Can only assign to variables, statics, indexing expressions or tuple elements".to_string()))?;
+                }
+
+                // Assignment is right associative, e.g. `a = b = c` is `a = (b = c)`
+                let value = self.parse_precedence(Precedence::Assign)?;
+
+                ExpressionKind::Assign {
+                    target: Box::new(left),
+                    operator: binary_operator,
+                    value: Box::new(value)
+                }
+            },
+            TokenKind::OpenBracket => {
+                let index = self.parse_expression()?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseBracket, self.file)?;
+
+                ExpressionKind::Index {
+                    target: Box::new(left),
+                    index: Box::new(index)
+                }
+            },
+            TokenKind::Dot => {
+                let name = self.get_next_token_or_error()?;
+
+                // Tuple element access e.g. pair.0
+                if let TokenKind::Literal { kind: LiteralKind::Int { base: Base::Decimal, empty_int: false }, .. } = name.kind {
+                    let index = name.string.parse().map_err(|_| {
+                        self.error_at(&name, format!("Invalid tuple index `{}`", name.string))
+                    })?;
+
+                    return Ok(Expression {
+                        kind: ExpressionKind::TupleIndex { target: Box::new(left), index },
+                        span
+                    });
+                }
+
+                name.should_be_kind(TokenKind::Identifier, self.file)?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenParen, self.file)?;
+
+                let parameters = self.parse_call_params_until_close()?;
+
+                ExpressionKind::MethodCall {
+                    path: Box::new(left),
+                    name: normalise_ident(name.string),
+                    parameters
+                }
+            },
+            TokenKind::Keyword(Keyword::As) => {
+                ExpressionKind::Cast {
+                    value: Box::new(left),
+                    r#type: self.parse_type()?
+                }
+            },
+            // `x |> f(y)` is the call `f(x, y)`
+            TokenKind::Pipe => {
+                let mut call = self.parse_precedence(precedence.next())?;
+                let call_span = call.span;
+
+                let parameters = match &mut call.kind {
+                    ExpressionKind::FunctionCall { parameters, .. }
+                        | ExpressionKind::MethodCall { parameters, .. } => parameters,
+                    ExpressionKind::Ident(name) => Err(span_error(call_span, format!(
+                        "The right side of `|>` must be a call, which the value is passed to e.g. `|> {name}()`"
+                    )))?,
+                    ExpressionKind::Path(path) => Err(span_error(call_span, format!(
+                        "The right side of `|>` must be a call, which the value is passed to e.g. `|> {}()`",
+                        path.segments.join("::")
+                    )))?,
+                    // e.g. `x |> f() + 1`, which is `x |> (f() + 1)`
+                    ExpressionKind::Binary { .. } => Err(span_error(
+                        call_span,
+                        "The right side of `|>` must be a call, but `|>` binds less tightly than arithmetic, so this needs brackets e.g. `(x |> f()) + 1`".to_string()
+                    ))?,
+                    _ => Err(span_error(
+                        call_span,
+                        "The right side of `|>` must be a call, which the value is passed to as its first argument".to_string()
+                    ))?
+                };
+
+                let argument = Argument { span, value: left, name: None, piped: true };
+                parameters.get_or_insert_with(Vec::new).insert(0, argument);
+
+                return Ok(call);
+            },
+            TokenKind::OpenParen => {
+                let parameters = self.parse_call_params_until_close()?;
+
+                ExpressionKind::FunctionCall {
+                    callee: Box::new(left),
+                    parameters
+                }
+            },
+            _ => {
+                let Some(operator) = binary_operator else {
+                    Err(self.error_at(&operator_token, format!("Expected an operator but got `{}`", operator_token.string)))?
+                };
+
+                // `**` is right associative, e.g. `2 ** 3 ** 2` is `2 ** (3 ** 2)`
+                let right = match operator {
+                    BinaryOperator::Power => self.parse_precedence(precedence)?,
+                    _ => self.parse_precedence(precedence.next())?
+                };
+
+                ExpressionKind::Binary {
+                    left: Box::new(left),
+                    operator,
+                    right: Box::new(right)
+                }
+            }
+        };
+
+        Ok(Expression { kind, span })
+    }
+
+    // Parses call parameters after the `(`, up to and including the `)`
+    fn parse_call_params_until_close(&mut self) -> Result<Option<CallParameters>> {
+        let Some(close_paren_or_arg) = self.tokenizer.peek() else {
+            Err(self.error_at_eof(
+                "Expected close of function call or arguments but reached the end of the file".to_string()
+            ))?
+        };
+
+        let mut parameters: Option<CallParameters> = None;
+
+        match close_paren_or_arg.kind {
+            TokenKind::CloseParen => {},
+            _ => {
+                parameters = Some(self.parse_call_params()?);
+            }
+        }
+
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+
+        Ok(parameters)
+    }
+
+    fn parse_integer_literal(&self, token: &Token<'src>, base: &Base, suffix_start: usize) -> Result<(u128, Option<IntTy>)> {
+        let (digits, suffix) = token.string.split_at(suffix_start);
+
+        let suffix = match suffix {
+            "" => None,
+            suffix => Some(IntTy::from_name(suffix).ok_or_else(|| {
+                self.error_at(token, format!("Invalid suffix `{suffix}` for an integer literal"))
+            })?)
+        };
+
+        let (radix, digits) = match base {
+            Base::Binary => (2, &digits[2..]),
+            Base::Octal => (8, &digits[2..]),
+            Base::Hexadecimal => (16, &digits[2..]),
+            Base::Decimal => (10, digits.strip_prefix("0d").unwrap_or(digits))
+        };
+
+        let value = u128::from_str_radix(digits, radix).map_err(|err| {
+            self.error_at(token, format!("Invalid integer literal `{}` ({err})", token.string))
+        })?;
+
+        Ok((value, suffix))
+    }
+
+    // Turns the contents of a string or character literal,
+    // including the quotes, into the string it represents
+    fn unescape_string(&self, token: &Token<'src>, suffix_start: usize) -> Result<String> {
+        let contents = &token.string[1..suffix_start - 1];
+        let mut string = String::with_capacity(contents.len());
+        let mut chars = contents.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                string.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('t') => string.push('\t'),
+                Some('r') => string.push('\r'),
+                Some('0') => string.push('\0'),
+                Some('\\') => string.push('\\'),
+                Some('"') => string.push('"'),
+                Some('\'') => string.push('\''),
+                Some(c) => Err(self.error_at(token, format!("Unknown escape sequence `\\{c}`")))?,
+                None => Err(self.error_at(token, "Unfinished escape sequence".to_string()))?
+            }
+        }
+
+        Ok(string)
+    }
+
+    fn parse_literal(&mut self) -> Result<LiteralType> {
+        let token = self.tokenizer.next().unwrap();
+        let TokenKind::Literal { kind, suffix_start } = &token.kind else { unreachable!() };
+
+        match kind {
+            LiteralKind::Str(terminated) => {
+                if !terminated {
+                    Err(self.error_at(&token, "Unterminated string literal".to_string()))?;
+                }
+
+                if *suffix_start != token.string.len() {
+                    Err(self.error_at(&token, "String literals can't have a suffix".to_string()))?;
+                }
+
+                Ok(LiteralType::String(self.unescape_string(&token, *suffix_start)?))
+            },
+            LiteralKind::Char(terminated) => {
+                if !terminated {
+                    Err(self.error_at(&token, "Unterminated character literal".to_string()))?;
+                }
+
+                if *suffix_start != token.string.len() {
+                    Err(self.error_at(&token, "Character literals can't have a suffix".to_string()))?;
+                }
+
+                let string = self.unescape_string(&token, *suffix_start)?;
+                let mut chars = string.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(LiteralType::Char(c)),
+                    _ => Err(self.error_at(&token, format!(
+                        "Character literals must contain exactly one character, but `{}` doesn't", token.string
+                    )))
+                }
+            },
+            LiteralKind::Int { empty_int: true, .. } => {
+                Err(self.error_at(&token, format!("Expected digits after `{}`", token.string)))
+            },
+            LiteralKind::Int { base, .. } => {
+                let (value, suffix) = self.parse_integer_literal(&token, base, *suffix_start)?;
+                Ok(LiteralType::Integer { value, suffix })
+            },
+            LiteralKind::Float { empty_exponent: true, .. } => {
+                Err(self.error_at(&token, format!("Expected digits in the exponent of `{}`", token.string)))
+            },
+            LiteralKind::Float { base, .. } => {
+                if *base != Base::Decimal {
+                    Err(self.error_at(&token, "Float literals must be written in decimal".to_string()))?;
+                }
+
+                let (digits, suffix) = token.string.split_at(*suffix_start);
+                let suffix = match suffix {
+                    "" => None,
+                    suffix => Some(FloatTy::from_name(suffix).ok_or_else(|| {
+                        self.error_at(&token, format!("Invalid suffix `{suffix}` for a float literal"))
+                    })?)
+                };
+
+                let value = digits.parse().map_err(|err| {
+                    self.error_at(&token, format!("Invalid float literal `{}` ({err})", token.string))
+                })?;
+
+                Ok(LiteralType::Float { value, suffix })
+            }
+        }
+    }
+
+    // Parses an array literal after the `[`, either
+    // a list of elements or a repeated element
+    fn parse_array_literal(&mut self) -> Result<ExpressionKind> {
+        if let Some(Token { kind: TokenKind::CloseBracket, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+            return Ok(ExpressionKind::Array(Vec::new()));
+        }
+
+        let first = self.parse_expression()?;
+
+        if let Some(Token { kind: TokenKind::Semicolon, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+            let count = self.parse_array_length()?;
+            self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseBracket, self.file)?;
+
+            return Ok(ExpressionKind::ArrayRepeat { value: Box::new(first), count });
+        }
+
+        let mut elements = vec![first];
+        while let Some(Token { kind: TokenKind::Comma, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+
+            // Allow a trailing comma
+            if let Some(Token { kind: TokenKind::CloseBracket, .. }) = self.tokenizer.peek() { break };
+
+            elements.push(self.parse_expression()?);
+        }
+
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseBracket, self.file)?;
+
+        Ok(ExpressionKind::Array(elements))
+    }
+
+    // The `4` in `[i32; 4]` or `[0; 4]`
+    fn parse_array_length(&mut self) -> Result<usize> {
+        let token = self.get_next_token_or_error()?;
+
+        let TokenKind::Literal { kind: LiteralKind::Int { base, empty_int: false }, suffix_start } = &token.kind else {
+            Err(self.error_at(&token, format!("Expected an array length but got `{}`", token.string)))?
+        };
+
+        let (length, _) = self.parse_integer_literal(&token, base, *suffix_start)?;
+        usize::try_from(length).map_err(|_| {
+            self.error_at(&token, format!("Invalid array length `{}`", token.string))
+        })
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expression> {
         let Some(next) = self.tokenizer.peek() else {
-            todo!("Handle error: 'expected expression but no more tokens'");
+            Err(self.error_at_eof("Expected expression but reached the end of the file".to_string()))?
         };
 
-        match &next.kind {
+        let span = next.span(self.file);
+
+        let kind = match &next.kind {
             TokenKind::BinOp(BinaryOperation::Minus) => {
                 // Labelled as binary operation in tokenizer,
                 // but actually they are used as unary operations here
                 self.tokenizer.next();
-                Ok(Expression::Unary { operator: UnaryOperator::Negate, right: Box::new(self.parse_expression()?) })
+                ExpressionKind::Unary {
+                    operator: UnaryOperator::Negate,
+                    right: Box::new(self.parse_precedence(Precedence::Unary)?)
+                }
             },
             TokenKind::Bang => {
                 self.tokenizer.next();
-                Ok(Expression::Unary { operator: UnaryOperator::Not, right: Box::new(self.parse_expression()?) })
+                ExpressionKind::Unary {
+                    operator: UnaryOperator::Not,
+                    right: Box::new(self.parse_precedence(Precedence::Unary)?)
+                }
             },
-            TokenKind::Identifier => {
-                // Either ident on its own:
-                //      x, y, x. etc
-                //  or function call:
-                //      x(), hfdjksf()
-                
-                let identifier = self.tokenizer.next().unwrap();
-                let Some(next) = self.tokenizer.peek() else {
-                    return Ok(
-                        Expression::Ident(identifier.string.to_string())
-                    );
-                };
+            // Types are never parsed as expressions, so a `~` here
+            // can't be the return type of a function
+            TokenKind::Tilde => {
+                self.tokenizer.next();
+                ExpressionKind::Unary {
+                    operator: UnaryOperator::BitNot,
+                    right: Box::new(self.parse_precedence(Precedence::Unary)?)
+                }
+            },
+            TokenKind::OpenParen => {
+                self.tokenizer.next();
 
-                match next.kind {
-                    TokenKind::OpenParen => {
-                        // Function call
-                        self.tokenizer.next().unwrap();
+                // (), the only value of the unit type
+                if let Some(Token { kind: TokenKind::CloseParen, .. }) = self.tokenizer.peek() {
+                    self.tokenizer.next();
+                    return Ok(Expression { kind: ExpressionKind::Tuple(Vec::new()), span });
+                }
 
-                        let Some(close_paren_or_arg) = self.tokenizer.peek() else {
-                            todo!("Handle error: 'expected close of function call or arguments but no more tokens'");
-                        };
+                let expression = self.parse_expression()?;
 
-                        let mut parameters: Option<CallParameters> = None;
+                // Without a comma, this is just a parenthesised expression,
+                // which starts at the `(` so that e.g. `(a + b) * c` does too
+                let Some(Token { kind: TokenKind::Comma, .. }) = self.tokenizer.peek() else {
+                    self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+                    return Ok(Expression { span, ..expression });
+                };
 
-                        match close_paren_or_arg.kind {
-                            TokenKind::CloseParen => {},
-                            _ => {
-                                parameters = Some(self.parse_call_params()?);
-                            }
-                        }
+                let mut elements = vec![expression];
+                while let Some(Token { kind: TokenKind::Comma, .. }) = self.tokenizer.peek() {
+                    self.tokenizer.next();
 
-                        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen)?;
+                    // Allow a trailing comma, which is needed for
+                    // tuples with one element e.g. (1,)
+                    if let Some(Token { kind: TokenKind::CloseParen, .. }) = self.tokenizer.peek() { break };
 
-                        Ok(
-                            Expression::FunctionCall { 
-                                name: identifier.string.to_string(), 
-                                parameters
-                            }
-                        )
-                    },
-                    _ => {
-                        Ok(
-                            Expression::Ident(identifier.string.to_string())
-                        )
-                    }
-                } 
+                    elements.push(self.parse_expression()?);
+                }
+
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+
+                ExpressionKind::Tuple(elements)
             },
-            TokenKind::Literal { kind, suffix_start: _ } => {
-                match kind {
-                    LiteralKind::Str(terminated) => {
-                        if !terminated {
-                            todo!("Handle error: 'unterminated string literal'"); 
-                        }
+            TokenKind::OpenBracket => {
+                self.tokenizer.next();
+                self.parse_array_literal()?
+            },
+            TokenKind::Identifier => {
+                // Either ident on its own:
+                //      x, y, x. etc
+                //  or a path to an item:
+                //      maths::add
 
-                        let literal_token = self.tokenizer.next().unwrap();
+                let identifier = self.tokenizer.next().unwrap();
+                let mut path = self.parse_path(identifier)?;
 
-                        Ok(
-                            Expression::Literal(LiteralType::String(literal_token.string.to_string()))
-                        )
-                    },
-                    _ => todo!("Handle other literal kinds")
-                } 
+                match path.segments.len() {
+                    1 => ExpressionKind::Ident(path.segments.remove(0)),
+                    _ => ExpressionKind::Path(path)
+                }
+            },
+            TokenKind::Literal { .. } => {
+                ExpressionKind::Literal(self.parse_literal()?)
             },
             TokenKind::Keyword(k) => {
                 match k {
@@ -175,63 +842,257 @@ impl<'src> Parser<'src> {
 
                         let block = self.parse_block()?;
 
-                        Ok(Expression::IfExpression { condition, block })
+                        let mut else_branch = None;
+                        if let Some(Token { kind: TokenKind::Keyword(Keyword::Else), .. }) = self.tokenizer.peek() {
+                            self.tokenizer.next();
+
+                            else_branch = Some(match self.tokenizer.peek() {
+                                Some(Token { kind: TokenKind::Keyword(Keyword::If), .. }) => {
+                                    ElseBranch::If(Box::new(self.parse_prefix()?))
+                                },
+                                _ => ElseBranch::Block(self.parse_block()?)
+                            });
+                        }
+
+                        ExpressionKind::IfExpression { condition, block, else_branch }
+                    },
+                    Keyword::Loop => {
+                        self.tokenizer.next();
+                        ExpressionKind::Loop(self.parse_block()?)
+                    },
+                    Keyword::Break => {
+                        self.tokenizer.next();
+                        ExpressionKind::Break
+                    },
+                    Keyword::Return => {
+                        self.tokenizer.next();
+
+                        // `return` by itself returns nothing
+                        match self.tokenizer.peek() {
+                            None | Some(Token { kind: TokenKind::Semicolon | TokenKind::CloseCurly, .. }) => {
+                                ExpressionKind::Return(None)
+                            },
+                            _ => ExpressionKind::Return(Some(Box::new(self.parse_expression()?)))
+                        }
+                    },
+                    Keyword::Func => {
+                        self.tokenizer.next();
+                        self.parse_closure(false)?
+                    },
+                    Keyword::Impure => {
+                        self.tokenizer.next();
+                        self.get_next_token_or_error()?.should_be_kind(TokenKind::Keyword(Keyword::Func), self.file)?;
+                        self.parse_closure(true)?
+                    },
+                    // A function of a builtin type e.g. `u8::try_from`
+                    k if primitive_type(k).is_some() => {
+                        let name = self.tokenizer.next().unwrap();
+                        let Some(Token { kind: TokenKind::ColonColon, .. }) = self.tokenizer.peek() else {
+                            Err(self.error_at(&name, format!("Expected `::` after the type `{}`", name.string)))?
+                        };
+
+                        ExpressionKind::Path(self.parse_path(name)?)
+                    },
+                    Keyword::True => {
+                        self.tokenizer.next();
+                        ExpressionKind::Literal(LiteralType::True)
                     },
-                    _ => todo!("Handle error: 'can't start expression with {k:?}'")
+                    Keyword::False => {
+                        self.tokenizer.next();
+                        ExpressionKind::Literal(LiteralType::False)
+                    },
+                    _ => Err(span_error(span, format!("Can't start expression with {k:?}")))?
                 }
             },
             _ => {
-                todo!("Handle error: 'can't start expression with {:?}'", next.kind); 
+                Err(span_error(span, format!("Can't start expression with {:?} (`{}`)", next.kind, next.string)))?
             }
-        } 
+        };
+
+        Ok(Expression { kind, span })
     }
 
-    fn parse_statement(&mut self) -> Result<Statement> {
-        let expr = self.parse_expression()?;
-        self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon)?;
-        Ok(Statement::Expression(expr))
+    // Parses a comma separated list after the opening `(` up to and
+    // including the `)`, returning whether there was a comma, since
+    // `(x)` is just `x` but `(x,)` is a tuple of one element
+    fn parse_parenthesised<T>(&mut self, mut parse: impl FnMut(&mut Self) -> Result<T>) -> Result<(Vec<T>, bool)> {
+        let mut elements = Vec::new();
+        let mut had_comma = false;
+
+        'parse_elements: loop {
+            if let Some(Token { kind: TokenKind::CloseParen, .. }) = self.tokenizer.peek() {
+                break 'parse_elements;
+            }
+
+            elements.push(parse(self)?);
+
+            match self.tokenizer.peek() {
+                Some(Token { kind: TokenKind::Comma, .. }) => {
+                    self.tokenizer.next();
+                    had_comma = true;
+                },
+                _ => break 'parse_elements
+            }
+        }
+
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+
+        Ok((elements, had_comma))
+    }
+
+    fn parse_pattern(&mut self) -> Result<Pattern> {
+        let token = self.get_next_token_or_error()?;
+
+        match token.kind {
+            TokenKind::Identifier if token.string == "_" => Ok(Pattern::Wildcard),
+            TokenKind::Identifier => Ok(Pattern::Ident(normalise_ident(token.string))),
+            TokenKind::OpenParen => {
+                let (mut patterns, had_comma) = self.parse_parenthesised(Self::parse_pattern)?;
+
+                match (patterns.len(), had_comma) {
+                    (0, _) => Err(self.error_at(&token, "Expected a pattern inside of the `()`".to_string())),
+                    (1, false) => Ok(patterns.remove(0)),
+                    _ => Ok(Pattern::Tuple(patterns))
+                }
+            },
+            _ => Err(self.error_at(&token, format!("Expected a pattern but got `{}`", token.string)))
+        }
+    }
+
+    fn parse_let(&mut self) -> Result<Statement> {
+        let let_token = self.tokenizer.next().unwrap();
+
+        let pattern = self.parse_pattern()?;
+
+        let bindings = pattern.bindings();
+        for (idx, name) in bindings.iter().enumerate() {
+            if bindings[..idx].contains(name) {
+                Err(self.error_at(&let_token, format!("`{name}` is bound more than once in the same pattern")))?;
+            }
+        }
+
+        let mut r#type = None;
+        if let Some(Token { kind: TokenKind::Colon, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+            r#type = Some(self.parse_type()?);
+        }
+
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::Eq, self.file)?;
+        let value = self.parse_expression_node()?;
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+
+        Ok(Statement::Let {
+            pattern,
+            r#type,
+            value,
+            span: let_token.span(self.file)
+        })
+    }
+
+    fn parse_defer(&mut self) -> Result<Statement> {
+        let defer_token = self.tokenizer.next().unwrap();
+
+        // Like other blocks, a deferred block doesn't need a `;`
+        let body = match self.tokenizer.peek() {
+            Some(Token { kind: TokenKind::OpenCurly, .. }) => Deferred::Block(self.parse_block()?),
+            _ => {
+                let expression = self.parse_expression_node()?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+                Deferred::Expression(expression)
+            }
+        };
+
+        Ok(Statement::Defer {
+            body,
+            span: defer_token.span(self.file)
+        })
     }
 
     fn parse_block(&mut self) -> Result<Block> {
-        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenCurly)?;
+        self.start(Node::Block);
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenCurly, self.file)?;
+
+        let block = self.parse_statements(true)?;
 
-        let mut block = Block::new();
+        // Close curly already found inside parse_statements
+        self.tokenizer.next();
+        self.finish();
+
+        Ok(block)
+    }
+
+    // Parses the statements of a block up to its `}`, or
+    // without `braced`, up to the end of the file
+    fn parse_statements(&mut self, braced: bool) -> Result<Block> {
+        let mut block = Block { statements: Vec::new(), tail: None };
 
-        // Parse statements
         'parse_statements: loop {
             let Some(next_token) = self.tokenizer.peek() else {
-                todo!("Handle error: 'expected statement or end of function def, but no more tokens'");
+                if !braced { break 'parse_statements };
+                Err(self.error_at_eof("Expected statement or end of block but reached the end of the file".to_string()))?
             };
 
             match next_token.kind {
-                TokenKind::CloseCurly => break 'parse_statements,
+                TokenKind::CloseCurly if braced => break 'parse_statements,
+                TokenKind::Keyword(Keyword::Let) => {
+                    self.start(Node::LetStatement);
+                    block.statements.push(self.parse_let()?);
+                    self.finish();
+                    continue 'parse_statements;
+                },
+                TokenKind::Keyword(Keyword::Defer) => {
+                    self.start(Node::DeferStatement);
+                    block.statements.push(self.parse_defer()?);
+                    self.finish();
+                    continue 'parse_statements;
+                },
+                _ => {}
+            };
+
+            self.start(Node::ExpressionStatement);
+            let expr = self.parse_expression_node()?;
+
+            match self.tokenizer.peek() {
+                Some(Token { kind: TokenKind::Semicolon, .. }) => {
+                    self.tokenizer.next();
+                    block.statements.push(Statement::Expression(expr));
+                },
+                // An expression without a `;` at the end of
+                // the block is what the block evaluates to
+                Some(Token { kind: TokenKind::CloseCurly, .. }) if braced => {
+                    block.tail = Some(Box::new(expr));
+                },
+                None if !braced => {
+                    block.tail = Some(Box::new(expr));
+                },
+                // Expressions ending in a block don't need a `;`
+                _ if expr.kind.ends_with_block() => {
+                    block.statements.push(Statement::Expression(expr));
+                },
                 _ => {
-                    block.push(self.parse_statement()?);
+                    self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
                 }
-            };
+            }
+            self.finish();
         }
 
-        // Close curly already found inside parse_statements
-        self.tokenizer.next();
-
         Ok(block)
     }
 
-    #[allow(dead_code)]
     fn parse_return_type(&mut self) -> Result<ReturnType> {
         let r#type = self.parse_type()?;
-        
+
         match self.tokenizer.peek() {
             Some(t) => {
                 match t.kind {
                     TokenKind::Bang => {
                         self.tokenizer.next();
-                        return Ok((r#type, true));
+                        Ok((r#type, true))
                     },
-                    _ => return Ok((r#type, false))
+                    _ => Ok((r#type, false))
                 }
             },
-            None => return Ok((r#type, false))
+            None => Ok((r#type, false))
         }
     }
 
@@ -239,44 +1100,116 @@ impl<'src> Parser<'src> {
         // Only handle compiler types for now, but
         // later on we want to be able to have user
         // defined types!
-         
+
         match self.tokenizer.next() {
             Some(t) => {
+                if let TokenKind::Keyword(keyword) = &t.kind {
+                    if let Some(r#type) = primitive_type(keyword) {
+                        return Ok(r#type);
+                    }
+                }
+
                 match &t.kind {
-                    TokenKind::Keyword(k) => {
-                        match k {
-                            Keyword::I32 => Ok(Type::I32),
-                            _ => todo!("Handle error: 'expected type but got {k:?}'")
+                    TokenKind::Keyword(Keyword::Never) => Ok(Type::Never),
+                    TokenKind::Keyword(Keyword::Impure) => {
+                        self.get_next_token_or_error()?.should_be_kind(TokenKind::Keyword(Keyword::Func), self.file)?;
+                        self.parse_function_type(true)
+                    },
+                    TokenKind::Keyword(Keyword::Func) => self.parse_function_type(false),
+                    TokenKind::OpenParen => {
+                        let (mut types, had_comma) = self.parse_parenthesised(Self::parse_type)?;
+
+                        match (types.len(), had_comma) {
+                            (0, _) => Ok(Type::Unit),
+                            // Just a parenthesised type
+                            (1, false) => Ok(types.remove(0)),
+                            _ => Ok(Type::Tuple(types))
                         }
                     },
-                    k => {
-                        todo!("Handle error: 'expected type but got {k:?}'");
-                    }
+                    TokenKind::OpenBracket => {
+                        let element = Box::new(self.parse_type()?);
+
+                        let next = self.get_next_token_or_error()?;
+                        match next.kind {
+                            // [i32]
+                            TokenKind::CloseBracket => Ok(Type::Slice(element)),
+                            // [i32; 4]
+                            TokenKind::Semicolon => {
+                                let length = self.parse_array_length()?;
+                                self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseBracket, self.file)?;
+                                Ok(Type::Array(element, length))
+                            },
+                            _ => Err(self.error_at(&next, format!("Expected `;` or `]` but got `{}`", next.string)))
+                        }
+                    },
+                    k => Err(self.error_at(&t, format!("Expected type but got {k:?} (`{}`)", t.string)))
                 }
             },
-            None => todo!("Handle error: 'expected type but no more tokens'")
+            None => Err(self.error_at_eof("Expected type but reached the end of the file".to_string()))
         }
     }
 
+    // Parses a function type after the `func` keyword
+    fn parse_function_type(&mut self, impure: bool) -> Result<Type> {
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenParen, self.file)?;
+        let (parameters, _) = self.parse_parenthesised(Self::parse_type)?;
+
+        let mut return_type = None;
+        if let Some(Token { kind: TokenKind::Tilde, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+            return_type = Some(Box::new(self.parse_return_type()?));
+        }
+
+        Ok(Type::Function { impure, parameters, return_type })
+    }
+
     fn parse_function_parameters(&mut self) -> Result<FunctionParameters> {
         let mut params = FunctionParameters::new();
-        
+        // Every name bound by the parameters so far
+        let mut bound: Vec<String> = Vec::new();
+
         'parse_params: loop {
-            let r#type = self.parse_type()?; 
+            let Some(type_token) = self.tokenizer.peek() else {
+                Err(self.error_at_eof("Expected a parameter but reached the end of the file".to_string()))?
+            };
+            let param_span = type_token.span(self.file);
+            self.start(Node::Param);
+            let r#type = self.parse_type()?;
+
+            let Some(pattern_token) = self.tokenizer.peek() else {
+                Err(self.error_at_eof("Expected a parameter name but reached the end of the file".to_string()))?
+            };
+            let span = pattern_token.span(self.file);
+            let pattern = self.parse_pattern()?;
 
-            let identifier = self.get_next_token_or_error()?;
-            identifier.should_be_kind(TokenKind::Identifier)?;
+            for name in pattern.bindings() {
+                if bound.iter().any(|other| other == name) {
+                    Err(span_error(span, format!("Tried to define the parameter {name} again")))?;
+                }
+
+                bound.push(name.to_string());
+            }
 
-            if let Some(v) = params.insert(identifier.string.to_string(), r#type) {
-                todo!("Handle error: 'Tried to define the parameter {} again (was defined as {v:?})'",
-                    identifier.string);
+            let mut default = None;
+            if let Some(Token { kind: TokenKind::Eq, .. }) = self.tokenizer.peek() {
+                self.tokenizer.next();
+                default = Some(self.parse_expression_node()?);
             }
+            self.finish();
+
+            params.push(Param { pattern, r#type, default, span: param_span });
 
             match self.tokenizer.peek() {
                 Some(t) => {
                     match &t.kind {
                         TokenKind::Comma => {
                             self.tokenizer.next();
+
+                            // A trailing comma, e.g. after the last of
+                            // a list of parameters written one per line
+                            if let Some(Token { kind: TokenKind::CloseParen, .. }) = self.tokenizer.peek() {
+                                break 'parse_params;
+                            }
                         },
                         _ => break 'parse_params
                     }
@@ -285,96 +1218,378 @@ impl<'src> Parser<'src> {
             }
         };
 
-        return Ok(params);
+        Ok(params)
     }
 
-    fn parse_function_definition(&mut self) -> Result<Function> {
-        // Starts with next token being the identifier
-        let identifier = self.get_next_token_or_error()?;
-        identifier.should_be_kind(TokenKind::Identifier)?;
+    // Parses the `(...)` of a function or closure
+    fn parse_function_parameter_list(&mut self) -> Result<Option<FunctionParameters>> {
+        self.start(Node::ParamList);
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenParen, self.file)?;
 
-        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenParen)?;
-        
         let mut parameters: Option<FunctionParameters> = None;
 
-        match self.tokenizer.peek().unwrap_or_else(|| {
-            todo!("Handle error: 'expected function def params or block, but no more tokens'");
-        }).kind {
+        let Some(next) = self.tokenizer.peek() else {
+            Err(self.error_at_eof("Expected function parameters but reached the end of the file".to_string()))?
+        };
+
+        match next.kind {
             TokenKind::CloseParen => {},
             _ => {
                 parameters = Some(self.parse_function_parameters()?);
             }
         }
 
-        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen)?;
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+        self.finish();
+
+        Ok(parameters)
+    }
+
+    // Parses a closure after the `func` keyword
+    fn parse_closure(&mut self, impure: bool) -> Result<ExpressionKind> {
+        let parameters = self.parse_function_parameter_list()?;
+
+        // Closures are called through variables, which
+        // don't know the names or defaults of parameters
+        if let Some(param) = parameters.iter().flatten().find(|param| param.default.is_some()) {
+            Err(span_error(param.span, "Closures can't have default values for their parameters".to_string()))?;
+        }
+
+        let mut return_type = None;
+        if let Some(Token { kind: TokenKind::Tilde, .. }) = self.tokenizer.peek() {
+            self.tokenizer.next();
+            return_type = Some(self.parse_return_type()?);
+        }
+
+        let block = self.parse_block()?;
+
+        Ok(ExpressionKind::Closure { impure, parameters, return_type, block })
+    }
+
+    fn parse_function_definition(&mut self, public: bool, impure: bool, span: Span) -> Result<Function> {
+        // Starts with next token being the identifier
+        let identifier = self.get_next_token_or_error()?;
+        identifier.should_be_kind(TokenKind::Identifier, self.file)?;
+
+        let parameters = self.parse_function_parameter_list()?;
 
         let mut function = Function {
-            impure: false,
-            name: identifier.string.to_string(),
+            docs: self.tokenizer.docs_before(span),
+            public,
+            impure,
+            name: normalise_ident(identifier.string),
             parameters,
             return_type: None,
-            block: None
+            block: None,
+            span
         };
         // We need to do this match twice:
         // 1. Handle a return type
         // 2. Handle either `;` or `{}`
-        match self.tokenizer.peek().unwrap_or_else(|| {
-            todo!("Handle error: 'function definition not followed by anything'"); 
-        }).kind {
-            TokenKind::Tilde => {
-                self.tokenizer.next();
-                let r#type = self.parse_return_type()?;
-                function.return_type = Some(r#type);
-            },
-            _ => {}
+        if let Some(Token { kind: TokenKind::Tilde, .. }) = self.tokenizer.peek() {
+            self.start(Node::ReturnType);
+            self.tokenizer.next();
+            let r#type = self.parse_return_type()?;
+            function.return_type = Some(r#type);
+            self.finish();
         }
 
-        match self.tokenizer.peek().unwrap_or_else(|| {
-            todo!("Handle error: 'function definition not followed by anything'"); 
-        }).kind {
+        let Some(next) = self.tokenizer.peek() else {
+            Err(self.error_at_eof("Function definition not followed by anything".to_string()))?
+        };
+
+        match next.kind {
             TokenKind::Semicolon => { self.tokenizer.next(); },
             TokenKind::OpenCurly => { function.block = Some(self.parse_block()?); },
-            _ => todo!("Handle error: 'function definition followed by invalid token'")
+            _ => {
+                let next = self.tokenizer.next().unwrap();
+                Err(self.error_at(&next, format!(
+                    "Function definition followed by invalid token `{}`", next.string
+                )))?
+            }
         };
 
         Ok(function)
     }
 
-    fn parse_item(&mut self, token: Token<'src>) -> Result<TopLevelItem> {
-        match token.kind {
-            TokenKind::Keyword(kw) => {
-                match kw {
-                    Keyword::Func => {
-                        Ok(TopLevelItem::Function(
-                            self.parse_function_definition()?
-                        ))
-                    }
-                    _ => {
-                        todo!("Handle keyword {kw:?}");
-                    }
+    // Parses a function after any `pub`, starting with `func`, `impure`
+    // or `pure`. Only foreign functions (those without a body) can be
+    // marked `pure`, since they are impure unless they say otherwise.
+    fn parse_function_item(&mut self, token: Token<'src>, public: bool, span: Span) -> Result<Function> {
+        let mut token = token;
+
+        let impure = token.kind == TokenKind::Keyword(Keyword::Impure);
+        // `pure` isn't a keyword, since it can only go here
+        let pure = token.kind == TokenKind::Identifier && token.string == "pure";
+        if impure || pure {
+            token = self.get_next_token_or_error()?;
+        }
+        token.should_be_kind(TokenKind::Keyword(Keyword::Func), self.file)?;
+
+        let mut function = self.parse_function_definition(public, impure, span)?;
+
+        if function.block.is_none() && !pure {
+            function.impure = true;
+        } else if function.block.is_some() && pure {
+            Err(span_error(span, format!(
+                "Only foreign functions can be marked `pure`, `{}` is already pure unless marked `impure`",
+                function.name
+            )))?;
+        }
+
+        Ok(function)
+    }
+
+    fn parse_extern_block(&mut self, span: Span) -> Result<ExternBlock> {
+        let abi_token = self.get_next_token_or_error()?;
+        let abi = match (&abi_token.kind, abi_token.string) {
+            (TokenKind::Literal { kind: LiteralKind::Str(_), .. }, "\"C\"") => Abi::C,
+            (TokenKind::Literal { kind: LiteralKind::Str(_), .. }, abi) => Err(self.error_at(&abi_token, format!(
+                "Unsupported ABI {abi}, only \"C\" is supported"
+            )))?,
+            _ => Err(self.error_at(&abi_token, format!(
+                "Expected an ABI string like \"C\" after `extern`, but got `{}`", abi_token.string
+            )))?
+        };
+
+        self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenCurly, self.file)?;
+
+        let mut functions = Vec::new();
+        loop {
+            let mark = self.mark();
+            let mut token = self.get_next_token_or_error()?;
+            if token.kind == TokenKind::CloseCurly { break };
+            self.start_at(mark, Node::Function);
+
+            let function_span = token.span(self.file);
+            let public = token.kind == TokenKind::Keyword(Keyword::Pub);
+            if public {
+                token = self.get_next_token_or_error()?;
+            }
+
+            let function = self.parse_function_item(token, public, function_span)?;
+            if function.block.is_some() {
+                Err(span_error(function_span, format!(
+                    "`{}` is in an `extern` block, so it can't have a body", function.name
+                )))?;
+            }
+            self.finish();
+
+            functions.push(function);
+        }
+
+        Ok(ExternBlock { abi, functions, span })
+    }
+
+    fn parse_item(&mut self, token: Token<'src>, mark: usize) -> Result<TopLevelItem> {
+        let span = token.span(self.file);
+        let mut token = token;
+
+        let public = token.kind == TokenKind::Keyword(Keyword::Pub);
+        if public {
+            token = self.get_next_token_or_error()?;
+        }
+
+        let item = match &token.kind {
+            TokenKind::Keyword(Keyword::Func | Keyword::Impure) => {
+                self.start_at(mark, Node::Function);
+                TopLevelItem::Function(self.parse_function_item(token, public, span)?)
+            },
+            TokenKind::Identifier if token.string == "pure" => {
+                self.start_at(mark, Node::Function);
+                TopLevelItem::Function(self.parse_function_item(token, public, span)?)
+            },
+            TokenKind::Keyword(Keyword::Extern) => {
+                self.start_at(mark, Node::ExternBlock);
+                if public {
+                    Err(span_error(span, "`extern` blocks can't be `pub`, mark the functions inside them instead".to_string()))?;
                 }
+
+                TopLevelItem::Extern(self.parse_extern_block(span)?)
             },
-            _ => todo!("Handle error: 'Tried to start an item with an invalid token'") 
+            TokenKind::Keyword(keyword @ (Keyword::Const | Keyword::Static)) => {
+                self.start_at(mark, Node::Global);
+                let kind = match keyword {
+                    Keyword::Const => GlobalKind::Const,
+                    _ => GlobalKind::Static
+                };
+
+                let name = self.get_next_token_or_error()?;
+                name.should_be_kind(TokenKind::Identifier, self.file)?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Colon, self.file)?;
+                let r#type = self.parse_type()?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Eq, self.file)?;
+                let value = self.parse_expression_node()?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+
+                TopLevelItem::Global(Global {
+                    kind,
+                    public,
+                    name: normalise_ident(name.string),
+                    r#type,
+                    value,
+                    span
+                })
+            },
+            TokenKind::Keyword(Keyword::Mod) => {
+                self.start_at(mark, Node::ModuleDeclaration);
+                let name = self.get_next_token_or_error()?;
+                name.should_be_kind(TokenKind::Identifier, self.file)?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+
+                TopLevelItem::Module(ModuleDeclaration {
+                    public,
+                    name: normalise_ident(name.string),
+                    span
+                })
+            },
+            TokenKind::Keyword(Keyword::Use) => {
+                self.start_at(mark, Node::UseDeclaration);
+                let first_segment = self.get_next_token_or_error()?;
+                first_segment.should_be_kind(TokenKind::Identifier, self.file)?;
+                let path = self.parse_path(first_segment)?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+
+                TopLevelItem::Use(UseDeclaration {
+                    public,
+                    path,
+                    span
+                })
+            },
+            TokenKind::Identifier if token.string == "static_assert" => {
+                self.start_at(mark, Node::StaticAssert);
+                if public {
+                    Err(span_error(span, "A `static_assert` can't be `pub`".to_string()))?;
+                }
+
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::OpenParen, self.file)?;
+                let condition = self.parse_expression_node()?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::CloseParen, self.file)?;
+                self.get_next_token_or_error()?.should_be_kind(TokenKind::Semicolon, self.file)?;
+
+                TopLevelItem::StaticAssert(StaticAssert { condition, span })
+            },
+            k => Err(self.error_at(&token, format!("Tried to start an item with {k:?} (`{}`)", token.string)))?
+        };
+        self.finish();
+
+        Ok(item)
+    }
+
+    fn parse_program(&mut self) -> Result<Vec<TopLevelItem>> {
+        let mut items = Vec::new();
+
+        loop {
+            let mark = self.mark();
+            let Some(token) = self.tokenizer.next() else { break };
+            items.push(self.parse_item(token, mark)?);
         }
+
+        Ok(items)
     }
+}
+
+pub fn parse(file: FileId) -> Result<Ast> {
+    let src = file_src(file);
+    let mut parser = Parser::new(&src, file);
 
-    fn parse_program(&mut self) -> Result<Ast> {
-        let mut ast = Ast::new();
+    let items = parser.parse_program()?;
+    parser.check_comments()?;
+    lint_identifiers(&src, file);
 
-        while let Some(token) = self.tokenizer.next() {
-            ast.push(self.parse_item(token)?); 
+    let docs = match parser.tokenizer.module_docs.is_empty() {
+        true => None,
+        false => Some(doc_text(&parser.tokenizer.module_docs))
+    };
+    Ok(Ast { docs, items })
+}
+
+// Parses a file, returning where each node of its syntax tree starts and
+// finishes, so that a syntax tree is built by this parser rather than by
+// another copy of the grammar. If the file doesn't parse, the events stop
+// where the error was found, leaving the nodes around it unfinished.
+pub fn parse_events(file: FileId) -> (Vec<Event>, Result<()>) {
+    let src = file_src(file);
+    let mut parser = Parser::new(&src, file);
+    parser.tokenizer.events = Some(Vec::new());
+
+    let result = parser.parse_program().and_then(|_| parser.check_comments());
+    (parser.tokenizer.events.unwrap_or_default(), result)
+}
+
+// Parses a file of statements, which may end with an expression
+// that is their value, like the inside of a block, e.g. for the REPL
+pub fn parse_statements(file: FileId) -> Result<Block> {
+    let src = file_src(file);
+    let mut parser = Parser::new(&src, file);
+    // Statements aren't a module, so there's nothing for `#!` comments to document
+    parser.tokenizer.code_started = true;
+
+    let block = parser.parse_statements(false)?;
+    parser.check_comments()?;
+    lint_identifiers(&src, file);
+    Ok(block)
+}
+
+// Warns about identifiers that may not be what they look like, following
+// UTS #39, which can only happen if some of them aren't ASCII
+fn lint_identifiers(src: &str, file: FileId) {
+    if src.is_ascii() {
+        return;
+    }
+
+    // Where each identifier is first used, in the order they're used
+    let mut seen = HashSet::new();
+    let identifiers: Vec<(String, Span)> = tokenize(src)
+        .filter(|token| token.kind == TokenKind::Identifier)
+        .map(|token| (normalise_ident(token.string), token.span(file)))
+        .filter(|(name, _)| seen.insert(name.clone()))
+        .collect();
+
+    let mut skeletons: HashMap<String, &str> = HashMap::new();
+    for (name, span) in &identifiers {
+
+        if let Some(scripts) = unicode::mixed_scripts(name) {
+            let (last, rest) = scripts.split_last().unwrap();
+            span_warning(*span, format!(
+                "The identifier `{name}` mixes {} and {last} characters, so may not be what it looks like",
+                rest.join(", ")
+            ));
         }
 
-        Ok(ast)
+        // ASCII identifiers that look alike, e.g. `l1` and `ll`, are
+        // left alone, since they're easy enough to tell apart
+        let skeleton = unicode::skeleton(name);
+        match skeletons.get(&skeleton) {
+            Some(other) if !name.is_ascii() || !other.is_ascii() => {
+                span_warning(*span, format!(
+                    "The identifier `{name}` looks like `{other}`, but they are different identifiers"
+                ));
+            },
+            Some(_) => {},
+            None => { skeletons.insert(skeleton, name); }
+        }
     }
 }
 
-pub fn parse(input: &str) -> Result<()> {
-    let mut parser = Parser::new(input);
+// The source code of the expression that starts at `span`, which
+// must have been parsed already, e.g. for `assert` to show the
+// condition that failed
+pub fn expression_text(span: Span) -> String {
+    let src = file_src(span.file);
 
-    let ast = parser.parse_program()?;
+    let line_start: usize = src.split_inclusive('\n').take(span.line).map(str::len).sum();
+    let start = src[line_start..].char_indices()
+        .nth(span.column)
+        .map_or(src.len(), |(idx, _)| line_start + idx);
+    let rest = &src[start..];
 
-    println!("{ast:#?}");
+    // The expression ends where the token after it begins
+    let mut parser = Parser::new(rest, span.file);
+    let end = match parser.parse_expression() {
+        Ok(_) => parser.tokenizer.peek().map_or(rest.len(), |token| token.string.as_ptr() as usize - rest.as_ptr() as usize),
+        Err(_) => unreachable!("`{span:?}` should be the start of an expression that has already been parsed")
+    };
 
-    Ok(())
+    rest[..end].trim_end().to_string()
 }
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use oxygen_ast::{ Ast, Function, TopLevelItem };
+use oxygen_error::{ capture_diagnostics, Diagnostic };
+use oxygen_parser::{ parse, parse_statements };
+use oxygen_span::add_file;
+
+fn parse_src(src: &str) -> Ast {
+    parse(add_file(Path::new("<comments>"), src)).unwrap_or_else(|_| panic!("couldn't parse:\n{src}"))
+}
+
+fn functions(ast: &Ast) -> Vec<&Function> {
+    ast.items.iter().flat_map(|item| match item {
+        TopLevelItem::Function(function) => vec![function],
+        TopLevelItem::Extern(block) => block.functions.iter().collect(),
+        _ => Vec::new()
+    }).collect()
+}
+
+// The errors from parsing `src`, with where they point
+fn errors(src: &str) -> Vec<(usize, usize, String)> {
+    let file = add_file(Path::new("<comments>"), src);
+    let (ast, diagnostics) = capture_diagnostics(|| parse(file));
+    assert!(ast.is_err(), "parsed:\n{src}");
+    positions(diagnostics)
+}
+
+fn positions(diagnostics: Vec<Diagnostic>) -> Vec<(usize, usize, String)> {
+    diagnostics.into_iter()
+        .map(|diagnostic| {
+            let span = diagnostic.span.unwrap();
+            (span.line, span.column, diagnostic.message)
+        })
+        .collect()
+}
+
+#[test]
+fn doc_comments_are_attached_to_functions() {
+    let ast = parse_src(concat!(
+        "#!/usr/bin/env oxygen\n",
+        "#! The module\n",
+        "#!\n",
+        "#!   indented\n",
+        "\n",
+        "## Adds one\n",
+        "##to x\n",
+        "pub func f(i32 x) ~ i32 { x + 1 }\n",
+        "\n",
+        "# Not a doc comment\n",
+        "func g() {}\n",
+        "\n",
+        "const X: i32 = 1;\n",
+        "\n",
+        "extern \"C\" {\n",
+        "  ## Foreign\n",
+        "  pure func abs(i32 x) ~ i32;\n",
+        "}\n",
+        "## Before #= a block comment =#\n",
+        "#= and a line comment =#\n",
+        "# in between\n",
+        "impure func h() {}\n"
+    ));
+
+    assert_eq!(ast.docs.as_deref(), Some("The module\n\n  indented"));
+
+    let docs: Vec<_> = functions(&ast).iter().map(|function| function.docs.as_deref()).collect();
+    assert_eq!(docs, [Some("Adds one\nto x"), None, Some("Foreign"), Some("Before #= a block comment =#")]);
+}
+
+#[test]
+fn block_comments_are_skipped() {
+    let ast = parse_src("func f(#= a #= nested =# comment =# i32 x) ~ i32 {\n  x #= across\n  lines =# + 1\n}");
+    assert_eq!(functions(&ast).len(), 1);
+}
+
+#[test]
+fn unterminated_block_comments_are_errors() {
+    let error = |line, column| vec![(line, column, "Unterminated block comment".to_string())];
+
+    // Between items, where the file could otherwise end
+    assert_eq!(errors("func f() {}\n#= a #= b =#\n"), error(1, 0));
+    // In the middle of an item, rather than it being the end of the file that's wrong
+    assert_eq!(errors("func f() {\n  let x = #= 1;\n}\n"), error(1, 10));
+}
+
+#[test]
+fn doc_comments_that_document_nothing_are_errors() {
+    let error = |line, column| (line, column, "Doc comments can only document functions".to_string());
+
+    assert_eq!(errors(concat!(
+        "## A\n",
+        "const A: i32 = 1;\n",
+        "## B\n",
+        "pub static B: i32 = 2;\n",
+        "## C\n",
+        "use c::d;\n",
+        "## D\n",
+        "mod d;\n",
+        "## E\n",
+        "extern \"C\" {}\n",
+        "## F\n",
+        "static_assert(true);\n",
+        "func f() {\n",
+        "  ## G\n",
+        "  let x = 1;\n",
+        "  ## H\n",
+        "}\n",
+        "## I\n",
+        "## J\n"
+    )), [error(0, 0), error(2, 0), error(4, 0), error(6, 0), error(8, 0), error(10, 0), error(13, 2), error(15, 2), error(17, 0)]);
+
+    // Statements aren't items, so can't be documented either
+    let file = add_file(Path::new("<comments>"), "## K\nlet x = 1;\nx");
+    let (block, diagnostics) = capture_diagnostics(|| parse_statements(file));
+    assert!(block.is_err());
+    assert_eq!(positions(diagnostics), [error(0, 0)]);
+}
+
+#[test]
+fn module_docs_only_come_at_the_start_of_a_module() {
+    let error = |line, column| (line, column, "Module docs can only come at the start of a module".to_string());
+
+    assert_eq!(errors("#! Module\nfunc f() {}\n#! Too late\nfunc g() {\n  #! Inside\n}"), [error(2, 0), error(4, 2)]);
+
+    // Not even at the start of the REPL's input, which isn't a module
+    let file = add_file(Path::new("<comments>"), "#! Module\nprint(1);");
+    let (block, diagnostics) = capture_diagnostics(|| parse_statements(file));
+    assert!(block.is_err());
+    assert_eq!(positions(diagnostics), [error(0, 0)]);
+}
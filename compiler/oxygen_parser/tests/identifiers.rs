@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use oxygen_ast::TopLevelItem;
+use oxygen_error::capture_diagnostics;
+use oxygen_parser::parse;
+use oxygen_span::{ add_file, Span };
+
+// The warnings from parsing `src`, with where they point
+fn warnings(src: &str) -> Vec<(usize, usize, String)> {
+    let file = add_file(Path::new("<identifiers>"), src);
+    let (ast, diagnostics) = capture_diagnostics(|| parse(file));
+    assert!(ast.is_ok(), "couldn't parse:\n{src}");
+
+    diagnostics.into_iter()
+        .map(|diagnostic| {
+            let span = diagnostic.span.unwrap();
+            (span.line, span.column, diagnostic.message)
+        })
+        .collect()
+}
+
+#[test]
+fn identifiers_are_normalised() {
+    // The first is `ö` as one character and the second `o` with a combining
+    // diaeresis, which are the same identifier once normalised
+    let file = add_file(Path::new("<normalised>"), "func gr\u{f6}\u{df}e() {}\nfunc gro\u{308}\u{df}e() {}");
+    let ast = parse(file).ok().unwrap();
+
+    let names: Vec<&str> = ast.items.iter()
+        .map(|item| match item {
+            TopLevelItem::Function(function) => function.name.as_str(),
+            _ => unreachable!()
+        })
+        .collect();
+    assert_eq!(names, ["gr\u{f6}\u{df}e", "gr\u{f6}\u{df}e"]);
+}
+
+#[test]
+fn mixed_scripts_are_warned_about() {
+    // `а` is Cyrillic
+    assert_eq!(warnings("func m() { let pаypal = 1; }"), [
+        (0, 15, "The identifier `pаypal` mixes Latin and Cyrillic characters, so may not be what it looks like".to_string())
+    ]);
+
+    // Scripts that are written together, and digits and accents, which go with any script
+    assert_eq!(warnings("func m() { let 漢字かな = 1; let größe2 = 2; let e\u{301} = 3; let 名前 = 4; }"), []);
+}
+
+#[test]
+fn confusable_identifiers_are_warned_about() {
+    // The second `рое` is all Cyrillic, so isn't the first
+    assert_eq!(warnings("func m() { let poe = 1; let рое = 2; let mіx = 3; }"), [
+        (0, 28, "The identifier `рое` looks like `poe`, but they are different identifiers".to_string()),
+        (0, 41, "The identifier `mіx` mixes Latin and Cyrillic characters, so may not be what it looks like".to_string())
+    ]);
+
+    // Only identifiers that aren't all ASCII are compared
+    assert_eq!(warnings("func m() { let l1 = 1; let ll = 2; let größe = 3; }"), []);
+}
+
+#[test]
+fn wide_characters_take_up_two_columns() {
+    let file = add_file(Path::new("<wide>"), "let 名前 = 🦀;\nlet e\u{301} = x;");
+    assert_eq!(Span::new(file, 0, 7).display_column(), 9);
+    assert_eq!(Span::new(file, 0, 9).display_column(), 11);
+    assert_eq!(Span::new(file, 1, 7).display_column(), 6);
+    // Past the end of the line, e.g. the end of the file
+    assert_eq!(Span::new(file, 1, 12).display_column(), 11);
+}
+
+#[test]
+fn some_ligatures_take_up_three_columns() {
+    let file = add_file(Path::new("<ligature>"), "print(\"\u{17d8}\", x);");
+    assert_eq!(Span::new(file, 0, 10).display_column(), 12);
+}
@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::io::{ self, BufRead, Write };
+use std::path::Path;
+
+use oxygen_ast::{ Block, GlobalKind, Statement, TopLevelItem };
+use oxygen_error::{ early_error, span_error, Result };
+use oxygen_interpreter::{ evaluate_globals, evaluate_snippet, Globals, HostFunctions, Value };
+use oxygen_lexer::{ tokenize, Keyword, TokenKind };
+use oxygen_parser::{ parse, parse_statements };
+use oxygen_resolve::{ resolve, resolve_snippet, Crate, Module };
+use oxygen_span::add_file;
+use oxygen_typeck::{ check, check_snippet, FunctionTy, Ty, TypeckResults, Variables };
+
+const PROMPT: &str = ">> ";
+// Shown while the input so far has brackets, or a block comment, that haven't been closed
+const CONTINUATION_PROMPT: &str = ".. ";
+
+const HELP: &str = "\
+Type expressions, statements or items to run them. Variables and items
+are kept until the REPL is left, and defining an item again replaces it,
+but variables keep the values they already have.
+
+\t:ast <code>      Print the AST of the code, without running it.
+\t:tokens <code>   Print the tokens of the code.
+\t:type <expr>     Print the type of the expression, without running it.
+\t:help            Print this message.
+\t:quit            Leave the REPL (as does the end of the input).";
+
+// Whether the input has a `{`, `(` or `[`, or a block comment, that hasn't
+// been closed yet, in which case the rest of it is on the next line
+fn is_unfinished(input: &str) -> bool {
+    let mut in_comment = false;
+    let depth: isize = tokenize(input).map(|token| match token.kind {
+        TokenKind::OpenCurly | TokenKind::OpenParen | TokenKind::OpenBracket => 1,
+        TokenKind::CloseCurly | TokenKind::CloseParen | TokenKind::CloseBracket => -1,
+        TokenKind::BlockComment { terminated } => {
+            in_comment = !terminated;
+            0
+        },
+        _ => 0
+    }).sum();
+
+    depth > 0 || in_comment
+}
+
+// Whether the input defines items e.g. `func square(i32 x) ~ i32 { x * x }`,
+// rather than being statements e.g. `let f = func(i32 x) ~ i32 { x * x };`
+fn is_item(input: &str) -> bool {
+    let mut tokens = tokenize(input).filter(|token| !token.kind.is_comment());
+    let Some(first) = tokens.next() else { return false };
+
+    match first.kind {
+        TokenKind::Keyword(Keyword::Pub | Keyword::Const | Keyword::Static | Keyword::Extern | Keyword::Mod | Keyword::Use) => true,
+        TokenKind::Identifier => first.string == "static_assert" || first.string == "pure",
+        // A function has a name, and a closure doesn't
+        TokenKind::Keyword(Keyword::Func | Keyword::Impure) => {
+            let mut next = tokens.next();
+            if first.kind == TokenKind::Keyword(Keyword::Impure) {
+                next = tokens.next();
+            }
+
+            next.is_some_and(|token| token.kind == TokenKind::Identifier)
+        },
+        _ => false
+    }
+}
+
+// The names that items define, which replace the items defined before with them
+fn defined_names(items: &[TopLevelItem]) -> Vec<String> {
+    items.iter().flat_map(|item| match item {
+        TopLevelItem::Function(function) => vec![function.name.clone()],
+        TopLevelItem::Extern(block) => block.functions.iter().map(|function| function.name.clone()).collect(),
+        TopLevelItem::Global(global) => vec![global.name.clone()],
+        _ => Vec::new()
+    }).collect()
+}
+
+// How a value is shown as the result of an input, which
+// unlike `print` puts quotes around strings and characters
+fn show(value: &Value<'_>) -> String {
+    match value {
+        Value::Str(string) => format!("{:?}", &**string),
+        Value::Char(c) => format!("{c:?}"),
+        value => value.to_string()
+    }
+}
+
+// The type of an item in the crate root
+fn item_ty(krate: &Crate, name: &str) -> Option<Ty> {
+    let path = ["crate".to_string(), name.to_string()];
+
+    if let Some((_, function)) = krate.functions().find(|(other, _)| *other == path) {
+        return Some(Ty::Function(FunctionTy::of(function)));
+    }
+
+    krate.globals().find(|(other, _)| *other == path).map(|(_, global)| Ty::from_ast(&global.r#type))
+}
+
+// Whether a value of the type can have functions or closures in it
+fn holds_function(ty: &Ty) -> bool {
+    match ty {
+        Ty::Function(_) => true,
+        Ty::Array(element, _) | Ty::Slice(element) => holds_function(element),
+        Ty::Tuple(elements) => elements.iter().any(holds_function),
+        _ => false
+    }
+}
+
+// Builds a crate out of the definitions and checks it, working out
+// its consts and statics. The crate is kept until the REPL is left,
+// since variables can hold functions and closures that point into it.
+fn compile(definitions: &[Definition]) -> Result<(&'static Crate, TypeckResults, Globals<'static>)> {
+    let mut items = Vec::new();
+    for definition in definitions {
+        items.extend(parse(add_file(Path::new("<repl>"), &definition.src))?.items);
+    }
+
+    let mut krate = Crate {
+        modules: vec![Module {
+            name: "crate".to_string(),
+            parent: None,
+            file: add_file(Path::new("<repl>"), ""),
+            items,
+            docs: None,
+            children: HashMap::new()
+        }]
+    };
+    resolve(&mut krate)?;
+    let types = check(&krate)?;
+    oxygen_abi::check(&krate)?;
+
+    let krate: &'static Crate = Box::leak(Box::new(krate));
+    let globals = evaluate_globals(krate, &types)?;
+
+    Ok((krate, types, globals))
+}
+
+struct Definition {
+    names: Vec<String>,
+    src: String
+}
+
+pub struct Repl {
+    // Every item defined so far, in the order they were defined
+    definitions: Vec<Definition>,
+    // The crate built out of the definitions
+    krate: &'static Crate,
+    // What checking has worked out about everything entered so far,
+    // since code from before can still be run through a variable
+    types: TypeckResults,
+    // The values of consts, and the current values of statics
+    globals: Globals<'static>,
+    // The variables made so far, which later inputs can use
+    variables: HashMap<String, Value<'static>>,
+    variable_types: Variables,
+    host: HostFunctions,
+    overflow_checks: bool
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Repl::new()
+    }
+}
+
+impl Repl {
+    pub fn new() -> Self {
+        let Ok((krate, types, globals)) = compile(&[]) else {
+            unreachable!("a crate without any items should always compile")
+        };
+
+        Repl {
+            definitions: Vec::new(),
+            krate,
+            types,
+            globals,
+            variables: HashMap::new(),
+            variable_types: Variables::new(),
+            host: HostFunctions::standard(),
+            overflow_checks: true
+        }
+    }
+
+    // Reads inputs until the end of `input` or until `:quit` is entered,
+    // writing the prompts and what each input shows to `output`. What the
+    // code that is run prints, and any errors, aren't written to `output`.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        writeln!(output, "Oxide REPL, type :help for help")?;
+
+        let mut lines = input.lines();
+        let mut input = String::new();
+
+        loop {
+            write!(output, "{}", if input.is_empty() { PROMPT } else { CONTINUATION_PROMPT })?;
+            output.flush()?;
+
+            let Some(line) = lines.next() else {
+                writeln!(output)?;
+                return Ok(());
+            };
+
+            input.push_str(&line?);
+            input.push('\n');
+            if is_unfinished(&input) { continue };
+
+            let finished = std::mem::take(&mut input);
+            match finished.trim() {
+                "" => {},
+                ":quit" | ":q" => return Ok(()),
+                input => {
+                    // Errors have already been reported, and
+                    // don't stop what's entered next from running
+                    if let Ok(shown) = self.handle(input) {
+                        let _ = io::stdout().flush();
+                        if !shown.is_empty() {
+                            writeln!(output, "{shown}")?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Runs a complete input, returning what it shows
+    fn handle(&mut self, input: &str) -> Result<String> {
+        let (command, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+
+        match command {
+            ":help" | ":h" => Ok(HELP.to_string()),
+            ":ast" => self.show_ast(rest),
+            ":tokens" => Ok(tokenize(rest)
+                .map(|token| format!("{}:{} {:?} `{}`", token.line + 1, token.column + 1, token.kind, token.string))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            ":type" | ":t" => {
+                let mut block = self.parse_input(rest)?;
+                resolve_snippet(self.krate, self.variables.keys().cloned().collect(), &mut block)?;
+                let (_, ty, _) = check_snippet(self.krate, &block, &self.variable_types)?;
+                Ok(ty.to_string())
+            },
+            _ if command.starts_with(':') => Err(early_error(format!("No such command: {command} (type :help for a list)"))),
+            _ if is_item(input) => self.define(input).map(|_| String::new()),
+            _ => self.evaluate(input)
+        }
+    }
+
+    fn show_ast(&self, input: &str) -> Result<String> {
+        let file = add_file(Path::new("<repl>"), input);
+
+        if is_item(input) {
+            Ok(format!("{:#?}", parse(file)?))
+        } else {
+            Ok(format!("{:#?}", parse_statements(file)?))
+        }
+    }
+
+    // Parses statements, which are run at the top level of the REPL
+    fn parse_input(&self, input: &str) -> Result<Block> {
+        let block = parse_statements(add_file(Path::new("<repl>"), input))?;
+
+        for statement in &block.statements {
+            if let Statement::Defer { span, .. } = statement {
+                Err(span_error(*span, "`defer` can't be used outside of a block in the REPL, \
+                    since there's no end of a scope for it to wait for".to_string()))?;
+            }
+        }
+
+        Ok(block)
+    }
+
+    // Adds items, replacing any defined before with the same names,
+    // as long as everything defined so far still compiles with them
+    fn define(&mut self, input: &str) -> Result<()> {
+        let ast = parse(add_file(Path::new("<repl>"), input))?;
+        if ast.docs.is_some() {
+            Err(early_error("Module docs can't be written in the REPL, since it isn't a module".to_string()))?;
+        }
+        let items = ast.items;
+
+        if let Some(TopLevelItem::Module(declaration)) = items.iter().find(|item| matches!(item, TopLevelItem::Module(_))) {
+            Err(span_error(declaration.span, "Modules can't be declared in the REPL, since there's no file for them to be next to".to_string()))?;
+        }
+
+        let names = defined_names(&items);
+        let mut definitions: Vec<_> = std::mem::take(&mut self.definitions).into_iter()
+            .filter(|definition| !definition.names.iter().any(|name| names.contains(name)))
+            .collect();
+        definitions.push(Definition { names: names.clone(), src: input.to_string() });
+
+        let compiled = compile(&definitions).and_then(|compiled| {
+            self.check_types_kept(compiled.0, &names)?;
+            Ok(compiled)
+        });
+
+        let (krate, types, mut globals) = match compiled {
+            Ok(compiled) => compiled,
+            Err(error) => {
+                // Put back what was there before
+                definitions.pop();
+                self.definitions = definitions;
+                return Err(error);
+            }
+        };
+
+        let replaced: Vec<Vec<String>> = names.iter().map(|name| vec!["crate".to_string(), name.clone()]).collect();
+        globals.keep_statics(krate, &self.globals, &replaced);
+
+        self.definitions = definitions;
+        self.krate = krate;
+        self.types.extend(types);
+        self.globals = globals;
+
+        Ok(())
+    }
+
+    // Functions kept in variables and statics still call items by their
+    // names, so the type of an item can't change while they might use it
+    fn check_types_kept(&self, krate: &Crate, names: &[String]) -> Result<()> {
+        let statics = self.krate.globals()
+            .filter(|(_, global)| global.kind == GlobalKind::Static && !names.contains(&global.name))
+            .map(|(_, global)| (&global.name, Ty::from_ast(&global.r#type)));
+
+        let Some(holder) = self.variable_types.iter()
+            .map(|(name, ty)| (name, ty.clone()))
+            .chain(statics)
+            .filter(|(_, ty)| holds_function(ty))
+            .map(|(name, _)| name)
+            .min()
+        else {
+            return Ok(());
+        };
+
+        for name in names {
+            let Some(old) = item_ty(self.krate, name) else { continue };
+            let new = item_ty(krate, name).expect("the item was just defined");
+
+            if old != new {
+                Err(early_error(format!(
+                    "Can't change the type of `{name}` from `{old}` to `{new}`, \
+                    since `{holder}` holds a function that might use it"
+                )))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn evaluate(&mut self, input: &str) -> Result<String> {
+        let mut block = self.parse_input(input)?;
+
+        resolve_snippet(self.krate, self.variables.keys().cloned().collect(), &mut block)?;
+        let (types, ty, variable_types) = check_snippet(self.krate, &block, &self.variable_types)?;
+        self.types.extend(types);
+
+        // Kept until the REPL is left, like the crate,
+        // since closures made by it can be kept in variables
+        let block: &'static Block = Box::leak(Box::new(block));
+
+        let value = evaluate_snippet(
+            self.krate,
+            &self.types,
+            &mut self.globals,
+            &mut self.variables,
+            &self.host,
+            self.overflow_checks,
+            block
+        )?;
+        self.variable_types = variable_types;
+
+        if block.tail.is_some() && !matches!(value, Value::Unit) {
+            Ok(format!("{}: {ty}", show(&value)))
+        } else {
+            Ok(String::new())
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use oxygen_error::capture_diagnostics;
+use oxygen_repl::Repl;
+
+// Runs each line of `input` through a new REPL, returning the lines
+// it showed (without the greeting or prompts) and the errors it reported
+fn session(input: &str) -> (Vec<String>, Vec<String>) {
+    let mut output = Vec::new();
+    let (result, diagnostics) = capture_diagnostics(|| Repl::new().run(input.as_bytes(), &mut output));
+    result.unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let shown = output.strip_prefix("Oxide REPL, type :help for help\n").unwrap()
+        .replace(">> ", "")
+        .replace(".. ", "")
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    (shown, diagnostics.into_iter().map(|diagnostic| diagnostic.message).collect())
+}
+
+#[test]
+fn unfinished_input_continues_on_the_next_line() {
+    let (shown, errors) = session("\
+func half(
+  i32 x
+) ~ i32 {
+  x / 2
+}
+half([
+  4, 6
+][1])
+#= a block comment
+over two lines =#
+1
+");
+    assert_eq!(shown, ["3: i32", "1: i32"]);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn variables_are_kept_between_inputs() {
+    let (shown, errors) = session("\
+let x = 2;
+let y = x * 3;
+x = 10;
+(x, y)
+let y = 'y';
+y
+");
+    assert_eq!(shown, ["(10, 6): (i32, i32)", "'y': char"]);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn inputs_are_only_run_once() {
+    let (shown, errors) = session("\
+static CALLS: i32 = 0;
+impure func count() ~ i32 { CALLS = CALLS + 1; CALLS }
+let a = count();
+let b = count();
+func unrelated() {}
+(a, b, CALLS)
+");
+    assert_eq!(shown, ["(1, 2, 2): (i32, i32, i32)"]);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn redefining_an_item_keeps_the_values_of_variables() {
+    let (shown, errors) = session("\
+func sq(i32 x) ~ i32 { x * x }
+let y = sq(3);
+func sq(i32 x) ~ bool { x > 0 }
+y
+sq(3)
+");
+    assert_eq!(shown, ["9: i32", "true: bool"]);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn items_used_by_functions_in_variables_keep_their_type() {
+    let (shown, errors) = session("\
+func sq(i32 x) ~ i32 { x * x }
+let f = func(i32 x) ~ i32 { sq(x) + 1 };
+func sq(i32 x) ~ bool { x > 0 }
+func sq(i32 x) ~ i32 { x }
+f(3)
+");
+    assert_eq!(shown, ["4: i32"]);
+    assert_eq!(errors, [
+        "Can't change the type of `sq` from `func(i32) ~ i32` to `func(i32) ~ bool`, since `f` holds a function that might use it"
+    ]);
+}
+
+#[test]
+fn inputs_that_fail_change_nothing() {
+    let (shown, errors) = session("\
+let xs = [1, 2, 3];
+xs = [4, 5, 6]; let i = xs[3];
+func broken() ~ i32 { true }
+xs
+i
+");
+    assert_eq!(shown, ["[1, 2, 3]: [i32; 3]"]);
+    assert_eq!(errors, [
+        "index out of bounds: the length is 3 but the index is 3",
+        "`broken` should return `i32` but its body has type `bool`",
+        "Couldn't find a variable, function, const or static named `i`"
+    ]);
+}
+
+#[test]
+fn commands_show_code_without_running_it() {
+    let (shown, errors) = session("\
+let x = 1.5;
+:type (x, [true; 2])
+:tokens x + 1
+:ast x = 2.5;
+x
+");
+    assert_eq!(shown[0], "(f64, [bool; 2])");
+    assert_eq!(shown[1..4], [
+        "1:1 Identifier `x`",
+        "1:3 BinOp(Plus) `+`",
+        "1:5 Literal { kind: Int { base: Decimal, empty_int: false }, suffix_start: 1 } `1`"
+    ]);
+    assert_eq!(shown[4], "Block {");
+    assert!(shown.iter().any(|line| line.contains("Assign {")));
+    assert_eq!(shown.last().unwrap(), "1.5: f64");
+    assert!(errors.is_empty(), "{errors:?}");
+
+    let (shown, errors) = session(":help\n:nope\n:quit\n1\n");
+    assert!(shown[0].starts_with("Type expressions, statements or items"));
+    assert!(!shown.contains(&"1: i32".to_string()));
+    assert_eq!(errors, ["No such command: :nope (type :help for a list)"]);
+}
@@ -0,0 +1,617 @@
+use std::collections::{ HashMap, HashSet };
+use std::path::{ Path as FsPath, PathBuf };
+
+use oxygen_ast::*;
+use oxygen_ast::visit_mut::{ walk_block, walk_expression, walk_statement, VisitorMut };
+use oxygen_error::{ early_error, span_error, ErrorEmitted, Result };
+use oxygen_parser::parse;
+use oxygen_span::{ add_file, FileId, Span };
+
+// Functions provided by the compiler, which can be
+// called from anywhere without being imported
+const BUILTINS: &[&str] = &["print", "panic", "assert", "assert_eq"];
+
+// Whether `path` is `T::try_from`, which converts
+// an integer to `T` if it fits, or errors if it doesn't
+fn is_conversion(path: &Path) -> bool {
+    let [ty, function] = &path.segments[..] else { return false };
+    (IntTy::from_name(ty).is_some() || ty == "char") && function == "try_from"
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ModuleId(pub usize);
+
+impl ModuleId {
+    pub const ROOT: ModuleId = ModuleId(0);
+}
+
+#[derive(Debug)]
+pub struct Module {
+    pub name: String,
+    pub parent: Option<ModuleId>,
+    pub file: FileId,
+    pub items: Vec<TopLevelItem>,
+    // The `#!` comments at the start of its file
+    pub docs: Option<String>,
+    // Modules declared with `mod name;` inside this one
+    pub children: HashMap<String, ModuleId>
+}
+
+// Every module of a program, starting with the crate
+// root (the input file) at `ModuleId::ROOT`
+#[derive(Debug)]
+pub struct Crate {
+    pub modules: Vec<Module>
+}
+
+impl Crate {
+    pub fn root(&self) -> &Module {
+        &self.modules[ModuleId::ROOT.0]
+    }
+
+    // The absolute path of a module e.g. ["crate", "maths", "trig"]
+    pub fn module_path(&self, module: ModuleId) -> Vec<String> {
+        let mut segments = Vec::new();
+        let mut current = Some(module);
+
+        while let Some(id) = current {
+            let module = &self.modules[id.0];
+            segments.push(module.name.clone());
+            current = module.parent;
+        }
+
+        segments.reverse();
+        segments
+    }
+
+    // Every item in the crate, along with the absolute
+    // path of the module it is defined in
+    fn items(&self) -> impl Iterator<Item = (Vec<String>, &TopLevelItem)> {
+        self.modules.iter().enumerate().flat_map(move |(idx, module)| {
+            let module_path = self.module_path(ModuleId(idx));
+            module.items.iter().map(move |item| (module_path.clone(), item))
+        })
+    }
+
+    // Every function in the crate (including foreign ones) along with its
+    // absolute path, which is what paths to them are after `resolve`
+    pub fn functions(&self) -> impl Iterator<Item = (Vec<String>, &Function)> {
+        self.items().flat_map(|(module_path, item)| {
+            let functions = match item {
+                TopLevelItem::Function(function) => std::slice::from_ref(function),
+                TopLevelItem::Extern(block) => &block.functions[..],
+                _ => &[]
+            };
+
+            functions.iter().map(move |function| {
+                let mut path = module_path.clone();
+                path.push(function.name.clone());
+                (path, function)
+            })
+        })
+    }
+
+    // Every const and static in the crate along with its absolute path
+    pub fn globals(&self) -> impl Iterator<Item = (Vec<String>, &Global)> {
+        self.items().filter_map(|(mut path, item)| match item {
+            TopLevelItem::Global(global) => {
+                path.push(global.name.clone());
+                Some((path, global))
+            },
+            _ => None
+        })
+    }
+
+    pub fn static_asserts(&self) -> impl Iterator<Item = &StaticAssert> {
+        self.items().filter_map(|(_, item)| match item {
+            TopLevelItem::StaticAssert(assert) => Some(assert),
+            _ => None
+        })
+    }
+}
+
+fn read_module(path: &FsPath) -> Result<(FileId, Ast)> {
+    let src = std::fs::read_to_string(path).map_err(|err| {
+        early_error(format!("Couldn't read {}: {err}", path.display()))
+    })?;
+
+    let file = add_file(path, &src);
+
+    Ok((file, parse(file)?))
+}
+
+// Reads the crate root and every file reachable from it through
+// `mod` declarations. A module `name` declared in `dir/x.ox` is found
+// at either `dir/x/name.ox` or `dir/x/name/mod.ox`, except that the
+// crate root and `mod.ox` files look in their own directory instead.
+pub fn load_crate(root_path: &FsPath) -> Result<Crate> {
+    let mut krate = Crate { modules: Vec::new() };
+
+    let directory = root_path.parent().unwrap_or(FsPath::new("")).to_path_buf();
+    load_module(&mut krate, "crate".to_string(), None, root_path, directory)?;
+
+    Ok(krate)
+}
+
+fn load_module(
+    krate: &mut Crate,
+    name: String,
+    parent: Option<ModuleId>,
+    path: &FsPath,
+    directory: PathBuf
+) -> Result<ModuleId> {
+    let (file, Ast { docs, items }) = read_module(path)?;
+
+    let id = ModuleId(krate.modules.len());
+    krate.modules.push(Module {
+        name,
+        parent,
+        file,
+        items: Vec::new(),
+        docs,
+        children: HashMap::new()
+    });
+
+    for item in &items {
+        let TopLevelItem::Module(declaration) = item else { continue };
+
+        if krate.modules[id.0].children.contains_key(&declaration.name) {
+            Err(span_error(declaration.span, format!(
+                "The module `{}` is declared more than once", declaration.name
+            )))?;
+        }
+
+        let file_path = directory.join(format!("{}.ox", declaration.name));
+        let mod_path = directory.join(&declaration.name).join("mod.ox");
+
+        let (child_path, child_directory) = match (file_path.is_file(), mod_path.is_file()) {
+            (true, false) => (file_path, directory.join(&declaration.name)),
+            (false, true) => (mod_path, directory.join(&declaration.name)),
+            (true, true) => Err(span_error(declaration.span, format!(
+                "The module `{}` is found at both {} and {}",
+                declaration.name, file_path.display(), mod_path.display()
+            )))?,
+            (false, false) => Err(span_error(declaration.span, format!(
+                "Couldn't find a file for the module `{}` (expected {} or {})",
+                declaration.name, file_path.display(), mod_path.display()
+            )))?
+        };
+
+        let child = load_module(krate, declaration.name.clone(), Some(id), &child_path, child_directory)?;
+        krate.modules[id.0].children.insert(declaration.name.clone(), child);
+    }
+
+    krate.modules[id.0].items = items;
+
+    Ok(id)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Res {
+    // A function, const or static
+    Item { module: ModuleId },
+    Module(ModuleId)
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Binding {
+    Defined(Res),
+    // Index of the `use` in the module's items
+    Import(usize)
+}
+
+struct Name {
+    binding: Binding,
+    public: bool,
+    span: Span
+}
+
+struct Resolver {
+    names: Vec<HashMap<String, Name>>,
+    parents: Vec<Option<ModuleId>>,
+    module_names: Vec<String>,
+    // The paths of every `use`, by module and item index
+    imports: HashMap<(ModuleId, usize), Path>,
+    resolved_imports: HashMap<(ModuleId, usize), Res>,
+    // Imports currently being resolved, to catch `use` cycles
+    resolving: HashSet<(ModuleId, usize)>,
+    // The absolute path of every module
+    module_paths: Vec<Vec<String>>,
+    // Variables in scope in the function being resolved, innermost last
+    locals: Vec<HashSet<String>>
+}
+
+impl Resolver {
+    fn new(krate: &Crate) -> Result<Self> {
+        let mut resolver = Resolver {
+            names: Vec::new(),
+            parents: krate.modules.iter().map(|m| m.parent).collect(),
+            module_names: krate.modules.iter().map(|m| m.name.clone()).collect(),
+            imports: HashMap::new(),
+            resolved_imports: HashMap::new(),
+            resolving: HashSet::new(),
+            module_paths: (0..krate.modules.len()).map(|idx| krate.module_path(ModuleId(idx))).collect(),
+            locals: Vec::new()
+        };
+
+        for (idx, module) in krate.modules.iter().enumerate() {
+            let id = ModuleId(idx);
+            let mut names: HashMap<String, Name> = HashMap::new();
+
+            for (item_idx, item) in module.items.iter().enumerate() {
+                let item_names = match item {
+                    TopLevelItem::Function(function) => vec![(
+                        function.name.clone(),
+                        function.span,
+                        Binding::Defined(Res::Item { module: id }),
+                        function.public
+                    )],
+                    TopLevelItem::Extern(block) => block.functions.iter().map(|function| (
+                        function.name.clone(),
+                        function.span,
+                        Binding::Defined(Res::Item { module: id }),
+                        function.public
+                    )).collect(),
+                    TopLevelItem::Global(global) => vec![(
+                        global.name.clone(),
+                        global.span,
+                        Binding::Defined(Res::Item { module: id }),
+                        global.public
+                    )],
+                    TopLevelItem::Module(declaration) => vec![(
+                        declaration.name.clone(),
+                        declaration.span,
+                        Binding::Defined(Res::Module(module.children[&declaration.name])),
+                        declaration.public
+                    )],
+                    TopLevelItem::Use(declaration) => {
+                        // `use crate;` would bring in a name that can
+                        // never be looked up, since `crate`, `self` and
+                        // `super` always mean the module itself
+                        let last = declaration.path.segments.last().unwrap();
+                        if matches!(last.as_str(), "crate" | "self" | "super") {
+                            Err(span_error(declaration.span, format!(
+                                "`use {};` doesn't import anything, import the items inside of `{last}` instead",
+                                declaration.path.segments.join("::")
+                            )))?;
+                        }
+
+                        resolver.imports.insert((id, item_idx), declaration.path.clone());
+                        vec![(
+                            declaration.path.segments.last().unwrap().clone(),
+                            declaration.span,
+                            Binding::Import(item_idx),
+                            declaration.public
+                        )]
+                    },
+                    TopLevelItem::StaticAssert(_) => Vec::new()
+                };
+
+                for (name, name_span, binding, public) in item_names {
+                    if let Some(previous) = names.get(&name) {
+                        Err(span_error(name_span, format!(
+                            "The name `{name}` is defined multiple times (previously defined at line {})",
+                            previous.span.line + 1
+                        )))?;
+                    }
+
+                    names.insert(name, Name { binding, public, span: name_span });
+                }
+            }
+
+            resolver.names.push(names);
+        }
+
+        Ok(resolver)
+    }
+
+    fn is_ancestor_or_self(&self, ancestor: ModuleId, module: ModuleId) -> bool {
+        let mut current = Some(module);
+
+        while let Some(id) = current {
+            if id == ancestor { return true };
+            current = self.parents[id.0];
+        }
+
+        false
+    }
+
+    fn describe(&self, module: ModuleId) -> String {
+        match module {
+            ModuleId::ROOT => "the crate root".to_string(),
+            _ => format!("module `{}`", self.module_names[module.0])
+        }
+    }
+
+    // Looks up a single name inside of `module`, checking that
+    // it can be seen from `from`. Private names are visible in the
+    // module that defines them and all of its descendants.
+    fn lookup(&mut self, module: ModuleId, name: &str, from: ModuleId, span: Span) -> Result<Option<Res>> {
+        let Some(entry) = self.names[module.0].get(name) else { return Ok(None) };
+        let (binding, public) = (entry.binding, entry.public);
+
+        if !public && !self.is_ancestor_or_self(module, from) {
+            Err(span_error(span, format!("`{name}` is private to {}", self.describe(module))))?;
+        }
+
+        match binding {
+            Binding::Defined(res) => Ok(Some(res)),
+            Binding::Import(item_idx) => Ok(Some(self.resolve_import(module, item_idx)?))
+        }
+    }
+
+    fn resolve_import(&mut self, module: ModuleId, item_idx: usize) -> Result<Res> {
+        let key = (module, item_idx);
+        if let Some(res) = self.resolved_imports.get(&key) {
+            return Ok(*res);
+        }
+
+        let path = self.imports[&key].clone();
+        if !self.resolving.insert(key) {
+            Err(span_error(path.span, format!(
+                "The import `{}` refers back to itself", path.segments.join("::")
+            )))?;
+        }
+
+        let res = self.resolve_path(&path, module)?;
+
+        self.resolving.remove(&key);
+        self.resolved_imports.insert(key, res);
+
+        Ok(res)
+    }
+
+    fn resolve_path(&mut self, path: &Path, from: ModuleId) -> Result<Res> {
+        let (first, rest) = path.segments.split_first().unwrap();
+
+        let mut res = match first.as_str() {
+            "crate" => Res::Module(ModuleId::ROOT),
+            "self" => Res::Module(from),
+            "super" => match self.parents[from.0] {
+                Some(parent) => Res::Module(parent),
+                None => Err(span_error(path.span, "There is no `super` of the crate root".to_string()))?
+            },
+            name => match self.lookup(from, name, from, path.span)? {
+                Some(res) => res,
+                None => Err(span_error(path.span, format!(
+                    "Couldn't find `{name}` in {}", self.describe(from)
+                )))?
+            }
+        };
+
+        for segment in rest {
+            let Res::Module(module) = res else {
+                Err(span_error(path.span, format!(
+                    "`{}` is not a module, so it has no `{segment}`", path.segments.join("::")
+                )))?
+            };
+
+            res = match segment.as_str() {
+                "super" => match self.parents[module.0] {
+                    Some(parent) => Res::Module(parent),
+                    None => Err(span_error(path.span, "There is no `super` of the crate root".to_string()))?
+                },
+                name => match self.lookup(module, name, from, path.span)? {
+                    Some(res) => res,
+                    None => Err(span_error(path.span, format!(
+                        "Couldn't find `{name}` in {}", self.describe(module)
+                    )))?
+                }
+            };
+        }
+
+        Ok(res)
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.locals.iter().any(|scope| scope.contains(name))
+    }
+
+    fn declare_pattern(&mut self, pattern: &Pattern) {
+        let scope = self.locals.last_mut().unwrap();
+        for name in pattern.bindings() {
+            scope.insert(name.to_string());
+        }
+    }
+
+    // Turns a path to a function, const or static into the absolute
+    // path of the item it refers to, so later passes don't need to
+    // know about imports at all.
+    fn resolve_item_path(&mut self, path: &Path, from: ModuleId) -> Result<Path> {
+        match self.resolve_path(path, from)? {
+            Res::Item { module } => {
+                // Imported items keep the name they were defined
+                // with, since `use` can't rename them
+                let mut segments = self.module_paths[module.0].clone();
+                segments.push(path.segments.last().unwrap().clone());
+
+                Ok(Path { segments, span: path.span })
+            },
+            Res::Module(_) => Err(span_error(path.span, format!(
+                "Expected a function, const or static, but `{}` is a module", path.segments.join("::")
+            )))
+        }
+    }
+
+    fn resolve_expression(&mut self, expression: &mut Expression, from: ModuleId) -> Result<()> {
+        let mut body = BodyResolver { resolver: self, from, error: None };
+        body.visit_expression(expression);
+
+        body.error.map_or(Ok(()), Err)
+    }
+
+    fn resolve_block(&mut self, block: &mut Block, from: ModuleId) -> Result<()> {
+        let mut body = BodyResolver { resolver: self, from, error: None };
+        body.visit_block(block);
+
+        body.error.map_or(Ok(()), Err)
+    }
+
+    fn resolve_function(&mut self, function: &mut Function, from: ModuleId) -> Result<()> {
+        // Default values are worked out at each call that leaves them
+        // out, so they can't see the other parameters
+        self.locals = vec![HashSet::new()];
+        for default in function.parameters.iter_mut().flatten().filter_map(|param| param.default.as_mut()) {
+            self.resolve_expression(default, from)?;
+        }
+
+        let Some(block) = &mut function.block else { return Ok(()) };
+
+        for param in function.parameters.iter().flatten() {
+            self.declare_pattern(&param.pattern);
+        }
+
+        self.resolve_block(block, from)
+    }
+
+    fn resolve_global(&mut self, global: &mut Global, from: ModuleId) -> Result<()> {
+        self.locals = vec![HashSet::new()];
+        self.resolve_expression(&mut global.value, from)
+    }
+
+    fn resolve_static_assert(&mut self, assert: &mut StaticAssert, from: ModuleId) -> Result<()> {
+        self.locals = vec![HashSet::new()];
+        self.resolve_expression(&mut assert.condition, from)
+    }
+}
+
+// Resolves the names in the code of an item, such as the body of a
+// function, stopping at the first that can't be resolved. Only names
+// and the scopes of local variables matter here, so everything else is
+// left to the default walk.
+struct BodyResolver<'r> {
+    resolver: &'r mut Resolver,
+    from: ModuleId,
+    error: Option<ErrorEmitted>
+}
+
+impl BodyResolver<'_> {
+    fn resolve_expression(&mut self, expression: &mut Expression) -> Result<()> {
+        let (resolver, from) = (&mut *self.resolver, self.from);
+
+        match &mut expression.kind {
+            ExpressionKind::Ident(name) => {
+                if resolver.is_local(name) { return Ok(()) };
+
+                let path = Path { segments: vec![name.clone()], span: expression.span };
+
+                // Builtins can be shadowed by items of the same name
+                let is_builtin = BUILTINS.contains(&name.as_str())
+                    && !resolver.names[from.0].contains_key(name);
+
+                expression.kind = if is_builtin {
+                    ExpressionKind::Path(path)
+                } else if resolver.names[from.0].contains_key(name) {
+                    ExpressionKind::Path(resolver.resolve_item_path(&path, from)?)
+                } else {
+                    Err(span_error(expression.span, format!("Couldn't find a variable, function, const or static named `{name}`")))?
+                };
+            },
+            ExpressionKind::Path(path) => {
+                let ty = &path.segments[0];
+                let is_primitive = IntTy::from_name(ty).is_some() || FloatTy::from_name(ty).is_some()
+                    || matches!(ty.as_str(), "bool" | "char");
+
+                // Functions of builtin types, such as `u8::try_from`,
+                // are builtins rather than items
+                if !is_primitive {
+                    *path = resolver.resolve_item_path(path, from)?;
+                } else if !is_conversion(path) {
+                    Err(span_error(path.span, format!(
+                        "There is no function `{}` on the type `{ty}`", path.segments[1..].join("::")
+                    )))?;
+                }
+            },
+            ExpressionKind::Closure { parameters, block, .. } => {
+                resolver.locals.push(HashSet::new());
+                for param in parameters.iter().flatten() {
+                    resolver.declare_pattern(&param.pattern);
+                }
+
+                self.visit_block(block);
+                self.resolver.locals.pop();
+            },
+            _ => walk_expression(self, expression)
+        }
+
+        Ok(())
+    }
+}
+
+impl VisitorMut for BodyResolver<'_> {
+    fn visit_block(&mut self, block: &mut Block) {
+        self.resolver.locals.push(HashSet::new());
+        walk_block(self, block);
+        self.resolver.locals.pop();
+    }
+
+    // Deferred code can only see the variables before
+    // it, which the default walk already keeps to
+    fn visit_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Let { pattern, value, .. } => {
+                // The variables only exist after their value
+                self.visit_expression(value);
+                self.resolver.declare_pattern(pattern);
+            },
+            _ => walk_statement(self, statement)
+        }
+    }
+
+    fn visit_expression(&mut self, expression: &mut Expression) {
+        if self.error.is_some() { return };
+
+        if let Err(error) = self.resolve_expression(expression) {
+            self.error = Some(error);
+        }
+    }
+}
+
+// Resolves every `use` and every path in the crate, reporting
+// anything that can't be found or isn't visible from where it's used.
+// Afterwards, every `Ident` expression refers to a local variable,
+// and every `Path` expression is either the absolute path of a
+// function, const or static (starting with `crate`) or the name
+// of a builtin, such as `print` or `u8::try_from`.
+pub fn resolve(krate: &mut Crate) -> Result<()> {
+    let mut resolver = Resolver::new(krate)?;
+
+    for (idx, module) in krate.modules.iter_mut().enumerate() {
+        let id = ModuleId(idx);
+
+        for (item_idx, item) in module.items.iter_mut().enumerate() {
+            match item {
+                TopLevelItem::Use(_) => {
+                    resolver.resolve_import(id, item_idx)?;
+                },
+                TopLevelItem::Function(function) => {
+                    resolver.resolve_function(function, id)?;
+                },
+                TopLevelItem::Global(global) => {
+                    resolver.resolve_global(global, id)?;
+                },
+                TopLevelItem::Extern(block) => {
+                    for function in &mut block.functions {
+                        resolver.resolve_function(function, id)?;
+                    }
+                },
+                TopLevelItem::StaticAssert(assert) => {
+                    resolver.resolve_static_assert(assert, id)?;
+                },
+                TopLevelItem::Module(_) => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Resolves `block` as if it were the body of a function in the crate
+// root, which must have been resolved already, that starts with
+// `variables` in scope, e.g. for code in the REPL
+pub fn resolve_snippet(krate: &Crate, variables: HashSet<String>, block: &mut Block) -> Result<()> {
+    let mut resolver = Resolver::new(krate)?;
+    resolver.locals = vec![variables];
+
+    resolver.resolve_block(block, ModuleId::ROOT)
+}
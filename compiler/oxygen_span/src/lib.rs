@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::path::{ Path, PathBuf };
+use std::rc::Rc;
+
+mod width;
+
+// Identifies a file that has been added to the source map
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FileId(usize);
+
+// A position in a source file. Both `line` and `column`
+// are 0-based, like the lexer's, and are only made 1-based
+// when displayed to the user.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Span {
+    pub file: FileId,
+    pub line: usize,
+    pub column: usize
+}
+
+impl Span {
+    pub fn new(file: FileId, line: usize, column: usize) -> Span {
+        Span {
+            file,
+            line,
+            column
+        }
+    }
+
+    // The column as it's shown in a terminal, where wide characters such
+    // as CJK and emoji take up two columns and combining accents none,
+    // so that it lines up with what the user sees in their editor
+    pub fn display_column(self) -> usize {
+        let src = file_src(self.file);
+        match src.lines().nth(self.line) {
+            Some(line) => {
+                let width: usize = line.chars().take(self.column).map(char_width).sum();
+                // Anything past the end of the line, e.g. the end of the file
+                width + self.column.saturating_sub(line.chars().count())
+            },
+            None => self.column
+        }
+    }
+}
+
+// How many columns `c` takes up in a terminal
+pub fn char_width(c: char) -> usize {
+    let within = |table: &[(char, char)]| table
+        .binary_search_by(|&(start, end)| {
+            if end < c { std::cmp::Ordering::Less }
+            else if start > c { std::cmp::Ordering::Greater }
+            else { std::cmp::Ordering::Equal }
+        })
+        .is_ok();
+
+    if c.is_ascii() { 1 }
+    else if within(width::WIDE) { 2 }
+    else if within(width::THREE_WIDE) { 3 }
+    else if within(width::ZERO_WIDTH) { 0 }
+    else { 1 }
+}
+
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub src: Rc<str>
+}
+
+thread_local! {
+    // Every file read during compilation lives here, so that
+    // anything holding a `Span` can find out where it came from
+    // without needing to carry the file name around.
+    static SOURCE_MAP: RefCell<Vec<SourceFile>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn add_file(path: &Path, src: &str) -> FileId {
+    SOURCE_MAP.with(|map| {
+        let mut map = map.borrow_mut();
+        map.push(SourceFile { path: path.to_path_buf(), src: Rc::from(src) });
+        FileId(map.len() - 1)
+    })
+}
+
+// Replaces the source of a file that has been edited, e.g. in an editor,
+// so spans into the old source now point into the new one
+pub fn set_file_src(file: FileId, src: &str) {
+    SOURCE_MAP.with(|map| map.borrow_mut()[file.0].src = Rc::from(src));
+}
+
+pub fn file_path(file: FileId) -> PathBuf {
+    SOURCE_MAP.with(|map| map.borrow()[file.0].path.clone())
+}
+
+pub fn file_src(file: FileId) -> Rc<str> {
+    SOURCE_MAP.with(|map| map.borrow()[file.0].src.clone())
+}
@@ -0,0 +1,1327 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use oxygen_ast::*;
+use oxygen_error::{ span_error, span_warning, Result };
+use oxygen_resolve::Crate;
+use oxygen_span::Span;
+
+// How many values an array made by repeating a value can hold, counting
+// those inside of its elements, so that a huge one is caught here
+// rather than running out of memory when the program is run
+const MAX_REPEATED_VALUES: usize = 1_000_000;
+
+// The type of a value, as opposed to `oxygen_ast::Type`
+// which is a type as written in the source
+#[derive(Clone, PartialEq, Debug)]
+pub enum Ty {
+    Int(IntTy),
+    Float(FloatTy),
+    Bool,
+    Char,
+    Str,
+    // What expressions that don't produce a value have,
+    // such as functions without a return type
+    Unit,
+    // What expressions that never finish have, such as a call to
+    // `panic` or a `return`, which can be used as any other type
+    Never,
+    Array(Box<Ty>, usize),
+    Slice(Box<Ty>),
+    Tuple(Vec<Ty>),
+    Function(FunctionTy)
+}
+
+// The type of a function or closure, along with its effects
+#[derive(Clone, PartialEq, Debug)]
+pub struct FunctionTy {
+    pub impure: bool,
+    pub parameters: Vec<Ty>,
+    pub return_ty: Box<Ty>,
+    // Whether the return type has a `!`
+    pub fallible: bool
+}
+
+impl FunctionTy {
+    pub fn of(function: &Function) -> FunctionTy {
+        FunctionTy::from_ast(
+            function.impure,
+            function.parameters.iter().flatten().map(|param| &param.r#type),
+            function.return_type.as_ref()
+        )
+    }
+
+    fn from_ast<'t>(impure: bool, parameters: impl Iterator<Item = &'t Type>, return_type: Option<&ReturnType>) -> FunctionTy {
+        FunctionTy {
+            impure,
+            parameters: parameters.map(Ty::from_ast).collect(),
+            return_ty: Box::new(return_type.map_or(Ty::Unit, |(r#type, _)| Ty::from_ast(r#type))),
+            fallible: return_type.is_some_and(|(_, fallible)| *fallible)
+        }
+    }
+}
+
+impl Ty {
+    pub fn from_ast(r#type: &Type) -> Ty {
+        match r#type {
+            Type::Int(ty) => Ty::Int(*ty),
+            Type::Float(ty) => Ty::Float(*ty),
+            Type::Bool => Ty::Bool,
+            Type::Char => Ty::Char,
+            Type::Unit => Ty::Unit,
+            Type::Never => Ty::Never,
+            Type::Array(element, length) => Ty::Array(Box::new(Ty::from_ast(element)), *length),
+            Type::Slice(element) => Ty::Slice(Box::new(Ty::from_ast(element))),
+            Type::Tuple(elements) => Ty::Tuple(elements.iter().map(Ty::from_ast).collect()),
+            Type::Function { impure, parameters, return_type } => Ty::Function(FunctionTy::from_ast(
+                *impure,
+                parameters.iter(),
+                return_type.as_deref()
+            ))
+        }
+    }
+
+    // Whether a value of this type can be used where a value
+    // of `expected` is needed. Arrays can be used as slices
+    // of the same element type, functions can be used where
+    // one with more effects (`impure` or `!`) is expected, and
+    // `never` can be used as anything, since it never happens.
+    pub fn coerces_to(&self, expected: &Ty) -> bool {
+        match (self, expected) {
+            (Ty::Never, _) => true,
+            (Ty::Function(function), Ty::Function(expected_function)) => {
+                function.parameters == expected_function.parameters
+                    && function.return_ty == expected_function.return_ty
+                    && (!function.impure || expected_function.impure)
+                    && (!function.fallible || expected_function.fallible)
+            },
+            (Ty::Array(element, _), Ty::Slice(expected_element)) => element == expected_element,
+            (Ty::Tuple(elements), Ty::Tuple(expected_elements)) => {
+                elements.len() == expected_elements.len()
+                    && elements.iter().zip(expected_elements).all(|(element, expected)| element.coerces_to(expected))
+            },
+            _ => self == expected
+        }
+    }
+
+    // The element type of an array or slice
+    pub fn element(&self) -> Option<&Ty> {
+        match self {
+            Ty::Array(element, _) | Ty::Slice(element) => Some(element),
+            _ => None
+        }
+    }
+
+    // How many values the interpreter stores for a value of this type,
+    // counting every element of an array or tuple inside of it
+    fn values(&self) -> usize {
+        match self {
+            Ty::Array(element, length) => element.values().saturating_mul(*length),
+            Ty::Tuple(elements) => elements.iter().fold(0, |total, element| total.saturating_add(element.values())),
+            _ => 1
+        }
+    }
+}
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Int(ty) => write!(f, "{}", ty.name()),
+            Ty::Float(ty) => write!(f, "{}", ty.name()),
+            Ty::Bool => write!(f, "bool"),
+            Ty::Char => write!(f, "char"),
+            Ty::Str => write!(f, "str"),
+            Ty::Unit => write!(f, "()"),
+            Ty::Never => write!(f, "never"),
+            Ty::Array(element, length) => write!(f, "[{element}; {length}]"),
+            Ty::Slice(element) => write!(f, "[{element}]"),
+            Ty::Tuple(elements) => {
+                write!(f, "(")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx != 0 { write!(f, ", ")? };
+                    write!(f, "{element}")?;
+                }
+                // (i32,) is a tuple, but (i32) is just an i32
+                if elements.len() == 1 { write!(f, ",")? };
+                write!(f, ")")
+            },
+            Ty::Function(function) => {
+                if function.impure { write!(f, "impure ")? };
+                write!(f, "func(")?;
+                for (idx, parameter) in function.parameters.iter().enumerate() {
+                    if idx != 0 { write!(f, ", ")? };
+                    write!(f, "{parameter}")?;
+                }
+                write!(f, ")")?;
+                if *function.return_ty != Ty::Unit || function.fallible {
+                    write!(f, " ~ {}", function.return_ty)?;
+                }
+                if function.fallible { write!(f, "!")? };
+                Ok(())
+            }
+        }
+    }
+}
+
+// What an integer method, e.g. `saturating_add`, does
+// when the result doesn't fit in the type
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OverflowMode {
+    // Wraps around, whether or not overflow checks are on
+    Wrapping,
+    // Stays at the largest or smallest value of the type
+    Saturating,
+    // Fails, whether or not overflow checks are on
+    Checked
+}
+
+// The methods of integers, which are the arithmetic operators
+// with a choice of what happens when the result doesn't fit
+pub fn integer_method(name: &str) -> Option<(OverflowMode, BinaryOperator)> {
+    let (mode, operation) = name.split_once('_')?;
+
+    let mode = match mode {
+        "wrapping" => OverflowMode::Wrapping,
+        "saturating" => OverflowMode::Saturating,
+        "checked" => OverflowMode::Checked,
+        _ => return None
+    };
+
+    let operator = match operation {
+        "add" => BinaryOperator::Plus,
+        "sub" => BinaryOperator::Minus,
+        "mul" => BinaryOperator::Star,
+        "div" => BinaryOperator::Divide,
+        "rem" => BinaryOperator::Remainder,
+        "pow" => BinaryOperator::Power,
+        _ => return None
+    };
+
+    Some((mode, operator))
+}
+
+// What a `return` in the code being checked returns from
+enum ReturnTarget {
+    // The value of a const or static, or the default
+    // value of a parameter, which can't use `return`
+    Nothing,
+    // Code run by a `defer`, after its scope has already been left
+    Deferred,
+    Function(Ty),
+    // A closure without a return type, which is worked out from its
+    // body and the types of the values its `return`s give
+    Inferred(Vec<(Ty, Span)>)
+}
+
+struct Checker<'a> {
+    signatures: &'a HashMap<Vec<String>, FunctionTy>,
+    functions: &'a HashMap<Vec<String>, &'a Function>,
+    globals: &'a HashMap<Vec<String>, (GlobalKind, Ty)>,
+    // Variables in scope, innermost last
+    scopes: Vec<HashMap<String, Ty>>,
+    // Whether the function or closure being checked is `impure`,
+    // and so is allowed to call impure functions
+    impure: bool,
+    returns: ReturnTarget,
+    // Every `loop` around the code being checked, innermost last,
+    // along with whether it has a `break`
+    loops: Vec<bool>,
+    literals: HashMap<Span, Ty>
+}
+
+// What checking worked out about the crate that isn't
+// written in it, which is needed to run the program
+#[derive(Default, Debug)]
+pub struct TypeckResults {
+    // The type of every integer and float literal, by where
+    // it is, since ones without a suffix take their type
+    // from how they're used e.g. `let x: u8 = 4;`
+    literals: HashMap<Span, Ty>
+}
+
+impl TypeckResults {
+    pub fn literal_type(&self, span: Span) -> &Ty {
+        &self.literals[&span]
+    }
+
+    // Adds what a later check worked out, e.g. in the REPL, where
+    // code checked before can still be run from a variable
+    pub fn extend(&mut self, other: TypeckResults) {
+        self.literals.extend(other.literals);
+    }
+}
+
+// The types of the variables in scope by name, e.g. those made by code in the REPL
+pub type Variables = HashMap<String, Ty>;
+
+// How a call's callee is described in errors
+fn describe_callee(callee: &Expression) -> String {
+    match &callee.kind {
+        ExpressionKind::Path(path) if path.segments[0] != "crate" => format!("builtin `{}`", path.segments.join("::")),
+        ExpressionKind::Path(path) => format!("function `{}`", path.segments.join("::")),
+        ExpressionKind::Ident(name) => format!("function `{name}`"),
+        _ => "function".to_string()
+    }
+}
+
+fn statement_span(statement: &Statement) -> Span {
+    match statement {
+        Statement::Expression(expression) => expression.span,
+        Statement::Let { span, .. } => *span,
+        Statement::Defer { span, .. } => *span
+    }
+}
+
+// Whether `expression` is an integer or float literal without
+// a suffix, whose type depends on where it is used
+fn is_untyped_literal(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Literal(LiteralType::Integer { suffix, .. }) => suffix.is_none(),
+        ExpressionKind::Literal(LiteralType::Float { suffix, .. }) => suffix.is_none(),
+        ExpressionKind::Unary { operator: UnaryOperator::Negate, right } => is_untyped_literal(right),
+        _ => false
+    }
+}
+
+// The value of an integer or character literal, which
+// can be negative e.g. `-1`, or `None` for anything else
+fn literal_value(expression: &Expression) -> Option<i128> {
+    match &expression.kind {
+        ExpressionKind::Literal(LiteralType::Integer { value, .. }) => i128::try_from(*value).ok(),
+        ExpressionKind::Literal(LiteralType::Char(c)) => Some(*c as i128),
+        ExpressionKind::Unary { operator: UnaryOperator::Negate, right } => literal_value(right).map(|value| -value),
+        _ => None
+    }
+}
+
+fn mismatch(span: Span, expected: &Ty, found: &Ty) -> oxygen_error::ErrorEmitted {
+    span_error(span, format!("Mismatched types: expected `{expected}` but found `{found}`"))
+}
+
+impl<'a> Checker<'a> {
+    fn lookup(&self, name: &str) -> Option<&Ty> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn check_function(&mut self, function: &Function, impure: bool) -> Result<()> {
+        let signature = FunctionTy::of(function);
+
+        // Default values are worked out by each call that leaves them
+        // out, and are pure so that doing so can't have any effects
+        self.impure = false;
+        self.scopes = vec![HashMap::new()];
+        self.returns = ReturnTarget::Nothing;
+        self.loops = Vec::new();
+        for (param, ty) in function.parameters.iter().flatten().zip(&signature.parameters) {
+            if let Some(default) = &param.default {
+                self.check_expression_is(default, ty)?;
+            }
+        }
+
+        let Some(block) = &function.block else { return Ok(()) };
+
+        self.impure = impure;
+        self.returns = ReturnTarget::Function((*signature.return_ty).clone());
+        for (param, ty) in function.parameters.iter().flatten().zip(&signature.parameters) {
+            self.bind_pattern(&param.pattern, ty, param.span)?;
+        }
+
+        let ty = self.check_block(block, Some(&signature.return_ty))?;
+        if !ty.coerces_to(&signature.return_ty) {
+            let span = block.tail.as_ref().map_or(function.span, |tail| tail.span);
+            Err(span_error(span, format!(
+                "`{}` should return `{}` but its body has type `{ty}`", function.name, signature.return_ty
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    fn check_block(&mut self, block: &Block, expected: Option<&Ty>) -> Result<Ty> {
+        self.scopes.push(HashMap::new());
+        let ty = self.check_block_in_scope(block, expected)?;
+        self.scopes.pop();
+
+        Ok(ty)
+    }
+
+    // Checks the block's statements in the innermost scope, so
+    // that the variables they make are left in it afterwards
+    fn check_block_in_scope(&mut self, block: &Block, expected: Option<&Ty>) -> Result<Ty> {
+        // The first statement that never finishes,
+        // after which the rest of the block can't be reached
+        let mut diverges_at: Option<Span> = None;
+        let mut warned = false;
+        let mut warn_unreachable = |diverges_at: Option<Span>, span: Span| {
+            if let (Some(diverges_at), false) = (diverges_at, warned) {
+                span_warning(span, format!(
+                    "Unreachable code, since the code at {}:{} never finishes", diverges_at.line + 1, diverges_at.display_column() + 1
+                ));
+                warned = true;
+            }
+        };
+
+        for statement in &block.statements {
+            warn_unreachable(diverges_at, statement_span(statement));
+
+            if self.check_statement(statement)? == Ty::Never && diverges_at.is_none() {
+                diverges_at = Some(statement_span(statement));
+            }
+        }
+
+        let mut ty = match &block.tail {
+            Some(tail) => {
+                warn_unreachable(diverges_at, tail.span);
+                self.check_expression(tail, expected)?
+            },
+            None => Ty::Unit
+        };
+
+        if diverges_at.is_some() {
+            ty = Ty::Never;
+        }
+
+        Ok(ty)
+    }
+
+    // Returns the type of the statement's expression, so
+    // that statements which never finish can be found
+    fn check_statement(&mut self, statement: &Statement) -> Result<Ty> {
+        let statement_ty = match statement {
+            Statement::Expression(expression) => {
+                self.check_expression(expression, None)?
+            },
+            Statement::Let { pattern, r#type, value, span } => {
+                let annotation = r#type.as_ref().map(Ty::from_ast);
+                let ty = self.check_expression(value, annotation.as_ref())?;
+                // Unless the variable is explicitly `()`
+                if annotation != Some(Ty::Unit) {
+                    self.check_value_used(value, &ty)?;
+                }
+
+                let ty = match annotation {
+                    Some(annotation) => {
+                        if !ty.coerces_to(&annotation) {
+                            Err(mismatch(value.span, &annotation, &ty))?;
+                        }
+                        annotation
+                    },
+                    None => ty
+                };
+
+                self.bind_pattern(pattern, &ty, *span)?;
+                ty
+            },
+            Statement::Defer { body, .. } => {
+                // Deferred code is run as its scope is being left, so it
+                // can't leave it again with a `return` or `break`. It is
+                // checked as part of the function, so can't be impure
+                // unless the function is.
+                let outer_returns = std::mem::replace(&mut self.returns, ReturnTarget::Deferred);
+                let outer_loops = std::mem::take(&mut self.loops);
+
+                match body {
+                    Deferred::Expression(expression) => self.check_expression(expression, None)?,
+                    Deferred::Block(block) => self.check_block(block, None)?
+                };
+
+                self.returns = outer_returns;
+                self.loops = outer_loops;
+
+                // Even if the deferred code never finishes,
+                // the code after the `defer` still runs first
+                Ty::Unit
+            }
+        };
+
+        Ok(statement_ty)
+    }
+
+    // Declares the variables of `pattern` in the current
+    // scope, given it matches a value of type `ty`
+    fn bind_pattern(&mut self, pattern: &Pattern, ty: &Ty, span: Span) -> Result<()> {
+        match (pattern, ty) {
+            (Pattern::Ident(name), _) => {
+                self.scopes.last_mut().unwrap().insert(name.clone(), ty.clone());
+            },
+            (Pattern::Wildcard, _) => {},
+            (Pattern::Tuple(patterns), Ty::Tuple(elements)) if patterns.len() == elements.len() => {
+                for (pattern, element) in patterns.iter().zip(elements) {
+                    self.bind_pattern(pattern, element, span)?;
+                }
+            },
+            (Pattern::Tuple(patterns), _) => Err(span_error(span, format!(
+                "A tuple pattern with {} elements can't match a value of type `{ty}`", patterns.len()
+            )))?
+        }
+
+        Ok(())
+    }
+
+    // Calls to functions that return `()` don't produce a
+    // value, so trying to use their result is a mistake
+    fn check_value_used(&self, expression: &Expression, ty: &Ty) -> Result<()> {
+        if let (ExpressionKind::FunctionCall { callee, .. }, Ty::Unit) = (&expression.kind, ty) {
+            Err(span_error(expression.span, format!(
+                "The {} doesn't return a value, so its result can't be used", describe_callee(callee)
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    // Checks that `expression` has a type that can be used as `expected`
+    fn check_expression_is(&mut self, expression: &Expression, expected: &Ty) -> Result<Ty> {
+        let ty = self.check_expression(expression, Some(expected))?;
+
+        if !ty.coerces_to(expected) {
+            Err(mismatch(expression.span, expected, &ty))?;
+        }
+
+        Ok(ty)
+    }
+
+    // Only calls to a function by its name know the names of
+    // its parameters, so every other call must use positions
+    fn check_positional(&self, name: &str, arguments: &[Argument]) -> Result<()> {
+        if let Some(argument) = arguments.iter().find(|argument| argument.name.is_some()) {
+            Err(span_error(argument.span, format!(
+                "The {name} can't be called with named arguments, only functions called by the name they are defined with can"
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    fn check_call_arguments(&mut self, span: Span, name: &str, parameters: &[Ty], arguments: &[Argument]) -> Result<()> {
+        self.check_positional(name, arguments)?;
+
+        if parameters.len() != arguments.len() {
+            Err(span_error(span, format!(
+                "The {name} takes {} argument(s) but {} were given", parameters.len(), arguments.len()
+            )))?;
+        }
+
+        for (argument, parameter) in arguments.iter().zip(parameters) {
+            self.check_expression_is(&argument.value, parameter)?;
+        }
+
+        Ok(())
+    }
+
+    // Matches the arguments of a call to a function by its name up
+    // with its parameters, first by position and then by name. Any
+    // parameters left over must have default values.
+    fn check_item_call(&mut self, span: Span, name: &str, function: &Function, parameters: &[Ty], arguments: &[Argument]) -> Result<()> {
+        let params = function.parameters.as_deref().unwrap_or(&[]);
+        let describe = |idx: usize, param: &Param| match param.name() {
+            Some(param_name) => format!("`{param_name}`"),
+            None => format!("number {}", idx + 1)
+        };
+
+        let positional = arguments.iter().filter(|argument| argument.name.is_none()).count();
+        if positional > params.len() {
+            Err(span_error(span, format!(
+                "The {name} takes {} argument(s) but {positional} were given", params.len()
+            )))?;
+        }
+
+        let mut given = vec![false; params.len()];
+        for (idx, argument) in arguments.iter().enumerate() {
+            let position = match &argument.name {
+                None => idx,
+                Some(argument_name) => {
+                    match params.iter().position(|param| param.name() == Some(argument_name)) {
+                        Some(position) => position,
+                        None => Err(span_error(argument.span, format!(
+                            "The {name} has no parameter named `{argument_name}`"
+                        )))?
+                    }
+                }
+            };
+
+            if given[position] {
+                Err(span_error(argument.span, format!(
+                    "The parameter {} of {name} is given more than once", describe(position, &params[position])
+                )))?;
+            }
+            given[position] = true;
+
+            self.check_expression_is(&argument.value, &parameters[position])?;
+        }
+
+        let missing: Vec<String> = params.iter().enumerate()
+            .filter(|(idx, param)| !given[*idx] && param.default.is_none())
+            .map(|(idx, param)| describe(idx, param))
+            .collect();
+
+        if !missing.is_empty() {
+            Err(span_error(span, format!(
+                "The call to {name} is missing arguments for the parameter(s) {}", missing.join(", ")
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    // Pure code can only call pure functions, so that calling a
+    // pure function can never have any effect other than its result
+    fn check_purity(&self, span: Span, callee: &str, impure: bool) -> Result<()> {
+        if impure && !self.impure {
+            Err(span_error(span, format!(
+                "Can't call the impure {callee} from a pure function, unless that function is marked `impure`"
+            )))?;
+        }
+
+        Ok(())
+    }
+
+    fn check_builtin_call(&mut self, span: Span, name: &str, arguments: &[Argument]) -> Result<Ty> {
+        let description = format!("builtin `{name}`");
+
+        match name {
+            // print takes anything that can be displayed
+            "print" => {
+                self.check_purity(span, &description, true)?;
+                self.check_positional(&description, arguments)?;
+
+                let [argument] = arguments else {
+                    Err(span_error(span, format!("The {description} takes 1 argument but {} were given", arguments.len())))?
+                };
+                let ty = self.check_expression(&argument.value, None)?;
+                self.check_value_used(&argument.value, &ty)?;
+                Ok(Ty::Unit)
+            },
+            "panic" => {
+                self.check_call_arguments(span, &description, &[Ty::Str], arguments)?;
+                Ok(Ty::Never)
+            },
+            // assert(condition) or assert(condition, "message")
+            "assert" => {
+                let parameters: &[Ty] = match arguments.len() {
+                    1 => &[Ty::Bool],
+                    2 => &[Ty::Bool, Ty::Str],
+                    _ => Err(span_error(span, format!(
+                        "The {description} takes 1 or 2 arguments but {} were given", arguments.len()
+                    )))?
+                };
+
+                self.check_call_arguments(span, &description, parameters, arguments)?;
+                Ok(Ty::Unit)
+            },
+            // assert_eq(left, right) or assert_eq(left, right, "message")
+            "assert_eq" => {
+                self.check_positional(&description, arguments)?;
+
+                let (left, right, message) = match arguments {
+                    [left, right] => (left, right, None),
+                    [left, right, message] => (left, right, Some(message)),
+                    _ => Err(span_error(span, format!(
+                        "The {description} takes 2 or 3 arguments but {} were given", arguments.len()
+                    )))?
+                };
+
+                // Like with `==`, an untyped literal takes its type from the other side
+                let (left_ty, right_ty) = if is_untyped_literal(&left.value) {
+                    let right_ty = self.check_expression(&right.value, None)?;
+                    (self.check_expression(&left.value, Some(&right_ty))?, right_ty)
+                } else {
+                    let left_ty = self.check_expression(&left.value, None)?;
+                    let right_ty = self.check_expression(&right.value, Some(&left_ty))?;
+                    (left_ty, right_ty)
+                };
+
+                // The same values that `==` can compare
+                let comparable = match (&left_ty, &right_ty) {
+                    (Ty::Never, _) | (_, Ty::Never) => true,
+                    (Ty::Function(_), _) => false,
+                    (left_ty, right_ty) => left_ty == right_ty
+                };
+                if !comparable {
+                    Err(span_error(span, format!(
+                        "The {description} can only compare two values of the same type, not `{left_ty}` and `{right_ty}`"
+                    )))?;
+                }
+
+                if let Some(message) = message {
+                    self.check_expression_is(&message.value, &Ty::Str)?;
+                }
+
+                Ok(Ty::Unit)
+            },
+            _ => match name.split_once("::") {
+                // T::try_from(value) converts an integer (or a `char`) to
+                // `T`, and is fallible since the value might not fit
+                Some((target, "try_from")) => {
+                    let target = IntTy::from_name(target).map_or(Ty::Char, Ty::Int);
+
+                    self.check_positional(&description, arguments)?;
+                    let [argument] = arguments else {
+                        Err(span_error(span, format!("The {description} takes 1 argument but {} were given", arguments.len())))?
+                    };
+
+                    let ty = self.check_expression(&argument.value, None)?;
+                    match (&target, &ty) {
+                        (_, Ty::Int(_) | Ty::Never) | (Ty::Int(_), Ty::Char) => Ok(target),
+                        _ => Err(span_error(argument.value.span, format!(
+                            "The {description} converts from an integer, not `{ty}`"
+                        )))
+                    }
+                },
+                _ => Err(span_error(span, format!("Unknown builtin function `{name}`")))
+            }
+        }
+    }
+
+    // The value of an integer expression made only of literals, e.g.
+    // `1 << 4`, which can be worked out while compiling. It must have
+    // been checked already, so that the types of its literals are known.
+    fn constant_int(&self, expression: &Expression) -> Option<(i128, IntTy)> {
+        match &expression.kind {
+            ExpressionKind::Literal(LiteralType::Integer { value, .. }) => {
+                let Ty::Int(ty) = self.literals[&expression.span] else { unreachable!() };
+                Some((i128::try_from(*value).ok()?, ty))
+            },
+            ExpressionKind::Unary { operator: UnaryOperator::Negate, right } => {
+                let (value, ty) = self.constant_int(right)?;
+                Some((ty.checked_neg(value).ok()?, ty))
+            },
+            ExpressionKind::Unary { operator: UnaryOperator::BitNot, right } => {
+                let (value, ty) = self.constant_int(right)?;
+                Some((ty.wrap(!value), ty))
+            },
+            ExpressionKind::Binary { left, operator, right } => {
+                let (left, ty) = self.constant_int(left)?;
+                let (right, _) = self.constant_int(right)?;
+                Some((ty.checked(*operator, left, right).ok()?, ty))
+            },
+            ExpressionKind::Cast { value, r#type: Type::Int(ty) } => {
+                let (value, _) = self.constant_int(value)?;
+                Some((ty.wrap(value), *ty))
+            },
+            _ => None
+        }
+    }
+
+    // Checks that a value of type `from` can be cast to `to`, warning
+    // when the cast can change the value rather than just its type
+    fn check_cast(&self, span: Span, value: &Expression, from: &Ty, to: &Ty) -> Result<()> {
+        let exact = match (from, to) {
+            (Ty::Never, _) => true,
+            _ if from == to => true,
+            (Ty::Int(from), Ty::Int(to)) => to.contains_all(*from),
+            (Ty::Int(from), Ty::Float(to)) => from.bits() - from.is_signed() as u32 <= to.mantissa_bits(),
+            (Ty::Float(_), Ty::Int(_)) => false,
+            (Ty::Float(from), Ty::Float(to)) => *from == FloatTy::F32 || *to == FloatTy::F64,
+            (Ty::Bool, Ty::Int(_)) => true,
+            (Ty::Char, Ty::Int(to)) => to.contains(char::MAX as i128),
+            (Ty::Int(IntTy::U8), Ty::Char) => true,
+            (Ty::Int(_) | Ty::Float(_), Ty::Bool) => Err(span_error(span, format!(
+                "Can't cast `{from}` to `bool`, compare it with zero instead e.g. `x != 0`"
+            )))?,
+            (Ty::Int(_), Ty::Char) => Err(span_error(span, format!(
+                "Can't cast `{from}` to `char`, only `u8` can be, use `char::try_from` instead"
+            )))?,
+            _ => Err(span_error(span, format!("Can't cast `{from}` to `{to}`")))?
+        };
+
+        // A literal that `to` can represent exactly, e.g. `'a' as u8`, is fine
+        let literal_fits = literal_value(value).is_some_and(|value| match to {
+            Ty::Int(to) => to.contains(value),
+            Ty::Float(to) => value.unsigned_abs() <= 1 << to.mantissa_bits(),
+            _ => true
+        });
+
+        if exact || literal_fits { return Ok(()) };
+
+        let reason = match (from, to) {
+            (Ty::Float(_), Ty::Int(_)) => format!(
+                "its fractional part is dropped, and values outside of what `{to}` can hold are clamped"
+            ),
+            (_, Ty::Float(_)) => format!("it is rounded to the nearest `{to}`"),
+            _ => format!("it is wrapped around to fit, `{to}::try_from` can be used to check that it fits instead")
+        };
+
+        span_warning(span, format!("Casting `{from}` to `{to}` can change the value, since {reason}"));
+
+        Ok(())
+    }
+
+    // The type of an integer or float literal, which is its suffix, or else
+    // the type expected of it, falling back to `i32` or `f64`. `negated`
+    // is whether it has a `-` in front of it, e.g. in `-128i8`.
+    fn check_number_literal(&mut self, literal: &LiteralType, span: Span, expected: Option<&Ty>, negated: bool) -> Result<Ty> {
+        let ty = match literal {
+            LiteralType::Integer { value, suffix } => {
+                let ty = match (suffix, expected) {
+                    (Some(ty), _) | (None, Some(Ty::Int(ty))) => *ty,
+                    _ => IntTy::I32
+                };
+
+                let signed = i128::try_from(*value).ok().map(|value| if negated { -value } else { value });
+                if !signed.is_some_and(|signed| ty.contains(signed)) {
+                    Err(span_error(span, format!(
+                        "The literal `{}{value}` doesn't fit in `{}`, which goes from {} to {}",
+                        if negated { "-" } else { "" }, ty.name(), ty.min(), ty.max()
+                    )))?;
+                }
+
+                Ty::Int(ty)
+            },
+            LiteralType::Float { value, suffix } => {
+                let ty = match (suffix, expected) {
+                    (Some(ty), _) | (None, Some(Ty::Float(ty))) => *ty,
+                    _ => FloatTy::F64
+                };
+
+                if ty.round(*value).is_infinite() {
+                    Err(span_error(span, format!("The literal `{value}` is too large for `{}`", ty.name())))?;
+                }
+
+                Ty::Float(ty)
+            },
+            _ => unreachable!("only number literals have a type that depends on where they are")
+        };
+
+        self.literals.insert(span, ty.clone());
+        Ok(ty)
+    }
+
+    fn check_binary(&self, span: Span, operator: BinaryOperator, left: &Ty, right: &Ty) -> Result<Ty> {
+        use BinaryOperator::*;
+
+        let ty = match (operator, left, right) {
+            (_, Ty::Never, _) | (_, _, Ty::Never) => Ty::Never,
+            (Plus | Minus | Star | Divide | Remainder | Power, Ty::Int(_) | Ty::Float(_), _) if left == right => left.clone(),
+            // The amount to shift by can be any integer
+            (ShiftLeft | ShiftRight, Ty::Int(_), Ty::Int(_)) => left.clone(),
+            (And | Or | Xor, Ty::Int(_) | Ty::Bool, _) if left == right => left.clone(),
+            (LogicalAnd | LogicalOr, Ty::Bool, Ty::Bool) => Ty::Bool,
+            (Greater | GreaterEq | Less | LessEq, Ty::Int(_) | Ty::Float(_) | Ty::Char, _) if left == right => Ty::Bool,
+            (EqEq | NotEq, left, right) if left == right && !matches!(left, Ty::Function(_)) => Ty::Bool,
+            _ => Err(span_error(span, format!(
+                "Can't use the operator `{operator}` on `{left}` and `{right}`"
+            )))?
+        };
+
+        Ok(ty)
+    }
+
+    fn check_expression(&mut self, expression: &Expression, expected: Option<&Ty>) -> Result<Ty> {
+        let span = expression.span;
+
+        let ty = match &expression.kind {
+            ExpressionKind::Literal(literal) => match literal {
+                LiteralType::Integer { .. } | LiteralType::Float { .. } => {
+                    self.check_number_literal(literal, span, expected, false)?
+                },
+                LiteralType::Char(_) => Ty::Char,
+                LiteralType::String(_) => Ty::Str,
+                LiteralType::True | LiteralType::False => Ty::Bool
+            },
+            ExpressionKind::Ident(name) => match self.lookup(name) {
+                Some(ty) => ty.clone(),
+                None => Err(span_error(span, format!("Couldn't find a variable named `{name}`")))?
+            },
+            ExpressionKind::Path(path) => {
+                // After resolution, every path to a function
+                // in the crate is absolute
+                if path.segments[0] != "crate" {
+                    Err(span_error(span, format!(
+                        "The builtin `{}` can only be called, not used as a value", path.segments.join("::")
+                    )))?;
+                }
+
+                match self.globals.get(&path.segments) {
+                    Some((kind, ty)) => {
+                        // Statics can change, so reading one has an effect
+                        // on the result just like calling an impure function
+                        if *kind == GlobalKind::Static && !self.impure {
+                            Err(span_error(span, format!(
+                                "Can't use the static `{}` from a pure function, unless that function is marked `impure`",
+                                path.segments.join("::")
+                            )))?;
+                        }
+
+                        ty.clone()
+                    },
+                    None => Ty::Function(self.signatures[&path.segments].clone())
+                }
+            },
+            ExpressionKind::FunctionCall { callee, parameters } => {
+                let arguments = parameters.as_deref().unwrap_or(&[]);
+
+                if let ExpressionKind::Path(path) = &callee.kind {
+                    if path.segments[0] != "crate" {
+                        return self.check_builtin_call(span, &path.segments.join("::"), arguments);
+                    }
+                }
+
+                let callee_ty = self.check_expression(callee, None)?;
+                let Ty::Function(function) = callee_ty else {
+                    Err(span_error(span, format!("A value of type `{callee_ty}` can't be called")))?
+                };
+
+                let name = describe_callee(callee);
+
+                self.check_purity(span, &name, function.impure)?;
+
+                match &callee.kind {
+                    ExpressionKind::Path(path) if self.functions.contains_key(&path.segments) => {
+                        let item = self.functions[&path.segments];
+                        self.check_item_call(span, &name, item, &function.parameters, arguments)?;
+                    },
+                    _ => self.check_call_arguments(span, &name, &function.parameters, arguments)?
+                }
+
+                *function.return_ty
+            },
+            ExpressionKind::Closure { impure, parameters, return_type, block } => {
+                let mut function = FunctionTy::from_ast(
+                    *impure,
+                    parameters.iter().flatten().map(|param| &param.r#type),
+                    return_type.as_ref()
+                );
+
+                // Closures can see the variables around them,
+                // so their parameters go in a scope on top
+                self.scopes.push(HashMap::new());
+                for (param, ty) in parameters.iter().flatten().zip(&function.parameters) {
+                    self.bind_pattern(&param.pattern, ty, param.span)?;
+                }
+
+                let returns = match return_type {
+                    Some(_) => ReturnTarget::Function((*function.return_ty).clone()),
+                    None => ReturnTarget::Inferred(Vec::new())
+                };
+
+                let outer_impure = std::mem::replace(&mut self.impure, *impure);
+                let outer_returns = std::mem::replace(&mut self.returns, returns);
+                // A `break` in a closure can't leave a loop outside of it
+                let outer_loops = std::mem::take(&mut self.loops);
+
+                let expected_return = return_type.as_ref().map(|_| &*function.return_ty);
+                let ty = self.check_block(block, expected_return)?;
+
+                self.impure = outer_impure;
+                let returns = std::mem::replace(&mut self.returns, outer_returns);
+                self.loops = outer_loops;
+                self.scopes.pop();
+
+                match (expected_return, returns) {
+                    (Some(expected), _) if !ty.coerces_to(expected) => {
+                        let span = block.tail.as_ref().map_or(span, |tail| tail.span);
+                        Err(mismatch(span, expected, &ty))?
+                    },
+                    (Some(_), _) => {},
+                    // Without a return type, a closure returns whatever its body
+                    // and `return`s do, which must all be the same type
+                    (None, ReturnTarget::Inferred(returned)) => {
+                        let return_ty = std::iter::once(&ty)
+                            .chain(returned.iter().map(|(ty, _)| ty))
+                            .find(|ty| **ty != Ty::Never)
+                            .cloned()
+                            .unwrap_or(Ty::Never);
+
+                        for (ty, span) in &returned {
+                            if !ty.coerces_to(&return_ty) {
+                                Err(mismatch(*span, &return_ty, ty))?;
+                            }
+                        }
+                        if !ty.coerces_to(&return_ty) {
+                            let span = block.tail.as_ref().map_or(span, |tail| tail.span);
+                            Err(mismatch(span, &return_ty, &ty))?;
+                        }
+
+                        function.return_ty = Box::new(return_ty);
+                    },
+                    (None, _) => unreachable!()
+                }
+
+                Ty::Function(function)
+            },
+            ExpressionKind::MethodCall { path, name, parameters } => {
+                let receiver = self.check_expression(path, None)?;
+                let arguments = parameters.as_deref().unwrap_or(&[]);
+
+                match (name.as_str(), &receiver) {
+                    ("len", Ty::Array(..) | Ty::Slice(_)) => {
+                        self.check_call_arguments(span, "method `len`", &[], arguments)?;
+                        Ty::Int(IntTy::I32)
+                    },
+                    // e.g. `x.wrapping_add(1)`
+                    (name, Ty::Int(_)) if integer_method(name).is_some() => {
+                        self.check_call_arguments(span, &format!("method `{name}`"), std::slice::from_ref(&receiver), arguments)?;
+                        receiver
+                    },
+                    _ => Err(span_error(span, format!("There is no method named `{name}` on `{receiver}`")))?
+                }
+            },
+            ExpressionKind::Cast { value, r#type } => {
+                let target = Ty::from_ast(r#type);
+
+                // A literal becomes the type it's cast to if it can,
+                // so that ones which don't fit, e.g. `300 as u8`, are caught
+                let expected = matches!(target, Ty::Int(_) | Ty::Float(_)).then_some(&target);
+                let ty = self.check_expression(value, expected)?;
+
+                self.check_cast(span, value, &ty, &target)?;
+                target
+            },
+            ExpressionKind::Binary { left, operator, right } => {
+                use BinaryOperator::*;
+
+                // Only operators whose result has the type of
+                // their left hand side pass on what is expected
+                let expected = match operator {
+                    Plus | Minus | Star | Divide | Remainder | Power | And | Or | Xor | ShiftLeft | ShiftRight => expected,
+                    _ => None
+                };
+
+                // A literal without a suffix takes the type of the other side,
+                // so that both `x + 1` and `1 + x` work for any integer `x`
+                let (left_ty, right_ty) = if is_untyped_literal(left) && !is_untyped_literal(right)
+                    && !matches!(operator, ShiftLeft | ShiftRight)
+                {
+                    let right_ty = self.check_expression(right, expected)?;
+                    (self.check_expression(left, Some(&right_ty))?, right_ty)
+                } else {
+                    let left_ty = self.check_expression(left, expected)?;
+                    let right_ty = self.check_expression(right, Some(&left_ty))?;
+                    (left_ty, right_ty)
+                };
+
+                let ty = self.check_binary(span, *operator, &left_ty, &right_ty)?;
+
+                // Operations on constants that will always panic,
+                // e.g. `2147483647 + 1`, are caught while compiling
+                if let (Ty::Int(_), Some((left, int)), Some((right, _))) = (&ty, self.constant_int(left), self.constant_int(right)) {
+                    if let Err(error) = int.checked(*operator, left, right) {
+                        Err(span_error(span, format!(
+                            "`{left} {operator} {right}` will always panic when it is run, with \"{error}\""
+                        )))?;
+                    }
+                }
+
+                ty
+            },
+            ExpressionKind::Unary { operator, right } => {
+                // A negative literal is checked as a whole,
+                // so that e.g. `-128i8` fits in an `i8`
+                let ty = match (operator, &right.kind) {
+                    (UnaryOperator::Negate, ExpressionKind::Literal(literal @ (LiteralType::Integer { .. } | LiteralType::Float { .. }))) => {
+                        self.check_number_literal(literal, right.span, expected, true)?
+                    },
+                    _ => self.check_expression(right, expected)?
+                };
+
+                // `-x` only overflows for the smallest value of a type e.g. `-(-128i8)`
+                if let (UnaryOperator::Negate, Some((value, int))) = (operator, self.constant_int(right)) {
+                    if let Err(error) = int.checked_neg(value) {
+                        Err(span_error(span, format!(
+                            "`-({value})` will always panic when it is run, with \"{error}\""
+                        )))?;
+                    }
+                }
+
+                match (operator, &ty) {
+                    (_, Ty::Never) => Ty::Never,
+                    (UnaryOperator::Negate, Ty::Int(int)) if int.is_signed() => ty,
+                    (UnaryOperator::Negate, Ty::Float(_)) | (UnaryOperator::BitNot, Ty::Int(_)) => ty,
+                    (UnaryOperator::Not, Ty::Bool) => Ty::Bool,
+                    _ => Err(span_error(span, format!("Can't use the operator `{operator}` on `{ty}`")))?
+                }
+            },
+            ExpressionKind::IfExpression { condition, block, else_branch } => {
+                self.check_expression_is(condition, &Ty::Bool)?;
+
+                let Some(else_branch) = else_branch else {
+                    // Without an `else`, there is nothing to produce
+                    // when the condition is false
+                    let ty = self.check_block(block, Some(&Ty::Unit))?;
+                    if !ty.coerces_to(&Ty::Unit) {
+                        let span = block.tail.as_ref().map_or(span, |tail| tail.span);
+                        Err(span_error(span, format!(
+                            "An `if` without an `else` can't produce a value, but this block has type `{ty}`"
+                        )))?;
+                    }
+
+                    return Ok(Ty::Unit);
+                };
+
+                let then_ty = self.check_block(block, expected)?;
+                let else_ty = match else_branch {
+                    ElseBranch::Block(block) => self.check_block(block, expected)?,
+                    ElseBranch::If(expression) => self.check_expression(expression, expected)?
+                };
+
+                // A branch that never finishes takes the type of the other one
+                if else_ty.coerces_to(&then_ty) {
+                    then_ty
+                } else if then_ty.coerces_to(&else_ty) {
+                    else_ty
+                } else {
+                    Err(span_error(span, format!(
+                        "The branches of this `if` have different types, `{then_ty}` and `{else_ty}`"
+                    )))?
+                }
+            },
+            ExpressionKind::Loop(block) => {
+                self.loops.push(false);
+                let ty = self.check_block(block, Some(&Ty::Unit))?;
+                let has_break = self.loops.pop().unwrap();
+
+                if !ty.coerces_to(&Ty::Unit) {
+                    let span = block.tail.as_ref().map_or(span, |tail| tail.span);
+                    Err(span_error(span, format!(
+                        "The body of a `loop` can't produce a value, but this block has type `{ty}`"
+                    )))?;
+                }
+
+                // A loop can only be left with a `break`
+                if has_break { Ty::Unit } else { Ty::Never }
+            },
+            ExpressionKind::Break => {
+                match self.loops.last_mut() {
+                    Some(has_break) => *has_break = true,
+                    None if matches!(self.returns, ReturnTarget::Deferred) => {
+                        Err(span_error(span, "Deferred code can't `break` out of a `loop` around the `defer`".to_string()))?
+                    },
+                    None => Err(span_error(span, "`break` can only be used inside of a `loop`".to_string()))?
+                }
+
+                Ty::Never
+            },
+            ExpressionKind::Return(value) => {
+                let expected = match &self.returns {
+                    ReturnTarget::Function(ty) => Some(ty.clone()),
+                    ReturnTarget::Inferred(_) => None,
+                    ReturnTarget::Nothing => Err(span_error(span, "`return` can only be used inside of a function".to_string()))?,
+                    ReturnTarget::Deferred => Err(span_error(span, "Deferred code can't `return`, since it runs as its scope is being left".to_string()))?
+                };
+
+                let (ty, value_span) = match value {
+                    Some(value) => (self.check_expression(value, expected.as_ref())?, value.span),
+                    None => (Ty::Unit, span)
+                };
+
+                match &mut self.returns {
+                    ReturnTarget::Function(expected) if !ty.coerces_to(expected) => {
+                        Err(mismatch(value_span, expected, &ty))?
+                    },
+                    ReturnTarget::Inferred(returned) => returned.push((ty, value_span)),
+                    _ => {}
+                }
+
+                Ty::Never
+            },
+            ExpressionKind::Array(elements) => {
+                let expected_element = expected.and_then(Ty::element);
+
+                let Some((first, rest)) = elements.split_first() else {
+                    match expected_element {
+                        Some(element) => return Ok(Ty::Array(Box::new(element.clone()), 0)),
+                        None => Err(span_error(span, "Couldn't work out the type of this empty array".to_string()))?
+                    }
+                };
+
+                let element = self.check_expression(first, expected_element)?;
+                self.check_value_used(first, &element)?;
+                for other in rest {
+                    self.check_expression_is(other, &element)?;
+                }
+
+                Ty::Array(Box::new(element), elements.len())
+            },
+            ExpressionKind::ArrayRepeat { value, count } => {
+                let element = self.check_expression(value, expected.and_then(Ty::element))?;
+                let ty = Ty::Array(Box::new(element), *count);
+
+                if ty.values() > MAX_REPEATED_VALUES {
+                    Err(span_error(span, format!(
+                        "`{ty}` is too big to make by repeating a value, which can make at most {MAX_REPEATED_VALUES} values"
+                    )))?;
+                }
+
+                ty
+            },
+            ExpressionKind::Tuple(elements) => {
+                let expected_elements = match expected {
+                    Some(Ty::Tuple(expected_elements)) if expected_elements.len() == elements.len() => {
+                        Some(expected_elements)
+                    },
+                    _ => None
+                };
+
+                // () is the unit value rather than a tuple
+                if elements.is_empty() {
+                    return Ok(Ty::Unit);
+                }
+
+                let mut tys = Vec::new();
+                for (idx, element) in elements.iter().enumerate() {
+                    let expected_element = expected_elements.map(|expected_elements| &expected_elements[idx]);
+                    let ty = self.check_expression(element, expected_element)?;
+                    self.check_value_used(element, &ty)?;
+                    tys.push(ty);
+                }
+
+                Ty::Tuple(tys)
+            },
+            ExpressionKind::TupleIndex { target, index } => {
+                let target_ty = self.check_expression(target, None)?;
+
+                match &target_ty {
+                    Ty::Tuple(elements) if *index < elements.len() => elements[*index].clone(),
+                    Ty::Tuple(elements) => Err(span_error(span, format!(
+                        "There is no element {index} in `{target_ty}`, which only has {}", elements.len()
+                    )))?,
+                    _ => Err(span_error(span, format!("Can't access element {index} of `{target_ty}`, which isn't a tuple")))?
+                }
+            },
+            ExpressionKind::Index { target, index } => {
+                let target_ty = self.check_expression(target, None)?;
+                self.check_expression_is(index, &Ty::Int(IntTy::I32))?;
+
+                match target_ty.element() {
+                    Some(element) => element.clone(),
+                    None => Err(span_error(span, format!("Can't index into a value of type `{target_ty}`")))?
+                }
+            },
+            ExpressionKind::Assign { target, operator, value } => {
+                // Functions and consts can never change, so the only
+                // items that can be assigned to (or into) are statics
+                let mut root = &**target;
+                while let ExpressionKind::Index { target, .. } | ExpressionKind::TupleIndex { target, .. } = &root.kind {
+                    root = target;
+                }
+
+                if let ExpressionKind::Path(path) = &root.kind {
+                    if !matches!(self.globals.get(&path.segments), Some((GlobalKind::Static, _))) {
+                        Err(span_error(root.span, format!(
+                            "Can't assign to `{}`, which isn't a `static`", path.segments.join("::")
+                        )))?;
+                    }
+                }
+
+                let target_ty = self.check_expression(target, None)?;
+
+                match operator {
+                    Some(operator) => {
+                        let value_ty = self.check_expression(value, Some(&target_ty))?;
+                        let result = self.check_binary(span, *operator, &target_ty, &value_ty)?;
+                        if result != target_ty {
+                            Err(mismatch(span, &target_ty, &result))?;
+                        }
+                    },
+                    None => {
+                        let ty = self.check_expression_is(value, &target_ty)?;
+                        if target_ty != Ty::Unit {
+                            self.check_value_used(value, &ty)?;
+                        }
+                    }
+                }
+
+                Ty::Unit
+            }
+        };
+
+        Ok(ty)
+    }
+}
+
+// Checks the types of every function body and const or static
+// value in the crate, which must have been resolved already
+pub fn check(krate: &Crate) -> Result<TypeckResults> {
+    check_crate(krate, None).map(|(results, _)| results)
+}
+
+// Checks the crate like `check`, then `block` as if it were the body
+// of an impure function in the crate root (which must have been resolved
+// with `resolve_snippet`) that starts with `variables` in scope, e.g. for
+// code in the REPL. It can't `return`, since there's nothing to return
+// from. Returns the type of its value, and the variables it leaves.
+pub fn check_snippet(krate: &Crate, block: &Block, variables: &Variables) -> Result<(TypeckResults, Ty, Variables)> {
+    let (results, snippet) = check_crate(krate, Some((block, variables)))?;
+    let (ty, variables) = snippet.unwrap();
+    Ok((results, ty, variables))
+}
+
+fn check_crate(krate: &Crate, snippet: Option<(&Block, &Variables)>) -> Result<(TypeckResults, Option<(Ty, Variables)>)> {
+    let signatures: HashMap<Vec<String>, FunctionTy> = krate.functions()
+        .map(|(path, function)| (path, FunctionTy::of(function)))
+        .collect();
+
+    let globals: HashMap<Vec<String>, (GlobalKind, Ty)> = krate.globals()
+        .map(|(path, global)| (path, (global.kind, Ty::from_ast(&global.r#type))))
+        .collect();
+
+    let functions: HashMap<Vec<String>, &Function> = krate.functions().collect();
+
+    let mut checker = Checker {
+        signatures: &signatures,
+        functions: &functions,
+        globals: &globals,
+        scopes: Vec::new(),
+        impure: false,
+        returns: ReturnTarget::Nothing,
+        loops: Vec::new(),
+        literals: HashMap::new()
+    };
+
+    // The values of consts and statics are worked out when the
+    // program is compiled, so they can only use pure code
+    for (path, global) in krate.globals() {
+        checker.impure = false;
+        checker.scopes = vec![HashMap::new()];
+        checker.returns = ReturnTarget::Nothing;
+        checker.loops = Vec::new();
+        checker.check_expression_is(&global.value, &globals[&path].1)?;
+    }
+
+    // Static assertions are worked out while compiling too
+    for assert in krate.static_asserts() {
+        checker.impure = false;
+        checker.scopes = vec![HashMap::new()];
+        checker.returns = ReturnTarget::Nothing;
+        checker.loops = Vec::new();
+        checker.check_expression_is(&assert.condition, &Ty::Bool)?;
+    }
+
+    let main_path = vec!["crate".to_string(), "main".to_string()];
+    if let Some(main) = functions.get(&main_path) {
+        let signature = &signatures[&main_path];
+
+        if !signature.parameters.is_empty() {
+            Err(span_error(main.span, "`main` can't take any parameters".to_string()))?;
+        }
+
+        // An `i32` is the exit status of the program
+        if !matches!(*signature.return_ty, Ty::Unit | Ty::Int(IntTy::I32)) {
+            Err(span_error(main.span, format!(
+                "`main` must return either `()` or `i32` (the exit status), not `{}`", signature.return_ty
+            )))?;
+        }
+    }
+
+    for (path, function) in krate.functions() {
+        // `main` is where a program's effects happen, so it
+        // is always allowed to call impure functions
+        let is_main = path == main_path;
+        checker.check_function(function, function.impure || is_main)?;
+    }
+
+    let snippet = match snippet {
+        Some((block, variables)) => {
+            checker.impure = true;
+            checker.scopes = vec![variables.clone()];
+            checker.returns = ReturnTarget::Nothing;
+            checker.loops = Vec::new();
+            let ty = checker.check_block_in_scope(block, None)?;
+            Some((ty, checker.scopes.pop().unwrap()))
+        },
+        None => None
+    };
+
+    Ok((TypeckResults { literals: checker.literals }, snippet))
+}
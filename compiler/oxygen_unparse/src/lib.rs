@@ -0,0 +1,602 @@
+use oxygen_ast::*;
+
+// What each level of nesting is indented by, as `oxygen fmt` does
+const INDENT: &str = "  ";
+
+// The value on the left of a `|>` and the rest of the arguments,
+// if the expression is a call that was written with one
+fn piped_call(expression: &Expression) -> Option<(&Argument, &[Argument])> {
+    let (ExpressionKind::FunctionCall { parameters: Some(parameters), .. }
+        | ExpressionKind::MethodCall { parameters: Some(parameters), .. }) = &expression.kind else { return None };
+
+    match parameters.split_first() {
+        Some((first, rest)) if first.piped => Some((first, rest)),
+        _ => None
+    }
+}
+
+// How tightly the expression binds as it's written, which decides
+// whether it needs brackets around it to be used as an operand
+fn precedence(expression: &Expression) -> Precedence {
+    match &expression.kind {
+        // `return` takes everything after it as its value, so
+        // it's bracketed anywhere that an assignment would be
+        ExpressionKind::Assign { .. } | ExpressionKind::Return(_) => Precedence::Assign,
+        ExpressionKind::Binary { operator, .. } => operator.precedence(),
+        ExpressionKind::Cast { .. } => Precedence::Cast,
+        ExpressionKind::Unary { .. } => Precedence::Unary,
+        _ if piped_call(expression).is_some() => Precedence::Pipeline,
+        _ => Precedence::Call
+    }
+}
+
+// Whether code that goes straight after an `if` or `loop` statement
+// would carry on the expression instead e.g. `if x {} -1` is `(if x {}) - 1`
+fn continues_expression(code: &str) -> bool {
+    code.starts_with(['-', '(', '['])
+}
+
+fn escape(c: char, quote: char) -> String {
+    match c {
+        '\n' => "\\n".to_string(),
+        '\t' => "\\t".to_string(),
+        '\r' => "\\r".to_string(),
+        '\0' => "\\0".to_string(),
+        '\\' => "\\\\".to_string(),
+        c if c == quote => format!("\\{c}"),
+        c => c.to_string()
+    }
+}
+
+struct Printer {
+    out: String,
+    indent: usize
+}
+
+impl Printer {
+    fn new(indent: usize) -> Self {
+        Printer { out: String::new(), indent }
+    }
+
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    // Starts a new line at the current level of nesting
+    fn new_line(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn item(&mut self, item: &TopLevelItem) {
+        match item {
+            TopLevelItem::Function(function) => self.function(function),
+            TopLevelItem::Global(global) => {
+                if global.public { self.write("pub ") };
+                self.write(match global.kind {
+                    GlobalKind::Const => "const ",
+                    GlobalKind::Static => "static "
+                });
+                self.write(&global.name);
+                self.write(": ");
+                self.r#type(&global.r#type);
+                self.write(" = ");
+                self.expression(&global.value, Precedence::Assign);
+                self.write(";");
+            },
+            TopLevelItem::Extern(block) => {
+                self.write(match block.abi {
+                    Abi::C => "extern \"C\" {"
+                });
+
+                self.indent += 1;
+                for function in &block.functions {
+                    self.new_line();
+                    self.function(function);
+                }
+                self.indent -= 1;
+
+                if !block.functions.is_empty() { self.new_line() };
+                self.write("}");
+            },
+            TopLevelItem::Module(declaration) => {
+                if declaration.public { self.write("pub ") };
+                self.write("mod ");
+                self.write(&declaration.name);
+                self.write(";");
+            },
+            TopLevelItem::Use(declaration) => {
+                if declaration.public { self.write("pub ") };
+                self.write("use ");
+                self.write(&declaration.path.segments.join("::"));
+                self.write(";");
+            },
+            TopLevelItem::StaticAssert(assert) => {
+                self.write("static_assert(");
+                self.expression(&assert.condition, Precedence::Assign);
+                self.write(");");
+            }
+        }
+    }
+
+    // Doc comments, each line starting with `marker`
+    fn docs(&mut self, marker: &str, docs: &str) {
+        for line in docs.split('\n') {
+            self.write(marker);
+            if !line.is_empty() {
+                self.write(" ");
+                self.write(line);
+            }
+            self.new_line();
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        if let Some(docs) = &function.docs {
+            self.docs("##", docs);
+        }
+
+        if function.public { self.write("pub ") };
+
+        // Foreign functions are impure unless they say otherwise
+        match (&function.block, function.impure) {
+            (Some(_), true) => self.write("impure "),
+            (None, false) => self.write("pure "),
+            _ => {}
+        }
+
+        self.write("func ");
+        self.write(&function.name);
+        self.parameters(&function.parameters);
+
+        if let Some(return_type) = &function.return_type {
+            self.write(" ~ ");
+            self.return_type(return_type);
+        }
+
+        match &function.block {
+            Some(block) => {
+                self.write(" ");
+                self.block(block);
+            },
+            None => self.write(";")
+        }
+    }
+
+    // The `(...)` of a function or closure
+    fn parameters(&mut self, parameters: &Option<FunctionParameters>) {
+        self.write("(");
+
+        for (idx, param) in parameters.iter().flatten().enumerate() {
+            if idx > 0 { self.write(", ") };
+
+            self.r#type(&param.r#type);
+            self.write(" ");
+            self.pattern(&param.pattern);
+
+            if let Some(default) = &param.default {
+                self.write(" = ");
+                self.expression(default, Precedence::Assign);
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn return_type(&mut self, (r#type, fallible): &ReturnType) {
+        // The `!` would belong to the return type of the function
+        // type instead e.g. `~ (func() ~ i32)!` rather than `~ func() ~ i32!`
+        let bracketed = *fallible && matches!(r#type, Type::Function { return_type: Some(_), .. });
+
+        if bracketed { self.write("(") };
+        self.r#type(r#type);
+        if bracketed { self.write(")") };
+
+        if *fallible { self.write("!") };
+    }
+
+    fn r#type(&mut self, r#type: &Type) {
+        match r#type {
+            Type::Int(int) => self.write(int.name()),
+            Type::Float(float) => self.write(float.name()),
+            Type::Bool => self.write("bool"),
+            Type::Char => self.write("char"),
+            Type::Unit => self.write("()"),
+            Type::Never => self.write("never"),
+            Type::Array(element, length) => {
+                self.write("[");
+                self.r#type(element);
+                self.write(&format!("; {length}]"));
+            },
+            Type::Slice(element) => {
+                self.write("[");
+                self.r#type(element);
+                self.write("]");
+            },
+            Type::Tuple(types) => {
+                self.write("(");
+                for (idx, r#type) in types.iter().enumerate() {
+                    if idx > 0 { self.write(", ") };
+                    self.r#type(r#type);
+                }
+                // Without a comma, `(i32,)` would just be `i32`
+                if types.len() == 1 { self.write(",") };
+                self.write(")");
+            },
+            Type::Function { impure, parameters, return_type } => {
+                if *impure { self.write("impure ") };
+                self.write("func(");
+                for (idx, r#type) in parameters.iter().enumerate() {
+                    if idx > 0 { self.write(", ") };
+                    self.r#type(r#type);
+                }
+                self.write(")");
+
+                if let Some(return_type) = return_type {
+                    self.write(" ~ ");
+                    self.return_type(return_type);
+                }
+            }
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Ident(name) => self.write(name),
+            Pattern::Wildcard => self.write("_"),
+            Pattern::Tuple(patterns) => {
+                self.write("(");
+                for (idx, pattern) in patterns.iter().enumerate() {
+                    if idx > 0 { self.write(", ") };
+                    self.pattern(pattern);
+                }
+                if patterns.len() == 1 { self.write(",") };
+                self.write(")");
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        if block.statements.is_empty() && block.tail.is_none() {
+            self.write("{}");
+            return;
+        }
+
+        self.write("{");
+        self.indent += 1;
+        for line in self.statements(block) {
+            self.new_line();
+            self.write(&line);
+        }
+        self.indent -= 1;
+        self.new_line();
+        self.write("}");
+    }
+
+    // Each statement of the block and then its tail, which all
+    // start on a new line at the current level of nesting
+    fn statements(&self, block: &Block) -> Vec<String> {
+        let mut lines: Vec<_> = block.statements.iter().map(|statement| {
+            let mut printer = Printer::new(self.indent);
+            printer.statement(statement);
+            printer.out
+        }).collect();
+
+        if let Some(tail) = &block.tail {
+            let mut printer = Printer::new(self.indent);
+            printer.expression(tail, Precedence::Assign);
+            lines.push(printer.out);
+        }
+
+        // An `if` or `loop` used as a statement doesn't need a `;`, unless
+        // it's the last thing in the block, where it would be the tail,
+        // or what comes after it would carry on the expression
+        for (idx, statement) in block.statements.iter().enumerate() {
+            let Statement::Expression(expression) = statement else { continue };
+            if !expression.kind.ends_with_block() { continue };
+
+            if lines.get(idx + 1).is_none_or(|next| continues_expression(next)) {
+                lines[idx].push(';');
+            }
+        }
+
+        lines
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Expression(expression) => {
+                self.expression(expression, Precedence::Assign);
+                // Added by `statements` when it's needed
+                if !expression.kind.ends_with_block() { self.write(";") };
+            },
+            Statement::Let { pattern, r#type, value, .. } => {
+                self.write("let ");
+                self.pattern(pattern);
+                if let Some(r#type) = r#type {
+                    self.write(": ");
+                    self.r#type(r#type);
+                }
+                self.write(" = ");
+                self.expression(value, Precedence::Assign);
+                self.write(";");
+            },
+            Statement::Defer { body, .. } => {
+                self.write("defer ");
+                match body {
+                    Deferred::Block(block) => self.block(block),
+                    Deferred::Expression(expression) => {
+                        self.expression(expression, Precedence::Assign);
+                        self.write(";");
+                    }
+                }
+            }
+        }
+    }
+
+    fn literal(&mut self, literal: &LiteralType) {
+        match literal {
+            LiteralType::Integer { value, suffix } => {
+                self.write(&value.to_string());
+                if let Some(suffix) = suffix { self.write(suffix.name()) };
+            },
+            LiteralType::Float { value, suffix } => {
+                // The shortest digits that are read back as the same value,
+                // which always has a `.` or an exponent e.g. `1.0`, `1e-7`
+                match value.is_infinite() {
+                    true => self.write("1e999"),
+                    false => self.write(&format!("{value:?}"))
+                }
+                if let Some(suffix) = suffix { self.write(suffix.name()) };
+            },
+            LiteralType::Char(c) => {
+                self.write("'");
+                self.write(&escape(*c, '\''));
+                self.write("'");
+            },
+            LiteralType::String(string) => {
+                self.write("\"");
+                for c in string.chars() {
+                    self.write(&escape(c, '"'));
+                }
+                self.write("\"");
+            },
+            LiteralType::True => self.write("true"),
+            LiteralType::False => self.write("false")
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[Argument]) {
+        self.write("(");
+
+        for (idx, argument) in arguments.iter().enumerate() {
+            if idx > 0 { self.write(", ") };
+
+            if let Some(name) = &argument.name {
+                self.write(name);
+                self.write(": ");
+            }
+            self.expression(&argument.value, Precedence::Assign);
+        }
+
+        self.write(")");
+    }
+
+    fn expressions(&mut self, expressions: &[Expression]) {
+        for (idx, expression) in expressions.iter().enumerate() {
+            if idx > 0 { self.write(", ") };
+            self.expression(expression, Precedence::Assign);
+        }
+    }
+
+    // Writes the expression where the code around it needs something
+    // which binds at least as tightly as `context`, bracketing it if not
+    fn expression(&mut self, expression: &Expression, context: Precedence) {
+        let bracketed = precedence(expression) < context;
+
+        if bracketed { self.write("(") };
+        self.expression_kind(expression);
+        if bracketed { self.write(")") };
+    }
+
+    fn expression_kind(&mut self, expression: &Expression) {
+        if let Some((piped, rest)) = piped_call(expression) {
+            // Left associative, e.g. `x |> f() |> g()` is `(x |> f()) |> g()`
+            self.expression(&piped.value, Precedence::Pipeline);
+            self.write(" |> ");
+
+            match &expression.kind {
+                ExpressionKind::FunctionCall { callee, .. } => self.expression(callee, Precedence::Call),
+                ExpressionKind::MethodCall { path, name, .. } => {
+                    self.expression(path, Precedence::Call);
+                    self.write(".");
+                    self.write(name);
+                },
+                _ => unreachable!()
+            }
+
+            self.arguments(rest);
+            return;
+        }
+
+        match &expression.kind {
+            ExpressionKind::Literal(literal) => self.literal(literal),
+            ExpressionKind::Ident(name) => self.write(name),
+            ExpressionKind::Path(path) => self.write(&path.segments.join("::")),
+            ExpressionKind::FunctionCall { callee, parameters } => {
+                self.expression(callee, Precedence::Call);
+                self.arguments(parameters.as_deref().unwrap_or_default());
+            },
+            ExpressionKind::Closure { impure, parameters, return_type, block } => {
+                if *impure { self.write("impure ") };
+                self.write("func");
+                self.parameters(parameters);
+
+                if let Some(return_type) = return_type {
+                    self.write(" ~ ");
+                    self.return_type(return_type);
+                }
+
+                self.write(" ");
+                self.block(block);
+            },
+            ExpressionKind::MethodCall { path, name, parameters } => {
+                self.expression(path, Precedence::Call);
+                self.write(".");
+                self.write(name);
+                self.arguments(parameters.as_deref().unwrap_or_default());
+            },
+            ExpressionKind::Cast { value, r#type } => {
+                self.expression(value, Precedence::Cast);
+                self.write(" as ");
+                self.r#type(r#type);
+            },
+            ExpressionKind::Binary { left, operator, right } => {
+                let (left_context, right_context) = match operator {
+                    // Right associative, e.g. `2 ** 3 ** 2` is `2 ** (3 ** 2)`,
+                    // and a unary operator on the right is fine e.g. `2 ** -1`
+                    BinaryOperator::Power => (Precedence::Power.next(), Precedence::Unary),
+                    operator => (operator.precedence(), operator.precedence().next())
+                };
+
+                self.expression(left, left_context);
+                self.write(" ");
+                self.write(operator.symbol());
+                self.write(" ");
+                self.expression(right, right_context);
+            },
+            ExpressionKind::Unary { operator, right } => {
+                self.write(operator.symbol());
+                self.expression(right, Precedence::Unary);
+            },
+            ExpressionKind::IfExpression { condition, block, else_branch } => {
+                self.write("if ");
+                self.expression(condition, Precedence::Assign);
+                self.write(" ");
+                self.block(block);
+
+                match else_branch {
+                    Some(ElseBranch::Block(block)) => {
+                        self.write(" else ");
+                        self.block(block);
+                    },
+                    Some(ElseBranch::If(expression)) => {
+                        self.write(" else ");
+                        self.expression(expression, Precedence::Assign);
+                    },
+                    None => {}
+                }
+            },
+            ExpressionKind::Loop(block) => {
+                self.write("loop ");
+                self.block(block);
+            },
+            ExpressionKind::Break => self.write("break"),
+            ExpressionKind::Return(value) => {
+                self.write("return");
+                if let Some(value) = value {
+                    self.write(" ");
+                    self.expression(value, Precedence::Assign);
+                }
+            },
+            ExpressionKind::Array(elements) => {
+                self.write("[");
+                self.expressions(elements);
+                self.write("]");
+            },
+            ExpressionKind::ArrayRepeat { value, count } => {
+                self.write("[");
+                self.expression(value, Precedence::Assign);
+                self.write(&format!("; {count}]"));
+            },
+            ExpressionKind::Index { target, index } => {
+                self.expression(target, Precedence::Call);
+                self.write("[");
+                self.expression(index, Precedence::Assign);
+                self.write("]");
+            },
+            ExpressionKind::Tuple(elements) => {
+                self.write("(");
+                self.expressions(elements);
+                // Without a comma, `(x,)` would just be `x`
+                if elements.len() == 1 { self.write(",") };
+                self.write(")");
+            },
+            ExpressionKind::TupleIndex { target, index } => {
+                // `1.0` is a float rather than the first element of `1`
+                let integer = matches!(target.kind, ExpressionKind::Literal(LiteralType::Integer { suffix: None, .. }));
+
+                if integer { self.write("(") };
+                self.expression(target, Precedence::Call);
+                if integer { self.write(")") };
+
+                self.write(&format!(".{index}"));
+            },
+            ExpressionKind::Assign { target, operator, value } => {
+                self.expression(target, Precedence::Call);
+                self.write(" ");
+                if let Some(operator) = operator { self.write(operator.symbol()) };
+                self.write("= ");
+                // Right associative, e.g. `a = b = c` is `a = (b = c)`
+                self.expression(value, Precedence::Assign);
+            }
+        }
+    }
+}
+
+// Whether the items go on lines next to each other, rather
+// than having a blank line between them e.g. a list of `use`s
+fn is_grouped(item: &TopLevelItem, next: &TopLevelItem) -> bool {
+    match (item, next) {
+        (TopLevelItem::Function(_) | TopLevelItem::Extern(_), _) | (_, TopLevelItem::Function(_) | TopLevelItem::Extern(_)) => false,
+        (item, next) => std::mem::discriminant(item) == std::mem::discriminant(next)
+    }
+}
+
+// Turns the AST back into source code, which parses to the same AST,
+// with brackets only where operators would otherwise bind differently
+pub fn unparse(ast: &Ast) -> String {
+    let mut printer = Printer::new(0);
+
+    if let Some(docs) = &ast.docs {
+        // Which would otherwise be a `#!/usr/bin/env oxygen` line
+        if docs.starts_with('/') {
+            printer.write("#");
+            printer.new_line();
+        }
+        printer.docs("#!", docs);
+        if !ast.items.is_empty() { printer.write("\n") };
+    }
+
+    for (idx, item) in ast.items.iter().enumerate() {
+        if idx > 0 {
+            printer.write("\n");
+            if !is_grouped(&ast.items[idx - 1], item) { printer.write("\n") };
+        }
+
+        printer.item(item);
+    }
+
+    if !ast.items.is_empty() { printer.write("\n") };
+    printer.out
+}
+
+// The statements of the block without the `{}` around them, one per
+// line, e.g. for what `oxygen_parser::parse_statements` parses
+pub fn unparse_statements(block: &Block) -> String {
+    Printer::new(0).statements(block).iter().map(|line| format!("{line}\n")).collect()
+}
+
+pub fn unparse_expression(expression: &Expression) -> String {
+    let mut printer = Printer::new(0);
+    printer.expression(expression, Precedence::Assign);
+    printer.out
+}
+
+pub fn unparse_type(r#type: &Type) -> String {
+    let mut printer = Printer::new(0);
+    printer.r#type(r#type);
+    printer.out
+}
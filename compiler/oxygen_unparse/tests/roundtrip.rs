@@ -0,0 +1,547 @@
+use std::fs;
+use std::path::PathBuf;
+
+use oxygen_ast::*;
+use oxygen_parser::{ parse, parse_statements };
+use oxygen_span::{ add_file, FileId, Span };
+use oxygen_unparse::{ unparse, unparse_expression, unparse_statements, unparse_type };
+
+fn repo_path(path: &str) -> PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+fn file(name: &str, src: &str) -> FileId {
+    add_file(std::path::Path::new(name), src)
+}
+
+fn sources_in(directory: &str) -> Vec<(PathBuf, String)> {
+    let mut sources: Vec<_> = fs::read_dir(repo_path(directory)).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ox"))
+        .map(|path| {
+            let src = fs::read_to_string(&path).unwrap();
+            (path, src)
+        })
+        .collect();
+
+    sources.sort();
+    sources
+}
+
+fn parse_src(src: &str) -> Ast {
+    parse(file("<unparsed>", src)).unwrap_or_else(|_| panic!("couldn't parse:\n{src}"))
+}
+
+fn parse_statements_src(src: &str) -> Block {
+    parse_statements(file("<statements>", src)).unwrap_or_else(|_| panic!("couldn't parse:\n{src}"))
+}
+
+// The debug output of the AST without any spans, since printed
+// code is laid out differently to what was parsed
+fn without_spans(debug: String) -> String {
+    let mut stripped = String::with_capacity(debug.len());
+    let mut rest = debug.as_str();
+
+    while let Some(start) = rest.find("Span {") {
+        stripped.push_str(&rest[..start]);
+        let end = rest[start..].find('}').unwrap();
+        rest = &rest[start + end + 1..];
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+// Prints the AST, parses what was printed, and checks that it's the same AST,
+// and that printing it again gives the same code. Returns the printed code.
+fn assert_round_trips(ast: &Ast) -> String {
+    let printed = unparse(ast);
+    let reparsed = parse_src(&printed);
+
+    assert_eq!(
+        without_spans(format!("{reparsed:?}")),
+        without_spans(format!("{ast:?}")),
+        "the printed code parsed differently:\n{printed}"
+    );
+    assert_eq!(unparse(&reparsed), printed);
+
+    printed
+}
+
+#[test]
+fn examples_round_trip() {
+    let mut sources = sources_in("../../examples");
+    sources.extend(sources_in("../oxygen_fmt/tests/fixtures"));
+    assert!(!sources.is_empty());
+
+    for (_, src) in sources {
+        assert_round_trips(&parse_src(&src));
+    }
+}
+
+// Each expression is printed with only the brackets it needs
+#[test]
+fn minimal_brackets() {
+    for (src, expected) in [
+        ("(1 + 2) * 3", "(1 + 2) * 3"),
+        ("(1 + (2 * 3))", "1 + 2 * 3"),
+        ("(a - b) - c", "a - b - c"),
+        ("a - (b - c)", "a - (b - c)"),
+        ("2 ** (3 ** 2)", "2 ** 3 ** 2"),
+        ("(2 ** 3) ** 2", "(2 ** 3) ** 2"),
+        ("-(2 ** 2)", "-2 ** 2"),
+        ("(-2) ** 2", "(-2) ** 2"),
+        ("2 ** (-x)", "2 ** -x"),
+        ("-(-x)", "--x"),
+        ("(-x) as i64", "-x as i64"),
+        ("-(x as i64)", "-(x as i64)"),
+        ("((a as i32) as i64)", "a as i32 as i64"),
+        ("(a || b) && c", "(a || b) && c"),
+        ("a || (b && c)", "a || b && c"),
+        ("(a == b) == c", "a == b == c"),
+        ("(1 << 2) + 3", "(1 << 2) + 3"),
+        ("x |> f() |> g(1)", "x |> f() |> g(1)"),
+        ("(x |> f()) + 1", "(x |> f()) + 1"),
+        ("(x + 1) |> f()", "x + 1 |> f()"),
+        ("a < (x |> f())", "a < x |> f()"),
+        ("x |> (f)(c)", "x |> f(c)"),
+        ("(x |> f())()", "(x |> f())()"),
+        ("x |> list.push(y)", "x |> list.push(y)"),
+        ("a = (b = c)", "a = b = c"),
+        ("(a += 1)", "a += 1"),
+        ("(f)(x)[0].1.len()", "f(x)[0].1.len()"),
+        ("(a + b)[0]", "(a + b)[0]"),
+        ("(1).0", "(1).0"),
+        ("pair.0.1", "pair.0.1"),
+        ("(return 1) + 2", "(return 1) + 2"),
+        ("f((a, b), (c,), ())", "f((a, b), (c,), ())"),
+        ("f(x: 1 + 2, y: (a = 3))", "f(x: 1 + 2, y: a = 3)"),
+        ("[(1 + 2); 3]", "[1 + 2; 3]"),
+        ("(if a { 1 } else { 2 }) + 3", "if a {\n  1\n} else {\n  2\n} + 3"),
+        ("(func(i32 x) ~ i32 { x })(1)", "func(i32 x) ~ i32 {\n  x\n}(1)"),
+        ("0x1F + 1.5e3 + 2.0f32 + 7u8", "31 + 1500.0 + 2.0f32 + 7u8"),
+        ("\"a\\n\\\"b\\\" 'c'\"", "\"a\\n\\\"b\\\" 'c'\""),
+        ("'\\''", "'\\''")
+    ] {
+        let block = parse_statements_src(src);
+        let expression = block.tail.unwrap_or_else(|| panic!("`{src}` isn't an expression"));
+
+        assert_eq!(unparse_expression(&expression), expected, "printing `{src}`");
+    }
+}
+
+#[test]
+fn types() {
+    for (src, expected) in [
+        ("(i32)", "i32"),
+        ("(i32,)", "(i32,)"),
+        ("[(f64, bool); 4]", "[(f64, bool); 4]"),
+        ("impure func(i32, [u8]) ~ char!", "impure func(i32, [u8]) ~ char!"),
+        // The `!` belongs to whichever return type it's bracketed with
+        ("func() ~ (func() ~ i32)!", "func() ~ (func() ~ i32)!"),
+        ("func() ~ (func() ~ i32!)", "func() ~ func() ~ i32!"),
+        ("func() ~ func()!", "func() ~ func()!")
+    ] {
+        let src = format!("const X: {src} = 0;");
+        let [TopLevelItem::Global(global)] = &parse_src(&src).items[..] else { unreachable!() };
+
+        assert_eq!(unparse_type(&global.r#type), expected);
+    }
+}
+
+#[test]
+fn statements_keep_their_meaning() {
+    // An `if` at the end of a block is its value without a `;`, and
+    // `-1` after an `if` would be subtracted from it without one
+    let src = "\
+func main() {
+  if a {
+    f();
+  };
+}
+
+func values() ~ i32 {
+  if a {
+    1
+  } else {
+    2
+  };
+  -1
+}
+
+func tail() ~ i32 {
+  loop {
+    break;
+  }
+  if a {
+    1
+  } else {
+    2
+  }
+}
+";
+    assert_eq!(assert_round_trips(&parse_src(src)), src);
+
+    let block = parse_statements_src("let x = 1; defer print(x); x");
+    assert_eq!(unparse_statements(&block), "let x = 1;\ndefer print(x);\nx\n");
+}
+
+#[test]
+fn printed_code_is_formatted() {
+    for (path, src) in sources_in("../../examples") {
+        let printed = assert_round_trips(&parse_src(&src));
+        assert_eq!(oxygen_fmt::format(&printed), printed, "{} isn't formatted when printed", path.display());
+    }
+}
+
+// Random ASTs of every kind of item, statement, expression and type that
+// the parser can give, made from a seed so that failures can be repeated
+struct Generator {
+    state: u64,
+    // Counts up to give each binding a different name, since
+    // a pattern or parameter list can't bind a name twice
+    names: usize,
+    span: Span
+}
+
+impl Generator {
+    fn new(seed: u64) -> Self {
+        Generator {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+            names: 0,
+            span: Span::new(file("<generated>", ""), 0, 0)
+        }
+    }
+
+    // xorshift64*
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.next() % 100 < percent
+    }
+
+    fn pick<T: Copy>(&mut self, options: &[T]) -> T {
+        options[self.below(options.len())]
+    }
+
+    fn several<T>(&mut self, max: usize, mut generate: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        let count = self.below(max + 1);
+        (0..count).map(|_| generate(self)).collect()
+    }
+
+    fn name(&mut self) -> String {
+        self.pick(&["a", "b", "value", "count", "x1", "_tmp", "snake_case2"]).to_string()
+    }
+
+    fn fresh_name(&mut self) -> String {
+        self.names += 1;
+        format!("n{}", self.names)
+    }
+
+    fn program(&mut self) -> Ast {
+        let mut items = self.several(6, Self::item);
+        items.push(self.item());
+
+        Ast {
+            docs: self.chance(20).then(|| self.pick(&["The module", "Two\nlines", "", "  indented", "/ not a shebang"]).to_string()),
+            items
+        }
+    }
+
+    fn item(&mut self) -> TopLevelItem {
+        let span = self.span;
+
+        match self.below(7) {
+            0 | 1 => TopLevelItem::Function(self.function(true)),
+            2 => TopLevelItem::Global(Global {
+                kind: self.pick(&[GlobalKind::Const, GlobalKind::Static]),
+                public: self.chance(30),
+                name: self.fresh_name().to_uppercase(),
+                r#type: self.r#type(2),
+                value: self.expression(2),
+                span
+            }),
+            3 => TopLevelItem::Extern(ExternBlock {
+                abi: Abi::C,
+                functions: self.several(3, |generator| generator.function(false)),
+                span
+            }),
+            4 => TopLevelItem::Module(ModuleDeclaration { public: self.chance(30), name: self.name(), span }),
+            5 => TopLevelItem::Use(UseDeclaration { public: self.chance(30), path: self.path(1), span }),
+            _ => TopLevelItem::StaticAssert(StaticAssert { condition: self.expression(2), span })
+        }
+    }
+
+    fn function(&mut self, can_have_block: bool) -> Function {
+        let has_block = can_have_block && self.chance(80);
+
+        Function {
+            docs: self.chance(20).then(|| self.pick(&["Adds one", "Two\nlines", "", "  indented", "# heading"]).to_string()),
+            public: self.chance(30),
+            impure: self.chance(40),
+            name: self.fresh_name(),
+            parameters: self.parameters(true),
+            return_type: self.chance(60).then(|| self.return_type(2)),
+            block: has_block.then(|| self.block(3)),
+            span: self.span
+        }
+    }
+
+    fn parameters(&mut self, defaults: bool) -> Option<FunctionParameters> {
+        let parameters = self.several(3, |generator| Param {
+            pattern: generator.pattern(2),
+            r#type: generator.r#type(2),
+            default: (defaults && generator.chance(20)).then(|| generator.expression(2)),
+            span: generator.span
+        });
+
+        (!parameters.is_empty()).then_some(parameters)
+    }
+
+    fn pattern(&mut self, depth: usize) -> Pattern {
+        match self.below(if depth == 0 { 2 } else { 4 }) {
+            0 => Pattern::Wildcard,
+            1 | 2 => Pattern::Ident(self.fresh_name()),
+            _ => {
+                let mut patterns = self.several(2, |generator| generator.pattern(depth - 1));
+                patterns.push(self.pattern(depth - 1));
+                Pattern::Tuple(patterns)
+            }
+        }
+    }
+
+    fn return_type(&mut self, depth: usize) -> ReturnType {
+        (self.r#type(depth), self.chance(30))
+    }
+
+    fn r#type(&mut self, depth: usize) -> Type {
+        let ints = [IntTy::I8, IntTy::I16, IntTy::I32, IntTy::I64, IntTy::U8, IntTy::U16, IntTy::U32, IntTy::U64];
+
+        match self.below(if depth == 0 { 6 } else { 10 }) {
+            0 => Type::Int(self.pick(&ints)),
+            1 => Type::Float(self.pick(&[FloatTy::F32, FloatTy::F64])),
+            2 => Type::Bool,
+            3 => Type::Char,
+            4 => Type::Unit,
+            5 => Type::Never,
+            6 => Type::Array(Box::new(self.r#type(depth - 1)), self.below(100)),
+            7 => Type::Slice(Box::new(self.r#type(depth - 1))),
+            8 => {
+                let mut types = self.several(2, |generator| generator.r#type(depth - 1));
+                types.push(self.r#type(depth - 1));
+                Type::Tuple(types)
+            },
+            _ => Type::Function {
+                impure: self.chance(50),
+                parameters: self.several(2, |generator| generator.r#type(depth - 1)),
+                return_type: self.chance(70).then(|| Box::new(self.return_type(depth - 1)))
+            }
+        }
+    }
+
+    // A path in an expression has at least two segments, since
+    // one by itself is parsed as `ExpressionKind::Ident`
+    fn path(&mut self, min_segments: usize) -> Path {
+        let count = min_segments + self.below(2);
+        let segments = (0..count).map(|_| self.name()).collect();
+        Path { segments, span: self.span }
+    }
+
+    fn block(&mut self, depth: usize) -> Block {
+        Block {
+            statements: self.several(3, |generator| generator.statement(depth)),
+            tail: self.chance(50).then(|| Box::new(self.expression(depth)))
+        }
+    }
+
+    fn statement(&mut self, depth: usize) -> Statement {
+        let span = self.span;
+
+        match self.below(8) {
+            0 | 1 => Statement::Let {
+                pattern: self.pattern(2),
+                r#type: self.chance(40).then(|| self.r#type(2)),
+                value: self.expression(depth),
+                span
+            },
+            2 => Statement::Defer {
+                body: match self.chance(50) {
+                    true => Deferred::Block(self.block(depth.saturating_sub(1))),
+                    false => Deferred::Expression(self.expression(depth))
+                },
+                span
+            },
+            // `return` without a value can only go at the end of a statement
+            3 => Statement::Expression(self.expression_of(ExpressionKind::Return(None))),
+            4 => Statement::Expression(self.if_expression(depth)),
+            _ => Statement::Expression(self.expression(depth))
+        }
+    }
+
+    fn expression_of(&self, kind: ExpressionKind) -> Expression {
+        Expression { kind, span: self.span }
+    }
+
+    fn boxed(&mut self, depth: usize) -> Box<Expression> {
+        Box::new(self.expression(depth))
+    }
+
+    fn if_expression(&mut self, depth: usize) -> Expression {
+        let depth = depth.saturating_sub(1);
+
+        let else_branch = match self.below(3) {
+            0 => None,
+            1 => Some(ElseBranch::Block(self.block(depth))),
+            _ => Some(ElseBranch::If(Box::new(self.if_expression(depth))))
+        };
+
+        let kind = ExpressionKind::IfExpression {
+            condition: self.boxed(depth),
+            block: self.block(depth),
+            else_branch
+        };
+
+        self.expression_of(kind)
+    }
+
+    fn literal(&mut self) -> LiteralType {
+        let ints = [IntTy::I8, IntTy::I16, IntTy::I32, IntTy::I64, IntTy::U8, IntTy::U16, IntTy::U32, IntTy::U64];
+        let chars = ['a', 'Z', '0', ' ', '\n', '\t', '\r', '\0', '\\', '\'', '"', 'é', '🦀'];
+
+        match self.below(6) {
+            0 => LiteralType::Integer {
+                value: self.next() as u128 >> self.below(64),
+                suffix: self.chance(30).then(|| self.pick(&ints))
+            },
+            1 => LiteralType::Float {
+                value: match self.below(3) {
+                    0 => self.below(10_000) as f64 / 8.0,
+                    1 => f64::from_bits(self.next() >> 2),
+                    _ => self.pick(&[0.1, 1e-7, 1e300, 5e-324, f64::MAX, f64::INFINITY])
+                },
+                suffix: self.chance(30).then(|| self.pick(&[FloatTy::F32, FloatTy::F64]))
+            },
+            2 => LiteralType::Char(self.pick(&chars)),
+            3 => LiteralType::String(self.several(6, |generator| generator.pick(&chars)).into_iter().collect()),
+            4 => LiteralType::True,
+            _ => LiteralType::False
+        }
+    }
+
+    // Something that can be assigned to
+    fn place(&mut self, depth: usize) -> Expression {
+        let kind = match self.below(4) {
+            0 => ExpressionKind::Ident(self.name()),
+            1 => ExpressionKind::Path(self.path(2)),
+            2 => ExpressionKind::Index { target: self.boxed(depth), index: self.boxed(depth) },
+            _ => ExpressionKind::TupleIndex { target: self.boxed(depth), index: self.below(12) }
+        };
+
+        self.expression_of(kind)
+    }
+
+    fn arguments(&mut self, depth: usize, piped: bool) -> Option<CallParameters> {
+        let mut arguments = Vec::new();
+
+        if piped {
+            let value = self.expression(depth);
+            arguments.push(Argument { name: None, value, span: self.span, piped: true });
+        }
+
+        // Positional arguments can't come after named ones
+        for _ in 0..self.below(3) {
+            let value = self.expression(depth);
+            arguments.push(Argument { name: None, value, span: self.span, piped: false });
+        }
+        for _ in 0..self.below(2) {
+            let name = self.name();
+            let value = self.expression(depth);
+            arguments.push(Argument { name: Some(name), value, span: self.span, piped: false });
+        }
+
+        (!arguments.is_empty()).then_some(arguments)
+    }
+
+    fn expression(&mut self, depth: usize) -> Expression {
+        let operators = [
+            BinaryOperator::Plus, BinaryOperator::Minus, BinaryOperator::Star, BinaryOperator::Divide,
+            BinaryOperator::Remainder, BinaryOperator::Power, BinaryOperator::And, BinaryOperator::Or,
+            BinaryOperator::Xor, BinaryOperator::ShiftLeft, BinaryOperator::ShiftRight, BinaryOperator::EqEq,
+            BinaryOperator::NotEq, BinaryOperator::Greater, BinaryOperator::GreaterEq, BinaryOperator::Less,
+            BinaryOperator::LessEq, BinaryOperator::LogicalAnd, BinaryOperator::LogicalOr
+        ];
+
+        if depth == 0 {
+            let kind = match self.below(4) {
+                0 => ExpressionKind::Ident(self.name()),
+                1 => ExpressionKind::Path(self.path(2)),
+                2 => ExpressionKind::Break,
+                _ => ExpressionKind::Literal(self.literal())
+            };
+
+            return self.expression_of(kind);
+        }
+
+        let depth = depth - 1;
+        let kind = match self.below(22) {
+            0 => ExpressionKind::Literal(self.literal()),
+            1 => ExpressionKind::Ident(self.name()),
+            2 | 3 => {
+                let piped = self.chance(30);
+                ExpressionKind::FunctionCall { callee: self.boxed(depth), parameters: self.arguments(depth, piped) }
+            },
+            4 => ExpressionKind::Closure {
+                impure: self.chance(30),
+                parameters: self.parameters(false),
+                return_type: self.chance(50).then(|| self.return_type(2)),
+                block: self.block(depth)
+            },
+            5 => {
+                let piped = self.chance(30);
+                ExpressionKind::MethodCall { path: self.boxed(depth), name: self.name(), parameters: self.arguments(depth, piped) }
+            },
+            6 => ExpressionKind::Cast { value: self.boxed(depth), r#type: self.r#type(2) },
+            7..=10 => ExpressionKind::Binary {
+                left: self.boxed(depth),
+                operator: self.pick(&operators),
+                right: self.boxed(depth)
+            },
+            11 | 12 => ExpressionKind::Unary {
+                operator: self.pick(&[UnaryOperator::Negate, UnaryOperator::Not, UnaryOperator::BitNot]),
+                right: self.boxed(depth)
+            },
+            13 => return self.if_expression(depth),
+            14 => ExpressionKind::Loop(self.block(depth)),
+            15 => ExpressionKind::Return(Some(self.boxed(depth))),
+            16 => ExpressionKind::Array(self.several(3, |generator| generator.expression(depth))),
+            17 => ExpressionKind::ArrayRepeat { value: self.boxed(depth), count: self.below(64) },
+            18 => ExpressionKind::Index { target: self.boxed(depth), index: self.boxed(depth) },
+            19 => ExpressionKind::Tuple(self.several(3, |generator| generator.expression(depth))),
+            20 => ExpressionKind::TupleIndex { target: self.boxed(depth), index: self.below(12) },
+            _ => ExpressionKind::Assign {
+                target: Box::new(self.place(depth)),
+                operator: self.chance(50).then(|| self.pick(&operators[..11])),
+                value: self.boxed(depth)
+            }
+        };
+
+        self.expression_of(kind)
+    }
+}
+
+#[test]
+fn generated_programs_round_trip() {
+    for seed in 0..500 {
+        let ast = Generator::new(seed).program();
+        assert_round_trips(&ast);
+    }
+}
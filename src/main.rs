@@ -1,14 +1,29 @@
 use std::env;
+use std::io::Write;
+use std::thread;
 
 use oxygen::run_compiler;
 
+// The interpreter goes through several of its own functions for every
+// call in the program being run, so it needs a lot more stack than the
+// main thread has to get as deep as the interpreter lets calls go
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
 fn main() {
     let arguments: Vec<String> = env::args().collect();
 
-    run_compiler(arguments).unwrap_or_else(|_err| {
-        // oxygen_error::Result is an `ErrorEmitted` type,
-        // so we don't actually care too much about the error,
-        // since it has already been emitted to the user.
-        std::process::exit(1);
-    });
+    // oxygen_error::Result is an `ErrorEmitted` type,
+    // so we don't actually care too much about the error,
+    // since it has already been emitted to the user.
+    let compiler = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || run_compiler(arguments).unwrap_or(1))
+        .unwrap();
+
+    // A panic inside of the compiler has already been printed
+    let status = compiler.join().unwrap_or(101);
+
+    // Exiting doesn't flush anything the program printed
+    let _ = std::io::stdout().flush();
+    std::process::exit(status);
 }
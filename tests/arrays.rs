@@ -0,0 +1,125 @@
+mod common;
+
+use common::{ error, panic, run, stdout };
+
+#[test]
+fn arrays_can_be_indexed_and_assigned_to() {
+    let output = stdout("index", "func main() {
+  let xs = [1, 2, 3];
+  xs[1] = 5;
+  xs[2] += 10;
+  print(xs);
+  print(xs[0] + xs[1]);
+}
+");
+    assert_eq!(output, "[1, 5, 13]6");
+}
+
+#[test]
+fn array_repeats_copy_their_value() {
+    let output = stdout("repeat", "func main() {
+  let row = [0; 2];
+  let grid = [row; 3];
+  grid[1][0] = 5;
+  print(grid);
+  print(row);
+  print([true; 0]);
+}
+");
+    assert_eq!(output, "[[0, 0], [5, 0], [0, 0]][0, 0][]");
+}
+
+#[test]
+fn arrays_are_passed_as_slices() {
+    let output = stdout("slices", "func sum([i32] numbers) ~ i32 {
+  let total = 0;
+  let i = 0;
+  loop {
+    if i == numbers.len() { break; }
+    total += numbers[i];
+    i += 1;
+  }
+  total
+}
+
+func main() {
+  let empty: [i32; 0] = [];
+  print(sum([1, 2, 3]));
+  print(sum([4; 5]));
+  print(sum(empty));
+}
+");
+    assert_eq!(output, "6200");
+}
+
+#[test]
+fn indexing_out_of_bounds_panics() {
+    let output = run("out-of-bounds", "func main() {
+  let xs = [1, 2, 3];
+  print(xs[3]);
+}
+");
+    assert_eq!(output.panic(), Some("index out of bounds: the length is 3 but the index is 3"));
+    assert_eq!(output.panic_location(), Some("3:9"));
+
+    let message = panic("negative-index", "func main() {
+  let xs = [1, 2];
+  let i = 0 - 1;
+  print(xs[i]);
+}
+");
+    assert_eq!(message, "index out of bounds: the length is 2 but the index is -1");
+
+    let message = panic("slice-out-of-bounds", "func first([bool] flags) ~ bool {
+  flags[0]
+}
+
+func main() {
+  let none: [bool; 0] = [];
+  first(none);
+}
+");
+    assert_eq!(message, "index out of bounds: the length is 0 but the index is 0");
+}
+
+#[test]
+fn array_types_are_checked() {
+    assert_eq!(
+        error("length", "func main() {\n  let xs: [i32; 3] = [1, 2];\n}\n"),
+        "Mismatched types: expected `[i32; 3]` but found `[i32; 2]`"
+    );
+    assert_eq!(
+        error("elements", "func main() {\n  let xs = [1, true];\n}\n"),
+        "Mismatched types: expected `i32` but found `bool`"
+    );
+    assert_eq!(
+        error("empty", "func main() {\n  let xs = [];\n}\n"),
+        "Couldn't work out the type of this empty array"
+    );
+    assert_eq!(
+        error("not-an-array", "func main() {\n  let x = 4;\n  print(x[0]);\n}\n"),
+        "Can't index into a value of type `i32`"
+    );
+    assert_eq!(
+        error("index-type", "func main() {\n  let xs = [1, 2];\n  print(xs[true]);\n}\n"),
+        "Mismatched types: expected `i32` but found `bool`"
+    );
+}
+
+#[test]
+fn repeating_a_value_too_many_times_is_an_error() {
+    let output = run("huge-repeat", "func main() {\n  let xs = [0; 99999999999];\n}\n");
+    assert_eq!(output.errors(), [
+        "`[i32; 99999999999]` is too big to make by repeating a value, which can make at most 1000000 values"
+    ]);
+    assert_eq!(output.location(), Some("2:12"));
+
+    // The values inside of each element count too
+    assert_eq!(
+        error("nested-repeat", "func main() {\n  let xs = [[(0, true); 1000]; 1000];\n}\n"),
+        "`[[(i32, bool); 1000]; 1000]` is too big to make by repeating a value, which can make at most 1000000 values"
+    );
+
+    let output = stdout("largest-repeat", "func main() {\n  let xs = [[0; 1000]; 1000];\n  print(xs.len());\n}\n");
+    assert_eq!(output, "1000");
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn passing_assertions_do_nothing() {
+    let output = stdout("pass", "const LIMIT: i32 = 10;
+static_assert(LIMIT < 100);
+
+func main() {
+  let x = 3;
+  assert(x > 1);
+  assert(x == 3, \"x should be 3\");
+  assert_eq(x * 2, 6);
+  assert_eq((x, true), (3, true), \"pairs\");
+  print(\"ok\");
+}
+");
+    assert_eq!(output, "ok");
+}
+
+#[test]
+fn failed_assertions_show_the_code_that_failed() {
+    let output = run("assert", "func main() {\n  let x = 3;\n  assert(x < 2);\n}\n");
+    assert_eq!(output.panic(), Some("assertion `x < 2` failed"));
+    assert_eq!(output.panic_location(), Some("3:3"));
+
+    let output = run("assert-message", "func main() {\n  let x = 3;\n  assert(x < 2, \"x is too big\");\n}\n");
+    assert_eq!(output.panic(), Some("assertion `x < 2` failed: x is too big"));
+}
+
+#[test]
+fn failed_equality_assertions_show_both_values() {
+    let output = run("assert-eq", "func main() {\n  let x = 3;\n  assert_eq(x + 1, 5);\n}\n");
+    assert!(output.stderr.ends_with("assertion `x + 1 == 5` failed\n  left: 4\n right: 5\n"), "{}", output.stderr);
+    assert_eq!(output.panic_location(), Some("3:3"));
+
+    let output = run("assert-eq-message", "func main() {\n  let x = 3;\n  assert_eq(x + 1, 5, \"off by one\");\n}\n");
+    assert!(output.stderr.ends_with("assertion `x + 1 == 5` failed: off by one\n  left: 4\n right: 5\n"), "{}", output.stderr);
+}
+
+#[test]
+fn failed_static_assertions_stop_compilation() {
+    let output = run("static", "const LIMIT: i32 = 10;\nstatic_assert(LIMIT < 100);\nstatic_assert(LIMIT * 20 < 100);\n\nfunc main() {\n  print(\"ran\");\n}\n");
+    assert_eq!(output.status, Some(1));
+    assert_eq!(output.stdout, "");
+    assert_eq!(output.errors(), ["Static assertion `LIMIT * 20 < 100` failed"]);
+    assert_eq!(output.location(), Some("3:1"));
+}
+
+#[test]
+fn assertions_are_checked() {
+    assert_eq!(error("condition", "func main() {\n  assert(1);\n}\n"), "Mismatched types: expected `bool` but found `i32`");
+    assert_eq!(
+        error("no-arguments", "func main() {\n  assert();\n}\n"),
+        "The builtin `assert` takes 1 or 2 arguments but 0 were given"
+    );
+    assert_eq!(
+        error("message", "func main() {\n  assert(true, 1);\n}\n"),
+        "Mismatched types: expected `str` but found `i32`"
+    );
+    assert_eq!(
+        error("different-types", "func main() {\n  assert_eq(1, true);\n}\n"),
+        "The builtin `assert_eq` can only compare two values of the same type, not `i32` and `bool`"
+    );
+    assert_eq!(
+        error("one-argument", "func main() {\n  assert_eq(1);\n}\n"),
+        "The builtin `assert_eq` takes 2 or 3 arguments but 1 were given"
+    );
+    assert_eq!(
+        error("static-condition", "static_assert(1);\n\nfunc main() {}\n"),
+        "Mismatched types: expected `bool` but found `i32`"
+    );
+    assert_eq!(
+        error("static-static", "static S: i32 = 1;\nstatic_assert(S == 1);\n\nfunc main() {}\n"),
+        "Can't use the static `crate::S` from a pure function, unless that function is marked `impure`"
+    );
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::{ error, panic, run, stdout };
+
+#[test]
+fn casts_convert_between_numbers() {
+    let output = run("values", "func main() {
+  let big = 300;
+  let negative = -1;
+  print(big as u8);
+  print(\" \");
+  print(negative as u32);
+  print(\" \");
+  print(-2.9 as i32);
+  print(\" \");
+  print(1e10 as i32);
+  print(\" \");
+  print(7 as f64 / 2.0);
+  print(\" \");
+  print(true as i32);
+  print(\" \");
+  print(65u8 as char);
+  print(\" \");
+  print(big as i64);
+}
+");
+    assert_eq!(output.status, Some(0), "{}", output.stderr);
+    assert_eq!(output.stdout, "44 4294967295 -2 2147483647 3.5 1 A 300");
+}
+
+#[test]
+fn lossy_casts_are_warned_about() {
+    let output = run("lossy", "func main() {
+  let big = 300;
+  let wide: i64 = 5;
+  let a = big as u8;
+  let b = 2.9 as i32;
+  let c = wide as f32;
+  let d = big as i64;
+}
+");
+    assert_eq!(output.status, Some(0));
+    assert_eq!(output.warnings(), [
+        "Casting `i32` to `u8` can change the value, since it is wrapped around to fit, \
+        `u8::try_from` can be used to check that it fits instead",
+        "Casting `f64` to `i32` can change the value, since its fractional part is dropped, \
+        and values outside of what `i32` can hold are clamped",
+        "Casting `i64` to `f32` can change the value, since it is rounded to the nearest `f32`"
+    ]);
+}
+
+#[test]
+fn try_from_checks_that_the_value_fits() {
+    let output = stdout("fits", "func main() {\n  print(u8::try_from(200));\n  print(char::try_from(97));\n}\n");
+    assert_eq!(output, "200a");
+
+    let message = panic("too-big", "func main() {\n  let x = 300;\n  print(u8::try_from(x));\n}\n");
+    assert_eq!(message, "`300` doesn't fit in `u8`, which goes from 0 to 255");
+
+    let message = panic("not-a-char", "func main() {\n  let x = 1114112;\n  print(char::try_from(x));\n}\n");
+    assert_eq!(message, "`1114112` isn't a valid `char`");
+}
+
+#[test]
+fn invalid_casts_and_conversions_are_errors() {
+    assert_eq!(
+        error("to-bool", "func main() {\n  let x = 1 as bool;\n}\n"),
+        "Can't cast `i32` to `bool`, compare it with zero instead e.g. `x != 0`"
+    );
+    assert_eq!(
+        error("to-char", "func main() {\n  let x = 65 as char;\n}\n"),
+        "Can't cast `i32` to `char`, only `u8` can be, use `char::try_from` instead"
+    );
+    assert_eq!(
+        error("tuple", "func main() {\n  let x = (1, 2) as i32;\n}\n"),
+        "Can't cast `(i32, i32)` to `i32`"
+    );
+    assert_eq!(
+        error("try-from-bool", "func main() {\n  let x = u8::try_from(true);\n}\n"),
+        "The builtin `u8::try_from` converts from an integer, not `bool`"
+    );
+    assert_eq!(
+        error("try-from-float", "func main() {\n  let x = f32::try_from(1);\n}\n"),
+        "There is no function `try_from` on the type `f32`"
+    );
+    assert_eq!(
+        error("literal", "func main() {\n  let x = 300u8;\n}\n"),
+        "The literal `300` doesn't fit in `u8`, which goes from 0 to 255"
+    );
+}
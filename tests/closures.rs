@@ -0,0 +1,95 @@
+mod common;
+
+use common::{ error, stdout };
+
+#[test]
+fn functions_and_closures_are_values() {
+    let output = stdout("values", "func apply(func(i32) ~ i32 f, i32 x) ~ i32 {
+  f(x)
+}
+
+func double(i32 x) ~ i32 {
+  x * 2
+}
+
+func main() {
+  let offset = 10;
+  let add = func(i32 x) ~ i32 { x + offset };
+  print(apply(double, 4));
+  print(apply(add, 4));
+  print(apply(func(i32 x) { x - 1 }, 4));
+}
+");
+    assert_eq!(output, "8143");
+}
+
+#[test]
+fn impure_closures_can_be_called_from_impure_functions() {
+    let output = stdout("impure", "impure func call(impure func() f) {
+  f();
+}
+
+impure func main() {
+  call(impure func() { print(\"hi\"); });
+}
+");
+    assert_eq!(output, "hi");
+}
+
+#[test]
+fn function_types_are_checked() {
+    assert_eq!(
+        error("return-type", "func main() {\n  let f: func(i32) ~ bool = func(i32 x) ~ i32 { x };\n}\n"),
+        "Mismatched types: expected `func(i32) ~ bool` but found `func(i32) ~ i32`"
+    );
+    assert_eq!(
+        error("argument", "func main() {\n  let f = func(i32 x) ~ i32 { x };\n  f(true);\n}\n"),
+        "Mismatched types: expected `i32` but found `bool`"
+    );
+    assert_eq!(
+        error("not-callable", "func main() {\n  let x = 4;\n  x();\n}\n"),
+        "A value of type `i32` can't be called"
+    );
+    assert_eq!(
+        error("builtin-value", "func main() {\n  print;\n}\n"),
+        "The builtin `print` can only be called, not used as a value"
+    );
+}
+
+#[test]
+fn pure_functions_cannot_have_effects() {
+    assert_eq!(
+        error("pure-calls-impure", "impure func shout() {
+  print(\"!\");
+}
+
+func helper() {
+  shout();
+}
+
+func main() {
+  helper();
+}
+"),
+        "Can't call the impure function `crate::shout` from a pure function, unless that function is marked `impure`"
+    );
+    assert_eq!(
+        error("pure-calls-impure-closure", "func helper(impure func() f) {\n  f();\n}\n\nfunc main() {}\n"),
+        "Can't call the impure function `f` from a pure function, unless that function is marked `impure`"
+    );
+    assert_eq!(
+        error("impure-as-pure", "impure func shout() {
+  print(\"!\");
+}
+
+func call(func() f) {
+  f();
+}
+
+impure func main() {
+  call(shout);
+}
+"),
+        "Mismatched types: expected `func()` but found `impure func()`"
+    );
+}
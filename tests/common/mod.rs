@@ -0,0 +1,127 @@
+// Helpers shared by the tests that run the `oxygen` binary on whole programs
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+pub struct Output {
+    // The exit status, which is None if the process was killed
+    pub status: Option<i32>,
+    pub stdout: String,
+    // Without any colours, so messages can be compared as they read
+    pub stderr: String
+}
+
+impl Output {
+    // The messages of every error, in the order they were emitted
+    pub fn errors(&self) -> Vec<&str> {
+        self.stderr.lines().filter_map(|line| line.strip_prefix("error: ")).collect()
+    }
+
+    pub fn warnings(&self) -> Vec<&str> {
+        self.stderr.lines().filter_map(|line| line.strip_prefix("warning: ")).collect()
+    }
+
+    // Where the first error points to as `line:column`
+    pub fn location(&self) -> Option<&str> {
+        self.stderr.lines()
+            .find_map(|line| line.trim_start().strip_prefix("--> "))
+            .map(line_and_column)
+    }
+
+    // The message the program panicked with, if it did
+    pub fn panic(&self) -> Option<&str> {
+        let mut lines = self.stderr.lines();
+        lines.find(|line| line.starts_with("panicked at "))?;
+        lines.next()
+    }
+
+    // Where the program panicked as `line:column`
+    pub fn panic_location(&self) -> Option<&str> {
+        self.stderr.lines()
+            .find_map(|line| line.strip_prefix("panicked at "))
+            .map(|location| line_and_column(location.strip_suffix(':').unwrap()))
+    }
+}
+
+// Drops the directory from `path:line:column`, since it's different each run
+fn line_and_column(location: &str) -> &str {
+    let column = location.rfind(':').unwrap();
+    let line = location[..column].rfind(':').unwrap();
+    &location[line + 1..]
+}
+
+// Removes the escape codes that colour the diagnostics
+fn strip_colours(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Every code used is a `CSI ... m` sequence
+            for c in chars.by_ref() {
+                if c == 'm' { break };
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+// Writes `files` into a new directory, each as `(path, source)`, and runs
+// the first one as the crate root with `arguments` before its path
+pub fn run_files(name: &str, files: &[(&str, &str)], arguments: &[&str]) -> Output {
+    let directory: PathBuf = std::env::temp_dir()
+        .join(format!("oxygen-test-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+
+    for (path, source) in files {
+        let path = directory.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_oxygen"))
+        .args(arguments)
+        .arg(directory.join(files[0].0))
+        .output()
+        .unwrap();
+
+    fs::remove_dir_all(&directory).unwrap();
+
+    Output {
+        status: output.status.code(),
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: strip_colours(&String::from_utf8(output.stderr).unwrap())
+    }
+}
+
+// Runs `source` as a program by itself
+pub fn run(name: &str, source: &str) -> Output {
+    run_files(name, &[("main.ox", source)], &[])
+}
+
+// Runs `source`, which should succeed, returning what it printed
+pub fn stdout(name: &str, source: &str) -> String {
+    let output = run(name, source);
+    assert_eq!(output.status, Some(0), "the program failed:\n{}", output.stderr);
+    output.stdout
+}
+
+// Runs `source`, which should fail to compile, returning
+// the message of its first error
+pub fn error(name: &str, source: &str) -> String {
+    let output = run(name, source);
+    assert_ne!(output.status, Some(0), "the program succeeded, printing:\n{}", output.stdout);
+    output.errors().first().expect(&output.stderr).to_string()
+}
+
+// Runs `source`, which should panic, returning what it panicked with
+pub fn panic(name: &str, source: &str) -> String {
+    let output = run(name, source);
+    assert_ne!(output.status, Some(0), "the program succeeded, printing:\n{}", output.stdout);
+    output.panic().expect(&output.stderr).to_string()
+}
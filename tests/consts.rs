@@ -0,0 +1,157 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn consts_and_statics_are_worked_out_while_compiling() {
+    let output = stdout("globals", "func square(i32 x) ~ i32 {
+  x * x
+}
+
+const LIMIT: i32 = square(8);
+static COUNTER: i32 = LIMIT + 1;
+
+impure func bump() {
+  COUNTER += 1;
+}
+
+impure func main() {
+  bump();
+  bump();
+  print(LIMIT);
+  print(\" \");
+  print(COUNTER);
+}
+");
+    assert_eq!(output, "64 67");
+}
+
+#[test]
+fn globals_that_depend_on_themselves_are_errors() {
+    let output = run("cycle", "const A: i32 = B;\nconst B: i32 = A;\n\nfunc main() {}\n");
+    assert_eq!(output.errors(), [
+        "The value of `crate::A` depends on itself",
+        "Couldn't work out the value of `A` while compiling"
+    ]);
+}
+
+#[test]
+fn panics_while_compiling_are_errors() {
+    let output = run("panic", "func f(i32 x) ~ i32 {
+  x / (x - 1)
+}
+
+const A: i32 = f(1);
+
+func main() {}
+");
+    assert_eq!(output.status, Some(1));
+    assert_eq!(output.panic(), Some("attempt to divide by zero"));
+    assert_eq!(output.errors(), ["Couldn't work out the value of `A` while compiling"]);
+    assert_eq!(output.location(), Some("5:1"));
+}
+
+#[test]
+fn recursion_while_compiling_is_limited() {
+    let output = run("recursion", "func f(i32 n) ~ i32 {
+  f(n + 1)
+}
+
+const A: i32 = f(1);
+
+func main() {}
+");
+    assert_eq!(output.status, Some(1));
+    assert_eq!(output.errors(), [
+        "This makes calls more than 256 deep while being worked out, so it might never finish",
+        "Couldn't work out the value of `A` while compiling"
+    ]);
+    assert_eq!(output.location(), Some("5:1"));
+
+    // Recursion that stays under the limit is fine
+    let output = stdout("shallow-recursion", "func depth(i32 n) ~ i32 {
+  if n == 0 { 0 } else { 1 + depth(n - 1) }
+}
+
+const A: i32 = depth(250);
+
+func main() {
+  print(A);
+}
+");
+    assert_eq!(output, "250");
+}
+
+#[test]
+fn recursion_at_run_time_is_limited() {
+    let output = run("endless-call", "func f() ~ i32 {
+  f()
+}
+
+func main() {
+  print(f());
+}
+");
+    assert_eq!(output.panic(), Some("This call goes more than 1000 calls deep, so the program might never finish"));
+    assert_eq!(output.panic_location(), Some("2:3"));
+
+    let output = stdout("deep-call", "func depth(i32 n) ~ i32 {
+  if n == 0 { 0 } else { 1 + depth(n - 1) }
+}
+
+func main() {
+  print(depth(990));
+}
+");
+    assert_eq!(output, "990");
+}
+
+#[test]
+fn loops_while_compiling_are_limited() {
+    let output = run("loop", "func spin() ~ i32 {
+  loop {}
+}
+
+func middle() ~ i32 {
+  spin()
+}
+
+const A: i32 = 1;
+static B: i32 = A + middle();
+
+func main() {}
+");
+    assert_eq!(output.status, Some(1));
+    assert_eq!(output.errors(), [
+        "This takes more than 1000000 steps to work out, so it might never finish",
+        "Couldn't work out the value of `B` while compiling"
+    ]);
+    assert_eq!(output.location(), Some("10:1"));
+
+    let output = run("assert-loop", "func spin() ~ bool {\n  loop {}\n}\n\nstatic_assert(spin());\n\nfunc main() {}\n");
+    assert_eq!(output.errors(), [
+        "This takes more than 1000000 steps to work out, so it might never finish",
+        "Couldn't work out this static assertion while compiling"
+    ]);
+    assert_eq!(output.location(), Some("5:1"));
+}
+
+#[test]
+fn globals_are_checked() {
+    assert_eq!(
+        error("type", "const A: i32 = true;\n\nfunc main() {}\n"),
+        "Mismatched types: expected `i32` but found `bool`"
+    );
+    assert_eq!(
+        error("assign-const", "const A: i32 = 1;\n\nimpure func main() {\n  A = 2;\n}\n"),
+        "Can't assign to `crate::A`, which isn't a `static`"
+    );
+    assert_eq!(
+        error("pure-static", "static S: i32 = 1;\n\nfunc get() ~ i32 {\n  S\n}\n\nfunc main() {}\n"),
+        "Can't use the static `crate::S` from a pure function, unless that function is marked `impure`"
+    );
+    assert_eq!(
+        error("impure-value", "impure func f() ~ i32 {\n  1\n}\n\nconst A: i32 = f();\n\nfunc main() {}\n"),
+        "Can't call the impure function `crate::f` from a pure function, unless that function is marked `impure`"
+    );
+}
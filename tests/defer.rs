@@ -0,0 +1,81 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn deferred_code_runs_in_reverse_order_when_its_scope_is_left() {
+    let output = stdout("order", "impure func work() {
+  defer print(\"1\");
+  defer {
+    print(\"2\");
+  }
+  if true {
+    defer print(\"3\");
+    print(\"4\");
+  }
+  print(\"5\");
+}
+
+impure func main() {
+  work();
+}
+");
+    assert_eq!(output, "43521");
+}
+
+#[test]
+fn deferred_code_runs_on_return_and_break() {
+    let output = stdout("return", "impure func early(bool b) ~ i32 {
+  defer print(\"cleanup \");
+  if b { return 1; }
+  2
+}
+
+impure func main() {
+  print(early(true));
+  print(\" \");
+  print(early(false));
+}
+");
+    assert_eq!(output, "cleanup 1 cleanup 2");
+
+    let output = stdout("break", "impure func main() {
+  let i = 0;
+  loop {
+    defer print(i);
+    i += 1;
+    if i == 3 { break; }
+  }
+}
+");
+    assert_eq!(output, "123");
+}
+
+#[test]
+fn deferred_code_sees_variables_as_they_are_when_it_runs() {
+    let output = stdout("variables", "impure func main() {\n  let x = 1;\n  defer print(x);\n  x = 2;\n}\n");
+    assert_eq!(output, "2");
+}
+
+#[test]
+fn deferred_code_runs_when_panicking() {
+    let output = run("panic", "impure func main() {\n  defer print(\"deferred\");\n  panic(\"boom\");\n}\n");
+    assert_eq!(output.stdout, "deferred");
+    assert_eq!(output.panic(), Some("boom"));
+}
+
+#[test]
+fn deferred_code_cannot_leave_its_scope() {
+    assert_eq!(
+        error("return_in_defer", "impure func f() ~ i32 {\n  defer { return 1; }\n  2\n}\n\nimpure func main() {}\n"),
+        "Deferred code can't `return`, since it runs as its scope is being left"
+    );
+    assert_eq!(
+        error("break_in_defer", "impure func main() {\n  loop {\n    defer { break; }\n  }\n}\n"),
+        "Deferred code can't `break` out of a `loop` around the `defer`"
+    );
+    assert_eq!(
+        error("pure", "func f() {\n  defer print(\"x\");\n}\n\nfunc main() {}\n"),
+        "Can't call the impure builtin `print` from a pure function, unless that function is marked `impure`"
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::{ error, panic, run, run_files, stdout };
+
+#[test]
+fn foreign_functions_are_called_through_the_host() {
+    let output = stdout("call", "extern \"C\" {
+  pure func abs(i32 x) ~ i32;
+  func putchar(i32 c) ~ i32;
+}
+
+impure func main() {
+  print(abs(-5));
+  putchar(65);
+}
+");
+    assert_eq!(output, "5A");
+}
+
+#[test]
+fn every_number_type_crosses_into_c() {
+    let output = stdout("numbers", "extern \"C\" {
+  pure func llabs(i64 x) ~ i64;
+  pure func sqrt(f64 x) ~ f64;
+  pure func sqrtf(f32 x) ~ f32;
+}
+
+impure func main() {
+  print(llabs(-5000000000));
+  print(\" \");
+  print(sqrt(2.25));
+  print(\" \");
+  print(sqrtf(6.25f32));
+}
+");
+    assert_eq!(output, "5000000000 1.5 2.5");
+}
+
+#[test]
+fn foreign_functions_cannot_be_called_while_compiling() {
+    let output = run("compiling", "extern \"C\" {
+  pure func abs(i32 x) ~ i32;
+}
+
+const A: i32 = abs(1);
+
+func main() {}
+");
+    assert_eq!(output.errors(), [
+        "Can't call the foreign function `abs` while compiling",
+        "Couldn't work out the value of `A` while compiling"
+    ]);
+}
+
+#[test]
+fn missing_foreign_functions_panic_when_called() {
+    let message = panic("missing", "extern \"C\" {
+  pure func nothing(i32 x) ~ i32;
+}
+
+impure func main() {
+  nothing(1);
+}
+");
+    assert_eq!(message, "The foreign function `nothing` isn't available when running in the interpreter");
+}
+
+#[test]
+fn foreign_signatures_are_checked() {
+    assert_eq!(
+        error("parameter", "extern \"C\" {\n  pure func f((i32, i32) x);\n}\n\nfunc main() {}\n"),
+        "The foreign function `f` can't take a `(i32, i32)`, which has no C representation"
+    );
+    assert_eq!(
+        error("fallible", "func abs(i32 x) ~ i32!;\n\nfunc main() {}\n"),
+        "The foreign function `abs` can't return an error (`!`), since C has no way to represent it"
+    );
+    assert_eq!(
+        error("abi", "extern \"Rust\" {\n}\n\nfunc main() {}\n"),
+        "Unsupported ABI \"Rust\", only \"C\" is supported"
+    );
+    assert_eq!(
+        error("host", "extern \"C\" {\n  pure func abs(i32 x) ~ bool;\n}\n\nfunc main() {}\n"),
+        "`abs` is declared as `bool abs(int32_t)`, but the interpreter provides `int32_t abs(int32_t)`"
+    );
+}
+
+#[test]
+fn foreign_functions_must_be_declared_the_same_everywhere() {
+    let output = run_files("declarations", &[
+        ("main.ox", "mod other;\n\nextern \"C\" {\n  pure func abs(i32 x) ~ i32;\n}\n\nfunc main() {}\n"),
+        ("other.ox", "extern \"C\" {\n  pure func abs(bool x) ~ i32;\n}\n")
+    ], &[]);
+    assert_eq!(output.errors(), ["`abs` is declared as `int32_t abs(bool)` here, but as `int32_t abs(int32_t)` elsewhere"]);
+
+    let output = run_files("c-types", &[
+        ("main.ox", "mod other;\n\nextern \"C\" {\n  pure func f(i8 a, i16 b, i32 c, i64 d, f32 e) ~ char;\n}\n\nfunc main() {}\n"),
+        ("other.ox", "extern \"C\" {\n  pure func f(u8 a, u16 b, u32 c, u64 d, f64 e) ~ ();\n}\n")
+    ], &[]);
+    assert_eq!(output.errors(), [
+        "`f` is declared as `void f(uint8_t, uint16_t, uint32_t, uint64_t, double)` here, \
+         but as `uint32_t f(int8_t, int16_t, int32_t, int64_t, float)` elsewhere"
+    ]);
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::run_files;
+
+#[test]
+fn items_are_found_through_modules_and_imports() {
+    let output = run_files("modules", &[
+        ("main.ox", "mod maths;\nuse maths::trig::half;\n\nfunc main() {\n  print(maths::add(1, 2));\n  print(half(8));\n}\n"),
+        ("maths.ox", "pub mod trig;\n\npub func add(i32 a, i32 b) ~ i32 {\n  a + b\n}\n"),
+        ("maths/trig.ox", "pub func half(i32 x) ~ i32 {\n  self::divide(x, 2)\n}\n\nfunc divide(i32 a, i32 b) ~ i32 {\n  a / b\n}\n")
+    ], &[]);
+
+    assert_eq!(output.status, Some(0), "{}", output.stderr);
+    assert_eq!(output.stdout, "34");
+}
+
+#[test]
+fn modules_can_be_in_mod_files() {
+    let output = run_files("mod-files", &[
+        ("main.ox", "mod shapes;\n\nfunc main() {\n  print(shapes::square::area(3));\n}\n"),
+        ("shapes/mod.ox", "pub mod square;\n"),
+        ("shapes/square.ox", "pub func area(i32 side) ~ i32 {\n  side * side\n}\n")
+    ], &[]);
+
+    assert_eq!(output.status, Some(0), "{}", output.stderr);
+    assert_eq!(output.stdout, "9");
+}
+
+#[test]
+fn private_items_are_only_visible_inside_their_module() {
+    let output = run_files("private", &[
+        ("main.ox", "mod maths;\n\nfunc main() {\n  print(maths::secret());\n}\n"),
+        ("maths.ox", "func secret() ~ i32 {\n  4\n}\n")
+    ], &[]);
+
+    assert_eq!(output.errors(), ["`secret` is private to module `maths`"]);
+    assert_eq!(output.location(), Some("4:9"));
+}
+
+#[test]
+fn private_modules_are_not_visible_from_outside() {
+    let output = run_files("private-module", &[
+        ("main.ox", "mod outer;\n\nfunc main() {\n  print(outer::inner::four());\n}\n"),
+        ("outer.ox", "mod inner;\n"),
+        ("outer/inner.ox", "pub func four() ~ i32 {\n  4\n}\n")
+    ], &[]);
+
+    assert_eq!(output.errors(), ["`inner` is private to module `outer`"]);
+}
+
+#[test]
+fn children_can_see_the_private_items_of_their_ancestors() {
+    let output = run_files("ancestors", &[
+        ("main.ox", "mod child;\n\nfunc secret() ~ i32 {\n  7\n}\n\nfunc main() {\n  print(child::reveal());\n}\n"),
+        ("child.ox", "pub func reveal() ~ i32 {\n  crate::secret()\n}\n")
+    ], &[]);
+
+    assert_eq!(output.status, Some(0), "{}", output.stderr);
+    assert_eq!(output.stdout, "7");
+}
+
+#[test]
+fn imports_that_refer_back_to_themselves_are_errors() {
+    let output = run_files("use-cycle", &[
+        ("main.ox", "mod a;\nmod b;\n\nfunc main() {}\n"),
+        ("a.ox", "pub use crate::b::f;\n"),
+        ("b.ox", "pub use crate::a::f;\n")
+    ], &[]);
+
+    assert_eq!(output.errors(), ["The import `crate::b::f` refers back to itself"]);
+}
+
+#[test]
+fn importing_a_module_keyword_by_itself_is_an_error() {
+    for (path, keyword) in [("crate", "crate"), ("self", "self"), ("maths::super", "super")] {
+        let output = run_files("use-keyword", &[
+            ("main.ox", &format!("mod maths;\nuse {path};\n\nfunc main() {{}}\n")),
+            ("maths.ox", "")
+        ], &[]);
+
+        assert_eq!(output.errors(), [format!(
+            "`use {path};` doesn't import anything, import the items inside of `{keyword}` instead"
+        )]);
+        assert_eq!(output.location(), Some("2:1"));
+    }
+}
+
+#[test]
+fn missing_modules_and_items_are_errors() {
+    let output = run_files("missing-module", &[("main.ox", "mod nowhere;\n\nfunc main() {}\n")], &[]);
+    assert!(output.errors()[0].starts_with("Couldn't find a file for the module `nowhere`"), "{}", output.stderr);
+
+    let output = run_files("missing-item", &[
+        ("main.ox", "mod maths;\n\nfunc main() {\n  maths::nothing();\n}\n"),
+        ("maths.ox", "")
+    ], &[]);
+    assert_eq!(output.errors(), ["Couldn't find `nothing` in module `maths`"]);
+
+    let output = run_files("super-of-root", &[("main.ox", "use super::x;\n\nfunc main() {}\n")], &[]);
+    assert_eq!(output.errors(), ["There is no `super` of the crate root"]);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn diverging_code_fits_any_type() {
+    let output = run("diverging", "func fail() ~ never {
+  panic(\"stop\")
+}
+
+func pick(bool b) ~ i32 {
+  if b { 1 } else { fail() }
+}
+
+func main() {
+  print(pick(true));
+  print(pick(false));
+}
+");
+    assert_eq!(output.stdout, "1");
+    assert_eq!(output.panic(), Some("stop"));
+    assert_eq!(output.panic_location(), Some("2:3"));
+}
+
+#[test]
+fn loops_are_left_with_break_or_return() {
+    let output = stdout("loops", "func first_over(i32 limit) ~ i32 {
+  let x = 1;
+  loop {
+    if x > limit {
+      return x;
+    }
+    x *= 2;
+  }
+}
+
+func main() {
+  let count = 0;
+  loop {
+    count += 1;
+    if count == 3 { break; } else if count > 3 { panic(\"missed\"); }
+  }
+  print(count);
+  print(first_over(20));
+}
+");
+    assert_eq!(output, "332");
+}
+
+#[test]
+fn code_after_diverging_code_is_unreachable() {
+    let output = run("unreachable", "func f() ~ i32 {\n  return 1;\n  2\n}\n\nfunc main() {}\n");
+    assert_eq!(output.status, Some(0));
+    assert_eq!(output.warnings(), ["Unreachable code, since the code at 2:3 never finishes"]);
+    assert_eq!(output.location(), Some("3:3"));
+}
+
+#[test]
+fn never_and_control_flow_are_checked() {
+    assert_eq!(
+        error("returns", "func f() ~ never {\n  1\n}\n\nfunc main() {}\n"),
+        "`f` should return `never` but its body has type `i32`"
+    );
+    assert_eq!(
+        error("loop-value", "func main() {\n  let x: i32 = loop { break; };\n}\n"),
+        "Mismatched types: expected `i32` but found `()`"
+    );
+    assert_eq!(
+        error("break-outside", "func main() {\n  break;\n}\n"),
+        "`break` can only be used inside of a `loop`"
+    );
+    assert_eq!(
+        error("loop-body", "func main() {\n  loop { 1 }\n}\n"),
+        "The body of a `loop` can't produce a value, but this block has type `i32`"
+    );
+    assert_eq!(
+        error("if-without-else", "func f(bool b) ~ i32 {\n  if b { 1 }\n}\n\nfunc main() {}\n"),
+        "An `if` without an `else` can't produce a value, but this block has type `i32`"
+    );
+    assert_eq!(
+        error("branches", "func f(bool b) ~ i32 {\n  if b { 1 } else { true }\n}\n\nfunc main() {}\n"),
+        "The branches of this `if` have different types, `i32` and `bool`"
+    );
+}
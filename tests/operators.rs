@@ -0,0 +1,79 @@
+mod common;
+
+use common::{ error, panic, stdout };
+
+#[test]
+fn operators_work_out_their_values() {
+    let output = stdout("values", "func main() {
+  print(-7 % 3);
+  print(\" \");
+  print(2 ** 10);
+  print(\" \");
+  print(-2 ** 2);
+  print(\" \");
+  print(6 ^ 3);
+  print(\" \");
+  print(1 << 4);
+  print(\" \");
+  print(-16 >> 2);
+  print(\" \");
+  print(~5);
+  print(\" \");
+  print(1 + 2 * 3 << 1);
+  print(\" \");
+  print(6 & 3 | 8);
+}
+");
+    assert_eq!(output, "-1 1024 -4 5 16 -4 -6 14 10");
+}
+
+#[test]
+fn operators_are_written_as_they_are_in_errors() {
+    assert_eq!(
+        error("shift_type", "func main() {\n  let x = true << 1;\n}\n"),
+        "Can't use the operator `<<` on `bool` and `i32`"
+    );
+    assert_eq!(
+        error("equality", "func main() {\n  let x = true == 1;\n}\n"),
+        "Can't use the operator `==` on `bool` and `i32`"
+    );
+    assert_eq!(
+        error("power", "func main() {\n  let x = \"a\" ** \"b\";\n}\n"),
+        "Can't use the operator `**` on `str` and `str`"
+    );
+    assert_eq!(
+        error("remainder_type", "func main() {\n  let x = 1.5 % true;\n}\n"),
+        "Can't use the operator `%` on `f64` and `bool`"
+    );
+    assert_eq!(
+        error("xor", "func main() {\n  let x = 'a' ^ 'b';\n}\n"),
+        "Can't use the operator `^` on `char` and `char`"
+    );
+    assert_eq!(error("bit-not", "func main() {\n  let x = ~true;\n}\n"), "Can't use the operator `~` on `bool`");
+    assert_eq!(error("negate", "func main() {\n  let x = -true;\n}\n"), "Can't use the operator `-` on `bool`");
+}
+
+#[test]
+fn constant_operations_that_always_panic_are_errors() {
+    assert_eq!(
+        error("remainder-by-zero", "func main() {\n  let x = 1 % 0;\n}\n"),
+        "`1 % 0` will always panic when it is run, with \"attempt to calculate the remainder with a divisor of zero\""
+    );
+    assert_eq!(
+        error("shift-too-far", "func main() {\n  let x = 1 << 32;\n}\n"),
+        "`1 << 32` will always panic when it is run, with \"attempt to shift by 32, which isn't between 0 and 31\""
+    );
+    assert_eq!(
+        error("negative-power", "func main() {\n  let x = 2 ** -1;\n}\n"),
+        "`2 ** -1` will always panic when it is run, with \"attempt to raise an integer to a negative power (-1)\""
+    );
+}
+
+#[test]
+fn operations_on_variables_panic_when_run() {
+    let message = panic("shift_overflow", "func main() {\n  let bits = 40;\n  print(1 << bits);\n}\n");
+    assert_eq!(message, "attempt to shift by 40, which isn't between 0 and 31");
+
+    let message = panic("remainder_by_zero", "func main() {\n  let zero = 0;\n  print(5 % zero);\n}\n");
+    assert_eq!(message, "attempt to calculate the remainder with a divisor of zero");
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::{ error, panic, run_files, stdout };
+
+#[test]
+fn overflow_panics_by_default() {
+    let message = panic("add", "func main() {\n  let max = 2147483647;\n  print(max + 1);\n}\n");
+    assert_eq!(message, "attempt to add with overflow");
+
+    let message = panic("subtract", "func main() {\n  let zero: u8 = 0;\n  print(zero - 1);\n}\n");
+    assert_eq!(message, "attempt to subtract with overflow");
+
+    let message = panic("negate", "func main() {\n  let min = -2147483648;\n  print(-min);\n}\n");
+    assert_eq!(message, "attempt to negate with overflow");
+}
+
+#[test]
+fn overflow_wraps_without_overflow_checks() {
+    let source = "func main() {\n  let max = 2147483647;\n  print(max + 1);\n}\n";
+    let output = run_files("unchecked", &[("main.ox", source)], &["-C", "overflow-checks=no"]);
+    assert_eq!(output.status, Some(0), "{}", output.stderr);
+    assert_eq!(output.stdout, "-2147483648");
+}
+
+#[test]
+fn overflow_methods_choose_what_happens() {
+    let output = stdout("methods", "func main() {
+  let max = 2147483647;
+  let small: u8 = 250;
+  print(max.wrapping_add(1));
+  print(\" \");
+  print(max.saturating_add(1));
+  print(\" \");
+  print(small.saturating_add(10));
+  print(\" \");
+  print(small.wrapping_mul(2));
+  print(\" \");
+  print(small.saturating_sub(255));
+  print(\" \");
+  print(small.checked_sub(50));
+}
+");
+    assert_eq!(output, "-2147483648 2147483647 255 244 0 200");
+
+    // Checked methods fail whatever the setting is
+    let source = "func main() {\n  let max = 2147483647;\n  print(max.checked_add(1));\n}\n";
+    let output = run_files("checked", &[("main.ox", source)], &["-C", "overflow-checks=no"]);
+    assert_eq!(output.panic(), Some("attempt to add with overflow"));
+}
+
+#[test]
+fn overflow_diagnostics() {
+    assert_eq!(
+        error("constant", "func main() {\n  let x = 2147483647 + 1;\n}\n"),
+        "`2147483647 + 1` will always panic when it is run, with \"attempt to add with overflow\""
+    );
+    assert_eq!(
+        error("unknown-method", "func main() {\n  let x = 1;\n  print(x.frobnicate_add(1));\n}\n"),
+        "There is no method named `frobnicate_add` on `i32`"
+    );
+    assert_eq!(
+        error("float-method", "func main() {\n  let x = 1.5;\n  print(x.wrapping_add(1.0));\n}\n"),
+        "There is no method named `wrapping_add` on `f64`"
+    );
+
+    let output = run_files("option", &[("main.ox", "func main() {}\n")], &["-C", "overflow-checks=maybe"]);
+    assert_eq!(output.errors(), ["Expected `yes` or `no` for `-C overflow-checks`, but got `maybe`"]);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn arguments_can_be_named_or_left_to_their_default() {
+    let output = stdout("defaults", "func range(i32 start, i32 end = 10, i32 step = 1) ~ i32 {
+  (end - start) / step
+}
+
+func main() {
+  print(range(0));
+  print(\" \");
+  print(range(0, step: 2));
+  print(\" \");
+  print(range(step: 5, start: 0, end: 20));
+}
+");
+    assert_eq!(output, "10 5 4");
+}
+
+#[test]
+fn arguments_are_evaluated_in_the_order_they_are_written() {
+    let output = stdout("order", "impure func show(i32 x) ~ i32 {
+  print(x);
+  x
+}
+
+func subtract(i32 a, i32 b) ~ i32 {
+  a - b
+}
+
+impure func main() {
+  print(\" \");
+  print(subtract(b: show(1), a: show(2)));
+}
+");
+    assert_eq!(output, " 121");
+}
+
+#[test]
+fn arguments_are_checked_against_the_parameters() {
+    assert_eq!(
+        error("missing", "func f(i32 a, i32 b) {}\n\nfunc main() {\n  f(1);\n}\n"),
+        "The call to function `crate::f` is missing arguments for the parameter(s) `b`"
+    );
+    assert_eq!(
+        error("too-many", "func f(i32 a) {}\n\nfunc main() {\n  f(1, 2);\n}\n"),
+        "The function `crate::f` takes 1 argument(s) but 2 were given"
+    );
+    assert_eq!(
+        error("unknown-name", "func f(i32 a) {}\n\nfunc main() {\n  f(b: 1);\n}\n"),
+        "The function `crate::f` has no parameter named `b`"
+    );
+    assert_eq!(
+        error("twice", "func f(i32 a) {}\n\nfunc main() {\n  f(1, a: 2);\n}\n"),
+        "The parameter `a` of function `crate::f` is given more than once"
+    );
+    assert_eq!(
+        error("positional-after-named", "func f(i32 a) {}\n\nfunc main() {\n  f(a: 1, 2);\n}\n"),
+        "Positional arguments can't come after named arguments"
+    );
+    assert_eq!(
+        error("named-value", "func f(i32 a) {}\n\nfunc main() {\n  let g = f;\n  g(a: 1);\n}\n"),
+        "The function `g` can't be called with named arguments, only functions called by the name they are defined with can"
+    );
+}
+
+#[test]
+fn parameters_are_checked() {
+    assert_eq!(
+        error("default-type", "func f(i32 a = true) {}\n\nfunc main() {}\n"),
+        "Mismatched types: expected `i32` but found `bool`"
+    );
+    assert_eq!(
+        error("duplicate", "func f(i32 a, i32 a) {}\n\nfunc main() {}\n"),
+        "Tried to define the parameter a again"
+    );
+}
+
+#[test]
+fn defaults_that_call_their_function_forever_panic() {
+    let output = run("endless-default", "func f(i32 a = f()) ~ i32 {
+  a
+}
+
+func main() {
+  print(f());
+}
+");
+    assert_eq!(output.panic(), Some("This call goes more than 1000 calls deep, so the program might never finish"));
+    assert_eq!(output.panic_location(), Some("1:16"));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::{ error, stdout };
+
+#[test]
+fn pipelines_pass_the_value_as_the_first_argument() {
+    let output = stdout("chain", "func double(i32 x) ~ i32 {
+  x * 2
+}
+
+func add(i32 a, i32 b) ~ i32 {
+  a + b
+}
+
+func main() {
+  let increment = func(i32 x) ~ i32 { x + 1 };
+  print(3 |> double() |> add(1));
+  print(\" \");
+  print(3 |> add(b: 10));
+  print(\" \");
+  print(1 + 2 |> double());
+  print(\" \");
+  print(1 |> increment());
+  print(\" \");
+  print(2 |> double() == 4);
+}
+");
+    assert_eq!(output, "7 13 6 2 true");
+}
+
+#[test]
+fn the_right_side_of_a_pipeline_must_be_a_call() {
+    assert_eq!(
+        error("function", "func double(i32 x) ~ i32 {\n  x * 2\n}\n\nfunc main() {\n  print(3 |> double);\n}\n"),
+        "The right side of `|>` must be a call, which the value is passed to e.g. `|> double()`"
+    );
+    assert_eq!(
+        error("literal", "func main() {\n  print(1 |> 2);\n}\n"),
+        "The right side of `|>` must be a call, which the value is passed to as its first argument"
+    );
+}
+
+#[test]
+fn piped_values_count_as_arguments() {
+    assert_eq!(
+        error("too-many", "func f(i32 a) ~ i32 {\n  a\n}\n\nfunc main() {\n  print(1 |> f(2));\n}\n"),
+        "The function `crate::f` takes 1 argument(s) but 2 were given"
+    );
+    assert_eq!(
+        error("named", "func f(i32 a) ~ i32 {\n  a\n}\n\nfunc main() {\n  print(1 |> f(a: 2));\n}\n"),
+        "The parameter `a` of function `crate::f` is given more than once"
+    );
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::{ error, stdout };
+
+#[test]
+fn tuples_can_be_returned_and_destructured() {
+    let output = stdout("destructure", "func divide(i32 a, i32 b) ~ (i32, i32) {
+  (a / b, a % b)
+}
+
+func main() {
+  let (q, r) = divide(7, 2);
+  print(q);
+  print(r);
+
+  let pair = (1, (\"two\", true));
+  let (_, (word, flag)) = pair;
+  print(word);
+  print(flag);
+}
+");
+    assert_eq!(output, "31twotrue");
+}
+
+#[test]
+fn tuple_elements_can_be_accessed() {
+    let output = stdout("elements", "func main() {
+  let pair = (1, (\"two\", true));
+  print(pair);
+  print(pair.1.0);
+  print((5,));
+  print(());
+}
+");
+    assert_eq!(output, "(1, (two, true))two(5,)()");
+}
+
+#[test]
+fn tuple_types_are_checked() {
+    assert_eq!(
+        error("order", "func main() {\n  let p: (i32, bool) = (true, 1);\n}\n"),
+        "Mismatched types: expected `(i32, bool)` but found `(bool, i32)`"
+    );
+    assert_eq!(
+        error("pattern-length", "func main() {\n  let (a, b) = (1, 2, 3);\n}\n"),
+        "A tuple pattern with 2 elements can't match a value of type `(i32, i32, i32)`"
+    );
+    assert_eq!(
+        error("pattern-not-tuple", "func main() {\n  let (a, b) = 5;\n}\n"),
+        "A tuple pattern with 2 elements can't match a value of type `i32`"
+    );
+    assert_eq!(
+        error("missing-element", "func main() {\n  let pair = (1, 2);\n  print(pair.2);\n}\n"),
+        "There is no element 2 in `(i32, i32)`, which only has 2"
+    );
+    assert_eq!(
+        error("not-a-tuple", "func main() {\n  let x = 1;\n  print(x.0);\n}\n"),
+        "Can't access element 0 of `i32`, which isn't a tuple"
+    );
+}
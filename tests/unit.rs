@@ -0,0 +1,81 @@
+mod common;
+
+use common::{ error, run, stdout };
+
+#[test]
+fn unit_is_a_value() {
+    let output = stdout("value", "func unit() ~ () {
+  ()
+}
+
+func nothing() {}
+
+func main() {
+  let u: () = ();
+  let pair = (u, 1);
+  print(pair);
+  print(u);
+  unit();
+  nothing();
+}
+");
+    assert_eq!(output, "((), 1)()");
+}
+
+#[test]
+fn main_returns_the_exit_status() {
+    let output = run("status", "func main() ~ i32 {\n  print(\"bye\");\n  3\n}\n");
+    assert_eq!(output.status, Some(3));
+    assert_eq!(output.stdout, "bye");
+
+    let output = run("no-status", "func main() {}\n");
+    assert_eq!(output.status, Some(0));
+}
+
+#[test]
+fn functions_without_a_return_type_return_unit() {
+    assert_eq!(
+        error("result-used", "func nothing() {}\n\nfunc main() {\n  let u = nothing();\n}\n"),
+        "The function `crate::nothing` doesn't return a value, so its result can't be used"
+    );
+    assert_eq!(
+        error("explicit-unit-used", "func unit() ~ () {\n  ()\n}\n\nfunc main() {\n  print(unit());\n}\n"),
+        "The function `crate::unit` doesn't return a value, so its result can't be used"
+    );
+    assert_eq!(
+        error("body-value", "func f() {\n  1\n}\n\nfunc main() {}\n"),
+        "`f` should return `()` but its body has type `i32`"
+    );
+    assert_eq!(
+        error("missing-value", "func f() ~ i32 {\n}\n\nfunc main() {}\n"),
+        "`f` should return `i32` but its body has type `()`"
+    );
+}
+
+#[test]
+fn main_is_checked() {
+    assert_eq!(error("parameters", "func main(i32 x) {}\n"), "`main` can't take any parameters");
+    assert_eq!(
+        error("return-type", "func main() ~ bool {\n  true\n}\n"),
+        "`main` must return either `()` or `i32` (the exit status), not `bool`"
+    );
+    assert_eq!(error("missing", "func f() {}\n"), "There is no `main` function in the crate root");
+}
+
+#[test]
+fn unit_functions_can_be_fallible() {
+    let output = run("fallible", "func check(i32 x) ~ ()! {
+  if x < 0 {
+    panic(\"negative\");
+  }
+}
+
+func main() {
+  check(1);
+  print(\"ok\");
+  check(-1);
+}
+");
+    assert_eq!(output.stdout, "ok");
+    assert_eq!(output.panic(), Some("negative"));
+}
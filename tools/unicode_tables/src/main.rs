@@ -0,0 +1,269 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use oxygen_lexer::unicode;
+use oxygen_span::char_width;
+use unicode_normalization::char::{ canonical_combining_class, compose, decompose_canonical };
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{ Script, UnicodeScript };
+use unicode_security::confusable_detection::skeleton;
+use unicode_width::UnicodeWidthChar;
+use unicode_xid::UnicodeXID;
+
+const UNICODE_VERSION: &str = "16.0.0";
+
+fn main() -> ExitCode {
+    match env::args().nth(1).as_deref() {
+        None => {
+            write_table("../../compiler/oxygen_lexer/src/unicode/tables.rs", lexer_tables());
+            write_table("../../compiler/oxygen_span/src/width.rs", width_tables());
+            ExitCode::SUCCESS
+        },
+        Some("check") => check(),
+        Some(_) => {
+            eprintln!("Usage: cargo run --release [-- check]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn write_table(path: &str, table: String) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path);
+    fs::write(&path, table).unwrap_or_else(|error| panic!("couldn't write {}: {error}", path.display()));
+    println!("Wrote {}", path.display());
+}
+
+fn chars() -> impl Iterator<Item = char> {
+    (0..=0x10ffff).filter_map(char::from_u32)
+}
+
+fn escape(c: char) -> String {
+    if c.is_ascii_alphanumeric() || c == '_' { c.to_string() } else { format!("\\u{{{:x}}}", c as u32) }
+}
+
+fn char_literal(c: char) -> String {
+    format!("'{}'", escape(c))
+}
+
+fn str_literal(s: &str) -> String {
+    format!("\"{}\"", s.chars().map(escape).collect::<String>())
+}
+
+// The characters `matches`, as inclusive ranges, which carry
+// on over the surrogates since they can't be `char`s anyway
+fn ranges(matches: impl Fn(char) -> bool) -> Vec<(char, char)> {
+    let mut ranges: Vec<(char, char)> = Vec::new();
+    for c in chars().filter(|&c| matches(c)) {
+        match ranges.last_mut() {
+            Some((_, end)) if *end as u32 + 1 == c as u32 || (*end, c) == ('\u{d7ff}', '\u{e000}') => *end = c,
+            _ => ranges.push((c, c))
+        }
+    }
+    ranges
+}
+
+// Ranges of characters with the same non-default `value`
+fn ranges_of<T: Copy + PartialEq>(value: impl Fn(char) -> Option<T>) -> Vec<(char, char, T)> {
+    let mut ranges: Vec<(char, char, T)> = Vec::new();
+    for c in chars() {
+        let Some(value) = value(c) else { continue };
+        match ranges.last_mut() {
+            Some((_, end, last)) if *last == value && *end as u32 + 1 == c as u32 => *end = c,
+            _ => ranges.push((c, c, value))
+        }
+    }
+    ranges
+}
+
+fn range_literal(&(start, end): &(char, char)) -> String {
+    format!("({}, {})", char_literal(start), char_literal(end))
+}
+
+fn emit<T>(out: &mut String, comment: &str, name: &str, r#type: &str, items: &[T], per_line: usize, literal: impl Fn(&T) -> String) {
+    let lines: Vec<String> = items.chunks(per_line)
+        .map(|chunk| chunk.iter().map(&literal).collect::<Vec<_>>().join(", "))
+        .collect();
+
+    writeln!(out, "\n{comment}").unwrap();
+    writeln!(out, "pub const {name}: &[{type}] = &[").unwrap();
+    writeln!(out, "    {}", lines.join(",\n    ")).unwrap();
+    writeln!(out, "];").unwrap();
+}
+
+fn is_hangul_syllable(c: char) -> bool {
+    ('\u{ac00}'..='\u{d7a3}').contains(&c)
+}
+
+fn lexer_tables() -> String {
+    let mut out = format!(
+        "// Generated from version {UNICODE_VERSION} of the Unicode Character Database and of\n\
+         // the confusables data of UTS #39. Don't edit this by hand, but remake it\n\
+         // with tools/unicode_tables.\n"
+    );
+
+    emit(&mut out, "// Characters with the XID_Start property", "XID_START", "(char, char)",
+        &ranges(|c| c.is_xid_start()), 4, range_literal);
+    emit(&mut out, "// Characters with the XID_Continue property", "XID_CONTINUE", "(char, char)",
+        &ranges(|c| c.is_xid_continue()), 4, range_literal);
+
+    let combining_classes = ranges_of(|c| Some(canonical_combining_class(c)).filter(|&class| class != 0));
+    emit(&mut out, "// The Canonical_Combining_Class of every character where it isn't 0", "COMBINING_CLASS",
+        "(char, char, u8)", &combining_classes, 3,
+        |(start, end, class)| format!("({}, {}, {class})", char_literal(*start), char_literal(*end)));
+
+    let decompositions: Vec<(char, String)> = chars()
+        .filter(|&c| !is_hangul_syllable(c))
+        .filter_map(|c| {
+            let mut decomposed = String::new();
+            decompose_canonical(c, |c| decomposed.push(c));
+            (decomposed != c.to_string()).then_some((c, decomposed))
+        })
+        .collect();
+    emit(&mut out, "// The full canonical decomposition of every character that has one,\n\
+                    // apart from Hangul syllables, which are decomposed by an algorithm",
+        "DECOMPOSITION", "(char, &str)", &decompositions, 3,
+        |(c, decomposed)| format!("({}, {})", char_literal(*c), str_literal(decomposed)));
+
+    // A character is composed from the composition of all but the last
+    // character of its decomposition, and that last character, unless
+    // it's excluded from composition, so isn't its own NFC
+    let mut compositions: Vec<(char, char, char)> = decompositions.iter()
+        .filter(|(c, _)| c.to_string().nfc().eq([*c]))
+        .filter_map(|(c, decomposed)| {
+            let (last, init) = decomposed.chars().collect::<Vec<_>>().split_last().map(|(&last, init)| (last, init.to_vec()))?;
+            let mut first = init.into_iter().nfc();
+            let (Some(first), None) = (first.next(), first.next()) else { return None };
+            (compose(first, last) == Some(*c)).then_some((first, last, *c))
+        })
+        .collect();
+    compositions.sort();
+    emit(&mut out, "// The pairs of characters that compose into another, sorted\n\
+                    // by the pair, apart from Hangul, which is composed by an algorithm",
+        "COMPOSITION", "(char, char, char)", &compositions, 3,
+        |(first, second, c)| format!("({}, {}, {})", char_literal(*first), char_literal(*second), char_literal(*c)));
+
+    let script = |c: char| Some(c.script()).filter(|script| !matches!(script, Script::Common | Script::Inherited | Script::Unknown));
+    let mut names: Vec<Script> = chars().filter_map(script).collect();
+    names.sort_by_key(|script| script.full_name());
+    names.dedup();
+    let scripts = ranges_of(|c| script(c).map(|script| names.iter().position(|&name| name == script).unwrap()));
+    emit(&mut out, "// The names of scripts, which `SCRIPTS` refers to by index", "SCRIPT_NAMES", "&str", &names, 6,
+        |script| format!("\"{}\"", script.full_name().replace('_', " ")));
+    emit(&mut out, "// The script of every character that belongs to one, rather than being\n\
+                    // Common or Inherited, which are used alongside any script",
+        "SCRIPTS", "(char, char, u8)", &scripts, 3,
+        |(start, end, script)| format!("({}, {}, {script})", char_literal(*start), char_literal(*end)));
+
+    // Only for characters that are their own NFD, since
+    // the skeleton of an identifier is made from its NFD
+    let confusables: Vec<(char, String)> = chars()
+        .filter(|&c| c.is_xid_continue() && c.to_string().nfd().eq([c]))
+        .filter_map(|c| {
+            let prototype: String = skeleton(&c.to_string()).collect();
+            (prototype != c.to_string()).then_some((c, prototype))
+        })
+        .collect();
+    emit(&mut out, "// What each character that can be in an identifier looks like, where\n\
+                    // that's something else, from which a skeleton of the identifier is made",
+        "CONFUSABLES", "(char, &str)", &confusables, 3,
+        |(c, prototype)| format!("({}, {})", char_literal(*c), str_literal(prototype)));
+
+    out
+}
+
+// Control characters are shown as one column by the terminals
+// that show them at all, and so are characters without a width
+fn terminal_width(c: char) -> usize {
+    if c.is_control() { 1 } else { c.width().unwrap_or(1) }
+}
+
+fn width_tables() -> String {
+    if let Some(c) = chars().find(|&c| terminal_width(c) > 3) {
+        panic!("{c:?} is wider than any of the tables");
+    }
+
+    let mut out = format!(
+        "// Generated from version {UNICODE_VERSION} of the Unicode Character Database. Don't edit\n\
+         // this by hand, but remake it with tools/unicode_tables.\n"
+    );
+
+    emit(&mut out, "// Characters that take up two columns in a terminal, such as CJK and emoji", "WIDE", "(char, char)",
+        &ranges(|c| terminal_width(c) == 2), 4, range_literal);
+    emit(&mut out, "// Characters that take up three columns, which are only ligatures\n\
+                    // of three other characters, such as the Khmer sign beyyal", "THREE_WIDE", "(char, char)",
+        &ranges(|c| terminal_width(c) == 3), 4, range_literal);
+    emit(&mut out, "// Characters that take up no columns, such as combining accents", "ZERO_WIDTH", "(char, char)",
+        &ranges(|c| terminal_width(c) == 0), 4, range_literal);
+
+    out
+}
+
+// An xorshift64 generator, so the strings checked are the same every time
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn check() -> ExitCode {
+    let mut mismatches = 0;
+    let mut mismatch = |what: &str, s: &str| {
+        mismatches += 1;
+        if mismatches <= 20 {
+            println!("{what} differs for {s:?} ({:x?})", s.chars().map(|c| c as u32).collect::<Vec<_>>());
+        }
+    };
+
+    for c in chars() {
+        let s = c.to_string();
+        if unicode::is_xid_start(c) != c.is_xid_start() {
+            mismatch("XID_Start", &s);
+        }
+        if unicode::is_xid_continue(c) != c.is_xid_continue() {
+            mismatch("XID_Continue", &s);
+        }
+        if unicode::nfc(&s) != s.nfc().collect::<String>() {
+            mismatch("NFC", &s);
+        }
+        if c.is_xid_continue() && unicode::skeleton(&s) != skeleton(&s).collect::<String>() {
+            mismatch("The skeleton", &s);
+        }
+        if char_width(c) != terminal_width(c) {
+            mismatch("The width", &s);
+        }
+    }
+
+    // Combining marks of different classes, Hangul jamo and syllables, and
+    // letters that compose with them, in every order and many combinations
+    let pool: Vec<char> = "aeoAEOsSuUnN\u{300}\u{301}\u{308}\u{323}\u{327}\u{345}\u{31b}\
+                           \u{1100}\u{1161}\u{11a8}\u{ac00}\u{ac01}\u{1e63}\u{1e69}\u{3b1}\u{3c9}\u{1f00}\
+                           \u{9c7}\u{9be}\u{5d0}\u{5b4}\u{f71}\u{f72}\u{f73}\u{f80}\u{fb2}\
+                           \u{1b05}\u{1b35}\u{11099}\u{110ba}".chars().collect();
+    let mut generator = Generator(0x0139_408d_cbbf_7a44);
+    for _ in 0..300_000 {
+        let length = generator.next() % 8;
+        let s: String = (0..length).map(|_| pool[(generator.next() % pool.len() as u64) as usize]).collect();
+        if unicode::nfc(&s) != s.nfc().collect::<String>() {
+            mismatch("NFC", &s);
+        }
+        if unicode::skeleton(&s) != skeleton(&s).collect::<String>() {
+            mismatch("The skeleton", &s);
+        }
+    }
+
+    if mismatches == 0 {
+        println!("The compiler agrees with the Unicode crates");
+        ExitCode::SUCCESS
+    } else {
+        println!("{mismatches} differences from the Unicode crates");
+        ExitCode::FAILURE
+    }
+}